@@ -12,14 +12,41 @@ use crate::animation::ZmoAsset;
 pub struct AnimationFrameEvent {
     pub entity: Entity,
     pub flags: AnimationEventFlags,
+    /// Motion frame the event is placed on
+    pub frame: usize,
+    /// Seconds since the exact time of the event frame, events are sent on the
+    /// first update at or after that time
+    pub late_by: f32,
 }
 
 impl AnimationFrameEvent {
-    pub fn new(entity: Entity, flags: AnimationEventFlags) -> Self {
-        Self { entity, flags }
+    pub fn new(entity: Entity, flags: AnimationEventFlags, frame: usize, late_by: f32) -> Self {
+        Self {
+            entity,
+            flags,
+            frame,
+            late_by,
+        }
     }
 }
 
+/// Whether an event repeats the one sent on the previous frame.
+///
+/// Some motions place the same event on neighbouring frames, or on both the
+/// last and first frame so low frame rates still hit it, which would otherwise
+/// fire it twice each loop.
+fn is_repeated_event(
+    last_event: Option<(u16, usize)>,
+    event_id: u16,
+    absolute_frame: usize,
+) -> bool {
+    matches!(
+        last_event,
+        Some((last_event_id, last_frame))
+            if last_event_id == event_id && last_frame + 1 == absolute_frame
+    )
+}
+
 #[derive(Reflect, Component)]
 pub struct AnimationState {
     /// Currently playing animation asset
@@ -57,8 +84,17 @@ pub struct AnimationState {
     /// This is used to track which frame events we have emitted so far
     last_absolute_event_frame: usize,
 
+    /// Last event emitted and the absolute frame it was on
+    last_event: Option<(u16, usize)>,
+
     /// Seconds to delay animation start by
     start_delay: Option<f32>,
+
+    /// Whether playback is paused, the current frame is held until resumed
+    paused: bool,
+
+    /// Frame to jump to on the next advance
+    seek_frame: Option<f32>,
 }
 
 impl Default for AnimationState {
@@ -75,7 +111,10 @@ impl Default for AnimationState {
             current_frame_index: 0,
             next_frame_index: 1,
             last_absolute_event_frame: 0,
+            last_event: None,
             start_delay: None,
+            paused: false,
+            seek_frame: None,
         }
     }
 }
@@ -127,6 +166,22 @@ impl AnimationState {
         }
     }
 
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+
+    /// Jump to the given frame, fractional frames blend with the next frame
+    pub fn seek_to_frame(&mut self, frame: f32) {
+        self.seek_frame = Some(frame.max(0.0));
+        self.completed = false;
+        self.last_absolute_event_frame = frame.max(0.0) as usize;
+        self.last_event = None;
+    }
+
+    pub fn paused(&self) -> bool {
+        self.paused
+    }
+
     pub fn completed(&self) -> bool {
         self.completed
     }
@@ -176,19 +231,32 @@ impl AnimationState {
         }
 
         let current_time = time.elapsed_secs_f64();
-        let start_time = if let Some(start_time) = self.start_time {
+        let mut start_time = if let Some(start_time) = self.start_time {
             start_time
         } else {
             self.start_time = Some(current_time);
             current_time
         };
 
+        let frames_per_second = zmo_asset.fps as f64 * self.animation_speed as f64;
+        if let Some(seek_frame) = self.seek_frame.take() {
+            if frames_per_second > 0.0 {
+                start_time = current_time - seek_frame as f64 / frames_per_second;
+            } else {
+                start_time = current_time;
+            }
+            self.start_time = Some(start_time);
+        } else if self.paused {
+            // Push the start time forward so the current frame is held
+            start_time += time.delta_secs_f64();
+            self.start_time = Some(start_time);
+        }
+
         if self.interpolate_weight < 1.0 {
             self.interpolate_weight += time.delta_secs() / zmo_asset.interpolation_interval;
         }
 
-        let animation_frame_number =
-            (current_time - start_time) * (zmo_asset.fps as f64) * self.animation_speed as f64;
+        let animation_frame_number = (current_time - start_time) * frames_per_second;
 
         self.current_loop_count = animation_frame_number as usize / zmo_asset.num_frames;
         self.completed = self.current_loop_count >= self.max_loop_count.unwrap_or(usize::MAX);
@@ -214,22 +282,48 @@ impl AnimationState {
         self.completed
     }
 
+    /// Calls `event_handler` with the event id, motion frame and seconds since
+    /// the event frame for each frame event passed since the last call
     pub fn iter_animation_events(
         &mut self,
         zmo_asset: &ZmoAsset,
-        mut event_handler: impl FnMut(u16),
+        mut event_handler: impl FnMut(u16, usize, f32),
     ) {
         let num_frames = zmo_asset.num_frames;
         let current_event_frame = self.current_frame_index + self.current_loop_count * num_frames;
+        let current_frame_position = current_event_frame as f32 + self.current_frame_fract;
+        let frames_per_second = zmo_asset.fps as f32 * self.animation_speed;
 
         while self.last_absolute_event_frame <= current_event_frame {
-            if let Some(event_id) =
-                zmo_asset.get_frame_event(self.last_absolute_event_frame % num_frames)
-            {
-                event_handler(event_id.get());
+            let absolute_frame = self.last_absolute_event_frame;
+            if let Some(event_id) = zmo_asset.get_frame_event(absolute_frame % num_frames) {
+                let event_id = event_id.get();
+                if !is_repeated_event(self.last_event, event_id, absolute_frame) {
+                    let late_by = if frames_per_second > 0.0 {
+                        ((current_frame_position - absolute_frame as f32) / frames_per_second)
+                            .max(0.0)
+                    } else {
+                        0.0
+                    };
+                    event_handler(event_id, absolute_frame % num_frames, late_by);
+                }
+                self.last_event = Some((event_id, absolute_frame));
             }
 
             self.last_absolute_event_frame += 1;
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repeated_event_on_next_frame_is_skipped() {
+        assert!(!is_repeated_event(None, 1, 10));
+        assert!(is_repeated_event(Some((1, 9)), 1, 10));
+        assert!(!is_repeated_event(Some((1, 8)), 1, 10));
+        assert!(!is_repeated_event(Some((2, 9)), 1, 10));
+    }
+}
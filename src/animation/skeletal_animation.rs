@@ -73,10 +73,12 @@ pub fn skeletal_animation_system(
         let animation = &mut skeletal_animation.0;
         animation.advance(zmo_asset, &time);
 
-        animation.iter_animation_events(zmo_asset, |event_id| {
+        animation.iter_animation_events(zmo_asset, |event_id, frame, late_by| {
             if let Some(flags) = game_data.animation_event_flags.get(event_id as usize) {
                 if !flags.is_empty() {
-                    animation_frame_events.write(AnimationFrameEvent::new(entity, *flags));
+                    animation_frame_events.write(AnimationFrameEvent::new(
+                        entity, *flags, frame, late_by,
+                    ));
                 }
             }
         });
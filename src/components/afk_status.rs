@@ -0,0 +1,9 @@
+use bevy::prelude::Component;
+
+/// The player is away from the keyboard, whispers are answered with the message
+#[derive(Component)]
+pub struct AfkStatus {
+    pub message: String,
+    /// Elapsed seconds when the player went AFK
+    pub since: f32,
+}
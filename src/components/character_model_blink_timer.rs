@@ -1,4 +1,4 @@
-use std::ops::Range;
+use std::{f32::consts::TAU, ops::Range};
 
 use bevy::{prelude::Component, reflect::Reflect};
 use rand::Rng;
@@ -9,24 +9,29 @@ pub struct CharacterBlinkTimer {
     pub is_open: bool,
     pub closed_duration: f32,
     pub open_duration: f32,
+
+    /// Position in the current breath from 0 to TAU, random so crowds do not
+    /// breathe in unison
+    pub breathing_phase: f32,
+    /// Seconds per breath, chosen from `CharacterIdleSettings` on first update
+    pub breathing_period: f32,
+    /// Breathing height added to the root bone last update
+    pub breathing_offset: f32,
 }
 
 impl CharacterBlinkTimer {
     pub const BLINK_CLOSED_DURATION: Range<f32> = 0.010..0.110;
     pub const BLINK_OPEN_DURATION: Range<f32> = 0.100..3.000;
 
-    pub fn new() -> Self {
+    pub fn new<R: Rng>(rng: &mut R) -> Self {
         Self {
             timer: 0.0,
             is_open: false,
-            closed_duration: rand::thread_rng().gen_range(Self::BLINK_CLOSED_DURATION),
-            open_duration: rand::thread_rng().gen_range(Self::BLINK_OPEN_DURATION),
+            closed_duration: rng.gen_range(Self::BLINK_CLOSED_DURATION),
+            open_duration: rng.gen_range(Self::BLINK_OPEN_DURATION),
+            breathing_phase: rng.gen_range(0.0..TAU),
+            breathing_period: 0.0,
+            breathing_offset: 0.0,
         }
     }
 }
-
-impl Default for CharacterBlinkTimer {
-    fn default() -> Self {
-        Self::new()
-    }
-}
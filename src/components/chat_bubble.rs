@@ -1,4 +1,7 @@
 use bevy::prelude::*;
+use rand::Rng;
+
+use crate::{components::NameTagType, events::ChatBubbleType};
 
 /// Component attached to a chat bubble entity to track its lifetime
 #[derive(Component, Reflect)]
@@ -13,16 +16,50 @@ pub struct ChatBubble {
     pub total_time: f32,
     /// Time when fade-out begins as fraction of total_time (e.g., 0.8 means fade starts at 80% of total time)
     pub fade_start_fraction: f32,
+    /// Seconds since the bubble was spawned
+    pub age: f32,
+    /// Size of the bubble on screen in pixels
+    pub size: Vec2,
+    /// Current upwards offset in pixels to keep clear of overlapping bubbles
+    pub stack_offset: f32,
+    pub bubble_type: ChatBubbleType,
 }
 
 impl ChatBubble {
-    pub fn new(target_entity: Entity, text: String, duration: f32) -> Self {
+    pub fn new(
+        target_entity: Entity,
+        text: String,
+        duration: f32,
+        size: Vec2,
+        bubble_type: ChatBubbleType,
+    ) -> Self {
         Self {
             target_entity,
             text,
             remaining_time: duration,
             total_time: duration,
             fade_start_fraction: 0.2, // Fade starts when 20% of time is left
+            age: 0.0,
+            size,
+            stack_offset: 0.0,
+            bubble_type,
+        }
+    }
+
+    /// Name tag distance settings which apply to the bubble
+    pub fn name_tag_type(&self) -> NameTagType {
+        match self.bubble_type {
+            ChatBubbleType::Monster => NameTagType::Monster,
+            ChatBubbleType::Npc => NameTagType::Npc,
+            _ => NameTagType::Character,
+        }
+    }
+
+    /// Start fading the bubble out now, unless it is already fading
+    pub fn fade_out(&mut self, fade_seconds: f32) {
+        if self.remaining_time > fade_seconds && self.total_time > 0.0 {
+            self.remaining_time = fade_seconds;
+            self.fade_start_fraction = fade_seconds / self.total_time;
         }
     }
 
@@ -64,17 +101,11 @@ pub struct MonsterChatter {
 }
 
 impl MonsterChatter {
-    pub fn new(min_interval: f32, max_interval: f32) -> Self {
+    pub fn new<R: Rng>(min_interval: f32, max_interval: f32, rng: &mut R) -> Self {
         Self {
-            time_until_next_chat: rand::random::<f32>() * (max_interval - min_interval) + min_interval,
+            time_until_next_chat: rng.gen::<f32>() * (max_interval - min_interval) + min_interval,
             min_interval,
             max_interval,
         }
     }
 }
-
-impl Default for MonsterChatter {
-    fn default() -> Self {
-        Self::new(30.0, 120.0) // Default: 30-120 seconds between chats
-    }
-}
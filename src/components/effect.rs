@@ -16,3 +16,12 @@ pub struct EffectMesh {}
 
 #[derive(Component, Default, Reflect)]
 pub struct EffectParticle {}
+
+/// Which kind of effect a particle emitter belongs to, decides which emitters
+/// are throttled first once the particle budget is exceeded
+#[derive(Component, Copy, Clone, Debug, PartialEq, Eq, Reflect)]
+pub enum ParticleCategory {
+    Combat,
+    Character,
+    Ambient,
+}
@@ -0,0 +1,22 @@
+use bevy::prelude::Component;
+
+use crate::resources::HIT_FLASH_SECONDS;
+
+/// Present on an entity for a moment after it is hit, flashes the model and
+/// holds its animation for a brief hit-stop.
+///
+/// Inserted by `hit_event_system` and removed by `hit_flash_system`.
+#[derive(Component)]
+pub struct HitFlash {
+    pub remaining: f32,
+    pub is_critical: bool,
+}
+
+impl HitFlash {
+    pub fn new(is_critical: bool) -> Self {
+        Self {
+            remaining: HIT_FLASH_SECONDS,
+            is_critical,
+        }
+    }
+}
@@ -1,9 +1,20 @@
-use bevy::prelude::{Component, Entity};
+use bevy::prelude::{Component, Deref, DerefMut, Entity};
 use rose_game_common::components::DroppedItem;
 
+use crate::resources::LootRarity;
+
 #[derive(Component)]
 pub struct ItemDropModel {
     pub dropped_item: Option<DroppedItem>,
     pub root_bone: Entity,
     pub model_parts: Vec<Entity>,
 }
+
+#[derive(Component)]
+pub struct LootBeam {
+    pub rarity: LootRarity,
+    pub opacity: f32,
+}
+
+#[derive(Component, Deref, DerefMut)]
+pub struct LootBeamEntity(pub Entity);
@@ -1,3 +1,4 @@
+mod afk_status;
 mod angelic_wings;
 mod bank;
 mod bird;
@@ -23,15 +24,18 @@ mod event_object;
 mod facing_direction;
 mod fish;
 mod flight;
+mod hit_flash;
 mod item_drop_model;
 mod item_drop_timer;
 mod model_height;
 mod monster_separation;
 mod name_tag_entity;
+mod network_movement;
 mod night_time_effect;
 mod npc_model;
 pub mod particle_sequence;
 mod party_info;
+mod party_member_marker;
 mod passive_recovery_time;
 mod pending_damage_list;
 mod pending_skill_effect_list;
@@ -39,8 +43,11 @@ mod personal_store;
 mod player_character;
 mod position;
 mod projectile;
+mod resting;
 mod season;
 mod sound_category;
+mod threat_state;
+mod transient_decal;
 mod vehicle;
 mod vehicle_model;
 mod vehicle_sound;
@@ -52,6 +59,7 @@ mod zone_object;
 mod skinning_target;
 mod skinned_mesh_target_bone;
 
+pub use afk_status::AfkStatus;
 pub use bank::Bank;
 pub use bird::{Bird, BirdMesh, BirdSettings, BirdWingLeft, BirdWingRight};
 pub use blink_clip::{sync_blink_clip_to_state, update_blink_clip_state, BlinkClip, BlinkClipPlugin, BlinkClipState, BlinkUniform};
@@ -81,22 +89,27 @@ pub use damage_digits::DamageDigits;
 pub use dead::Dead;
 pub use dirt_dash_effect::{DirtDashEffect, DirtDashParticle, DirtDashSettings};
 pub use dummy_bone_offset::DummyBoneOffset;
-pub use effect::{Effect, EffectMesh, EffectParticle};
+pub use effect::{Effect, EffectMesh, EffectParticle, ParticleCategory};
 pub use event_object::EventObject;
 pub use facing_direction::FacingDirection;
 pub use fish::{Fish, FishSettings, FishWaterRef, FishMesh, WaterSpawnedEvent};
-pub use item_drop_model::ItemDropModel;
+pub use hit_flash::HitFlash;
+pub use item_drop_model::{ItemDropModel, LootBeam, LootBeamEntity};
 pub use item_drop_timer::{ItemDropOwner, ItemDropRemainingTime};
 pub use model_height::ModelHeight;
 pub use monster_separation::MonsterSeparation;
 pub use name_tag_entity::{
     NameTag, NameTagEntity, NameTagHealthbarBackground, NameTagHealthbarForeground, NameTagName,
-    NameTagTargetMark, NameTagType, RemoveNameTagCommand,
+    NameTagTargetMark, NameTagThreatMark, NameTagType, RemoveNameTagCommand,
 };
+pub use network_movement::{PositionCorrection, RemotePositionBuffer, ServerPositionUpdate};
 pub use night_time_effect::NightTimeEffect;
 pub use npc_model::NpcModel;
 pub use particle_sequence::{ActiveParticle, ParticleSequence};
 pub use party_info::{PartyInfo, PartyOwner};
+pub use party_member_marker::{
+    PartyMemberMarker, PartyMemberMarkerEntity, PartyMemberMarkerHealthbar,
+};
 pub use passive_recovery_time::PassiveRecoveryTime;
 pub use pending_damage_list::{PendingDamage, PendingDamageList};
 pub use pending_skill_effect_list::{
@@ -105,15 +118,18 @@ pub use pending_skill_effect_list::{
 pub use personal_store::{PersonalStore, PersonalStoreModel};
 pub use player_character::PlayerCharacter;
 pub use position::Position;
-pub use projectile::{Projectile, ProjectileParabola, ProjectileTarget};
+pub use projectile::{Projectile, ProjectileFlight, ProjectileTarget};
+pub use resting::Resting;
 pub use season::{GrassBlade, Season, SeasonMarker, SpringFlower, SummerFlower, TerrainMeshForGrass, WeatherParticle};
 pub use sound_category::SoundCategory;
+pub use threat_state::ThreatState;
+pub use transient_decal::TransientDecal;
 pub use vehicle::Vehicle;
 pub use vehicle_model::VehicleModel;
 pub use vehicle_sound::{VehicleSound, VehicleSoundState};
 pub use visible_status_effects::{VisibleStatusEffect, VisibleStatusEffects};
-pub use warp_object::WarpObject;
-pub use zone::Zone;
+pub use warp_object::{WarpDestination, WarpObject};
+pub use zone::{Zone, ZoneInstancingStats};
 pub use zone_object::{
     ZoneObject, ZoneObjectAnimatedObject, ZoneObjectId, ZoneObjectPart,
     ZoneObjectPartCollisionShape, ZoneObjectTerrain,
@@ -3,8 +3,10 @@ use bevy::{
     prelude::{Component, Deref, DerefMut, Entity, World},
 };
 use enum_map::Enum;
+use serde::{Deserialize, Serialize};
 
-#[derive(Copy, Clone, Enum, Debug)]
+#[derive(Copy, Clone, Enum, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum NameTagType {
     Character,
     Monster,
@@ -22,6 +24,9 @@ pub struct NameTagName;
 #[derive(Component)]
 pub struct NameTagTargetMark;
 
+#[derive(Component)]
+pub struct NameTagThreatMark;
+
 #[derive(Component)]
 pub struct NameTagHealthbarForeground {
     pub uv_min_x: f32,
@@ -0,0 +1,24 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::{Component, Vec3};
+
+/// Authoritative position received from the server, consumed by `network_movement_system`
+#[derive(Component, Clone, Copy, Debug)]
+pub struct ServerPositionUpdate {
+    pub position: Vec3,
+}
+
+/// Remaining offset still to be applied to smoothly move onto the server position
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct PositionCorrection {
+    pub remaining: Vec3,
+    pub remaining_secs: f32,
+}
+
+/// Server positions for a remote entity, played back with a small delay so
+/// sparse updates can be interpolated between instead of snapped to
+#[derive(Component, Clone, Debug, Default)]
+pub struct RemotePositionBuffer {
+    /// (time received in seconds, position)
+    pub samples: VecDeque<(f64, Vec3)>,
+}
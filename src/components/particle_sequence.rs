@@ -68,6 +68,7 @@ impl ActiveParticle {
 #[derive(Debug)]
 pub struct ParticleSequenceKeyframe {
     pub start_time: f32,
+    pub start_time_range: RangeInclusive<f32>,
     pub fade: bool,
     pub next_fade_keyframe_index: Option<usize>,
     pub data: PtlKeyframeData,
@@ -96,39 +97,23 @@ pub struct ParticleSequence {
     pub particles: Vec<ActiveParticle>,
 
     pub finished: bool,
+    pub keyframe_start_times_selected: bool,
 }
 
 impl ParticleSequence {
     pub fn from(sequence: PtlSequence) -> Self {
-        let mut rng = rand::thread_rng();
-
-        // Select key frame start times
-        let mut keyframes: Vec<ParticleSequenceKeyframe> = sequence
+        // Key frame start times are selected by particle_sequence_system with the seeded rng
+        let keyframes: Vec<ParticleSequenceKeyframe> = sequence
             .keyframes
             .into_iter()
             .map(|keyframe| ParticleSequenceKeyframe {
-                start_time: rng.gen_range(keyframe.start_time),
+                start_time: *keyframe.start_time.start(),
+                start_time_range: keyframe.start_time,
                 fade: keyframe.fade,
                 next_fade_keyframe_index: None,
                 data: keyframe.data,
             })
             .collect();
-        keyframes.sort_by(|a, b| a.start_time.partial_cmp(&b.start_time).unwrap());
-
-        for i in 0..keyframes.len() {
-            let current = &keyframes[i];
-
-            // Find the first next event of the same type with "fade"
-            for j in i + 1..keyframes.len() {
-                let next = &keyframes[j];
-                if next.fade
-                    && std::mem::discriminant(&current.data) == std::mem::discriminant(&next.data)
-                {
-                    keyframes[i].next_fade_keyframe_index = Some(j);
-                    break;
-                }
-            }
-        }
 
         Self {
             keyframes,
@@ -150,9 +135,35 @@ impl ParticleSequence {
             num_loops: sequence.num_loops,
             num_particles: sequence.num_particles,
             start_delay: 0.0,
+            keyframe_start_times_selected: false,
         }
     }
 
+    pub fn select_keyframe_start_times<R: Rng>(&mut self, rng: &mut R) {
+        let keyframes = &mut self.keyframes;
+        for keyframe in keyframes.iter_mut() {
+            keyframe.start_time = rng.gen_range(keyframe.start_time_range.clone());
+        }
+        keyframes.sort_by(|a, b| a.start_time.partial_cmp(&b.start_time).unwrap());
+
+        for i in 0..keyframes.len() {
+            let current = &keyframes[i];
+
+            // Find the first next event of the same type with "fade"
+            for j in i + 1..keyframes.len() {
+                let next = &keyframes[j];
+                if next.fade
+                    && std::mem::discriminant(&current.data) == std::mem::discriminant(&next.data)
+                {
+                    keyframes[i].next_fade_keyframe_index = Some(j);
+                    break;
+                }
+            }
+        }
+
+        self.keyframe_start_times_selected = true;
+    }
+
     pub fn with_start_delay(mut self, start_delay: f32) -> Self {
         self.start_delay = start_delay;
         self
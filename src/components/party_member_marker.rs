@@ -0,0 +1,16 @@
+use bevy::prelude::{Component, Deref, DerefMut, Entity};
+
+/// Root of the over-head marker spawned above party members
+#[derive(Component)]
+pub struct PartyMemberMarker;
+
+#[derive(Component)]
+pub struct PartyMemberMarkerHealthbar {
+    pub uv_min_x: f32,
+    pub uv_max_x: f32,
+    pub full_width: f32,
+}
+
+/// Added to a party member entity which has a spawned `PartyMemberMarker`
+#[derive(Component, Deref, DerefMut)]
+pub struct PartyMemberMarkerEntity(pub Entity);
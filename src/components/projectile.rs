@@ -5,17 +5,21 @@ use bevy::{
 
 use rose_data::{EffectBulletMoveType, EffectId, SkillId};
 
+use crate::resources::ProjectileMotion;
+
 #[derive(Copy, Clone)]
 pub enum ProjectileTarget {
     Entity { entity: Entity },
     Position { position: Vec3 },
 }
 
-pub struct ProjectileParabola {
-    pub start_y: f32,
-    pub end_y: f32,
-    pub velocity_y: f32,
-    pub move_vec: Vec3,
+/// Flight path of a projectile, set on its first update
+pub struct ProjectileFlight {
+    pub start: Vec3,
+    /// Where the target was at launch, for projectiles which do not home
+    pub fixed_target: Option<Vec3>,
+    /// Height of the arc in metres
+    pub arc_height: f32,
     pub current_time: f32,
     pub total_time: f32,
 }
@@ -30,7 +34,8 @@ pub struct Projectile {
 
     pub move_type: EffectBulletMoveType,
     pub move_speed: f32,
-    pub parabola: Option<ProjectileParabola>,
+    pub motion: ProjectileMotion,
+    pub flight: Option<ProjectileFlight>,
 
     pub apply_damage: bool,
 }
@@ -0,0 +1,24 @@
+use bevy::prelude::Component;
+
+/// Present on the player while they are seated, HP and MP recover faster
+/// while resting.
+///
+/// Inserted and removed by `resting_system` from the player's sit command.
+#[derive(Component)]
+pub struct Resting {
+    /// HP and MP when the player sat down, used to show how much has been recovered
+    pub start_hp: i32,
+    pub start_mp: i32,
+    /// Standing up has been requested after taking damage
+    pub stand_requested: bool,
+}
+
+impl Resting {
+    pub fn new(hp: i32, mp: i32) -> Self {
+        Self {
+            start_hp: hp,
+            start_mp: mp,
+            stand_requested: false,
+        }
+    }
+}
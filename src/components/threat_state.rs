@@ -0,0 +1,13 @@
+use bevy::{prelude::Component, reflect::Reflect};
+
+/// Present on monsters which currently have the player as their target.
+///
+/// Inserted and removed by `threat_state_system` from the monster's attack
+/// commands and incoming hit events, read by the name tag threat indicator.
+#[derive(Component, Default, Reflect)]
+pub struct ThreatState {
+    /// Seconds since the monster last attacked or targeted the player
+    pub time_since_aggro: f32,
+    /// Accumulated time used to drive the name tag pulse
+    pub pulse_time: f32,
+}
@@ -0,0 +1,27 @@
+use bevy::prelude::Component;
+
+/// A short lived decal or ground effect, such as a blood spatter or the move
+/// destination marker.
+///
+/// `transient_decal_system` despawns these once their lifetime has passed,
+/// limits how many can exist at once and clears them all on zone change.
+#[derive(Component, Clone, Debug)]
+pub struct TransientDecal {
+    /// Elapsed time when the decal was spawned or last reused from a pool
+    pub spawned_at: f32,
+    /// Seconds the decal lives for, `None` uses `DecalSettings::decal_lifetime`
+    pub lifetime: Option<f32>,
+}
+
+impl TransientDecal {
+    pub fn new(spawned_at: f32, lifetime: Option<f32>) -> Self {
+        Self {
+            spawned_at,
+            lifetime,
+        }
+    }
+
+    pub fn expires_at(&self, default_lifetime: f32) -> f32 {
+        self.spawned_at + self.lifetime.unwrap_or(default_lifetime)
+    }
+}
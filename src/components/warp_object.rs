@@ -1,4 +1,8 @@
-use bevy::{prelude::Component, reflect::Reflect};
+use bevy::{
+    math::Vec3,
+    prelude::Component,
+    reflect::Reflect,
+};
 
 use rose_data::WarpGateId;
 
@@ -16,3 +20,47 @@ impl WarpObject {
         }
     }
 }
+
+/// Destination of a warp gate set in the map editor.
+///
+/// IFO warp objects only reference a warp id, so the destination is kept in
+/// the otherwise unused object name as `warp:<zone>:<x>,<y>,<z>`.
+#[derive(Component, Reflect, Clone, Copy, Debug, PartialEq)]
+pub struct WarpDestination {
+    pub zone_id: u16,
+    /// World position in the destination zone
+    pub position: Vec3,
+}
+
+impl WarpDestination {
+    const IFO_NAME_PREFIX: &'static str = "warp:";
+
+    pub fn from_ifo_name(name: &str) -> Option<Self> {
+        let (zone_id, position) = name
+            .strip_prefix(Self::IFO_NAME_PREFIX)?
+            .split_once(':')?;
+        let mut coordinates = position.split(',').map(|value| value.trim().parse::<f32>());
+        let x = coordinates.next()?.ok()?;
+        let y = coordinates.next()?.ok()?;
+        let z = coordinates.next()?.ok()?;
+        if coordinates.next().is_some() {
+            return None;
+        }
+
+        Some(Self {
+            zone_id: zone_id.trim().parse().ok()?,
+            position: Vec3::new(x, y, z),
+        })
+    }
+
+    pub fn to_ifo_name(&self) -> String {
+        format!(
+            "{}{}:{:.2},{:.2},{:.2}",
+            Self::IFO_NAME_PREFIX,
+            self.zone_id,
+            self.position.x,
+            self.position.y,
+            self.position.z
+        )
+    }
+}
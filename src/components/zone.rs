@@ -6,3 +6,12 @@ use rose_data::ZoneId;
 pub struct Zone {
     pub id: ZoneId,
 }
+
+/// Object parts spawned with shared materials and the number of distinct
+/// mesh and material pairs they were drawn with, only present when instanced
+/// zone objects are enabled
+#[derive(Component)]
+pub struct ZoneInstancingStats {
+    pub parts: usize,
+    pub draw_batches: usize,
+}
@@ -0,0 +1,393 @@
+//! First run setup shown when no game data could be loaded.
+//!
+//! Lets the user browse to their ROSE Online folder, VFS index or zip archive,
+//! detects the filesystem device type, writes it to the `[filesystem]` section
+//! of the config file and restarts the client with that config.
+
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use bevy::{
+    app::AppExit,
+    prelude::{MessageWriter, ResMut, Resource},
+};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::{
+    error_screen_app, filesystem_device_errors_ui, resources::ConfigFile, FilesystemConfig,
+    FilesystemDeviceConfig, FilesystemDeviceError,
+};
+
+/// Example filesystem config for users who prefer to edit the config by hand
+const FILESYSTEM_CONFIG_EXAMPLE: &str = r#"[[filesystem.devices]]
+type = "vfs"
+path = "C:/Games/ROSE Online/data.idx"
+
+# or a folder of extracted game data
+[[filesystem.devices]]
+type = "directory"
+path = "C:/Games/ROSE Online/data""#;
+
+/// Device types which can be picked when the detected one is wrong, overlays are
+/// only useful on top of other game data so they are not offered
+const DEVICE_TYPES: [(&str, fn(String) -> FilesystemDeviceConfig); 6] = [
+    ("vfs", FilesystemDeviceConfig::Vfs),
+    ("aruavfs", FilesystemDeviceConfig::AruaVfs),
+    ("titanvfs", FilesystemDeviceConfig::TitanVfs),
+    ("iroseph", FilesystemDeviceConfig::IrosePh),
+    ("directory", FilesystemDeviceConfig::Directory),
+    ("zip", FilesystemDeviceConfig::Zip),
+];
+
+fn file_name_lowercase(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().to_lowercase())
+        .unwrap_or_default()
+}
+
+/// Find an entry of a directory by name ignoring case, game data is often copied from Windows
+fn find_entry(directory: &Path, name: &str) -> Option<PathBuf> {
+    std::fs::read_dir(directory)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .find(|path| file_name_lowercase(path) == name)
+}
+
+/// Guess the filesystem device for a path picked by the user:
+/// - a `.zip` archive
+/// - a `.idx` index, whose type is decided by the data file next to it
+/// - a folder containing `data.idx`
+/// - a folder of extracted game data containing `3DDATA`
+pub fn detect_filesystem_device(path: &Path) -> Option<FilesystemDeviceConfig> {
+    let path_string = path.to_string_lossy().to_string();
+
+    if path.is_dir() {
+        if let Some(index_path) = find_entry(path, "data.idx") {
+            return detect_filesystem_device(&index_path);
+        }
+        if find_entry(path, "3ddata").map_or(false, |path| path.is_dir()) {
+            return Some(FilesystemDeviceConfig::Directory(path_string));
+        }
+        return None;
+    }
+
+    if !path.is_file() {
+        return None;
+    }
+
+    let file_name = file_name_lowercase(path);
+    if file_name.ends_with(".zip") {
+        Some(FilesystemDeviceConfig::Zip(path_string))
+    } else if file_name.ends_with(".idx") {
+        let directory = path.parent().unwrap_or(Path::new(""));
+        if find_entry(directory, "data.rose").is_some() {
+            Some(FilesystemDeviceConfig::AruaVfs(path_string))
+        } else if find_entry(directory, "data.trf").is_some() {
+            Some(FilesystemDeviceConfig::TitanVfs(path_string))
+        } else {
+            Some(FilesystemDeviceConfig::Vfs(path_string))
+        }
+    } else {
+        None
+    }
+}
+
+struct DirectoryEntry {
+    path: PathBuf,
+    name: String,
+    is_dir: bool,
+}
+
+#[derive(Resource)]
+struct DataPathWizard {
+    config_path: PathBuf,
+    hot_reload: bool,
+    device_errors: Vec<FilesystemDeviceError>,
+    current_dir: PathBuf,
+    path_text: String,
+    entries: Vec<DirectoryEntry>,
+    device: Option<FilesystemDeviceConfig>,
+    error: Option<String>,
+}
+
+impl DataPathWizard {
+    /// Browse to a folder or select a file, and detect the device for it
+    fn select(&mut self, path: PathBuf) {
+        self.device = detect_filesystem_device(&path);
+        self.error = None;
+
+        if path.is_dir() {
+            self.entries = read_directory(&path);
+            self.current_dir = path;
+        } else if let Some(parent) = path.parent().filter(|parent| *parent != self.current_dir) {
+            self.entries = read_directory(parent);
+            self.current_dir = parent.to_path_buf();
+        }
+        self.path_text = path.to_string_lossy().to_string();
+    }
+
+    fn save_and_restart(&self, device: &FilesystemDeviceConfig) -> anyhow::Result<()> {
+        ConfigFile::new(Some(self.config_path.clone())).save_section(
+            "filesystem",
+            &FilesystemConfig {
+                devices: vec![device.clone()],
+                hot_reload: self.hot_reload,
+            },
+        )?;
+        log::info!(
+            "[VFS INIT] Saved {} to {}, restarting",
+            device.describe(),
+            self.config_path.display()
+        );
+        restart_with_config(&self.config_path)?;
+        Ok(())
+    }
+}
+
+/// Folders and files which could be game data, sorted with folders first
+fn read_directory(directory: &Path) -> Vec<DirectoryEntry> {
+    let Ok(read_dir) = std::fs::read_dir(directory) else {
+        return Vec::new();
+    };
+
+    let mut entries: Vec<DirectoryEntry> = read_dir
+        .filter_map(|entry| entry.ok())
+        .map(|entry| {
+            let path = entry.path();
+            DirectoryEntry {
+                name: entry.file_name().to_string_lossy().to_string(),
+                is_dir: path.is_dir(),
+                path,
+            }
+        })
+        .filter(|entry| {
+            let name = entry.name.to_lowercase();
+            entry.is_dir || name.ends_with(".idx") || name.ends_with(".zip")
+        })
+        .collect();
+    entries.sort_by(|a, b| {
+        b.is_dir
+            .cmp(&a.is_dir)
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+    });
+    entries
+}
+
+/// Start the client again with the same arguments but the given config file
+fn restart_with_config(config_path: &Path) -> std::io::Result<()> {
+    let mut args = Vec::new();
+    let mut skip_value = false;
+    for arg in std::env::args().skip(1) {
+        if skip_value {
+            skip_value = false;
+        } else if arg == "--config" {
+            skip_value = true;
+        } else if !arg.starts_with("--config=") {
+            args.push(arg);
+        }
+    }
+
+    Command::new(std::env::current_exe()?)
+        .args(args)
+        .arg("--config")
+        .arg(config_path)
+        .spawn()?;
+    Ok(())
+}
+
+fn data_path_wizard_system(
+    mut egui_context: EguiContexts,
+    mut wizard: ResMut<DataPathWizard>,
+    mut app_exit_events: MessageWriter<AppExit>,
+) {
+    let Ok(ctx) = egui_context.ctx_mut() else {
+        return;
+    };
+    let wizard = &mut *wizard;
+
+    egui::CentralPanel::default().show(ctx, |ui| {
+        ui.heading("Set up game data");
+        ui.label("The client needs the game data of a ROSE Online installation. Browse to your ROSE Online folder, its data.idx, or a .zip of the game data.");
+
+        if !wizard.device_errors.is_empty() {
+            ui.separator();
+            ui.label("None of the configured filesystem devices could be loaded:");
+            filesystem_device_errors_ui(ui, &wizard.device_errors);
+        }
+        ui.separator();
+
+        let mut selected_path = None;
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(wizard.current_dir.parent().is_some(), egui::Button::new("Up"))
+                .clicked()
+            {
+                selected_path = wizard.current_dir.parent().map(Path::to_path_buf);
+            }
+
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut wizard.path_text).desired_width(f32::INFINITY),
+            );
+            if response.lost_focus() && ui.input(|input| input.key_pressed(egui::Key::Enter)) {
+                selected_path = Some(PathBuf::from(wizard.path_text.trim()));
+            }
+        });
+
+        egui::ScrollArea::vertical()
+            .max_height(ui.available_height() - 160.0)
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                for entry in wizard.entries.iter() {
+                    let label = if entry.is_dir {
+                        format!("[{}]", entry.name)
+                    } else {
+                        entry.name.clone()
+                    };
+                    if ui.selectable_label(false, label).clicked() {
+                        selected_path = Some(entry.path.clone());
+                    }
+                }
+            });
+
+        if let Some(path) = selected_path {
+            wizard.select(path);
+        }
+
+        ui.separator();
+
+        match wizard.device.clone() {
+            Some(device) => {
+                ui.horizontal(|ui| {
+                    ui.label("Detected:");
+                    let path = device.path().to_string();
+                    egui::ComboBox::from_id_salt("data_path_wizard_device_type")
+                        .selected_text(device.device_type())
+                        .show_ui(ui, |ui| {
+                            for (name, make_device) in DEVICE_TYPES {
+                                if ui.selectable_label(device.device_type() == name, name).clicked() {
+                                    wizard.device = Some(make_device(path.clone()));
+                                }
+                            }
+                        });
+                    ui.label(&path);
+                });
+            }
+            None => {
+                ui.label("No game data found here.");
+            }
+        }
+        ui.label(format!("The setting is saved to {}", wizard.config_path.display()));
+
+        if let Some(error) = wizard.error.as_ref() {
+            ui.colored_label(egui::Color32::RED, error);
+        }
+
+        ui.collapsing("Edit the config by hand", |ui| {
+            ui.add(
+                egui::TextEdit::multiline(&mut FILESYSTEM_CONFIG_EXAMPLE)
+                    .code_editor()
+                    .desired_width(f32::INFINITY),
+            );
+            ui.label("Supported device types are vfs, directory, zip, aruavfs, titanvfs, iroseph and overlay.");
+        });
+
+        ui.separator();
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(wizard.device.is_some(), egui::Button::new("Save and start"))
+                .clicked()
+            {
+                if let Some(device) = wizard.device.clone() {
+                    match wizard.save_and_restart(&device) {
+                        Ok(()) => {
+                            app_exit_events.write(AppExit::Success);
+                        }
+                        Err(error) => {
+                            log::error!("[VFS INIT] Failed to save game data path: {:#}", error);
+                            wizard.error = Some(format!("Failed to save: {:#}", error));
+                        }
+                    }
+                }
+            }
+            if ui.button("Quit").clicked() {
+                app_exit_events.write(AppExit::Success);
+            }
+        });
+    });
+}
+
+/// Shows the setup wizard in place of the game, `config_path` is where the
+/// picked device is saved, `config.toml` in the working directory when unset.
+pub(crate) fn run_data_path_wizard(
+    config_path: Option<PathBuf>,
+    hot_reload: bool,
+    device_errors: Vec<FilesystemDeviceError>,
+    width: f32,
+    height: f32,
+) {
+    let working_dir = std::env::current_dir().unwrap_or_default();
+    let config_path = working_dir.join(config_path.unwrap_or_else(|| PathBuf::from("config.toml")));
+
+    let mut wizard = DataPathWizard {
+        config_path,
+        hot_reload,
+        device_errors,
+        current_dir: working_dir.clone(),
+        path_text: String::new(),
+        entries: Vec::new(),
+        device: None,
+        error: None,
+    };
+    wizard.select(working_dir);
+
+    let mut app = error_screen_app(width, height);
+    app.insert_resource(wizard)
+        .add_systems(bevy_egui::EguiPrimaryContextPass, data_path_wizard_system);
+    app.run();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_filesystem_device() {
+        let root = std::env::temp_dir().join(format!(
+            "rose_data_path_wizard_test_{}",
+            std::process::id()
+        ));
+        let vfs_dir = root.join("vfs");
+        let titan_dir = root.join("titan");
+        let extracted_dir = root.join("extracted");
+        std::fs::create_dir_all(&vfs_dir).unwrap();
+        std::fs::create_dir_all(&titan_dir).unwrap();
+        std::fs::create_dir_all(extracted_dir.join("3DDATA")).unwrap();
+        std::fs::write(vfs_dir.join("DATA.IDX"), b"").unwrap();
+        std::fs::write(titan_dir.join("data.idx"), b"").unwrap();
+        std::fs::write(titan_dir.join("data.trf"), b"").unwrap();
+        std::fs::write(root.join("data.zip"), b"").unwrap();
+
+        assert!(matches!(
+            detect_filesystem_device(&vfs_dir),
+            Some(FilesystemDeviceConfig::Vfs(path)) if path.ends_with("DATA.IDX")
+        ));
+        assert!(matches!(
+            detect_filesystem_device(&titan_dir.join("data.idx")),
+            Some(FilesystemDeviceConfig::TitanVfs(_))
+        ));
+        assert!(matches!(
+            detect_filesystem_device(&extracted_dir),
+            Some(FilesystemDeviceConfig::Directory(_))
+        ));
+        assert!(matches!(
+            detect_filesystem_device(&root.join("data.zip")),
+            Some(FilesystemDeviceConfig::Zip(_))
+        ));
+        assert!(detect_filesystem_device(&root).is_none());
+
+        std::fs::remove_dir_all(&root).ok();
+    }
+}
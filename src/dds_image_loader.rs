@@ -1,12 +1,97 @@
 use bevy::{
     asset::{io::Reader, AssetLoader, LoadContext, RenderAssetUsages},
-    image::ImageSampler,
+    image::{ImageSampler, ImageSamplerDescriptor},
     prelude::{Image, TypePath},
     render::render_resource::{Extent3d, TextureDimension, TextureFormat, TextureViewDescriptor, TextureViewDimension},
     tasks::futures_lite::AsyncReadExt,
 };
 use log::{info, warn, error};
-use std::future::Future;
+
+use crate::resources::current_anisotropy;
+use std::{
+    future::Future,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex, OnceLock,
+    },
+};
+
+/// Size of the checkerboard placeholder used for textures which failed to decode
+const PLACEHOLDER_SIZE: u32 = 8;
+
+/// A DDS texture which could not be decoded and was replaced by a placeholder
+#[derive(Clone, Debug)]
+pub struct DdsLoadFailure {
+    pub path: String,
+    pub reason: String,
+}
+
+static DDS_LOAD_FAILURES: OnceLock<Mutex<Vec<DdsLoadFailure>>> = OnceLock::new();
+
+fn get_dds_load_failures() -> &'static Mutex<Vec<DdsLoadFailure>> {
+    DDS_LOAD_FAILURES.get_or_init(|| Mutex::new(Vec::new()))
+}
+
+/// Textures which failed to decode since startup, shown in the render debug window
+pub fn dds_load_failures() -> Vec<DdsLoadFailure> {
+    get_dds_load_failures().lock().unwrap().clone()
+}
+
+pub fn clear_dds_load_failures() {
+    get_dds_load_failures().lock().unwrap().clear();
+}
+
+static TEXTURES_SHIPPED_WITH_MIPMAPS: AtomicUsize = AtomicUsize::new(0);
+static TEXTURES_GENERATED_MIPMAPS: AtomicUsize = AtomicUsize::new(0);
+static TEXTURES_WITHOUT_MIPMAPS: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DdsMipmapStats {
+    /// Textures whose file contains a mip chain
+    pub shipped: usize,
+    /// Textures which had a mip chain generated at load time
+    pub generated: usize,
+    /// Textures uploaded with only the base level
+    pub without: usize,
+}
+
+static TEXTURES_DOWNSAMPLED: AtomicUsize = AtomicUsize::new(0);
+static TEXTURE_BYTES_SAVED: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DdsDownsampleStats {
+    /// Textures reduced to fit `max_texture_resolution`
+    pub textures: usize,
+    /// Texture memory saved by downsampling, excluding mipmaps
+    pub bytes_saved: usize,
+}
+
+pub fn dds_downsample_stats() -> DdsDownsampleStats {
+    DdsDownsampleStats {
+        textures: TEXTURES_DOWNSAMPLED.load(Ordering::Relaxed),
+        bytes_saved: TEXTURE_BYTES_SAVED.load(Ordering::Relaxed),
+    }
+}
+
+pub fn dds_mipmap_stats() -> DdsMipmapStats {
+    DdsMipmapStats {
+        shipped: TEXTURES_SHIPPED_WITH_MIPMAPS.load(Ordering::Relaxed),
+        generated: TEXTURES_GENERATED_MIPMAPS.load(Ordering::Relaxed),
+        without: TEXTURES_WITHOUT_MIPMAPS.load(Ordering::Relaxed),
+    }
+}
+
+fn record_dds_load_failure(path: &str, reason: String) {
+    warn!("[DDS LOADER] Failed to decode {}, using placeholder: {}", path, reason);
+
+    let mut failures = get_dds_load_failures().lock().unwrap();
+    // A reloaded texture replaces its previous entry
+    failures.retain(|failure| failure.path != path);
+    failures.push(DdsLoadFailure {
+        path: path.to_string(),
+        reason,
+    });
+}
 
 /// Custom asset loader for DDS files that handles unsupported formats like R8G8B8
 /// by converting them to formats Bevy can render (R8G8B8A8).
@@ -14,7 +99,12 @@ use std::future::Future;
 /// NOTE: All output is converted to R8G8B8A8 to avoid Bevy 0.13.2 issues with
 /// compressed texture pixel_size calculations that cause panics.
 #[derive(Default, TypePath)]
-pub struct DdsImageLoader;
+pub struct DdsImageLoader {
+    /// Generate a full mip chain for decoded textures, see `generate_mipmaps`
+    pub generate_mipmaps: bool,
+    /// Textures larger than this are downsampled before upload to save VRAM
+    pub max_texture_resolution: Option<u32>,
+}
 
 impl AssetLoader for DdsImageLoader {
     type Asset = Image;
@@ -38,74 +128,38 @@ impl AssetLoader for DdsImageLoader {
            // info!("[DDS LOADER] Loading DDS texture: {}", asset_path);
            // info!("[DDS LOADER] File size: {} bytes", bytes.len());
 
-            // Parse the DDS header to determine format
-            let dds_info = parse_dds_header(&bytes)?;
-           // info!("[DDS LOADER] DDS format: {:?}, {}x{}, mips: {}",
-                //dds_info.format, dds_info.width, dds_info.height, dds_info.mip_count);
-
-            // Handle based on format - ALL paths convert to R8G8B8A8
-            // This avoids Bevy 0.13.2 panics with compressed texture pixel_size
-            match dds_info.format {
-                DdsFormat::R8G8B8 => {
-                   // info!("[DDS LOADER] Converting R8G8B8 to R8G8B8A8");
-                    convert_rgb_to_rgba(&bytes, &dds_info, is_cube)
-                }
-                DdsFormat::R8G8B8A8 | DdsFormat::B8G8R8A8 => {
-                   // info!("[DDS LOADER] Loading RGBA data directly");
-                    load_rgba_direct(&bytes, &dds_info, is_cube)
-                }
-                DdsFormat::B8G8R8 => {
-                   // info!("[DDS LOADER] Converting B8G8R8 to R8G8B8A8");
-                    convert_bgr_to_rgba(&bytes, &dds_info, is_cube)
-                }
-                DdsFormat::A1R5G5B5 => {
-                    // info!("[DDS LOADER] Converting A1R5G5B5 to R8G8B8A8");
-                    convert_a1r5g5b5_to_rgba(&bytes, &dds_info, is_cube)
-                }
-                DdsFormat::R5G6B5 => {
-                    // info!("[DDS LOADER] Converting R5G6B5 to R8G8B8A8");
-                    convert_r5g6b5_to_rgba(&bytes, &dds_info, is_cube)
-                }
-                DdsFormat::A4R4G4B4 => {
-                    // info!("[DDS LOADER] Converting A4R4G4B4 to R8G8B8A8");
-                    convert_a4r4g4b4_to_rgba(&bytes, &dds_info, is_cube)
-                }
-                DdsFormat::B5G6R5 => {
-                   // info!("[DDS LOADER] Converting B5G6R5 to R8G8B8A8");
-                    convert_b5g6r5_to_rgba(&bytes, &dds_info, is_cube)
+        // A texture which fails to decode is replaced with a magenta placeholder so
+        // the rest of the material still renders and the broken asset stands out
+        match decode_dds(&bytes, &asset_path, is_cube) {
+            Ok((mut image, shipped_mip_count)) => {
+                if shipped_mip_count > 1 {
+                    TEXTURES_SHIPPED_WITH_MIPMAPS.fetch_add(1, Ordering::Relaxed);
                 }
-                DdsFormat::Bc1Dxt1 => {
-                   // info!("[DDS LOADER] Decompressing BC1/DXT1 to R8G8B8A8");
-                    decompress_bc1_to_rgba(&bytes, &dds_info, is_cube)
-                }
-                DdsFormat::Bc2Dxt3 => {
-                   // info!("[DDS LOADER] Decompressing BC2/DXT3 to R8G8B8A8");
-                    decompress_bc2_to_rgba(&bytes, &dds_info, is_cube)
-                }
-                DdsFormat::Bc3Dxt5 => {
-                   // info!("[DDS LOADER] Decompressing BC3/DXT5 to R8G8B8A8");
-                    decompress_bc3_to_rgba(&bytes, &dds_info, is_cube)
-                }
-                DdsFormat::A8 => {
-                    // Alpha-only textures are common for particle masks.
-                    // Convert to white RGB + alpha so shader tinting remains visible.
-                    convert_a8_to_rgba(&bytes, &dds_info, is_cube)
-                }
-                DdsFormat::L8 => {
-                    // Luminance-only textures map luminance to RGB with full alpha.
-                    convert_l8_to_rgba(&bytes, &dds_info, is_cube)
-                }
-                DdsFormat::L8A8 => {
-                    // Luminance+alpha textures are often used by legacy VFX masks.
-                    convert_l8a8_to_rgba(&bytes, &dds_info, is_cube)
+
+                if let Some(max_texture_resolution) = self.max_texture_resolution {
+                    let bytes_saved = limit_resolution(&mut image, max_texture_resolution);
+                    if bytes_saved > 0 {
+                        TEXTURES_DOWNSAMPLED.fetch_add(1, Ordering::Relaxed);
+                        TEXTURE_BYTES_SAVED.fetch_add(bytes_saved, Ordering::Relaxed);
+                    }
                 }
-                _ => {
-                    // Try image crate as fallback - it will also convert to RGBA8
-                    warn!("[DDS LOADER] Format {:?}, trying image crate", dds_info.format);
-                    try_image_crate(&bytes, &asset_path, is_cube)
+
+                // Only the base level is decoded, so shipped mip chains are
+                // regenerated from it as well
+                if self.generate_mipmaps && !is_cube && generate_mipmaps(&mut image) {
+                    TEXTURES_GENERATED_MIPMAPS.fetch_add(1, Ordering::Relaxed);
+                } else {
+                    TEXTURES_WITHOUT_MIPMAPS.fetch_add(1, Ordering::Relaxed);
                 }
+
+                Ok(image)
+            }
+            Err(error) => {
+                record_dds_load_failure(&asset_path, error.to_string());
+                Ok(create_placeholder_image(is_cube))
             }
         }
+        }
     }
 
     fn extensions(&self) -> &[&str] {
@@ -115,6 +169,88 @@ impl AssetLoader for DdsImageLoader {
     }
 }
 
+/// Decodes the base level of a DDS texture to RGBA8, returns the image and
+/// the number of mip levels in the file
+fn decode_dds(bytes: &[u8], asset_path: &str, is_cube: bool) -> anyhow::Result<(Image, u32)> {
+    // Parse the DDS header to determine format
+    let dds_info = parse_dds_header(bytes)?;
+   // info!("[DDS LOADER] DDS format: {:?}, {}x{}, mips: {}",
+        //dds_info.format, dds_info.width, dds_info.height, dds_info.mip_count);
+
+    // Handle based on format - ALL paths convert to R8G8B8A8
+    // This avoids Bevy 0.13.2 panics with compressed texture pixel_size
+    let image = match dds_info.format {
+        DdsFormat::R8G8B8 => {
+           // info!("[DDS LOADER] Converting R8G8B8 to R8G8B8A8");
+            convert_rgb_to_rgba(bytes, &dds_info, is_cube)
+        }
+        DdsFormat::R8G8B8A8 | DdsFormat::B8G8R8A8 => {
+           // info!("[DDS LOADER] Loading RGBA data directly");
+            load_rgba_direct(bytes, &dds_info, is_cube)
+        }
+        DdsFormat::B8G8R8 => {
+           // info!("[DDS LOADER] Converting B8G8R8 to R8G8B8A8");
+            convert_bgr_to_rgba(bytes, &dds_info, is_cube)
+        }
+        DdsFormat::A1R5G5B5 => {
+            // info!("[DDS LOADER] Converting A1R5G5B5 to R8G8B8A8");
+            convert_a1r5g5b5_to_rgba(bytes, &dds_info, is_cube)
+        }
+        DdsFormat::R5G6B5 => {
+            // info!("[DDS LOADER] Converting R5G6B5 to R8G8B8A8");
+            convert_r5g6b5_to_rgba(bytes, &dds_info, is_cube)
+        }
+        DdsFormat::A4R4G4B4 => {
+            // info!("[DDS LOADER] Converting A4R4G4B4 to R8G8B8A8");
+            convert_a4r4g4b4_to_rgba(bytes, &dds_info, is_cube)
+        }
+        DdsFormat::B5G6R5 => {
+           // info!("[DDS LOADER] Converting B5G6R5 to R8G8B8A8");
+            convert_b5g6r5_to_rgba(bytes, &dds_info, is_cube)
+        }
+        DdsFormat::Bc1Dxt1 => {
+           // info!("[DDS LOADER] Decompressing BC1/DXT1 to R8G8B8A8");
+            decompress_bc1_to_rgba(bytes, &dds_info, is_cube)
+        }
+        DdsFormat::Bc2Dxt3 => {
+           // info!("[DDS LOADER] Decompressing BC2/DXT3 to R8G8B8A8");
+            decompress_bc2_to_rgba(bytes, &dds_info, is_cube)
+        }
+        DdsFormat::Bc3Dxt5 => {
+           // info!("[DDS LOADER] Decompressing BC3/DXT5 to R8G8B8A8");
+            decompress_bc3_to_rgba(bytes, &dds_info, is_cube)
+        }
+        DdsFormat::A8 => {
+            // Alpha-only textures are common for particle masks.
+            // Convert to white RGB + alpha so shader tinting remains visible.
+            convert_a8_to_rgba(bytes, &dds_info, is_cube)
+        }
+        DdsFormat::L8 => {
+            // Luminance-only textures map luminance to RGB with full alpha.
+            convert_l8_to_rgba(bytes, &dds_info, is_cube)
+        }
+        DdsFormat::L8A8 => {
+            // Luminance+alpha textures are often used by legacy VFX masks.
+            convert_l8a8_to_rgba(bytes, &dds_info, is_cube)
+        }
+        DdsFormat::Bc4 => {
+            // Single channel, expanded to greyscale with full alpha
+            decompress_bc4_to_rgba(bytes, &dds_info, is_cube)
+        }
+        DdsFormat::Bc5 => {
+            // Two channels, usually a normal map, blue is left at zero
+            decompress_bc5_to_rgba(bytes, &dds_info, is_cube)
+        }
+        _ => {
+            // Try image crate as fallback - it will also convert to RGBA8
+            warn!("[DDS LOADER] Format {:?}, trying image crate", dds_info.format);
+            try_image_crate(bytes, asset_path, is_cube)
+        }
+    }?;
+
+    Ok((image, dds_info.mip_count))
+}
+
 #[derive(Debug, Clone, Copy)]
 enum DdsFormat {
     Unknown,
@@ -362,7 +498,10 @@ fn create_rgba_image(width: u32, height: u32, rgba_data: Vec<u8>, is_cube: bool)
             ..Default::default()
         });
     }
-    image.sampler = ImageSampler::linear();
+    image.sampler = ImageSampler::Descriptor(ImageSamplerDescriptor {
+        anisotropy_clamp: current_anisotropy(),
+        ..ImageSamplerDescriptor::linear()
+    });
     image
 }
 
@@ -877,6 +1016,227 @@ fn decompress_bc3_to_rgba(bytes: &[u8], info: &DdsInfo, is_cube: bool) -> anyhow
     Ok(create_rgba_image(info.width, info.height, rgba_data, is_cube))
 }
 
+/// Decodes an 8 byte BC4 style block, as used for BC3 alpha and BC4/BC5 channels
+fn decode_bc4_block(block: &[u8]) -> [u8; 16] {
+    let value0 = block[0];
+    let value1 = block[1];
+    let lookup = u64::from_le_bytes([
+        block[2], block[3], block[4], block[5], block[6], block[7], 0, 0,
+    ]);
+
+    let mut table = [0u8; 8];
+    table[0] = value0;
+    table[1] = value1;
+    if value0 > value1 {
+        for i in 2..8 {
+            table[i] = (((8 - i) as u16 * value0 as u16 + (i - 1) as u16 * value1 as u16) / 7) as u8;
+        }
+    } else {
+        for i in 2..6 {
+            table[i] = (((6 - i) as u16 * value0 as u16 + (i - 1) as u16 * value1 as u16) / 5) as u8;
+        }
+        table[6] = 0;
+        table[7] = 255;
+    }
+
+    let mut values = [0u8; 16];
+    for (index, value) in values.iter_mut().enumerate() {
+        *value = table[((lookup >> (index * 3)) & 7) as usize];
+    }
+    values
+}
+
+fn decompress_bc4_to_rgba(bytes: &[u8], info: &DdsInfo, is_cube: bool) -> anyhow::Result<Image> {
+    let data_start = info.data_offset;
+    let block_count_x = ((info.width + 3) / 4) as usize;
+    let block_count_y = ((info.height + 3) / 4) as usize;
+    let block_size = 8;
+
+    if data_start + block_count_x * block_count_y * block_size > bytes.len() {
+        anyhow::bail!("Not enough data for BC4 decompression");
+    }
+
+    let mut rgba_data = vec![0u8; (info.width * info.height * 4) as usize];
+
+    for by in 0..block_count_y {
+        for bx in 0..block_count_x {
+            let block_offset = data_start + (by * block_count_x + bx) * block_size;
+            let values = decode_bc4_block(&bytes[block_offset..block_offset + block_size]);
+
+            for py in 0..4 {
+                for px in 0..4 {
+                    let x = (bx * 4 + px) as u32;
+                    let y = (by * 4 + py) as u32;
+                    if x < info.width && y < info.height {
+                        let value = values[py * 4 + px];
+                        let pixel_offset = ((y * info.width + x) * 4) as usize;
+                        rgba_data[pixel_offset..pixel_offset + 4]
+                            .copy_from_slice(&[value, value, value, 255]);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(create_rgba_image(info.width, info.height, rgba_data, is_cube))
+}
+
+fn decompress_bc5_to_rgba(bytes: &[u8], info: &DdsInfo, is_cube: bool) -> anyhow::Result<Image> {
+    let data_start = info.data_offset;
+    let block_count_x = ((info.width + 3) / 4) as usize;
+    let block_count_y = ((info.height + 3) / 4) as usize;
+    let block_size = 16;
+
+    if data_start + block_count_x * block_count_y * block_size > bytes.len() {
+        anyhow::bail!("Not enough data for BC5 decompression");
+    }
+
+    let mut rgba_data = vec![0u8; (info.width * info.height * 4) as usize];
+
+    for by in 0..block_count_y {
+        for bx in 0..block_count_x {
+            let block_offset = data_start + (by * block_count_x + bx) * block_size;
+            let red = decode_bc4_block(&bytes[block_offset..block_offset + 8]);
+            let green = decode_bc4_block(&bytes[block_offset + 8..block_offset + 16]);
+
+            for py in 0..4 {
+                for px in 0..4 {
+                    let x = (bx * 4 + px) as u32;
+                    let y = (by * 4 + py) as u32;
+                    if x < info.width && y < info.height {
+                        let idx = py * 4 + px;
+                        let pixel_offset = ((y * info.width + x) * 4) as usize;
+                        rgba_data[pixel_offset..pixel_offset + 4]
+                            .copy_from_slice(&[red[idx], green[idx], 0, 255]);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(create_rgba_image(info.width, info.height, rgba_data, is_cube))
+}
+
+/// Halves an RGBA8 image with a box filter, returns the data and new size
+fn downsample_rgba(data: &[u8], width: u32, height: u32) -> (Vec<u8>, u32, u32) {
+    let next_width = (width / 2).max(1);
+    let next_height = (height / 2).max(1);
+    let mut output = Vec::with_capacity((next_width * next_height * 4) as usize);
+
+    for y in 0..next_height {
+        for x in 0..next_width {
+            let mut sum = [0u32; 4];
+            for (sx, sy) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+                let source_x = (x * 2 + sx).min(width - 1);
+                let source_y = (y * 2 + sy).min(height - 1);
+                let offset = ((source_y * width + source_x) * 4) as usize;
+                for (total, value) in sum.iter_mut().zip(&data[offset..offset + 4]) {
+                    *total += *value as u32;
+                }
+            }
+
+            for channel in sum {
+                output.push(((channel + 2) / 4) as u8);
+            }
+        }
+    }
+
+    (output, next_width, next_height)
+}
+
+/// Halves a decoded RGBA8 image until it fits within `max_resolution`, returns
+/// the number of bytes saved
+fn limit_resolution(image: &mut Image, max_resolution: u32) -> usize {
+    let max_resolution = max_resolution.max(1);
+    let size = image.texture_descriptor.size;
+    if image.texture_descriptor.format != TextureFormat::Rgba8UnormSrgb
+        || size.depth_or_array_layers != 1
+        || size.width.max(size.height) <= max_resolution
+    {
+        return 0;
+    }
+
+    let Some(mut data) = image.data.take() else {
+        return 0;
+    };
+    let original_len = data.len();
+
+    let mut width = size.width;
+    let mut height = size.height;
+    while width.max(height) > max_resolution {
+        let (next_data, next_width, next_height) = downsample_rgba(&data, width, height);
+        data = next_data;
+        width = next_width;
+        height = next_height;
+    }
+
+    let saved = original_len - data.len();
+    image.data = Some(data);
+    image.texture_descriptor.size = Extent3d {
+        width,
+        height,
+        depth_or_array_layers: 1,
+    };
+    saved
+}
+
+/// Appends a box filtered mip chain to a decoded RGBA8 image, returns false if
+/// the image is not in a format which can be downsampled here.
+fn generate_mipmaps(image: &mut Image) -> bool {
+    if image.texture_descriptor.format != TextureFormat::Rgba8UnormSrgb
+        || image.texture_descriptor.size.depth_or_array_layers != 1
+    {
+        return false;
+    }
+
+    let Some(base_data) = image.data.take() else {
+        return false;
+    };
+
+    let mut width = image.texture_descriptor.size.width;
+    let mut height = image.texture_descriptor.size.height;
+    let mip_level_count = 32 - width.max(height).leading_zeros();
+    if mip_level_count <= 1 {
+        image.data = Some(base_data);
+        return false;
+    }
+
+    let mut data = base_data;
+    data.reserve(data.len() / 3 + 4);
+    let mut level_start = 0;
+
+    for _ in 1..mip_level_count {
+        let (level, next_width, next_height) =
+            downsample_rgba(&data[level_start..], width, height);
+        level_start = data.len();
+        data.extend_from_slice(&level);
+        width = next_width;
+        height = next_height;
+    }
+
+    image.data = Some(data);
+    image.texture_descriptor.mip_level_count = mip_level_count;
+    true
+}
+
+/// Magenta and black checkerboard shown in place of textures which failed to decode
+fn create_placeholder_image(is_cube: bool) -> Image {
+    let mut rgba_data = Vec::with_capacity((PLACEHOLDER_SIZE * PLACEHOLDER_SIZE * 4) as usize);
+    for y in 0..PLACEHOLDER_SIZE {
+        for x in 0..PLACEHOLDER_SIZE {
+            if (x + y) % 2 == 0 {
+                rgba_data.extend_from_slice(&[255, 0, 255, 255]);
+            } else {
+                rgba_data.extend_from_slice(&[0, 0, 0, 255]);
+            }
+        }
+    }
+
+    let mut image = create_rgba_image(PLACEHOLDER_SIZE, PLACEHOLDER_SIZE, rgba_data, is_cube);
+    image.sampler = ImageSampler::nearest();
+    image
+}
+
 fn try_image_crate(bytes: &[u8], asset_path: &str, is_cube: bool) -> anyhow::Result<Image> {
     use image::ImageFormat;
     
@@ -892,3 +1252,37 @@ fn try_image_crate(bytes: &[u8], asset_path: &str, is_cube: bool) -> anyhow::Res
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_mipmaps() {
+        let mut image = create_rgba_image(4, 2, vec![255; 4 * 2 * 4], false);
+        assert!(generate_mipmaps(&mut image));
+        assert_eq!(image.texture_descriptor.mip_level_count, 3);
+        // 4x2 + 2x1 + 1x1 pixels
+        assert_eq!(image.data.as_ref().unwrap().len(), (8 + 2 + 1) * 4);
+        assert!(image.data.as_ref().unwrap().iter().all(|&value| value == 255));
+    }
+
+    #[test]
+    fn test_limit_resolution() {
+        let mut image = create_rgba_image(8, 2, vec![128; 8 * 2 * 4], false);
+        assert_eq!(limit_resolution(&mut image, 2), (16 - 2) * 4);
+        assert_eq!(image.texture_descriptor.size.width, 2);
+        assert_eq!(image.texture_descriptor.size.height, 1);
+        assert_eq!(image.data.as_ref().unwrap(), &vec![128; 2 * 4]);
+
+        let mut image = create_rgba_image(4, 4, vec![0; 4 * 4 * 4], false);
+        assert_eq!(limit_resolution(&mut image, 4), 0);
+    }
+
+    #[test]
+    fn test_generate_mipmaps_single_pixel() {
+        let mut image = create_rgba_image(1, 1, vec![0; 4], false);
+        assert!(!generate_mipmaps(&mut image));
+        assert_eq!(image.texture_descriptor.mip_level_count, 1);
+    }
+}
@@ -7,8 +7,12 @@ pub enum ChatboxEvent {
     Whisper(String, String),
     Party(String, String),
     Clan(String, String),
+    /// Clan status such as members coming online, shown with clan chat
+    ClanNotice(String),
     Allied(String, String),
     Announce(Option<String>, String),
     System(String),
     Quest(String),
+    /// Not shown, selects the whisper tab and starts a whisper to the name
+    StartWhisper(String),
 }
@@ -5,4 +5,5 @@ use rose_data::ZoneId;
 #[derive(Message)]
 pub enum GameConnectionEvent {
     Connected(ZoneId),
+    Disconnected(String),
 }
@@ -0,0 +1,11 @@
+use bevy::prelude::Message;
+
+#[derive(Message)]
+pub enum LoginConnectionEvent {
+    /// The login server accepted our credentials
+    LoggedIn,
+    /// The connection could not be made or was lost, retrying may succeed
+    ConnectionFailed { error: String },
+    /// The login server rejected the login, retrying will not help
+    LoginRejected,
+}
@@ -10,6 +10,7 @@ mod conversation_dialog_event;
 mod flight_event;
 mod game_connection_event;
 mod hit_event;
+mod login_connection_event;
 mod login_event;
 mod message_box_event;
 mod move_destination_effect_event;
@@ -42,6 +43,7 @@ pub use conversation_dialog_event::ConversationDialogEvent;
 pub use flight_event::FlightToggleEvent;
 pub use game_connection_event::GameConnectionEvent;
 pub use hit_event::HitEvent;
+pub use login_connection_event::LoginConnectionEvent;
 pub use login_event::LoginEvent;
 pub use message_box_event::MessageBoxEvent;
 pub use move_destination_effect_event::MoveDestinationEffectEvent;
@@ -49,7 +51,7 @@ pub use move_speed_event::MoveSpeedSetEvent;
 pub use network_event::NetworkEvent;
 pub use npc_store_event::NpcStoreEvent;
 pub use number_input_dialog_event::NumberInputDialogEvent;
-pub use party_event::PartyEvent;
+pub use party_event::{LootRollChoice, PartyEvent};
 pub use ping_event::{PingRequestEvent, PingResponseEvent, PingState};
 pub use personal_store_event::PersonalStoreEvent;
 pub use player_command_event::PlayerCommandEvent;
@@ -12,4 +12,6 @@ pub enum NpcStoreEvent {
         store_tab_slot: usize,
         quantity: usize,
     },
+    /// The player confirmed an expensive transaction
+    ConfirmTransaction,
 }
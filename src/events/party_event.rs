@@ -1,7 +1,28 @@
 use bevy::prelude::{Entity, Message};
 
+use rose_data::Item;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LootRollChoice {
+    Need,
+    Greed,
+    Pass,
+}
+
+impl LootRollChoice {
+    pub fn name(&self) -> &'static str {
+        match self {
+            LootRollChoice::Need => "Need",
+            LootRollChoice::Greed => "Greed",
+            LootRollChoice::Pass => "Pass",
+        }
+    }
+}
+
 #[derive(Message)]
 pub enum PartyEvent {
     InvitedCreate(Entity),
     InvitedJoin(Entity),
+    /// A party member looted an item the party can roll for
+    LootRollStart { looter: String, item: Item },
 }
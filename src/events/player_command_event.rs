@@ -1,6 +1,6 @@
 use bevy::prelude::{Entity, Message};
 
-use rose_data::{AmmoIndex, EquipmentIndex, VehiclePartIndex};
+use rose_data::{AmmoIndex, EquipmentIndex, MotionId, VehiclePartIndex};
 use rose_game_common::components::{HotbarSlot, ItemSlot, SkillSlot};
 
 use crate::components::Position;
@@ -25,7 +25,12 @@ pub enum PlayerCommandEvent {
     DropMoney(usize),
     BankDepositItem(ItemSlot),
     BankWithdrawItem(usize),
+    BankWithdrawItemWithQuantity(usize, usize),
     EnterRepairMode(ItemSlot), // Enter repair mode with the repair tool slot
     ExitRepairMode,            // Exit repair mode
     RepairItem(ItemSlot),      // Repair an equipment item
+    Emote(MotionId),
+    SitToggle,
+    /// `/afk [message]`, toggles AFK or changes the message while AFK
+    Afk(Option<String>),
 }
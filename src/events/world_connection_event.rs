@@ -20,4 +20,7 @@ pub enum WorldConnectionEvent {
     DeleteCharacterError {
         name: String,
     },
+    Disconnected {
+        error: String,
+    },
 }
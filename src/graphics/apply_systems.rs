@@ -4,9 +4,19 @@
 //! actual render configuration (cameras, lights, etc.).
 
 use crate::graphics::*;
-use bevy::{core_pipeline::tonemapping::Tonemapping, prelude::*, render::view::ColorGrading};
+use bevy::{
+    core_pipeline::tonemapping::Tonemapping,
+    image::{ImageFilterMode, ImageSampler},
+    prelude::*,
+    render::{render_resource::DownlevelFlags, renderer::RenderAdapter, view::ColorGrading},
+};
 use bevy_light::{CascadeShadowConfig, DirectionalLight, DirectionalLightShadowMap, ShadowFilteringMethod};
-use bevy_post_process::bloom::Bloom;
+use bevy_post_process::{bloom::Bloom, motion_blur::MotionBlur};
+
+use crate::{
+    render::TerrainMaterial,
+    resources::{set_current_anisotropy, AccessibilitySettings, TextureFilteringSettings},
+};
 
 /// System that applies color grading settings (brightness, contrast, saturation, gamma)
 /// to all cameras with ColorGrading components.
@@ -134,6 +144,44 @@ pub fn apply_bloom_system(
     }
 }
 
+/// System that applies motion blur settings to cameras.
+///
+/// The blur follows the motion vector prepass so it works the same with MSAA,
+/// SMAA and FXAA. Name tags and the rest of the UI are drawn by egui after post
+/// processing and are never blurred.
+pub fn apply_motion_blur_system(
+    mut commands: Commands,
+    graphics_settings: Res<GraphicsSettings>,
+    accessibility_settings: Res<AccessibilitySettings>,
+    cameras: Query<(Entity, Option<&MotionBlur>), With<Camera3d>>,
+) {
+    let shutter = if accessibility_settings.disable_motion_blur {
+        None
+    } else {
+        graphics_settings.motion_blur_shutter()
+    };
+
+    // Checked every frame rather than on change so new cameras pick it up
+    for (entity, motion_blur) in cameras.iter() {
+        match (shutter, motion_blur) {
+            (Some((shutter_angle, samples)), motion_blur) => {
+                if motion_blur.map_or(true, |motion_blur| {
+                    motion_blur.shutter_angle != shutter_angle || motion_blur.samples != samples
+                }) {
+                    commands.entity(entity).insert(MotionBlur {
+                        shutter_angle,
+                        samples,
+                    });
+                }
+            }
+            (None, Some(_)) => {
+                commands.entity(entity).remove::<MotionBlur>();
+            }
+            (None, None) => {}
+        }
+    }
+}
+
 /// System that applies shadow filtering method to lights.
 pub fn apply_shadow_filtering_system(
     graphics_settings: Res<GraphicsSettings>,
@@ -193,3 +241,73 @@ pub fn apply_ambient_light_system(
     // Base brightness is 80.0 (Bevy's default), multiplier ranges from 0.0 to 2.0
     ambient_light.brightness =80.0 * graphics_settings.ambient_light_brightness;
 }
+
+/// Anisotropy levels above this are clamped by the driver, so this is the
+/// most that can be requested whenever the adapter supports it at all
+const MAX_SAMPLER_ANISOTROPY: u16 = 16;
+
+/// The highest anisotropy the render adapter can use
+pub fn max_supported_anisotropy(render_adapter: Option<&RenderAdapter>) -> u16 {
+    match render_adapter {
+        Some(render_adapter)
+            if !render_adapter
+                .get_downlevel_capabilities()
+                .flags
+                .contains(DownlevelFlags::ANISOTROPIC_FILTERING) =>
+        {
+            1
+        }
+        _ => MAX_SAMPLER_ANISOTROPY,
+    }
+}
+
+/// System that applies the anisotropic filtering level to loaded textures and
+/// terrain materials. Textures loaded afterwards pick it up from the loader.
+pub fn apply_texture_filtering_system(
+    texture_filtering_settings: Res<TextureFilteringSettings>,
+    render_adapter: Option<Res<RenderAdapter>>,
+    mut images: ResMut<Assets<Image>>,
+    mut terrain_materials: ResMut<Assets<TerrainMaterial>>,
+) {
+    // Skip if settings haven't changed
+    if !texture_filtering_settings.is_changed() {
+        return;
+    }
+
+    let anisotropy = texture_filtering_settings
+        .effective_anisotropy(max_supported_anisotropy(render_adapter.as_deref()));
+    set_current_anisotropy(anisotropy);
+
+    // Anisotropy requires every filter to be linear, other samplers are left alone
+    let image_ids: Vec<_> = images
+        .iter()
+        .filter(|(_, image)| match &image.sampler {
+            ImageSampler::Descriptor(descriptor) => {
+                descriptor.mag_filter == ImageFilterMode::Linear
+                    && descriptor.min_filter == ImageFilterMode::Linear
+                    && descriptor.mipmap_filter == ImageFilterMode::Linear
+                    && descriptor.anisotropy_clamp != anisotropy
+            }
+            ImageSampler::Default => false,
+        })
+        .map(|(id, _)| id)
+        .collect();
+    for id in image_ids {
+        if let Some(image) = images.get_mut(id) {
+            if let ImageSampler::Descriptor(descriptor) = &mut image.sampler {
+                descriptor.anisotropy_clamp = anisotropy;
+            }
+        }
+    }
+
+    let material_ids: Vec<_> = terrain_materials
+        .iter()
+        .filter(|(_, material)| material.anisotropy != anisotropy)
+        .map(|(id, _)| id)
+        .collect();
+    for id in material_ids {
+        if let Some(material) = terrain_materials.get_mut(id) {
+            material.anisotropy = anisotropy;
+        }
+    }
+}
@@ -334,6 +334,13 @@ impl SmaaQuality {
     }
 }
 
+/// Longest motion blur shutter angle, a half frame exposure like film, longer
+/// blur smears enough to cause motion sickness
+pub const MAX_MOTION_BLUR_SHUTTER_ANGLE: f32 = 0.5;
+
+/// Most motion blur samples per pixel
+pub const MAX_MOTION_BLUR_SAMPLES: u32 = 8;
+
 /// Resource for storing graphics settings that can be modified at runtime.
 /// These settings control visual quality and performance tradeoffs.
 #[derive(Resource, Debug, Clone, Reflect)]
@@ -386,9 +393,13 @@ pub struct GraphicsSettings {
     /// Motion blur enabled
     pub motion_blur_enabled: bool,
 
-    /// Motion blur intensity (0.0 - 1.0)
+    /// Motion blur intensity (0.0 - 1.0), scaled to a shutter angle of at
+    /// most `MAX_MOTION_BLUR_SHUTTER_ANGLE`
     pub motion_blur_intensity: f32,
 
+    /// Motion blur samples per pixel (1 - 8), more is smoother but slower
+    pub motion_blur_samples: u32,
+
     /// SSAO enabled
     pub ssao_enabled: bool,
 
@@ -452,6 +463,7 @@ impl Default for GraphicsSettings {
             bloom_intensity: 0.15,
             motion_blur_enabled: false,
             motion_blur_intensity: 0.5,
+            motion_blur_samples: 1,
             ssao_enabled: true,
             ssao_quality: SsaoQuality::default(),
             dof_enabled: false,
@@ -476,6 +488,18 @@ impl Default for GraphicsSettings {
 }
 
 impl GraphicsSettings {
+    /// Motion blur shutter angle and sample count, `None` when motion blur is off
+    pub fn motion_blur_shutter(&self) -> Option<(f32, u32)> {
+        if !self.motion_blur_enabled || self.motion_blur_intensity <= 0.0 {
+            return None;
+        }
+
+        Some((
+            self.motion_blur_intensity.clamp(0.0, 1.0) * MAX_MOTION_BLUR_SHUTTER_ANGLE,
+            self.motion_blur_samples.clamp(1, MAX_MOTION_BLUR_SAMPLES),
+        ))
+    }
+
     /// Low-end preset for older hardware
     pub fn low_preset() -> Self {
         Self {
@@ -493,6 +517,7 @@ impl GraphicsSettings {
             bloom_intensity: 0.0,
             motion_blur_enabled: false,
             motion_blur_intensity: 0.0,
+            motion_blur_samples: 1,
             ssao_enabled: false,
             ssao_quality: SsaoQuality::Off,
             dof_enabled: false,
@@ -525,6 +550,7 @@ impl GraphicsSettings {
             bloom_intensity: 0.1,
             motion_blur_enabled: false,
             motion_blur_intensity: 0.5,
+            motion_blur_samples: 1,
             ssao_enabled: true,
             ssao_quality: SsaoQuality::Low,
             dof_enabled: false,
@@ -557,6 +583,7 @@ impl GraphicsSettings {
             bloom_intensity: 0.15,
             motion_blur_enabled: false,
             motion_blur_intensity: 0.5,
+            motion_blur_samples: 2,
             ssao_enabled: true,
             ssao_quality: SsaoQuality::Medium,
             dof_enabled: false,
@@ -589,6 +616,7 @@ impl GraphicsSettings {
             bloom_intensity: 0.2,
             motion_blur_enabled: true,
             motion_blur_intensity: 0.3,
+            motion_blur_samples: 4,
             ssao_enabled: true,
             ssao_quality: SsaoQuality::High,
             dof_enabled: true,
@@ -603,3 +631,25 @@ impl GraphicsSettings {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_motion_blur_shutter_is_clamped() {
+        let mut settings = GraphicsSettings {
+            motion_blur_enabled: true,
+            motion_blur_intensity: 4.0,
+            motion_blur_samples: 64,
+            ..Default::default()
+        };
+        assert_eq!(
+            settings.motion_blur_shutter(),
+            Some((MAX_MOTION_BLUR_SHUTTER_ANGLE, MAX_MOTION_BLUR_SAMPLES))
+        );
+
+        settings.motion_blur_enabled = false;
+        assert_eq!(settings.motion_blur_shutter(), None);
+    }
+}
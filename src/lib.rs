@@ -9,8 +9,7 @@ use bevy::{
         post_process::{
             bloom::Bloom,
             dof::{DepthOfField, DepthOfFieldMode},
-            motion_blur::MotionBlur,
-            auto_exposure::AutoExposure,
+            auto_exposure::{AutoExposure, AutoExposurePlugin},
         },
         anti_alias::contrast_adaptive_sharpening::ContrastAdaptiveSharpening,
         anti_alias::smaa::Smaa,
@@ -23,10 +22,10 @@ use bevy::{
         render::view::{ColorGrading, ColorGradingGlobal, ColorGradingSection},
         render::experimental::occlusion_culling::OcclusionCulling,
         prelude::{
-            default, in_state, not, resource_exists, App, AppExtStates, AssetServer, Assets, Camera, Camera3d,
+            default, in_state, not, resource_exists, App, AppExit, AppExtStates, AssetServer, Assets, Camera, Camera2d, Camera3d,
             ClearColorConfig, Color, Commands, Cuboid, Entity, Handle, Image, IntoScheduleConfigs,
-            Local, Msaa, OnEnter, OnExit, PerspectiveProjection,
-            PluginGroup, PostStartup, PostUpdate, PreUpdate, Projection, Quat, Query, Res, ResMut, Startup, State,
+            Local, MessageWriter, Msaa, OnEnter, OnExit, PerspectiveProjection,
+            PluginGroup, PostStartup, PostUpdate, PreUpdate, Projection, Quat, Query, Res, ResMut, Resource, Startup, State,
             SystemSet, Time, Transform, Update, Vec3, With, Without, World,
         },
         camera::{Camera as CameraComponent, Exposure},
@@ -35,6 +34,7 @@ use bevy::{
     render::{
         settings::{Backends, RenderCreation, WgpuFeatures, WgpuSettings},
         render_asset::RenderAssets,
+        renderer::{RenderAdapter, RenderDevice},
         ExtractSchedule, Render, RenderApp,
     },
         transform::{TransformSystems, components::GlobalTransform},
@@ -49,15 +49,15 @@ use bevy_egui::{egui, input::egui_wants_any_pointer_input, EguiContext, EguiCont
 use bevy_rapier3d::plugin::PhysicsSet;
 use enum_map::enum_map;
 use exe_resource_loader::{ExeResourceCursor, ExeResourceLoader};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::{
     path::{Path, PathBuf},
     sync::{Arc, mpsc},
 };
 
-use rose_data::{CharacterMotionDatabaseOptions, NpcDatabaseOptions, ZoneId};
+use rose_data::{CharacterMotionDatabaseOptions, NpcDatabaseOptions, ZoneId, ZoneList};
 use rose_file_readers::{
-    AruaVfsIndex, HostFilesystemDevice, IrosePhVfsIndex, LtbFile, StbFile, TitanVfsIndex, VfsIndex,
+    AruaVfsIndex, IrosePhVfsIndex, LtbFile, StbFile, TitanVfsIndex, VfsIndex,
     VirtualFilesystem, VirtualFilesystemDevice, ZscFile,
 };
 
@@ -83,8 +83,12 @@ pub mod scripting;
 pub mod systems;
 pub mod terrain;
 pub mod ui;
+mod data_path_wizard;
 pub mod dds_image_loader;
 pub mod vfs_asset_io;
+pub mod vfs_overlay_device;
+pub mod vfs_path_resolver;
+pub mod vfs_zip_device;
 pub mod zms_asset_loader;
 pub mod zone_loader;
 pub mod blood_effect_plugin;
@@ -93,13 +97,14 @@ use audio::OddioPlugin;
 use diagnostics::RenderDiagnosticsPlugin;
 use events::{
      BankEvent, BoardBoatEvent, CharacterSelectEvent, ChatBubbleEvent, ChatboxEvent, ClanDialogEvent, ClientEntityEvent,
-     ConversationDialogEvent, FlightToggleEvent, GameConnectionEvent, HitEvent, LoadZoneEvent, LoginEvent,
+     ConversationDialogEvent, FlightToggleEvent, GameConnectionEvent, HitEvent, LoadZoneEvent, LoginConnectionEvent, LoginEvent,
      MessageBoxEvent, MoveDestinationEffectEvent, MoveSpeedSetEvent, NetworkEvent, NpcStoreEvent,
      NumberInputDialogEvent, PartyEvent, PingRequestEvent, PingResponseEvent, PingState, PersonalStoreEvent,
      PlayerCommandEvent, QuestScrollEvent, QuestTriggerEvent, SpawnEffectEvent, SpawnProjectileEvent, SystemFuncEvent,
      DisembarkBoatEvent, UseItemEvent, WorldConnectionEvent, ZoneEvent, ZoneLoadedFromVfsEvent,
      };
 use model_loader::ModelLoader;
+use protocol::{irose::IroseNetworkBackend, NetworkBackend};
 use render::{
     RoseRenderPlugin,
     DamageDigitMaterialPlugin,
@@ -139,67 +144,77 @@ use render::{
 };
 use resources::{
     load_ui_resources, run_network_thread, ui_requested_cursor_apply_system, update_ui_resources,
-    AppState, ClientEntityList, CurrentZone, DamageDigitsSpawner, DebugRenderConfig, FlightSettings, GameData, LoginCameraAnimation, MonsterChatterPhrases, NameTagSettings,
-    NetworkThread, NetworkThreadMessage, RenderConfiguration, RenderExtractionDiagnostics, SelectedTarget, ServerConfiguration,
-    SoundCache, SoundSettings, SpecularTexture, VfsResource, WaterSettings, WindSettings, WindState, WorldTime, ZoneTime,
+    AccessibilitySettings, AfkSettings, AppState, AutoPotionNotice, AutoPotionSettings, BankSettings, BloodSettings, AssetHotReload, ClientEntityList, CameraMotionSettings, CameraShake, CharacterIdleSettings, CharacterNameRules, CharacterSelectScene, ChatBubbleSettings, ChatterSettings, ConfigFile, ConversationHistory, ConversationSettings, CurrentZone, DamageDigitsSpawner, DecalSettings, DebugRenderConfig, ExposureSettings, FlightSettings, FogSettings, FriendsList, GameData, LogSettings, HealthFeedbackSettings, HitFeedbackSettings, IdleCameraSettings, InputActivity, LoginCameraAnimation, LootFilterSettings, MonsterChatterPhrases, MousePickDebug, MousePickSettings, NameTagSettings,
+    NetworkLogging, NetworkMovementSettings, NetworkStats, NetworkThread, NetworkThreadMessage, NpcStoreSettings, OcclusionCullingSettings, OcclusionCullingState, ParticleBudget, ParticleBudgetStats, PartyMarkerSettings, ProjectileSettings, QuickUseSettings, ReconnectSession, RenderConfiguration, RngSeed, RenderExtractionDiagnostics, SelectedTarget, ServerConfiguration, WorldPings, EmoteList,
+    SoundCache, SoundSettings, SpecularTexture, TextureFilteringSettings, TimeScaleSettings, TrailSettings, UiLayoutSettings, UiScale, UiSoundSettings, VfsResource, WaterSettings, WeatherOverride, WindSettings, WindState, WorldTime, ZoneTime,
 };
+use logging::set_crash_report_zone;
 use scripting::RoseScriptingPlugin;
 use systems::{
-    ability_values_system, animation_effect_system, animation_sound_system, auto_login_system,
-    background_music_system, character_model_add_collider_system, character_model_blink_system,
+    ability_values_system, animation_effect_system, animation_sound_system, asset_hot_reload_material_system,
+    asset_hot_reload_system, auto_login_system, auto_loot_system, auto_potion_system,
+    background_music_system, blood_settings_system, camera_motion_system, camera_shake_system, character_model_add_collider_system, character_model_blink_system,
     character_model_update_system, character_select_enter_system, character_select_event_system,
     character_select_exit_system, character_select_input_system,
     character_select_models_system, character_select_system, CharacterSelectInputState,
     chat_bubble_spawn_system, chat_bubble_update_system, chat_bubble_cleanup_system, chat_bubble_orphan_cleanup_system,
     add_monster_chatter_system, monster_chatter_system,
-    clan_system, client_entity_event_system, collision_height_only_system,
+    accessibility_settings_system, afk_auto_reply_system, afk_system, clan_system, client_entity_event_system, friends_list_system, collision_height_only_system,
     collision_player_system, collision_player_system_join_zone, command_system,
     conversation_dialog_system, cooldown_system, damage_digit_render_system,
-    create_damage_digit_material_system,
-    directional_light_system, effect_system, facing_direction_system,
+    create_damage_digit_material_system, death_spectator_camera_system, debug_render_mode_system,
+    directional_light_system, effect_system, exposure_settings_system, facing_direction_system,
     flight_movement_system, flight_pose_system, flight_pose_blend_update_system, flight_toggle_system, ensure_flight_state_system,
-    free_camera_system, game_connection_system, game_keyboard_input_system, game_mouse_input_system, game_state_enter_system,
-    game_zone_change_system, hit_event_system, item_drop_model_add_collider_system,
-    item_drop_model_system, login_connection_system, login_event_system, login_state_enter_system,
-    login_state_exit_system, login_system, model_viewer_enter_system, model_viewer_exit_system,
-    model_viewer_system, move_destination_effect_system, move_speed_set_system, name_tag_system,
-    name_tag_update_color_system, name_tag_update_healthbar_system, name_tag_visibility_system,
-    network_thread_system, npc_idle_sound_system, npc_model_add_collider_system,
-    npc_model_update_system, orbit_camera_system, particle_sequence_system,
+    free_camera_system, game_connection_system, game_keyboard_input_system, game_mouse_input_system, hover_highlight_system, mouse_pick_debug_system, game_state_enter_system,
+    game_zone_change_system, hit_event_system, hit_flash_system, transient_decal_system, input_activity_system, item_drop_model_add_collider_system,
+    item_drop_model_system, loot_beam_system, login_connection_system, login_event_system, login_state_enter_system,
+    login_state_exit_system, login_system, model_viewer_enter_system, model_viewer_equipment_system,
+    model_viewer_exit_system, model_viewer_normals_system, model_viewer_system, model_viewer_turntable_system,
+    move_destination_effect_system, move_speed_set_system, name_tag_system,
+    name_tag_fade_system, name_tag_update_color_system, name_tag_update_healthbar_system, name_tag_update_threat_system,
+    name_tag_visibility_system, network_logging_system, network_movement_playback_system,
+    network_movement_reconcile_system, network_stats_system, network_thread_system, npc_idle_sound_system, npc_model_add_collider_system,
+    npc_model_update_system, gpu_occlusion_culling_unsupported_reason, occlusion_culling_fallback_system,
+    orbit_camera_system, particle_category_system, particle_sequence_system,
     particle_storage_buffer_update_system, create_default_particle_texture,
-    passive_recovery_system, pending_damage_system, pending_skill_effect_system,
+    party_marker_system, passive_recovery_system, resting_system, pending_damage_system, pending_skill_effect_system,
     personal_store_model_add_collider_system, personal_store_model_system, player_command_system,
-    projectile_system, quest_trigger_system, spawn_effect_system, spawn_projectile_system,
-    sail_camera_system, sailing_movement_system, status_effect_system, system_func_event_system,
-    monster_separation_system, update_position_system, use_item_event_system,
+    projectile_system, quest_trigger_system, reconnect_system, spawn_effect_system, spawn_projectile_system,
+    sail_camera_system, sailing_movement_system, save_settings_system, write_config_file_system, status_effect_system, system_func_event_system,
+    threat_state_system, monster_separation_system, ui_scale_system, update_position_system, use_item_event_system,
     vehicle_model_system, vehicle_sound_system, visible_status_effects_system,
-    world_connection_system, world_time_system, zone_time_system, zone_viewer_enter_system,
+    world_connection_system, world_ping_system, world_time_system, time_scale_system, weather_override_system, fog_settings_system, log_settings_system, zone_time_system, zone_viewer_enter_system,
     wind_update_system, sync_vegetation_wind_system, ensure_boat_state_system, boat_toggle_system,
     boat_buoyancy_system,
     // DISABLED: color_grading_time_of_day_system conflicts with Bevy 0.16 Atmosphere
     // color_grading_time_of_day_system,
-    DebugInspectorPlugin, FishPlugin, BirdPlugin, DirtDashPlugin, WingSpawnPlugin, WindEffectPlugin,
+    BenchmarkPlugin, DebugInspectorPlugin, FishPlugin, BirdPlugin, DirtDashPlugin, WingSpawnPlugin, WindEffectPlugin,
 };
 use ui::{
     admin_menu_keyboard_system, load_dialog_sprites_system, ui_admin_menu_system, ui_bank_system,
     ui_character_create_system, ui_character_info_system, ui_character_select_name_tag_system,
     ui_character_select_system, ui_chatbox_system, ui_clan_system, ui_create_clan_system,
-    ui_debug_camera_info_system, ui_debug_client_entity_list_system, ui_debug_command_viewer_system,
+    ui_friends_system, ui_debug_camera_info_system, ui_debug_client_entity_list_system, ui_debug_command_viewer_system,
     ui_debug_diagnostics_system, ui_debug_dialog_list_system, ui_debug_effect_list_system,
     ui_debug_entity_inspector_system, ui_debug_item_list_system, ui_debug_menu_system,
+    ui_debug_log_system, ui_debug_network_system, ui_debug_packet_log_system,
     ui_debug_npc_list_system, ui_debug_physics_system, ui_debug_render_system,
-    ui_debug_skill_list_system, ui_debug_zone_lighting_system, ui_debug_zone_list_system,
+    ui_debug_animation_events_system, ui_debug_skill_list_system, ui_debug_weather_system, ui_debug_zone_lighting_system, ui_debug_zone_list_system,
     ui_debug_zone_time_system, ui_drag_and_drop_system, ui_game_menu_system, ui_hotbar_system,
-    ui_inventory_system, ui_item_drop_name_system, ui_login_system, ui_message_box_system,
-    ui_minimap_system, ui_npc_store_system, ui_number_input_dialog_system, ui_party_option_system,
-    ui_party_system, ui_personal_store_system, ui_player_info_system, ui_quest_list_system,
-    ui_respawn_system, ui_sailing_hud_system, ui_selected_target_system, ui_server_select_system, ui_settings_system,
+    ui_inventory_system, ui_item_drop_name_system, ui_layout_system, ui_log_console_system, ui_login_system, ui_message_box_system,
+    ui_minimap_system, ui_npc_store_system, ui_number_input_dialog_system, ui_party_marker_system, ui_auto_potion_system, ui_health_vignette_system, ui_ping_wheel_system, ui_emote_wheel_system,
+    ui_party_option_system, ui_party_system, ui_personal_store_system, ui_player_info_system, ui_quest_list_system, ui_quick_use_system,
+    ui_reconnect_system, ui_respawn_system, ui_sailing_hud_system, ui_selected_target_system, ui_server_select_system, ui_settings_system,
     ui_skill_list_system, ui_skill_tree_system, ui_sound_event_system, ui_status_effects_system,
-    ui_window_sound_system, widgets::Dialog, DepthOfFieldSettings, DialogLoader, UiSoundEvent,
+    ui_window_sound_system, widgets::Dialog, DepthOfFieldSettings, DialogLoader, UiLayoutEvent, UiSoundEvent,
     UiStateAdminMenu, UiStateDebugWindows, UiStateDragAndDrop, UiStateWindows,
 };
+use data_path_wizard::run_data_path_wizard;
 use dds_image_loader::DdsImageLoader;
 use vfs_asset_io::{VfsAssetIo, VfsAssetReaderPlugin};
+use vfs_overlay_device::OverlayFilesystemDevice;
+use vfs_path_resolver::CaseInsensitiveHostDevice;
+use vfs_zip_device::ZipFilesystemDevice;
 use zms_asset_loader::{ZmsAssetLoader, ZmsMaterialNumFaces, ZmsNoSkinAssetLoader};
 use zone_loader::{zone_loader_system, zone_loaded_from_vfs_system, force_zone_visibility_system, ZoneLoader, ZoneLoaderAsset, ZoneLoadChannelReceiver, ZoneLoadChannelSender, MemoryTrackingResource};
 
@@ -212,16 +227,143 @@ pub struct AccountConfig {
     pub password: String,
 }
 
-#[derive(Default, Deserialize)]
+#[derive(Deserialize)]
 #[serde(default)]
 pub struct AutoLoginConfig {
     pub enabled: bool,
     pub channel_id: Option<usize>,
     pub server_id: Option<usize>,
     pub character_name: Option<String>,
+    /// Number of times to retry after a failed connection, 0 disables retrying
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled after each failed attempt
+    pub retry_base_delay_secs: f32,
+    pub retry_max_delay_secs: f32,
+}
+
+impl Default for AutoLoginConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            channel_id: None,
+            server_id: None,
+            character_name: None,
+            max_retries: 5,
+            retry_base_delay_secs: 2.0,
+            retry_max_delay_secs: 30.0,
+        }
+    }
 }
 
 #[derive(Deserialize)]
+#[serde(default)]
+pub struct ReconnectConfig {
+    /// Reconnect automatically when the connection drops while in game
+    pub enabled: bool,
+    pub max_attempts: u32,
+    /// How long to wait for each step of a reconnect attempt before giving up on it
+    pub timeout_secs: f32,
+}
+
+impl Default for ReconnectConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_attempts: 3,
+            timeout_secs: 20.0,
+        }
+    }
+}
+
+/// Mode the client starts in
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StartupMode {
+    #[default]
+    Game,
+    ZoneViewer,
+    ModelViewer,
+    MapEditor,
+    Benchmark,
+}
+
+impl StartupMode {
+    pub fn name(&self) -> &'static str {
+        match self {
+            StartupMode::Game => "Game",
+            StartupMode::ZoneViewer => "ZoneViewer",
+            StartupMode::ModelViewer => "ModelViewer",
+            StartupMode::MapEditor => "MapEditor",
+            StartupMode::Benchmark => "Benchmark",
+        }
+    }
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+pub struct StartupConfig {
+    pub mode: StartupMode,
+    /// Zone loaded by the zone viewer and map editor, defaults to zone 1
+    pub zone_id: Option<u16>,
+}
+
+impl StartupConfig {
+    pub fn zone_id(&self) -> Option<ZoneId> {
+        let zone_id = self.zone_id?;
+        let valid_zone_id = ZoneId::new(zone_id);
+        if valid_zone_id.is_none() {
+            log::warn!("[Startup] Invalid startup zone id {}, using the default zone", zone_id);
+        }
+        valid_zone_id
+    }
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+pub struct RandomConfig {
+    /// Seed for procedural and cosmetic randomness, a time based seed is used
+    /// when unset except in benchmark mode
+    pub seed: Option<u64>,
+}
+
+impl RandomConfig {
+    pub fn rng_seed(&self) -> RngSeed {
+        self.seed.map_or_else(RngSeed::from_time, RngSeed::new)
+    }
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(default)]
+pub struct BenchmarkConfig {
+    /// Camera path through the startup zone, in world coordinates
+    pub waypoints: Vec<[f32; 3]>,
+    /// Camera speed along the path in metres per second
+    pub speed: f32,
+    /// Time to wait after the zone is loaded before recording frame times
+    pub warmup_secs: f32,
+    /// Path to write the summary to as JSON
+    pub output_path: Option<String>,
+}
+
+impl Default for BenchmarkConfig {
+    fn default() -> Self {
+        // A loop around the centre of the zone
+        Self {
+            waypoints: vec![
+                [5120.0, 50.0, -5120.0],
+                [5280.0, 50.0, -5120.0],
+                [5280.0, 50.0, -5280.0],
+                [5120.0, 50.0, -5280.0],
+                [5120.0, 50.0, -5120.0],
+            ],
+            speed: 10.0,
+            warmup_secs: 5.0,
+            output_path: None,
+        }
+    }
+}
+
+#[derive(Clone, Deserialize, Serialize)]
 #[serde(tag = "type", content = "path")]
 pub enum FilesystemDeviceConfig {
     #[serde(rename = "vfs")]
@@ -234,27 +376,95 @@ pub enum FilesystemDeviceConfig {
     TitanVfs(String),
     #[serde(rename = "iroseph")]
     IrosePh(String),
+    #[serde(rename = "zip")]
+    Zip(String),
+    /// Mod directory which overrides files from all other devices
+    #[serde(rename = "overlay")]
+    Overlay(String),
 }
 
-#[derive(Default, Deserialize)]
+impl FilesystemDeviceConfig {
+    pub fn path(&self) -> &str {
+        match self {
+            FilesystemDeviceConfig::Vfs(path)
+            | FilesystemDeviceConfig::Directory(path)
+            | FilesystemDeviceConfig::AruaVfs(path)
+            | FilesystemDeviceConfig::TitanVfs(path)
+            | FilesystemDeviceConfig::IrosePh(path)
+            | FilesystemDeviceConfig::Zip(path)
+            | FilesystemDeviceConfig::Overlay(path) => path,
+        }
+    }
+
+    /// Device type as written in the config
+    pub fn device_type(&self) -> &'static str {
+        match self {
+            FilesystemDeviceConfig::Vfs(_) => "vfs",
+            FilesystemDeviceConfig::Directory(_) => "directory",
+            FilesystemDeviceConfig::AruaVfs(_) => "aruavfs",
+            FilesystemDeviceConfig::TitanVfs(_) => "titanvfs",
+            FilesystemDeviceConfig::IrosePh(_) => "iroseph",
+            FilesystemDeviceConfig::Zip(_) => "zip",
+            FilesystemDeviceConfig::Overlay(_) => "overlay",
+        }
+    }
+
+    /// Device type and path as written in the config, e.g. `vfs data.idx`
+    pub fn describe(&self) -> String {
+        format!("{} {}", self.device_type(), self.path())
+    }
+}
+
+/// A configured filesystem device which failed to load
+pub struct FilesystemDeviceError {
+    pub device: String,
+    pub error: String,
+}
+
+fn filesystem_device_error(
+    errors: &mut Vec<FilesystemDeviceError>,
+    device_config: &FilesystemDeviceConfig,
+    error: impl std::fmt::Display,
+) {
+    let device = device_config.describe();
+    let error = if Path::new(device_config.path()).exists() {
+        format!("{:#}", error)
+    } else {
+        format!("{} does not exist ({:#})", device_config.path(), error)
+    };
+    log::error!("[VFS INIT] Failed to load {}: {}", device, error);
+    errors.push(FilesystemDeviceError { device, error });
+}
+
+#[derive(Default, Deserialize, Serialize)]
 #[serde(default)]
 pub struct FilesystemConfig {
     pub devices: Vec<FilesystemDeviceConfig>,
+    /// Watch the game data directory and reload assets when they change on disk
+    pub hot_reload: bool,
 }
 
 impl FilesystemConfig {
     /// Creates a virtual filesystem from the configured devices.
     /// Returns a tuple of (VFS, base_path) where base_path is the real filesystem
     /// path where game data is stored. This is used for saving files back to disk.
-    pub fn create_virtual_filesystem(&self) -> Option<(Arc<VirtualFilesystem>, PathBuf)> {
+    ///
+    /// A device which fails to load is added to `errors` and skipped, the
+    /// filesystem is made from the remaining devices.
+    pub fn create_virtual_filesystem(
+        &self,
+        errors: &mut Vec<FilesystemDeviceError>,
+    ) -> Option<(Arc<VirtualFilesystem>, PathBuf)> {
         let mut vfs_devices: Vec<Box<dyn VirtualFilesystemDevice + Send + Sync>> = Vec::new();
+        let mut overlay_devices: Vec<Box<dyn VirtualFilesystemDevice + Send + Sync>> = Vec::new();
         let mut base_path: Option<PathBuf> = None;
+        let mut loaded_devices = Vec::new();
         
         for device_config in self.devices.iter() {
             match device_config {
                 FilesystemDeviceConfig::Directory(path) => {
                     log::info!("Loading game data from host directory {}", path);
-                    vfs_devices.push(Box::new(HostFilesystemDevice::new(path.into())));
+                    vfs_devices.push(Box::new(CaseInsensitiveHostDevice::new(path.into())));
                     // For directory-based VFS, the base path is the directory itself
                     // Only set if path is non-empty
                     if !path.is_empty() {
@@ -275,16 +485,19 @@ impl FilesystemConfig {
                         .unwrap_or_else(|| std::env::current_dir().unwrap_or_default());
 
                     log::info!("Loading game data from AruaVfs {}", path);
-                    vfs_devices.push(Box::new(
-                        AruaVfsIndex::load(Path::new(path), &index_root_path.join("data.rose"))
-                            .unwrap_or_else(|_| panic!("Failed to load AruaVfs at {}", path)),
-                    ));
+                    match AruaVfsIndex::load(Path::new(path), &index_root_path.join("data.rose")) {
+                        Ok(index) => vfs_devices.push(Box::new(index)),
+                        Err(error) => {
+                            filesystem_device_error(errors, device_config, error);
+                            continue;
+                        }
+                    }
 
                     log::info!(
                         "Loading game data from AruaVfs root path {}",
                         index_root_path.to_string_lossy()
                     );
-                    vfs_devices.push(Box::new(HostFilesystemDevice::new(index_root_path.clone())));
+                    vfs_devices.push(Box::new(CaseInsensitiveHostDevice::new(index_root_path.clone())));
                     // Use the VFS root path as base path for saving
                     if base_path.is_none() {
                         base_path = Some(index_root_path);
@@ -304,13 +517,16 @@ impl FilesystemConfig {
                         .unwrap_or_else(|| std::env::current_dir().unwrap_or_default());
 
                     log::info!("Loading game data from TitanVfs {}", path);
-                    vfs_devices.push(Box::new(
-                        TitanVfsIndex::load(Path::new(path), &index_root_path.join("data.trf"))
-                            .unwrap_or_else(|_| panic!("Failed to load TitanVfs at {}", path)),
-                    ));
+                    match TitanVfsIndex::load(Path::new(path), &index_root_path.join("data.trf")) {
+                        Ok(index) => vfs_devices.push(Box::new(index)),
+                        Err(error) => {
+                            filesystem_device_error(errors, device_config, error);
+                            continue;
+                        }
+                    }
 
                     log::info!("Loading game data from TitanVfs root path {}", index_root_path.to_string_lossy());
-                    vfs_devices.push(Box::new(HostFilesystemDevice::new(index_root_path.clone())));
+                    vfs_devices.push(Box::new(CaseInsensitiveHostDevice::new(index_root_path.clone())));
                     // Use the VFS root path as base path for saving
                     if base_path.is_none() {
                         base_path = Some(index_root_path);
@@ -318,10 +534,13 @@ impl FilesystemConfig {
                 }
                 FilesystemDeviceConfig::Vfs(path) => {
                     log::info!("Loading game data from Vfs {}", path);
-                    vfs_devices.push(Box::new(
-                        VfsIndex::load(Path::new(path))
-                            .unwrap_or_else(|_| panic!("Failed to load Vfs at {}", path)),
-                    ));
+                    match VfsIndex::load(Path::new(path)) {
+                        Ok(index) => vfs_devices.push(Box::new(index)),
+                        Err(error) => {
+                            filesystem_device_error(errors, device_config, error);
+                            continue;
+                        }
+                    }
 
                     // Get the parent directory of the VFS index file
                     // For relative paths like "data.idx", parent() returns empty string
@@ -336,7 +555,7 @@ impl FilesystemConfig {
                         .unwrap_or_else(|| std::env::current_dir().unwrap_or_default());
                     
                     log::info!("Loading game data from Vfs root path {}", index_root_path.to_string_lossy());
-                    vfs_devices.push(Box::new(HostFilesystemDevice::new(index_root_path.clone())));
+                    vfs_devices.push(Box::new(CaseInsensitiveHostDevice::new(index_root_path.clone())));
                     // Use the VFS root path as base path for saving
                     if base_path.is_none() {
                         base_path = Some(index_root_path);
@@ -356,22 +575,54 @@ impl FilesystemConfig {
                         .unwrap_or_else(|| std::env::current_dir().unwrap_or_default());
 
                     log::info!("Loading game data from iRosePH {}", path);
-                    vfs_devices.push(Box::new(
-                        IrosePhVfsIndex::load(Path::new(path))
-                            .unwrap_or_else(|_| panic!("Failed to load iRosePH VFS at {}", path)),
-                    ));
+                    match IrosePhVfsIndex::load(Path::new(path)) {
+                        Ok(index) => vfs_devices.push(Box::new(index)),
+                        Err(error) => {
+                            filesystem_device_error(errors, device_config, error);
+                            continue;
+                        }
+                    }
 
                     log::info!(
                         "Loading game data from iRosePH root path {}",
                         index_root_path.to_string_lossy()
                     );
-                    vfs_devices.push(Box::new(HostFilesystemDevice::new(index_root_path.clone())));
+                    vfs_devices.push(Box::new(CaseInsensitiveHostDevice::new(index_root_path.clone())));
                     // Use the VFS root path as base path for saving
                     if base_path.is_none() {
                         base_path = Some(index_root_path);
                     }
                 }
+                FilesystemDeviceConfig::Zip(path) => {
+                    // Zip archives are read only, so they never provide the base path for saving
+                    log::info!("Loading game data from zip archive {}", path);
+                    match ZipFilesystemDevice::load(Path::new(path)) {
+                        Ok(device) => vfs_devices.push(Box::new(device)),
+                        Err(error) => {
+                            filesystem_device_error(errors, device_config, error);
+                            continue;
+                        }
+                    }
+                }
+                FilesystemDeviceConfig::Overlay(path) => {
+                    log::info!("Loading overlay from host directory {}", path);
+                    overlay_devices.push(Box::new(OverlayFilesystemDevice::new(path.into())));
+                }
             }
+            loaded_devices.push(device_config.describe());
+        }
+
+        log::info!(
+            "[VFS INIT] Loaded {} of {} filesystem devices: [{}]",
+            loaded_devices.len(),
+            self.devices.len(),
+            loaded_devices.join(", ")
+        );
+
+        // Overlays take priority over every other device, in declared order
+        if !overlay_devices.is_empty() {
+            overlay_devices.append(&mut vfs_devices);
+            vfs_devices = overlay_devices;
         }
 
         if vfs_devices.is_empty() {
@@ -448,6 +699,13 @@ pub struct GraphicsConfig {
     pub trail_effect_duration_multiplier: f32,
     pub disable_vsync: bool,
     pub use_new_terrain: bool,
+    /// Generate mipmaps for DDS textures when they are loaded
+    pub generate_mipmaps: bool,
+    /// Downsample textures larger than this to reduce VRAM, full resolution when unset
+    pub max_texture_resolution: Option<u32>,
+    /// Share materials between identical zone object parts so they are drawn
+    /// instanced, experimental while it stabilises
+    pub instanced_zone_objects: bool,
 }
 
 impl Default for GraphicsConfig {
@@ -461,11 +719,14 @@ impl Default for GraphicsConfig {
             trail_effect_duration_multiplier: 1.0,
             disable_vsync: false,
             use_new_terrain: false,
+            generate_mipmaps: false,
+            max_texture_resolution: None,
+            instanced_zone_objects: false,
         }
     }
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize)]
 #[serde(default)]
 pub struct SoundVolumeConfig {
     pub global: f32,
@@ -493,11 +754,24 @@ impl Default for SoundVolumeConfig {
     }
 }
 
-#[derive(Deserialize)]
+#[derive(Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct SoundMuteConfig {
+    pub background_music: bool,
+    pub player_footstep: bool,
+    pub player_combat: bool,
+    pub other_footstep: bool,
+    pub other_combat: bool,
+    pub npc_sounds: bool,
+    pub ui_sounds: bool,
+}
+
+#[derive(Deserialize, Serialize)]
 #[serde(default)]
 pub struct SoundConfig {
     pub enabled: bool,
     pub volume: SoundVolumeConfig,
+    pub muted: SoundMuteConfig,
 }
 
 impl Default for SoundConfig {
@@ -505,6 +779,61 @@ impl Default for SoundConfig {
         Self {
             enabled: true,
             volume: SoundVolumeConfig::default(),
+            muted: SoundMuteConfig::default(),
+        }
+    }
+}
+
+impl From<&SoundConfig> for SoundSettings {
+    fn from(config: &SoundConfig) -> Self {
+        Self {
+            enabled: config.enabled,
+            global_gain: config.volume.global,
+            gains: enum_map! {
+                SoundCategory::BackgroundMusic => config.volume.background_music,
+                SoundCategory::PlayerFootstep => config.volume.player_footstep,
+                SoundCategory::PlayerCombat => config.volume.player_combat,
+                SoundCategory::OtherFootstep => config.volume.other_footstep,
+                SoundCategory::OtherCombat => config.volume.other_combat,
+                SoundCategory::NpcSounds => config.volume.npc_sounds,
+                SoundCategory::Ui => config.volume.ui_sounds,
+            },
+            muted: enum_map! {
+                SoundCategory::BackgroundMusic => config.muted.background_music,
+                SoundCategory::PlayerFootstep => config.muted.player_footstep,
+                SoundCategory::PlayerCombat => config.muted.player_combat,
+                SoundCategory::OtherFootstep => config.muted.other_footstep,
+                SoundCategory::OtherCombat => config.muted.other_combat,
+                SoundCategory::NpcSounds => config.muted.npc_sounds,
+                SoundCategory::Ui => config.muted.ui_sounds,
+            },
+        }
+    }
+}
+
+impl From<&SoundSettings> for SoundConfig {
+    fn from(settings: &SoundSettings) -> Self {
+        Self {
+            enabled: settings.enabled,
+            volume: SoundVolumeConfig {
+                global: settings.global_gain,
+                background_music: settings.gains[SoundCategory::BackgroundMusic],
+                player_footstep: settings.gains[SoundCategory::PlayerFootstep],
+                player_combat: settings.gains[SoundCategory::PlayerCombat],
+                other_footstep: settings.gains[SoundCategory::OtherFootstep],
+                other_combat: settings.gains[SoundCategory::OtherCombat],
+                npc_sounds: settings.gains[SoundCategory::NpcSounds],
+                ui_sounds: settings.gains[SoundCategory::Ui],
+            },
+            muted: SoundMuteConfig {
+                background_music: settings.muted[SoundCategory::BackgroundMusic],
+                player_footstep: settings.muted[SoundCategory::PlayerFootstep],
+                player_combat: settings.muted[SoundCategory::PlayerCombat],
+                other_footstep: settings.muted[SoundCategory::OtherFootstep],
+                other_combat: settings.muted[SoundCategory::OtherCombat],
+                npc_sounds: settings.muted[SoundCategory::NpcSounds],
+                ui_sounds: settings.muted[SoundCategory::Ui],
+            },
         }
     }
 }
@@ -514,11 +843,98 @@ impl Default for SoundConfig {
 pub struct Config {
     pub account: AccountConfig,
     pub auto_login: AutoLoginConfig,
+    pub benchmark: BenchmarkConfig,
     pub filesystem: FilesystemConfig,
     pub game: GameConfig,
     pub graphics: GraphicsConfig,
+    pub logging: LogSettings,
+    pub random: RandomConfig,
+    pub reconnect: ReconnectConfig,
     pub server: ServerConfig,
     pub sound: SoundConfig,
+    pub startup: StartupConfig,
+    pub name_tags: NameTagSettings,
+    pub network_movement: NetworkMovementSettings,
+    pub loot_filter: LootFilterSettings,
+    pub npc_store: NpcStoreSettings,
+    pub texture_filtering: TextureFilteringSettings,
+    pub occlusion_culling: OcclusionCullingSettings,
+    pub trails: TrailSettings,
+    pub particle_budget: ParticleBudget,
+    pub mouse_pick: MousePickSettings,
+    pub ui_layout: UiLayoutSettings,
+    pub ui_scale: UiScale,
+    pub accessibility: AccessibilitySettings,
+    pub bank: BankSettings,
+    pub idle_camera: IdleCameraSettings,
+    pub afk: AfkSettings,
+    pub conversation: ConversationSettings,
+    pub ui_sound: UiSoundSettings,
+    pub fog: FogSettings,
+    pub exposure: ExposureSettings,
+    pub blood: BloodSettings,
+    pub decals: DecalSettings,
+    pub hit_feedback: HitFeedbackSettings,
+    pub health_feedback: HealthFeedbackSettings,
+    pub auto_potion: AutoPotionSettings,
+    pub quick_use: QuickUseSettings,
+    pub character_idle: CharacterIdleSettings,
+    pub chat_bubbles: ChatBubbleSettings,
+    pub chatter: ChatterSettings,
+    pub character_select: CharacterSelectScene,
+    pub character_name: CharacterNameRules,
+    pub camera_motion: CameraMotionSettings,
+    pub map_editor_grid: map_editor::EditorGridSettings,
+
+    /// Path the config was loaded from, in game settings are saved back to it
+    #[serde(skip)]
+    pub path: Option<PathBuf>,
+}
+
+impl Config {
+    /// Short description of the config for crash reports, credentials are left out
+    pub fn summary(&self) -> String {
+        let devices = self
+            .filesystem
+            .devices
+            .iter()
+            .map(FilesystemDeviceConfig::describe)
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        [
+            format!(
+                "path: {}",
+                self.path.as_ref().map_or("<none>".to_string(), |path| path.display().to_string())
+            ),
+            format!("startup: {} zone {:?}", self.startup.mode.name(), self.startup.zone_id),
+            format!(
+                "game: data {} network {} ui {}",
+                self.game.data_version, self.game.network_version, self.game.ui_version
+            ),
+            format!("filesystem: [{}] hot_reload {}", devices, self.filesystem.hot_reload),
+            format!("server: {}:{}", self.server.ip, self.server.port),
+            format!(
+                "account: username {}, password {}",
+                if self.account.username.is_empty() { "unset" } else { "<redacted>" },
+                if self.account.password.is_empty() { "unset" } else { "<redacted>" },
+            ),
+            format!("auto_login: {}", self.auto_login.enabled),
+            format!(
+                "graphics: vsync {} new_terrain {} passthrough_terrain_textures {} mipmaps {} max_texture_resolution {:?} instanced_zone_objects {}",
+                !self.graphics.disable_vsync,
+                self.graphics.use_new_terrain,
+                self.graphics.passthrough_terrain_textures,
+                self.graphics.generate_mipmaps,
+                self.graphics.max_texture_resolution,
+                self.graphics.instanced_zone_objects,
+            ),
+            format!("sound: {}", self.sound.enabled),
+            format!("logging: {}", self.logging.filter()),
+            format!("random seed: {:?}", self.random.seed),
+        ]
+        .join("\n")
+    }
 }
 
 pub fn load_config(path: &Path) -> Config {
@@ -530,14 +946,20 @@ pub fn load_config(path: &Path) -> Config {
                 path.to_string_lossy(),
                 error
             );
-            return Config::default();
+            return Config {
+                path: Some(path.to_path_buf()),
+                ..Default::default()
+            };
         }
     };
 
-    match toml::from_str(&toml_str) {
+    match toml::from_str::<Config>(&toml_str) {
         Ok(config) => {
             println!("Read configuration from {}", path.to_string_lossy());
-            config
+            Config {
+                path: Some(path.to_path_buf()),
+                ..config
+            }
         }
         Err(error) => {
             println!(
@@ -545,6 +967,7 @@ pub fn load_config(path: &Path) -> Config {
                 path.to_string_lossy(),
                 error
             );
+            // Do not set path, we must not overwrite a config file we failed to parse
             Config::default()
         }
     }
@@ -554,6 +977,9 @@ pub fn load_config(path: &Path) -> Config {
 pub struct SystemsConfig {
     pub disable_player_command_system: bool,
     pub add_custom_systems: Option<Box<dyn FnOnce(&mut App)>>,
+    /// Protocol implementation used to connect to servers, required for
+    /// `network_version = "custom"` and defaults to iROSE otherwise
+    pub network_backend: Option<Box<dyn NetworkBackend>>,
 }
 
 pub fn run_game(config: &Config, systems_config: SystemsConfig) {
@@ -570,16 +996,89 @@ pub fn run_zone_viewer(config: &Config, zone_id: Option<ZoneId>) {
         AppState::ZoneViewer,
         SystemsConfig {
             add_custom_systems: Some(Box::new(move |app| {
-                app.world_mut()
-                    .write_message(LoadZoneEvent::new(
-                        zone_id.unwrap_or_else(|| ZoneId::new(1).unwrap()),
-                    ));
+                app.insert_resource(StartupZone(zone_id))
+                    .add_systems(Startup, load_startup_zone_system);
             })),
             ..Default::default()
         },
     );
 }
 
+/// Run the mode and zone selected by the `[startup]` config section
+pub fn run_startup_mode(config: &Config) {
+    match config.startup.mode {
+        StartupMode::Game => run_game(config, SystemsConfig::default()),
+        StartupMode::ZoneViewer => run_zone_viewer(config, config.startup.zone_id()),
+        StartupMode::ModelViewer => run_model_viewer(config),
+        StartupMode::MapEditor => run_map_editor(config, config.startup.zone_id()),
+        StartupMode::Benchmark => run_benchmark(config, config.startup.zone_id()),
+    }
+}
+
+/// Run the zone viewer with the camera flying the benchmark path, prints
+/// frame time statistics and exits at the end of the path
+pub fn run_benchmark(config: &Config, zone_id: Option<ZoneId>) {
+    let benchmark_config = config.benchmark.clone();
+    let rng_seed = RngSeed::new(config.random.seed.unwrap_or(RngSeed::FIXED_SEED));
+    run_client(
+        config,
+        AppState::ZoneViewer,
+        SystemsConfig {
+            add_custom_systems: Some(Box::new(move |app| {
+                app.insert_resource(StartupZone(zone_id))
+                    .insert_resource(rng_seed)
+                    .add_systems(Startup, load_startup_zone_system)
+                    .add_plugins(BenchmarkPlugin {
+                        config: benchmark_config,
+                    });
+            })),
+            ..Default::default()
+        },
+    );
+}
+
+/// The zone to load at startup, zones missing from the zone list fall back to zone 1
+fn startup_zone_id(zone_list: &ZoneList, zone_id: Option<ZoneId>) -> ZoneId {
+    let default_zone_id = ZoneId::new(1).unwrap();
+    match zone_id {
+        Some(zone_id) if zone_list.get_zone(zone_id).is_some() => zone_id,
+        Some(zone_id) => {
+            log::warn!(
+                "[Startup] Zone {} is not in the zone list, loading zone {} instead",
+                zone_id.get(),
+                default_zone_id.get()
+            );
+            default_zone_id
+        }
+        None => default_zone_id,
+    }
+}
+
+/// Zone requested for the zone viewer or map editor
+#[derive(Resource)]
+struct StartupZone(Option<ZoneId>);
+
+fn validate_character_select_scene_system(
+    mut character_select_scene: ResMut<CharacterSelectScene>,
+    game_data: Option<Res<GameData>>,
+) {
+    if let Some(game_data) = game_data {
+        character_select_scene.validate(&game_data.zone_list);
+    }
+}
+
+fn crash_report_zone_system(current_zone: Option<Res<CurrentZone>>) {
+    set_crash_report_zone(current_zone.map(|current_zone| current_zone.id.get()));
+}
+
+fn load_startup_zone_system(
+    startup_zone: Res<StartupZone>,
+    game_data: Res<GameData>,
+    mut load_zone_events: MessageWriter<LoadZoneEvent>,
+) {
+    load_zone_events.write(LoadZoneEvent::new(startup_zone_id(&game_data.zone_list, startup_zone.0)));
+}
+
 /// Run the map editor mode
 ///
 /// This launches the application in map editor mode, which allows editing
@@ -590,10 +1089,8 @@ pub fn run_map_editor(config: &Config, zone_id: Option<ZoneId>) {
         AppState::MapEditor,
         SystemsConfig {
             add_custom_systems: Some(Box::new(move |app| {
-                app.world_mut()
-                    .write_message(LoadZoneEvent::new(
-                        zone_id.unwrap_or_else(|| ZoneId::new(1).unwrap()),
-                    ));
+                app.insert_resource(StartupZone(zone_id))
+                    .add_systems(Startup, load_startup_zone_system);
             })),
             ..Default::default()
         },
@@ -705,22 +1202,47 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
     log::info!("[VFS INIT] Starting VFS initialization...");
     log::info!("[VFS INIT] Config has {} filesystem devices", config.filesystem.devices.len());
     
+    let (window_width, window_height) =
+        if let GraphicsModeConfig::Window { width, height } = config.graphics.mode {
+            (width, height)
+        } else {
+            (1920.0, 1080.0)
+        };
+
+    let mut device_errors = Vec::new();
     let (virtual_filesystem, base_path) =
-        if let Some((vfs, base)) = config.filesystem.create_virtual_filesystem() {
+        if let Some((vfs, base)) = config.filesystem.create_virtual_filesystem(&mut device_errors) {
             log::info!("[VFS INIT] VFS created successfully!");
             log::info!("[VFS INIT] Base path for saving: {:?}", base);
             (vfs, base)
         } else {
-            log::error!("[VFS INIT] No filesystem devices configured, VFS initialization failed!");
+            if device_errors.is_empty() {
+                log::error!("[VFS INIT] No filesystem devices configured, VFS initialization failed!");
+            } else {
+                log::error!("[VFS INIT] No filesystem device could be loaded, VFS initialization failed!");
+            }
+            run_data_path_wizard(
+                config.path.clone(),
+                config.filesystem.hot_reload,
+                device_errors,
+                window_width,
+                window_height,
+            );
             return;
         };
 
-    let (window_width, window_height) =
-        if let GraphicsModeConfig::Window { width, height } = config.graphics.mode {
-            (width, height)
-        } else {
-            (1920.0, 1080.0)
-        };
+    // Load game data up front so every missing file can be reported at once
+    let game_data = match config.game.data_version.as_str() {
+        "irose" => match load_game_data_irose(&virtual_filesystem) {
+            Ok(game_data) => Some(game_data),
+            Err(errors) => {
+                run_game_data_error_screen(errors, device_errors, window_width, window_height);
+                return;
+            }
+        },
+        "custom" => None,
+        unknown => panic!("Unknown game data version {}", unknown),
+    };
 
     let mut app = App::new();
 
@@ -729,6 +1251,22 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
     // the VFS from VfsResource during build, eliminating a redundant Arc clone.
     // Previously: 2 clones (one for plugin, one for resource)
     // Now: 1 clone (only for resource, plugin retrieves from resource)
+    if config.filesystem.hot_reload {
+        match AssetHotReload::new(&base_path) {
+            Ok(asset_hot_reload) => {
+                log::info!("Watching {} for asset changes", base_path.to_string_lossy());
+                app.insert_resource(asset_hot_reload);
+            }
+            Err(error) => {
+                log::error!(
+                    "Failed to watch {} for asset changes: {}",
+                    base_path.to_string_lossy(),
+                    error
+                );
+            }
+        }
+    }
+
     app.insert_resource(VfsResource {
         vfs: virtual_filesystem.clone(),
         base_path,
@@ -828,11 +1366,14 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
                         },
                         ..Default::default()
                     }),
+                    // Closing the window is handled by window_close_requested_system,
+                    // so the map editor can ask to save unsaved changes first
+                    close_when_requested: false,
                     ..Default::default()
                 })
                 .set(bevy::log::LogPlugin {
                     level: bevy::log::Level::INFO,
-                    filter: "wgpu=error,naga=error,offset_allocator=warn,rose_offline_client::map_editor=info".to_string(),
+                    filter: config.logging.filter(),
                     ..default()
                 })
                 .set(bevy::pbr::PbrPlugin::default()),
@@ -856,6 +1397,9 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
     // Disabled: RenderDocPlugin (debug plugin)
     app.add_plugins(OddioPlugin);
 
+    // Wireframe rendering is used by the model viewer and the debug render modes
+    app.add_plugins(bevy::pbr::wireframe::WireframePlugin::default());
+
     // Initialise rose stuff
     log::info!("[ASSET LOADER DIAGNOSTIC] Registering asset loaders...");
     log::info!("[ASSET LOADER DIAGNOSTIC] Registering ZmsAssetLoader");
@@ -889,7 +1433,10 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
     app.register_asset_loader(ZmsAssetLoader)
         .init_asset::<ZmsMaterialNumFaces>()
         .register_asset_loader(ZmsNoSkinAssetLoader)
-        .register_asset_loader(DdsImageLoader)
+        .register_asset_loader(DdsImageLoader {
+            generate_mipmaps: config.graphics.generate_mipmaps,
+            max_texture_resolution: config.graphics.max_texture_resolution,
+        })
         .register_asset_loader(ExeResourceLoader)
         .init_asset::<ExeResourceCursor>()
         .register_asset_loader(DialogLoader)
@@ -900,6 +1447,7 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
             passthrough_terrain_textures: config.graphics.passthrough_terrain_textures,
             trail_effect_duration_multiplier: config.graphics.trail_effect_duration_multiplier,
             use_new_terrain: config.graphics.use_new_terrain,
+            instanced_zone_objects: config.graphics.instanced_zone_objects,
         })
         .insert_resource(ServerConfiguration {
             ip: config.server.ip.clone(),
@@ -910,20 +1458,60 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
             preset_channel_id: config.auto_login.channel_id,
             preset_character_name: config.auto_login.character_name.clone(),
             auto_login: config.auto_login.enabled,
+            auto_login_max_retries: config.auto_login.max_retries,
+            auto_login_retry_base_delay: config.auto_login.retry_base_delay_secs,
+            auto_login_retry_max_delay: config.auto_login.retry_max_delay_secs,
+            reconnect: config.reconnect.enabled,
+            reconnect_max_attempts: config.reconnect.max_attempts,
+            reconnect_timeout: config.reconnect.timeout_secs,
         })
-        .insert_resource(SoundSettings {
-            enabled: config.sound.enabled,
-            global_gain: config.sound.volume.global,
-            gains: enum_map! {
-                SoundCategory::BackgroundMusic => config.sound.volume.background_music,
-                SoundCategory::PlayerFootstep => config.sound.volume.player_footstep,
-                SoundCategory::PlayerCombat => config.sound.volume.player_combat,
-                SoundCategory::OtherFootstep => config.sound.volume.other_footstep,
-                SoundCategory::OtherCombat => config.sound.volume.other_combat,
-                SoundCategory::NpcSounds => config.sound.volume.npc_sounds,
-                SoundCategory::Ui => config.sound.volume.ui_sounds,
-            },
-        })
+        .insert_resource(ConfigFile::new(config.path.clone()))
+        .insert_resource(config.name_tags.clone())
+        .insert_resource(config.texture_filtering.clone())
+        .insert_resource(config.occlusion_culling.clone())
+        .insert_resource(config.trails.clone())
+        .insert_resource(config.particle_budget.clone())
+        .init_resource::<ParticleBudgetStats>()
+        .insert_resource(config.mouse_pick.clone())
+        .init_resource::<MousePickDebug>()
+        .init_resource::<OcclusionCullingState>()
+        .insert_resource(config.network_movement.clone())
+        .insert_resource(config.loot_filter.clone())
+        .insert_resource(config.ui_layout.clone())
+        .insert_resource(config.ui_scale.clone())
+        .insert_resource(config.accessibility)
+        .insert_resource(config.bank.clone())
+        .insert_resource(config.npc_store.clone())
+        .insert_resource(config.idle_camera.clone())
+        .insert_resource(config.afk.clone())
+        .insert_resource(config.conversation.clone())
+        .insert_resource(config.ui_sound.clone())
+        .insert_resource(config.fog.clone())
+        .insert_resource(config.exposure.clone())
+        .insert_resource(config.blood.clone())
+        .insert_resource(config.decals.clone())
+        .insert_resource(config.hit_feedback.clone())
+        .insert_resource(config.health_feedback.clone())
+        .insert_resource(config.auto_potion.clone())
+        .insert_resource(config.quick_use.clone())
+        .insert_resource(config.character_idle.clone())
+        .insert_resource(config.chat_bubbles.clone())
+        .insert_resource(config.chatter.clone())
+        .insert_resource(config.character_select.clone())
+        .insert_resource(config.character_name.clone())
+        .insert_resource(config.camera_motion.clone())
+        .insert_resource(config.map_editor_grid.clone())
+        .insert_resource(config.random.rng_seed())
+        .insert_resource(config.logging.clone())
+        .insert_resource(
+            config
+                .chatter
+                .phrases_file
+                .as_deref()
+                .map_or_else(MonsterChatterPhrases::new, MonsterChatterPhrases::load),
+        )
+        .init_resource::<InputActivity>()
+        .insert_resource(SoundSettings::from(&config.sound))
         .add_plugins((
             RoseAnimationPlugin,
             // CRITICAL: Add these to fix the panic and enable rendering
@@ -955,6 +1543,15 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
     app.add_plugins(ExtensionMaterialPlugin);
     log::info!("[MATERIAL PLUGIN] ExtensionMaterialPlugin registered successfully");
 
+    // Histogram based eye adaptation for the camera AutoExposure
+    if !app.is_plugin_added::<AutoExposurePlugin>() {
+        app.add_plugins(AutoExposurePlugin);
+    }
+    app.add_systems(Update, exposure_settings_system);
+    app.add_systems(Update, blood_settings_system.before(hit_event_system));
+    app.add_systems(Update, transient_decal_system);
+    app.add_systems(Update, hit_flash_system.after(hit_event_system));
+
     // Optional: Add these for full rendering support
     app.add_plugins((
             TrailEffectRenderPlugin,
@@ -1042,6 +1639,7 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
         .add_message::<FlightToggleEvent>()
         .add_message::<GameConnectionEvent>()
         .add_message::<HitEvent>()
+        .add_message::<LoginConnectionEvent>()
         .add_message::<LoginEvent>()
         .add_message::<LoadZoneEvent>()
         .add_message::<MessageBoxEvent>()
@@ -1065,7 +1663,8 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
         .add_message::<WorldConnectionEvent>()
         .add_message::<ZoneEvent>()
         .add_message::<ZoneLoadedFromVfsEvent>()
-        .add_message::<UiSoundEvent>();
+        .add_message::<UiSoundEvent>()
+        .add_message::<UiLayoutEvent>();
 
     app.add_systems(
         PostUpdate,
@@ -1080,10 +1679,26 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
     // Camera systems use EguiContexts to check if egui wants pointer input
     app.add_systems(
         Update,
-        (free_camera_system, orbit_camera_system)
+        (
+            free_camera_system,
+            orbit_camera_system,
+            camera_motion_system.after(orbit_camera_system),
+            camera_shake_system.after(camera_motion_system),
+        )
             .in_set(GameSystemSets::UpdateCamera)
             .after(bevy_egui::EguiPreUpdateSet::InitContexts),
     );
+    app.add_systems(
+        Update,
+        input_activity_system.before(GameSystemSets::UpdateCamera),
+    );
+    app.add_systems(
+        Update,
+        death_spectator_camera_system
+            .run_if(in_state(AppState::Game))
+            .before(orbit_camera_system)
+            .after(bevy_egui::EguiPreUpdateSet::InitContexts),
+    );
 
     // Configure system ordering for model systems
     app.configure_sets(
@@ -1124,6 +1739,8 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
         ),
     );
 
+    app.add_systems(Update, particle_category_system.before(particle_sequence_system));
+
     app.add_systems(
         Update,
         (
@@ -1176,6 +1793,7 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
             npc_model_add_collider_system,
             item_drop_model_system,
             item_drop_model_add_collider_system,
+            loot_beam_system.after(item_drop_model_system),
             animation_effect_system,
             projectile_system,
             spawn_projectile_system,
@@ -1210,7 +1828,7 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
             // This system was applying time-based color grading (temperature/saturation changes)
             // which conflicts with the new atmospheric scattering system.
             // color_grading_time_of_day_system,
-            directional_light_system,
+            directional_light_system.after(zone_time_system),
             // Update terrain lighting based on zone lighting and time of day
             // Must run after zone_time_system to get current time state for intensity adjustment
             render::terrain_material::update_terrain_lighting_system.after(zone_time_system),
@@ -1219,11 +1837,20 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
             update_starry_sky_system.after(update_starry_sky_night_factor),
         ),
     );
+    app.add_systems(Update, time_scale_system.before(zone_time_system));
+    app.add_systems(Update, weather_override_system.after(zone_time_system));
+    app.add_systems(
+        Update,
+        fog_settings_system
+            .after(weather_override_system)
+            .before(apply_water_settings),
+    );
     // update_ui_resources uses EguiContexts - must run in EguiPrimaryContextPass for bevy_egui 0.39
     app.add_systems(bevy_egui::EguiPrimaryContextPass, update_ui_resources);
 
     // ui_requested_cursor_apply_system uses EguiContexts - must run in EguiPrimaryContextPass for bevy_egui 0.39
     app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_requested_cursor_apply_system);
+    app.add_systems(Update, (ui_scale_system, accessibility_settings_system));
 
     // ui_item_drop_name_system uses EguiContexts - must run in EguiPrimaryContextPass for bevy_egui 0.39
     app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_item_drop_name_system);
@@ -1262,16 +1889,28 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
 
     // DISABLED: app.add_systems(Update, ui_debug_physics_system); // Too many parameters for Bevy 0.15
     // More debug UI systems - must run in EguiPrimaryContextPass for bevy_egui 0.39
+    app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_debug_network_system);
+    app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_debug_packet_log_system);
     app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_debug_render_system);
+    app.add_systems(Update, debug_render_mode_system);
     app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_debug_skill_list_system);
     app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_debug_zone_lighting_system);
     app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_debug_zone_list_system);
     app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_debug_zone_time_system);
+    app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_debug_weather_system);
+    app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_debug_log_system);
+    app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_log_console_system);
+    app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_debug_animation_events_system);
     // DISABLED: app.add_systems(Update, ui_debug_diagnostics_system);
 
     // character_model_blink_system in PostUpdate to avoid any conflicts with model destruction
     // e.g. through the character select exit system.
-    app.add_systems(PostUpdate, character_model_blink_system);
+    app.add_systems(
+        PostUpdate,
+        character_model_blink_system
+            .after(animation::RoseAnimationSystem)
+            .before(TransformSystems::Propagate),
+    );
 
     // Sky sphere follows camera in PostUpdate to ensure camera transform is up to date
     app.add_systems(PostUpdate, sky_sphere_follow_camera_system.after(TransformSystems::Propagate));
@@ -1310,6 +1949,16 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
         )
     );
 
+    app.add_systems(
+        Update,
+        (
+            asset_hot_reload_system.before(zone_loader_system),
+            asset_hot_reload_material_system,
+        ),
+    );
+
+    app.add_systems(Update, occlusion_culling_fallback_system);
+
     app.add_systems(
         PostUpdate,
         force_zone_visibility_system
@@ -1334,6 +1983,11 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
     // Map Editor
     app.add_systems(OnEnter(AppState::MapEditor), map_editor::map_editor_enter_system);
     app.add_systems(OnExit(AppState::MapEditor), map_editor::map_editor_exit_system);
+    app.add_systems(
+        Update,
+        save_settings_system::<map_editor::EditorGridSettings>
+            .run_if(in_state(AppState::MapEditor)),
+    );
 
     // CRITICAL DIAGNOSTIC: Check if transform and visibility propagation sets are running
     app.add_systems(
@@ -1388,7 +2042,16 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
     app.add_systems(OnExit(AppState::ModelViewer), model_viewer_exit_system);
     app.add_systems(
         PostUpdate,
-        model_viewer_system.run_if(in_state(AppState::ModelViewer)),
+        (
+            model_viewer_system,
+            model_viewer_equipment_system,
+            model_viewer_normals_system,
+        )
+            .run_if(in_state(AppState::ModelViewer)),
+    );
+    app.add_systems(
+        Update,
+        model_viewer_turntable_system.run_if(in_state(AppState::ModelViewer)),
     );
 
     // Game Login
@@ -1470,9 +2133,20 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
         .init_resource::<ClientEntityList>()
         .init_resource::<DebugRenderConfig>()
         .init_resource::<WorldTime>()
+        .init_resource::<TimeScaleSettings>()
+        .init_resource::<WeatherOverride>()
         .init_resource::<ZoneTime>()
         .init_resource::<SelectedTarget>()
-        .init_resource::<NameTagSettings>()
+        .init_resource::<FriendsList>()
+        .init_resource::<ConversationHistory>()
+        .init_resource::<AutoPotionNotice>()
+        .init_resource::<WorldPings>()
+        .init_resource::<PartyMarkerSettings>()
+        .init_resource::<ProjectileSettings>()
+        .init_resource::<CameraShake>()
+        .init_resource::<ReconnectSession>()
+        .init_resource::<NetworkStats>()
+        .init_resource::<NetworkLogging>()
         .init_resource::<DepthOfFieldSettings>()
         .init_resource::<ui::PostProcessingSettings>()
         .init_resource::<ui::StarrySkyRenderSettings>()
@@ -1480,7 +2154,6 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
         .init_resource::<FlightSettings>()
         .init_resource::<WindSettings>()
         .init_resource::<WindState>()
-        .init_resource::<MonsterChatterPhrases>()
         .init_resource::<AtmosphereState>()
         .init_resource::<graphics::GraphicsSettings>();
 
@@ -1505,19 +2178,34 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
         graphics::apply_shadow_quality_system,
         graphics::apply_tonemapping_system,
         graphics::apply_bloom_system,
+        graphics::apply_motion_blur_system,
         graphics::apply_shadow_filtering_system,
         graphics::apply_msaa_system,
         graphics::apply_ambient_light_system,
+        graphics::apply_texture_filtering_system,
     ));
 
     // Register systems individually to avoid Bevy 0.13's IntoSystemConfigs trait bound issues
     // Game systems - part 1
     app.add_systems(Update, ability_values_system.run_if(in_state(AppState::Game)));
     app.add_systems(Update, clan_system.run_if(in_state(AppState::Game)));
+    app.add_systems(Update, friends_list_system.run_if(in_state(AppState::Game)));
+    app.add_systems(
+        Update,
+        (afk_system, afk_auto_reply_system).run_if(in_state(AppState::Game)),
+    );
+    app.add_systems(Update, auto_potion_system.run_if(in_state(AppState::Game)));
     app.add_systems(Update, command_system.run_if(in_state(AppState::Game)));
     app.add_systems(Update, facing_direction_system.run_if(in_state(AppState::Game)));
     app.add_systems(Update, update_position_system.run_if(in_state(AppState::Game)));
     app.add_systems(Update, monster_separation_system.run_if(in_state(AppState::Game)).after(update_position_system));
+    app.add_systems(
+        Update,
+        (network_movement_reconcile_system, network_movement_playback_system)
+            .chain()
+            .run_if(in_state(AppState::Game))
+            .after(update_position_system),
+    );
     app.add_systems(Update, collision_height_only_system.run_if(in_state(AppState::Game)));
     // CRITICAL: collision_player_system_join_zone must run BEFORE collision_player_system
     // - join_zone uses a long raycast (Y=100000) to find initial ground height on spawn
@@ -1566,13 +2254,90 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
     // Game systems - part 2
     app.add_systems(Update, (use_item_event_system.run_if(in_state(AppState::Game)),));
     app.add_systems(Update, (status_effect_system.run_if(in_state(AppState::Game)),));
-    app.add_systems(Update, (passive_recovery_system.run_if(in_state(AppState::Game)),));
+    app.add_systems(
+        Update,
+        (passive_recovery_system, resting_system).run_if(in_state(AppState::Game)),
+    );
     app.add_systems(Update, (quest_trigger_system.run_if(in_state(AppState::Game)),));
+    app.add_systems(Update, reconnect_system.run_if(in_state(AppState::Game)));
+    app.add_systems(Update, threat_state_system.run_if(in_state(AppState::Game)).after(hit_event_system));
+    app.add_systems(
+        Update,
+        name_tag_update_threat_system
+            .run_if(in_state(AppState::Game))
+            .after(threat_state_system)
+            .after(name_tag_visibility_system),
+    );
+    app.add_systems(
+        Update,
+        name_tag_fade_system
+            .run_if(in_state(AppState::Game))
+            .after(name_tag_visibility_system),
+    );
+    app.add_systems(Update, party_marker_system.run_if(in_state(AppState::Game)));
+
+    // Log levels are changed from the debug UI in every state
+    app.add_systems(Update, (log_settings_system, save_settings_system::<LogSettings>));
+    app.add_systems(Update, write_config_file_system);
+    app.add_systems(Update, crash_report_zone_system);
+
+    // Write settings changed in game back to the config file, other states such as the
+    // model viewer change settings temporarily which must not be saved
+    app.add_systems(
+        Update,
+        (
+            save_settings_system::<NameTagSettings>,
+            save_settings_system::<LootFilterSettings>,
+            save_settings_system::<TextureFilteringSettings>,
+            save_settings_system::<TrailSettings>,
+            save_settings_system::<ParticleBudget>,
+            save_settings_system::<UiLayoutSettings>,
+            save_settings_system::<UiScale>,
+            save_settings_system::<AccessibilitySettings>,
+            save_settings_system::<BankSettings>,
+            save_settings_system::<IdleCameraSettings>,
+            save_settings_system::<FogSettings>,
+            save_settings_system::<ExposureSettings>,
+            save_settings_system::<BloodSettings>,
+            save_settings_system::<DecalSettings>,
+            save_settings_system::<HitFeedbackSettings>,
+            save_settings_system::<CharacterIdleSettings>,
+            save_settings_system::<ChatBubbleSettings>,
+            save_settings_system::<ChatterSettings>,
+            save_settings_system::<CameraMotionSettings>,
+            save_settings_system::<SoundSettings>,
+        )
+            .run_if(in_state(AppState::Game)),
+    );
+    app.add_systems(
+        Update,
+        (
+            save_settings_system::<AfkSettings>,
+            save_settings_system::<ConversationSettings>,
+            save_settings_system::<UiSoundSettings>,
+            save_settings_system::<HealthFeedbackSettings>,
+            save_settings_system::<AutoPotionSettings>,
+            save_settings_system::<QuickUseSettings>,
+        )
+            .run_if(in_state(AppState::Game)),
+    );
+
     // game_mouse_input_system uses EguiContexts to check if egui wants pointer input
     // This can stay in Update since it only queries egui state, doesn't render
     app.add_systems(Update, game_mouse_input_system.after(bevy_egui::EguiPreUpdateSet::InitContexts));
+    app.add_systems(
+        Update,
+        (hover_highlight_system, mouse_pick_debug_system).after(game_mouse_input_system),
+    );
+    app.add_systems(Update, world_ping_system.run_if(in_state(AppState::Game)));
     // game_keyboard_input_system uses EguiContexts to skip input while typing in UI.
     app.add_systems(Update, game_keyboard_input_system.after(bevy_egui::EguiPreUpdateSet::InitContexts));
+    app.add_systems(
+        Update,
+        auto_loot_system
+            .after(bevy_egui::EguiPreUpdateSet::InitContexts)
+            .run_if(in_state(AppState::Game)),
+    );
     
     // UI systems - part 1 (must run in EguiPrimaryContextPass for bevy_egui 0.39)
     app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_admin_menu_system.run_if(in_state(AppState::Game)));
@@ -1582,18 +2347,27 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
     app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_character_info_system.run_if(in_state(AppState::Game)));
     app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_clan_system.run_if(in_state(AppState::Game)));
     app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_create_clan_system.run_if(in_state(AppState::Game)));
+    app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_friends_system.run_if(in_state(AppState::Game)));
     app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_inventory_system.run_if(in_state(AppState::Game)));
     app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_game_menu_system.run_if(in_state(AppState::Game)));
     app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_hotbar_system.run_if(in_state(AppState::Game)));
+    app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_layout_system.run_if(in_state(AppState::Game)));
     app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_minimap_system.run_if(in_state(AppState::Game)));
     app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_npc_store_system.run_if(in_state(AppState::Game)));
     app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_party_system.run_if(in_state(AppState::Game)));
+    app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_party_marker_system.run_if(in_state(AppState::Game)));
+    app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_health_vignette_system.run_if(in_state(AppState::Game)));
+    app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_auto_potion_system.run_if(in_state(AppState::Game)));
+    app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_ping_wheel_system.run_if(in_state(AppState::Game)));
+    app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_emote_wheel_system.run_if(in_state(AppState::Game)));
     app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_party_option_system.run_if(in_state(AppState::Game)));
     app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_personal_store_system.run_if(in_state(AppState::Game)));
     app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_player_info_system.run_if(in_state(AppState::Game)));
     app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_quest_list_system.run_if(in_state(AppState::Game)));
+    app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_quick_use_system.run_if(in_state(AppState::Game)));
 
     // UI systems - part 2 (must run in EguiPrimaryContextPass for bevy_egui 0.39)
+    app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_reconnect_system.run_if(in_state(AppState::Game)));
     app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_respawn_system.run_if(in_state(AppState::Game)));
     app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_sailing_hud_system.run_if(in_state(AppState::Game)));
     app.add_systems(bevy_egui::EguiPrimaryContextPass, ui_selected_target_system.run_if(in_state(AppState::Game)));
@@ -1619,10 +2393,28 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
     );
 
     // Setup network
+    let network_backend: Option<Box<dyn NetworkBackend>> =
+        match config.game.network_version.as_str() {
+            "irose" => Some(
+                systems_config
+                    .network_backend
+                    .take()
+                    .unwrap_or_else(|| Box::new(IroseNetworkBackend)),
+            ),
+            // Without a backend the custom network systems must be added through add_custom_systems
+            "custom" => systems_config.network_backend.take(),
+            unknown => panic!("Unknown game network version {}", unknown),
+        };
+    let has_network_backend = network_backend.is_some();
+
     let (network_thread_tx, network_thread_rx) =
         tokio::sync::mpsc::unbounded_channel::<NetworkThreadMessage>();
     let network_thread = std::thread::spawn(move || run_network_thread(network_thread_rx));
-    app.insert_resource(NetworkThread::new(network_thread_tx.clone()));
+    app.insert_resource(NetworkThread::new(
+        network_thread_tx.clone(),
+        // Unused when there is no backend as network_thread_system is not added
+        network_backend.unwrap_or_else(|| Box::new(IroseNetworkBackend)),
+    ));
 
     // Run network systems before Update, so we can add/remove entities
     app.add_systems(
@@ -1633,6 +2425,9 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
             game_connection_system.run_if(resource_exists::<CurrentZone>),
         ),
     );
+    app.add_systems(PreUpdate, (network_stats_system, network_logging_system));
+
+    app.add_systems(Startup, validate_character_select_scene_system);
 
     app.add_systems(PostStartup, load_common_game_data
         .after(bevy_egui::EguiStartupSet::InitContexts));
@@ -1653,13 +2448,14 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
         app_builder(&mut app);
     }
 
-    match config.game.network_version.as_str() {
-        "irose" => {
-            app.add_systems(PostUpdate, network_thread_system);
-        }
-        "custom" => {}
-        unknown => panic!("Unknown game network version {}", unknown),
-    };
+    // Logged so a run can be reproduced with the same seed
+    app.add_systems(Startup, |rng_seed: Res<RngSeed>| {
+        log::info!("[RngSeed] Random seed {}", rng_seed.seed);
+    });
+
+    if has_network_backend {
+        app.add_systems(PostUpdate, network_thread_system);
+    }
 
     match config.game.ui_version.as_str() {
         "irose" => {
@@ -1671,13 +2467,11 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
         unknown => panic!("Unknown game ui version {}", unknown),
     };
 
-    match config.game.data_version.as_str() {
-        "irose" => {
-            app.add_systems(Startup, load_game_data_irose);
-        }
-        "custom" => {}
-        unknown => panic!("Unknown game data version {}", unknown),
-    };
+    if let Some(game_data) = game_data {
+        app.insert_resource(SoundCache::new(game_data.sounds.len()));
+        app.insert_resource(EmoteList::new(&game_data));
+        app.insert_resource(game_data);
+    }
 
     app.configure_sets(
         PostUpdate,
@@ -1753,57 +2547,288 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
     network_thread.join().ok();
 }
 
-fn load_game_data_irose(
-    mut commands: Commands,
-    vfs_resource: Res<VfsResource>,
-    asset_server: Res<AssetServer>,
+/// Minimal app with a window and egui for the startup error screens
+fn error_screen_app(width: f32, height: f32) -> App {
+    let mut app = App::new();
+    app.add_plugins(bevy::prelude::DefaultPlugins.set(bevy::window::WindowPlugin {
+        primary_window: Some(Window {
+            title: "rose-offline-client".to_string(),
+            resolution: bevy::window::WindowResolution::new(width as u32, height as u32),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }))
+    .add_plugins(bevy_egui::EguiPlugin {
+        bindless_mode_array_size: None,
+        ..Default::default()
+    })
+    .add_systems(Startup, |mut commands: Commands| {
+        commands.spawn((Camera2d, PrimaryEguiContext));
+    });
+    app
+}
+
+#[derive(Resource)]
+struct FilesystemDeviceErrors(Vec<FilesystemDeviceError>);
+
+fn filesystem_device_errors_ui(ui: &mut egui::Ui, device_errors: &[FilesystemDeviceError]) {
+    egui::Grid::new("filesystem_device_errors")
+        .num_columns(2)
+        .striped(true)
+        .show(ui, |ui| {
+            for error in device_errors.iter() {
+                ui.colored_label(egui::Color32::RED, &error.device);
+                ui.label(&error.error);
+                ui.end_row();
+            }
+        });
+}
+
+/// Shows the game data files which failed to load in place of the game
+fn run_game_data_error_screen(
+    errors: Vec<GameDataLoadError>,
+    device_errors: Vec<FilesystemDeviceError>,
+    width: f32,
+    height: f32,
 ) {
-    let string_database = rose_data_irose::get_string_database(&vfs_resource.vfs, 1)
-        .expect("Failed to load string database");
+    let mut app = error_screen_app(width, height);
+    app.insert_resource(GameDataLoadErrors(errors))
+        .insert_resource(FilesystemDeviceErrors(device_errors))
+        .add_systems(
+            bevy_egui::EguiPrimaryContextPass,
+            |mut egui_context: EguiContexts,
+             game_data_load_errors: Res<GameDataLoadErrors>,
+             device_errors: Res<FilesystemDeviceErrors>,
+             mut app_exit_events: MessageWriter<AppExit>| {
+                let Ok(ctx) = egui_context.ctx_mut() else {
+                    return;
+                };
+
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    ui.heading("Failed to load game data");
+                    ui.label(format!(
+                        "{} file(s) could not be loaded, check the filesystem devices in your config.",
+                        game_data_load_errors.0.len()
+                    ));
+                    ui.separator();
 
-    let items = Arc::new(
-        rose_data_irose::get_item_database(&vfs_resource.vfs, string_database.clone())
-            .expect("Failed to load item database"),
-    );
-    let npcs = Arc::new(
+                    if !device_errors.0.is_empty() {
+                        ui.label("Some filesystem devices failed to load and were skipped:");
+                        filesystem_device_errors_ui(ui, &device_errors.0);
+                        ui.separator();
+                    }
+
+                    egui::ScrollArea::vertical()
+                        .max_height(ui.available_height() - 40.0)
+                        .show(ui, |ui| {
+                            egui::Grid::new("game_data_load_errors")
+                                .num_columns(2)
+                                .striped(true)
+                                .show(ui, |ui| {
+                                    for error in game_data_load_errors.0.iter() {
+                                        ui.colored_label(egui::Color32::RED, error.name);
+                                        ui.label(&error.error);
+                                        ui.end_row();
+                                    }
+                                });
+                        });
+
+                    ui.separator();
+                    if ui.button("Quit").clicked() {
+                        app_exit_events.write(AppExit::Success);
+                    }
+                });
+            },
+        );
+    app.run();
+}
+
+#[derive(Resource)]
+struct GameDataLoadErrors(Vec<GameDataLoadError>);
+
+/// A game data file which failed to load
+pub struct GameDataLoadError {
+    pub name: &'static str,
+    pub error: String,
+}
+
+fn try_load<T, E: std::fmt::Display>(
+    errors: &mut Vec<GameDataLoadError>,
+    name: &'static str,
+    result: Result<T, E>,
+) -> Option<T> {
+    match result {
+        Ok(value) => Some(value),
+        Err(error) => {
+            log::error!("Failed to load {}: {}", name, error);
+            errors.push(GameDataLoadError {
+                name,
+                error: error.to_string(),
+            });
+            None
+        }
+    }
+}
+
+/// Loads every database, collecting all failures so they can be reported
+/// together instead of stopping at the first missing file.
+fn load_game_data_irose(vfs: &VirtualFilesystem) -> Result<GameData, Vec<GameDataLoadError>> {
+    let mut errors = Vec::new();
+
+    let Some(string_database) = try_load(
+        &mut errors,
+        "string database",
+        rose_data_irose::get_string_database(vfs, 1),
+    ) else {
+        // Almost every other database needs the strings, no point continuing
+        return Err(errors);
+    };
+
+    let items = try_load(
+        &mut errors,
+        "item database",
+        rose_data_irose::get_item_database(vfs, string_database.clone()),
+    )
+    .map(Arc::new);
+    let npcs = try_load(
+        &mut errors,
+        "npc database",
         rose_data_irose::get_npc_database(
-            &vfs_resource.vfs,
+            vfs,
             string_database.clone(),
             &NpcDatabaseOptions {
                 load_frame_data: false,
             },
-        )
-        .expect("Failed to load npc database"),
-    );
-    let skills = Arc::new(
-        rose_data_irose::get_skill_database(&vfs_resource.vfs, string_database.clone())
-            .expect("Failed to load skill database"),
-    );
-    let character_motion_database = Arc::new(
+        ),
+    )
+    .map(Arc::new);
+    let skills = try_load(
+        &mut errors,
+        "skill database",
+        rose_data_irose::get_skill_database(vfs, string_database.clone()),
+    )
+    .map(Arc::new);
+    let character_motion_database = try_load(
+        &mut errors,
+        "character motion list",
         rose_data_irose::get_character_motion_database(
-            &vfs_resource.vfs,
+            vfs,
             &CharacterMotionDatabaseOptions {
                 load_frame_data: false,
             },
-        )
-        .expect("Failed to load character motion list"),
+        ),
+    )
+    .map(Arc::new);
+    let zone_list = try_load(
+        &mut errors,
+        "zone list",
+        rose_data_irose::get_zone_list(vfs, string_database.clone()),
+    )
+    .map(Arc::new);
+    let sounds = try_load(
+        &mut errors,
+        "sound database",
+        rose_data_irose::get_sound_database(vfs),
+    );
+    let client_strings = try_load(
+        &mut errors,
+        "client strings",
+        rose_data_irose::get_client_strings(string_database.clone()),
+    );
+    let effect_database = try_load(
+        &mut errors,
+        "effect database",
+        rose_data_irose::get_effect_database(vfs),
     );
-    let zone_list = Arc::new(
-        rose_data_irose::get_zone_list(&vfs_resource.vfs, string_database.clone())
-            .expect("Failed to load zone list"),
+    let job_class = try_load(
+        &mut errors,
+        "job class database",
+        rose_data_irose::get_job_class_database(vfs, string_database.clone()),
+    )
+    .map(Arc::new);
+    let quests = try_load(
+        &mut errors,
+        "quest database",
+        rose_data_irose::get_quest_database(vfs, string_database.clone()),
+    )
+    .map(Arc::new);
+    let skybox = try_load(
+        &mut errors,
+        "skybox database",
+        rose_data_irose::get_skybox_database(vfs),
+    );
+    let status_effects = try_load(
+        &mut errors,
+        "status effect database",
+        rose_data_irose::get_status_effect_database(vfs, string_database.clone()),
+    )
+    .map(Arc::new);
+    let ltb_event = try_load(
+        &mut errors,
+        "3DDATA/EVENT/ULNGTB_CON.LTB",
+        vfs.read_file::<LtbFile, _>("3DDATA/EVENT/ULNGTB_CON.LTB"),
+    );
+    let zsc_event_object = try_load(
+        &mut errors,
+        "3DDATA/SPECIAL/EVENT_OBJECT.ZSC",
+        vfs.read_file::<ZscFile, _>("3DDATA/SPECIAL/EVENT_OBJECT.ZSC"),
+    );
+    let zsc_special_object = try_load(
+        &mut errors,
+        "3DDATA/SPECIAL/LIST_DECO_SPECIAL.ZSC",
+        vfs.read_file::<ZscFile, _>("3DDATA/SPECIAL/LIST_DECO_SPECIAL.ZSC"),
+    );
+    let stb_morph_object = try_load(
+        &mut errors,
+        "3DDATA/STB/LIST_MORPH_OBJECT.STB",
+        vfs.read_file::<StbFile, _>("3DDATA/STB/LIST_MORPH_OBJECT.STB"),
     );
 
+    let (
+        Some(items),
+        Some(npcs),
+        Some(skills),
+        Some(character_motion_database),
+        Some(zone_list),
+        Some(sounds),
+        Some(client_strings),
+        Some(effect_database),
+        Some(job_class),
+        Some(quests),
+        Some(skybox),
+        Some(status_effects),
+        Some(ltb_event),
+        Some(zsc_event_object),
+        Some(zsc_special_object),
+        Some(stb_morph_object),
+    ) = (
+        items,
+        npcs,
+        skills,
+        character_motion_database,
+        zone_list,
+        sounds,
+        client_strings,
+        effect_database,
+        job_class,
+        quests,
+        skybox,
+        status_effects,
+        ltb_event,
+        zsc_event_object,
+        zsc_special_object,
+        stb_morph_object,
+    )
+    else {
+        return Err(errors);
+    };
+
     // Initialize ZoneLoader with zone_list
     log::info!("[GameData] Initializing ZoneLoader with zone_list");
     zone_loader::ZoneLoader::init_zone_list(zone_list.clone());
     log::info!("[GameData] ZoneLoader initialized successfully");
 
-    let sounds = rose_data_irose::get_sound_database(&vfs_resource.vfs)
-        .expect("Failed to load sound database");
-
-    commands.insert_resource(SoundCache::new(sounds.len()));
-
-    commands.insert_resource(GameData {
+    Ok(GameData {
         ability_value_calculator: rose_game_irose::data::get_ability_value_calculator(
             items.clone(),
             skills.clone(),
@@ -1819,73 +2844,31 @@ fn load_game_data_irose(
             flags
         },
         character_motion_database,
-        client_strings: rose_data_irose::get_client_strings(string_database.clone())
-            .expect("Failed to load client strings"),
+        client_strings,
         data_decoder: rose_data_irose::get_data_decoder(),
         effect_database: {
-            let db = rose_data_irose::get_effect_database(&vfs_resource.vfs)
-                .expect("Failed to load effect database");
-            if db.is_empty() {
+            if effect_database.is_empty() {
                 log::warn!("Effect database is empty! Visual effects will not appear.");
             } else {
-                log::info!("Loaded {} effects from effect database", db.len());
+                log::info!("Loaded {} effects from effect database", effect_database.len());
             }
-            db
+            effect_database
         },
         items,
-        job_class: Arc::new(
-            rose_data_irose::get_job_class_database(&vfs_resource.vfs, string_database.clone())
-                .expect("Failed to load job class database"),
-        ),
+        job_class,
         npcs,
-        quests: Arc::new(
-            rose_data_irose::get_quest_database(&vfs_resource.vfs, string_database.clone())
-                .expect("Failed to load quest database"),
-        ),
+        quests,
         skills,
-        skybox: rose_data_irose::get_skybox_database(&vfs_resource.vfs)
-            .expect("Failed to load skybox database"),
+        skybox,
         sounds,
-        status_effects: Arc::new(
-            rose_data_irose::get_status_effect_database(&vfs_resource.vfs, string_database.clone())
-                .expect("Failed to load status effect database"),
-        ),
+        status_effects,
         string_database,
         zone_list,
-        ltb_event: vfs_resource
-            .vfs
-            .read_file::<LtbFile, _>("3DDATA/EVENT/ULNGTB_CON.LTB")
-            .expect("Failed to load event language file"),
-        zsc_event_object: vfs_resource
-            .vfs
-            .read_file::<ZscFile, _>("3DDATA/SPECIAL/EVENT_OBJECT.ZSC")
-            .expect("Failed to load 3DDATA/SPECIAL/EVENT_OBJECT.ZSC"),
-        zsc_special_object: vfs_resource
-            .vfs
-            .read_file::<ZscFile, _>("3DDATA/SPECIAL/LIST_DECO_SPECIAL.ZSC")
-            .expect("Failed to load 3DDATA/SPECIAL/LIST_DECO_SPECIAL.ZSC"),
-        stb_morph_object: vfs_resource
-            .vfs
-            .read_file::<StbFile, _>("3DDATA/STB/LIST_MORPH_OBJECT.STB")
-            .expect("Failed to load 3DDATA/STB/LIST_MORPH_OBJECT.STB"),
-        character_select_positions: vec![
-            Transform::from_translation(Vec3::new(5205.0, 1.0, -5205.0))
-                .with_rotation(Quat::from_xyzw(0.0, 1.0, 0.0, 0.0))
-                .with_scale(Vec3::new(1.5, 1.5, 1.5)),
-            Transform::from_translation(Vec3::new(5202.70, 1.0, -5206.53))
-                .with_rotation(Quat::from_xyzw(0.0, 1.0, 0.0, 0.0))
-                .with_scale(Vec3::new(1.5, 1.5, 1.5)),
-            Transform::from_translation(Vec3::new(5200.00, 1.0, -5207.07))
-                .with_rotation(Quat::from_xyzw(0.0, 1.0, 0.0, 0.0))
-                .with_scale(Vec3::new(1.5, 1.5, 1.5)),
-            Transform::from_translation(Vec3::new(5197.30, 1.0, -5206.53))
-                .with_rotation(Quat::from_xyzw(0.0, 1.0, 0.0, 0.0))
-                .with_scale(Vec3::new(1.5, 1.5, 1.5)),
-            Transform::from_translation(Vec3::new(5195.00, 1.0, -5205.00))
-                .with_rotation(Quat::from_xyzw(0.0, 1.0, 0.0, 0.0))
-                .with_scale(Vec3::new(1.5, 1.5, 1.5)),
-        ],
-    });
+        ltb_event,
+        zsc_event_object,
+        zsc_special_object,
+        stb_morph_object,
+    })
 }
 
 fn load_common_game_data(
@@ -1895,6 +2878,9 @@ fn load_common_game_data(
     asset_server: Res<AssetServer>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut scattering_mediums: ResMut<Assets<bevy::pbr::ScatteringMedium>>,
+    occlusion_culling_settings: Res<OcclusionCullingSettings>,
+    render_device: Option<Res<RenderDevice>>,
+    render_adapter: Option<Res<RenderAdapter>>,
 ) {
     //info!("[load_common_game_data] Starting to load common game data");
 
@@ -1955,13 +2941,39 @@ fn load_common_game_data(
         Smaa::default(),
         // Prepasses for depth (required for some effects and GPU occlusion culling)
         DepthPrepass,
-        // GPU Occlusion Culling - Bevy 0.16 experimental feature
-        // Culls objects hidden behind other objects to improve performance
-        OcclusionCulling,
         // Underwater state tracking for underwater rendering effect
         CameraUnderwaterState::default(),
     )).id();
 
+    // GPU Occlusion Culling - Bevy 0.16 experimental feature
+    // Culls objects hidden behind other objects to improve performance, only
+    // enabled when the adapter supports it, otherwise zone objects fall back
+    // to distance culling in occlusion_culling_fallback_system
+    let gpu_occlusion_culling = if !occlusion_culling_settings.enabled {
+        info!("GPU occlusion culling disabled by configuration");
+        false
+    } else if let Some(reason) = gpu_occlusion_culling_unsupported_reason(
+        render_device.as_deref(),
+        render_adapter.as_deref(),
+    ) {
+        warn!("GPU occlusion culling disabled, {}", reason);
+        false
+    } else {
+        info!("GPU occlusion culling enabled");
+        true
+    };
+    if gpu_occlusion_culling {
+        commands.entity(camera_entity).insert(OcclusionCulling);
+    } else if occlusion_culling_settings.fallback_distance_culling {
+        info!(
+            "Zone objects will be distance culled at {}m",
+            occlusion_culling_settings.fallback_distance
+        );
+    }
+    commands.insert_resource(OcclusionCullingState {
+        gpu_enabled: gpu_occlusion_culling,
+    });
+
     commands.entity(camera_entity).insert((
         // Environment Map Light for richer PBR reflections and lighting
         // The DDS loader is configured to load this texture as a cubemap when the #cube label is used
@@ -1973,8 +2985,6 @@ fn load_common_game_data(
         },
         // Bevy 0.16 Screen Space Reflections
         ScreenSpaceReflections::default(),
-        // Bevy 0.16 Motion Blur
-        MotionBlur::default(),
         // Bevy 0.16 Auto Exposure
         AutoExposure::default(),
         // Bevy 0.16 Contrast Adaptive Sharpening
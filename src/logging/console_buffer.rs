@@ -0,0 +1,177 @@
+//! In-memory buffer of recent log lines for the in-game log console.
+//!
+//! `LogConsoleLayer` is installed with the session logging and copies every
+//! event passing the log filter into a bounded ring buffer, the console UI
+//! pulls new lines from it with `read_new_log_lines`.
+
+use std::collections::VecDeque;
+use std::fmt::Write;
+use std::sync::Mutex;
+
+use chrono::Local;
+use tracing::{field::Visit, Event, Level, Subscriber};
+use tracing_subscriber::{layer::Context, Layer};
+
+/// Maximum number of lines kept, older lines are dropped
+pub const LOG_CONSOLE_CAPACITY: usize = 5000;
+
+#[derive(Clone, Debug)]
+pub struct LogLine {
+    /// Local time the event was logged, `HH:MM:SS.mmm`
+    pub time: String,
+    pub level: Level,
+    pub target: String,
+    pub message: String,
+}
+
+impl std::fmt::Display for LogLine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {:>5} {}: {}", self.time, self.level, self.target, self.message)
+    }
+}
+
+struct LogBuffer {
+    lines: VecDeque<LogLine>,
+    /// Number of lines ever pushed, used by readers to find the lines they have not seen
+    total: u64,
+}
+
+static LOG_BUFFER: Mutex<LogBuffer> = Mutex::new(LogBuffer {
+    lines: VecDeque::new(),
+    total: 0,
+});
+
+fn push_line(buffer: &mut LogBuffer, line: LogLine, capacity: usize) {
+    if buffer.lines.len() >= capacity {
+        buffer.lines.pop_front();
+    }
+    buffer.lines.push_back(line);
+    buffer.total += 1;
+}
+
+fn read_new_lines(buffer: &LogBuffer, seen: u64, out: &mut VecDeque<LogLine>, capacity: usize) -> u64 {
+    let new_lines = (buffer.total - seen.min(buffer.total)).min(buffer.lines.len() as u64) as usize;
+    out.extend(buffer.lines.iter().skip(buffer.lines.len() - new_lines).cloned());
+    while out.len() > capacity {
+        out.pop_front();
+    }
+    buffer.total
+}
+
+/// Append the lines logged since `seen` to `out`, keeping at most
+/// `LOG_CONSOLE_CAPACITY` lines, and return the new `seen` value
+pub fn read_new_log_lines(seen: u64, out: &mut VecDeque<LogLine>) -> u64 {
+    let Ok(buffer) = LOG_BUFFER.lock() else {
+        return seen;
+    };
+    read_new_lines(&buffer, seen, out, LOG_CONSOLE_CAPACITY)
+}
+
+/// The last `count` lines, `None` when the buffer is locked, e.g. when a panic
+/// happened while logging, so a crash report never waits on it
+pub(crate) fn try_recent_log_lines(count: usize) -> Option<Vec<LogLine>> {
+    let buffer = LOG_BUFFER.try_lock().ok()?;
+    let skip = buffer.lines.len().saturating_sub(count);
+    Some(buffer.lines.iter().skip(skip).cloned().collect())
+}
+
+/// Total number of lines logged, cheap to check whether there is anything new
+pub fn log_line_count() -> u64 {
+    LOG_BUFFER.lock().map_or(0, |buffer| buffer.total)
+}
+
+/// Collects the message of an event, other fields are appended as `key=value`
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: String,
+    /// Target of events forwarded from the `log` crate
+    log_target: Option<String>,
+}
+
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+        match field.name() {
+            "message" => self.message = value.to_string(),
+            "log.target" => self.log_target = Some(value.to_string()),
+            name if name.starts_with("log.") => {}
+            name => {
+                write!(self.fields, " {}={}", name, value).ok();
+            }
+        }
+    }
+
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+        match field.name() {
+            "message" => self.message = format!("{:?}", value),
+            name if name.starts_with("log.") => {}
+            name => {
+                write!(self.fields, " {}={:?}", name, value).ok();
+            }
+        }
+    }
+}
+
+/// Tracing layer feeding the log console buffer
+pub struct LogConsoleLayer;
+
+impl<S: Subscriber> Layer<S> for LogConsoleLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+
+        let metadata = event.metadata();
+        let line = LogLine {
+            time: Local::now().format("%H:%M:%S%.3f").to_string(),
+            level: *metadata.level(),
+            target: visitor
+                .log_target
+                .unwrap_or_else(|| metadata.target().to_string()),
+            message: visitor.message + &visitor.fields,
+        };
+
+        if let Ok(mut buffer) = LOG_BUFFER.lock() {
+            push_line(&mut buffer, line, LOG_CONSOLE_CAPACITY);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(message: &str) -> LogLine {
+        LogLine {
+            time: String::new(),
+            level: Level::INFO,
+            target: String::new(),
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_ring_buffer() {
+        let mut buffer = LogBuffer {
+            lines: VecDeque::new(),
+            total: 0,
+        };
+        for index in 0..5 {
+            push_line(&mut buffer, line(&index.to_string()), 3);
+        }
+        assert_eq!(buffer.total, 5);
+        assert_eq!(buffer.lines.len(), 3);
+
+        // A reader which fell behind only gets the lines still in the buffer
+        let mut out = VecDeque::new();
+        let seen = read_new_lines(&buffer, 0, &mut out, 3);
+        assert_eq!(seen, 5);
+        let messages: Vec<&str> = out.iter().map(|line| line.message.as_str()).collect();
+        assert_eq!(messages, ["2", "3", "4"]);
+
+        push_line(&mut buffer, line("5"), 3);
+        let seen = read_new_lines(&buffer, seen, &mut out, 3);
+        assert_eq!(seen, 6);
+        let messages: Vec<&str> = out.iter().map(|line| line.message.as_str()).collect();
+        assert_eq!(messages, ["3", "4", "5"]);
+    }
+}
@@ -0,0 +1,140 @@
+//! Panic hook writing a crash report to the session log folder.
+//!
+//! The report contains the panic message and location, a backtrace, a summary
+//! of the config with credentials left out, the current zone and the most
+//! recent log lines. Nothing in the hook unwraps or blocks on a lock, a panic
+//! inside a panic hook aborts the process without any report.
+
+use std::{
+    any::Any,
+    backtrace::Backtrace,
+    fs::{self, File},
+    io::Write,
+    panic::PanicHookInfo,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, AtomicU16, Ordering},
+        Mutex,
+    },
+};
+
+use chrono::Local;
+
+use super::console_buffer::try_recent_log_lines;
+
+/// Number of recent log lines included in the report
+const CRASH_REPORT_LOG_LINES: usize = 200;
+
+struct CrashContext {
+    report_dir: Option<PathBuf>,
+    config_summary: String,
+}
+
+static CRASH_CONTEXT: Mutex<CrashContext> = Mutex::new(CrashContext {
+    report_dir: None,
+    config_summary: String::new(),
+});
+
+/// Current zone id, 0 when no zone is loaded
+static CURRENT_ZONE_ID: AtomicU16 = AtomicU16::new(0);
+
+/// Set once the first panic is being reported, later panics are usually caused
+/// by the first one and only go to the default hook
+static PANIC_REPORTED: AtomicBool = AtomicBool::new(false);
+
+/// Install the panic hook, reports are written to `report_dir`.
+///
+/// `config_summary` must not contain credentials, it is written to the report as is.
+pub fn install_panic_hook(report_dir: PathBuf, config_summary: String) {
+    if let Ok(mut context) = CRASH_CONTEXT.lock() {
+        context.report_dir = Some(report_dir);
+        context.config_summary = config_summary;
+    }
+
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        if PANIC_REPORTED.swap(true, Ordering::SeqCst) {
+            default_hook(info);
+            return;
+        }
+
+        let report_path = write_crash_report(info);
+        default_hook(info);
+
+        let mut stderr = std::io::stderr();
+        match report_path {
+            Some(path) => writeln!(
+                stderr,
+                "\nThe client crashed. A crash report was written to {}\nPlease include it when reporting this bug.",
+                path.display()
+            ),
+            None => writeln!(stderr, "\nThe client crashed. Failed to write a crash report."),
+        }
+        .ok();
+    }));
+}
+
+/// Update the zone reported in crash reports
+pub fn set_crash_report_zone(zone_id: Option<u16>) {
+    CURRENT_ZONE_ID.store(zone_id.unwrap_or(0), Ordering::Relaxed);
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.as_str()
+    } else {
+        "<non-string panic payload>"
+    }
+}
+
+fn write_crash_report(info: &PanicHookInfo) -> Option<PathBuf> {
+    // Never wait on the lock, the panic may have happened while it was held
+    let (report_dir, config_summary) = match CRASH_CONTEXT.try_lock() {
+        Ok(context) => (
+            context.report_dir.clone().unwrap_or_else(|| PathBuf::from("logs")),
+            context.config_summary.clone(),
+        ),
+        Err(_) => (PathBuf::from("logs"), String::new()),
+    };
+    fs::create_dir_all(&report_dir).ok()?;
+
+    let now = Local::now();
+    let path = report_dir.join(format!("crash_report_{}.txt", now.format("%Y-%m-%d_%H-%M-%S")));
+    let mut file = File::create(&path).ok()?;
+
+    let thread = std::thread::current();
+    writeln!(file, "rose-offline-client crash report").ok()?;
+    writeln!(file, "Time: {}", now.to_rfc3339()).ok();
+    writeln!(file, "Version: {}", env!("CARGO_PKG_VERSION")).ok();
+    writeln!(file, "OS: {} {}", std::env::consts::OS, std::env::consts::ARCH).ok();
+    writeln!(file, "Thread: {}", thread.name().unwrap_or("<unnamed>")).ok();
+    writeln!(file, "Panic: {}", panic_message(info.payload())).ok();
+    if let Some(location) = info.location() {
+        writeln!(file, "Location: {}:{}:{}", location.file(), location.line(), location.column()).ok();
+    }
+    match CURRENT_ZONE_ID.load(Ordering::Relaxed) {
+        0 => writeln!(file, "Zone: none"),
+        zone_id => writeln!(file, "Zone: {}", zone_id),
+    }
+    .ok();
+
+    writeln!(file, "\n== Config ==\n{}", config_summary).ok();
+    writeln!(file, "== Backtrace ==\n{}", Backtrace::force_capture()).ok();
+
+    writeln!(file, "\n== Recent log ==").ok();
+    match try_recent_log_lines(CRASH_REPORT_LOG_LINES) {
+        Some(lines) => {
+            for line in lines {
+                writeln!(file, "{}", line).ok();
+            }
+        }
+        None => {
+            writeln!(file, "<log buffer unavailable>").ok();
+        }
+    }
+
+    file.flush().ok();
+    Some(path)
+}
@@ -5,11 +5,17 @@
 //! folder containing:
 //! - `session.json`: Session metadata
 //! - `structured.jsonl`: JSON Lines format log entries
+//!
+//! Recent lines are also kept in memory for the in-game log console, and a
+//! panic hook writes crash reports to the session folder.
 
+mod console_buffer;
+mod crash_report;
 mod json_format;
 
 use std::fs::{self, File, OpenOptions};
 use std::path::PathBuf;
+use std::sync::OnceLock;
 use std::time::SystemTime;
 
 use chrono::{DateTime, Local, Utc};
@@ -18,12 +24,18 @@ use tracing_appender::non_blocking::{NonBlocking, WorkerGuard};
 use tracing_subscriber::{
     fmt::{self, format::FmtSpan},
     layer::SubscriberExt,
+    reload,
     util::SubscriberInitExt,
-    EnvFilter, Layer, Registry,
+    EnvFilter, Registry,
 };
 
+pub use console_buffer::{log_line_count, read_new_log_lines, LogConsoleLayer, LogLine, LOG_CONSOLE_CAPACITY};
+pub use crash_report::{install_panic_hook, set_crash_report_zone};
 pub use json_format::TagExtractingJsonFormat;
 
+/// Handle to replace the filter of the running session
+static FILTER_HANDLE: OnceLock<reload::Handle<EnvFilter, Registry>> = OnceLock::new();
+
 /// Session metadata written to `session.json`
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SessionInfo {
@@ -95,7 +107,7 @@ pub struct LoggingGuard {
 pub struct LoggingConfig {
     /// Base directory for log folders (default: "logs")
     pub log_directory: PathBuf,
-    /// Minimum log level (default: "debug")
+    /// Log filter, a level optionally followed by `module=level` overrides (default: "info")
     pub level: String,
     /// Whether to also output to console (default: true)
     pub console_output: bool,
@@ -118,7 +130,8 @@ impl Default for LoggingConfig {
 /// 2. Sets up JSON Lines output to `structured.jsonl`
 /// 3. Creates a `session.json` metadata file
 /// 4. Maintains console output simultaneously
-/// 5. Uses non-blocking writes for performance
+/// 5. Keeps recent lines in memory for the in-game log console
+/// 6. Uses non-blocking writes for performance
 ///
 /// # Arguments
 /// * `mode` - The mode the client is running in (e.g., "Game", "ZoneViewer")
@@ -165,33 +178,38 @@ pub fn init_session_logging(
 
     let (non_blocking, file_guard) = tracing_appender::non_blocking(file);
 
-    // Build the subscriber with layers
+    // Build the subscriber with layers, the filter is shared by all outputs and
+    // can be replaced at runtime through `set_log_filter`
     let env_filter = EnvFilter::try_from_default_env()
         .unwrap_or_else(|_| EnvFilter::new(&config.level));
+    let (filter_layer, filter_handle) = reload::Layer::new(env_filter);
+    FILTER_HANDLE.set(filter_handle).ok();
 
     // JSON Lines layer for file output with tag extraction
     let json_layer = fmt::layer()
         .event_format(TagExtractingJsonFormat::new())
         .fmt_fields(tracing_subscriber::fmt::format::JsonFields::new())
         .with_writer(non_blocking)
-        .with_ansi(false)
-        .with_filter(env_filter.clone());
+        .with_ansi(false);
 
     if config.console_output {
         // Console layer with standard formatting
         let console_layer = fmt::layer()
             .with_target(true)
             .with_thread_ids(false)
-            .with_ansi(true)
-            .with_filter(env_filter);
+            .with_ansi(true);
 
         tracing_subscriber::registry()
+            .with(filter_layer)
             .with(json_layer)
             .with(console_layer)
+            .with(LogConsoleLayer)
             .init();
     } else {
         tracing_subscriber::registry()
+            .with(filter_layer)
             .with(json_layer)
+            .with(LogConsoleLayer)
             .init();
     }
 
@@ -222,6 +240,15 @@ pub fn init_bevy_logging(
     init_session_logging(mode, config, session_config)
 }
 
+/// Replace the log filter of the running session, e.g. `info,rose_offline_client::zone_loader=warn`
+pub fn set_log_filter(filter: &str) -> Result<(), anyhow::Error> {
+    let handle = FILTER_HANDLE
+        .get()
+        .ok_or_else(|| anyhow::anyhow!("Session logging is not initialized"))?;
+    handle.reload(EnvFilter::try_new(filter)?)?;
+    Ok(())
+}
+
 /// Get the path to the current session's log directory (if initialized)
 pub fn get_session_log_path() -> Option<PathBuf> {
     // This is a placeholder - in a real implementation, you'd store this
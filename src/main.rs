@@ -2,11 +2,9 @@
 
 use std::path::Path;
 
-use rose_data::ZoneId;
 use rose_offline_client::{
-    load_config, run_game, run_map_editor, run_model_viewer, run_zone_viewer, Config, FilesystemDeviceConfig,
-    SystemsConfig,
-    logging::{init_session_logging, LoggingConfig, LoggingGuard},
+    load_config, run_startup_mode, Config, FilesystemDeviceConfig, StartupMode,
+    logging::{init_session_logging, install_panic_hook, LoggingConfig, LoggingGuard},
 };
 
 fn main() {
@@ -49,10 +47,23 @@ fn main() {
                 .help("Optional path to extracted data, any files here override ones in data.idx")
                 .takes_value(true),
         )
+        .arg(
+            clap::Arg::new("data-zip")
+                .long("data-zip")
+                .help("Path to a .zip archive of game data")
+                .takes_value(true),
+        )
+        .arg(
+            clap::Arg::new("data-overlay")
+                .long("data-overlay")
+                .help("Path to a mod directory whose files override all other game data, may be repeated")
+                .takes_value(true)
+                .multiple_occurrences(true),
+        )
         .arg(
             clap::Arg::new("zone")
                 .long("zone")
-                .help("Runs as zone viewer, loading the specified zone")
+                .help("Zone to load, runs as zone viewer unless another mode is selected")
                 .takes_value(true),
         )
         .arg(
@@ -70,6 +81,17 @@ fn main() {
                 .long("map-editor")
                 .help("Run map editor mode"),
         )
+        .arg(
+            clap::Arg::new("benchmark")
+                .long("benchmark")
+                .help("Fly the benchmark path through the zone, print frame times and exit"),
+        )
+        .arg(
+            clap::Arg::new("benchmark-output")
+                .long("benchmark-output")
+                .help("Path to write the benchmark summary to as JSON")
+                .takes_value(true),
+        )
         .arg(
             clap::Arg::new("disable-vsync")
                 .long("disable-vsync")
@@ -223,6 +245,32 @@ fn main() {
         config.graphics.use_new_terrain = true;
     }
 
+    if matches.is_present("model-viewer") {
+        config.startup.mode = StartupMode::ModelViewer;
+    } else if matches.is_present("zone-viewer") {
+        config.startup.mode = StartupMode::ZoneViewer;
+    } else if matches.is_present("map-editor") {
+        config.startup.mode = StartupMode::MapEditor;
+    } else if matches.is_present("benchmark") {
+        config.startup.mode = StartupMode::Benchmark;
+    }
+
+    if let Some(output_path) = matches.value_of("benchmark-output") {
+        config.benchmark.output_path = Some(output_path.into());
+    }
+
+    if let Some(zone) = matches.value_of("zone") {
+        match zone.parse::<u16>() {
+            Ok(zone_id) => {
+                config.startup.zone_id = Some(zone_id);
+                if config.startup.mode == StartupMode::Game {
+                    config.startup.mode = StartupMode::ZoneViewer;
+                }
+            }
+            Err(_) => println!("Ignoring invalid zone id {}", zone),
+        }
+    }
+
     if let Some(version) = matches.value_of("data-version") {
         config.game.data_version = version.to_string();
     }
@@ -263,6 +311,13 @@ fn main() {
             .push(FilesystemDeviceConfig::Vfs(vfs_path.into()));
     }
 
+    if let Some(zip_path) = matches.value_of("data-zip") {
+        config
+            .filesystem
+            .devices
+            .push(FilesystemDeviceConfig::Zip(zip_path.into()));
+    }
+
     if let Some(directory_path) = matches.value_of("data-path") {
         config
             .filesystem
@@ -270,7 +325,21 @@ fn main() {
             .push(FilesystemDeviceConfig::Directory(directory_path.into()));
     }
 
-    if config.filesystem.devices.is_empty() && Path::exists(Path::new("data.idx")) {
+    if let Some(overlay_paths) = matches.values_of("data-overlay") {
+        for overlay_path in overlay_paths {
+            config
+                .filesystem
+                .devices
+                .push(FilesystemDeviceConfig::Overlay(overlay_path.into()));
+        }
+    }
+
+    let has_game_data_device = config
+        .filesystem
+        .devices
+        .iter()
+        .any(|device| !matches!(device, FilesystemDeviceConfig::Overlay(_)));
+    if !has_game_data_device && Path::exists(Path::new("data.idx")) {
         config
             .filesystem
             .devices
@@ -280,49 +349,24 @@ fn main() {
     println!("Determining which mode to run...");
     
     // Determine mode name for logging
-    let mode = if matches.is_present("model-viewer") {
-        "ModelViewer"
-    } else if matches.is_present("zone-viewer") {
-        "ZoneViewer"
-    } else if matches.is_present("map-editor") {
-        "MapEditor"
-    } else {
-        "Game"
-    };
+    let mode = config.startup.mode.name();
     
     // Initialize session-based logging
     // The guard must be kept alive for the duration of the application
     let _logging_guard = init_session_logging(
         mode,
-        None, // Use default logging config
+        Some(LoggingConfig {
+            level: config.logging.filter(),
+            ..Default::default()
+        }),
         None, // No additional session config
     ).expect("Failed to initialize logging");
     
     log::info!("[LOGGING] Session logging initialized for mode: {}", mode);
+
+    // Crashes write a report with the panic, backtrace and recent log to the session folder
+    install_panic_hook(_logging_guard.session_dir.clone(), config.summary());
     
-    if matches.is_present("model-viewer") {
-        println!("Running in model viewer mode");
-        run_model_viewer(&config);
-    } else if matches.is_present("zone-viewer") {
-        println!("Running in zone viewer mode");
-        run_zone_viewer(
-            &config,
-            matches
-                .value_of("zone")
-                .and_then(|str| str.parse::<u16>().ok())
-                .and_then(ZoneId::new),
-        );
-    } else if matches.is_present("map-editor") {
-        println!("Running in map editor mode");
-        run_map_editor(
-            &config,
-            matches
-                .value_of("zone")
-                .and_then(|str| str.parse::<u16>().ok())
-                .and_then(ZoneId::new),
-        );
-    } else {
-        println!("Running in game mode");
-        run_game(&config, SystemsConfig::default());
-    }
+    println!("Running in {} mode", mode);
+    run_startup_mode(&config);
 }
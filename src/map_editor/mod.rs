@@ -68,6 +68,7 @@ pub use resources::{
     DeletedZoneObjects,
     EditorGridSettings,
     EditorMode,
+    EditorVisibility,
     HierarchyFilter,
     MapEditorState,
     ModelCategory,
@@ -85,16 +86,27 @@ pub use save::{
 };
 
 use bevy::prelude::*;
+use systems::arrange_system::ArrangeSystemPlugin;
+use systems::camera_focus_system::CameraFocusPlugin;
 use systems::duplicate_system::DuplicateSystemPlugin;
 use systems::grid_system::EditorGridPlugin;
+use systems::ifo_import_system::IfoImportPlugin;
 use systems::keyboard_shortcuts_system::KeyboardShortcutsPlugin;
 use systems::load_models_system;
+use systems::measure_system::MeasureToolPlugin;
 use systems::model_placement_system::ModelPlacementPlugin;
+use systems::play_test_system::PlayTestPlugin;
 use systems::property_update_system::PropertyUpdatePlugin;
 use systems::selection_highlight_system::SelectionHighlightPlugin;
 use systems::selection_system::EditorSelectionPlugin;
+use systems::terrain_block_system::TerrainBlockPlugin;
+use systems::terrain_sculpt_system::TerrainSculptPlugin;
 use systems::transform_gizmo_system::TransformGizmoPlugin;
 use systems::undo_system::UndoRedoPlugin;
+use systems::unsaved_changes_system::UnsavedChangesPlugin;
+use systems::visibility_system::EditorVisibilityPlugin;
+use systems::warp_link_system::WarpLinkPlugin;
+use systems::zone_validation_system::ZoneValidationPlugin;
 use ui::EditorUiPlugin;
 use ui::zone_list_panel::ZoneListPanelState;
 use crate::systems::{FreeCamera, OrbitCamera};
@@ -124,11 +136,23 @@ impl Plugin for MapEditorPlugin {
             .add_plugins(PropertyUpdatePlugin)
             .add_plugins(KeyboardShortcutsPlugin)
             .add_plugins(UndoRedoPlugin)
+            .add_plugins(CameraFocusPlugin)
+            .add_plugins(MeasureToolPlugin)
+            .add_plugins(EditorVisibilityPlugin)
+            .add_plugins(WarpLinkPlugin)
+            .add_plugins(ZoneValidationPlugin)
+            .add_plugins(PlayTestPlugin)
+            .add_plugins(TerrainBlockPlugin)
+            .add_plugins(TerrainSculptPlugin)
             // Phase 2.5: Model management plugins
             .add_plugins(ModelPlacementPlugin)
             .add_plugins(DuplicateSystemPlugin)
+            .add_plugins(ArrangeSystemPlugin)
+            .add_plugins(IfoImportPlugin)
             // Phase 2.6: Save functionality
-            .add_plugins(save::SavePlugin);
+            .add_plugins(save::SavePlugin)
+            .add_plugins(save::AutosavePlugin)
+            .add_plugins(UnsavedChangesPlugin);
         
         // Phase 2.5: Load available models on startup (after GameData is loaded)
         app.add_systems(Update, load_models_system::load_available_models_system);
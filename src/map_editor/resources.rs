@@ -3,10 +3,21 @@
 //! This module contains the resource definitions for the map editor system.
 
 use bevy::prelude::*;
-use std::collections::HashSet;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
 
-/// Maximum number of undo actions to keep in history
-const MAX_UNDO_HISTORY: usize = 100;
+use rose_data::ZoneId;
+
+use crate::components::ZoneObject;
+use crate::resources::PersistentSettings;
+
+/// Default number of undo actions to keep in history
+pub const DEFAULT_UNDO_HISTORY: usize = 100;
+
+/// Maximum number of terrain cells kept in the undo and redo stacks together,
+/// older terrain edits are dropped beyond it
+pub const MAX_TERRAIN_HISTORY_CELLS: usize = 1_000_000;
 
 /// Message to request duplication of selected entities
 #[derive(Message, Debug, Clone)]
@@ -35,8 +46,55 @@ impl DuplicateSelectedEvent {
     }
 }
 
+/// Axis used by the align and distribute tools
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArrangeAxis {
+    X,
+    Y,
+    Z,
+}
+
+impl ArrangeAxis {
+    pub fn index(self) -> usize {
+        match self {
+            ArrangeAxis::X => 0,
+            ArrangeAxis::Y => 1,
+            ArrangeAxis::Z => 2,
+        }
+    }
+}
+
+/// Which edge of the selection bounds objects are aligned to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlignAnchor {
+    Min,
+    Center,
+    Max,
+}
+
+/// Message to arrange the selected entities, each produces a single undo entry
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub enum ArrangeSelectedEvent {
+    /// Move every object to the min, center or max of the selection on an axis
+    Align { axis: ArrangeAxis, anchor: AlignAnchor },
+    /// Space objects evenly between the two outermost objects on an axis
+    Distribute { axis: ArrangeAxis },
+    /// Drop objects onto the terrain below them, optionally tilting them to
+    /// match the slope
+    SnapToTerrain { align_to_normal: bool },
+}
+
+/// Message to add the objects of an IFO file to the current zone
+#[derive(Message, Debug, Clone)]
+pub struct ImportIfoEvent {
+    /// IFO file to import, relative paths are relative to the game data folder
+    pub path: PathBuf,
+    /// Offset added to every imported object, in world units
+    pub offset: Vec3,
+}
+
 /// Main resource for map editor state
-#[derive(Resource, Default)]
+#[derive(Resource)]
 pub struct MapEditorState {
     /// Whether the map editor is enabled
     pub enabled: bool,
@@ -44,6 +102,16 @@ pub struct MapEditorState {
     /// Currently selected entities (multi-select support)
     pub selected_entities: HashSet<Entity>,
     
+    /// Selected terrain block, selected instead of objects by clicking the terrain
+    pub selected_terrain_block: Option<TerrainBlock>,
+    
+    /// Heightmap copied from a terrain block, pasted onto another block
+    pub heightmap_clipboard: Option<HeightmapClipboard>,
+    
+    /// Terrain blocks changed since the zone was opened, their HIM files
+    /// must be written when saving
+    pub dirty_terrain_blocks: HashSet<(u32, u32)>,
+    
     /// Current editor mode
     pub editor_mode: EditorMode,
     
@@ -68,11 +136,86 @@ pub struct MapEditorState {
     /// Filter for hierarchy panel
     pub hierarchy_filter: String,
     
+    /// Object type shown in the hierarchy panel
+    pub hierarchy_type_filter: HierarchyFilter,
+    
+    /// Entity the editor camera should fly to, applied by `camera_focus_system`
+    pub pending_focus: Option<Entity>,
+    
+    /// Points picked with the measuring tool in Measure mode
+    pub measure_tool: MeasureTool,
+    
+    /// Brush raising and lowering the terrain in Sculpt mode
+    pub terrain_brush: TerrainBrush,
+    
+    /// Settings of the File > Import IFO dialog
+    pub ifo_import: IfoImportDialog,
+    
+    /// Whether warp gates show lines and labels to their destinations
+    pub show_warp_links: bool,
+    
+    /// Zone statistics and issues panel
+    pub zone_validation: ZoneValidation,
+    
+    /// Periodic recovery snapshots of the edited zone
+    pub autosave: Autosave,
+    
+    /// Operation waiting for the user to save or discard unsaved changes,
+    /// applied by `unsaved_changes_system`
+    pub pending_discard: Option<PendingDiscard>,
+    
+    /// Walking the zone with a stand-in player, see `play_test_system`
+    pub play_test: PlayTest,
+    
     /// Undo stack for editor actions
-    pub undo_stack: Vec<EditorAction>,
+    pub undo_stack: Vec<EditorHistoryEntry>,
     
     /// Redo stack for editor actions
-    pub redo_stack: Vec<EditorAction>,
+    pub redo_stack: Vec<EditorHistoryEntry>,
+    
+    /// Maximum number of actions kept in each of the undo and redo stacks
+    pub history_depth: usize,
+    
+    /// Whether the undo history panel is visible
+    pub show_history: bool,
+    
+    /// Undo or redo requested by the UI, applied by `undo_redo_system`
+    pub pending_history_step: Option<HistoryStep>,
+}
+
+impl Default for MapEditorState {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            selected_entities: HashSet::new(),
+            selected_terrain_block: None,
+            heightmap_clipboard: None,
+            dirty_terrain_blocks: HashSet::new(),
+            editor_mode: EditorMode::default(),
+            transform_space: TransformSpace::default(),
+            snap_to_grid: false,
+            grid_size: 0.0,
+            show_grid: false,
+            is_modified: false,
+            model_browser_search: String::new(),
+            hierarchy_filter: String::new(),
+            hierarchy_type_filter: HierarchyFilter::default(),
+            pending_focus: None,
+            measure_tool: MeasureTool::default(),
+            terrain_brush: TerrainBrush::default(),
+            ifo_import: IfoImportDialog::default(),
+            show_warp_links: false,
+            zone_validation: ZoneValidation::default(),
+            autosave: Autosave::default(),
+            pending_discard: None,
+            play_test: PlayTest::default(),
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            history_depth: DEFAULT_UNDO_HISTORY,
+            show_history: false,
+            pending_history_step: None,
+        }
+    }
 }
 
 impl MapEditorState {
@@ -81,6 +224,9 @@ impl MapEditorState {
         Self {
             enabled: true,
             selected_entities: HashSet::new(),
+            selected_terrain_block: None,
+            heightmap_clipboard: None,
+            dirty_terrain_blocks: HashSet::new(),
             editor_mode: EditorMode::default(),
             transform_space: TransformSpace::default(),
             snap_to_grid: true,
@@ -89,14 +235,28 @@ impl MapEditorState {
             is_modified: false,
             model_browser_search: String::new(),
             hierarchy_filter: String::new(),
+            hierarchy_type_filter: HierarchyFilter::default(),
+            pending_focus: None,
+            measure_tool: MeasureTool::default(),
+            terrain_brush: TerrainBrush::default(),
+            ifo_import: IfoImportDialog::default(),
+            show_warp_links: false,
+            zone_validation: ZoneValidation::default(),
+            autosave: Autosave::default(),
+            pending_discard: None,
+            play_test: PlayTest::default(),
             undo_stack: Vec::new(),
             redo_stack: Vec::new(),
+            history_depth: DEFAULT_UNDO_HISTORY,
+            show_history: false,
+            pending_history_step: None,
         }
     }
     
     /// Clear all selected entities
     pub fn clear_selection(&mut self) {
         self.selected_entities.clear();
+        self.selected_terrain_block = None;
     }
     
     /// Add an entity to the selection
@@ -135,40 +295,100 @@ impl MapEditorState {
     
     /// Push an action to the undo stack and clear redo stack
     pub fn push_action(&mut self, action: EditorAction) {
-        self.undo_stack.push(action);
-        
-        // Limit undo history size
-        if self.undo_stack.len() > MAX_UNDO_HISTORY {
-            self.undo_stack.remove(0);
-        }
+        let label = action.label();
+        self.push_named_action(label, action);
+    }
+    
+    /// Push an action with a custom history label to the undo stack and clear redo stack
+    pub fn push_named_action(&mut self, label: impl Into<String>, action: EditorAction) {
+        self.push_undo(EditorHistoryEntry {
+            label: label.into(),
+            action,
+        });
         
         // Clear redo stack when new action is performed
         self.redo_stack.clear();
+    }
+    
+    /// Push an entry to the undo stack without clearing the redo stack, used when redoing
+    pub fn push_undo(&mut self, entry: EditorHistoryEntry) {
+        self.undo_stack.push(entry);
+        self.mark_modified();
         
-        // Mark as modified
+        // Limit undo history size
+        let excess = self.undo_stack.len().saturating_sub(self.history_depth.max(1));
+        self.undo_stack.drain(..excess);
+        self.trim_terrain_history();
+    }
+    
+    /// Request an operation that discards the edited zone, asking the user
+    /// first when there are unsaved changes
+    pub fn request_discard(&mut self, action: DiscardAction) {
+        self.pending_discard = Some(PendingDiscard {
+            action,
+            step: DiscardStep::Asking,
+            error: None,
+        });
+    }
+    
+    /// Mark the zone as changed since the last save and the last autosave
+    pub fn mark_modified(&mut self) {
         self.is_modified = true;
+        self.autosave.dirty = true;
     }
     
     /// Pop an action from the undo stack
-    pub fn pop_undo(&mut self) -> Option<EditorAction> {
+    pub fn pop_undo(&mut self) -> Option<EditorHistoryEntry> {
         self.undo_stack.pop()
     }
     
     /// Push an action to the redo stack
-    pub fn push_redo(&mut self, action: EditorAction) {
-        self.redo_stack.push(action);
+    pub fn push_redo(&mut self, entry: EditorHistoryEntry) {
+        self.redo_stack.push(entry);
+        self.mark_modified();
         
         // Limit redo history size
-        if self.redo_stack.len() > MAX_UNDO_HISTORY {
-            self.redo_stack.remove(0);
-        }
+        let excess = self.redo_stack.len().saturating_sub(self.history_depth.max(1));
+        self.redo_stack.drain(..excess);
+        self.trim_terrain_history();
     }
     
     /// Pop an action from the redo stack
-    pub fn pop_redo(&mut self) -> Option<EditorAction> {
+    pub fn pop_redo(&mut self) -> Option<EditorHistoryEntry> {
         self.redo_stack.pop()
     }
     
+    /// Drop the oldest terrain edits once the history holds more than
+    /// `MAX_TERRAIN_HISTORY_CELLS` terrain cells
+    pub fn trim_terrain_history(&mut self) {
+        let terrain_cells = |stack: &[EditorHistoryEntry]| -> usize {
+            stack.iter().map(|entry| entry.action.terrain_cell_count()).sum()
+        };
+        let mut total = terrain_cells(&self.undo_stack) + terrain_cells(&self.redo_stack);
+        
+        // The bottom of each stack is the furthest away from the current state
+        for stack in [&mut self.undo_stack, &mut self.redo_stack] {
+            while total > MAX_TERRAIN_HISTORY_CELLS {
+                let Some(index) = stack
+                    .iter()
+                    .position(|entry| entry.action.terrain_cell_count() > 0)
+                else {
+                    break;
+                };
+                total -= stack.remove(index).action.terrain_cell_count();
+            }
+        }
+    }
+    
+    /// Drop the oldest history once `history_depth` has been lowered
+    pub fn trim_history(&mut self) {
+        let depth = self.history_depth.max(1);
+        let excess = self.undo_stack.len().saturating_sub(depth);
+        self.undo_stack.drain(..excess);
+        let excess = self.redo_stack.len().saturating_sub(depth);
+        self.redo_stack.drain(..excess);
+    }
+    
     /// Check if undo is available
     pub fn can_undo(&self) -> bool {
         !self.undo_stack.is_empty()
@@ -186,6 +406,20 @@ impl MapEditorState {
     }
 }
 
+/// Undo or redo a number of steps through the history
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistoryStep {
+    Undo(usize),
+    Redo(usize),
+}
+
+/// An action in the undo or redo stack with its label for the history panel
+#[derive(Debug, Clone)]
+pub struct EditorHistoryEntry {
+    pub label: String,
+    pub action: EditorAction,
+}
+
 /// Editor action for undo/redo system
 #[derive(Debug, Clone)]
 pub enum EditorAction {
@@ -225,6 +459,229 @@ pub enum EditorAction {
     AddEntities {
         entities: Vec<Entity>,
     },
+    /// Terrain heightmap cells were changed, usually by one brush stroke
+    TerrainEdit {
+        cells: Vec<TerrainCellDelta>,
+    },
+}
+
+impl EditorAction {
+    /// Human readable name of the action, e.g. "Move" or "Delete 3 objects"
+    pub fn label(&self) -> String {
+        match self {
+            EditorAction::TransformEntity {
+                old_transform,
+                new_transform,
+                ..
+            } => transform_label(old_transform, new_transform).to_string(),
+            EditorAction::TransformEntities { entities } => {
+                let verb = entities
+                    .first()
+                    .map_or("Transform", |(_, old, new)| transform_label(old, new));
+                format!("{} {} objects", verb, entities.len())
+            }
+            EditorAction::AddEntity { .. } => "Place object".to_string(),
+            EditorAction::AddEntities { entities } => format!("Add {} objects", entities.len()),
+            EditorAction::DeleteEntity { entity_type, .. } => format!("Delete {}", entity_type),
+            EditorAction::DeleteEntities { entities } => format!("Delete {} objects", entities.len()),
+            EditorAction::ModifyComponent { component_type, .. } => {
+                format!("Edit {}", component_type)
+            }
+            EditorAction::TerrainEdit { cells } => format!("Sculpt terrain ({} cells)", cells.len()),
+        }
+    }
+    
+    /// Entities the action was applied to
+    pub fn entities(&self) -> Vec<Entity> {
+        match self {
+            EditorAction::TransformEntity { entity, .. }
+            | EditorAction::AddEntity { entity }
+            | EditorAction::DeleteEntity { entity, .. }
+            | EditorAction::ModifyComponent { entity, .. } => vec![*entity],
+            EditorAction::TransformEntities { entities } => {
+                entities.iter().map(|(entity, ..)| *entity).collect()
+            }
+            EditorAction::DeleteEntities { entities } => {
+                entities.iter().map(|(entity, ..)| *entity).collect()
+            }
+            EditorAction::AddEntities { entities } => entities.clone(),
+            EditorAction::TerrainEdit { .. } => Vec::new(),
+        }
+    }
+    
+    /// Number of terrain cells stored by the action
+    pub fn terrain_cell_count(&self) -> usize {
+        match self {
+            EditorAction::TerrainEdit { cells } => cells.len(),
+            _ => 0,
+        }
+    }
+}
+
+/// Terrain data changed by a terrain edit. The editor has no tile painting,
+/// so tilemap edits are not recorded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TerrainLayer {
+    /// Heightmap vertex, value is the height in HIM units (centimetres)
+    Height,
+}
+
+/// A single cell of a terrain block
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TerrainCell {
+    pub block_x: u32,
+    pub block_y: u32,
+    pub layer: TerrainLayer,
+    /// Index of the cell in the block's heightmap
+    pub index: usize,
+}
+
+/// Value of a terrain cell before and after an edit
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TerrainCellDelta {
+    pub cell: TerrainCell,
+    pub old_value: f32,
+    pub new_value: f32,
+}
+
+/// Message to write terrain cell values when a terrain edit is undone or redone.
+/// The terrain editor applies the values to the zone's heightmaps and
+/// regenerates the mesh and collider of the changed blocks.
+#[derive(Message, Debug, Clone)]
+pub struct TerrainEditEvent {
+    pub cells: Vec<(TerrainCell, f32)>,
+}
+
+impl TerrainEditEvent {
+    /// Blocks that need their mesh and collider regenerated
+    pub fn blocks(&self) -> HashSet<(u32, u32)> {
+        self.cells
+            .iter()
+            .map(|(cell, _)| (cell.block_x, cell.block_y))
+            .collect()
+    }
+}
+
+/// Cells changed by a terrain brush stroke in progress, grouped into one
+/// undo entry when the mouse is released
+#[derive(Default, Debug, Clone)]
+pub struct TerrainStroke {
+    /// Value of each cell before the stroke started and its current value
+    cells: HashMap<TerrainCell, (f32, f32)>,
+    /// Order cells were first changed in
+    order: Vec<TerrainCell>,
+}
+
+impl TerrainStroke {
+    /// Record a change of a cell, keeping the value it had before the stroke
+    pub fn record(&mut self, cell: TerrainCell, old_value: f32, new_value: f32) {
+        match self.cells.get_mut(&cell) {
+            Some((_, value)) => *value = new_value,
+            None => {
+                self.cells.insert(cell, (old_value, new_value));
+                self.order.push(cell);
+            }
+        }
+    }
+    
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+    
+    /// End the stroke, returning the undo action for the cells that ended
+    /// with a different value
+    pub fn finish(&mut self) -> Option<EditorAction> {
+        let cells = self
+            .order
+            .drain(..)
+            .filter_map(|cell| {
+                let (old_value, new_value) = self.cells.remove(&cell)?;
+                (old_value != new_value).then_some(TerrainCellDelta {
+                    cell,
+                    old_value,
+                    new_value,
+                })
+            })
+            .collect::<Vec<_>>();
+        self.cells.clear();
+        
+        (!cells.is_empty()).then_some(EditorAction::TerrainEdit { cells })
+    }
+}
+
+/// Brush raising the terrain under the cursor in Sculpt mode, or lowering it
+/// with Shift held
+#[derive(Debug, Clone)]
+pub struct TerrainBrush {
+    /// Radius in metres, the brush fades out towards its edge
+    pub radius: f32,
+    /// Metres per second the terrain moves at the brush centre
+    pub strength: f32,
+    /// Terrain point under the cursor
+    pub hover_point: Option<Vec3>,
+    /// Cells changed since the mouse was pressed
+    pub stroke: TerrainStroke,
+}
+
+impl Default for TerrainBrush {
+    fn default() -> Self {
+        Self {
+            radius: 10.0,
+            strength: 2.0,
+            hover_point: None,
+            stroke: TerrainStroke::default(),
+        }
+    }
+}
+
+/// A terrain block entity and its position in the zone's 64x64 block grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TerrainBlock {
+    pub entity: Entity,
+    pub block_x: u32,
+    pub block_y: u32,
+}
+
+/// Operation on a whole terrain block from the properties panel
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TerrainBlockOperation {
+    /// Set every heightmap vertex to this height in metres
+    Flatten(f32),
+    /// Copy the heightmap to the clipboard
+    CopyHeightmap,
+    /// Replace the heightmap with the one on the clipboard
+    PasteHeightmap,
+}
+
+/// Message to apply an operation to a terrain block, handled by `terrain_block_system`
+#[derive(Message, Debug, Clone, Copy)]
+pub struct TerrainBlockEvent {
+    pub block: TerrainBlock,
+    pub operation: TerrainBlockOperation,
+}
+
+/// Heightmap copied from a terrain block
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeightmapClipboard {
+    pub block_x: u32,
+    pub block_y: u32,
+    pub width: u32,
+    pub height: u32,
+    /// Heights in HIM units, row by row
+    pub heights: Vec<f32>,
+}
+
+/// Name of a transform change by which part of the transform changed
+fn transform_label(old: &Transform, new: &Transform) -> &'static str {
+    let moved = old.translation != new.translation;
+    let rotated = old.rotation != new.rotation;
+    let scaled = old.scale != new.scale;
+    match (moved, rotated, scaled) {
+        (true, false, false) => "Move",
+        (false, true, false) => "Rotate",
+        (false, false, true) => "Scale",
+        _ => "Transform",
+    }
 }
 
 /// Editor mode for the map editor
@@ -237,6 +694,8 @@ pub enum EditorMode {
     Scale,
     Add,
     Delete,
+    Measure,
+    Sculpt,
 }
 
 impl EditorMode {
@@ -249,8 +708,285 @@ impl EditorMode {
             EditorMode::Scale => "Scale",
             EditorMode::Add => "Add",
             EditorMode::Delete => "Delete",
+            EditorMode::Measure => "Measure",
+            EditorMode::Sculpt => "Sculpt",
+        }
+    }
+}
+
+/// Distances over a set of measured points, in metres
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Measurement {
+    /// Straight line length summed over every segment
+    pub distance: f32,
+    /// Length ignoring height, summed over every segment
+    pub horizontal: f32,
+    /// Height of the last point above the first
+    pub height: f32,
+}
+
+impl Measurement {
+    /// Measure a path through the points, `None` with fewer than two points
+    pub fn between(points: &[Vec3]) -> Option<Self> {
+        let (first, last) = (points.first()?, points.last()?);
+        if points.len() < 2 {
+            return None;
+        }
+        
+        let mut measurement = Measurement {
+            height: last.y - first.y,
+            ..Default::default()
+        };
+        for segment in points.windows(2) {
+            let delta = segment[1] - segment[0];
+            measurement.distance += delta.length();
+            measurement.horizontal += delta.with_y(0.0).length();
+        }
+        Some(measurement)
+    }
+}
+
+/// State of the measuring tool
+#[derive(Default, Debug, Clone)]
+pub struct MeasureTool {
+    /// Sum any number of segments instead of measuring between two points
+    pub path_mode: bool,
+    /// Points picked on the terrain, in world space
+    pub points: Vec<Vec3>,
+    /// Terrain point under the cursor, previews the next segment
+    pub hover_point: Option<Vec3>,
+}
+
+impl MeasureTool {
+    /// Add a picked point, a finished ruler measurement starts over
+    pub fn add_point(&mut self, point: Vec3) {
+        if !self.path_mode && self.points.len() >= 2 {
+            self.points.clear();
+        }
+        self.points.push(point);
+    }
+    
+    /// Whether more points are expected before the measurement is complete
+    pub fn is_in_progress(&self) -> bool {
+        !self.points.is_empty() && (self.path_mode || self.points.len() < 2)
+    }
+    
+    /// Cancel the current measurement
+    pub fn cancel(&mut self) {
+        self.points.clear();
+    }
+    
+    /// Points to measure, including the cursor while still in progress
+    pub fn measured_points(&self) -> Vec<Vec3> {
+        let mut points = self.points.clone();
+        if self.is_in_progress() {
+            points.extend(self.hover_point);
         }
+        points
     }
+    
+    pub fn measurement(&self) -> Option<Measurement> {
+        Measurement::between(&self.measured_points())
+    }
+}
+
+/// State of the IFO import dialog
+#[derive(Default, Debug, Clone)]
+pub struct IfoImportDialog {
+    /// Whether the dialog is open
+    pub is_open: bool,
+    /// Path of the IFO file to import
+    pub path: String,
+    /// Offset added to the stored object positions
+    pub offset: Vec3,
+    /// Outcome of the last import, shown in the dialog
+    pub last_result: Option<String>,
+}
+
+/// Default distance objects may be above or below the terrain before they are reported
+pub const DEFAULT_TERRAIN_TOLERANCE: f32 = 10.0;
+
+/// Kind of problem found by the zone validation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZoneIssueKind {
+    /// Object id does not exist in the zone's ZSC
+    MissingModel,
+    /// Object is far above the terrain
+    Floating,
+    /// Object is far below the terrain
+    Buried,
+    /// Warp gate at the same position as another warp gate
+    OverlappingWarp,
+    /// Sound object that can not be heard
+    ZeroRadiusSound,
+}
+
+impl ZoneIssueKind {
+    /// Get a display name for the issue
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ZoneIssueKind::MissingModel => "Missing model",
+            ZoneIssueKind::Floating => "Floating",
+            ZoneIssueKind::Buried => "Buried",
+            ZoneIssueKind::OverlappingWarp => "Overlapping warp",
+            ZoneIssueKind::ZeroRadiusSound => "Zero radius sound",
+        }
+    }
+}
+
+/// A problem with a single zone object
+#[derive(Debug, Clone)]
+pub struct ZoneIssue {
+    pub entity: Entity,
+    pub kind: ZoneIssueKind,
+    pub message: String,
+}
+
+/// Result of scanning the current zone
+#[derive(Default, Debug, Clone)]
+pub struct ZoneValidationReport {
+    /// Number of objects of each type, in hierarchy filter order
+    pub object_counts: Vec<(HierarchyFilter, usize)>,
+    pub issues: Vec<ZoneIssue>,
+}
+
+/// State of the zone validation panel
+#[derive(Debug, Clone)]
+pub struct ZoneValidation {
+    /// Whether the panel is visible
+    pub is_open: bool,
+    /// Scan requested by the panel, applied by `zone_validation_system`
+    pub pending_scan: bool,
+    /// Height above or below the terrain at which objects are reported
+    pub terrain_tolerance: f32,
+    /// Result of the last scan
+    pub report: Option<ZoneValidationReport>,
+}
+
+impl Default for ZoneValidation {
+    fn default() -> Self {
+        Self {
+            is_open: false,
+            pending_scan: false,
+            terrain_tolerance: DEFAULT_TERRAIN_TOLERANCE,
+            report: None,
+        }
+    }
+}
+
+/// Default time between autosaves, in seconds
+pub const DEFAULT_AUTOSAVE_INTERVAL: f32 = 300.0;
+
+/// Default number of recovery snapshots kept per zone
+pub const DEFAULT_AUTOSAVE_RETENTION: usize = 5;
+
+/// Autosave settings and recovery state
+#[derive(Debug, Clone)]
+pub struct Autosave {
+    /// Whether recovery snapshots are written periodically
+    pub enabled: bool,
+    /// Time between snapshots, in seconds
+    pub interval: f32,
+    /// Number of snapshots kept per zone, older ones are deleted
+    pub retention: usize,
+    /// Whether the zone changed since the last snapshot or save
+    pub dirty: bool,
+    /// Snapshot newer than the last save, offered to the user when a zone is opened
+    pub recovery_prompt: Option<RecoverySnapshot>,
+}
+
+impl Default for Autosave {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval: DEFAULT_AUTOSAVE_INTERVAL,
+            retention: DEFAULT_AUTOSAVE_RETENTION,
+            dirty: false,
+            recovery_prompt: None,
+        }
+    }
+}
+
+/// A recovery snapshot written by the autosave
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecoverySnapshot {
+    pub zone_id: u16,
+    /// Directory with the IFO files of the snapshot
+    pub path: PathBuf,
+    /// Name of the snapshot directory, the time it was written
+    pub timestamp: String,
+}
+
+/// Message to restore or discard a recovery snapshot
+#[derive(Message, Debug, Clone)]
+pub enum RecoveryEvent {
+    /// Copy the snapshot over the zone's IFO files and reload the zone
+    Restore(RecoverySnapshot),
+    /// Delete all recovery snapshots of the zone
+    Discard(RecoverySnapshot),
+}
+
+/// Editor operation that discards the edited zone
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiscardAction {
+    NewZone,
+    LoadZone {
+        zone_id: ZoneId,
+        despawn_other_zones: bool,
+    },
+    ExitEditor,
+}
+
+impl DiscardAction {
+    /// Get a display name for the action
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            DiscardAction::NewZone => "creating a new zone",
+            DiscardAction::LoadZone { .. } => "opening another zone",
+            DiscardAction::ExitEditor => "exiting the editor",
+        }
+    }
+}
+
+/// Progress of a `DiscardAction` waiting on unsaved changes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiscardStep {
+    /// Waiting for the user to choose Save, Discard or Cancel
+    Asking,
+    /// The zone is being saved, the action follows once it succeeded
+    Saving,
+    /// The user chose to discard the changes
+    Discard,
+}
+
+/// An operation waiting on unsaved changes
+#[derive(Debug, Clone)]
+pub struct PendingDiscard {
+    pub action: DiscardAction,
+    pub step: DiscardStep,
+    /// Why saving failed, shown in the dialog
+    pub error: Option<String>,
+}
+
+/// Start or stop play-testing, applied by `play_test_system`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayTestRequest {
+    /// Spawn the stand-in player below the editor camera
+    Start,
+    /// Return to the editor camera
+    Stop,
+}
+
+/// Play-testing the edited zone without leaving the editor
+///
+/// The editor is disabled while play-testing, so zone objects, selection,
+/// history and unsaved changes are kept as they are.
+#[derive(Debug, Clone, Default)]
+pub struct PlayTest {
+    /// Whether the stand-in player is walking the zone
+    pub is_active: bool,
+    /// Request from the UI
+    pub pending: Option<PlayTestRequest>,
 }
 
 /// Transform space for gizmos
@@ -281,7 +1017,7 @@ pub enum SelectionMode {
 }
 
 /// Hierarchy filter options
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum HierarchyFilter {
     #[default]
     All,
@@ -310,6 +1046,54 @@ impl HierarchyFilter {
             HierarchyFilter::Sounds => "Sounds",
         }
     }
+    
+    /// All filters in the order they are listed in the hierarchy panel
+    pub const ALL: [HierarchyFilter; 9] = [
+        HierarchyFilter::All,
+        HierarchyFilter::DecoObjects,
+        HierarchyFilter::CnstObjects,
+        HierarchyFilter::EventObjects,
+        HierarchyFilter::WarpObjects,
+        HierarchyFilter::Terrain,
+        HierarchyFilter::Water,
+        HierarchyFilter::Effects,
+        HierarchyFilter::Sounds,
+    ];
+    
+    /// The object type of a zone object, `None` for types without a filter
+    pub fn of_zone_object(zone_object: &ZoneObject) -> Option<HierarchyFilter> {
+        HierarchyFilter::ALL
+            .into_iter()
+            .skip(1)
+            .find(|filter| filter.matches(zone_object))
+    }
+    
+    /// Check whether a zone object passes this filter
+    pub fn matches(&self, zone_object: &ZoneObject) -> bool {
+        match self {
+            HierarchyFilter::All => true,
+            HierarchyFilter::DecoObjects => matches!(
+                zone_object,
+                ZoneObject::DecoObject(_) | ZoneObject::DecoObjectPart(_)
+            ),
+            HierarchyFilter::CnstObjects => matches!(
+                zone_object,
+                ZoneObject::CnstObject(_) | ZoneObject::CnstObjectPart(_)
+            ),
+            HierarchyFilter::EventObjects => matches!(
+                zone_object,
+                ZoneObject::EventObject(_) | ZoneObject::EventObjectPart(_)
+            ),
+            HierarchyFilter::WarpObjects => matches!(
+                zone_object,
+                ZoneObject::WarpObject(_) | ZoneObject::WarpObjectPart(_)
+            ),
+            HierarchyFilter::Terrain => matches!(zone_object, ZoneObject::Terrain(_)),
+            HierarchyFilter::Water => matches!(zone_object, ZoneObject::Water),
+            HierarchyFilter::Effects => matches!(zone_object, ZoneObject::EffectObject { .. }),
+            HierarchyFilter::Sounds => matches!(zone_object, ZoneObject::SoundObject { .. }),
+        }
+    }
 }
 
 /// Model category for the model browser
@@ -447,6 +1231,8 @@ pub struct SelectedModel {
     pub search_filter: String,
     /// Flag to indicate that a placement is pending (user clicked "Add to Zone")
     pub pending_placement: bool,
+    /// Scatter brush for painting many instances of the model in Add mode
+    pub scatter_brush: ScatterBrush,
 }
 
 impl SelectedModel {
@@ -459,6 +1245,7 @@ impl SelectedModel {
             scroll_position: 0.0,
             search_filter: String::new(),
             pending_placement: false,
+            scatter_brush: ScatterBrush::default(),
         }
     }
     
@@ -490,17 +1277,78 @@ impl SelectedModel {
     }
 }
 
+/// Settings for painting instances of the selected model with the mouse
+#[derive(Debug, Clone)]
+pub struct ScatterBrush {
+    /// Paint while the mouse is held instead of placing one model per click
+    pub enabled: bool,
+    /// Brush radius in metres
+    pub radius: f32,
+    /// Instances per 100 square metres
+    pub density: f32,
+    /// Random rotation around the vertical axis, in degrees either way
+    pub rotation_jitter: f32,
+    /// Smallest random scale
+    pub scale_min: f32,
+    /// Largest random scale
+    pub scale_max: f32,
+    /// Tilt instances to match the terrain slope
+    pub align_to_normal: bool,
+}
+
+impl Default for ScatterBrush {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            radius: 5.0,
+            density: 4.0,
+            rotation_jitter: 180.0,
+            scale_min: 0.8,
+            scale_max: 1.2,
+            align_to_normal: true,
+        }
+    }
+}
+
+impl ScatterBrush {
+    /// Number of instances placed each time the brush paints
+    pub fn instances_per_dab(&self) -> usize {
+        let area = std::f32::consts::PI * self.radius * self.radius;
+        (self.density.max(0.0) * area / 100.0).round() as usize
+    }
+    
+    /// Closest two instances of one stroke may be, so repeated dabs do not pile up
+    pub fn min_spacing(&self) -> f32 {
+        if self.density <= 0.0 {
+            return f32::INFINITY;
+        }
+        (100.0 / self.density).sqrt() * 0.5
+    }
+}
+
 /// Resource to track grid settings
-#[derive(Resource, Clone)]
+///
+/// The grid follows the camera and fades out towards `draw_distance`.
+/// Colours are sRGBA as bevy's `Color` can not be stored in the config file.
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct EditorGridSettings {
     /// Whether the grid is visible
     pub visible: bool,
-    /// Grid cell size
+    /// Size of a minor grid cell in metres, also used for snapping
     pub cell_size: f32,
-    /// Grid extent (total size)
-    pub extent: f32,
-    /// Grid color
-    pub color: Color,
+    /// Minor cells between two major lines
+    pub subdivisions: u32,
+    /// Distance from the camera the grid is drawn to
+    pub draw_distance: f32,
+    /// Height of the grid plane in metres
+    pub height: f32,
+    /// Colour of the minor lines
+    pub minor_color: [f32; 4],
+    /// Colour of the major lines
+    pub major_color: [f32; 4],
+    /// Whether the lines through the origin are drawn in the X and Z axis colours
+    pub show_axes: bool,
 }
 
 impl Default for EditorGridSettings {
@@ -508,12 +1356,32 @@ impl Default for EditorGridSettings {
         Self {
             visible: true,
             cell_size: 1.0,
-            extent: 100.0,
-            color: Color::srgba(0.5, 0.5, 0.5, 0.5),
+            subdivisions: 10,
+            draw_distance: 100.0,
+            height: 0.0,
+            minor_color: [0.5, 0.5, 0.5, 0.3],
+            major_color: [0.8, 0.8, 0.8, 0.6],
+            show_axes: true,
         }
     }
 }
 
+impl EditorGridSettings {
+    pub fn minor_color(&self) -> Color {
+        let [red, green, blue, alpha] = self.minor_color;
+        Color::srgba(red, green, blue, alpha)
+    }
+
+    pub fn major_color(&self) -> Color {
+        let [red, green, blue, alpha] = self.major_color;
+        Color::srgba(red, green, blue, alpha)
+    }
+}
+
+impl PersistentSettings for EditorGridSettings {
+    const CONFIG_SECTION: &'static str = "map_editor_grid";
+}
+
 /// Type of zone object for deletion tracking
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ZoneObjectType {
@@ -558,3 +1426,63 @@ impl DeletedZoneObjects {
         self.objects.len()
     }
 }
+
+/// Resource to track objects hidden in the editor
+///
+/// Hiding is editor-only, hidden objects are still saved to the IFO.
+/// Hidden objects are also skipped by viewport picking.
+#[derive(Resource, Default, Debug)]
+pub struct EditorVisibility {
+    /// Objects hidden individually from the hierarchy panel
+    pub hidden_entities: HashSet<Entity>,
+    /// Object types hidden from the hierarchy panel
+    pub hidden_types: HashSet<HierarchyFilter>,
+    /// When set, only these objects and the terrain are shown
+    pub isolated: Option<HashSet<Entity>>,
+}
+
+impl EditorVisibility {
+    /// Check whether an object of the given type is hidden
+    pub fn is_hidden(&self, entity: Entity, object_type: Option<HierarchyFilter>) -> bool {
+        if self.hidden_entities.contains(&entity)
+            || object_type.is_some_and(|object_type| self.hidden_types.contains(&object_type))
+        {
+            return true;
+        }
+        
+        self.isolated.as_ref().is_some_and(|isolated| {
+            !isolated.contains(&entity) && object_type != Some(HierarchyFilter::Terrain)
+        })
+    }
+    
+    /// Toggle whether a single object is hidden
+    pub fn toggle_entity(&mut self, entity: Entity) {
+        if !self.hidden_entities.remove(&entity) {
+            self.hidden_entities.insert(entity);
+        }
+    }
+    
+    /// Toggle whether every object of a type is hidden
+    pub fn toggle_type(&mut self, object_type: HierarchyFilter) {
+        if !self.hidden_types.remove(&object_type) {
+            self.hidden_types.insert(object_type);
+        }
+    }
+    
+    /// Hide everything except the given objects and the terrain
+    pub fn isolate(&mut self, entities: &HashSet<Entity>) {
+        self.isolated = Some(entities.clone());
+    }
+    
+    /// Check if anything is currently hidden
+    pub fn is_any_hidden(&self) -> bool {
+        !self.hidden_entities.is_empty() || !self.hidden_types.is_empty() || self.isolated.is_some()
+    }
+    
+    /// Show every object again
+    pub fn show_all(&mut self) {
+        self.hidden_entities.clear();
+        self.hidden_types.clear();
+        self.isolated = None;
+    }
+}
@@ -0,0 +1,330 @@
+//! Autosave for the Map Editor
+//!
+//! Periodically writes the edited zone to a timestamped recovery snapshot,
+//! using the same IFO and HIM export as a manual save but to a separate directory:
+//!
+//! ```text
+//! <game data>/map_editor_recovery/zone_<id>/<timestamp>/<x>_<y>.IFO
+//! <game data>/map_editor_recovery/zone_<id>/<timestamp>/<x>_<y>.HIM
+//! ```
+//!
+//! When a zone is opened in the editor and its newest snapshot is newer than
+//! the zone's IFO files, the user is offered to restore it.
+
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use bevy::prelude::*;
+use rose_data::ZoneId;
+
+use crate::events::LoadZoneEvent;
+use crate::map_editor::resources::{DeletedZoneObjects, MapEditorState, RecoveryEvent, RecoverySnapshot};
+use crate::resources::{CurrentZone, VfsResource};
+use crate::vfs_asset_io::remove_from_vfs_file_cache;
+use crate::vfs_path_resolver::clear_path_resolver_caches;
+use crate::zone_loader::ZoneLoaderAsset;
+
+use super::save_system::{create_backup, SaveZoneEvent};
+
+/// Directory below the game data folder holding the recovery snapshots
+const RECOVERY_DIRECTORY: &str = "map_editor_recovery";
+
+/// Plugin for the autosave and crash recovery systems
+pub struct AutosavePlugin;
+
+impl Plugin for AutosavePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<RecoveryEvent>().add_systems(
+            Update,
+            (autosave_system, recovery_check_system, recovery_event_system),
+        );
+    }
+}
+
+/// System to write a recovery snapshot once the zone has had unsaved changes
+/// for the autosave interval
+pub fn autosave_system(
+    time: Res<Time>,
+    mut elapsed: Local<f32>,
+    map_editor_state: Res<MapEditorState>,
+    current_zone: Option<Res<CurrentZone>>,
+    vfs_resource: Res<VfsResource>,
+    mut save_events: MessageWriter<SaveZoneEvent>,
+) {
+    let autosave = &map_editor_state.autosave;
+    if !map_editor_state.enabled
+        || !autosave.enabled
+        || !map_editor_state.is_modified
+        || !autosave.dirty
+    {
+        *elapsed = 0.0;
+        return;
+    }
+
+    *elapsed += time.delta_secs();
+    if *elapsed < autosave.interval {
+        return;
+    }
+    *elapsed = 0.0;
+
+    let Some(current_zone) = current_zone else {
+        return;
+    };
+
+    let zone_id = current_zone.id.get();
+    let zone_directory = recovery_directory(&vfs_resource.base_path, zone_id);
+    let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S").to_string();
+    let snapshot_path = zone_directory.join(&timestamp);
+    if let Err(error) = std::fs::create_dir_all(&snapshot_path) {
+        log::error!("[Autosave] Failed to create {:?}: {}", snapshot_path, error);
+        return;
+    }
+
+    match prune_snapshots(&zone_directory, autosave.retention) {
+        Ok(0) => {}
+        Ok(pruned) => log::info!("[Autosave] Deleted {} old recovery snapshots", pruned),
+        Err(error) => log::warn!("[Autosave] Failed to delete old recovery snapshots: {}", error),
+    }
+
+    log::info!("[Autosave] Writing recovery snapshot {} for zone {}", timestamp, zone_id);
+    save_events.write(SaveZoneEvent::recovery(zone_id, snapshot_path));
+}
+
+/// System to offer restoring a recovery snapshot when a zone is opened in the editor
+pub fn recovery_check_system(
+    mut checked_zone: Local<Option<u16>>,
+    mut map_editor_state: ResMut<MapEditorState>,
+    current_zone: Option<Res<CurrentZone>>,
+    zone_loader_assets: Res<Assets<ZoneLoaderAsset>>,
+    vfs_resource: Res<VfsResource>,
+) {
+    if !map_editor_state.enabled {
+        // Play-testing stays in the same zone, it was already checked
+        if !map_editor_state.play_test.is_active {
+            *checked_zone = None;
+        }
+        return;
+    }
+
+    let Some(current_zone) = current_zone else {
+        return;
+    };
+    let zone_id = current_zone.id.get();
+    if *checked_zone == Some(zone_id) {
+        return;
+    }
+    let Some(zone_data) = zone_loader_assets.get(&current_zone.handle) else {
+        return;
+    };
+    *checked_zone = Some(zone_id);
+
+    let zone_directory = recovery_directory(&vfs_resource.base_path, zone_id);
+    let Some((path, modified)) = snapshot_directories(&zone_directory).pop() else {
+        return;
+    };
+
+    let last_save = last_modified_ifo(&vfs_resource.base_path.join(&zone_data.zone_path));
+    if last_save.is_some_and(|last_save| last_save >= modified) {
+        return;
+    }
+
+    let timestamp = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    log::info!("[Autosave] Found recovery snapshot {} newer than the last save of zone {}", timestamp, zone_id);
+    map_editor_state.autosave.recovery_prompt = Some(RecoverySnapshot {
+        zone_id,
+        path,
+        timestamp,
+    });
+}
+
+/// System to restore or discard recovery snapshots
+pub fn recovery_event_system(
+    mut events: MessageReader<RecoveryEvent>,
+    mut map_editor_state: ResMut<MapEditorState>,
+    mut deleted_zone_objects: ResMut<DeletedZoneObjects>,
+    current_zone: Option<Res<CurrentZone>>,
+    zone_loader_assets: Res<Assets<ZoneLoaderAsset>>,
+    vfs_resource: Res<VfsResource>,
+    mut load_zone_events: MessageWriter<LoadZoneEvent>,
+) {
+    for event in events.read() {
+        map_editor_state.autosave.recovery_prompt = None;
+
+        match event {
+            RecoveryEvent::Restore(snapshot) => {
+                let Some(zone_data) = current_zone
+                    .as_ref()
+                    .filter(|current_zone| current_zone.id.get() == snapshot.zone_id)
+                    .and_then(|current_zone| zone_loader_assets.get(&current_zone.handle))
+                else {
+                    log::error!("[Autosave] Zone {} must be open to restore its recovery snapshot", snapshot.zone_id);
+                    continue;
+                };
+                let Some(zone_id) = ZoneId::new(snapshot.zone_id) else {
+                    continue;
+                };
+
+                let zone_path = vfs_resource.base_path.join(&zone_data.zone_path);
+                if let Err(error) = create_backup(&zone_path) {
+                    log::warn!("[Autosave] Failed to create backup: {}", error);
+                }
+
+                match restore_snapshot(&snapshot.path, &zone_path) {
+                    Ok(file_names) => {
+                        for file_name in file_names.iter() {
+                            let vfs_path = zone_data.zone_path.join(file_name);
+                            remove_from_vfs_file_cache(&vfs_path.to_string_lossy());
+                        }
+                        clear_path_resolver_caches();
+                        log::info!(
+                            "[Autosave] Restored {} zone files from recovery snapshot {}, reloading zone",
+                            file_names.len(),
+                            snapshot.timestamp
+                        );
+                    }
+                    Err(error) => {
+                        log::error!("[Autosave] Failed to restore recovery snapshot {}: {}", snapshot.timestamp, error);
+                        continue;
+                    }
+                }
+
+                // The restored files are now the saved state of the zone
+                map_editor_state.clear_selection();
+                map_editor_state.clear_history();
+                map_editor_state.is_modified = false;
+                map_editor_state.autosave.dirty = false;
+                map_editor_state.dirty_terrain_blocks.clear();
+                deleted_zone_objects.clear();
+                load_zone_events.write(LoadZoneEvent::new(zone_id));
+            }
+            RecoveryEvent::Discard(snapshot) => {
+                let zone_directory = recovery_directory(&vfs_resource.base_path, snapshot.zone_id);
+                match std::fs::remove_dir_all(&zone_directory) {
+                    Ok(()) => log::info!("[Autosave] Discarded recovery snapshots of zone {}", snapshot.zone_id),
+                    Err(error) => log::warn!("[Autosave] Failed to delete {:?}: {}", zone_directory, error),
+                }
+            }
+        }
+    }
+}
+
+/// Directory with the recovery snapshots of a zone
+pub fn recovery_directory(base_path: &Path, zone_id: u16) -> PathBuf {
+    base_path
+        .join(RECOVERY_DIRECTORY)
+        .join(format!("zone_{}", zone_id))
+}
+
+/// Snapshot directories of a zone with their modification time, oldest first
+fn snapshot_directories(zone_directory: &Path) -> Vec<(PathBuf, SystemTime)> {
+    let Ok(entries) = std::fs::read_dir(zone_directory) else {
+        return Vec::new();
+    };
+
+    let mut snapshots = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let metadata = entry.metadata().ok()?;
+            metadata
+                .is_dir()
+                .then(|| (entry.path(), metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH)))
+        })
+        .collect::<Vec<_>>();
+
+    // Directory names are timestamps, so they sort by age
+    snapshots.sort_by(|(a, _), (b, _)| a.file_name().cmp(&b.file_name()));
+    snapshots
+}
+
+/// Delete the oldest snapshots of a zone so at most `retention` remain,
+/// returns how many were deleted
+pub fn prune_snapshots(zone_directory: &Path, retention: usize) -> std::io::Result<usize> {
+    let snapshots = snapshot_directories(zone_directory);
+    let excess = snapshots.len().saturating_sub(retention.max(1));
+    for (path, _) in snapshots.iter().take(excess) {
+        std::fs::remove_dir_all(path)?;
+    }
+    Ok(excess)
+}
+
+fn is_ifo_file(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("ifo"))
+}
+
+/// IFO and HIM files, the zone files written by a save
+fn is_saved_zone_file(path: &Path) -> bool {
+    is_ifo_file(path)
+        || path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("him"))
+}
+
+/// Time the newest IFO file of a zone was written, the last manual save
+fn last_modified_ifo(zone_path: &Path) -> Option<SystemTime> {
+    std::fs::read_dir(zone_path)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| is_ifo_file(&entry.path()))
+        .filter_map(|entry| entry.metadata().and_then(|metadata| metadata.modified()).ok())
+        .max()
+}
+
+/// Copy the IFO and HIM files of a snapshot into the zone directory, returns the copied file names
+fn restore_snapshot(snapshot_path: &Path, zone_path: &Path) -> std::io::Result<Vec<String>> {
+    std::fs::create_dir_all(zone_path)?;
+
+    let mut file_names = Vec::new();
+    for entry in std::fs::read_dir(snapshot_path)? {
+        let path = entry?.path();
+        if !is_saved_zone_file(&path) {
+            continue;
+        }
+        let Some(file_name) = path.file_name() else {
+            continue;
+        };
+        std::fs::copy(&path, zone_path.join(file_name))?;
+        file_names.push(file_name.to_string_lossy().into_owned());
+    }
+    Ok(file_names)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prune_and_restore_snapshots() {
+        let root_path = std::env::temp_dir().join(format!(
+            "rose_map_editor_autosave_test_{}",
+            std::process::id()
+        ));
+        let zone_directory = recovery_directory(&root_path, 2);
+        for timestamp in ["20240101_100000", "20240101_110000", "20240101_120000"] {
+            let snapshot = zone_directory.join(timestamp);
+            std::fs::create_dir_all(&snapshot).unwrap();
+            std::fs::write(snapshot.join("32_32.IFO"), timestamp).unwrap();
+        }
+
+        assert_eq!(prune_snapshots(&zone_directory, 2).unwrap(), 1);
+        let remaining = snapshot_directories(&zone_directory)
+            .into_iter()
+            .map(|(path, _)| path.file_name().unwrap().to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(remaining, ["20240101_110000", "20240101_120000"]);
+
+        let zone_path = root_path.join("3DDATA").join("MAPS").join("JDT01");
+        let restored = restore_snapshot(&zone_directory.join("20240101_120000"), &zone_path).unwrap();
+        assert_eq!(restored, ["32_32.IFO"]);
+        assert_eq!(
+            std::fs::read_to_string(zone_path.join("32_32.IFO")).unwrap(),
+            "20240101_120000"
+        );
+        assert!(last_modified_ifo(&zone_path).is_some());
+
+        std::fs::remove_dir_all(&root_path).ok();
+    }
+}
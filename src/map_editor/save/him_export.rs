@@ -0,0 +1,85 @@
+//! HIM File Export System
+//!
+//! Writes heightmaps edited in the map editor back to HIM files. A HIM file
+//! starts with a 16 byte header (width, height, patch grid count and patch
+//! size) followed by width x height f32 heights, row by row, and the patch
+//! data of the block. Only the heights are replaced, the header and the data
+//! after the heights are copied from the original file.
+
+use std::io;
+use std::path::Path;
+
+/// Size of the width, height, grid count and patch size header
+const HIM_HEADER_SIZE: usize = 16;
+
+/// Copy of a HIM file with its heights replaced
+pub fn replace_him_heights(original: &[u8], heights: &[f32]) -> io::Result<Vec<u8>> {
+    let read_u32 = |offset: usize| {
+        original
+            .get(offset..offset + 4)
+            .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()) as usize)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "HIM file header is truncated"))
+    };
+    let width = read_u32(0)?;
+    let height = read_u32(4)?;
+    if width * height != heights.len() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "HIM file is {}x{} but the edited heightmap has {} heights",
+                width,
+                height,
+                heights.len()
+            ),
+        ));
+    }
+
+    let heights_end = HIM_HEADER_SIZE + heights.len() * 4;
+    if original.len() < heights_end {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "HIM file heights are truncated"));
+    }
+
+    let mut data = original.to_vec();
+    for (bytes, height) in data[HIM_HEADER_SIZE..heights_end]
+        .chunks_exact_mut(4)
+        .zip(heights)
+    {
+        bytes.copy_from_slice(&height.to_le_bytes());
+    }
+    Ok(data)
+}
+
+/// Export the heights of a terrain block to a HIM file, based on the block's original HIM file
+pub fn export_him_block(original: &[u8], heights: &[f32], path: &Path) -> io::Result<usize> {
+    let data = replace_him_heights(original, heights)?;
+    std::fs::write(path, &data)?;
+    Ok(data.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replace_him_heights() {
+        // 2x1 heightmap with a grid count, patch size and trailing patch data
+        let mut original = Vec::new();
+        for value in [2u32, 1, 4] {
+            original.extend_from_slice(&value.to_le_bytes());
+        }
+        original.extend_from_slice(&250.0f32.to_le_bytes());
+        original.extend_from_slice(&10.0f32.to_le_bytes());
+        original.extend_from_slice(&20.0f32.to_le_bytes());
+        original.extend_from_slice(b"patch");
+
+        let data = replace_him_heights(&original, &[-5.0, 300.0]).unwrap();
+        assert_eq!(data.len(), original.len());
+        assert_eq!(&data[..HIM_HEADER_SIZE], &original[..HIM_HEADER_SIZE]);
+        assert_eq!(&data[16..20], &(-5.0f32).to_le_bytes());
+        assert_eq!(&data[20..24], &300.0f32.to_le_bytes());
+        assert_eq!(&data[24..], b"patch");
+
+        assert!(replace_him_heights(&original, &[1.0]).is_err());
+        assert!(replace_him_heights(&original[..20], &[1.0, 2.0]).is_err());
+    }
+}
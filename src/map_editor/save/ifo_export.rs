@@ -674,4 +674,31 @@ mod tests {
         let _block_offset = u32::from_le_bytes([writer.buffer[8], writer.buffer[9], writer.buffer[10], writer.buffer[11]]);
         // Offset should be 12 (4 for block_count + 8 for header entry)
     }
+
+    #[test]
+    fn test_event_object_round_trip() {
+        use rose_file_readers::{IfoFile, RoseFile, RoseFileReader};
+
+        let mut event_object = IfoEventObject::new(7);
+        event_object.object.position = [100.0, -200.0, 50.0];
+        event_object.quest_trigger_name = "TRIGGER_ZONE2_GATE".to_string();
+        event_object.script_function_name = "OnGateTouched".to_string();
+
+        let mut block = IfoBlock::new(32, 32);
+        block.deco_objects.push(IfoObject::new(1));
+        block.event_objects.push(event_object);
+
+        let mut writer = IfoWriter::new();
+        writer.write_block(&block).unwrap();
+
+        let ifo: IfoFile = RoseFile::read(RoseFileReader::from(&writer.buffer), &Default::default()).unwrap();
+        assert_eq!(ifo.deco_objects.len(), 1);
+        assert_eq!(ifo.event_objects.len(), 1);
+
+        let read_back = &ifo.event_objects[0];
+        assert_eq!(read_back.object.object_id, 7);
+        assert_eq!(read_back.object.position.x, 100.0);
+        assert_eq!(read_back.quest_trigger_name, "TRIGGER_ZONE2_GATE");
+        assert_eq!(read_back.script_function_name, "OnGateTouched");
+    }
 }
@@ -1,17 +1,23 @@
 //! Map Editor Save System
 //! 
 //! This module provides save/export functionality for the map editor.
-//! It allows exporting modified zones back to IFO format.
+//! It allows exporting modified zones back to IFO format and edited
+//! terrain back to HIM files.
 //!
 //! # Architecture
 //!
 //! - `ifo_types`: Data structures for IFO file format
 //! - `ifo_export`: Binary IFO file writer
+//! - `him_export`: Writes edited heights into HIM files
 //! - `save_system`: Bevy systems for saving zones
+//! - `autosave`: Periodic recovery snapshots and restoring them
 
 pub mod ifo_types;
 pub mod ifo_export;
+pub mod him_export;
 pub mod save_system;
+pub mod autosave;
 
 pub use ifo_types::*;
 pub use save_system::{SaveZoneEvent, SaveStatus, SavePlugin};
+pub use autosave::AutosavePlugin;
@@ -6,15 +6,19 @@ use std::collections::HashSet;
 use std::path::PathBuf;
 
 use bevy::prelude::*;
+use rose_file_readers::VfsPath;
 
 use crate::components::{
-    EventObject, WarpObject, ZoneObject,
+    EventObject, WarpDestination, WarpObject, ZoneObject,
 };
 use crate::map_editor::resources::{DeletedZoneObjects, ZoneObjectType};
 use crate::map_editor::systems::model_placement_system::EditorPlacedObject;
+use crate::map_editor::systems::terrain_block_system::zone_block;
 use crate::resources::CurrentZone;
-use crate::zone_loader::ZoneLoaderAsset;
+use crate::vfs_path_resolver::{clear_path_resolver_caches, normalize_vfs_path};
+use crate::zone_loader::{read_bytes_with_priority_sync, ZoneLoaderAsset};
 
+use super::him_export::export_him_block;
 use super::ifo_export::{export_ifo_block, ExportStats};
 use super::ifo_types::*;
 
@@ -25,6 +29,8 @@ pub struct SaveZoneEvent {
     pub zone_id: u16,
     /// Optional custom path (None = save to original path)
     pub path: Option<PathBuf>,
+    /// Autosave recovery snapshot, which leaves the editor's saved state untouched
+    pub recovery: bool,
 }
 
 impl SaveZoneEvent {
@@ -33,6 +39,7 @@ impl SaveZoneEvent {
         Self {
             zone_id,
             path: None,
+            recovery: false,
         }
     }
 
@@ -41,6 +48,16 @@ impl SaveZoneEvent {
         Self {
             zone_id,
             path: Some(path),
+            recovery: false,
+        }
+    }
+
+    /// Create a SaveZoneEvent writing a recovery snapshot to `path`
+    pub fn recovery(zone_id: u16, path: PathBuf) -> Self {
+        Self {
+            zone_id,
+            path: Some(path),
+            recovery: true,
         }
     }
 }
@@ -161,15 +178,18 @@ pub fn save_zone_system(
         &ZoneObject,
         Option<&EventObject>,
         Option<&WarpObject>,
+        Option<&WarpDestination>,
         Option<&EditorPlacedObject>,
     )>,
 ) {
     // Process all save events
     for event in events.read() {
         log::info!("[SaveSystem] ====== SAVE ZONE SYSTEM TRIGGERED ======");
-        log::info!("[SaveSystem] Processing SaveZoneEvent for zone {}", event.zone_id);
+        log::info!("[SaveSystem] Processing SaveZoneEvent for zone {} (recovery: {})", event.zone_id, event.recovery);
         
-        save_status.set_saving("Saving zone...");
+        if !event.recovery {
+            save_status.set_saving("Saving zone...");
+        }
 
         // Get the zone data
         let zone_data = if let Some(ref current_zone) = current_zone {
@@ -183,7 +203,9 @@ pub fn save_zone_system(
         let Some(zone_data) = zone_data else {
             let error = "No zone currently loaded or zone data not available";
             log::error!("[SaveSystem] {}", error);
-            save_status.set_complete(SaveResult::failure(error.to_string()));
+            if !event.recovery {
+                save_status.set_complete(SaveResult::failure(error.to_string()));
+            }
             continue;
         };
 
@@ -322,8 +344,11 @@ pub fn save_zone_system(
         log::info!("[SaveSystem] Removed {} objects from export_data", deleted_count);
         log::info!("[SaveSystem] Blocks modified by deletions: {:?}", deletion_modified_blocks);
         
-        // Clear the tracked deletions after processing
-        deleted_zone_objects.clear();
+        // Clear the tracked deletions after processing, a recovery snapshot
+        // does not replace the real save so they are still needed for it
+        if !event.recovery {
+            deleted_zone_objects.clear();
+        }
 
         // STEP 2: Track which blocks have been modified by the editor
         let mut modified_blocks: HashSet<(u32, u32)> = HashSet::new();
@@ -333,7 +358,7 @@ pub fn save_zone_system(
         let mut updated_objects_count = 0usize;
         let mut added_objects_count = 0usize;
         
-        for (_entity, transform, zone_object, event_object, warp_object, editor_placed) in zone_objects_query.iter() {
+        for (_entity, transform, zone_object, event_object, warp_object, warp_destination, editor_placed) in zone_objects_query.iter() {
             // Determine block coordinates from position
             // Zone is 64x64 blocks, each block is 160 units
             let (translation, rotation, scale) = (
@@ -416,6 +441,7 @@ pub fn save_zone_system(
                                 &ifo_object,
                                 event_object,
                                 warp_object,
+                                warp_destination,
                             );
                             
                             if found {
@@ -462,6 +488,7 @@ pub fn save_zone_system(
                         if let Some(event_obj) = event_object {
                             let mut ifo_event = IfoEventObject::new(id.zsc_object_id as u32);
                             ifo_event.object = ifo_object.clone();
+                            ifo_event.object.object_id = id.zsc_object_id as u32;
                             ifo_event.quest_trigger_name = event_obj.quest_trigger_name.clone();
                             ifo_event.script_function_name = event_obj.script_function_name.clone();
                             block.block.event_objects.push(ifo_event);
@@ -471,6 +498,7 @@ pub fn save_zone_system(
                         if let Some(event_obj) = event_object {
                             let mut ifo_event = IfoEventObject::new(part.zsc_object_id as u32);
                             ifo_event.object = ifo_object.clone();
+                            ifo_event.object.object_id = part.zsc_object_id as u32;
                             ifo_event.quest_trigger_name = event_obj.quest_trigger_name.clone();
                             ifo_event.script_function_name = event_obj.script_function_name.clone();
                             block.block.event_objects.push(ifo_event);
@@ -480,6 +508,8 @@ pub fn save_zone_system(
                         if let Some(warp_obj) = warp_object {
                             let mut ifo_warp = IfoWarpObject::new(id.zsc_object_id as u32, warp_obj.warp_id.get());
                             ifo_warp.object = ifo_object.clone();
+                            ifo_warp.object.warp_id = warp_obj.warp_id.get();
+                            set_warp_destination_name(&mut ifo_warp.object, warp_destination);
                             block.block.warp_objects.push(ifo_warp);
                         }
                     }
@@ -487,6 +517,8 @@ pub fn save_zone_system(
                         if let Some(warp_obj) = warp_object {
                             let mut ifo_warp = IfoWarpObject::new(part.zsc_object_id as u32, warp_obj.warp_id.get());
                             ifo_warp.object = ifo_object.clone();
+                            ifo_warp.object.warp_id = warp_obj.warp_id.get();
+                            set_warp_destination_name(&mut ifo_warp.object, warp_destination);
                             block.block.warp_objects.push(ifo_warp);
                         }
                     }
@@ -522,7 +554,12 @@ pub fn save_zone_system(
         log::info!("[SaveSystem] Modified blocks: {:?}", modified_blocks);
 
         // Create backup of original files before overwriting
-        if let Err(e) = create_backup(&output_path) {
+        if event.recovery {
+            if let Err(e) = std::fs::create_dir_all(&output_path) {
+                log::error!("[SaveSystem] Failed to create recovery directory {:?}: {}", output_path, e);
+                continue;
+            }
+        } else if let Err(e) = create_backup(&output_path) {
             log::warn!("[SaveSystem] Failed to create backup: {}", e);
             // Continue anyway - backup failure shouldn't prevent save
         }
@@ -571,15 +608,63 @@ pub fn save_zone_system(
             log::info!("[SaveSystem] Skipped {} unmodified blocks", skipped_blocks);
         }
 
+        // Write the heightmaps of terrain blocks changed by terrain edits
+        let mut terrain_blocks_exported = 0usize;
+        for &(block_x, block_y) in map_editor_state.dirty_terrain_blocks.iter() {
+            let Some(block_data) = zone_block(zone_data, block_x, block_y) else {
+                continue;
+            };
+
+            let file_name = format!("{}_{}.HIM", block_x, block_y);
+            let file_path = output_path.join(&file_name);
+            let vfs_path = VfsPath::from(PathBuf::from(normalize_vfs_path(
+                &zone_data.zone_path.join(&file_name).to_string_lossy(),
+            )));
+
+            log::info!("[SaveSystem] Writing modified heightmap: {:?}", file_path);
+
+            // The header and patch data are kept from the HIM file the block was loaded from
+            let result = read_bytes_with_priority_sync(&vfs_resource.vfs, &vfs_resource.base_path, &vfs_path)
+                .and_then(|original| Ok(export_him_block(&original, &block_data.him.heights, &file_path)?));
+            match result {
+                Ok(size) => {
+                    terrain_blocks_exported += 1;
+                    stats.bytes_written += size;
+                    log::info!("[SaveSystem] Exported {} ({} bytes)", file_name, size);
+                }
+                Err(e) => {
+                    stats.blocks_failed += 1;
+                    errors.push(format!("{}: {}", file_name, e));
+                    log::error!("[SaveSystem] Failed to export {}: {}", file_name, e);
+                }
+            }
+        }
+
+        // The case insensitive path resolvers cache misses, new files would not be found
+        clear_path_resolver_caches();
+
+        if event.recovery {
+            if stats.blocks_failed == 0 {
+                map_editor_state.autosave.dirty = false;
+                log::info!("[SaveSystem] Wrote recovery snapshot of {} blocks to {:?}", stats.blocks_exported, output_path);
+            } else {
+                log::error!("[SaveSystem] Recovery snapshot failed: {}", errors.join(", "));
+            }
+            continue;
+        }
+
         // Update save status
-        if stats.blocks_failed == 0 && stats.blocks_exported > 0 {
-            let result = SaveResult::success(stats.blocks_exported, stats.total_objects);
+        let blocks_exported = stats.blocks_exported + terrain_blocks_exported;
+        if stats.blocks_failed == 0 && blocks_exported > 0 {
+            let result = SaveResult::success(blocks_exported, stats.total_objects);
             log::info!("[SaveSystem] {}", result.message());
             save_status.set_complete(result);
             
             // Mark zone as unmodified
             map_editor_state.is_modified = false;
-        } else if stats.blocks_exported == 0 {
+            map_editor_state.autosave.dirty = false;
+            map_editor_state.dirty_terrain_blocks.clear();
+        } else if blocks_exported == 0 {
             let result = SaveResult::failure("No blocks were exported (no objects found or all blocks empty)".to_string());
             log::error!("[SaveSystem] {}", result.message());
             save_status.set_complete(result);
@@ -595,6 +680,18 @@ pub fn save_zone_system(
     }
 }
 
+/// Store the editor warp destination in the object name, clearing a
+/// destination that was unlinked in the editor
+fn set_warp_destination_name(object: &mut IfoObject, warp_destination: Option<&WarpDestination>) {
+    match warp_destination {
+        Some(destination) => object.object_name = destination.to_ifo_name(),
+        None if WarpDestination::from_ifo_name(&object.object_name).is_some() => {
+            object.object_name.clear();
+        }
+        None => {}
+    }
+}
+
 /// Try to find and update an existing object in the export data
 /// Returns true if the object was found and updated, false otherwise
 ///
@@ -615,6 +712,7 @@ fn update_existing_object(
     new_ifo_object: &IfoObject,
     event_object: Option<&EventObject>,
     warp_object: Option<&WarpObject>,
+    warp_destination: Option<&WarpDestination>,
 ) -> bool {
     // Get mutable access to the block
     let index = (block_x + block_y * 64) as usize;
@@ -680,6 +778,7 @@ fn update_existing_object(
                 if let Some(warp) = warp_object {
                     warp_obj.object.warp_id = warp.warp_id.get();
                 }
+                set_warp_destination_name(&mut warp_obj.object, warp_destination);
                 log::debug!("[SaveSystem] Updated warp_object[{}] in block ({}, {})",
                     ifo_object_id, block_x, block_y);
                 return true;
@@ -733,8 +832,8 @@ fn update_existing_object(
     false
 }
 
-/// Create a backup of the original IFO files
-fn create_backup(zone_path: &PathBuf) -> std::io::Result<()> {
+/// Create a backup of the original IFO and HIM files
+pub(crate) fn create_backup(zone_path: &PathBuf) -> std::io::Result<()> {
     // Check if the zone path exists on the real filesystem
     if !zone_path.exists() {
         log::warn!("[SaveSystem] Zone path does not exist on filesystem: {:?}", zone_path);
@@ -753,13 +852,13 @@ fn create_backup(zone_path: &PathBuf) -> std::io::Result<()> {
     let timestamped_backup_dir = backup_dir.join(timestamp.to_string());
     std::fs::create_dir_all(&timestamped_backup_dir)?;
 
-    // Copy all IFO files to backup
+    // Copy all IFO and HIM files to backup
     let mut copied_count = 0;
     for entry in std::fs::read_dir(zone_path)? {
         let entry = entry?;
         let path = entry.path();
         
-        if path.extension().map_or(false, |ext| ext.eq_ignore_ascii_case("ifo")) {
+        if path.extension().map_or(false, |ext| ext.eq_ignore_ascii_case("ifo") || ext.eq_ignore_ascii_case("him")) {
             let file_name = path.file_name().unwrap();
             let backup_path = timestamped_backup_dir.join(file_name);
             std::fs::copy(&path, &backup_path)?;
@@ -768,7 +867,7 @@ fn create_backup(zone_path: &PathBuf) -> std::io::Result<()> {
     }
 
     if copied_count > 0 {
-        log::info!("[SaveSystem] Created backup of {} IFO files in {:?}", copied_count, timestamped_backup_dir);
+        log::info!("[SaveSystem] Created backup of {} IFO and HIM files in {:?}", copied_count, timestamped_backup_dir);
     }
 
     Ok(())
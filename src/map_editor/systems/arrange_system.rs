@@ -0,0 +1,221 @@
+//! Arrange System for Map Editor
+//!
+//! Handles the align, distribute and snap to terrain tools that operate on
+//! the current multi-selection. Each operation is recorded as a single undo step.
+
+use bevy::prelude::*;
+use bevy_rapier3d::plugin::context::systemparams::ReadRapierContext;
+use bevy_rapier3d::prelude::{CollisionGroups, QueryFilter};
+
+use crate::components::{COLLISION_FILTER_INSPECTABLE, COLLISION_GROUP_ZONE_TERRAIN};
+use crate::map_editor::{
+    components::SelectedInEditor,
+    resources::{AlignAnchor, ArrangeAxis, ArrangeSelectedEvent, EditorAction, MapEditorState},
+};
+
+/// Height above an object the terrain ray starts from, so objects sunk into
+/// the ground still find the surface
+const SNAP_RAY_START_HEIGHT: f32 = 1000.0;
+
+/// Plugin for the arrange system
+pub struct ArrangeSystemPlugin;
+
+impl Plugin for ArrangeSystemPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<ArrangeSelectedEvent>()
+            .add_systems(Update, handle_arrange_event);
+    }
+}
+
+/// System to handle arrange events for the selected entities
+pub fn handle_arrange_event(
+    mut events: MessageReader<ArrangeSelectedEvent>,
+    mut map_editor_state: ResMut<MapEditorState>,
+    mut selected_entities: Query<(Entity, &mut Transform, &GlobalTransform), With<SelectedInEditor>>,
+    rapier_context: ReadRapierContext,
+) {
+    for event in events.read() {
+        if !map_editor_state.enabled {
+            continue;
+        }
+
+        let mut selection = selected_entities
+            .iter()
+            .map(|(entity, transform, global_transform)| {
+                (entity, *transform, global_transform.translation())
+            })
+            .collect::<Vec<_>>();
+        selection.sort_by_key(|(entity, ..)| *entity);
+
+        if selection.is_empty() {
+            continue;
+        }
+
+        // Work out the new world position and rotation of each object
+        let targets: Vec<(Vec3, Option<Quat>)> = match *event {
+            ArrangeSelectedEvent::Align { .. } | ArrangeSelectedEvent::Distribute { .. } => {
+                let positions = selection
+                    .iter()
+                    .map(|(_, _, position)| *position)
+                    .collect::<Vec<_>>();
+                arranged_positions(&positions, event)
+                    .into_iter()
+                    .map(|position| (position, None))
+                    .collect()
+            }
+            ArrangeSelectedEvent::SnapToTerrain { align_to_normal } => {
+                let Ok(rapier_context) = rapier_context.single() else {
+                    continue;
+                };
+                let filter = QueryFilter::new().groups(CollisionGroups::new(
+                    COLLISION_FILTER_INSPECTABLE,
+                    COLLISION_GROUP_ZONE_TERRAIN,
+                ));
+
+                selection
+                    .iter()
+                    .map(|(_, transform, position)| {
+                        let Some((_, hit)) = rapier_context.cast_ray_and_get_normal(
+                            *position + Vec3::Y * SNAP_RAY_START_HEIGHT,
+                            Vec3::NEG_Y,
+                            SNAP_RAY_START_HEIGHT * 2.0,
+                            true,
+                            filter,
+                        ) else {
+                            return (*position, None);
+                        };
+
+                        let rotation = align_to_normal.then(|| {
+                            // Keep the heading, tilt to the slope
+                            let (yaw, _, _) = transform.rotation.to_euler(EulerRot::YXZ);
+                            Quat::from_rotation_arc(Vec3::Y, hit.normal.normalize_or(Vec3::Y))
+                                * Quat::from_rotation_y(yaw)
+                        });
+                        (hit.point, rotation)
+                    })
+                    .collect()
+            }
+        };
+
+        let mut changes = Vec::new();
+        for ((entity, old_transform, position), (target, rotation)) in
+            selection.iter().zip(targets)
+        {
+            let mut new_transform = *old_transform;
+            new_transform.translation += target - *position;
+            if let Some(rotation) = rotation {
+                new_transform.rotation = rotation;
+            }
+
+            if new_transform != *old_transform {
+                changes.push((*entity, *old_transform, new_transform));
+            }
+        }
+
+        if changes.is_empty() {
+            log::info!("[ArrangeSystem] {} left the selection unchanged", arrange_label(event));
+            continue;
+        }
+
+        for (entity, _, new_transform) in changes.iter() {
+            if let Ok((_, mut transform, _)) = selected_entities.get_mut(*entity) {
+                *transform = *new_transform;
+            }
+        }
+
+        log::info!(
+            "[ArrangeSystem] {} applied to {} entities",
+            arrange_label(event),
+            changes.len()
+        );
+        map_editor_state.push_named_action(
+            arrange_label(event),
+            EditorAction::TransformEntities { entities: changes },
+        );
+    }
+}
+
+/// Positions after an align or distribute, in the same order as `positions`.
+/// Snap to terrain leaves positions unchanged as it depends on the physics world.
+pub fn arranged_positions(positions: &[Vec3], event: &ArrangeSelectedEvent) -> Vec<Vec3> {
+    let mut result = positions.to_vec();
+
+    match *event {
+        ArrangeSelectedEvent::Align { axis, anchor } => {
+            let axis = axis.index();
+            let min = positions.iter().map(|p| p[axis]).fold(f32::INFINITY, f32::min);
+            let max = positions.iter().map(|p| p[axis]).fold(f32::NEG_INFINITY, f32::max);
+            let value = match anchor {
+                AlignAnchor::Min => min,
+                AlignAnchor::Center => (min + max) / 2.0,
+                AlignAnchor::Max => max,
+            };
+
+            for position in result.iter_mut() {
+                position[axis] = value;
+            }
+        }
+        ArrangeSelectedEvent::Distribute { axis } => {
+            if positions.len() < 3 {
+                return result;
+            }
+
+            let axis = axis.index();
+            let mut order = (0..positions.len()).collect::<Vec<_>>();
+            order.sort_by(|a, b| positions[*a][axis].total_cmp(&positions[*b][axis]));
+
+            let first = positions[order[0]][axis];
+            let last = positions[order[order.len() - 1]][axis];
+            let step = (last - first) / (order.len() - 1) as f32;
+
+            for (rank, index) in order.into_iter().enumerate() {
+                result[index][axis] = first + step * rank as f32;
+            }
+        }
+        ArrangeSelectedEvent::SnapToTerrain { .. } => {}
+    }
+
+    result
+}
+
+/// History label for an arrange operation
+fn arrange_label(event: &ArrangeSelectedEvent) -> String {
+    match event {
+        ArrangeSelectedEvent::Align { axis, anchor } => format!("Align {:?} {:?}", axis, anchor),
+        ArrangeSelectedEvent::Distribute { axis } => format!("Distribute {:?}", axis),
+        ArrangeSelectedEvent::SnapToTerrain { .. } => "Snap to terrain".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_align_and_distribute() {
+        let positions = [
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(10.0, 3.0, 5.0),
+            Vec3::new(2.0, 2.0, 1.0),
+        ];
+
+        let aligned = arranged_positions(
+            &positions,
+            &ArrangeSelectedEvent::Align {
+                axis: ArrangeAxis::Y,
+                anchor: AlignAnchor::Center,
+            },
+        );
+        assert!(aligned.iter().all(|p| p.y == 2.0));
+        assert_eq!(aligned[1].x, 10.0);
+
+        let distributed = arranged_positions(
+            &positions,
+            &ArrangeSelectedEvent::Distribute { axis: ArrangeAxis::X },
+        );
+        assert_eq!(distributed[0].x, 0.0);
+        assert_eq!(distributed[1].x, 10.0);
+        assert_eq!(distributed[2].x, 5.0);
+        assert_eq!(distributed[2].z, 1.0);
+    }
+}
@@ -0,0 +1,58 @@
+//! Camera Focus System for the Map Editor
+//!
+//! Flies the editor free camera to an entity requested through
+//! `MapEditorState::pending_focus`, from the hierarchy panel, the View menu or
+//! the F shortcut.
+
+use bevy::prelude::*;
+use dolly::prelude::Position;
+
+use crate::map_editor::resources::MapEditorState;
+use crate::systems::FreeCamera;
+
+/// Distance kept between the camera and a focused object of unit scale
+const FOCUS_DISTANCE: f32 = 20.0;
+
+/// Plugin for the camera focus system
+pub struct CameraFocusPlugin;
+
+impl Plugin for CameraFocusPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, camera_focus_system);
+    }
+}
+
+/// System to move the free camera so the focused entity sits in the middle of
+/// the view, keeping the current camera angle. The rig smoothing makes it fly there.
+pub fn camera_focus_system(
+    mut map_editor_state: ResMut<MapEditorState>,
+    mut query_camera: Query<(&mut FreeCamera, &GlobalTransform)>,
+    query_transform: Query<&GlobalTransform, Without<FreeCamera>>,
+) {
+    let Some(entity) = map_editor_state.pending_focus.take() else {
+        return;
+    };
+
+    let Ok(target_transform) = query_transform.get(entity) else {
+        log::warn!("[CameraFocus] Entity {:?} has no transform to focus on", entity);
+        return;
+    };
+
+    let Ok((mut free_camera, camera_transform)) = query_camera.single_mut() else {
+        log::info!("[CameraFocus] Free camera is not active, press Tab to enable it");
+        return;
+    };
+
+    // Pull back further for large objects
+    let (scale, _, target) = target_transform.to_scale_rotation_translation();
+    let distance = FOCUS_DISTANCE * scale.max_element().max(1.0);
+    let position = target - *camera_transform.forward() * distance;
+
+    free_camera.rig.driver_mut::<Position>().position = mint::Point3 {
+        x: position.x,
+        y: position.y,
+        z: position.z,
+    };
+
+    log::info!("[CameraFocus] Focusing entity {:?} at {:?}", entity, target);
+}
@@ -168,9 +168,12 @@ pub fn handle_duplicate_event(
         
         // Record the action for undo
         if !new_entities.is_empty() {
-            map_editor_state.push_action(EditorAction::AddEntities {
-                entities: new_entities.clone(),
-            });
+            map_editor_state.push_named_action(
+                format!("Duplicate {} objects", new_entities.len()),
+                EditorAction::AddEntities {
+                    entities: new_entities.clone(),
+                },
+            );
         }
         
         log::info!("[DuplicateSystem] Duplicated {} entities", new_entities.len());
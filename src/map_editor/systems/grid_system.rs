@@ -1,21 +1,31 @@
 //! Editor Grid System
 //! 
-//! This module provides a visual grid for the map editor at y=0.
-//! The grid helps with positioning and alignment of objects.
+//! This module provides a visual grid for the map editor. The grid follows
+//! the camera over the grid plane and fades out with distance, so it is
+//! visible wherever the camera is in a zone. Every `subdivisions` minor
+//! lines there is a major line, the lines through the origin are drawn in
+//! the X and Z axis colours.
 
 use bevy::{
+    color::Alpha,
     prelude::{
-        App, Commands, Entity, Gizmos, IntoScheduleConfigs, Plugin, Query, Res, ResMut, 
-        Update, Vec3, Color, With, Without, InheritedVisibility, Transform, Mesh, 
-        MeshMaterial3d, StandardMaterial, Assets, Handle, Component,
+        App, Camera3d, Commands, Entity, Gizmos, GlobalTransform, Plugin, Query, Res, ResMut, 
+        Update, Vec3, Color, With, InheritedVisibility, Mesh, 
+        StandardMaterial, Assets,
     },
 };
 
 use crate::map_editor::{
-    components::{EditorGrid, EditorOnly},
+    components::EditorGrid,
     resources::{MapEditorState, EditorGridSettings},
 };
 
+/// Most lines drawn along each axis, minor lines are skipped when there would be more
+const MAX_LINES_PER_AXIS: i64 = 400;
+
+/// Each line is drawn in this many pieces so it can fade out with distance
+const FADE_SEGMENTS: usize = 16;
+
 /// Plugin for the editor grid system
 pub struct EditorGridPlugin;
 
@@ -25,92 +35,160 @@ impl Plugin for EditorGridPlugin {
     }
 }
 
+/// Kind of a grid line, by its index from the origin
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridLine {
+    Axis,
+    Major,
+    Minor,
+}
+
+impl GridLine {
+    pub fn of(index: i64, subdivisions: i64) -> Self {
+        if index == 0 {
+            GridLine::Axis
+        } else if index.rem_euclid(subdivisions) == 0 {
+            GridLine::Major
+        } else {
+            GridLine::Minor
+        }
+    }
+}
+
+/// Opacity of the grid at a distance from the camera, fading out towards the draw distance
+pub fn fade_alpha(distance: f32, draw_distance: f32) -> f32 {
+    let t = (distance / draw_distance).clamp(0.0, 1.0);
+    1.0 - t * t
+}
+
+/// Every how many lines a line is drawn, so at most `MAX_LINES_PER_AXIS` are drawn
+fn line_step(draw_distance: f32, cell_size: f32, subdivisions: i64) -> i64 {
+    let line_count = (2.0 * draw_distance / cell_size).ceil() as i64;
+    let mut step = 1;
+    while line_count / step > MAX_LINES_PER_AXIS {
+        step *= subdivisions.max(2);
+    }
+    step
+}
+
 /// System that renders the editor grid using gizmos
 /// 
 /// This system:
-/// - Draws a grid at y=0 using Bevy's gizmo system
+/// - Draws the grid around the camera using Bevy's gizmo system
 /// - Respects the grid visibility setting
-/// - Uses the grid size from EditorGridSettings
+/// - Keeps the snapping grid size and the status bar in sync with the settings
 pub fn grid_render_system(
-    map_editor_state: Res<MapEditorState>,
+    mut map_editor_state: ResMut<MapEditorState>,
     grid_settings: Res<EditorGridSettings>,
+    query_camera: Query<&GlobalTransform, With<Camera3d>>,
     mut gizmos: Gizmos,
 ) {
+    // Snap to the drawn grid, the state is only borrowed mutably when it differs
+    if grid_settings.is_changed() {
+        let state = map_editor_state.as_ref();
+        if state.grid_size != grid_settings.cell_size || state.show_grid != grid_settings.visible {
+            map_editor_state.grid_size = grid_settings.cell_size;
+            map_editor_state.show_grid = grid_settings.visible;
+        }
+    }
+
     // Only run when map editor is enabled and grid is visible
     if !map_editor_state.enabled || !grid_settings.visible {
         return;
     }
 
-    let cell_size = grid_settings.cell_size;
-    let extent = grid_settings.extent;
-    let grid_color = grid_settings.color;
-
-    // Draw grid lines along X axis
-    let half_extent = extent / 2.0;
-    let num_lines = (extent / cell_size) as i32 + 1;
-
-    for i in 0..num_lines {
-        let z = -half_extent + (i as f32) * cell_size;
-        
-        // Vary line intensity for major lines
-        let is_major_line = i % 10 == 0;
-        let line_color = if is_major_line {
-            Color::srgba(grid_color.to_srgba().red, grid_color.to_srgba().green, grid_color.to_srgba().blue, 0.8)
-        } else {
-            grid_color
-        };
-
-        // Draw line along X axis
-        gizmos.line(
-            Vec3::new(-half_extent, 0.0, z),
-            Vec3::new(half_extent, 0.0, z),
-            line_color,
+    let Ok(camera_transform) = query_camera.single() else {
+        return;
+    };
+    let camera = camera_transform.translation();
+
+    let cell_size = grid_settings.cell_size.max(0.01);
+    let subdivisions = grid_settings.subdivisions.max(1) as i64;
+    let draw_distance = grid_settings.draw_distance.max(cell_size);
+    let height = grid_settings.height;
+    let step = line_step(draw_distance, cell_size, subdivisions);
+
+    let minor_color = grid_settings.minor_color();
+    let major_color = grid_settings.major_color();
+    let line_color = |line: GridLine, axis_color: Color| match line {
+        GridLine::Axis if grid_settings.show_axes => axis_color,
+        GridLine::Axis | GridLine::Major => major_color,
+        GridLine::Minor => minor_color,
+    };
+
+    // Lines along the Z axis, the one at x = 0 is the Z axis (blue)
+    let first_x = ((camera.x - draw_distance) / cell_size).floor() as i64;
+    let last_x = ((camera.x + draw_distance) / cell_size).ceil() as i64;
+    for index in first_x..=last_x {
+        if index.rem_euclid(step) != 0 {
+            continue;
+        }
+        let x = index as f32 * cell_size;
+        draw_faded_line(
+            &mut gizmos,
+            Vec3::new(x, height, camera.z - draw_distance),
+            Vec3::new(x, height, camera.z + draw_distance),
+            line_color(GridLine::of(index, subdivisions), Color::srgba(0.0, 0.0, 1.0, 1.0)),
+            camera,
+            draw_distance,
         );
     }
 
-    // Draw grid lines along Z axis
-    for i in 0..num_lines {
-        let x = -half_extent + (i as f32) * cell_size;
-        
-        // Vary line intensity for major lines
-        let is_major_line = i % 10 == 0;
-        let line_color = if is_major_line {
-            Color::srgba(grid_color.to_srgba().red, grid_color.to_srgba().green, grid_color.to_srgba().blue, 0.8)
-        } else {
-            grid_color
-        };
-
-        // Draw line along Z axis
-        gizmos.line(
-            Vec3::new(x, 0.0, -half_extent),
-            Vec3::new(x, 0.0, half_extent),
-            line_color,
+    // Lines along the X axis, the one at z = 0 is the X axis (red)
+    let first_z = ((camera.z - draw_distance) / cell_size).floor() as i64;
+    let last_z = ((camera.z + draw_distance) / cell_size).ceil() as i64;
+    for index in first_z..=last_z {
+        if index.rem_euclid(step) != 0 {
+            continue;
+        }
+        let z = index as f32 * cell_size;
+        draw_faded_line(
+            &mut gizmos,
+            Vec3::new(camera.x - draw_distance, height, z),
+            Vec3::new(camera.x + draw_distance, height, z),
+            line_color(GridLine::of(index, subdivisions), Color::srgba(1.0, 0.0, 0.0, 1.0)),
+            camera,
+            draw_distance,
         );
     }
 
-    // Draw origin axes for reference
-    let axis_length = 5.0;
-    
-    // X axis (red)
-    gizmos.line(
-        Vec3::ZERO,
-        Vec3::X * axis_length,
-        Color::srgba(1.0, 0.0, 0.0, 1.0),
-    );
-    
-    // Y axis (green)
-    gizmos.line(
-        Vec3::ZERO,
-        Vec3::Y * axis_length,
-        Color::srgba(0.0, 1.0, 0.0, 1.0),
-    );
-    
-    // Z axis (blue)
-    gizmos.line(
-        Vec3::ZERO,
-        Vec3::Z * axis_length,
-        Color::srgba(0.0, 0.0, 1.0, 1.0),
-    );
+    // Y axis (green) at the origin
+    if grid_settings.show_axes {
+        let origin = Vec3::new(0.0, height, 0.0);
+        draw_faded_line(
+            &mut gizmos,
+            origin,
+            origin + Vec3::Y * 5.0,
+            Color::srgba(0.0, 1.0, 0.0, 1.0),
+            camera,
+            draw_distance,
+        );
+    }
+}
+
+/// Draw a line in pieces which fade out with their distance from the camera
+fn draw_faded_line(
+    gizmos: &mut Gizmos,
+    start: Vec3,
+    end: Vec3,
+    color: Color,
+    camera: Vec3,
+    draw_distance: f32,
+) {
+    let alpha = color.alpha();
+    let faded = |point: Vec3| color.with_alpha(alpha * fade_alpha(point.distance(camera), draw_distance));
+
+    let mut previous = start;
+    let mut previous_color = faded(start);
+    for segment in 1..=FADE_SEGMENTS {
+        let point = start.lerp(end, segment as f32 / FADE_SEGMENTS as f32);
+        let point_color = faded(point);
+        if previous_color.alpha() > 0.0 || point_color.alpha() > 0.0 {
+            gizmos.line_gradient(previous, point, previous_color, point_color);
+        }
+        previous = point;
+        previous_color = point_color;
+    }
 }
 
 /// System to spawn a mesh-based grid (alternative to gizmo grid)
@@ -157,4 +235,21 @@ mod tests {
         // Basic test to ensure the module compiles
         assert!(true);
     }
+
+    #[test]
+    fn test_grid_lines() {
+        assert_eq!(GridLine::of(0, 10), GridLine::Axis);
+        assert_eq!(GridLine::of(10, 10), GridLine::Major);
+        assert_eq!(GridLine::of(-20, 10), GridLine::Major);
+        assert_eq!(GridLine::of(-3, 10), GridLine::Minor);
+
+        assert_eq!(fade_alpha(0.0, 100.0), 1.0);
+        assert_eq!(fade_alpha(100.0, 100.0), 0.0);
+        assert_eq!(fade_alpha(250.0, 100.0), 0.0);
+        assert!(fade_alpha(50.0, 100.0) > fade_alpha(75.0, 100.0));
+
+        // 200 lines fit, 20000 only leave the major lines
+        assert_eq!(line_step(100.0, 1.0, 10), 1);
+        assert_eq!(line_step(1000.0, 0.1, 10), 100);
+    }
 }
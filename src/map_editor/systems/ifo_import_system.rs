@@ -0,0 +1,181 @@
+//! IFO Import System for the Map Editor
+//!
+//! Adds the deco and cnst objects of an IFO file, for example a block of
+//! another zone, to the current zone at their stored positions. Objects are
+//! spawned through the model placement path as editor placed objects, so
+//! they are saved into the current zone's blocks like any placed model.
+
+use std::path::Path;
+
+use bevy::{pbr::ExtendedMaterial, prelude::*};
+use rose_file_readers::{IfoFile, RoseFile, RoseFileReader};
+
+use crate::map_editor::{
+    resources::{EditorAction, ImportIfoEvent, MapEditorState, ModelCategory},
+    save::IfoObject,
+    systems::{
+        load_models_system::model_info_from_zsc,
+        model_placement_system::place_model_at_position,
+    },
+};
+use crate::render::RoseObjectExtension;
+use crate::resources::CurrentZone;
+use crate::zone_loader::ZoneLoaderAsset;
+use crate::VfsResource;
+
+/// World position of the zone origin, IFO positions are relative to it
+const ZONE_CENTER: Vec3 = Vec3::new(5200.0, 0.0, -5200.0);
+
+/// Plugin for the IFO import system
+pub struct IfoImportPlugin;
+
+impl Plugin for IfoImportPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<ImportIfoEvent>()
+            .add_systems(Update, ifo_import_system);
+    }
+}
+
+/// System to handle IFO import events
+#[allow(clippy::too_many_arguments)]
+pub fn ifo_import_system(
+    mut commands: Commands,
+    mut events: MessageReader<ImportIfoEvent>,
+    mut map_editor_state: ResMut<MapEditorState>,
+    asset_server: Res<AssetServer>,
+    current_zone: Option<Res<CurrentZone>>,
+    zone_loader_assets: Res<Assets<ZoneLoaderAsset>>,
+    vfs_resource: Res<VfsResource>,
+    mut object_materials: ResMut<Assets<ExtendedMaterial<StandardMaterial, RoseObjectExtension>>>,
+) {
+    for event in events.read() {
+        let Some(zone_data) = current_zone
+            .as_ref()
+            .and_then(|current_zone| zone_loader_assets.get(&current_zone.handle))
+        else {
+            log::error!("[IfoImport] No zone loaded to import {:?} into", event.path);
+            map_editor_state.ifo_import.last_result = Some("No zone loaded".to_string());
+            continue;
+        };
+
+        let path = if event.path.is_relative() {
+            vfs_resource.base_path.join(&event.path)
+        } else {
+            event.path.clone()
+        };
+
+        let ifo = match read_ifo_file(&path) {
+            Ok(ifo) => ifo,
+            Err(error) => {
+                log::error!("[IfoImport] {}", error);
+                map_editor_state.ifo_import.last_result = Some(error);
+                continue;
+            }
+        };
+
+        let mut entities = Vec::new();
+        let mut unresolved = 0;
+        for (objects, zsc, category, category_name) in [
+            (&ifo.deco_objects, &zone_data.zsc_deco, ModelCategory::Deco, "Deco"),
+            (&ifo.cnst_objects, &zone_data.zsc_cnst, ModelCategory::Cnst, "Cnst"),
+        ] {
+            for object in objects.iter() {
+                // Object ids index the current zone's ZSC, not the one the IFO was made for
+                let Some(model_info) =
+                    model_info_from_zsc(zsc, object.object_id as usize, category, category_name)
+                else {
+                    log::warn!(
+                        "[IfoImport] {} object id {} does not exist in the current zone ({} objects), skipping",
+                        category_name,
+                        object.object_id,
+                        zsc.objects.len()
+                    );
+                    unresolved += 1;
+                    continue;
+                };
+
+                let transform =
+                    imported_transform(&IfoObject::from_rose_ifo_object(object), event.offset);
+                entities.extend(place_model_at_position(
+                    &mut commands,
+                    &asset_server,
+                    &mut object_materials,
+                    zone_data,
+                    &model_info,
+                    transform,
+                ));
+            }
+        }
+
+        let not_supported = ifo.event_objects.len()
+            + ifo.warps.len()
+            + ifo.sound_objects.len()
+            + ifo.effect_objects.len()
+            + ifo.animated_objects.len();
+        if not_supported > 0 {
+            log::warn!(
+                "[IfoImport] Skipped {} event, warp, sound, effect and animated objects, only deco and cnst objects are imported",
+                not_supported
+            );
+        }
+
+        let file_name = path
+            .file_name()
+            .map_or_else(|| path.to_string_lossy(), |name| name.to_string_lossy())
+            .into_owned();
+        let mut message = format!("Imported {} objects from {}", entities.len(), file_name);
+        if unresolved > 0 {
+            message.push_str(&format!(", {} unknown object ids skipped", unresolved));
+        }
+        log::info!("[IfoImport] {}", message);
+        map_editor_state.ifo_import.last_result = Some(message.clone());
+
+        if !entities.is_empty() {
+            map_editor_state.push_named_action(message, EditorAction::AddEntities { entities });
+        }
+    }
+}
+
+fn read_ifo_file(path: &Path) -> Result<IfoFile, String> {
+    let data = std::fs::read(path)
+        .map_err(|error| format!("Failed to read {}: {}", path.display(), error))?;
+    RoseFile::read(RoseFileReader::from(&data), &Default::default())
+        .map_err(|error| format!("Failed to parse {}: {}", path.display(), error))
+}
+
+/// World transform of an imported IFO object, the inverse of the conversion
+/// used when saving
+pub fn imported_transform(object: &IfoObject, offset: Vec3) -> Transform {
+    let (translation, rotation, scale) = object.to_bevy_transform();
+    Transform::from_translation(translation + ZONE_CENTER + offset)
+        .with_rotation(rotation)
+        .with_scale(scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_imported_transform_matches_save() {
+        let world = Transform::from_xyz(5300.0, 12.5, -5150.0)
+            .with_rotation(Quat::from_rotation_y(0.7))
+            .with_scale(Vec3::new(1.0, 2.0, 1.5));
+
+        // Same conversion as the save system
+        let object = IfoObject::from_transform(
+            3,
+            world.translation - ZONE_CENTER,
+            world.rotation,
+            world.scale,
+        );
+
+        let imported = imported_transform(&object, Vec3::ZERO);
+        assert!(imported.translation.abs_diff_eq(world.translation, 0.001));
+        assert!(imported.rotation.abs_diff_eq(world.rotation, 0.0001));
+        assert_eq!(imported.scale, world.scale);
+
+        let offset = imported_transform(&object, Vec3::new(160.0, 0.0, 0.0));
+        assert!(offset.translation.abs_diff_eq(world.translation + Vec3::X * 160.0, 0.001));
+    }
+}
@@ -6,8 +6,9 @@
 //! - Ctrl+Z: Undo
 //! - Ctrl+Y: Redo
 //! - Escape: Deselect all
-//! - E/R: Switch to Rotate/Scale mode (Q for Select, V for Add, X for Delete)
+//! - E/R: Switch to Rotate/Scale mode (Q for Select, V for Add, X for Delete, M for Measure, B for Sculpt)
 //! - Tab: Toggle free camera on/off
+//! - F5: Play from here
 //! - Note: W is reserved for FreeCamera forward movement
 
 use bevy::prelude::*;
@@ -15,7 +16,7 @@ use bevy_egui::EguiContexts;
 
 use crate::components::ZoneObject;
 use crate::map_editor::components::SelectedInEditor;
-use crate::map_editor::resources::{DeletedZoneObjects, DuplicateSelectedEvent, EditorAction, EditorMode, MapEditorState, ZoneObjectType};
+use crate::map_editor::resources::{DeletedZoneObjects, DuplicateSelectedEvent, EditorAction, EditorMode, MapEditorState, PlayTestRequest, ZoneObjectType};
 use crate::systems::{FreeCamera, OrbitCamera};
 
 /// System to handle keyboard shortcuts for the map editor
@@ -46,8 +47,8 @@ pub fn keyboard_shortcuts_system(
     // Handle mode switching (W/E/R/Q)
     handle_mode_switches(&mut map_editor_state, &keyboard);
     
-    // Handle Escape - Deselect all
-    if keyboard.just_pressed(KeyCode::Escape) {
+    // Handle Escape - Deselect all, in Measure mode it cancels the measurement instead
+    if keyboard.just_pressed(KeyCode::Escape) && map_editor_state.editor_mode != EditorMode::Measure {
         handle_deselect_all(&mut map_editor_state, &mut commands, &selected_entities);
     }
     
@@ -81,7 +82,7 @@ pub fn keyboard_shortcuts_system(
     
     // Handle F - Focus on selected entity
     if keyboard.just_pressed(KeyCode::KeyF) {
-        handle_focus_selected(&map_editor_state);
+        handle_focus_selected(&mut map_editor_state);
     }
     
     // Handle G - Toggle snap to grid
@@ -90,6 +91,12 @@ pub fn keyboard_shortcuts_system(
         log::info!("[KeyboardShortcuts] Snap to grid: {}", map_editor_state.snap_to_grid);
     }
     
+    // Handle F5 - Play from here, applied by play_test_system
+    if keyboard.just_pressed(KeyCode::F5) {
+        map_editor_state.play_test.pending = Some(PlayTestRequest::Start);
+        log::info!("[KeyboardShortcuts] Play from here requested");
+    }
+    
     // Note: Ctrl+S save functionality is handled via the menu bar UI
     // The keyboard input S (without modifiers) is used for FreeCamera movement
     
@@ -175,6 +182,18 @@ fn handle_mode_switches(map_editor_state: &mut MapEditorState, keyboard: &Button
         map_editor_state.editor_mode = EditorMode::Delete;
         log::info!("[KeyboardShortcuts] Switched to Delete mode");
     }
+    
+    // M for Measure mode, Ctrl+M is the model browser
+    if keyboard.just_pressed(KeyCode::KeyM) && !is_ctrl_pressed(keyboard) {
+        map_editor_state.editor_mode = EditorMode::Measure;
+        log::info!("[KeyboardShortcuts] Switched to Measure mode");
+    }
+    
+    // B for Sculpt mode, the terrain brush
+    if keyboard.just_pressed(KeyCode::KeyB) {
+        map_editor_state.editor_mode = EditorMode::Sculpt;
+        log::info!("[KeyboardShortcuts] Switched to Sculpt mode");
+    }
 }
 
 /// Handle Escape - Deselect all entities
@@ -313,17 +332,11 @@ fn handle_select_all(map_editor_state: &mut MapEditorState) {
 }
 
 /// Handle F - Focus on selected entity
-fn handle_focus_selected(map_editor_state: &MapEditorState) {
-    if map_editor_state.selection_count() == 0 {
-        return;
-    }
-    
-    // For a full implementation, we would:
-    // 1. Get the first selected entity's transform
-    // 2. Move the editor camera to focus on it
-    
-    if let Some(_entity) = map_editor_state.first_selected() {
-        log::info!("[KeyboardShortcuts] Focus on selected entity (not fully implemented)");
+fn handle_focus_selected(map_editor_state: &mut MapEditorState) {
+    // The camera is moved by camera_focus_system
+    if let Some(entity) = map_editor_state.first_selected() {
+        map_editor_state.pending_focus = Some(entity);
+        log::info!("[KeyboardShortcuts] Focus on selected entity {:?}", entity);
     }
 }
 
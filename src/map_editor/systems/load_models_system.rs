@@ -99,32 +99,42 @@ fn load_models_from_zsc(
     models: &mut Vec<ModelInfo>,
     category_name: &str,
 ) {
-    for (object_id, object) in zsc.objects.iter().enumerate() {
-        // Get the first part's mesh path as the primary mesh
-        let primary_mesh_path = object.parts.first()
-            .map(|part| {
-                let mesh_id = part.mesh_id as usize;
-                zsc.meshes.get(mesh_id)
-                    .map(|m| m.path().to_string_lossy().into_owned())
-                    .unwrap_or_default()
-            })
-            .unwrap_or_default();
-        
-        // Create a display name from the mesh path
-        let name = create_model_name(&primary_mesh_path, object_id, category_name);
-        
-        let model_info = ModelInfo::new(
-            object_id as u32,
-            name,
-            primary_mesh_path,
-            category,
-            object.parts.len(),
-        );
-        
-        models.push(model_info);
+    for object_id in 0..zsc.objects.len() {
+        models.extend(model_info_from_zsc(zsc, object_id, category, category_name));
     }
 }
 
+/// Create the ModelInfo for an object of a ZSC file, None if the id is out of range
+pub(crate) fn model_info_from_zsc(
+    zsc: &ZscFile,
+    object_id: usize,
+    category: ModelCategory,
+    category_name: &str,
+) -> Option<ModelInfo> {
+    let object = zsc.objects.get(object_id)?;
+    
+    // Get the first part's mesh path as the primary mesh
+    let primary_mesh_path = object.parts.first()
+        .map(|part| {
+            let mesh_id = part.mesh_id as usize;
+            zsc.meshes.get(mesh_id)
+                .map(|m| m.path().to_string_lossy().into_owned())
+                .unwrap_or_default()
+        })
+        .unwrap_or_default();
+    
+    // Create a display name from the mesh path
+    let name = create_model_name(&primary_mesh_path, object_id, category_name);
+    
+    Some(ModelInfo::new(
+        object_id as u32,
+        name,
+        primary_mesh_path,
+        category,
+        object.parts.len(),
+    ))
+}
+
 /// Load default deco and cnst models from VFS (fallback when no zone is loaded)
 /// IMPORTANT: Real filesystem takes priority over VFS to support map editor modifications.
 fn load_default_deco_cnst_from_vfs(
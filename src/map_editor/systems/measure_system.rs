@@ -0,0 +1,173 @@
+//! Measure System for the Map Editor
+//!
+//! In Measure mode, clicking the terrain picks points for the measuring tool.
+//! The ruler measures between two points, path mode sums any number of segments.
+//! Right click removes the last point and Escape cancels the measurement.
+
+use bevy::{prelude::*, window::PrimaryWindow};
+use bevy_egui::EguiContexts;
+use bevy_rapier3d::plugin::context::systemparams::ReadRapierContext;
+use bevy_rapier3d::prelude::{CollisionGroups, QueryFilter};
+
+use crate::components::{COLLISION_FILTER_INSPECTABLE, COLLISION_GROUP_ZONE_TERRAIN};
+use crate::map_editor::resources::{EditorMode, MapEditorState};
+
+const MEASURE_LINE_COLOR: Color = Color::srgb(1.0, 0.9, 0.2);
+const MEASURE_HEIGHT_COLOR: Color = Color::srgba(1.0, 0.5, 0.1, 0.6);
+
+/// Plugin for the measure system
+pub struct MeasureToolPlugin;
+
+impl Plugin for MeasureToolPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                measure_tool_system.after(bevy_egui::EguiPreUpdateSet::InitContexts),
+                draw_measure_gizmos,
+            )
+                .chain(),
+        );
+    }
+}
+
+/// System that picks terrain points for the measuring tool
+#[allow(clippy::too_many_arguments)]
+pub fn measure_tool_system(
+    mut map_editor_state: ResMut<MapEditorState>,
+    mut egui_ctx: EguiContexts,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    rapier_context: ReadRapierContext,
+    query_window: Query<&Window, With<PrimaryWindow>>,
+    query_camera: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+) {
+    if !map_editor_state.enabled || map_editor_state.editor_mode != EditorMode::Measure {
+        if !map_editor_state.measure_tool.points.is_empty() {
+            map_editor_state.measure_tool.cancel();
+        }
+        map_editor_state.measure_tool.hover_point = None;
+        return;
+    }
+
+    let Ok(ctx) = egui_ctx.ctx_mut() else {
+        return;
+    };
+
+    if keyboard.just_pressed(KeyCode::Escape) && !ctx.wants_keyboard_input() {
+        map_editor_state.measure_tool.cancel();
+        log::info!("[Measure] Measurement cancelled");
+    }
+
+    // Skip if egui wants pointer input (mouse is over UI)
+    if ctx.wants_pointer_input() {
+        map_editor_state.measure_tool.hover_point = None;
+        return;
+    }
+
+    let Ok(rapier_context) = rapier_context.single() else {
+        return;
+    };
+    let Ok(window) = query_window.single() else {
+        return;
+    };
+    let Some(cursor_position) = window.cursor_position() else {
+        map_editor_state.measure_tool.hover_point = None;
+        return;
+    };
+    let Ok((camera, camera_transform)) = query_camera.single() else {
+        return;
+    };
+    let Ok(ray) = camera.viewport_to_world(camera_transform, cursor_position) else {
+        return;
+    };
+
+    // Points are projected onto the terrain only, so heights are terrain heights
+    let hover_point = rapier_context
+        .cast_ray(
+            ray.origin,
+            *ray.direction,
+            10000000.0,
+            true,
+            QueryFilter::new().groups(CollisionGroups::new(
+                COLLISION_FILTER_INSPECTABLE,
+                COLLISION_GROUP_ZONE_TERRAIN,
+            )),
+        )
+        .map(|(_, distance)| ray.origin + *ray.direction * distance);
+    map_editor_state.measure_tool.hover_point = hover_point;
+
+    if mouse_input.just_pressed(MouseButton::Left) {
+        if let Some(point) = hover_point {
+            map_editor_state.measure_tool.add_point(point);
+            log::info!("[Measure] Added point {:?}", point);
+        }
+    }
+
+    if mouse_input.just_pressed(MouseButton::Right) {
+        map_editor_state.measure_tool.points.pop();
+    }
+}
+
+/// System to draw the measured segments in the viewport
+pub fn draw_measure_gizmos(mut gizmos: Gizmos, map_editor_state: Res<MapEditorState>) {
+    if !map_editor_state.enabled || map_editor_state.editor_mode != EditorMode::Measure {
+        return;
+    }
+
+    let measure_tool = &map_editor_state.measure_tool;
+    let points = measure_tool.measured_points();
+
+    for segment in points.windows(2) {
+        gizmos.line(segment[0], segment[1], MEASURE_LINE_COLOR);
+
+        // Show the height difference as a vertical drop from the higher end
+        let (high, low) = if segment[0].y > segment[1].y {
+            (segment[0], segment[1])
+        } else {
+            (segment[1], segment[0])
+        };
+        let corner = Vec3::new(high.x, low.y, high.z);
+        gizmos.line(high, corner, MEASURE_HEIGHT_COLOR);
+        gizmos.line(corner, low, MEASURE_HEIGHT_COLOR);
+    }
+
+    for point in measure_tool.points.iter() {
+        gizmos.sphere(*point, 0.25, MEASURE_LINE_COLOR);
+    }
+
+    if let Some(hover_point) = measure_tool.hover_point {
+        gizmos.sphere(hover_point, 0.15, MEASURE_LINE_COLOR.with_alpha(0.5));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::map_editor::resources::{MeasureTool, Measurement};
+
+    use super::*;
+
+    #[test]
+    fn test_ruler_and_path_measurements() {
+        let measurement = Measurement::between(&[Vec3::ZERO, Vec3::new(3.0, 2.0, 4.0)]).unwrap();
+        assert_eq!(measurement.horizontal, 5.0);
+        assert_eq!(measurement.height, 2.0);
+        assert!((measurement.distance - 29.0f32.sqrt()).abs() < 0.0001);
+
+        // Ruler starts over after two points, path mode keeps adding
+        let mut ruler = MeasureTool::default();
+        ruler.add_point(Vec3::ZERO);
+        ruler.add_point(Vec3::X);
+        ruler.add_point(Vec3::Z);
+        assert_eq!(ruler.points, vec![Vec3::Z]);
+
+        let mut path = MeasureTool {
+            path_mode: true,
+            ..Default::default()
+        };
+        for point in [Vec3::ZERO, Vec3::X * 2.0, Vec3::new(2.0, 0.0, 3.0)] {
+            path.add_point(point);
+        }
+        assert_eq!(path.measurement().unwrap().horizontal, 5.0);
+    }
+}
@@ -2,25 +2,47 @@
 //!
 //! This module contains the system implementations for the map editor.
 
+pub mod arrange_system;
+pub mod camera_focus_system;
 pub mod duplicate_system;
 pub mod grid_system;
+pub mod ifo_import_system;
 pub mod keyboard_shortcuts_system;
 pub mod load_models_system;
+pub mod measure_system;
 pub mod model_placement_system;
+pub mod play_test_system;
 pub mod property_update_system;
 pub mod selection_highlight_system;
 pub mod selection_system;
+pub mod terrain_block_system;
+pub mod terrain_sculpt_system;
 pub mod transform_gizmo_system;
 pub mod undo_system;
+pub mod unsaved_changes_system;
+pub mod visibility_system;
+pub mod warp_link_system;
+pub mod zone_validation_system;
 
 // Re-export systems for convenience
+pub use arrange_system::{handle_arrange_event, ArrangeSystemPlugin};
+pub use camera_focus_system::{camera_focus_system, CameraFocusPlugin};
 pub use duplicate_system::{handle_duplicate_event, DuplicateSystemPlugin};
 pub use grid_system::{grid_spawn_system, grid_visibility_system};
+pub use ifo_import_system::{ifo_import_system, IfoImportPlugin};
 pub use keyboard_shortcuts_system::keyboard_shortcuts_system;
 pub use load_models_system::{load_available_models_system, update_models_on_zone_load_system};
+pub use measure_system::{draw_measure_gizmos, measure_tool_system, MeasureToolPlugin};
 pub use model_placement_system::{model_placement_system, ModelPlacementPlugin};
-pub use property_update_system::{property_update_system, apply_undo_system};
+pub use play_test_system::{play_test_movement_system, play_test_request_system, PlayTestPlugin};
+pub use property_update_system::property_update_system;
 pub use selection_highlight_system::selection_highlight_system;
 pub use selection_system::editor_picking_system;
+pub use terrain_block_system::{terrain_block_event_system, terrain_edit_system, TerrainBlockPlugin};
+pub use terrain_sculpt_system::{draw_terrain_brush, terrain_sculpt_system, TerrainSculptPlugin};
 pub use transform_gizmo_system::{transform_gizmo_system, draw_gizmo_visuals};
 pub use undo_system::undo_redo_system;
+pub use unsaved_changes_system::{unsaved_changes_system, UnsavedChangesPlugin};
+pub use visibility_system::{editor_visibility_system, EditorVisibilityPlugin};
+pub use warp_link_system::{draw_warp_links, WarpLinkPlugin};
+pub use zone_validation_system::{zone_validation_system, ZoneValidationPlugin};
@@ -8,7 +8,7 @@ use bevy::{
         App, AssetServer, Camera, Camera3d, Commands, Entity, GlobalTransform,
         KeyCode, MouseButton, Plugin, Query, Res, ResMut, Transform, Update, Vec3, With,
         Mesh3d, MeshMaterial3d, Visibility, InheritedVisibility, ViewVisibility,
-        Name, Assets, StandardMaterial, Color, Local, Handle, Quat,
+        Name, Assets, StandardMaterial, Color, Local, Handle, Quat, Gizmos, Isometry3d, Vec3Swizzles,
     },
     window::{PrimaryWindow, Window},
     light::{NotShadowCaster, NotShadowReceiver},
@@ -22,23 +22,41 @@ use bevy_mesh::Mesh;
 use bevy_egui::EguiContexts;
 use bevy_rapier3d::prelude::{CollisionGroups, Group, QueryFilter, RigidBody, Collider, AsyncCollider, ComputedColliderShape};
 use bevy_rapier3d::plugin::context::systemparams::ReadRapierContext;
+use rand::{rngs::StdRng, Rng};
+use std::f32::consts::{FRAC_PI_2, TAU};
 
 use crate::{
     components::{
         ZoneObject, ZoneObjectId, ZoneObjectPart, ColliderParent,
         COLLISION_FILTER_INSPECTABLE, COLLISION_FILTER_COLLIDABLE,
-        COLLISION_GROUP_ZONE_OBJECT,
+        COLLISION_GROUP_ZONE_OBJECT, COLLISION_GROUP_ZONE_TERRAIN,
     },
     map_editor::{
-        resources::{MapEditorState, SelectedModel, EditorMode, ModelCategory},
+        resources::{EditorAction, MapEditorState, SelectedModel, EditorMode, ModelCategory},
         components::EditorSelectable,
     },
     zone_loader::ZoneLoaderAsset,
-    resources::CurrentZone,
+    resources::{CurrentZone, RngSeed},
     render::RoseObjectExtension,
     VfsResource,
 };
 
+/// Fraction of the brush radius the cursor must move before the scatter brush paints again
+const SCATTER_DAB_SPACING: f32 = 0.5;
+
+/// Height above the brush the terrain rays start from
+const SCATTER_RAY_START_HEIGHT: f32 = 1000.0;
+
+const SCATTER_BRUSH_COLOR: Color = Color::srgb(0.3, 1.0, 0.4);
+
+/// Instances painted by the scatter brush since the mouse was pressed
+#[derive(Default)]
+pub struct ScatterStroke {
+    entities: Vec<Entity>,
+    positions: Vec<Vec3>,
+    last_dab: Option<Vec3>,
+}
+
 /// Plugin for the model placement system
 pub struct ModelPlacementPlugin;
 
@@ -67,11 +85,12 @@ impl Plugin for ModelPlacementPlugin {
 /// This system:
 /// - Shows a preview of the selected model at cursor position
 /// - Places the model on left click when in Add mode
+/// - Paints instances while the mouse is held when the scatter brush is enabled
 /// - Uses raycast to find placement position on terrain/objects
 #[allow(clippy::too_many_arguments)]
 pub fn model_placement_system(
     mut commands: Commands,
-    map_editor_state: Res<MapEditorState>,
+    mut map_editor_state: ResMut<MapEditorState>,
     selected_model: Res<SelectedModel>,
     mut egui_ctx: EguiContexts,
     mouse_input: Res<ButtonInput<MouseButton>>,
@@ -81,13 +100,37 @@ pub fn model_placement_system(
     asset_server: Res<AssetServer>,
     current_zone: Option<Res<CurrentZone>>,
     zone_loader_assets: ResMut<Assets<ZoneLoaderAsset>>,
-    object_materials: ResMut<Assets<ExtendedMaterial<StandardMaterial, RoseObjectExtension>>>,
+    mut object_materials: ResMut<Assets<ExtendedMaterial<StandardMaterial, RoseObjectExtension>>>,
+    mut scatter_stroke: Local<ScatterStroke>,
+    mut gizmos: Gizmos,
+    rng_seed: Res<RngSeed>,
+    mut rng: Local<Option<StdRng>>,
 ) {
     // Only run when map editor is enabled and in Add mode
     if !map_editor_state.enabled {
         return;
     }
     
+    // A whole scatter stroke is undone at once, record it when the mouse is released
+    if !mouse_input.pressed(MouseButton::Left) || map_editor_state.editor_mode != EditorMode::Add {
+        let stroke = std::mem::take(&mut *scatter_stroke);
+        if !stroke.entities.is_empty() {
+            let name = selected_model
+                .model
+                .as_ref()
+                .map_or("objects", |model_info| model_info.name.as_str());
+            log::info!(
+                "[MODEL PLACEMENT] Scatter stroke placed {} instances of '{}'",
+                stroke.entities.len(),
+                name
+            );
+            map_editor_state.push_named_action(
+                format!("Scatter {} x {}", stroke.entities.len(), name),
+                EditorAction::AddEntities { entities: stroke.entities },
+            );
+        }
+    }
+    
     if map_editor_state.editor_mode != EditorMode::Add {
         // Only log occasionally to avoid spam
         return;
@@ -161,6 +204,83 @@ pub fn model_placement_system(
             }
         };
 
+        if selected_model.scatter_brush.enabled {
+            let brush = &selected_model.scatter_brush;
+            gizmos.circle(
+                Isometry3d::new(placement_position + Vec3::Y * 0.1, Quat::from_rotation_x(FRAC_PI_2)),
+                brush.radius,
+                SCATTER_BRUSH_COLOR,
+            );
+
+            // Paint again once the cursor has moved far enough from the last dab
+            let should_dab = mouse_input.pressed(MouseButton::Left)
+                && scatter_stroke.last_dab.is_none_or(|last_dab| {
+                    last_dab.distance(placement_position) >= brush.radius * SCATTER_DAB_SPACING
+                });
+            if !should_dab {
+                break;
+            }
+            scatter_stroke.last_dab = Some(placement_position);
+
+            let terrain_filter = QueryFilter::new().groups(CollisionGroups::new(
+                COLLISION_FILTER_INSPECTABLE,
+                COLLISION_GROUP_ZONE_TERRAIN,
+            ));
+            let min_spacing = brush.min_spacing();
+            let rng = rng.get_or_insert_with(|| rng_seed.rng("scatter_brush"));
+
+            for _ in 0..brush.instances_per_dab() {
+                // Uniformly distributed point in the brush circle
+                let angle = rng.gen_range(0.0..TAU);
+                let distance = brush.radius * rng.gen::<f32>().sqrt();
+                let point = placement_position + Vec3::new(angle.cos(), 0.0, angle.sin()) * distance;
+
+                let too_close = scatter_stroke.positions.iter().any(|position| {
+                    position.xz().distance(point.xz()) < min_spacing
+                });
+                if too_close {
+                    continue;
+                }
+
+                let Some((_, hit)) = rapier_context.cast_ray_and_get_normal(
+                    point + Vec3::Y * SCATTER_RAY_START_HEIGHT,
+                    Vec3::NEG_Y,
+                    SCATTER_RAY_START_HEIGHT * 2.0,
+                    true,
+                    terrain_filter,
+                ) else {
+                    continue;
+                };
+
+                let jitter = brush.rotation_jitter.abs();
+                let yaw = rng.gen_range(-jitter..=jitter).to_radians();
+                let mut rotation = Quat::from_rotation_y(yaw);
+                if brush.align_to_normal {
+                    rotation = Quat::from_rotation_arc(Vec3::Y, hit.normal.normalize_or(Vec3::Y)) * rotation;
+                }
+                let scale = if brush.scale_max > brush.scale_min {
+                    rng.gen_range(brush.scale_min..=brush.scale_max)
+                } else {
+                    brush.scale_min
+                };
+
+                if let Some(entity) = place_model_at_position(
+                    &mut commands,
+                    &asset_server,
+                    &mut object_materials,
+                    zone_data,
+                    model_info,
+                    Transform::from_translation(hit.point)
+                        .with_rotation(rotation)
+                        .with_scale(Vec3::splat(scale)),
+                ) {
+                    scatter_stroke.entities.push(entity);
+                    scatter_stroke.positions.push(hit.point);
+                }
+            }
+            break;
+        }
+
         // Handle left click for placement
         if mouse_input.just_pressed(MouseButton::Left) {
             log::info!(
@@ -184,13 +304,19 @@ pub fn model_placement_system(
             
             // Place the model with full mesh spawning using WORLD coordinates
             // (entity is not parented to zone, so transform is in world space)
-            place_model_at_position(
+            let Some(entity) = place_model_at_position(
                 &mut commands,
                 &asset_server,
                 object_materials.into_inner(),
                 zone_data,
                 model_info,
-                placement_position,  // Use WORLD coordinates for rendering
+                Transform::from_translation(placement_position),  // Use WORLD coordinates for rendering
+            ) else {
+                return;
+            };
+            map_editor_state.push_named_action(
+                format!("Place {}", model_info.name),
+                EditorAction::AddEntity { entity },
             );
             
             log::info!(
@@ -206,16 +332,16 @@ pub fn model_placement_system(
     }
 }
 
-/// Place a model at the specified position with full visual mesh spawning
+/// Place a model with the specified world transform with full visual mesh spawning
 #[allow(clippy::too_many_arguments)]
-fn place_model_at_position(
+pub(crate) fn place_model_at_position(
     commands: &mut Commands,
     asset_server: &AssetServer,
     object_materials: &mut Assets<ExtendedMaterial<StandardMaterial, RoseObjectExtension>>,
     zone_data: &ZoneLoaderAsset,
     model_info: &crate::map_editor::resources::ModelInfo,
-    position: Vec3,
-) {
+    object_transform: Transform,
+) -> Option<Entity> {
     use rose_file_readers::ZscCollisionFlags;
     
     let zsc_object_id = model_info.id as usize;
@@ -240,7 +366,7 @@ fn place_model_at_position(
             zsc_object_id,
             zsc.objects.len().saturating_sub(1)
         );
-        return;
+        return None;
     }
     
     let object = &zsc.objects[zsc_object_id];
@@ -270,8 +396,6 @@ fn place_model_at_position(
     };
     
     // Create the parent entity with transform
-    let object_transform = Transform::from_translation(position);
-    
     let mut object_entity_commands = commands.spawn((
         object_type,
         object_transform,
@@ -450,6 +574,8 @@ fn place_model_at_position(
         object.parts.len(),
         position
     );
+
+    Some(object_entity)
 }
 
 /// Component to mark objects placed by the editor
@@ -582,7 +708,7 @@ pub fn model_preview_system(
 #[allow(clippy::too_many_arguments)]
 pub fn add_to_zone_system(
     mut commands: Commands,
-    map_editor_state: Res<MapEditorState>,
+    mut map_editor_state: ResMut<MapEditorState>,
     mut selected_model: ResMut<SelectedModel>,
     query_camera: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
     asset_server: Res<AssetServer>,
@@ -643,20 +769,27 @@ pub fn add_to_zone_system(
     );
     
     // Place the model with full mesh spawning using WORLD coordinates
-    place_model_at_position(
+    if let Some(entity) = place_model_at_position(
         &mut commands,
         &asset_server,
         object_materials.into_inner(),
         zone_data,
         model_info,
-        world_position,  // Use WORLD coordinates for rendering
-    );
+        Transform::from_translation(world_position),  // Use WORLD coordinates for rendering
+    ) {
+        map_editor_state.push_named_action(
+            format!("Place {}", model_info.name),
+            EditorAction::AddEntity { entity },
+        );
+    }
     
     // Note: The pending_placement flag is already cleared by take_pending_placement()
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::map_editor::resources::ScatterBrush;
+    
     use super::*;
     
     #[test]
@@ -664,4 +797,23 @@ mod tests {
         // Basic test to ensure the module compiles
         assert!(true);
     }
+    
+    #[test]
+    fn test_scatter_brush_density() {
+        let brush = ScatterBrush {
+            radius: 10.0,
+            density: 2.0,
+            ..Default::default()
+        };
+        // 2 instances per 100 m² over a ~314 m² brush
+        assert_eq!(brush.instances_per_dab(), 6);
+        assert!((brush.min_spacing() - 50.0f32.sqrt() * 0.5).abs() < 0.0001);
+        
+        let empty = ScatterBrush {
+            density: 0.0,
+            ..Default::default()
+        };
+        assert_eq!(empty.instances_per_dab(), 0);
+        assert!(empty.min_spacing().is_infinite());
+    }
 }
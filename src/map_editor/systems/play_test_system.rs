@@ -0,0 +1,346 @@
+//! Play Test System for the Map Editor
+//!
+//! Play From Here spawns a stand-in player on the ground below the editor
+//! camera and follows it with the game's orbit camera. WASD walks relative to
+//! the camera, blocked by zone colliders and kept on the terrain and objects
+//! like `collision_player_system` does for the player. Escape returns to the
+//! editor camera where it was.
+//!
+//! The editor is only disabled while play-testing, so zone objects, the
+//! selection, the undo history and unsaved changes survive the round trip.
+
+use bevy::prelude::*;
+use bevy_egui::EguiContexts;
+use bevy_rapier3d::geometry::ShapeCastOptions;
+use bevy_rapier3d::plugin::context::systemparams::ReadRapierContext;
+use bevy_rapier3d::prelude::{Collider, CollisionGroups, QueryFilter, RapierContext};
+use bevy_rapier3d::rapier::prelude::Shape;
+use dolly::prelude::YawPitch;
+
+use crate::components::{
+    COLLISION_FILTER_COLLIDABLE, COLLISION_FILTER_MOVEABLE, COLLISION_GROUP_PHYSICS_TOY,
+    COLLISION_GROUP_ZONE_TERRAIN,
+};
+use crate::map_editor::components::EditorOnly;
+use crate::map_editor::resources::{MapEditorState, PlayTestRequest};
+use crate::map_editor::systems::keyboard_shortcuts_system::keyboard_shortcuts_system;
+use crate::resources::CurrentZone;
+use crate::systems::{FreeCamera, OrbitCamera};
+use crate::zone_loader::ZoneLoaderAsset;
+
+/// Height of the stand-in player
+const AVATAR_HEIGHT: f32 = 1.8;
+
+/// Radius of the stand-in player, the same as the player's wall collider
+const AVATAR_RADIUS: f32 = 0.4;
+
+/// Walking speed in metres per second, Shift runs
+const WALK_SPEED: f32 = 4.0;
+const RUN_SPEED: f32 = 10.0;
+
+/// Plugin for play-testing the edited zone
+pub struct PlayTestPlugin;
+
+impl Plugin for PlayTestPlugin {
+    fn build(&self, app: &mut App) {
+        // After the shortcuts, so the Escape that ends play-testing does not
+        // also deselect everything once the editor is enabled again
+        app.add_systems(
+            Update,
+            (play_test_request_system, play_test_movement_system)
+                .chain()
+                .after(keyboard_shortcuts_system),
+        );
+    }
+}
+
+/// Stand-in player spawned by Play From Here
+#[derive(Component)]
+pub struct PlayTestAvatar {
+    /// Editor camera position to return to
+    return_position: Vec3,
+    /// Editor camera yaw and pitch in degrees to return to
+    return_yaw_pitch: (f32, f32),
+}
+
+/// System to start and stop play-testing
+#[allow(clippy::too_many_arguments)]
+pub fn play_test_request_system(
+    mut commands: Commands,
+    mut map_editor_state: ResMut<MapEditorState>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut egui_contexts: EguiContexts,
+    query_camera: Query<(Entity, &Transform, Option<&FreeCamera>), With<Camera3d>>,
+    query_avatar: Query<(Entity, &PlayTestAvatar)>,
+    current_zone: Option<Res<CurrentZone>>,
+    zone_loader_assets: Res<Assets<ZoneLoaderAsset>>,
+    rapier_context: ReadRapierContext,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Only borrow mutably when something changes, the state is checked for changes
+    let play_test = &map_editor_state.as_ref().play_test;
+    let request = if play_test.is_active
+        && keyboard.just_pressed(KeyCode::Escape)
+        && !egui_contexts
+            .ctx_mut()
+            .is_ok_and(|ctx| ctx.wants_keyboard_input())
+    {
+        Some(PlayTestRequest::Stop)
+    } else {
+        play_test.pending
+    };
+    let Some(request) = request else {
+        return;
+    };
+    map_editor_state.play_test.pending = None;
+
+    match request {
+        PlayTestRequest::Start => {
+            if map_editor_state.play_test.is_active || !map_editor_state.enabled {
+                return;
+            }
+            let Some(zone_data) = current_zone
+                .as_ref()
+                .and_then(|current_zone| zone_loader_assets.get(&current_zone.handle))
+            else {
+                log::warn!("[PlayTest] Open a zone before play-testing");
+                return;
+            };
+            let Ok(rapier_context) = rapier_context.single() else {
+                return;
+            };
+            let Ok((camera_entity, camera_transform, free_camera)) = query_camera.single() else {
+                return;
+            };
+
+            let return_yaw_pitch = free_camera
+                .map(|free_camera| {
+                    let yaw_pitch = free_camera.rig.driver::<YawPitch>();
+                    (yaw_pitch.yaw_degrees, yaw_pitch.pitch_degrees)
+                })
+                .unwrap_or((-45.0, -20.0));
+
+            let spawn_position = ground_position(&rapier_context, zone_data, camera_transform.translation);
+            let avatar = commands
+                .spawn((
+                    PlayTestAvatar {
+                        return_position: camera_transform.translation,
+                        return_yaw_pitch,
+                    },
+                    EditorOnly,
+                    Transform::from_translation(spawn_position),
+                    Visibility::Visible,
+                    Name::new("Play Test Avatar"),
+                ))
+                .with_children(|child_builder| {
+                    child_builder.spawn((
+                        Mesh3d(meshes.add(Capsule3d::new(
+                            AVATAR_RADIUS,
+                            AVATAR_HEIGHT - AVATAR_RADIUS * 2.0,
+                        ))),
+                        MeshMaterial3d(materials.add(StandardMaterial {
+                            base_color: Color::srgb(0.2, 0.6, 1.0),
+                            ..Default::default()
+                        })),
+                        Transform::from_translation(Vec3::Y * AVATAR_HEIGHT / 2.0),
+                    ));
+                })
+                .id();
+
+            commands
+                .entity(camera_entity)
+                .remove::<FreeCamera>()
+                .insert(OrbitCamera::new(avatar, Vec3::new(0.0, 1.7, 0.0), 10.0));
+
+            map_editor_state.enabled = false;
+            map_editor_state.play_test.is_active = true;
+            log::info!("[PlayTest] Started at {:?}", spawn_position);
+        }
+        PlayTestRequest::Stop => {
+            if !map_editor_state.play_test.is_active {
+                return;
+            }
+
+            for (avatar_entity, avatar) in query_avatar.iter() {
+                for (camera_entity, _, _) in query_camera.iter() {
+                    let (yaw, pitch) = avatar.return_yaw_pitch;
+                    commands
+                        .entity(camera_entity)
+                        .remove::<OrbitCamera>()
+                        .insert(FreeCamera::new(avatar.return_position, yaw, pitch));
+                }
+                commands.entity(avatar_entity).despawn();
+            }
+
+            map_editor_state.enabled = true;
+            map_editor_state.play_test.is_active = false;
+            log::info!("[PlayTest] Returned to the editor");
+        }
+    }
+}
+
+/// System to walk the stand-in player with WASD
+#[allow(clippy::too_many_arguments)]
+pub fn play_test_movement_system(
+    map_editor_state: Res<MapEditorState>,
+    mut query_avatar: Query<&mut Transform, With<PlayTestAvatar>>,
+    query_camera: Query<&Transform, (With<Camera3d>, Without<PlayTestAvatar>)>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut egui_contexts: EguiContexts,
+    time: Res<Time>,
+    current_zone: Option<Res<CurrentZone>>,
+    zone_loader_assets: Res<Assets<ZoneLoaderAsset>>,
+    rapier_context: ReadRapierContext,
+) {
+    if !map_editor_state.play_test.is_active {
+        return;
+    }
+    let Some(zone_data) = current_zone
+        .as_ref()
+        .and_then(|current_zone| zone_loader_assets.get(&current_zone.handle))
+    else {
+        return;
+    };
+    let Ok(rapier_context) = rapier_context.single() else {
+        return;
+    };
+    let Ok(mut transform) = query_avatar.single_mut() else {
+        return;
+    };
+    let Ok(camera_transform) = query_camera.single() else {
+        return;
+    };
+
+    let mut input = Vec2::ZERO;
+    if !egui_contexts
+        .ctx_mut()
+        .is_ok_and(|ctx| ctx.wants_keyboard_input())
+    {
+        for key in keyboard.get_pressed() {
+            match key {
+                KeyCode::KeyW => input.y += 1.0,
+                KeyCode::KeyS => input.y -= 1.0,
+                KeyCode::KeyA => input.x -= 1.0,
+                KeyCode::KeyD => input.x += 1.0,
+                _ => {}
+            }
+        }
+    }
+
+    let delta_time = time.delta_secs();
+    let direction = walk_direction(camera_transform.rotation, input);
+    if direction != Vec3::ZERO {
+        let speed = if keyboard.pressed(KeyCode::ShiftLeft) {
+            RUN_SPEED
+        } else {
+            WALK_SPEED
+        };
+        let translation_delta = direction * speed * delta_time;
+        transform.rotation = Quat::from_rotation_arc(Vec3::NEG_Z, direction);
+
+        // Stop in front of walls, the same cast as for the player
+        let cast_origin = transform.translation + Vec3::new(0.0, 1.2, 0.0);
+        let ball_collider = Collider::ball(AVATAR_RADIUS);
+        let time_of_impact = rapier_context
+            .cast_shape(
+                cast_origin + direction * AVATAR_RADIUS,
+                Quat::default(),
+                direction,
+                <&dyn Shape>::from(&ball_collider),
+                ShapeCastOptions {
+                    max_time_of_impact: translation_delta.length(),
+                    target_distance: 0.0,
+                    compute_impact_geometry_on_penetration: false,
+                    stop_at_penetration: false,
+                },
+                QueryFilter::new().groups(CollisionGroups::new(
+                    COLLISION_FILTER_COLLIDABLE,
+                    !COLLISION_GROUP_ZONE_TERRAIN & !COLLISION_GROUP_PHYSICS_TOY,
+                )),
+            )
+            .map(|(_, hit)| hit.time_of_impact);
+
+        transform.translation += match time_of_impact {
+            Some(time_of_impact) => direction * (time_of_impact - 0.1).max(0.0),
+            None => translation_delta,
+        };
+    }
+
+    // Stand on the terrain or objects below, falling like the player does
+    let ground = ground_position(&rapier_context, zone_data, transform.translation);
+    let fall_distance = delta_time * 9.81;
+    transform.translation.y = if transform.translation.y - ground.y > fall_distance {
+        transform.translation.y - fall_distance
+    } else {
+        ground.y
+    };
+}
+
+/// Walking direction on the ground for the pressed keys, relative to where
+/// the camera looks. `input.y` is forward, `input.x` is right.
+fn walk_direction(camera_rotation: Quat, input: Vec2) -> Vec3 {
+    let forward = (camera_rotation * Vec3::NEG_Z)
+        .with_y(0.0)
+        .normalize_or_zero();
+    let right = Vec3::new(-forward.z, 0.0, forward.x);
+    (forward * input.y + right * input.x).normalize_or_zero()
+}
+
+/// Highest ground below a position, the terrain or a walkable object
+fn ground_position(
+    rapier_context: &RapierContext<'_>,
+    zone_data: &ZoneLoaderAsset,
+    position: Vec3,
+) -> Vec3 {
+    let terrain_height =
+        zone_data.get_terrain_height(position.x * 100.0, -position.z * 100.0) / 100.0;
+
+    let ray_origin = position + Vec3::new(0.0, 1.35, 0.0);
+    let collision_height = rapier_context
+        .cast_ray(
+            ray_origin,
+            Vec3::NEG_Y,
+            10000.0,
+            false,
+            QueryFilter::new().groups(CollisionGroups::new(
+                COLLISION_FILTER_MOVEABLE,
+                !COLLISION_GROUP_PHYSICS_TOY,
+            )),
+        )
+        .map(|(_, distance)| ray_origin.y - distance);
+
+    let height = collision_height.map_or(terrain_height, |collision_height| {
+        collision_height.max(terrain_height)
+    });
+    Vec3::new(position.x, height, position.z)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_walk_direction() {
+        let forward = walk_direction(Quat::IDENTITY, Vec2::new(0.0, 1.0));
+        assert!(forward.abs_diff_eq(Vec3::NEG_Z, 1e-5));
+
+        let right = walk_direction(Quat::IDENTITY, Vec2::new(1.0, 0.0));
+        assert!(right.abs_diff_eq(Vec3::X, 1e-5));
+
+        // Looking down does not slow walking forward
+        let looking_down = Quat::from_rotation_x(-0.5);
+        let forward = walk_direction(looking_down, Vec2::new(0.0, 1.0));
+        assert!(forward.abs_diff_eq(Vec3::NEG_Z, 1e-5));
+
+        // Turned left a quarter, forward is -X
+        let turned = Quat::from_rotation_y(std::f32::consts::FRAC_PI_2);
+        let forward = walk_direction(turned, Vec2::new(0.0, 1.0));
+        assert!(forward.abs_diff_eq(Vec3::NEG_X, 1e-5));
+
+        let diagonal = walk_direction(Quat::IDENTITY, Vec2::new(1.0, 1.0));
+        assert!((diagonal.length() - 1.0).abs() < 1e-5);
+
+        assert_eq!(walk_direction(Quat::IDENTITY, Vec2::ZERO), Vec3::ZERO);
+    }
+}
@@ -5,11 +5,14 @@
 
 use bevy::prelude::*;
 
+use rose_data::{WarpGateId, ZoneId};
+
 use crate::components::{
-    EventObject, WarpObject, ZoneObject, ZoneObjectPart, ZoneObjectPartCollisionShape,
+    EventObject, WarpDestination, WarpObject, ZoneObject, ZoneObjectPart, ZoneObjectPartCollisionShape,
 };
 use crate::map_editor::components::SelectedInEditor;
 use crate::map_editor::resources::{EditorAction, MapEditorState};
+use crate::resources::GameData;
 
 /// Messages for property changes from the UI
 #[derive(Message, Debug, Clone)]
@@ -53,7 +56,8 @@ pub enum PropertyChangeEvent {
         old_value: String,
         new_value: String,
     },
-    /// Warp object property changed
+    /// Warp object property changed, `warp_id` or `destination` where the
+    /// destination is in the `WarpDestination` IFO name format, empty when unlinked
     WarpObjectChanged {
         entity: Entity,
         property_name: String,
@@ -69,6 +73,25 @@ pub enum PropertyChangeEvent {
     },
 }
 
+/// Longest string the IFO format can store
+const IFO_MAX_STRING_LENGTH: usize = 255;
+
+/// Problem with an event object trigger or function name that would leave the
+/// object without a working script or break the saved IFO
+pub fn event_name_warning(name: &str) -> Option<&'static str> {
+    if name.trim().is_empty() {
+        Some("Name is empty")
+    } else if name.trim() != name {
+        Some("Name has leading or trailing spaces")
+    } else if !name.is_ascii() {
+        Some("Name contains non-ASCII characters")
+    } else if name.len() > IFO_MAX_STRING_LENGTH {
+        Some("Name is longer than 255 characters and will be cut off when saved")
+    } else {
+        None
+    }
+}
+
 /// Resource to store pending property changes (for batch processing)
 #[derive(Resource, Default)]
 pub struct PendingPropertyChanges {
@@ -101,6 +124,7 @@ pub fn property_update_system(
     mut zone_objects: Query<&mut ZoneObject>,
     mut event_objects: Query<&mut EventObject>,
     mut warp_objects: Query<&mut WarpObject>,
+    game_data: Option<Res<GameData>>,
     mut commands: Commands,
 ) {
     for event in events.read() {
@@ -269,6 +293,17 @@ pub fn property_update_system(
                     match property_name.as_str() {
                         "quest_trigger_name" => {
                             event_object.quest_trigger_name = new_value.clone();
+                            
+                            let is_unknown_trigger = game_data.as_ref().is_some_and(|game_data| {
+                                game_data.quests.get_trigger_by_name(new_value).is_none()
+                            });
+                            if !new_value.is_empty() && is_unknown_trigger {
+                                log::warn!(
+                                    "[PropertyUpdate] Quest trigger '{}' of entity {:?} is not in the quest data",
+                                    new_value,
+                                    entity
+                                );
+                            }
                         }
                         "script_function_name" => {
                             event_object.script_function_name = new_value.clone();
@@ -276,9 +311,18 @@ pub fn property_update_system(
                         _ => {}
                     }
                     
+                    if let Some(warning) = event_name_warning(new_value) {
+                        log::warn!(
+                            "[PropertyUpdate] EventObject {} of entity {:?}: {}",
+                            property_name,
+                            entity,
+                            warning
+                        );
+                    }
+                    
                     map_editor_state.push_action(EditorAction::ModifyComponent {
                         entity: *entity,
-                        component_type: "EventObject".to_string(),
+                        component_type: format!("EventObject.{}", property_name),
                         old_value: old_value.clone(),
                         new_value: new_value.clone(),
                     });
@@ -300,11 +344,45 @@ pub fn property_update_system(
                 new_value,
             } => {
                 if let Ok(mut warp_object) = warp_objects.get_mut(*entity) {
-                    // WarpObject has warp_id which is a WarpGateId
-                    // For now, just log the change - actual update would need WarpGateId parsing
+                    let component_type = match property_name.as_str() {
+                        "warp_id" => {
+                            let Ok(warp_id) = new_value.parse::<u16>() else {
+                                log::warn!("[PropertyUpdate] Invalid warp id '{}'", new_value);
+                                continue;
+                            };
+                            warp_object.warp_id = WarpGateId::new(warp_id);
+                            "WarpObject"
+                        }
+                        "destination" => {
+                            match WarpDestination::from_ifo_name(new_value) {
+                                Some(destination) => {
+                                    // Reject links to zones that are not in the zone list
+                                    let zone_exists = game_data.as_ref().is_none_or(|game_data| {
+                                        ZoneId::new(destination.zone_id)
+                                            .and_then(|zone_id| game_data.zone_list.get_zone(zone_id))
+                                            .is_some()
+                                    });
+                                    if !zone_exists {
+                                        log::warn!(
+                                            "[PropertyUpdate] Warp destination zone {} does not exist",
+                                            destination.zone_id
+                                        );
+                                        continue;
+                                    }
+                                    commands.entity(*entity).insert(destination);
+                                }
+                                None => {
+                                    commands.entity(*entity).remove::<WarpDestination>();
+                                }
+                            }
+                            "WarpDestination"
+                        }
+                        _ => "WarpObject",
+                    };
+                    
                     map_editor_state.push_action(EditorAction::ModifyComponent {
                         entity: *entity,
-                        component_type: "WarpObject".to_string(),
+                        component_type: component_type.to_string(),
                         old_value: old_value.clone(),
                         new_value: new_value.clone(),
                     });
@@ -385,221 +463,6 @@ pub fn property_update_system(
     }
 }
 
-/// System to apply undo operations
-pub fn apply_undo_system(
-    mut map_editor_state: ResMut<MapEditorState>,
-    mut transforms: Query<&mut Transform>,
-    mut commands: Commands,
-    keyboard: Res<ButtonInput<KeyCode>>,
-) {
-    // Check for Ctrl+Z (undo)
-    if keyboard.just_pressed(KeyCode::KeyZ) && (keyboard.pressed(KeyCode::ControlLeft) || keyboard.pressed(KeyCode::ControlRight)) {
-        if !keyboard.pressed(KeyCode::ShiftLeft) && !keyboard.pressed(KeyCode::ShiftRight) {
-            if let Some(action) = map_editor_state.pop_undo() {
-                apply_undo_action(action, &mut transforms, &mut commands, &mut map_editor_state);
-                log::info!("[PropertyUpdate] Undo applied");
-            }
-        }
-    }
-    
-    // Check for Ctrl+Y or Ctrl+Shift+Z (redo)
-    if keyboard.just_pressed(KeyCode::KeyY) && (keyboard.pressed(KeyCode::ControlLeft) || keyboard.pressed(KeyCode::ControlRight)) {
-        if let Some(action) = map_editor_state.pop_redo() {
-            apply_redo_action(action, &mut transforms, &mut commands, &mut map_editor_state);
-            log::info!("[PropertyUpdate] Redo applied");
-        }
-    }
-    
-    // Also handle Ctrl+Shift+Z for redo
-    if keyboard.just_pressed(KeyCode::KeyZ) && 
-       (keyboard.pressed(KeyCode::ControlLeft) || keyboard.pressed(KeyCode::ControlRight)) &&
-       (keyboard.pressed(KeyCode::ShiftLeft) || keyboard.pressed(KeyCode::ShiftRight)) {
-        if let Some(action) = map_editor_state.pop_redo() {
-            apply_redo_action(action, &mut transforms, &mut commands, &mut map_editor_state);
-            log::info!("[PropertyUpdate] Redo applied (Ctrl+Shift+Z)");
-        }
-    }
-}
-
-/// Apply an undo action
-fn apply_undo_action(
-    action: EditorAction,
-    transforms: &mut Query<&mut Transform>,
-    commands: &mut Commands,
-    map_editor_state: &mut MapEditorState,
-) {
-    match action {
-        EditorAction::TransformEntity {
-            entity,
-            old_transform,
-            new_transform,
-        } => {
-            if let Ok(mut transform) = transforms.get_mut(entity) {
-                *transform = old_transform;
-                
-                // Push to redo stack
-                map_editor_state.push_redo(EditorAction::TransformEntity {
-                    entity,
-                    old_transform,
-                    new_transform,
-                });
-            }
-        }
-        
-        EditorAction::TransformEntities { entities } => {
-            let mut redo_entities = Vec::new();
-            for (entity, old_transform, new_transform) in entities {
-                if let Ok(mut transform) = transforms.get_mut(entity) {
-                    *transform = old_transform;
-                    redo_entities.push((entity, old_transform, new_transform));
-                }
-            }
-            map_editor_state.push_redo(EditorAction::TransformEntities {
-                entities: redo_entities,
-            });
-        }
-        
-        EditorAction::AddEntity { entity } => {
-            // Undo add = delete
-            commands.entity(entity).despawn();
-            map_editor_state.push_redo(EditorAction::AddEntity { entity });
-        }
-        
-        EditorAction::AddEntities { entities } => {
-            for entity in &entities {
-                commands.entity(*entity).despawn();
-            }
-            map_editor_state.push_redo(EditorAction::AddEntities { entities });
-        }
-        
-        EditorAction::DeleteEntity {
-            entity,
-            transform,
-            entity_type,
-            serialized_data,
-        } => {
-            // Undo delete = recreate entity (simplified - would need proper deserialization)
-            log::info!(
-                "[PropertyUpdate] Would recreate entity {:?} of type {}",
-                entity,
-                entity_type
-            );
-            // This would require more complex entity recreation logic
-            map_editor_state.push_redo(EditorAction::DeleteEntity {
-                entity,
-                transform,
-                entity_type,
-                serialized_data,
-            });
-        }
-        
-        EditorAction::DeleteEntities { entities } => {
-            for (entity, transform, entity_type, serialized_data) in entities {
-                log::info!(
-                    "[PropertyUpdate] Would recreate entity {:?} of type {}",
-                    entity,
-                    entity_type
-                );
-            }
-        }
-        
-        EditorAction::ModifyComponent {
-            entity,
-            component_type,
-            old_value,
-            new_value,
-        } => {
-            // Component modification undo would need component-specific handling
-            log::info!(
-                "[PropertyUpdate] Would undo component {} modification for {:?}: {} <- {}",
-                component_type,
-                entity,
-                old_value,
-                new_value
-            );
-            map_editor_state.push_redo(EditorAction::ModifyComponent {
-                entity,
-                component_type,
-                old_value,
-                new_value,
-            });
-        }
-    }
-}
-
-/// Apply a redo action
-fn apply_redo_action(
-    action: EditorAction,
-    transforms: &mut Query<&mut Transform>,
-    commands: &mut Commands,
-    map_editor_state: &mut MapEditorState,
-) {
-    match action {
-        EditorAction::TransformEntity {
-            entity,
-            old_transform,
-            new_transform,
-        } => {
-            if let Ok(mut transform) = transforms.get_mut(entity) {
-                *transform = new_transform;
-                
-                // Push back to undo stack
-                map_editor_state.push_action(EditorAction::TransformEntity {
-                    entity,
-                    old_transform,
-                    new_transform,
-                });
-            }
-        }
-        
-        EditorAction::TransformEntities { entities } => {
-            let mut undo_entities = Vec::new();
-            for (entity, old_transform, new_transform) in entities {
-                if let Ok(mut transform) = transforms.get_mut(entity) {
-                    *transform = new_transform;
-                    undo_entities.push((entity, old_transform, new_transform));
-                }
-            }
-            // Don't push to undo stack here to avoid infinite loop
-            // The push_action would clear redo stack
-        }
-        
-        EditorAction::AddEntity { entity } => {
-            // Redo add = the entity should already exist
-            log::info!("[PropertyUpdate] Redo AddEntity for {:?}", entity);
-        }
-        
-        EditorAction::AddEntities { entities } => {
-            log::info!("[PropertyUpdate] Redo AddEntities for {} entities", entities.len());
-        }
-        
-        EditorAction::DeleteEntity { entity, .. } => {
-            commands.entity(entity).despawn();
-        }
-        
-        EditorAction::DeleteEntities { entities } => {
-            for (entity, ..) in entities {
-                commands.entity(entity).despawn();
-            }
-        }
-        
-        EditorAction::ModifyComponent {
-            entity,
-            component_type,
-            old_value,
-            new_value,
-        } => {
-            log::info!(
-                "[PropertyUpdate] Would redo component {} modification for {:?}: {} -> {}",
-                component_type,
-                entity,
-                old_value,
-                new_value
-            );
-        }
-    }
-}
-
 /// Plugin for property update systems
 pub struct PropertyUpdatePlugin;
 
@@ -607,9 +470,6 @@ impl Plugin for PropertyUpdatePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<PendingPropertyChanges>()
             .add_message::<PropertyChangeEvent>()
-            .add_systems(Update, (
-                property_update_system,
-                apply_undo_system,
-            ).chain());
+            .add_systems(Update, property_update_system);
     }
 }
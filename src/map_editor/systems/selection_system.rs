@@ -2,12 +2,13 @@
 //! 
 //! This module provides raycast-based entity picking for the map editor.
 //! It handles click-based selection with multi-select support via Ctrl modifier.
+//! Clicking the terrain selects the terrain block instead.
 
 use bevy::{
     input::ButtonInput,
     prelude::{
-        App, Camera, Camera3d, Commands, Entity, GlobalTransform, IntoScheduleConfigs, KeyCode, 
-        MouseButton, Plugin, Query, Res, ResMut, Update, With, Added, Or, Without,
+        App, Camera, Camera3d, Commands, Entity, GlobalTransform, InheritedVisibility, IntoScheduleConfigs,
+        KeyCode, MouseButton, Plugin, Query, Res, ResMut, Update, With, Added, Or, Without,
     },
     window::{PrimaryWindow, Window},
 };
@@ -16,10 +17,10 @@ use bevy_rapier3d::prelude::{CollisionGroups, Group, QueryFilter};
 use bevy_rapier3d::plugin::context::systemparams::ReadRapierContext;
 
 use crate::{
-    components::{COLLISION_FILTER_INSPECTABLE, ColliderParent},
+    components::{COLLISION_FILTER_INSPECTABLE, ColliderParent, ZoneObject},
     map_editor::{
         components::{EditorSelectable, SelectedInEditor},
-        resources::MapEditorState,
+        resources::{MapEditorState, TerrainBlock},
     },
 };
 
@@ -56,6 +57,8 @@ pub fn editor_picking_system(
     query_collider_parent: Query<&ColliderParent>,
     query_selectable: Query<&EditorSelectable>,
     query_selected: Query<Entity, With<SelectedInEditor>>,
+    query_visibility: Query<&InheritedVisibility>,
+    query_zone_object: Query<&ZoneObject>,
 ) {
     // Only run when map editor is enabled
     if !map_editor_state.enabled {
@@ -68,6 +71,16 @@ pub fn editor_picking_system(
         return;
     }
 
+    // Measure mode clicks pick ruler points instead, and Escape cancels the measurement
+    if map_editor_state.editor_mode == crate::map_editor::resources::EditorMode::Measure {
+        return;
+    }
+
+    // Sculpt mode clicks drag the terrain brush instead
+    if map_editor_state.editor_mode == crate::map_editor::resources::EditorMode::Sculpt {
+        return;
+    }
+
     // Get rapier context
     let Ok(rapier_context) = rapier_context.single() else {
         return;
@@ -96,16 +109,23 @@ pub fn editor_picking_system(
                 continue;
             };
 
-            // Cast ray to find entity
+            // Cast ray to find entity, skipping objects hidden in the editor
+            let is_visible = |collider_entity: Entity| {
+                query_visibility
+                    .get(collider_entity)
+                    .map_or(true, |visibility| visibility.get())
+            };
             let hit_result = rapier_context.cast_ray(
                 ray.origin,
                 *ray.direction,
                 10000000.0,
                 false,
-                QueryFilter::new().groups(CollisionGroups::new(
-                    COLLISION_FILTER_INSPECTABLE,
-                    Group::all(),
-                )),
+                QueryFilter::new()
+                    .groups(CollisionGroups::new(
+                        COLLISION_FILTER_INSPECTABLE,
+                        Group::all(),
+                    ))
+                    .predicate(&is_visible),
             );
 
             if let Some((hit_entity, _distance)) = hit_result {
@@ -129,6 +149,27 @@ pub fn editor_picking_system(
                 let ctrl_pressed = keyboard.pressed(KeyCode::ControlLeft) 
                     || keyboard.pressed(KeyCode::ControlRight);
 
+                // The terrain selects its block, which is not part of a multi-selection
+                if let Ok(ZoneObject::Terrain(terrain)) = query_zone_object.get(target_entity) {
+                    if !ctrl_pressed {
+                        for entity in query_selected.iter() {
+                            commands.entity(entity).remove::<SelectedInEditor>();
+                        }
+                        map_editor_state.clear_selection();
+                        map_editor_state.selected_terrain_block = Some(TerrainBlock {
+                            entity: target_entity,
+                            block_x: terrain.block_x,
+                            block_y: terrain.block_y,
+                        });
+                        log::debug!(
+                            "[MapEditor] Selected terrain block {}_{}",
+                            terrain.block_x,
+                            terrain.block_y
+                        );
+                    }
+                    break;
+                }
+
                 if ctrl_pressed {
                     // Toggle selection
                     if map_editor_state.selected_entities.contains(&target_entity) {
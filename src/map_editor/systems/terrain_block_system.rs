@@ -0,0 +1,500 @@
+//! Terrain Block System for the Map Editor
+//!
+//! Clicking the terrain selects its block instead of an object. The selected
+//! block is outlined on the terrain and the properties panel offers block
+//! operations: flatten, copy heightmap and paste heightmap.
+//!
+//! Operations are recorded as terrain edits, so they can be undone, and are
+//! applied like undone or redone terrain edits: through `TerrainEditEvent`,
+//! which writes the heights into the zone's heightmaps, updates the block's
+//! mesh and collider and marks the block dirty for saving. Colliders of blocks
+//! changed by a brush stroke are rebuilt once, when the stroke ends.
+
+use std::collections::{HashMap, HashSet};
+
+use bevy::camera::primitives::Aabb;
+use bevy::prelude::*;
+use bevy_mesh::{Indices, VertexAttributeValues};
+use bevy_rapier3d::prelude::Collider;
+
+use crate::components::ZoneObject;
+use crate::map_editor::resources::{
+    EditorAction, HeightmapClipboard, MapEditorState, TerrainBlockEvent, TerrainBlockOperation,
+    TerrainCell, TerrainCellDelta, TerrainEditEvent, TerrainLayer,
+};
+use crate::render::TERRAIN_MESH_ATTRIBUTE_TILE_INFO;
+use crate::resources::CurrentZone;
+use crate::terrain::GlobalTerrainNoise;
+use crate::zone_loader::{terrain_collider, ZoneLoaderAsset, ZoneLoaderBlock};
+
+/// Size of a terrain block in metres
+pub const BLOCK_SIZE: f32 = 160.0;
+
+/// Distance between two heightmap vertices in metres
+pub const HEIGHTMAP_SPACING: f32 = 2.5;
+
+/// Plugin for terrain block selection and operations
+pub struct TerrainBlockPlugin;
+
+impl Plugin for TerrainBlockPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<TerrainBlockEvent>().add_systems(
+            Update,
+            (
+                terrain_block_event_system,
+                terrain_edit_system.after(terrain_block_event_system),
+                draw_terrain_block_selection,
+            ),
+        );
+    }
+}
+
+/// Translation of a terrain block entity
+pub fn block_origin(block_x: u32, block_y: u32) -> Vec3 {
+    Vec3::new(
+        BLOCK_SIZE * block_x as f32 - 5200.0,
+        0.0,
+        -BLOCK_SIZE * (65.0 - block_y as f32) + 5200.0,
+    )
+}
+
+/// Loaded data of a terrain block
+pub fn zone_block(zone_data: &ZoneLoaderAsset, block_x: u32, block_y: u32) -> Option<&ZoneLoaderBlock> {
+    zone_data
+        .blocks
+        .get(block_x as usize + block_y as usize * 64)
+        .and_then(|block| block.as_deref())
+}
+
+/// Lowest, highest and average height of a heightmap, in HIM units
+pub fn height_range(heights: &[f32]) -> Option<(f32, f32, f32)> {
+    if heights.is_empty() {
+        return None;
+    }
+    let min = heights.iter().copied().fold(f32::MAX, f32::min);
+    let max = heights.iter().copied().fold(f32::MIN, f32::max);
+    let average = heights.iter().sum::<f32>() / heights.len() as f32;
+    Some((min, max, average))
+}
+
+/// Changes from a block's heights to new heights, only for the vertices that change
+pub fn height_deltas(
+    block_x: u32,
+    block_y: u32,
+    old_heights: &[f32],
+    new_height: impl Fn(usize) -> f32,
+) -> Vec<TerrainCellDelta> {
+    old_heights
+        .iter()
+        .enumerate()
+        .filter_map(|(index, &old_value)| {
+            let new_value = new_height(index);
+            (new_value != old_value).then_some(TerrainCellDelta {
+                cell: TerrainCell {
+                    block_x,
+                    block_y,
+                    layer: TerrainLayer::Height,
+                    index,
+                },
+                old_value,
+                new_value,
+            })
+        })
+        .collect()
+}
+
+/// System to apply terrain block operations from the properties panel
+pub fn terrain_block_event_system(
+    mut events: MessageReader<TerrainBlockEvent>,
+    mut map_editor_state: ResMut<MapEditorState>,
+    current_zone: Option<Res<CurrentZone>>,
+    zone_loader_assets: Res<Assets<ZoneLoaderAsset>>,
+    mut terrain_edit_events: MessageWriter<TerrainEditEvent>,
+) {
+    for event in events.read() {
+        let TerrainBlockEvent { block, operation } = *event;
+        let Some(block_data) = current_zone
+            .as_ref()
+            .and_then(|current_zone| zone_loader_assets.get(&current_zone.handle))
+            .and_then(|zone_data| zone_block(zone_data, block.block_x, block.block_y))
+        else {
+            log::warn!("[TerrainBlock] Block {}_{} is not loaded", block.block_x, block.block_y);
+            continue;
+        };
+        let heightmap = &block_data.him;
+
+        let (label, cells) = match operation {
+            TerrainBlockOperation::CopyHeightmap => {
+                map_editor_state.heightmap_clipboard = Some(HeightmapClipboard {
+                    block_x: block.block_x,
+                    block_y: block.block_y,
+                    width: heightmap.width,
+                    height: heightmap.height,
+                    heights: heightmap.heights.clone(),
+                });
+                log::info!("[TerrainBlock] Copied heightmap of block {}_{}", block.block_x, block.block_y);
+                continue;
+            }
+            TerrainBlockOperation::Flatten(height) => (
+                format!("Flatten block {}_{}", block.block_x, block.block_y),
+                height_deltas(block.block_x, block.block_y, &heightmap.heights, |_| height * 100.0),
+            ),
+            TerrainBlockOperation::PasteHeightmap => {
+                let Some(clipboard) = map_editor_state.heightmap_clipboard.as_ref() else {
+                    continue;
+                };
+                if clipboard.width != heightmap.width || clipboard.height != heightmap.height {
+                    log::warn!(
+                        "[TerrainBlock] Can not paste a {}x{} heightmap onto block {}_{} of {}x{}",
+                        clipboard.width,
+                        clipboard.height,
+                        block.block_x,
+                        block.block_y,
+                        heightmap.width,
+                        heightmap.height
+                    );
+                    continue;
+                }
+                (
+                    format!(
+                        "Paste heightmap of {}_{} onto {}_{}",
+                        clipboard.block_x, clipboard.block_y, block.block_x, block.block_y
+                    ),
+                    height_deltas(block.block_x, block.block_y, &heightmap.heights, |index| {
+                        clipboard.heights[index]
+                    }),
+                )
+            }
+        };
+
+        if cells.is_empty() {
+            log::info!("[TerrainBlock] {} changes nothing", label);
+            continue;
+        }
+
+        log::info!("[TerrainBlock] {} ({} vertices)", label, cells.len());
+        terrain_edit_events.write(TerrainEditEvent {
+            cells: cells.iter().map(|delta| (delta.cell, delta.new_value)).collect(),
+        });
+        map_editor_state.push_named_action(label, EditorAction::TerrainEdit { cells });
+    }
+}
+
+/// System to write terrain edits into the zone's heightmaps and update the
+/// mesh and collider of the changed blocks.
+///
+/// Meshes are updated as soon as their heights change. Rebuilding a collider
+/// is much slower, so during a brush stroke it waits until the stroke ends and
+/// is then done once for every block the stroke changed.
+#[allow(clippy::too_many_arguments)]
+pub fn terrain_edit_system(
+    mut commands: Commands,
+    mut events: MessageReader<TerrainEditEvent>,
+    mut map_editor_state: ResMut<MapEditorState>,
+    current_zone: Option<Res<CurrentZone>>,
+    mut zone_loader_assets: ResMut<Assets<ZoneLoaderAsset>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    query_terrain: Query<(Entity, &ZoneObject, &Mesh3d, &Transform)>,
+    terrain_noise: Res<GlobalTerrainNoise>,
+    mut pending_colliders: Local<HashSet<(u32, u32)>>,
+) {
+    let stroke_active = !map_editor_state.terrain_brush.stroke.is_empty();
+    if events.is_empty() && (stroke_active || pending_colliders.is_empty()) {
+        return;
+    }
+    let Some(zone_data) = current_zone
+        .as_ref()
+        .and_then(|current_zone| zone_loader_assets.get_mut(&current_zone.handle))
+    else {
+        events.clear();
+        pending_colliders.clear();
+        return;
+    };
+
+    // How much each heightmap vertex of a changed block moved, in HIM units
+    let mut changed_blocks: HashMap<(u32, u32), Vec<f32>> = HashMap::new();
+    for event in events.read() {
+        for &(cell, value) in event.cells.iter() {
+            let Some(block_data) = zone_data
+                .blocks
+                .get_mut(cell.block_x as usize + cell.block_y as usize * 64)
+                .and_then(|block| block.as_deref_mut())
+            else {
+                continue;
+            };
+
+            match cell.layer {
+                TerrainLayer::Height => {
+                    let heights = &mut block_data.him.heights;
+                    let vertex_count = heights.len();
+                    if let Some(height) = heights.get_mut(cell.index) {
+                        let deltas = changed_blocks
+                            .entry((cell.block_x, cell.block_y))
+                            .or_insert_with(|| vec![0.0; vertex_count]);
+                        deltas[cell.index] += value - *height;
+                        *height = value;
+                    }
+                }
+            }
+        }
+    }
+    pending_colliders.extend(changed_blocks.keys().copied());
+
+    for (entity, zone_object, mesh, transform) in query_terrain.iter() {
+        let ZoneObject::Terrain(terrain) = zone_object else {
+            continue;
+        };
+        let block = (terrain.block_x, terrain.block_y);
+        let deltas = changed_blocks.get(&block);
+        let rebuild_collider = !stroke_active && pending_colliders.contains(&block);
+        if deltas.is_none() && !rebuild_collider {
+            continue;
+        }
+        let Some(block_data) = zone_block(zone_data, terrain.block_x, terrain.block_y) else {
+            continue;
+        };
+        let Some(mesh) = meshes.get_mut(&mesh.0) else {
+            continue;
+        };
+
+        if let Some(deltas) = deltas {
+            update_terrain_mesh(mesh, block_data, deltas, transform.translation, &terrain_noise);
+        }
+
+        if rebuild_collider {
+            // Built the same way as when the block was spawned
+            if mesh.attribute(TERRAIN_MESH_ATTRIBUTE_TILE_INFO).is_some() {
+                commands.entity(entity).insert(terrain_collider(
+                    &block_data.him,
+                    transform.translation,
+                    &terrain_noise,
+                ));
+            } else if let Some((collider, aabb)) = prebuilt_terrain_collider(mesh) {
+                commands.entity(entity).insert((collider, aabb));
+            }
+        }
+    }
+
+    if !stroke_active {
+        pending_colliders.clear();
+    }
+    if !changed_blocks.is_empty() {
+        map_editor_state
+            .dirty_terrain_blocks
+            .extend(changed_blocks.into_keys());
+    }
+}
+
+/// Height change at a point of a block from the change of each heightmap
+/// vertex, interpolated between the four vertices around the point
+fn interpolated_delta(deltas: &[f32], width: usize, x: f32, z: f32) -> f32 {
+    if width == 0 || deltas.is_empty() {
+        return 0.0;
+    }
+    let height = deltas.len() / width;
+    let grid_x = (x / HEIGHTMAP_SPACING).clamp(0.0, (width - 1) as f32);
+    let grid_y = (z / HEIGHTMAP_SPACING).clamp(0.0, (height - 1) as f32);
+    let x0 = grid_x.floor() as usize;
+    let y0 = grid_y.floor() as usize;
+    let x1 = (x0 + 1).min(width - 1);
+    let y1 = (y0 + 1).min(height - 1);
+    let tx = grid_x - x0 as f32;
+    let ty = grid_y - y0 as f32;
+
+    let top = deltas[x0 + y0 * width] * (1.0 - tx) + deltas[x1 + y0 * width] * tx;
+    let bottom = deltas[x0 + y1 * width] * (1.0 - tx) + deltas[x1 + y1 * width] * tx;
+    top * (1.0 - ty) + bottom * ty
+}
+
+/// Update the heights and normals of a terrain block mesh after its heightmap
+/// changed by `deltas`. Meshes generated by `spawn_terrain` get their heights
+/// from the heightmap the same way, the prebuilt meshes of --new-terrain have
+/// each vertex moved by the height change under it.
+fn update_terrain_mesh(
+    mesh: &mut Mesh,
+    block_data: &ZoneLoaderBlock,
+    deltas: &[f32],
+    origin: Vec3,
+    terrain_noise: &GlobalTerrainNoise,
+) {
+    let heightmap = &block_data.him;
+    let is_generated = mesh.attribute(TERRAIN_MESH_ATTRIBUTE_TILE_INFO).is_some();
+    let terrain_height = |heightmap_x: i32, heightmap_y: i32| {
+        let height = heightmap.get_clamped(heightmap_x, heightmap_y) / 100.0;
+        if is_generated {
+            let world_x = origin.x + heightmap_x as f32 * HEIGHTMAP_SPACING;
+            let world_z = origin.z + heightmap_y as f32 * HEIGHTMAP_SPACING;
+            height + terrain_noise.get_noise(world_x, world_z)
+        } else {
+            height
+        }
+    };
+
+    let Some(VertexAttributeValues::Float32x3(positions)) =
+        mesh.attribute_mut(Mesh::ATTRIBUTE_POSITION)
+    else {
+        return;
+    };
+
+    // Vertices of neighbouring tiles are duplicated, find each one's heightmap vertex by position
+    let mut changed_normals = Vec::new();
+    for (index, position) in positions.iter_mut().enumerate() {
+        let heightmap_x = (position[0] / HEIGHTMAP_SPACING).round() as i32;
+        let heightmap_y = (position[2] / HEIGHTMAP_SPACING).round() as i32;
+        if is_generated {
+            position[1] = terrain_height(heightmap_x, heightmap_y);
+        } else {
+            let delta =
+                interpolated_delta(deltas, heightmap.width as usize, position[0], position[2]);
+            if delta == 0.0 {
+                continue;
+            }
+            position[1] += delta / 100.0;
+        }
+
+        let normal = Vec3::new(
+            (terrain_height(heightmap_x - 1, heightmap_y) - terrain_height(heightmap_x + 1, heightmap_y)) / 2.0,
+            1.0,
+            (terrain_height(heightmap_x, heightmap_y - 1) - terrain_height(heightmap_x, heightmap_y + 1)) / 2.0,
+        )
+        .normalize();
+        changed_normals.push((index, [normal.x, normal.y, normal.z]));
+    }
+
+    if let Some(VertexAttributeValues::Float32x3(normals)) =
+        mesh.attribute_mut(Mesh::ATTRIBUTE_NORMAL)
+    {
+        for (index, normal) in changed_normals {
+            normals[index] = normal;
+        }
+    }
+}
+
+/// Collider and bounds of a prebuilt --new-terrain mesh, built from its
+/// triangles like `spawn_new_terrain` does
+fn prebuilt_terrain_collider(mesh: &Mesh) -> Option<(Collider, Aabb)> {
+    let Some(VertexAttributeValues::Float32x3(positions)) =
+        mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+    else {
+        return None;
+    };
+    let Some(Indices::U32(indices)) = mesh.indices() else {
+        return None;
+    };
+
+    let mut min = Vec3::splat(f32::MAX);
+    let mut max = Vec3::splat(f32::MIN);
+    let vertices: Vec<Vec3> = positions
+        .iter()
+        .map(|&position| {
+            let position = Vec3::from(position);
+            min = min.min(position);
+            max = max.max(position);
+            position
+        })
+        .collect();
+    let triangles = indices
+        .chunks_exact(3)
+        .map(|triangle| [triangle[0], triangle[1], triangle[2]])
+        .collect();
+
+    let collider = Collider::trimesh(vertices, triangles).ok()?;
+    Some((collider, Aabb::from_min_max(min, max)))
+}
+
+/// System to outline the selected terrain block on the terrain
+pub fn draw_terrain_block_selection(
+    map_editor_state: Res<MapEditorState>,
+    current_zone: Option<Res<CurrentZone>>,
+    zone_loader_assets: Res<Assets<ZoneLoaderAsset>>,
+    terrain_noise: Res<GlobalTerrainNoise>,
+    mut gizmos: Gizmos,
+) {
+    if !map_editor_state.enabled {
+        return;
+    }
+    let Some(block) = map_editor_state.selected_terrain_block else {
+        return;
+    };
+    let Some(block_data) = current_zone
+        .as_ref()
+        .and_then(|current_zone| zone_loader_assets.get(&current_zone.handle))
+        .and_then(|zone_data| zone_block(zone_data, block.block_x, block.block_y))
+    else {
+        return;
+    };
+
+    // Orange, distinct from the cyan object selection
+    let color = Color::srgba(1.0, 0.5, 0.0, 1.0);
+    let heightmap = &block_data.him;
+    let origin = block_origin(block.block_x, block.block_y);
+    let last_x = heightmap.width as i32 - 1;
+    let last_y = heightmap.height as i32 - 1;
+    let point = |x: i32, y: i32| {
+        let world_x = origin.x + x as f32 * HEIGHTMAP_SPACING;
+        let world_z = origin.z + y as f32 * HEIGHTMAP_SPACING;
+        let height = heightmap.get_clamped(x, y) / 100.0 + terrain_noise.get_noise(world_x, world_z);
+        Vec3::new(world_x, height + 0.2, world_z)
+    };
+
+    // Follow the terrain along the edges of the block
+    let edges = [
+        (0..=last_x).map(|x| point(x, 0)).collect::<Vec<_>>(),
+        (0..=last_y).map(|y| point(last_x, y)).collect(),
+        (0..=last_x).rev().map(|x| point(x, last_y)).collect(),
+        (0..=last_y).rev().map(|y| point(0, y)).collect(),
+    ];
+    for edge in edges {
+        gizmos.linestrip(edge, color);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_height_deltas() {
+        let heights = [100.0, 250.0, 100.0, -50.0];
+
+        let flatten = height_deltas(3, 4, &heights, |_| 100.0);
+        assert_eq!(flatten.len(), 2);
+        assert_eq!(flatten[0].cell.index, 1);
+        assert_eq!(flatten[0].cell.block_x, 3);
+        assert_eq!(flatten[0].cell.block_y, 4);
+        assert_eq!(flatten[0].old_value, 250.0);
+        assert_eq!(flatten[0].new_value, 100.0);
+        assert_eq!(flatten[1].cell.index, 3);
+
+        let clipboard = [100.0, 250.0, 0.0, -50.0];
+        let paste = height_deltas(0, 0, &heights, |index| clipboard[index]);
+        assert_eq!(paste.len(), 1);
+        assert_eq!(paste[0].cell.index, 2);
+
+        assert_eq!(height_range(&heights), Some((-50.0, 250.0, 100.0)));
+        assert_eq!(height_range(&[]), None);
+    }
+
+    #[test]
+    fn test_interpolated_delta() {
+        // 3x3 heightmap with only the middle vertex raised
+        let mut deltas = [0.0; 9];
+        deltas[4] = 100.0;
+
+        assert_eq!(interpolated_delta(&deltas, 3, 2.5, 2.5), 100.0);
+        assert_eq!(interpolated_delta(&deltas, 3, 0.0, 0.0), 0.0);
+        assert_eq!(interpolated_delta(&deltas, 3, 1.25, 2.5), 50.0);
+        assert_eq!(interpolated_delta(&deltas, 3, 1.25, 1.25), 25.0);
+
+        // Points past the edge use the nearest edge vertex
+        assert_eq!(interpolated_delta(&deltas, 3, 2.5, 10.0), 0.0);
+        assert_eq!(interpolated_delta(&[], 3, 2.5, 2.5), 0.0);
+    }
+
+    #[test]
+    fn test_block_origin() {
+        // Block 32_32 starts at the zone centre, 5200 - 160 * 32 = 80 metres before it
+        assert_eq!(block_origin(32, 32), Vec3::new(-80.0, 0.0, -80.0));
+        assert_eq!(block_origin(33, 33), Vec3::new(80.0, 0.0, 80.0));
+    }
+}
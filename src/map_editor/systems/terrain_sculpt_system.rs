@@ -0,0 +1,282 @@
+//! Terrain Sculpt System for the Map Editor
+//!
+//! In Sculpt mode, holding the left mouse button on the terrain raises the
+//! heightmap vertices under the brush, or lowers them with Shift held. The
+//! heights are applied through `TerrainEditEvent` while dragging and the whole
+//! stroke becomes a single undo entry when the mouse is released.
+
+use std::f32::consts::FRAC_PI_2;
+
+use bevy::{prelude::*, window::PrimaryWindow};
+use bevy_egui::EguiContexts;
+use bevy_rapier3d::plugin::context::systemparams::ReadRapierContext;
+use bevy_rapier3d::prelude::{CollisionGroups, QueryFilter};
+
+use crate::components::{COLLISION_FILTER_INSPECTABLE, COLLISION_GROUP_ZONE_TERRAIN};
+use crate::map_editor::resources::{
+    EditorMode, MapEditorState, TerrainCell, TerrainEditEvent, TerrainLayer,
+};
+use crate::map_editor::systems::terrain_block_system::{
+    block_origin, terrain_edit_system, zone_block, BLOCK_SIZE, HEIGHTMAP_SPACING,
+};
+use crate::resources::CurrentZone;
+use crate::zone_loader::ZoneLoaderAsset;
+
+const BRUSH_COLOR: Color = Color::srgba(0.3, 1.0, 0.4, 0.8);
+
+/// Plugin for the terrain sculpt brush
+pub struct TerrainSculptPlugin;
+
+impl Plugin for TerrainSculptPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                terrain_sculpt_system
+                    .after(bevy_egui::EguiPreUpdateSet::InitContexts)
+                    .before(terrain_edit_system),
+                draw_terrain_brush,
+            ),
+        );
+    }
+}
+
+/// How much of the brush strength applies at a distance from its centre,
+/// 1 at the centre fading smoothly to 0 at the radius
+pub fn brush_falloff(distance: f32, radius: f32) -> f32 {
+    if radius <= 0.0 || distance >= radius {
+        return 0.0;
+    }
+    let t = distance / radius;
+    (1.0 - t * t).powi(2)
+}
+
+/// New heights of the vertices of a heightmap under the brush, as
+/// `(index, old height, new height)` in HIM units
+pub fn sculpt_heights(
+    heights: &[f32],
+    width: usize,
+    origin: Vec3,
+    center: Vec3,
+    radius: f32,
+    amount: f32,
+) -> Vec<(usize, f32, f32)> {
+    if width == 0 {
+        return Vec::new();
+    }
+    let height = heights.len() / width;
+
+    // Only visit the vertices inside the brush's bounding square
+    let first_x = ((center.x - radius - origin.x) / HEIGHTMAP_SPACING).ceil().max(0.0) as usize;
+    let last_x = ((center.x + radius - origin.x) / HEIGHTMAP_SPACING).floor();
+    let first_y = ((center.z - radius - origin.z) / HEIGHTMAP_SPACING).ceil().max(0.0) as usize;
+    let last_y = ((center.z + radius - origin.z) / HEIGHTMAP_SPACING).floor();
+    if last_x < 0.0 || last_y < 0.0 {
+        return Vec::new();
+    }
+    let last_x = (last_x as usize).min(width - 1);
+    let last_y = (last_y as usize).min(height.saturating_sub(1));
+
+    let mut changes = Vec::new();
+    for y in first_y..=last_y {
+        for x in first_x..=last_x {
+            let vertex_x = origin.x + x as f32 * HEIGHTMAP_SPACING;
+            let vertex_z = origin.z + y as f32 * HEIGHTMAP_SPACING;
+            let distance = Vec2::new(vertex_x - center.x, vertex_z - center.z).length();
+            let falloff = brush_falloff(distance, radius);
+            if falloff <= 0.0 {
+                continue;
+            }
+
+            let index = x + y * width;
+            let old_height = heights[index];
+            changes.push((index, old_height, old_height + amount * falloff));
+        }
+    }
+    changes
+}
+
+/// System to sculpt the terrain under the cursor in Sculpt mode
+#[allow(clippy::too_many_arguments)]
+pub fn terrain_sculpt_system(
+    mut map_editor_state: ResMut<MapEditorState>,
+    mut egui_ctx: EguiContexts,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+    rapier_context: ReadRapierContext,
+    query_window: Query<&Window, With<PrimaryWindow>>,
+    query_camera: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    current_zone: Option<Res<CurrentZone>>,
+    zone_loader_assets: Res<Assets<ZoneLoaderAsset>>,
+    mut terrain_edit_events: MessageWriter<TerrainEditEvent>,
+) {
+    // A stroke ends when the mouse is released or the brush is put away
+    let is_sculpting = map_editor_state.enabled
+        && map_editor_state.editor_mode == EditorMode::Sculpt
+        && mouse_input.pressed(MouseButton::Left);
+    if !is_sculpting && !map_editor_state.terrain_brush.stroke.is_empty() {
+        if let Some(action) = map_editor_state.terrain_brush.stroke.finish() {
+            map_editor_state.push_named_action("Sculpt terrain", action);
+        }
+    }
+
+    if !map_editor_state.enabled || map_editor_state.editor_mode != EditorMode::Sculpt {
+        map_editor_state.terrain_brush.hover_point = None;
+        return;
+    }
+
+    let Ok(ctx) = egui_ctx.ctx_mut() else {
+        return;
+    };
+
+    // Skip if egui wants pointer input (mouse is over UI), unless dragging a stroke over it
+    if ctx.wants_pointer_input() && map_editor_state.terrain_brush.stroke.is_empty() {
+        map_editor_state.terrain_brush.hover_point = None;
+        return;
+    }
+
+    let Ok(rapier_context) = rapier_context.single() else {
+        return;
+    };
+    let Ok(window) = query_window.single() else {
+        return;
+    };
+    let Some(cursor_position) = window.cursor_position() else {
+        map_editor_state.terrain_brush.hover_point = None;
+        return;
+    };
+    let Ok((camera, camera_transform)) = query_camera.single() else {
+        return;
+    };
+    let Ok(ray) = camera.viewport_to_world(camera_transform, cursor_position) else {
+        return;
+    };
+
+    let hover_point = rapier_context
+        .cast_ray(
+            ray.origin,
+            *ray.direction,
+            10000000.0,
+            true,
+            QueryFilter::new().groups(CollisionGroups::new(
+                COLLISION_FILTER_INSPECTABLE,
+                COLLISION_GROUP_ZONE_TERRAIN,
+            )),
+        )
+        .map(|(_, distance)| ray.origin + *ray.direction * distance);
+    map_editor_state.terrain_brush.hover_point = hover_point;
+
+    if !is_sculpting {
+        return;
+    }
+    let Some(center) = hover_point else {
+        return;
+    };
+    let Some(zone_data) = current_zone
+        .as_ref()
+        .and_then(|current_zone| zone_loader_assets.get(&current_zone.handle))
+    else {
+        return;
+    };
+
+    let brush = &mut map_editor_state.terrain_brush;
+    let direction = if keyboard.pressed(KeyCode::ShiftLeft) || keyboard.pressed(KeyCode::ShiftRight) {
+        -1.0
+    } else {
+        1.0
+    };
+    // Strength is in metres per second, heights are in HIM units
+    let amount = direction * brush.strength * 100.0 * time.delta_secs();
+    let radius = brush.radius;
+
+    // Neighbouring blocks share their edge vertices, both copies are moved the same amount
+    let block_range = |min: f32, max: f32| {
+        (min.floor().max(0.0) as u32)..=(max.floor().clamp(0.0, 63.0) as u32)
+    };
+    let blocks_x = block_range(
+        (center.x - radius + 5200.0) / BLOCK_SIZE,
+        (center.x + radius + 5200.0) / BLOCK_SIZE,
+    );
+    let blocks_y = block_range(
+        (center.z - radius - 5200.0) / BLOCK_SIZE + 65.0,
+        (center.z + radius - 5200.0) / BLOCK_SIZE + 65.0,
+    );
+
+    let mut cells = Vec::new();
+    for block_y in blocks_y {
+        for block_x in blocks_x.clone() {
+            let Some(block_data) = zone_block(zone_data, block_x, block_y) else {
+                continue;
+            };
+            let heightmap = &block_data.him;
+            for (index, old_height, new_height) in sculpt_heights(
+                &heightmap.heights,
+                heightmap.width as usize,
+                block_origin(block_x, block_y),
+                center,
+                radius,
+                amount,
+            ) {
+                let cell = TerrainCell {
+                    block_x,
+                    block_y,
+                    layer: TerrainLayer::Height,
+                    index,
+                };
+                brush.stroke.record(cell, old_height, new_height);
+                cells.push((cell, new_height));
+            }
+        }
+    }
+
+    if !cells.is_empty() {
+        terrain_edit_events.write(TerrainEditEvent { cells });
+    }
+}
+
+/// System to outline the brush on the terrain
+pub fn draw_terrain_brush(mut gizmos: Gizmos, map_editor_state: Res<MapEditorState>) {
+    if !map_editor_state.enabled || map_editor_state.editor_mode != EditorMode::Sculpt {
+        return;
+    }
+
+    let brush = &map_editor_state.terrain_brush;
+    if let Some(hover_point) = brush.hover_point {
+        gizmos.circle(
+            Isometry3d::new(hover_point + Vec3::Y * 0.2, Quat::from_rotation_x(FRAC_PI_2)),
+            brush.radius,
+            BRUSH_COLOR,
+        );
+        gizmos.sphere(hover_point, 0.15, BRUSH_COLOR);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_brush_falloff() {
+        assert_eq!(brush_falloff(0.0, 10.0), 1.0);
+        assert_eq!(brush_falloff(10.0, 10.0), 0.0);
+        assert_eq!(brush_falloff(1.0, 0.0), 0.0);
+        assert!(brush_falloff(5.0, 10.0) > brush_falloff(8.0, 10.0));
+    }
+
+    #[test]
+    fn test_sculpt_heights() {
+        // 3x3 heightmap covering 5 metres
+        let heights = [0.0; 9];
+        let origin = Vec3::ZERO;
+
+        // Brush centred on the middle vertex only reaches it and its four neighbours
+        let changes = sculpt_heights(&heights, 3, origin, Vec3::new(2.5, 0.0, 2.5), 3.0, 100.0);
+        assert_eq!(changes.len(), 5);
+        assert_eq!(changes.iter().find(|(index, _, _)| *index == 4), Some(&(4, 0.0, 100.0)));
+        assert!(changes.iter().all(|&(index, _, _)| index % 2 == 1 || index == 4));
+
+        // A brush outside the block changes nothing
+        assert!(sculpt_heights(&heights, 3, origin, Vec3::new(-20.0, 0.0, 2.5), 3.0, 100.0).is_empty());
+    }
+}
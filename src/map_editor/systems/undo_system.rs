@@ -5,64 +5,73 @@
 //! - Entity deletion
 //! - Entity duplication
 //! - Component modifications
+//! - Terrain heightmap edits, stored as per-cell deltas
 
 use bevy::prelude::*;
 use bevy_egui::EguiContexts;
 
-use crate::map_editor::components::{EditorSelectable, SelectedInEditor};
-use crate::map_editor::resources::{EditorAction, MapEditorState};
+use rose_data::WarpGateId;
 
-/// Maximum number of undo steps to keep
-const MAX_UNDO_STEPS: usize = 50;
+use crate::components::{EventObject, WarpDestination, WarpObject};
+use crate::map_editor::components::{EditorSelectable, SelectedInEditor};
+use crate::map_editor::resources::{
+    EditorAction, EditorHistoryEntry, HistoryStep, MapEditorState, TerrainCellDelta, TerrainEditEvent,
+};
 
-/// System to handle undo/redo keyboard shortcuts
+/// System to handle undo/redo keyboard shortcuts and history steps requested by the UI
 pub fn undo_redo_system(
     mut map_editor_state: ResMut<MapEditorState>,
     mut transforms: Query<&mut Transform>,
     mut commands: Commands,
     keyboard: Res<ButtonInput<KeyCode>>,
     mut egui_contexts: EguiContexts,
+    mut terrain_edit_events: MessageWriter<TerrainEditEvent>,
 ) {
     // Don't process if editor is disabled
     if !map_editor_state.enabled {
         return;
     }
     
+    let mut step = map_editor_state.pending_history_step.take();
+    
     // Check if egui wants keyboard input
     let ctx = egui_contexts.ctx_mut().unwrap();
-    if ctx.wants_keyboard_input() {
-        return;
-    }
-    
-    let ctrl_pressed = keyboard.pressed(KeyCode::ControlLeft) || keyboard.pressed(KeyCode::ControlRight);
-    let shift_pressed = keyboard.pressed(KeyCode::ShiftLeft) || keyboard.pressed(KeyCode::ShiftRight);
-    
-    // Handle Ctrl+Z (undo) - but not Ctrl+Shift+Z
-    if keyboard.just_pressed(KeyCode::KeyZ) && ctrl_pressed && !shift_pressed {
-        if let Some(action) = map_editor_state.pop_undo() {
-            apply_undo(&mut commands, &mut transforms, action, &mut map_editor_state);
-            log::info!("[UndoRedo] Undo applied, {} steps remaining", map_editor_state.undo_stack.len());
-        } else {
-            log::info!("[UndoRedo] Nothing to undo");
+    if step.is_none() && !ctx.wants_keyboard_input() {
+        let ctrl_pressed = keyboard.pressed(KeyCode::ControlLeft) || keyboard.pressed(KeyCode::ControlRight);
+        let shift_pressed = keyboard.pressed(KeyCode::ShiftLeft) || keyboard.pressed(KeyCode::ShiftRight);
+        
+        if ctrl_pressed && keyboard.just_pressed(KeyCode::KeyZ) {
+            // Ctrl+Shift+Z is the alternative redo
+            step = Some(if shift_pressed { HistoryStep::Redo(1) } else { HistoryStep::Undo(1) });
+        } else if ctrl_pressed && !shift_pressed && keyboard.just_pressed(KeyCode::KeyY) {
+            step = Some(HistoryStep::Redo(1));
         }
     }
     
-    // Handle Ctrl+Y (redo)
-    if keyboard.just_pressed(KeyCode::KeyY) && ctrl_pressed && !shift_pressed {
-        if let Some(action) = map_editor_state.pop_redo() {
-            apply_redo(&mut commands, &mut transforms, action, &mut map_editor_state);
-            log::info!("[UndoRedo] Redo applied, {} steps remaining", map_editor_state.redo_stack.len());
-        } else {
-            log::info!("[UndoRedo] Nothing to redo");
+    match step {
+        Some(HistoryStep::Undo(count)) => {
+            for _ in 0..count {
+                let Some(entry) = map_editor_state.pop_undo() else {
+                    log::info!("[UndoRedo] Nothing to undo");
+                    break;
+                };
+                log::info!("[UndoRedo] Undo '{}'", entry.label);
+                apply_undo(&mut commands, &mut transforms, &mut terrain_edit_events, entry, &mut map_editor_state);
+            }
+            log::info!("[UndoRedo] {} undo steps remaining", map_editor_state.undo_stack.len());
         }
-    }
-    
-    // Handle Ctrl+Shift+Z (redo - alternative)
-    if keyboard.just_pressed(KeyCode::KeyZ) && ctrl_pressed && shift_pressed {
-        if let Some(action) = map_editor_state.pop_redo() {
-            apply_redo(&mut commands, &mut transforms, action, &mut map_editor_state);
-            log::info!("[UndoRedo] Redo applied (Ctrl+Shift+Z), {} steps remaining", map_editor_state.redo_stack.len());
+        Some(HistoryStep::Redo(count)) => {
+            for _ in 0..count {
+                let Some(entry) = map_editor_state.pop_redo() else {
+                    log::info!("[UndoRedo] Nothing to redo");
+                    break;
+                };
+                log::info!("[UndoRedo] Redo '{}'", entry.label);
+                apply_redo(&mut commands, &mut transforms, &mut terrain_edit_events, entry, &mut map_editor_state);
+            }
+            log::info!("[UndoRedo] {} redo steps remaining", map_editor_state.redo_stack.len());
         }
+        None => {}
     }
 }
 
@@ -70,9 +79,18 @@ pub fn undo_redo_system(
 fn apply_undo(
     commands: &mut Commands,
     transforms: &mut Query<&mut Transform>,
-    action: EditorAction,
+    terrain_edit_events: &mut MessageWriter<TerrainEditEvent>,
+    entry: EditorHistoryEntry,
     map_editor_state: &mut MapEditorState,
 ) {
+    let EditorHistoryEntry { label, action } = entry;
+    let push_redo = |map_editor_state: &mut MapEditorState, action: EditorAction| {
+        map_editor_state.push_redo(EditorHistoryEntry {
+            label: label.clone(),
+            action,
+        });
+    };
+    
     match action {
         EditorAction::TransformEntity {
             entity,
@@ -83,7 +101,7 @@ fn apply_undo(
                 *transform = old_transform;
                 
                 // Push to redo stack (without clearing it)
-                map_editor_state.push_redo(EditorAction::TransformEntity {
+                push_redo(map_editor_state, EditorAction::TransformEntity {
                     entity,
                     old_transform,
                     new_transform,
@@ -103,7 +121,7 @@ fn apply_undo(
             }
             if !redo_entities.is_empty() {
                 let count = redo_entities.len();
-                map_editor_state.push_redo(EditorAction::TransformEntities {
+                push_redo(map_editor_state, EditorAction::TransformEntities {
                     entities: redo_entities,
                 });
                 log::info!("[UndoRedo] Undid transform for {} entities", count);
@@ -114,7 +132,7 @@ fn apply_undo(
             // Undo add = delete the entity
             commands.entity(entity).despawn();
             map_editor_state.deselect_entity(entity);
-            map_editor_state.push_redo(EditorAction::AddEntity { entity });
+            push_redo(map_editor_state, EditorAction::AddEntity { entity });
             log::info!("[UndoRedo] Undid entity addition (despawned {:?})", entity);
         }
         
@@ -123,7 +141,7 @@ fn apply_undo(
                 commands.entity(*entity).despawn();
                 map_editor_state.deselect_entity(*entity);
             }
-            map_editor_state.push_redo(EditorAction::AddEntities { entities: entities.clone() });
+            push_redo(map_editor_state, EditorAction::AddEntities { entities: entities.clone() });
             log::info!("[UndoRedo] Undid addition of {} entities", entities.len());
         }
         
@@ -155,7 +173,7 @@ fn apply_undo(
             );
             
             // Store the redo action with the new entity
-            map_editor_state.push_redo(EditorAction::DeleteEntity {
+            push_redo(map_editor_state, EditorAction::DeleteEntity {
                 entity: new_entity,
                 transform,
                 entity_type,
@@ -184,7 +202,7 @@ fn apply_undo(
             
             if !redo_entities.is_empty() {
                 let count = redo_entities.len();
-                map_editor_state.push_redo(EditorAction::DeleteEntities { entities: redo_entities });
+                push_redo(map_editor_state, EditorAction::DeleteEntities { entities: redo_entities });
                 log::info!("[UndoRedo] Undid deletion of {} entities", count);
             }
         }
@@ -195,8 +213,7 @@ fn apply_undo(
             old_value,
             new_value,
         } => {
-            // Component modification undo would require component-specific handling
-            // This is a placeholder that swaps old and new values
+            apply_component_value(commands, entity, &component_type, &old_value);
             log::info!(
                 "[UndoRedo] Undid component {} modification for {:?}: {} <- {}",
                 component_type,
@@ -206,13 +223,21 @@ fn apply_undo(
             );
             
             // Push to redo with swapped values
-            map_editor_state.push_redo(EditorAction::ModifyComponent {
+            push_redo(map_editor_state, EditorAction::ModifyComponent {
                 entity,
                 component_type,
                 old_value: new_value,
                 new_value: old_value,
             });
         }
+        
+        EditorAction::TerrainEdit { cells } => {
+            write_terrain_values(terrain_edit_events, &cells);
+            log::info!("[UndoRedo] Undid terrain edit of {} cells", cells.len());
+            push_redo(map_editor_state, EditorAction::TerrainEdit {
+                cells: swap_terrain_values(cells),
+            });
+        }
     }
 }
 
@@ -220,9 +245,18 @@ fn apply_undo(
 fn apply_redo(
     commands: &mut Commands,
     transforms: &mut Query<&mut Transform>,
-    action: EditorAction,
+    terrain_edit_events: &mut MessageWriter<TerrainEditEvent>,
+    entry: EditorHistoryEntry,
     map_editor_state: &mut MapEditorState,
 ) {
+    let EditorHistoryEntry { label, action } = entry;
+    let push_undo = |map_editor_state: &mut MapEditorState, action: EditorAction| {
+        map_editor_state.push_undo(EditorHistoryEntry {
+            label: label.clone(),
+            action,
+        });
+    };
+    
     match action {
         EditorAction::TransformEntity {
             entity,
@@ -234,10 +268,7 @@ fn apply_redo(
                 
                 // Push back to undo stack
                 // Note: We directly manipulate the undo stack to avoid clearing redo
-                if map_editor_state.undo_stack.len() >= MAX_UNDO_STEPS {
-                    map_editor_state.undo_stack.remove(0);
-                }
-                map_editor_state.undo_stack.push(EditorAction::TransformEntity {
+                push_undo(map_editor_state, EditorAction::TransformEntity {
                     entity,
                     old_transform,
                     new_transform,
@@ -257,10 +288,7 @@ fn apply_redo(
             }
             if !undo_entities.is_empty() {
                 let count = undo_entities.len();
-                if map_editor_state.undo_stack.len() >= MAX_UNDO_STEPS {
-                    map_editor_state.undo_stack.remove(0);
-                }
-                map_editor_state.undo_stack.push(EditorAction::TransformEntities {
+                push_undo(map_editor_state, EditorAction::TransformEntities {
                     entities: undo_entities,
                 });
                 log::info!("[UndoRedo] Redid transform for {} entities", count);
@@ -277,11 +305,22 @@ fn apply_redo(
             log::info!("[UndoRedo] Redo AddEntities for {} entities (entity recreation needed)", entities.len());
         }
         
-        EditorAction::DeleteEntity { entity, .. } => {
+        EditorAction::DeleteEntity {
+            entity,
+            transform,
+            entity_type,
+            serialized_data,
+        } => {
             // Redo delete = despawn the entity
             commands.entity(entity).despawn();
             map_editor_state.deselect_entity(entity);
             log::info!("[UndoRedo] Redid entity deletion (despawned {:?})", entity);
+            push_undo(map_editor_state, EditorAction::DeleteEntity {
+                entity,
+                transform,
+                entity_type,
+                serialized_data,
+            });
         }
         
         EditorAction::DeleteEntities { entities } => {
@@ -290,6 +329,7 @@ fn apply_redo(
                 map_editor_state.deselect_entity(*entity);
             }
             log::info!("[UndoRedo] Redid deletion of {} entities", entities.len());
+            push_undo(map_editor_state, EditorAction::DeleteEntities { entities });
         }
         
         EditorAction::ModifyComponent {
@@ -298,25 +338,89 @@ fn apply_redo(
             old_value,
             new_value,
         } => {
+            // Redo entries hold the swapped values, old_value is the value to restore
+            apply_component_value(commands, entity, &component_type, &old_value);
             log::info!(
                 "[UndoRedo] Redid component {} modification for {:?}: {} -> {}",
                 component_type,
                 entity,
-                old_value,
-                new_value
+                new_value,
+                old_value
             );
             
             // Push back to undo
-            if map_editor_state.undo_stack.len() >= MAX_UNDO_STEPS {
-                map_editor_state.undo_stack.remove(0);
-            }
-            map_editor_state.undo_stack.push(EditorAction::ModifyComponent {
+            push_undo(map_editor_state, EditorAction::ModifyComponent {
                 entity,
                 component_type,
-                old_value,
-                new_value,
+                old_value: new_value,
+                new_value: old_value,
             });
         }
+        
+        EditorAction::TerrainEdit { cells } => {
+            // Redo entries hold the swapped values like component edits
+            write_terrain_values(terrain_edit_events, &cells);
+            log::info!("[UndoRedo] Redid terrain edit of {} cells", cells.len());
+            push_undo(map_editor_state, EditorAction::TerrainEdit {
+                cells: swap_terrain_values(cells),
+            });
+        }
+    }
+}
+
+/// Ask the terrain editor to write back the old value of each cell and
+/// regenerate the changed blocks
+fn write_terrain_values(terrain_edit_events: &mut MessageWriter<TerrainEditEvent>, cells: &[TerrainCellDelta]) {
+    terrain_edit_events.write(TerrainEditEvent {
+        cells: cells.iter().map(|delta| (delta.cell, delta.old_value)).collect(),
+    });
+}
+
+fn swap_terrain_values(cells: Vec<TerrainCellDelta>) -> Vec<TerrainCellDelta> {
+    cells
+        .into_iter()
+        .map(|delta| TerrainCellDelta {
+            old_value: delta.new_value,
+            new_value: delta.old_value,
+            ..delta
+        })
+        .collect()
+}
+
+/// Restore a component from the value recorded in a ModifyComponent action.
+/// Only event object and warp edits can be restored, other component edits are just logged.
+fn apply_component_value(commands: &mut Commands, entity: Entity, component_type: &str, value: &str) {
+    let Ok(mut entity_commands) = commands.get_entity(entity) else {
+        return;
+    };
+    
+    match component_type {
+        "EventObject.quest_trigger_name" => {
+            let value = value.to_string();
+            entity_commands
+                .entry::<EventObject>()
+                .and_modify(move |mut event_object| event_object.quest_trigger_name = value);
+        }
+        "EventObject.script_function_name" => {
+            let value = value.to_string();
+            entity_commands
+                .entry::<EventObject>()
+                .and_modify(move |mut event_object| event_object.script_function_name = value);
+        }
+        "WarpObject" => {
+            if let Ok(warp_id) = value.parse::<u16>() {
+                entity_commands.insert(WarpObject::new(WarpGateId::new(warp_id)));
+            }
+        }
+        "WarpDestination" => match WarpDestination::from_ifo_name(value) {
+            Some(destination) => {
+                entity_commands.insert(destination);
+            }
+            None => {
+                entity_commands.remove::<WarpDestination>();
+            }
+        },
+        _ => {}
     }
 }
 
@@ -325,6 +429,70 @@ pub struct UndoRedoPlugin;
 
 impl Plugin for UndoRedoPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, undo_redo_system);
+        app.add_message::<TerrainEditEvent>()
+            .add_systems(Update, undo_redo_system);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::map_editor::resources::{TerrainCell, TerrainLayer, TerrainStroke, MAX_TERRAIN_HISTORY_CELLS};
+
+    fn height_cell(index: usize) -> TerrainCell {
+        TerrainCell {
+            block_x: 32,
+            block_y: 31,
+            layer: TerrainLayer::Height,
+            index,
+        }
+    }
+
+    #[test]
+    fn test_terrain_stroke_coalesces_cells() {
+        let mut stroke = TerrainStroke::default();
+        stroke.record(height_cell(0), 1.0, 2.0);
+        stroke.record(height_cell(1), 5.0, 6.0);
+        stroke.record(height_cell(0), 2.0, 3.0);
+        // Raised and lowered back, nothing to undo
+        stroke.record(height_cell(2), 4.0, 4.5);
+        stroke.record(height_cell(2), 4.5, 4.0);
+
+        let Some(EditorAction::TerrainEdit { cells }) = stroke.finish() else {
+            panic!("stroke should produce a terrain edit");
+        };
+        assert!(stroke.is_empty());
+        assert_eq!(cells.len(), 2);
+        assert_eq!((cells[0].cell, cells[0].old_value, cells[0].new_value), (height_cell(0), 1.0, 3.0));
+        assert_eq!((cells[1].old_value, cells[1].new_value), (5.0, 6.0));
+
+        let swapped = swap_terrain_values(cells);
+        assert_eq!((swapped[0].old_value, swapped[0].new_value), (3.0, 1.0));
+    }
+
+    #[test]
+    fn test_terrain_history_is_capped() {
+        let mut map_editor_state = MapEditorState::new();
+        let stroke = |count: usize| EditorAction::TerrainEdit {
+            cells: (0..count)
+                .map(|index| TerrainCellDelta {
+                    cell: height_cell(index),
+                    old_value: 0.0,
+                    new_value: 1.0,
+                })
+                .collect(),
+        };
+
+        map_editor_state.push_action(stroke(MAX_TERRAIN_HISTORY_CELLS / 2));
+        map_editor_state.push_action(EditorAction::AddEntities { entities: Vec::new() });
+        map_editor_state.push_action(stroke(MAX_TERRAIN_HISTORY_CELLS / 2 + 1));
+
+        // The oldest stroke is dropped, the object edit is kept
+        assert_eq!(map_editor_state.undo_stack.len(), 2);
+        assert_eq!(map_editor_state.undo_stack[0].action.terrain_cell_count(), 0);
+        assert_eq!(
+            map_editor_state.undo_stack[1].action.terrain_cell_count(),
+            MAX_TERRAIN_HISTORY_CELLS / 2 + 1
+        );
     }
 }
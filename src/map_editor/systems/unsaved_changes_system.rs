@@ -0,0 +1,150 @@
+//! Unsaved Changes System for the Map Editor
+//!
+//! Applies operations that discard the edited zone (New Zone, opening another
+//! zone and exiting the editor) once the user chose in the unsaved changes
+//! dialog. After Save the operation waits for the save to succeed, a failed
+//! save brings the dialog back.
+//!
+//! The primary window is created with `close_when_requested` off, so closing
+//! the window goes through the same dialog as File > Exit.
+
+use bevy::prelude::*;
+use bevy::window::WindowCloseRequested;
+
+use crate::events::LoadZoneEvent;
+use crate::map_editor::resources::{
+    DeletedZoneObjects, DiscardAction, DiscardStep, MapEditorState,
+};
+use crate::map_editor::save::{save_system::save_zone_system, SaveStatus};
+use crate::map_editor::ui::NewZoneEvent;
+
+/// Plugin for the unsaved changes system
+pub struct UnsavedChangesPlugin;
+
+impl Plugin for UnsavedChangesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                window_close_requested_system,
+                unsaved_changes_system.after(save_zone_system),
+            )
+                .chain(),
+        );
+    }
+}
+
+/// What to do with a pending discard this frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiscardOutcome {
+    /// Still waiting for the user
+    Wait,
+    /// Apply the action
+    Proceed,
+    /// Saving did not clear the unsaved changes, ask again
+    SaveFailed,
+}
+
+impl DiscardOutcome {
+    pub fn of(step: DiscardStep, is_modified: bool) -> Self {
+        match step {
+            DiscardStep::Asking if is_modified => DiscardOutcome::Wait,
+            // The save system runs before this system, so the save has finished
+            DiscardStep::Saving if is_modified => DiscardOutcome::SaveFailed,
+            _ => DiscardOutcome::Proceed,
+        }
+    }
+}
+
+/// System to ask about unsaved changes before closing the window in the
+/// editor, outside the editor the app exits straight away
+pub fn window_close_requested_system(
+    mut window_close_requested_events: MessageReader<WindowCloseRequested>,
+    mut map_editor_state: ResMut<MapEditorState>,
+    mut app_exit_events: MessageWriter<AppExit>,
+) {
+    if window_close_requested_events.read().last().is_none() {
+        return;
+    }
+
+    if map_editor_state.enabled {
+        log::info!("[UnsavedChanges] Window close requested");
+        map_editor_state.request_discard(DiscardAction::ExitEditor);
+    } else {
+        app_exit_events.write(AppExit::Success);
+    }
+}
+
+/// System to apply operations once unsaved changes were saved or discarded
+pub fn unsaved_changes_system(
+    mut map_editor_state: ResMut<MapEditorState>,
+    mut deleted_zone_objects: ResMut<DeletedZoneObjects>,
+    save_status: Res<SaveStatus>,
+    mut new_zone_events: MessageWriter<NewZoneEvent>,
+    mut load_zone_events: MessageWriter<LoadZoneEvent>,
+    mut app_exit_events: MessageWriter<AppExit>,
+) {
+    // Only borrow mutably when something changes, the state is checked for changes
+    let Some(pending) = map_editor_state.pending_discard.as_ref() else {
+        return;
+    };
+    let action = pending.action;
+
+    match DiscardOutcome::of(pending.step, map_editor_state.is_modified) {
+        DiscardOutcome::Wait => return,
+        DiscardOutcome::SaveFailed => {
+            log::warn!("[UnsavedChanges] Save failed, asking again: {}", save_status.status_message);
+            if let Some(pending) = map_editor_state.pending_discard.as_mut() {
+                pending.step = DiscardStep::Asking;
+                pending.error = Some(save_status.status_message.clone());
+            }
+            return;
+        }
+        DiscardOutcome::Proceed => {}
+    }
+
+    map_editor_state.pending_discard = None;
+    log::info!("[UnsavedChanges] Proceeding with {}", action.display_name());
+
+    match action {
+        DiscardAction::NewZone => {
+            new_zone_events.write(NewZoneEvent {
+                prompt_if_modified: false,
+            });
+        }
+        DiscardAction::LoadZone {
+            zone_id,
+            despawn_other_zones,
+        } => {
+            load_zone_events.write(LoadZoneEvent {
+                id: zone_id,
+                despawn_other_zones,
+            });
+
+            // Edits of the previous zone do not apply to the new one
+            map_editor_state.clear_selection();
+            map_editor_state.clear_history();
+            map_editor_state.is_modified = false;
+            map_editor_state.autosave.dirty = false;
+            map_editor_state.dirty_terrain_blocks.clear();
+            deleted_zone_objects.clear();
+        }
+        DiscardAction::ExitEditor => {
+            app_exit_events.write(AppExit::Success);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_discard_outcome() {
+        assert_eq!(DiscardOutcome::of(DiscardStep::Asking, true), DiscardOutcome::Wait);
+        assert_eq!(DiscardOutcome::of(DiscardStep::Asking, false), DiscardOutcome::Proceed);
+        assert_eq!(DiscardOutcome::of(DiscardStep::Saving, false), DiscardOutcome::Proceed);
+        assert_eq!(DiscardOutcome::of(DiscardStep::Saving, true), DiscardOutcome::SaveFailed);
+        assert_eq!(DiscardOutcome::of(DiscardStep::Discard, true), DiscardOutcome::Proceed);
+    }
+}
@@ -0,0 +1,105 @@
+//! Visibility System for the Map Editor
+//!
+//! Applies the editor-only hidden objects from `EditorVisibility` to the
+//! `Visibility` of zone objects and their parts, restoring the original
+//! visibility once they are shown again or the editor is closed.
+
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+use crate::components::ZoneObject;
+use crate::map_editor::components::EditorSelectable;
+use crate::map_editor::resources::{EditorVisibility, HierarchyFilter, MapEditorState};
+
+/// Plugin for the editor visibility system
+pub struct EditorVisibilityPlugin;
+
+impl Plugin for EditorVisibilityPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EditorVisibility>()
+            .add_systems(Update, editor_visibility_system);
+    }
+}
+
+/// System to hide and show zone objects to match `EditorVisibility`
+pub fn editor_visibility_system(
+    map_editor_state: Res<MapEditorState>,
+    editor_visibility: Res<EditorVisibility>,
+    query_objects: Query<(Entity, &ZoneObject), With<EditorSelectable>>,
+    query_added: Query<(), Added<EditorSelectable>>,
+    query_children: Query<&Children>,
+    mut query_visibility: Query<&mut Visibility>,
+    mut hidden: Local<HashMap<Entity, Visibility>>,
+) {
+    if !map_editor_state.is_changed() && !editor_visibility.is_changed() && query_added.is_empty() {
+        return;
+    }
+
+    // Hide the parts as well, placed parts are explicitly visible and would
+    // otherwise not inherit the hidden state
+    let mut should_hide = HashSet::new();
+    if map_editor_state.enabled && editor_visibility.is_any_hidden() {
+        // Parts of isolated objects stay visible with their object
+        let isolated_parts = editor_visibility
+            .isolated
+            .iter()
+            .flatten()
+            .flat_map(|entity| query_children.iter_descendants(*entity))
+            .collect::<HashSet<_>>();
+
+        for (entity, zone_object) in query_objects.iter() {
+            if isolated_parts.contains(&entity) {
+                continue;
+            }
+            if editor_visibility.is_hidden(entity, HierarchyFilter::of_zone_object(zone_object)) {
+                should_hide.insert(entity);
+                should_hide.extend(query_children.iter_descendants(entity));
+            }
+        }
+    }
+
+    // Restore objects that are shown again, or were despawned
+    hidden.retain(|entity, original| {
+        if should_hide.contains(entity) {
+            return true;
+        }
+        if let Ok(mut visibility) = query_visibility.get_mut(*entity) {
+            *visibility = *original;
+        }
+        false
+    });
+
+    for entity in should_hide {
+        if hidden.contains_key(&entity) {
+            continue;
+        }
+        if let Ok(mut visibility) = query_visibility.get_mut(entity) {
+            hidden.insert(entity, *visibility);
+            *visibility = Visibility::Hidden;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_isolation_keeps_terrain() {
+        let mut world = World::new();
+        let selected = world.spawn_empty().id();
+        let other = world.spawn_empty().id();
+        let terrain = world.spawn_empty().id();
+
+        let mut editor_visibility = EditorVisibility::default();
+        editor_visibility.toggle_type(HierarchyFilter::DecoObjects);
+        assert!(editor_visibility.is_hidden(other, Some(HierarchyFilter::DecoObjects)));
+        assert!(!editor_visibility.is_hidden(other, Some(HierarchyFilter::CnstObjects)));
+
+        editor_visibility.show_all();
+        editor_visibility.isolate(&HashSet::from([selected]));
+        assert!(!editor_visibility.is_hidden(selected, Some(HierarchyFilter::CnstObjects)));
+        assert!(editor_visibility.is_hidden(other, Some(HierarchyFilter::CnstObjects)));
+        assert!(!editor_visibility.is_hidden(terrain, Some(HierarchyFilter::Terrain)));
+    }
+}
@@ -0,0 +1,143 @@
+//! Warp Link System for the Map Editor
+//!
+//! With View > Warp Links enabled, draws a line from each warp gate to its
+//! destination when it is in the current zone, or a marker for destinations
+//! in other zones. Warps without a destination, or linking to a zone that is
+//! not in the zone list, are drawn red.
+
+use bevy::prelude::*;
+use rose_data::ZoneId;
+
+use crate::components::{WarpDestination, WarpObject};
+use crate::map_editor::resources::MapEditorState;
+use crate::resources::{CurrentZone, GameData};
+
+/// Height of the marker drawn above warps that do not link into the current zone
+pub const WARP_MARKER_HEIGHT: f32 = 6.0;
+
+const WARP_LOCAL_COLOR: Color = Color::srgb(0.2, 1.0, 0.4);
+const WARP_OTHER_ZONE_COLOR: Color = Color::srgb(0.3, 0.7, 1.0);
+const WARP_BROKEN_COLOR: Color = Color::srgb(1.0, 0.15, 0.15);
+
+/// Plugin for the warp link system
+pub struct WarpLinkPlugin;
+
+impl Plugin for WarpLinkPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, draw_warp_links);
+    }
+}
+
+/// Where a warp gate leads
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WarpLinkStatus {
+    /// Destination is in the current zone
+    Local,
+    /// Destination is in another zone of the zone list
+    OtherZone,
+    /// No destination set
+    Unlinked,
+    /// Destination zone is not in the zone list
+    MissingZone,
+}
+
+impl WarpLinkStatus {
+    pub fn of(
+        destination: Option<&WarpDestination>,
+        current_zone_id: Option<u16>,
+        zone_exists: impl Fn(u16) -> bool,
+    ) -> Self {
+        match destination {
+            None => WarpLinkStatus::Unlinked,
+            Some(destination) if !zone_exists(destination.zone_id) => WarpLinkStatus::MissingZone,
+            Some(destination) if Some(destination.zone_id) == current_zone_id => WarpLinkStatus::Local,
+            Some(_) => WarpLinkStatus::OtherZone,
+        }
+    }
+
+    pub fn is_broken(self) -> bool {
+        matches!(self, WarpLinkStatus::Unlinked | WarpLinkStatus::MissingZone)
+    }
+
+    pub fn color(self) -> Color {
+        match self {
+            WarpLinkStatus::Local => WARP_LOCAL_COLOR,
+            WarpLinkStatus::OtherZone => WARP_OTHER_ZONE_COLOR,
+            WarpLinkStatus::Unlinked | WarpLinkStatus::MissingZone => WARP_BROKEN_COLOR,
+        }
+    }
+}
+
+/// Name of a zone from the zone list
+pub fn zone_name(game_data: &GameData, zone_id: u16) -> Option<&str> {
+    ZoneId::new(zone_id)
+        .and_then(|zone_id| game_data.zone_list.get_zone(zone_id))
+        .map(|zone_data| zone_data.name.as_str())
+}
+
+/// System to draw the warp links in the viewport
+pub fn draw_warp_links(
+    mut gizmos: Gizmos,
+    map_editor_state: Res<MapEditorState>,
+    game_data: Option<Res<GameData>>,
+    current_zone: Option<Res<CurrentZone>>,
+    query_warps: Query<(&GlobalTransform, Option<&WarpDestination>), With<WarpObject>>,
+) {
+    if !map_editor_state.enabled || !map_editor_state.show_warp_links {
+        return;
+    }
+
+    let current_zone_id = current_zone.map(|current_zone| current_zone.id.get());
+    let zone_exists = |zone_id| {
+        game_data
+            .as_ref()
+            .is_none_or(|game_data| zone_name(game_data, zone_id).is_some())
+    };
+
+    for (global_transform, destination) in query_warps.iter() {
+        let start = global_transform.translation();
+        let status = WarpLinkStatus::of(destination, current_zone_id, zone_exists);
+        let color = status.color();
+
+        match (status, destination) {
+            (WarpLinkStatus::Local, Some(destination)) => {
+                gizmos.line(start, destination.position, color);
+                gizmos.sphere(destination.position, 0.75, color);
+            }
+            _ => {
+                let top = start + Vec3::Y * WARP_MARKER_HEIGHT;
+                gizmos.line(start, top, color);
+                gizmos.sphere(top, 0.5, color);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_warp_link_status() {
+        let destination = WarpDestination {
+            zone_id: 2,
+            position: Vec3::new(5120.5, 10.0, -5300.25),
+        };
+        assert_eq!(
+            WarpDestination::from_ifo_name(&destination.to_ifo_name()),
+            Some(destination)
+        );
+        assert_eq!(WarpDestination::from_ifo_name("Warp01"), None);
+
+        let zone_exists = |zone_id| zone_id <= 10;
+        assert_eq!(WarpLinkStatus::of(Some(&destination), Some(2), zone_exists), WarpLinkStatus::Local);
+        assert_eq!(WarpLinkStatus::of(Some(&destination), Some(1), zone_exists), WarpLinkStatus::OtherZone);
+        assert_eq!(WarpLinkStatus::of(None, Some(1), zone_exists), WarpLinkStatus::Unlinked);
+
+        let missing = WarpDestination {
+            zone_id: 99,
+            ..destination
+        };
+        assert!(WarpLinkStatus::of(Some(&missing), Some(1), zone_exists).is_broken());
+    }
+}
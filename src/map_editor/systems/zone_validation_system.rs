@@ -0,0 +1,209 @@
+//! Zone Validation System for the Map Editor
+//!
+//! Scans the current zone when requested from the Zone Validation panel,
+//! counting the objects of each type and reporting objects with missing
+//! models, objects floating above or buried below the terrain, warp gates
+//! on top of each other and sound objects with a zero radius.
+
+use bevy::prelude::*;
+
+use crate::audio::SoundRadius;
+use crate::components::ZoneObject;
+use crate::map_editor::components::EditorSelectable;
+use crate::map_editor::resources::{
+    HierarchyFilter, MapEditorState, ZoneIssue, ZoneIssueKind, ZoneValidationReport,
+};
+use crate::map_editor::ui::hierarchy_panel::get_zone_object_name;
+use crate::resources::CurrentZone;
+use crate::zone_loader::ZoneLoaderAsset;
+
+/// Warp gates closer than this to each other are reported as overlapping
+const WARP_OVERLAP_DISTANCE: f32 = 1.0;
+
+/// Plugin for the zone validation system
+pub struct ZoneValidationPlugin;
+
+impl Plugin for ZoneValidationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, zone_validation_system);
+    }
+}
+
+/// System to scan the current zone when the validation panel requests it
+pub fn zone_validation_system(
+    mut map_editor_state: ResMut<MapEditorState>,
+    current_zone: Option<Res<CurrentZone>>,
+    zone_loader_assets: Res<Assets<ZoneLoaderAsset>>,
+    query_objects: Query<
+        (Entity, &ZoneObject, &GlobalTransform, Option<&Name>, Option<&SoundRadius>),
+        With<EditorSelectable>,
+    >,
+) {
+    if !map_editor_state.zone_validation.pending_scan {
+        return;
+    }
+    map_editor_state.zone_validation.pending_scan = false;
+
+    let Some(zone_data) = current_zone
+        .as_ref()
+        .and_then(|current_zone| zone_loader_assets.get(&current_zone.handle))
+    else {
+        log::warn!("[ZoneValidation] No zone loaded to validate");
+        map_editor_state.zone_validation.report = None;
+        return;
+    };
+
+    let tolerance = map_editor_state.zone_validation.terrain_tolerance;
+    let mut report = ZoneValidationReport {
+        object_counts: HierarchyFilter::ALL
+            .into_iter()
+            .skip(1)
+            .map(|filter| (filter, 0))
+            .collect(),
+        issues: Vec::new(),
+    };
+    let mut warps = Vec::new();
+
+    for (entity, zone_object, global_transform, name, sound_radius) in query_objects.iter() {
+        if let Some(object_type) = HierarchyFilter::of_zone_object(zone_object) {
+            if let Some((_, count)) = report
+                .object_counts
+                .iter_mut()
+                .find(|(filter, _)| *filter == object_type)
+            {
+                *count += 1;
+            }
+        }
+
+        let object_name = get_zone_object_name(zone_object, name, entity);
+        let mut add_issue = |kind: ZoneIssueKind, detail: String| {
+            report.issues.push(ZoneIssue {
+                entity,
+                kind,
+                message: format!("{}: {}", object_name, detail),
+            });
+        };
+
+        let (object_id, zsc) = match zone_object {
+            ZoneObject::DecoObject(id) => (id, Some(&zone_data.zsc_deco)),
+            ZoneObject::CnstObject(id) => (id, Some(&zone_data.zsc_cnst)),
+            ZoneObject::EventObject(id) | ZoneObject::WarpObject(id) => (id, None),
+            ZoneObject::SoundObject { .. } => {
+                if sound_radius.is_some_and(|radius| radius.0 <= 0.0) {
+                    add_issue(ZoneIssueKind::ZeroRadiusSound, "sound radius is zero".to_string());
+                }
+                continue;
+            }
+            _ => continue,
+        };
+
+        if let Some(zsc) = zsc {
+            if object_id.zsc_object_id >= zsc.objects.len() {
+                add_issue(
+                    ZoneIssueKind::MissingModel,
+                    format!(
+                        "ZSC object id {} does not exist ({} objects)",
+                        object_id.zsc_object_id,
+                        zsc.objects.len()
+                    ),
+                );
+            }
+        }
+
+        let position = global_transform.translation();
+        let terrain_height = terrain_height_at(zone_data, position);
+        if let Some(kind) = terrain_issue(position.y, terrain_height, tolerance) {
+            add_issue(
+                kind,
+                format!("{:.1} from the terrain", position.y - terrain_height),
+            );
+        }
+
+        if matches!(zone_object, ZoneObject::WarpObject(_)) {
+            warps.push((entity, object_name, position));
+        }
+    }
+
+    let warp_positions = warps
+        .iter()
+        .map(|(entity, _, position)| (*entity, *position))
+        .collect::<Vec<_>>();
+    for (entity, other) in overlapping_pairs(&warp_positions, WARP_OVERLAP_DISTANCE) {
+        let name_of = |entity| {
+            warps
+                .iter()
+                .find(|(warp, _, _)| *warp == entity)
+                .map_or("", |(_, name, _)| name.as_str())
+        };
+        report.issues.push(ZoneIssue {
+            entity,
+            kind: ZoneIssueKind::OverlappingWarp,
+            message: format!("{}: overlaps {}", name_of(entity), name_of(other)),
+        });
+    }
+
+    log::info!(
+        "[ZoneValidation] Scanned {} objects, found {} issues",
+        query_objects.iter().count(),
+        report.issues.len()
+    );
+    map_editor_state.zone_validation.report = Some(report);
+}
+
+/// Terrain height below a world position, in world units
+fn terrain_height_at(zone_data: &ZoneLoaderAsset, position: Vec3) -> f32 {
+    // Zone coordinates are in centimetres with Y pointing along -Z
+    zone_data.get_terrain_height(position.x * 100.0, -position.z * 100.0) / 100.0
+}
+
+/// Whether an object is too far above or below the terrain
+pub fn terrain_issue(object_height: f32, terrain_height: f32, tolerance: f32) -> Option<ZoneIssueKind> {
+    let distance = object_height - terrain_height;
+    if distance > tolerance {
+        Some(ZoneIssueKind::Floating)
+    } else if distance < -tolerance {
+        Some(ZoneIssueKind::Buried)
+    } else {
+        None
+    }
+}
+
+/// Pairs of positions closer than `distance` to each other
+pub fn overlapping_pairs(positions: &[(Entity, Vec3)], distance: f32) -> Vec<(Entity, Entity)> {
+    let mut pairs = Vec::new();
+    for (index, (entity, position)) in positions.iter().enumerate() {
+        for (other, other_position) in positions.iter().skip(index + 1) {
+            if position.distance(*other_position) < distance {
+                pairs.push((*entity, *other));
+            }
+        }
+    }
+    pairs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_terrain_issue() {
+        assert_eq!(terrain_issue(15.0, 2.0, 10.0), Some(ZoneIssueKind::Floating));
+        assert_eq!(terrain_issue(-20.0, 2.0, 10.0), Some(ZoneIssueKind::Buried));
+        assert_eq!(terrain_issue(8.0, 2.0, 10.0), None);
+    }
+
+    #[test]
+    fn test_overlapping_pairs() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+        let c = world.spawn_empty().id();
+
+        let positions = [
+            (a, Vec3::new(5200.0, 10.0, -5200.0)),
+            (b, Vec3::new(5200.5, 10.0, -5200.0)),
+            (c, Vec3::new(5210.0, 10.0, -5200.0)),
+        ];
+        assert_eq!(overlapping_pairs(&positions, WARP_OVERLAP_DISTANCE), vec![(a, b)]);
+    }
+}
@@ -8,7 +8,7 @@ use bevy_egui::egui;
 
 use crate::components::{EventObject, WarpObject, ZoneObject};
 use crate::map_editor::components::{EditorSelectable, SelectedInEditor};
-use crate::map_editor::resources::{MapEditorState, HierarchyFilter};
+use crate::map_editor::resources::{EditorVisibility, MapEditorState, HierarchyFilter};
 
 /// System parameter for querying hierarchy objects
 #[derive(SystemParam)]
@@ -21,6 +21,8 @@ pub struct HierarchyQuery<'w, 's> {
     event_objects: Query<'w, 's, (Entity, &'static EventObject, Option<&'static Name>), With<EditorSelectable>>,
     /// Warp objects  
     warp_objects: Query<'w, 's, (Entity, &'static WarpObject, Option<&'static Name>), With<EditorSelectable>>,
+    /// Objects hidden in the editor
+    visibility: ResMut<'w, EditorVisibility>,
 }
 
 /// Categories for organizing hierarchy objects
@@ -51,6 +53,33 @@ impl ObjectCategory {
             ObjectCategory::Animated => "Animated",
         }
     }
+    
+    /// The object type used to hide every object in this category
+    pub fn visibility_type(&self) -> Option<HierarchyFilter> {
+        match self {
+            ObjectCategory::Deco => Some(HierarchyFilter::DecoObjects),
+            ObjectCategory::Cnst => Some(HierarchyFilter::CnstObjects),
+            ObjectCategory::Event => Some(HierarchyFilter::EventObjects),
+            ObjectCategory::Warp => Some(HierarchyFilter::WarpObjects),
+            ObjectCategory::Terrain => Some(HierarchyFilter::Terrain),
+            ObjectCategory::Water => Some(HierarchyFilter::Water),
+            ObjectCategory::Effect => Some(HierarchyFilter::Effects),
+            ObjectCategory::Sound => Some(HierarchyFilter::Sounds),
+            ObjectCategory::Animated => None,
+        }
+    }
+}
+
+/// Small eye button to show or hide an object or type, returns true when clicked
+fn visibility_toggle(ui: &mut egui::Ui, is_hidden: bool) -> bool {
+    let (text, hover_text) = if is_hidden {
+        (egui::RichText::new("👁").weak(), "Show")
+    } else {
+        (egui::RichText::new("👁"), "Hide")
+    };
+    ui.add(egui::Button::new(text).small().frame(false))
+        .on_hover_text(hover_text)
+        .clicked()
 }
 
 /// Get the category of a zone object
@@ -69,7 +98,7 @@ fn get_zone_object_category(zone_object: &ZoneObject) -> ObjectCategory {
 }
 
 /// Get a display name for a zone object
-fn get_zone_object_name(zone_object: &ZoneObject, name: Option<&Name>, entity: Entity) -> String {
+pub(crate) fn get_zone_object_name(zone_object: &ZoneObject, name: Option<&Name>, entity: Entity) -> String {
     if let Some(name) = name {
         return name.as_str().to_string();
     }
@@ -100,12 +129,20 @@ fn get_zone_object_name(zone_object: &ZoneObject, name: Option<&Name>, entity: E
     }
 }
 
+/// Check whether an object matches the hierarchy search text, by name or type
+fn matches_search(search: &str, display_name: &str, category: ObjectCategory) -> bool {
+    let search = search.trim().to_lowercase();
+    search.is_empty()
+        || display_name.to_lowercase().contains(&search)
+        || category.display_name().to_lowercase().contains(&search)
+}
+
 /// Render the hierarchy panel (left side panel)
 pub fn editor_hierarchy_panel(
     ctx: &egui::Context, 
-    map_editor_state: &MapEditorState,
-    hierarchy_query: &HierarchyQuery,
-    mut commands: &mut Commands,
+    map_editor_state: &mut MapEditorState,
+    hierarchy_query: &mut HierarchyQuery,
+    commands: &mut Commands,
 ) {
     egui::SidePanel::left("hierarchy_panel")
         .default_width(250.0)
@@ -115,13 +152,13 @@ pub fn editor_hierarchy_panel(
             ui.heading("Hierarchy");
             ui.separator();
             
-            // Search filter
+            // Search filter, matches object names and types
             ui.horizontal(|ui| {
                 ui.label("Search:");
-                // Note: In a full implementation, we would update a state resource here
-                // For now, we just show the filter value
-                let mut search = map_editor_state.hierarchy_filter.clone();
-                ui.text_edit_singleline(&mut search);
+                ui.text_edit_singleline(&mut map_editor_state.hierarchy_filter);
+                if !map_editor_state.hierarchy_filter.is_empty() && ui.small_button("✖").clicked() {
+                    map_editor_state.hierarchy_filter.clear();
+                }
             });
             
             ui.separator();
@@ -130,66 +167,128 @@ pub fn editor_hierarchy_panel(
             ui.horizontal(|ui| {
                 ui.label("Filter:");
                 egui::ComboBox::from_id_salt("hierarchy_filter")
-                    .selected_text(map_editor_state.editor_mode.display_name())
+                    .selected_text(map_editor_state.hierarchy_type_filter.display_name())
                     .width(120.0)
                     .show_ui(ui, |ui| {
-                        // Note: These would need to update state in a full implementation
-                        ui.selectable_label(false, "All");
-                        ui.selectable_label(false, "Deco Objects");
-                        ui.selectable_label(false, "Cnst Objects");
-                        ui.selectable_label(false, "Event Objects");
-                        ui.selectable_label(false, "Warp Objects");
-                        ui.selectable_label(false, "Terrain");
-                        ui.selectable_label(false, "Water");
-                        ui.selectable_label(false, "Effects");
-                        ui.selectable_label(false, "Sounds");
+                        for filter in HierarchyFilter::ALL {
+                            ui.selectable_value(
+                                &mut map_editor_state.hierarchy_type_filter,
+                                filter,
+                                filter.display_name(),
+                            );
+                        }
                     });
             });
             
+            // Editor-only visibility, hidden objects are still saved
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(
+                        map_editor_state.selection_count() > 0,
+                        egui::Button::new("Isolate Selection"),
+                    )
+                    .on_hover_text("Hide everything except the selected objects and terrain")
+                    .clicked()
+                {
+                    hierarchy_query.visibility.isolate(&map_editor_state.selected_entities);
+                    log::info!("[Hierarchy] Isolated {} selected objects", map_editor_state.selection_count());
+                }
+                
+                if ui
+                    .add_enabled(hierarchy_query.visibility.is_any_hidden(), egui::Button::new("Show All"))
+                    .clicked()
+                {
+                    hierarchy_query.visibility.show_all();
+                    log::info!("[Hierarchy] Showing all objects");
+                }
+            });
+            
             ui.separator();
             
+            let search = map_editor_state.hierarchy_filter.clone();
+            let type_filter = map_editor_state.hierarchy_type_filter;
+            let is_searching = !search.trim().is_empty();
+            
             // Collect objects by category
-            let mut categories: std::collections::HashMap<ObjectCategory, Vec<(Entity, String, bool)>> = 
+            let mut categories: std::collections::HashMap<ObjectCategory, Vec<(Entity, String, bool, bool)>> = 
                 std::collections::HashMap::new();
+            let mut total_count = 0;
             
-            // Get selected entities set for quick lookup
-            let selected_set: std::collections::HashSet<Entity> = map_editor_state.selected_entities.iter().copied().collect();
+            // Selection comes from the same set as viewport picking so both stay in sync
+            let selected_set = &map_editor_state.selected_entities;
             
             // Query zone objects
             for (entity, zone_object, name, _transform) in hierarchy_query.zone_objects.iter() {
+                total_count += 1;
                 let category = get_zone_object_category(zone_object);
                 let display_name = get_zone_object_name(zone_object, name, entity);
+                if !type_filter.matches(zone_object) || !matches_search(&search, &display_name, category) {
+                    continue;
+                }
                 let is_selected = selected_set.contains(&entity);
+                let is_hidden = hierarchy_query
+                    .visibility
+                    .is_hidden(entity, HierarchyFilter::of_zone_object(zone_object));
                 
                 categories.entry(category)
                     .or_insert_with(Vec::new)
-                    .push((entity, display_name, is_selected));
+                    .push((entity, display_name, is_selected, is_hidden));
             }
             
             // Query event objects (separate component)
-            for (entity, _event_object, name) in hierarchy_query.event_objects.iter() {
-                let display_name = name
-                    .map(|n| n.as_str().to_string())
-                    .unwrap_or_else(|| format!("Event {:?}", entity));
-                let is_selected = selected_set.contains(&entity);
-                
-                categories.entry(ObjectCategory::Event)
-                    .or_insert_with(Vec::new)
-                    .push((entity, display_name, is_selected));
+            if matches!(type_filter, HierarchyFilter::All | HierarchyFilter::EventObjects) {
+                for (entity, _event_object, name) in hierarchy_query.event_objects.iter() {
+                    total_count += 1;
+                    let display_name = name
+                        .map(|n| n.as_str().to_string())
+                        .unwrap_or_else(|| format!("Event {:?}", entity));
+                    if !matches_search(&search, &display_name, ObjectCategory::Event) {
+                        continue;
+                    }
+                    let is_selected = selected_set.contains(&entity);
+                    let is_hidden = hierarchy_query
+                        .visibility
+                        .is_hidden(entity, Some(HierarchyFilter::EventObjects));
+                    
+                    categories.entry(ObjectCategory::Event)
+                        .or_insert_with(Vec::new)
+                        .push((entity, display_name, is_selected, is_hidden));
+                }
             }
             
             // Query warp objects (separate component)
-            for (entity, _warp_object, name) in hierarchy_query.warp_objects.iter() {
-                let display_name = name
-                    .map(|n| n.as_str().to_string())
-                    .unwrap_or_else(|| format!("Warp {:?}", entity));
-                let is_selected = selected_set.contains(&entity);
-                
-                categories.entry(ObjectCategory::Warp)
-                    .or_insert_with(Vec::new)
-                    .push((entity, display_name, is_selected));
+            if matches!(type_filter, HierarchyFilter::All | HierarchyFilter::WarpObjects) {
+                for (entity, _warp_object, name) in hierarchy_query.warp_objects.iter() {
+                    total_count += 1;
+                    let display_name = name
+                        .map(|n| n.as_str().to_string())
+                        .unwrap_or_else(|| format!("Warp {:?}", entity));
+                    if !matches_search(&search, &display_name, ObjectCategory::Warp) {
+                        continue;
+                    }
+                    let is_selected = selected_set.contains(&entity);
+                    let is_hidden = hierarchy_query
+                        .visibility
+                        .is_hidden(entity, Some(HierarchyFilter::WarpObjects));
+                    
+                    categories.entry(ObjectCategory::Warp)
+                        .or_insert_with(Vec::new)
+                        .push((entity, display_name, is_selected, is_hidden));
+                }
+            }
+            
+            // Keep the list stable between frames
+            for objects in categories.values_mut() {
+                objects.sort_by(|a, b| a.1.cmp(&b.1));
             }
             
+            // Track if any entity was clicked
+            let mut clicked_entity: Option<Entity> = None;
+            let mut toggle_selection = false;
+            let mut focus_entity: Option<Entity> = None;
+            let mut toggle_entity_visibility: Option<Entity> = None;
+            let mut toggle_type_visibility: Option<HierarchyFilter> = None;
+            
             // Object list (scrollable)
             egui::ScrollArea::vertical().show(ui, |ui| {
                 // Define order of categories
@@ -205,71 +304,126 @@ pub fn editor_hierarchy_panel(
                     ObjectCategory::Animated,
                 ];
                 
-                // Track if any entity was clicked
-                let mut clicked_entity: Option<Entity> = None;
-                let mut clicked_entity_is_selected = false;
-                
                 for category in category_order {
                     if let Some(objects) = categories.get(&category) {
                         if objects.is_empty() {
                             continue;
                         }
                         
-                        ui.collapsing(format!("{} ({})", category.display_name(), objects.len()), |ui| {
-                            for (entity, display_name, is_selected) in objects {
-                                let response = ui.selectable_label(*is_selected, display_name);
-                                
-                                // Context menu on right-click
-                                response.context_menu(|ui| {
-                                    if ui.button("Select").clicked() {
-                                        clicked_entity = Some(*entity);
-                                        clicked_entity_is_selected = false;
-                                        ui.close_menu();
-                                    }
-                                    
-                                    if ui.button("Focus in Viewport").clicked() {
-                                        log::info!("[Hierarchy] Focus on entity: {:?}", entity);
-                                        ui.close_menu();
+                        // Expand every section with matches while searching
+                        let mut header_state = egui::collapsing_header::CollapsingState::load_with_default_open(
+                            ui.ctx(),
+                            ui.make_persistent_id(("hierarchy_category", category)),
+                            false,
+                        );
+                        if is_searching {
+                            header_state.set_open(true);
+                        }
+                        
+                        header_state
+                            .show_header(ui, |ui| {
+                                if let Some(object_type) = category.visibility_type() {
+                                    let is_hidden = hierarchy_query.visibility.hidden_types.contains(&object_type);
+                                    if visibility_toggle(ui, is_hidden) {
+                                        toggle_type_visibility = Some(object_type);
                                     }
+                                }
+                                ui.label(format!("{} ({})", category.display_name(), objects.len()));
+                            })
+                            .body(|ui| {
+                                for (entity, display_name, is_selected, is_hidden) in objects {
+                                    let response = ui
+                                        .horizontal(|ui| {
+                                            if visibility_toggle(ui, *is_hidden) {
+                                                toggle_entity_visibility = Some(*entity);
+                                            }
+                                            
+                                            let text = if *is_hidden {
+                                                egui::RichText::new(display_name).weak()
+                                            } else {
+                                                egui::RichText::new(display_name)
+                                            };
+                                            ui.selectable_label(*is_selected, text)
+                                        })
+                                        .inner;
                                     
-                                    ui.separator();
+                                    // Context menu on right-click
+                                    response.context_menu(|ui| {
+                                        if ui.button("Select").clicked() {
+                                            clicked_entity = Some(*entity);
+                                            ui.close_menu();
+                                        }
+                                        
+                                        if ui.button("Focus in Viewport").clicked() {
+                                            focus_entity = Some(*entity);
+                                            ui.close_menu();
+                                        }
+                                        
+                                        ui.separator();
+                                        
+                                        if ui.button("Delete").clicked() {
+                                            log::info!("[Hierarchy] Delete entity: {:?}", entity);
+                                            ui.close_menu();
+                                        }
+                                    });
                                     
-                                    if ui.button("Delete").clicked() {
-                                        log::info!("[Hierarchy] Delete entity: {:?}", entity);
-                                        ui.close_menu();
+                                    // Double click selects and frames, Ctrl+click toggles like the viewport
+                                    if response.double_clicked() {
+                                        clicked_entity = Some(*entity);
+                                        focus_entity = Some(*entity);
+                                    } else if response.clicked() {
+                                        clicked_entity = Some(*entity);
+                                        toggle_selection = ui.input(|input| input.modifiers.command);
                                     }
-                                });
-                                
-                                // Handle click for selection
-                                if response.clicked() {
-                                    clicked_entity = Some(*entity);
-                                    clicked_entity_is_selected = *is_selected;
                                 }
-                            }
-                        });
+                            });
                     }
                 }
-                
-                // Handle selection change after the UI loop (to avoid borrow issues)
-                if let Some(entity) = clicked_entity {
-                    // Clear previous selection and select new entity
+            });
+            
+            if let Some(entity) = toggle_entity_visibility {
+                hierarchy_query.visibility.toggle_entity(entity);
+            }
+            
+            if let Some(object_type) = toggle_type_visibility {
+                hierarchy_query.visibility.toggle_type(object_type);
+            }
+            
+            // Handle selection change after the UI loop (to avoid borrow issues)
+            if let Some(entity) = clicked_entity {
+                if toggle_selection {
+                    if map_editor_state.is_entity_selected(entity) {
+                        map_editor_state.deselect_entity(entity);
+                        commands.entity(entity).remove::<SelectedInEditor>();
+                    } else {
+                        map_editor_state.select_entity(entity);
+                        commands.entity(entity).insert(SelectedInEditor);
+                    }
+                } else {
                     for selected_entity in hierarchy_query.selected.iter() {
                         commands.entity(selected_entity).remove::<SelectedInEditor>();
                     }
-                    
-                    // Note: We need to update MapEditorState as well
-                    // This would require ResMut<MapEditorState> which we can't easily pass here
-                    // For now, just add/remove the component
+                    map_editor_state.clear_selection();
+                    map_editor_state.select_entity(entity);
                     commands.entity(entity).insert(SelectedInEditor);
-                    
-                    log::info!("[Hierarchy] Selected entity: {:?}", entity);
                 }
-            });
+                
+                log::info!("[Hierarchy] Selected entity: {:?}", entity);
+            }
+            
+            if let Some(entity) = focus_entity {
+                map_editor_state.pending_focus = Some(entity);
+                log::info!("[Hierarchy] Focus on entity: {:?}", entity);
+            }
             
             // Footer with object count
-            let total_count: usize = categories.values().map(|v| v.len()).sum();
+            let shown_count: usize = categories.values().map(|v| v.len()).sum();
             ui.separator();
-            ui.label(format!("Total objects: {}", total_count));
+            if shown_count == total_count {
+                ui.label(format!("Total objects: {}", total_count));
+            } else {
+                ui.label(format!("Showing {} of {} objects", shown_count, total_count));
+            }
         });
 }
 
@@ -0,0 +1,121 @@
+//! History Panel for the Map Editor
+//!
+//! Lists the undo and redo stacks as named steps. Clicking a step undoes or
+//! redoes everything up to it in one go.
+
+use bevy::prelude::{Entity, Name, Query, ResMut};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::map_editor::resources::{EditorHistoryEntry, HistoryStep, MapEditorState};
+
+/// System to render the undo history window
+pub fn history_panel_system(
+    mut contexts: EguiContexts,
+    mut map_editor_state: ResMut<MapEditorState>,
+    query_name: Query<&Name>,
+) {
+    if !map_editor_state.enabled || !map_editor_state.show_history {
+        return;
+    }
+
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let mut is_open = map_editor_state.show_history;
+    let mut history_step = None;
+    let mut history_depth = map_editor_state.history_depth;
+
+    egui::Window::new("History")
+        .open(&mut is_open)
+        .resizable(true)
+        .default_width(280.0)
+        .default_height(360.0)
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.label("History depth:");
+                ui.add(egui::DragValue::new(&mut history_depth).range(1..=1000));
+            });
+
+            ui.separator();
+
+            egui::ScrollArea::vertical()
+                .auto_shrink([false, false])
+                .show(ui, |ui| {
+                    let undo_count = map_editor_state.undo_stack.len();
+
+                    if ui
+                        .add_enabled(undo_count > 0, egui::Button::new("Initial state").frame(false))
+                        .clicked()
+                    {
+                        history_step = Some(HistoryStep::Undo(undo_count));
+                    }
+
+                    // Oldest first, the last entry is the current state
+                    for (index, entry) in map_editor_state.undo_stack.iter().enumerate() {
+                        let is_current = index + 1 == undo_count;
+                        let text = history_entry_text(entry, &query_name);
+                        let text = if is_current {
+                            egui::RichText::new(format!("▶ {}", text)).strong()
+                        } else {
+                            egui::RichText::new(format!("  {}", text))
+                        };
+
+                        if ui.add(egui::Button::new(text).frame(false)).clicked() && !is_current {
+                            history_step = Some(HistoryStep::Undo(undo_count - 1 - index));
+                        }
+                    }
+
+                    // Redo stack top is the next step to redo
+                    for (index, entry) in map_editor_state.redo_stack.iter().rev().enumerate() {
+                        let text = egui::RichText::new(format!(
+                            "  {}",
+                            history_entry_text(entry, &query_name)
+                        ))
+                        .color(egui::Color32::GRAY);
+
+                        if ui.add(egui::Button::new(text).frame(false)).clicked() {
+                            history_step = Some(HistoryStep::Redo(index + 1));
+                        }
+                    }
+                });
+        });
+
+    map_editor_state.show_history = is_open;
+
+    if history_depth != map_editor_state.history_depth {
+        map_editor_state.history_depth = history_depth;
+        map_editor_state.trim_history();
+    }
+
+    if history_step.is_some() {
+        map_editor_state.pending_history_step = history_step;
+    }
+}
+
+/// Step label followed by the names of the objects it touched
+fn history_entry_text(entry: &EditorHistoryEntry, query_name: &Query<&Name>) -> String {
+    let entities = entry.action.entities();
+    if entities.is_empty() {
+        return entry.label.clone();
+    }
+
+    let names = entities
+        .iter()
+        .take(3)
+        .map(|entity| entity_name(*entity, query_name))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    if entities.len() > 3 {
+        format!("{} ({}, …)", entry.label, names)
+    } else {
+        format!("{} ({})", entry.label, names)
+    }
+}
+
+fn entity_name(entity: Entity, query_name: &Query<&Name>) -> String {
+    query_name
+        .get(entity)
+        .map_or_else(|_| "(deleted)".to_string(), |name| name.as_str().to_string())
+}
@@ -0,0 +1,76 @@
+//! IFO Import Window for the Map Editor
+//!
+//! Dialog opened from File > Import IFO to add the objects of another IFO
+//! file to the current zone, optionally offset from their stored positions.
+
+use std::path::PathBuf;
+
+use bevy::prelude::{MessageWriter, ResMut};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::map_editor::resources::{ImportIfoEvent, MapEditorState};
+
+/// System to render the IFO import dialog
+pub fn ifo_import_window_system(
+    mut contexts: EguiContexts,
+    mut map_editor_state: ResMut<MapEditorState>,
+    mut import_events: MessageWriter<ImportIfoEvent>,
+) {
+    if !map_editor_state.enabled || !map_editor_state.ifo_import.is_open {
+        return;
+    }
+
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let dialog = &mut map_editor_state.ifo_import;
+    let mut is_open = dialog.is_open;
+
+    egui::Window::new("Import IFO")
+        .open(&mut is_open)
+        .resizable(false)
+        .collapsible(false)
+        .show(ctx, |ui| {
+            ui.label("Adds the deco and cnst objects of an IFO file to the current zone.");
+            ui.label("Object ids are looked up in the current zone's ZSC files.");
+            ui.separator();
+
+            ui.horizontal(|ui| {
+                ui.label("File:");
+                ui.add(
+                    egui::TextEdit::singleline(&mut dialog.path)
+                        .hint_text("3DDATA/MAPS/JUNON/JDT01/32_32.IFO")
+                        .desired_width(300.0),
+                )
+                .on_hover_text("Relative paths are relative to the game data folder");
+            });
+
+            ui.horizontal(|ui| {
+                ui.label("Offset:");
+                ui.add(egui::DragValue::new(&mut dialog.offset.x).speed(0.5).prefix("X: "));
+                ui.add(egui::DragValue::new(&mut dialog.offset.y).speed(0.5).prefix("Y: "));
+                ui.add(egui::DragValue::new(&mut dialog.offset.z).speed(0.5).prefix("Z: "));
+            });
+
+            ui.separator();
+
+            let path = dialog.path.trim();
+            if ui
+                .add_enabled(!path.is_empty(), egui::Button::new("Import"))
+                .clicked()
+            {
+                log::info!("[MapEditor] Importing IFO {} with offset {:?}", path, dialog.offset);
+                import_events.write(ImportIfoEvent {
+                    path: PathBuf::from(path),
+                    offset: dialog.offset,
+                });
+            }
+
+            if let Some(ref result) = dialog.last_result {
+                ui.label(result);
+            }
+        });
+
+    dialog.is_open = is_open;
+}
@@ -0,0 +1,69 @@
+//! Measure Overlay for the Map Editor
+//!
+//! Labels each measured segment in the viewport with its length, and the end
+//! of a path with the total.
+
+use bevy::prelude::{Camera, Camera3d, GlobalTransform, Query, Res, Vec3, With};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::map_editor::resources::{EditorMode, MapEditorState, Measurement};
+
+/// System to draw world-space labels for the measuring tool
+pub fn measure_overlay_system(
+    mut contexts: EguiContexts,
+    map_editor_state: Res<MapEditorState>,
+    query_camera: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+) {
+    if !map_editor_state.enabled || map_editor_state.editor_mode != EditorMode::Measure {
+        return;
+    }
+
+    let points = map_editor_state.measure_tool.measured_points();
+    if points.len() < 2 {
+        return;
+    }
+
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+    let Ok((camera, camera_transform)) = query_camera.single() else {
+        return;
+    };
+
+    let painter = ctx.layer_painter(egui::LayerId::new(
+        egui::Order::Background,
+        egui::Id::new("measure_overlay"),
+    ));
+    let font = egui::FontId::proportional(14.0);
+
+    let mut draw_label = |position: Vec3, text: String| {
+        let Ok(screen) = camera.world_to_viewport(camera_transform, position) else {
+            return;
+        };
+        let galley = painter.layout_no_wrap(text, font.clone(), egui::Color32::YELLOW);
+        let rect = egui::Align2::CENTER_BOTTOM
+            .anchor_size(egui::pos2(screen.x, screen.y), galley.size())
+            .expand(3.0);
+        painter.rect_filled(rect, 3.0, egui::Color32::from_black_alpha(180));
+        painter.galley(rect.shrink(3.0).min, galley, egui::Color32::YELLOW);
+    };
+
+    for segment in points.windows(2) {
+        if let Some(measurement) = Measurement::between(segment) {
+            draw_label(
+                (segment[0] + segment[1]) / 2.0,
+                format!("{:.2} m ({:+.2} m)", measurement.distance, measurement.height),
+            );
+        }
+    }
+
+    // Path total at the last point
+    if points.len() > 2 {
+        if let Some(measurement) = Measurement::between(&points) {
+            draw_label(
+                points[points.len() - 1] + Vec3::Y * 0.5,
+                format!("Total {:.2} m", measurement.distance),
+            );
+        }
+    }
+}
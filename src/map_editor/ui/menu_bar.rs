@@ -5,7 +5,7 @@
 use bevy::prelude::*;
 use bevy_egui::egui;
 
-use crate::map_editor::resources::{DuplicateSelectedEvent, MapEditorState, EditorMode, SelectedModel};
+use crate::map_editor::resources::{DiscardAction, DuplicateSelectedEvent, EditorGridSettings, HistoryStep, MapEditorState, EditorMode, PlayTestRequest, SelectedModel};
 use crate::map_editor::save::{SaveZoneEvent, SaveStatus};
 use crate::map_editor::ui::NewZoneEvent;
 use crate::map_editor::ui::zone_list_panel::ZoneListPanelState;
@@ -20,20 +20,21 @@ pub struct HelpWindowState {
 /// Render the editor menu bar
 pub fn editor_menu_bar(
     ctx: &egui::Context,
-    map_editor_state: &MapEditorState,
+    map_editor_state: &mut MapEditorState,
     save_status: &SaveStatus,
     current_zone_id: Option<u16>,
     save_events: &mut MessageWriter<SaveZoneEvent>,
     zone_list_state: &mut ZoneListPanelState,
     new_zone_events: &mut MessageWriter<NewZoneEvent>,
     help_state: &mut HelpWindowState,
+    grid_settings: &mut EditorGridSettings,
     selected_model: &mut SelectedModel,
 ) {
     egui::TopBottomPanel::top("editor_menu_bar").show(ctx, |ui| {
         egui::menu::bar(ui, |ui| {
             file_menu(ui, map_editor_state, save_status, current_zone_id, save_events, new_zone_events, zone_list_state);
             edit_menu(ui, map_editor_state);
-            view_menu(ui, map_editor_state, selected_model);
+            view_menu(ui, map_editor_state, grid_settings, selected_model);
             zone_menu(ui, zone_list_state);
             object_menu(ui);
             help_menu(ui, &mut help_state.show_shortcuts, &mut help_state.show_about);
@@ -48,7 +49,7 @@ pub fn editor_menu_bar(
 /// File menu with New, Open, Save, Save As, Exit options
 fn file_menu(
     ui: &mut egui::Ui,
-    map_editor_state: &MapEditorState,
+    map_editor_state: &mut MapEditorState,
     save_status: &SaveStatus,
     current_zone_id: Option<u16>,
     save_events: &mut MessageWriter<SaveZoneEvent>,
@@ -103,6 +104,33 @@ fn file_menu(
             ui.close_menu();
         }
         
+        if ui.add_enabled(current_zone_id.is_some(), egui::Button::new("Import IFO...")).clicked() {
+            log::info!("[MapEditor] File > Import IFO clicked");
+            map_editor_state.ifo_import.is_open = true;
+            ui.close_menu();
+        }
+        
+        ui.menu_button("Autosave", |ui| {
+            let autosave = &mut map_editor_state.autosave;
+            ui.checkbox(&mut autosave.enabled, "Write recovery snapshots");
+            ui.add_enabled_ui(autosave.enabled, |ui| {
+                let mut minutes = autosave.interval / 60.0;
+                ui.horizontal(|ui| {
+                    ui.label("Every:");
+                    if ui
+                        .add(egui::DragValue::new(&mut minutes).speed(0.5).range(1.0..=120.0).suffix(" min"))
+                        .changed()
+                    {
+                        autosave.interval = minutes * 60.0;
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Keep:");
+                    ui.add(egui::DragValue::new(&mut autosave.retention).range(1..=50).suffix(" snapshots"));
+                });
+            });
+        });
+        
         // Show save status
         if save_status.is_saving {
             ui.label(egui::RichText::new("Saving...").color(egui::Color32::YELLOW));
@@ -118,6 +146,7 @@ fn file_menu(
         
         if ui.button("Exit Editor").clicked() {
             log::info!("[MapEditor] File > Exit Editor clicked");
+            map_editor_state.request_discard(DiscardAction::ExitEditor);
             ui.close_menu();
         }
     });
@@ -126,7 +155,7 @@ fn file_menu(
 /// Edit menu with Undo, Redo, Cut, Copy, Paste, Delete, Duplicate options
 fn edit_menu(
     ui: &mut egui::Ui,
-    map_editor_state: &MapEditorState,
+    map_editor_state: &mut MapEditorState,
 ) {
     ui.menu_button("Edit", |ui| {
         // Undo with shortcut
@@ -136,6 +165,7 @@ fn edit_menu(
         );
         if undo_button.clicked() {
             log::info!("[MapEditor] Edit > Undo clicked");
+            map_editor_state.pending_history_step = Some(HistoryStep::Undo(1));
             ui.close_menu();
         }
         
@@ -146,6 +176,7 @@ fn edit_menu(
         );
         if redo_button.clicked() {
             log::info!("[MapEditor] Edit > Redo clicked");
+            map_editor_state.pending_history_step = Some(HistoryStep::Redo(1));
             ui.close_menu();
         }
         
@@ -204,7 +235,12 @@ fn edit_menu(
 }
 
 /// View menu with grid and camera options
-fn view_menu(ui: &mut egui::Ui, map_editor_state: &MapEditorState, selected_model: &mut SelectedModel) {
+fn view_menu(
+    ui: &mut egui::Ui,
+    map_editor_state: &mut MapEditorState,
+    grid_settings: &mut EditorGridSettings,
+    selected_model: &mut SelectedModel,
+) {
     ui.menu_button("View", |ui| {
         // Model Browser toggle
         let browser_text = if selected_model.browser_visible {
@@ -218,18 +254,106 @@ fn view_menu(ui: &mut egui::Ui, map_editor_state: &MapEditorState, selected_mode
             ui.close_menu();
         }
         
+        // Undo history toggle
+        let history_text = if map_editor_state.show_history {
+            "✓ History"
+        } else {
+            "  History"
+        };
+        if ui.button(history_text).clicked() {
+            map_editor_state.show_history = !map_editor_state.show_history;
+            log::info!("[MapEditor] View > History clicked (visible: {})", map_editor_state.show_history);
+            ui.close_menu();
+        }
+        
+        // Warp destination lines and labels
+        let warp_links_text = if map_editor_state.show_warp_links {
+            "✓ Warp Links"
+        } else {
+            "  Warp Links"
+        };
+        if ui.button(warp_links_text).clicked() {
+            map_editor_state.show_warp_links = !map_editor_state.show_warp_links;
+            log::info!("[MapEditor] View > Warp Links clicked (visible: {})", map_editor_state.show_warp_links);
+            ui.close_menu();
+        }
+        
+        // Zone statistics and issues
+        let validation_text = if map_editor_state.zone_validation.is_open {
+            "✓ Zone Validation"
+        } else {
+            "  Zone Validation"
+        };
+        if ui.button(validation_text).clicked() {
+            let validation = &mut map_editor_state.zone_validation;
+            validation.is_open = !validation.is_open;
+            log::info!("[MapEditor] View > Zone Validation clicked (visible: {})", validation.is_open);
+            ui.close_menu();
+        }
+        
         ui.separator();
         
         // Toggle Grid
-        let grid_text = if map_editor_state.show_grid {
+        let grid_text = if grid_settings.visible {
             "✓ Toggle Grid"
         } else {
             "  Toggle Grid"
         };
         if ui.button(grid_text).clicked() {
-            log::info!("[MapEditor] View > Toggle Grid clicked");
-            ui.close_menu();
-        }
+            grid_settings.visible = !grid_settings.visible;
+            log::info!("[MapEditor] View > Toggle Grid clicked (visible: {})", grid_settings.visible);
+            ui.close_menu();
+        }
+        
+        ui.menu_button("Grid Settings", |ui| {
+            egui::Grid::new("grid_settings").num_columns(2).show(ui, |ui| {
+                ui.label("Cell size:");
+                ui.add(
+                    egui::DragValue::new(&mut grid_settings.cell_size)
+                        .speed(0.1)
+                        .range(0.1..=100.0)
+                        .suffix(" m"),
+                )
+                .on_hover_text("Also the snapping distance");
+                ui.end_row();
+                
+                ui.label("Major line every:");
+                ui.add(
+                    egui::DragValue::new(&mut grid_settings.subdivisions)
+                        .range(1..=100)
+                        .suffix(" cells"),
+                );
+                ui.end_row();
+                
+                ui.label("Draw distance:");
+                ui.add(
+                    egui::DragValue::new(&mut grid_settings.draw_distance)
+                        .speed(1.0)
+                        .range(10.0..=2000.0)
+                        .suffix(" m"),
+                );
+                ui.end_row();
+                
+                ui.label("Height:");
+                ui.add(egui::DragValue::new(&mut grid_settings.height).speed(0.5).suffix(" m"));
+                ui.end_row();
+                
+                ui.label("Minor lines:");
+                ui.color_edit_button_rgba_unmultiplied(&mut grid_settings.minor_color);
+                ui.end_row();
+                
+                ui.label("Major lines:");
+                ui.color_edit_button_rgba_unmultiplied(&mut grid_settings.major_color);
+                ui.end_row();
+            });
+            ui.checkbox(&mut grid_settings.show_axes, "Highlight X and Z axes");
+            if ui.button("Reset to Defaults").clicked() {
+                *grid_settings = EditorGridSettings {
+                    visible: grid_settings.visible,
+                    ..Default::default()
+                };
+            }
+        });
         
         // Snap to Grid
         let snap_text = if map_editor_state.snap_to_grid {
@@ -249,8 +373,22 @@ fn view_menu(ui: &mut egui::Ui, map_editor_state: &MapEditorState, selected_mode
             ui.close_menu();
         }
         
-        if ui.button("Frame Selection").clicked() {
+        let frame_button = ui.add_enabled(
+            map_editor_state.selection_count() > 0,
+            egui::Button::new("Frame Selection").shortcut_text("F"),
+        );
+        if frame_button.clicked() {
             log::info!("[MapEditor] View > Frame Selection clicked");
+            map_editor_state.pending_focus = map_editor_state.first_selected();
+            ui.close_menu();
+        }
+        
+        let play_button = ui
+            .add(egui::Button::new("▶ Play From Here").shortcut_text("F5"))
+            .on_hover_text("Walk the zone from the camera position, Escape returns to the editor");
+        if play_button.clicked() {
+            log::info!("[MapEditor] View > Play From Here clicked");
+            map_editor_state.play_test.pending = Some(PlayTestRequest::Start);
             ui.close_menu();
         }
         
@@ -373,6 +511,7 @@ pub fn show_keyboard_shortcuts_window(ctx: &egui::Context, is_open: &mut bool) {
             ui.label("R - Scale mode");
             ui.label("V - Add mode");
             ui.label("X - Delete mode");
+            ui.label("M - Measure mode (Escape cancels, right click removes a point)");
             
             ui.add_space(8.0);
             ui.heading("Actions");
@@ -392,6 +531,7 @@ pub fn show_keyboard_shortcuts_window(ctx: &egui::Context, is_open: &mut bool) {
             ui.label("WASD - Move camera (free camera mode)");
             ui.label("Mouse - Look around (free camera mode)");
             ui.label("Scroll - Zoom in/out");
+            ui.label("F5 - Play from here (WASD walks, Shift runs, Escape returns)");
             
             ui.add_space(8.0);
             ui.heading("Panels");
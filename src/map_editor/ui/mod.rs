@@ -21,17 +21,25 @@
 
 pub mod menu_bar;
 pub mod hierarchy_panel;
+pub mod history_panel;
+pub mod ifo_import_window;
+pub mod measure_overlay;
 pub mod model_browser_panel;
+pub mod play_test_overlay;
 pub mod properties_panel;
+pub mod recovery_window;
 pub mod status_bar;
+pub mod unsaved_changes_dialog;
+pub mod warp_link_overlay;
 pub mod zone_list_panel;
+pub mod zone_validation_panel;
 
 use bevy::{ecs::system::SystemParam, prelude::*};
 use bevy_egui::{egui, EguiContexts};
 
 use crate::components::{EventObject, WarpObject, ZoneObject};
 use crate::map_editor::components::SelectedInEditor;
-use crate::map_editor::resources::{AvailableModels, DuplicateSelectedEvent, EditorMode, HierarchyFilter, MapEditorState, SelectedModel};
+use crate::map_editor::resources::{ArrangeSelectedEvent, AvailableModels, DiscardAction, DuplicateSelectedEvent, EditorGridSettings, EditorMode, HierarchyFilter, MapEditorState, SelectedModel, TerrainBlockEvent};
 use crate::map_editor::systems::property_update_system::PropertyChangeEvent;
 use crate::map_editor::save::{SaveZoneEvent, SaveStatus};
 use crate::resources::{CurrentZone, GameData};
@@ -40,20 +48,37 @@ use crate::events::LoadZoneEvent;
 use menu_bar::editor_menu_bar;
 use menu_bar::HelpWindowState;
 use hierarchy_panel::{editor_hierarchy_panel, HierarchyQuery};
+use history_panel::history_panel_system;
+use ifo_import_window::ifo_import_window_system;
+use measure_overlay::measure_overlay_system;
 use model_browser_panel::editor_model_browser_panel;
+use play_test_overlay::play_test_overlay_system;
+use recovery_window::recovery_window_system;
 use status_bar::editor_status_bar;
+use unsaved_changes_dialog::unsaved_changes_dialog_system;
+use warp_link_overlay::warp_link_overlay_system;
 use zone_list_panel::{ZoneListPanelState, zone_list_panel_system};
+use zone_validation_panel::zone_validation_panel_system;
 
 // Re-export the standalone properties panel function
 pub use properties_panel::{
     editor_properties_panel, EntityDataQuery, PendingPropertyEdits,
 };
 
-/// System parameter combining queries needed by the properties panel
+/// System parameter combining queries and message writers needed by the properties panel
 #[derive(SystemParam)]
 pub struct PropertiesQueries<'w, 's> {
     pub name_query: Query<'w, 's, &'static Name>,
     pub transform_query: Query<'w, 's, &'static Transform>,
+    pub arrange_events: MessageWriter<'w, ArrangeSelectedEvent>,
+    pub terrain_block_events: MessageWriter<'w, TerrainBlockEvent>,
+}
+
+/// System parameter combining resources only edited from the menu bar
+#[derive(SystemParam)]
+pub struct MenuBarResources<'w> {
+    pub help_state: ResMut<'w, HelpWindowState>,
+    pub grid_settings: ResMut<'w, EditorGridSettings>,
 }
 
 /// Plugin for the map editor UI systems
@@ -88,6 +113,38 @@ impl Plugin for EditorUiPlugin {
             .add_systems(
                 bevy_egui::EguiPrimaryContextPass,
                 new_zone_system.run_if(resource_exists::<MapEditorState>),
+            )
+            .add_systems(
+                bevy_egui::EguiPrimaryContextPass,
+                history_panel_system.run_if(resource_exists::<MapEditorState>),
+            )
+            .add_systems(
+                bevy_egui::EguiPrimaryContextPass,
+                ifo_import_window_system.run_if(resource_exists::<MapEditorState>),
+            )
+            .add_systems(
+                bevy_egui::EguiPrimaryContextPass,
+                measure_overlay_system.run_if(resource_exists::<MapEditorState>),
+            )
+            .add_systems(
+                bevy_egui::EguiPrimaryContextPass,
+                warp_link_overlay_system.run_if(resource_exists::<MapEditorState>),
+            )
+            .add_systems(
+                bevy_egui::EguiPrimaryContextPass,
+                zone_validation_panel_system.run_if(resource_exists::<MapEditorState>),
+            )
+            .add_systems(
+                bevy_egui::EguiPrimaryContextPass,
+                recovery_window_system.run_if(resource_exists::<MapEditorState>),
+            )
+            .add_systems(
+                bevy_egui::EguiPrimaryContextPass,
+                unsaved_changes_dialog_system.run_if(resource_exists::<MapEditorState>),
+            )
+            .add_systems(
+                bevy_egui::EguiPrimaryContextPass,
+                play_test_overlay_system.run_if(resource_exists::<MapEditorState>),
             );
         
         log::info!("[EditorUiPlugin] Editor UI plugin initialized with model browser, zone list, and new zone handler");
@@ -120,14 +177,14 @@ pub fn editor_ui_system(
     current_zone: Option<Res<CurrentZone>>,
     mut save_events: MessageWriter<SaveZoneEvent>,
     entity_data: EntityDataQuery,
-    hierarchy_query: HierarchyQuery,
+    mut hierarchy_query: HierarchyQuery,
     mut pending_edits: ResMut<PendingPropertyEdits>,
-    queries: PropertiesQueries,
+    mut queries: PropertiesQueries,
     mut property_change_event: MessageWriter<PropertyChangeEvent>,
     mut duplicate_event: MessageWriter<DuplicateSelectedEvent>,
     mut zone_list_state: ResMut<ZoneListPanelState>,
     mut new_zone_events: MessageWriter<NewZoneEvent>,
-    mut help_state: ResMut<HelpWindowState>,
+    mut menu_resources: MenuBarResources,
     mut commands: Commands,
     mut selected_model: ResMut<SelectedModel>,
 ) {
@@ -144,18 +201,19 @@ pub fn editor_ui_system(
     // Menu Bar (top)
     editor_menu_bar(
         &*ctx,
-        &map_editor_state,
+        &mut map_editor_state,
         &save_status,
         current_zone_id,
         &mut save_events,
         &mut zone_list_state,
         &mut new_zone_events,
-        &mut help_state,
+        &mut menu_resources.help_state,
+        &mut menu_resources.grid_settings,
         &mut selected_model,
     );
     
     // Hierarchy Panel (left side) - now with entity query access
-    editor_hierarchy_panel(&*ctx, &map_editor_state, &hierarchy_query, &mut commands);
+    editor_hierarchy_panel(&*ctx, &mut map_editor_state, &mut hierarchy_query, &mut commands);
     
     // Properties Panel (right side) - now with entity data access
     editor_properties_panel(
@@ -167,6 +225,8 @@ pub fn editor_ui_system(
         &queries.transform_query,
         &mut property_change_event,
         &mut duplicate_event,
+        &mut queries.arrange_events,
+        &mut queries.terrain_block_events,
     );
     
     // Status Bar (bottom)
@@ -203,11 +263,12 @@ pub fn new_zone_system(
     mut map_editor_state: ResMut<MapEditorState>,
 ) {
     for event in events.read() {
-        // Check if we should prompt for unsaved changes
+        // Ask to save or discard unsaved changes first, the unsaved changes
+        // system sends the event again without the prompt once confirmed
         if event.prompt_if_modified && map_editor_state.is_modified {
-            // For now, just log a warning. In a full implementation,
-            // we would show a dialog asking the user to save.
-            log::warn!("[NewZone] Zone has unsaved changes, but proceeding with new zone (dialog not implemented)");
+            log::info!("[NewZone] Zone has unsaved changes, asking before creating a new zone");
+            map_editor_state.request_discard(DiscardAction::NewZone);
+            continue;
         }
         
         // Despawn all zone objects
@@ -220,6 +281,7 @@ pub fn new_zone_system(
         // Clear selection and reset modification state
         map_editor_state.clear_selection();
         map_editor_state.is_modified = false;
+        map_editor_state.autosave.dirty = false;
         map_editor_state.clear_history();
         
         log::info!("[NewZone] Cleared {} zone objects, editor state reset", despawned_count);
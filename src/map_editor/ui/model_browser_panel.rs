@@ -7,7 +7,7 @@ use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
 
 use crate::map_editor::resources::{
-    AvailableModels, MapEditorState, ModelCategory, SelectedModel, EditorMode,
+    AvailableModels, MapEditorState, ModelCategory, SelectedModel, EditorMode, ScatterBrush,
 };
 
 /// Model browser panel - displays at the bottom of the screen
@@ -70,6 +70,8 @@ pub fn editor_model_browser_panel(
                 }
             });
             
+            scatter_brush_controls(ui, &mut selected_model.scatter_brush);
+            
             // Model list with scrolling
             egui::ScrollArea::vertical()
                 .max_height(150.0)
@@ -196,6 +198,34 @@ pub fn editor_model_browser_panel(
         });
 }
 
+/// Scatter brush toggle and its density and jitter settings
+fn scatter_brush_controls(ui: &mut egui::Ui, brush: &mut ScatterBrush) {
+    ui.horizontal(|ui| {
+        ui.checkbox(&mut brush.enabled, "🖌 Scatter Brush")
+            .on_hover_text("Hold the left mouse button in Add mode to paint instances onto the terrain");
+        
+        ui.add_enabled_ui(brush.enabled, |ui| {
+            ui.separator();
+            ui.label("Radius:");
+            ui.add(egui::DragValue::new(&mut brush.radius).speed(0.1).range(0.5..=100.0).suffix(" m"));
+            ui.label("Density:");
+            ui.add(egui::DragValue::new(&mut brush.density).speed(0.05).range(0.0..=100.0))
+                .on_hover_text("Instances per 100 m²");
+            
+            ui.separator();
+            ui.label("Rotation ±:");
+            ui.add(egui::DragValue::new(&mut brush.rotation_jitter).speed(1.0).range(0.0..=180.0).suffix("°"));
+            ui.label("Scale:");
+            ui.add(egui::DragValue::new(&mut brush.scale_min).speed(0.01).range(0.01..=brush.scale_max));
+            ui.label("to");
+            ui.add(egui::DragValue::new(&mut brush.scale_max).speed(0.01).range(brush.scale_min..=100.0));
+            
+            ui.separator();
+            ui.checkbox(&mut brush.align_to_normal, "Align to slope");
+        });
+    });
+}
+
 /// System to render the model browser panel
 pub fn model_browser_panel_system(
     mut contexts: EguiContexts,
@@ -0,0 +1,43 @@
+//! Play Test Overlay for the Map Editor
+//!
+//! The editor panels are hidden while play-testing, this shows the controls
+//! and a button back to the editor instead.
+
+use bevy::prelude::ResMut;
+use bevy_egui::{egui, EguiContexts};
+
+use crate::map_editor::resources::{MapEditorState, PlayTestRequest};
+
+/// System to render the play test overlay
+pub fn play_test_overlay_system(
+    mut contexts: EguiContexts,
+    mut map_editor_state: ResMut<MapEditorState>,
+) {
+    if !map_editor_state.play_test.is_active {
+        return;
+    }
+
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let mut stop = false;
+    egui::Area::new(egui::Id::new("play_test_overlay"))
+        .anchor(egui::Align2::CENTER_TOP, egui::vec2(0.0, 8.0))
+        .show(ctx, |ui| {
+            egui::Frame::popup(ui.style()).show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new("▶ Play Test").strong());
+                    ui.label("WASD to walk, Shift to run, Escape to return");
+                    if ui.button("Return to Editor").clicked() {
+                        stop = true;
+                    }
+                });
+            });
+        });
+
+    if stop {
+        log::info!("[MapEditor] Play test > Return to Editor clicked");
+        map_editor_state.play_test.pending = Some(PlayTestRequest::Stop);
+    }
+}
@@ -3,15 +3,24 @@
 //! Displays properties of the selected entity including transform and components.
 //! Connects to actual entity data and sends property change events.
 
+use std::collections::BTreeSet;
+
 use bevy::{ecs::system::SystemParam, prelude::*};
 use bevy_egui::egui;
 
 use crate::components::{
-    EventObject, WarpObject, ZoneObject, ZoneObjectPart, ZoneObjectPartCollisionShape,
+    EventObject, WarpDestination, WarpObject, ZoneObject, ZoneObjectPart, ZoneObjectPartCollisionShape,
 };
 use crate::map_editor::components::SelectedInEditor;
-use crate::map_editor::resources::{DuplicateSelectedEvent, EditorMode, MapEditorState};
-use crate::map_editor::systems::property_update_system::PropertyChangeEvent;
+use crate::map_editor::resources::{
+    AlignAnchor, ArrangeAxis, ArrangeSelectedEvent, DuplicateSelectedEvent, EditorMode, MapEditorState,
+    TerrainBlock, TerrainBlockEvent, TerrainBlockOperation,
+};
+use crate::map_editor::systems::property_update_system::{event_name_warning, PropertyChangeEvent};
+use crate::map_editor::systems::terrain_block_system::{block_origin, height_range, zone_block, BLOCK_SIZE};
+use crate::map_editor::systems::warp_link_system::zone_name;
+use crate::resources::{CurrentZone, GameData};
+use crate::zone_loader::ZoneLoaderAsset;
 
 /// System parameter for accessing entity data in the properties panel
 #[derive(SystemParam)]
@@ -19,8 +28,14 @@ pub struct EntityDataQuery<'w, 's> {
     transforms: Query<'w, 's, &'static Transform, With<SelectedInEditor>>,
     zone_objects: Query<'w, 's, &'static ZoneObject, With<SelectedInEditor>>,
     event_objects: Query<'w, 's, &'static EventObject, With<SelectedInEditor>>,
+    all_event_objects: Query<'w, 's, &'static EventObject>,
     warp_objects: Query<'w, 's, &'static WarpObject, With<SelectedInEditor>>,
+    warp_destinations: Query<'w, 's, &'static WarpDestination, With<SelectedInEditor>>,
+    global_transforms: Query<'w, 's, &'static GlobalTransform, With<SelectedInEditor>>,
     names: Query<'w, 's, &'static Name, With<SelectedInEditor>>,
+    game_data: Option<Res<'w, GameData>>,
+    current_zone: Option<Res<'w, CurrentZone>>,
+    zone_loader_assets: Res<'w, Assets<ZoneLoaderAsset>>,
 }
 
 /// Resource to store pending property changes from UI
@@ -36,6 +51,12 @@ pub struct PendingPropertyEdits {
     pub initialized: bool,
     /// The entity we're editing
     pub editing_entity: Option<Entity>,
+    /// Keep objects upright when snapping them to terrain
+    pub snap_keep_upright: bool,
+    /// Height in metres to flatten the selected terrain block to
+    pub terrain_flatten_height: f32,
+    /// The terrain block the flatten height was initialized for
+    pub editing_terrain_block: Option<(u32, u32)>,
 }
 
 /// Render the properties panel (right side panel)
@@ -121,6 +142,8 @@ pub fn editor_properties_panel(
     transform_query: &Query<&Transform>,
     event_writer: &mut MessageWriter<PropertyChangeEvent>,
     duplicate_event_writer: &mut MessageWriter<DuplicateSelectedEvent>,
+    arrange_event_writer: &mut MessageWriter<ArrangeSelectedEvent>,
+    terrain_block_event_writer: &mut MessageWriter<TerrainBlockEvent>,
 ) {
     egui::SidePanel::right("properties_panel")
         .default_width(300.0)
@@ -134,10 +157,22 @@ pub fn editor_properties_panel(
             let selection_count = map_editor_state.selection_count();
             
             if selection_count == 0 {
-                ui.label(egui::RichText::new("No object selected").italics());
-                ui.label("Click on an object in the viewport or hierarchy to select it.");
                 pending_edits.initialized = false;
                 pending_edits.editing_entity = None;
+                if let Some(block) = map_editor_state.selected_terrain_block {
+                    terrain_block_properties(
+                        ui,
+                        block,
+                        map_editor_state,
+                        entity_data,
+                        pending_edits,
+                        terrain_block_event_writer,
+                    );
+                } else {
+                    ui.label(egui::RichText::new("No object selected").italics());
+                    ui.label("Click on an object in the viewport or hierarchy to select it.");
+                    pending_edits.editing_terrain_block = None;
+                }
                 return;
             }
             
@@ -159,11 +194,125 @@ pub fn editor_properties_panel(
                 }
             } else {
                 // Multi-selection - show summary
-                multi_object_properties_standalone(ui, map_editor_state, event_writer, duplicate_event_writer);
+                multi_object_properties_standalone(
+                    ui,
+                    map_editor_state,
+                    pending_edits,
+                    event_writer,
+                    duplicate_event_writer,
+                    arrange_event_writer,
+                );
             }
         });
 }
 
+
+/// Show properties and block operations for the selected terrain block
+fn terrain_block_properties(
+    ui: &mut egui::Ui,
+    block: TerrainBlock,
+    map_editor_state: &MapEditorState,
+    entity_data: &EntityDataQuery,
+    pending_edits: &mut PendingPropertyEdits,
+    event_writer: &mut MessageWriter<TerrainBlockEvent>,
+) {
+    let block_data = entity_data
+        .current_zone
+        .as_ref()
+        .and_then(|current_zone| entity_data.zone_loader_assets.get(&current_zone.handle))
+        .and_then(|zone_data| zone_block(zone_data, block.block_x, block.block_y));
+    let heights = block_data.map(|block_data| height_range(&block_data.him.heights));
+
+    // Initialize the flatten height when switching to a new block
+    let block_coords = (block.block_x, block.block_y);
+    if pending_edits.editing_terrain_block != Some(block_coords) {
+        pending_edits.terrain_flatten_height = heights
+            .flatten()
+            .map_or(0.0, |(_, _, average)| average / 100.0);
+        pending_edits.editing_terrain_block = Some(block_coords);
+    }
+
+    ui.horizontal(|ui| {
+        ui.label(egui::RichText::new("Terrain Block:").strong());
+        ui.label(format!("{}_{}", block.block_x, block.block_y));
+        if map_editor_state.dirty_terrain_blocks.contains(&block_coords) {
+            ui.label(egui::RichText::new("Modified").color(egui::Color32::YELLOW));
+        }
+    });
+
+    let origin = block_origin(block.block_x, block.block_y);
+    ui.label(format!(
+        "Bounds: X {:.0} to {:.0}, Z {:.0} to {:.0}",
+        origin.x,
+        origin.x + BLOCK_SIZE,
+        origin.z,
+        origin.z + BLOCK_SIZE
+    ));
+
+    let Some(block_data) = block_data else {
+        ui.label(egui::RichText::new("Heightmap not loaded").italics());
+        return;
+    };
+    ui.label(format!(
+        "Heightmap: {} x {}",
+        block_data.him.width, block_data.him.height
+    ));
+    if let Some((min, max, average)) = heights.flatten() {
+        ui.label(format!(
+            "Height: min {:.2} m, max {:.2} m, avg {:.2} m",
+            min / 100.0,
+            max / 100.0,
+            average / 100.0
+        ));
+    }
+
+    ui.separator();
+    ui.label(egui::RichText::new("Block Operations").strong());
+
+    ui.horizontal(|ui| {
+        ui.add(
+            egui::DragValue::new(&mut pending_edits.terrain_flatten_height)
+                .suffix(" m")
+                .speed(0.1),
+        );
+        if ui
+            .button("Flatten")
+            .on_hover_text("Set every height of the block to this height")
+            .clicked()
+        {
+            event_writer.write(TerrainBlockEvent {
+                block,
+                operation: TerrainBlockOperation::Flatten(pending_edits.terrain_flatten_height),
+            });
+        }
+    });
+
+    ui.horizontal(|ui| {
+        if ui.button("Copy Heightmap").clicked() {
+            event_writer.write(TerrainBlockEvent {
+                block,
+                operation: TerrainBlockOperation::CopyHeightmap,
+            });
+        }
+        let clipboard_source = map_editor_state
+            .heightmap_clipboard
+            .as_ref()
+            .map_or(String::new(), |clipboard| {
+                format!("Paste the heightmap copied from block {}_{}", clipboard.block_x, clipboard.block_y)
+            });
+        if ui
+            .add_enabled(map_editor_state.heightmap_clipboard.is_some(), egui::Button::new("Paste Heightmap"))
+            .on_hover_text(clipboard_source)
+            .on_disabled_hover_text("No heightmap copied")
+            .clicked()
+        {
+            event_writer.write(TerrainBlockEvent {
+                block,
+                operation: TerrainBlockOperation::PasteHeightmap,
+            });
+        }
+    });
+}
 /// Show properties for a single selected object
 fn single_object_properties(
     ui: &mut egui::Ui,
@@ -421,8 +570,10 @@ fn multi_object_properties(
 fn multi_object_properties_standalone(
     ui: &mut egui::Ui,
     map_editor_state: &MapEditorState,
+    pending_edits: &mut PendingPropertyEdits,
     _event_writer: &mut MessageWriter<PropertyChangeEvent>,
     duplicate_event_writer: &mut MessageWriter<DuplicateSelectedEvent>,
+    arrange_event_writer: &mut MessageWriter<ArrangeSelectedEvent>,
 ) {
     let count = map_editor_state.selection_count();
     
@@ -454,6 +605,13 @@ fn multi_object_properties_standalone(
     
     ui.separator();
     
+    // Align, distribute and snap to terrain
+    ui.collapsing("Arrange", |ui| {
+        arrange_editor(ui, count, pending_edits, arrange_event_writer);
+    });
+    
+    ui.separator();
+    
     // Bulk actions
     ui.label("Actions:");
     if ui.button("Delete All Selected").clicked() {
@@ -470,6 +628,61 @@ fn multi_object_properties_standalone(
     }
 }
 
+/// Align, distribute and snap to terrain tools for a multi-selection
+fn arrange_editor(
+    ui: &mut egui::Ui,
+    selection_count: usize,
+    pending_edits: &mut PendingPropertyEdits,
+    arrange_event_writer: &mut MessageWriter<ArrangeSelectedEvent>,
+) {
+    ui.label(egui::RichText::new("Align:").strong());
+    egui::Grid::new("arrange_align_grid").show(ui, |ui| {
+        for axis in [ArrangeAxis::X, ArrangeAxis::Y, ArrangeAxis::Z] {
+            ui.label(format!("{:?}", axis));
+            for (anchor, text) in [
+                (AlignAnchor::Min, "Min"),
+                (AlignAnchor::Center, "Center"),
+                (AlignAnchor::Max, "Max"),
+            ] {
+                if ui.button(text).clicked() {
+                    arrange_event_writer.write(ArrangeSelectedEvent::Align { axis, anchor });
+                    log::info!("[Properties] Align {:?} {:?} clicked", axis, anchor);
+                }
+            }
+            ui.end_row();
+        }
+    });
+    
+    ui.add_space(4.0);
+    
+    // Distributing needs objects between the two outermost ones
+    ui.label(egui::RichText::new("Distribute evenly:").strong());
+    ui.horizontal(|ui| {
+        for axis in [ArrangeAxis::X, ArrangeAxis::Y, ArrangeAxis::Z] {
+            if ui
+                .add_enabled(selection_count >= 3, egui::Button::new(format!("{:?}", axis)))
+                .on_disabled_hover_text("Select at least 3 objects")
+                .clicked()
+            {
+                arrange_event_writer.write(ArrangeSelectedEvent::Distribute { axis });
+                log::info!("[Properties] Distribute {:?} clicked", axis);
+            }
+        }
+    });
+    
+    ui.add_space(4.0);
+    
+    ui.horizontal(|ui| {
+        if ui.button("Snap to Terrain").clicked() {
+            arrange_event_writer.write(ArrangeSelectedEvent::SnapToTerrain {
+                align_to_normal: !pending_edits.snap_keep_upright,
+            });
+            log::info!("[Properties] Snap to terrain clicked");
+        }
+        ui.checkbox(&mut pending_edits.snap_keep_upright, "Keep upright");
+    });
+}
+
 /// Transform editor with position, rotation, and scale
 fn transform_editor(
     ui: &mut egui::Ui,
@@ -858,7 +1071,7 @@ fn event_object_editor_standalone(
     _map_editor_state: &MapEditorState,
 ) {
     if let Ok(event_object) = entity_data.event_objects.get(entity) {
-        event_object_editor_inner_with_events(ui, entity, event_object, event_writer);
+        event_object_editor_inner_with_events(ui, entity, event_object, entity_data, event_writer);
     }
 }
 
@@ -888,37 +1101,68 @@ fn event_object_editor_inner_with_events(
     ui: &mut egui::Ui,
     entity: Entity,
     event_object: &EventObject,
+    entity_data: &EntityDataQuery,
     event_writer: &mut MessageWriter<PropertyChangeEvent>,
 ) {
     ui.label(egui::RichText::new("Event Object Properties:").strong());
     
-    ui.horizontal(|ui| {
-        ui.label("Quest Trigger:");
-        let mut quest_trigger = event_object.quest_trigger_name.clone();
-        let old_value = quest_trigger.clone();
-        if ui.text_edit_singleline(&mut quest_trigger).changed() {
-            event_writer.write(PropertyChangeEvent::EventObjectChanged {
-                entity,
-                property_name: "quest_trigger_name".to_string(),
-                old_value,
-                new_value: quest_trigger,
-            });
+    // Names already used by event objects in the loaded zones
+    let mut known_triggers = BTreeSet::new();
+    let mut known_functions = BTreeSet::new();
+    for other in entity_data.all_event_objects.iter() {
+        if !other.quest_trigger_name.is_empty() {
+            known_triggers.insert(other.quest_trigger_name.as_str());
         }
-    });
+        if !other.script_function_name.is_empty() {
+            known_functions.insert(other.script_function_name.as_str());
+        }
+    }
     
-    ui.horizontal(|ui| {
-        ui.label("Script Function:");
-        let mut script_func = event_object.script_function_name.clone();
-        let old_value = script_func.clone();
-        if ui.text_edit_singleline(&mut script_func).changed() {
+    let game_data = entity_data.game_data.as_deref();
+    let fields = [
+        ("Quest Trigger:", "quest_trigger_name", &event_object.quest_trigger_name, &known_triggers),
+        ("Script Function:", "script_function_name", &event_object.script_function_name, &known_functions),
+    ];
+    
+    for (label, property_name, old_value, known_names) in fields {
+        let mut value = old_value.clone();
+        
+        ui.horizontal(|ui| {
+            ui.label(label);
+            ui.text_edit_singleline(&mut value);
+            egui::ComboBox::from_id_salt((property_name, entity))
+                .width(16.0)
+                .selected_text("")
+                .show_ui(ui, |ui| {
+                    if known_names.is_empty() {
+                        ui.label("No known names");
+                    }
+                    for name in known_names.iter() {
+                        ui.selectable_value(&mut value, name.to_string(), *name);
+                    }
+                });
+        });
+        
+        let mut warning = event_name_warning(&value);
+        if warning.is_none()
+            && property_name == "quest_trigger_name"
+            && game_data.is_some_and(|game_data| game_data.quests.get_trigger_by_name(&value).is_none())
+        {
+            warning = Some("Trigger not found in the quest data");
+        }
+        if let Some(warning) = warning {
+            ui.colored_label(egui::Color32::YELLOW, format!("⚠ {}", warning));
+        }
+        
+        if value != *old_value {
             event_writer.write(PropertyChangeEvent::EventObjectChanged {
                 entity,
-                property_name: "script_function_name".to_string(),
-                old_value,
-                new_value: script_func,
+                property_name: property_name.to_string(),
+                old_value: old_value.clone(),
+                new_value: value,
             });
         }
-    });
+    }
 }
 
 /// Warp object editor
@@ -943,7 +1187,7 @@ fn warp_object_editor_standalone(
     _map_editor_state: &MapEditorState,
 ) {
     if let Ok(warp_object) = entity_data.warp_objects.get(entity) {
-        warp_object_editor_inner_with_events(ui, entity, warp_object, event_writer);
+        warp_object_editor_inner_with_events(ui, entity, warp_object, entity_data, event_writer);
     }
 }
 
@@ -969,35 +1213,103 @@ fn warp_object_editor_inner_with_events(
     ui: &mut egui::Ui,
     entity: Entity,
     warp_object: &WarpObject,
+    entity_data: &EntityDataQuery,
     event_writer: &mut MessageWriter<PropertyChangeEvent>,
 ) {
     ui.label(egui::RichText::new("Warp Object Properties:").strong());
     
     ui.horizontal(|ui| {
         ui.label("Warp ID:");
-        // Display warp ID (WarpGateId is likely an enum or struct)
-        let warp_id_str = format!("{:?}", warp_object.warp_id);
-        ui.label(&warp_id_str);
-    });
-    
-    ui.add_space(4.0);
-    
-    // Target zone/position would be editable here
-    ui.label(egui::RichText::new("Target:").strong());
-    
-    let mut target_zone = String::from("Unknown");
-    ui.horizontal(|ui| {
-        ui.label("Target Zone:");
-        let old_value = target_zone.clone();
-        if ui.text_edit_singleline(&mut target_zone).changed() {
+        let old_warp_id = warp_object.warp_id.get();
+        let mut warp_id = old_warp_id;
+        if ui.add(egui::DragValue::new(&mut warp_id)).changed() && warp_id != old_warp_id {
             event_writer.write(PropertyChangeEvent::WarpObjectChanged {
                 entity,
-                property_name: "target_zone".to_string(),
-                old_value,
-                new_value: target_zone,
+                property_name: "warp_id".to_string(),
+                old_value: old_warp_id.to_string(),
+                new_value: warp_id.to_string(),
             });
         }
     });
+    
+    ui.add_space(4.0);
+    ui.label(egui::RichText::new("Destination:").strong());
+    
+    let game_data = entity_data.game_data.as_deref();
+    let destination = entity_data.warp_destinations.get(entity).ok();
+    let old_value = destination.map(WarpDestination::to_ifo_name).unwrap_or_default();
+    let mut new_destination = destination.copied();
+    
+    match new_destination.as_mut() {
+        None => {
+            ui.colored_label(egui::Color32::from_rgb(255, 80, 80), "Unlinked");
+            if ui.button("Link to Current Zone").clicked() {
+                // Start next to the warp so the new link is easy to find
+                let zone_id = entity_data.current_zone.as_ref().map_or(1, |zone| zone.id.get());
+                let position = entity_data
+                    .global_transforms
+                    .get(entity)
+                    .map_or(Vec3::ZERO, |transform| transform.translation());
+                new_destination = Some(WarpDestination {
+                    zone_id,
+                    position: position + Vec3::X * 5.0,
+                });
+            }
+        }
+        Some(destination) => {
+            let zone_label = |zone_id: u16| match game_data.and_then(|game_data| zone_name(game_data, zone_id)) {
+                Some(name) => format!("{} - {}", zone_id, name),
+                None => format!("{} (missing)", zone_id),
+            };
+            
+            ui.horizontal(|ui| {
+                ui.label("Zone:");
+                egui::ComboBox::from_id_salt(("warp_destination_zone", entity))
+                    .selected_text(zone_label(destination.zone_id))
+                    .show_ui(ui, |ui| {
+                        for zone_data in game_data.iter().flat_map(|game_data| game_data.zone_list.iter()) {
+                            let zone_id = zone_data.id.get();
+                            ui.selectable_value(
+                                &mut destination.zone_id,
+                                zone_id,
+                                format!("{} - {}", zone_id, zone_data.name),
+                            );
+                        }
+                    });
+            });
+            
+            if game_data.is_some_and(|game_data| zone_name(game_data, destination.zone_id).is_none()) {
+                ui.colored_label(
+                    egui::Color32::from_rgb(255, 80, 80),
+                    format!("Zone {} is not in the zone list", destination.zone_id),
+                );
+            }
+            
+            ui.horizontal(|ui| {
+                ui.label("Position:");
+                ui.add(egui::DragValue::new(&mut destination.position.x).speed(0.5).prefix("X: "));
+                ui.add(egui::DragValue::new(&mut destination.position.y).speed(0.5).prefix("Y: "));
+                ui.add(egui::DragValue::new(&mut destination.position.z).speed(0.5).prefix("Z: "));
+            });
+            
+            if ui.button("Unlink").clicked() {
+                new_destination = None;
+            }
+        }
+    }
+    
+    let new_value = new_destination
+        .as_ref()
+        .map(WarpDestination::to_ifo_name)
+        .unwrap_or_default();
+    if new_value != old_value {
+        event_writer.write(PropertyChangeEvent::WarpObjectChanged {
+            entity,
+            property_name: "destination".to_string(),
+            old_value,
+            new_value,
+        });
+    }
 }
 
 /// Check if entity has collision data
@@ -0,0 +1,62 @@
+//! Recovery Window for the Map Editor
+//!
+//! Shown when the opened zone has an autosave recovery snapshot that is
+//! newer than its last save, offering to restore or discard it.
+
+use bevy::prelude::{MessageWriter, ResMut};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::map_editor::resources::{MapEditorState, RecoveryEvent};
+
+/// System to render the recovery prompt
+pub fn recovery_window_system(
+    mut contexts: EguiContexts,
+    mut map_editor_state: ResMut<MapEditorState>,
+    mut recovery_events: MessageWriter<RecoveryEvent>,
+) {
+    if !map_editor_state.enabled {
+        return;
+    }
+    let Some(snapshot) = map_editor_state.autosave.recovery_prompt.clone() else {
+        return;
+    };
+
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let mut keep_open = true;
+    egui::Window::new("Recover Unsaved Changes")
+        .resizable(false)
+        .collapsible(false)
+        .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+        .show(ctx, |ui| {
+            ui.label(format!(
+                "An autosave of zone {} from {} is newer than the last save.",
+                snapshot.zone_id, snapshot.timestamp
+            ));
+            ui.label("Restoring replaces the zone's IFO files with the autosave and reloads the zone.");
+            ui.label("The current files are backed up first.");
+            ui.separator();
+
+            ui.horizontal(|ui| {
+                if ui.button("Restore").clicked() {
+                    log::info!("[MapEditor] Restoring recovery snapshot {}", snapshot.timestamp);
+                    recovery_events.write(RecoveryEvent::Restore(snapshot.clone()));
+                    keep_open = false;
+                }
+                if ui.button("Discard").clicked() {
+                    log::info!("[MapEditor] Discarding recovery snapshots of zone {}", snapshot.zone_id);
+                    recovery_events.write(RecoveryEvent::Discard(snapshot.clone()));
+                    keep_open = false;
+                }
+                if ui.button("Later").on_hover_text("Keep the autosave and continue editing").clicked() {
+                    keep_open = false;
+                }
+            });
+        });
+
+    if !keep_open {
+        map_editor_state.autosave.recovery_prompt = None;
+    }
+}
@@ -128,6 +128,8 @@ pub fn editor_status_bar(
                             EditorMode::Scale,
                             EditorMode::Add,
                             EditorMode::Delete,
+                            EditorMode::Measure,
+                            EditorMode::Sculpt,
                         ];
                         
                         for mode in modes {
@@ -147,6 +149,48 @@ pub fn editor_status_bar(
                 
                 ui.separator();
                 
+                // Measuring tool results
+                if current_mode == EditorMode::Measure {
+                    let measure_tool = &mut map_editor_state.measure_tool;
+                    ui.checkbox(&mut measure_tool.path_mode, "Path");
+                    
+                    if let Some(measurement) = measure_tool.measurement() {
+                        ui.label(
+                            egui::RichText::new(format!(
+                                "Distance: {:.2} m  Horizontal: {:.2} m  Height: {:+.2} m",
+                                measurement.distance, measurement.horizontal, measurement.height
+                            ))
+                            .color(egui::Color32::YELLOW),
+                        );
+                    } else if measure_tool.points.is_empty() {
+                        ui.label("Click the terrain to start measuring");
+                    } else {
+                        ui.label("Click the terrain to place the next point");
+                    }
+                    ui.separator();
+                }
+                
+                // Terrain brush settings
+                if current_mode == EditorMode::Sculpt {
+                    let terrain_brush = &mut map_editor_state.terrain_brush;
+                    ui.label("Radius:");
+                    ui.add(
+                        egui::DragValue::new(&mut terrain_brush.radius)
+                            .range(2.5..=80.0)
+                            .speed(0.5)
+                            .suffix(" m"),
+                    );
+                    ui.label("Strength:");
+                    ui.add(
+                        egui::DragValue::new(&mut terrain_brush.strength)
+                            .range(0.1..=20.0)
+                            .speed(0.1)
+                            .suffix(" m/s"),
+                    );
+                    ui.label("Drag to raise, Shift+drag to lower");
+                    ui.separator();
+                }
+                
                 // Grid status
                 if map_editor_state.show_grid {
                     ui.label(format!("Grid: {:.1}", map_editor_state.grid_size));
@@ -0,0 +1,85 @@
+//! Unsaved Changes Dialog for the Map Editor
+//!
+//! Modal asking to Save, Discard or Cancel before New Zone, opening another
+//! zone or exiting the editor would lose unsaved edits. The chosen operation
+//! is applied by `unsaved_changes_system`.
+
+use bevy::prelude::{MessageWriter, Res, ResMut};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::map_editor::resources::{DiscardStep, MapEditorState};
+use crate::map_editor::save::SaveZoneEvent;
+use crate::resources::CurrentZone;
+
+/// System to render the unsaved changes dialog
+pub fn unsaved_changes_dialog_system(
+    mut contexts: EguiContexts,
+    mut map_editor_state: ResMut<MapEditorState>,
+    current_zone: Option<Res<CurrentZone>>,
+    mut save_events: MessageWriter<SaveZoneEvent>,
+) {
+    if !map_editor_state.enabled || !map_editor_state.is_modified {
+        return;
+    }
+    let Some(pending) = map_editor_state.pending_discard.as_ref() else {
+        return;
+    };
+    if pending.step != DiscardStep::Asking {
+        return;
+    }
+    let action = pending.action;
+    let error = pending.error.clone();
+
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let current_zone_id = current_zone.map(|current_zone| current_zone.id.get());
+    let mut choice = None;
+    let mut cancelled = false;
+
+    let modal = egui::Modal::new(egui::Id::new("unsaved_changes_dialog")).show(ctx, |ui| {
+        ui.heading("Unsaved Changes");
+        ui.label(format!(
+            "The zone has unsaved changes. Save them before {}?",
+            action.display_name()
+        ));
+        if let Some(ref error) = error {
+            ui.label(egui::RichText::new(error).color(egui::Color32::RED));
+        }
+        ui.separator();
+
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(current_zone_id.is_some(), egui::Button::new("Save"))
+                .clicked()
+            {
+                choice = Some(DiscardStep::Saving);
+            }
+            if ui.button("Discard").clicked() {
+                choice = Some(DiscardStep::Discard);
+            }
+            if ui.button("Cancel").clicked() {
+                cancelled = true;
+            }
+        });
+    });
+
+    if let Some(step) = choice {
+        if step == DiscardStep::Saving {
+            if let Some(zone_id) = current_zone_id {
+                log::info!("[MapEditor] Saving zone {} before {}", zone_id, action.display_name());
+                save_events.write(SaveZoneEvent::new(zone_id));
+            }
+        } else {
+            log::info!("[MapEditor] Discarding unsaved changes before {}", action.display_name());
+        }
+        if let Some(pending) = map_editor_state.pending_discard.as_mut() {
+            pending.step = step;
+            pending.error = None;
+        }
+    } else if cancelled || modal.should_close() {
+        log::info!("[MapEditor] Cancelled {}", action.display_name());
+        map_editor_state.pending_discard = None;
+    }
+}
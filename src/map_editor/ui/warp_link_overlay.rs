@@ -0,0 +1,75 @@
+//! Warp Link Overlay for the Map Editor
+//!
+//! Labels each warp gate with the zone it leads to when View > Warp Links is
+//! enabled. Broken warps are labelled in red.
+
+use bevy::prelude::{Camera, Camera3d, GlobalTransform, Query, Res, Vec3, With};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::components::{WarpDestination, WarpObject};
+use crate::map_editor::resources::MapEditorState;
+use crate::map_editor::systems::warp_link_system::{zone_name, WarpLinkStatus, WARP_MARKER_HEIGHT};
+use crate::resources::{CurrentZone, GameData};
+
+/// System to draw world-space labels for warp destinations
+pub fn warp_link_overlay_system(
+    mut contexts: EguiContexts,
+    map_editor_state: Res<MapEditorState>,
+    game_data: Option<Res<GameData>>,
+    current_zone: Option<Res<CurrentZone>>,
+    query_camera: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    query_warps: Query<(&GlobalTransform, &WarpObject, Option<&WarpDestination>)>,
+) {
+    if !map_editor_state.enabled || !map_editor_state.show_warp_links {
+        return;
+    }
+
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+    let Ok((camera, camera_transform)) = query_camera.single() else {
+        return;
+    };
+
+    let painter = ctx.layer_painter(egui::LayerId::new(
+        egui::Order::Background,
+        egui::Id::new("warp_link_overlay"),
+    ));
+    let font = egui::FontId::proportional(14.0);
+
+    let current_zone_id = current_zone.map(|current_zone| current_zone.id.get());
+    let name_of = |zone_id| game_data.as_ref().and_then(|game_data| zone_name(game_data, zone_id));
+    let zone_exists = |zone_id| game_data.is_none() || name_of(zone_id).is_some();
+
+    for (global_transform, warp_object, destination) in query_warps.iter() {
+        let status = WarpLinkStatus::of(destination, current_zone_id, zone_exists);
+        let text = match (status, destination) {
+            (WarpLinkStatus::Unlinked, _) | (_, None) => "Unlinked".to_string(),
+            (WarpLinkStatus::MissingZone, Some(destination)) => {
+                format!("→ Zone {} (missing)", destination.zone_id)
+            }
+            (_, Some(destination)) => format!(
+                "→ {} ({})",
+                name_of(destination.zone_id).unwrap_or("Unknown"),
+                destination.zone_id
+            ),
+        };
+        let text = format!("Warp {}: {}", warp_object.warp_id.get(), text);
+        let color = if status.is_broken() {
+            egui::Color32::from_rgb(255, 80, 80)
+        } else {
+            egui::Color32::WHITE
+        };
+
+        let position = global_transform.translation() + Vec3::Y * WARP_MARKER_HEIGHT;
+        let Ok(screen) = camera.world_to_viewport(camera_transform, position) else {
+            continue;
+        };
+        let galley = painter.layout_no_wrap(text, font.clone(), color);
+        let rect = egui::Align2::CENTER_BOTTOM
+            .anchor_size(egui::pos2(screen.x, screen.y), galley.size())
+            .expand(3.0);
+        painter.rect_filled(rect, 3.0, egui::Color32::from_black_alpha(180));
+        painter.galley(rect.shrink(3.0).min, galley, color);
+    }
+}
@@ -3,14 +3,14 @@
 //! Provides a panel for switching between zones in the map editor.
 //! Based on the zone viewer's ui_debug_zone_list_system.rs
 
-use bevy::prelude::{MessageWriter, Res, ResMut, Resource};
+use bevy::prelude::{Res, ResMut, Resource};
 use bevy_egui::{egui, EguiContexts};
 use regex::Regex;
 
 use rose_data::ZoneId;
 
 use crate::{
-    events::LoadZoneEvent,
+    map_editor::resources::{DiscardAction, MapEditorState},
     resources::{GameData, CurrentZone},
 };
 
@@ -57,7 +57,7 @@ pub fn editor_zone_list_panel(
     state: &mut ZoneListPanelState,
     game_data: &GameData,
     current_zone: Option<&CurrentZone>,
-    load_zone_events: &mut MessageWriter<LoadZoneEvent>,
+    map_editor_state: &mut MapEditorState,
 ) {
     if !state.is_open {
         return;
@@ -168,8 +168,9 @@ pub fn editor_zone_list_panel(
                                                 zone_data.name,
                                                 state.despawn_other_zones
                                             );
-                                            load_zone_events.write(LoadZoneEvent {
-                                                id: zone_data.id,
+                                            // Asks to save unsaved changes before loading
+                                            map_editor_state.request_discard(DiscardAction::LoadZone {
+                                                zone_id: zone_data.id,
                                                 despawn_other_zones: state.despawn_other_zones,
                                             });
                                             state.is_open = false;
@@ -231,8 +232,7 @@ pub fn zone_list_panel_system(
     mut state: ResMut<ZoneListPanelState>,
     game_data: Res<GameData>,
     current_zone: Option<Res<CurrentZone>>,
-    mut load_zone_events: MessageWriter<LoadZoneEvent>,
-    map_editor_state: Res<crate::map_editor::resources::MapEditorState>,
+    mut map_editor_state: ResMut<MapEditorState>,
 ) {
     // Only show when map editor is enabled
     if !map_editor_state.enabled {
@@ -244,6 +244,6 @@ pub fn zone_list_panel_system(
         &mut state,
         &game_data,
         current_zone.as_deref(),
-        &mut load_zone_events,
+        &mut map_editor_state,
     );
 }
@@ -0,0 +1,117 @@
+//! Zone Validation Panel for the Map Editor
+//!
+//! Lists the object counts of the current zone and the issues found by the
+//! last scan. Clicking an issue selects the object and focuses the camera
+//! on it. The zone is only scanned when Refresh is clicked.
+
+use bevy::prelude::{Commands, Entity, Query, ResMut, With};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::map_editor::components::SelectedInEditor;
+use crate::map_editor::resources::MapEditorState;
+
+/// System to render the zone validation panel
+pub fn zone_validation_panel_system(
+    mut commands: Commands,
+    mut contexts: EguiContexts,
+    mut map_editor_state: ResMut<MapEditorState>,
+    query_selected: Query<Entity, With<SelectedInEditor>>,
+) {
+    if !map_editor_state.enabled || !map_editor_state.zone_validation.is_open {
+        return;
+    }
+
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let mut is_open = map_editor_state.zone_validation.is_open;
+    let mut clicked_entity = None;
+
+    egui::Window::new("Zone Validation")
+        .open(&mut is_open)
+        .default_width(360.0)
+        .show(ctx, |ui| {
+            let validation = &mut map_editor_state.zone_validation;
+
+            ui.horizontal(|ui| {
+                if ui.button("🔄 Refresh").clicked() {
+                    validation.pending_scan = true;
+                }
+                ui.label("Terrain tolerance:");
+                ui.add(
+                    egui::DragValue::new(&mut validation.terrain_tolerance)
+                        .speed(0.5)
+                        .range(0.0..=1000.0)
+                        .suffix(" m"),
+                )
+                .on_hover_text("Objects further than this above or below the terrain are reported");
+            });
+
+            ui.separator();
+
+            let Some(ref report) = validation.report else {
+                ui.label("Click Refresh to scan the current zone.");
+                return;
+            };
+
+            egui::CollapsingHeader::new("Objects")
+                .default_open(true)
+                .show(ui, |ui| {
+                    egui::Grid::new("zone_validation_counts")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            for (object_type, count) in report.object_counts.iter() {
+                                ui.label(object_type.display_name());
+                                ui.label(count.to_string());
+                                ui.end_row();
+                            }
+                        });
+                });
+
+            ui.separator();
+
+            if report.issues.is_empty() {
+                ui.label(egui::RichText::new("✓ No issues found").color(egui::Color32::GREEN));
+                return;
+            }
+
+            ui.label(
+                egui::RichText::new(format!("⚠ {} issues", report.issues.len()))
+                    .color(egui::Color32::YELLOW),
+            );
+            egui::ScrollArea::vertical().max_height(300.0).show(ui, |ui| {
+                for issue in report.issues.iter() {
+                    let text = format!("[{}] {}", issue.kind.display_name(), issue.message);
+                    if ui
+                        .selectable_label(false, text)
+                        .on_hover_text("Select and focus this object")
+                        .clicked()
+                    {
+                        clicked_entity = Some(issue.entity);
+                    }
+                }
+            });
+        });
+
+    map_editor_state.zone_validation.is_open = is_open;
+
+    if let Some(entity) = clicked_entity {
+        // The object may have been deleted since the last scan
+        let Ok(mut entity_commands) = commands.get_entity(entity) else {
+            log::warn!("[ZoneValidation] Object {:?} no longer exists, refresh the scan", entity);
+            return;
+        };
+        entity_commands.insert(SelectedInEditor);
+
+        for selected_entity in query_selected.iter() {
+            if selected_entity != entity {
+                commands.entity(selected_entity).remove::<SelectedInEditor>();
+            }
+        }
+        map_editor_state.clear_selection();
+        map_editor_state.select_entity(entity);
+        map_editor_state.pending_focus = Some(entity);
+        log::info!("[ZoneValidation] Selected entity: {:?}", entity);
+    }
+}
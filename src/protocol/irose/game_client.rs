@@ -67,13 +67,14 @@ use rose_network_irose::{
     ClientPacketCodec, IROSE_112_TABLE,
 };
 
-use crate::protocol::{ProtocolClient, ProtocolClientError};
+use crate::protocol::{ProtocolClient, ProtocolClientError, ProtocolStats};
 
 pub struct GameClient {
     server_address: SocketAddr,
     client_message_rx: tokio::sync::mpsc::UnboundedReceiver<ClientMessage>,
     server_message_tx: crossbeam_channel::Sender<ServerMessage>,
     packet_codec: Box<dyn PacketCodec + Send + Sync>,
+    stats: ProtocolStats,
 }
 
 impl GameClient {
@@ -82,12 +83,14 @@ impl GameClient {
         packet_codec_seed: u32,
         client_message_rx: tokio::sync::mpsc::UnboundedReceiver<ClientMessage>,
         server_message_tx: crossbeam_channel::Sender<ServerMessage>,
+        stats: ProtocolStats,
     ) -> Self {
         Self {
             server_address,
             client_message_rx,
             server_message_tx,
             packet_codec: Box::new(ClientPacketCodec::init(&IROSE_112_TABLE, packet_codec_seed)),
+            stats,
         }
     }
 
@@ -1164,16 +1167,16 @@ impl GameClient {
                 login_token,
                 ref password,
             } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientConnectRequest {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientConnectRequest {
                         login_token,
                         password_md5: &password.to_md5(),
                     }))
                     .await?
             }
             ClientMessage::JoinZoneRequest => {
-                connection
-                    .write_packet(Packet::from(&PacketClientJoinZone {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientJoinZone {
                         weight_rate: 0,
                         z: 0,
                     }))
@@ -1185,8 +1188,8 @@ impl GameClient {
                 y,
                 z,
             } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientMove {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientMove {
                         target_entity_id,
                         x,
                         y,
@@ -1195,28 +1198,28 @@ impl GameClient {
                     .await?
             }
             ClientMessage::Attack { target_entity_id } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientAttack { target_entity_id }))
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientAttack { target_entity_id }))
                     .await?
             }
             ClientMessage::PickupItemDrop { target_entity_id } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientPickupItemDrop {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientPickupItemDrop {
                         target_entity_id,
                     }))
                     .await?
             }
             ClientMessage::Chat { ref text } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientChat { text }))
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientChat { text }))
                     .await?
             }
             ClientMessage::ChangeAmmo {
                 ammo_index,
                 item_slot,
             } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientChangeAmmo {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientChangeAmmo {
                         ammo_index,
                         item_slot,
                     }))
@@ -1226,8 +1229,8 @@ impl GameClient {
                 equipment_index,
                 item_slot,
             } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientChangeEquipment {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientChangeEquipment {
                         equipment_index,
                         item_slot,
                     }))
@@ -1237,16 +1240,16 @@ impl GameClient {
                 vehicle_part_index,
                 item_slot,
             } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientChangeVehiclePart {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientChangeVehiclePart {
                         vehicle_part_index,
                         item_slot,
                     }))
                     .await?
             }
             ClientMessage::QuestDelete { slot, quest_id } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientQuestRequest {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientQuestRequest {
                         request_type: PacketClientQuestRequestType::DeleteQuest,
                         quest_slot: slot as u8,
                         quest_id: quest_id as u32,
@@ -1254,8 +1257,8 @@ impl GameClient {
                     .await?
             }
             ClientMessage::QuestTrigger { trigger } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientQuestRequest {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientQuestRequest {
                         request_type: PacketClientQuestRequestType::DoTrigger,
                         quest_slot: 0,
                         quest_id: trigger.hash,
@@ -1263,33 +1266,33 @@ impl GameClient {
                     .await?
             }
             ClientMessage::SetHotbarSlot { slot_index, slot } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientSetHotbarSlot {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientSetHotbarSlot {
                         slot_index,
                         slot,
                     }))
                     .await?
             }
             ClientMessage::IncreaseBasicStat { basic_stat_type } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientIncreaseBasicStat {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientIncreaseBasicStat {
                         basic_stat_type,
                     }))
                     .await?
             }
             ClientMessage::ReviveCurrentZone => {
-                connection
-                    .write_packet(Packet::from(&PacketClientReviveRequest::CurrentZone))
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientReviveRequest::CurrentZone))
                     .await?
             }
             ClientMessage::ReviveSaveZone => {
-                connection
-                    .write_packet(Packet::from(&PacketClientReviveRequest::SaveZone))
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientReviveRequest::SaveZone))
                     .await?
             }
             ClientMessage::PersonalStoreListItems { store_entity_id } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientPersonalStoreListItems {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientPersonalStoreListItems {
                         target_entity_id: store_entity_id,
                     }))
                     .await?
@@ -1298,16 +1301,16 @@ impl GameClient {
                 item_slot,
                 quantity,
             } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientDropItemFromInventory::Item(
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientDropItemFromInventory::Item(
                         item_slot,
                         quantity as u32,
                     )))
                     .await?
             }
             ClientMessage::DropMoney { quantity } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientDropItemFromInventory::Money(
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientDropItemFromInventory::Money(
                         quantity as u32,
                     )))
                     .await?
@@ -1316,42 +1319,42 @@ impl GameClient {
                 item_slot,
                 target_entity_id,
             } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientUseItem {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientUseItem {
                         item_slot,
                         target_entity_id,
                     }))
                     .await?
             }
             ClientMessage::WarpGateRequest { warp_gate_id } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientWarpGateRequest { warp_gate_id }))
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientWarpGateRequest { warp_gate_id }))
                     .await?
             }
             ClientMessage::LevelUpSkill { skill_slot } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientLevelUpSkill {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientLevelUpSkill {
                         skill_slot,
                         next_skill_idx: SkillId::new(0).unwrap(), // 0 means server will use current_skill_idx + 1
                     }))
                     .await?
             }
             ClientMessage::UseEmote { motion_id, is_stop } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientEmote { motion_id, is_stop }))
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientEmote { motion_id, is_stop }))
                     .await?
             }
             ClientMessage::CastSkillSelf { skill_slot } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientCastSkillSelf { skill_slot }))
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientCastSkillSelf { skill_slot }))
                     .await?
             }
             ClientMessage::CastSkillTargetEntity {
                 skill_slot,
                 target_entity_id,
             } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientCastSkillTargetEntity {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientCastSkillTargetEntity {
                         skill_slot,
                         target_entity_id,
                     }))
@@ -1361,30 +1364,30 @@ impl GameClient {
                 skill_slot,
                 position,
             } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientCastSkillTargetPosition {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientCastSkillTargetPosition {
                         skill_slot,
                         position,
                     }))
                     .await?
             }
             ClientMessage::RunToggle => {
-                connection
-                    .write_packet(Packet::from(&PacketClientMoveToggle {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientMoveToggle {
                         toggle_type: PacketClientMoveToggleType::Run,
                     }))
                     .await?
             }
             ClientMessage::SitToggle => {
-                connection
-                    .write_packet(Packet::from(&PacketClientMoveToggle {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientMoveToggle {
                         toggle_type: PacketClientMoveToggleType::Sit,
                     }))
                     .await?
             }
             ClientMessage::DriveToggle => {
-                connection
-                    .write_packet(Packet::from(&PacketClientMoveToggle {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientMoveToggle {
                         toggle_type: PacketClientMoveToggleType::Drive,
                     }))
                     .await?
@@ -1394,8 +1397,8 @@ impl GameClient {
                 buy_items,
                 sell_items,
             } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientNpcStoreTransaction {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientNpcStoreTransaction {
                         npc_entity_id,
                         buy_items,
                         sell_items,
@@ -1403,48 +1406,48 @@ impl GameClient {
                     .await?
             }
             ClientMessage::PartyCreate { invited_entity_id } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientPartyRequest::Create(
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientPartyRequest::Create(
                         invited_entity_id,
                     )))
                     .await?
             }
             ClientMessage::PartyInvite { invited_entity_id } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientPartyRequest::Invite(
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientPartyRequest::Invite(
                         invited_entity_id,
                     )))
                     .await?
             }
             ClientMessage::PartyLeave => {
-                connection
-                    .write_packet(Packet::from(&PacketClientPartyRequest::Leave))
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientPartyRequest::Leave))
                     .await?
             }
             ClientMessage::PartyChangeOwner {
                 new_owner_entity_id,
             } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientPartyRequest::ChangeOwner(
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientPartyRequest::ChangeOwner(
                         new_owner_entity_id,
                     )))
                     .await?
             }
             ClientMessage::PartyKick { character_id } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientPartyRequest::Kick(character_id)))
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientPartyRequest::Kick(character_id)))
                     .await?
             }
             ClientMessage::PartyAcceptCreateInvite { owner_entity_id } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientPartyReply::AcceptCreate(
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientPartyReply::AcceptCreate(
                         owner_entity_id,
                     )))
                     .await?
             }
             ClientMessage::PartyAcceptJoinInvite { owner_entity_id } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientPartyReply::AcceptJoin(
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientPartyReply::AcceptJoin(
                         owner_entity_id,
                     )))
                     .await?
@@ -1453,8 +1456,8 @@ impl GameClient {
                 reason,
                 owner_entity_id,
             } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientPartyReply::Reject(
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientPartyReply::Reject(
                         reason,
                         owner_entity_id,
                     )))
@@ -1464,16 +1467,16 @@ impl GameClient {
                 item_sharing,
                 xp_sharing,
             } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientPartyUpdateRules {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientPartyUpdateRules {
                         item_sharing,
                         xp_sharing,
                     }))
                     .await?
             }
             ClientMessage::MoveCollision { position } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientMoveCollision { position }))
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientMoveCollision { position }))
                     .await?
             }
             ClientMessage::PersonalStoreBuyItem {
@@ -1481,8 +1484,8 @@ impl GameClient {
                 store_slot_index,
                 buy_item,
             } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientPersonalStoreBuyItem {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientPersonalStoreBuyItem {
                         store_entity_id,
                         store_slot_index,
                         buy_item,
@@ -1490,8 +1493,8 @@ impl GameClient {
                     .await?
             }
             ClientMessage::BankOpen => {
-                connection
-                    .write_packet(Packet::from(&PacketClientBankOpen {}))
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientBankOpen {}))
                     .await?
             }
             ClientMessage::BankDepositItem {
@@ -1499,8 +1502,8 @@ impl GameClient {
                 item,
                 is_premium,
             } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientBankMoveItem::Deposit {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientBankMoveItem::Deposit {
                         item_slot,
                         item,
                         is_premium,
@@ -1512,8 +1515,8 @@ impl GameClient {
                 item,
                 is_premium,
             } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientBankMoveItem::Withdraw {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientBankMoveItem::Withdraw {
                         bank_slot,
                         item,
                         is_premium,
@@ -1521,8 +1524,8 @@ impl GameClient {
                     .await?
             }
             ClientMessage::SetReviveSaveZone => {
-                connection
-                    .write_packet(Packet::from(&PacketClientSetReviveZone))
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientSetReviveZone))
                     .await?
             }
             ClientMessage::ClanCreate {
@@ -1530,8 +1533,8 @@ impl GameClient {
                 description,
                 mark,
             } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientClanCommand::Create {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientClanCommand::Create {
                         name,
                         description,
                         mark,
@@ -1542,8 +1545,8 @@ impl GameClient {
                 equipment_index,
                 item_slot,
             } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientCraftItem::InsertGem {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientCraftItem::InsertGem {
                         equipment_index,
                         item_slot,
                     }))
@@ -1553,8 +1556,8 @@ impl GameClient {
                 skill_slot,
                 item_slot,
             } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientCraftItem::SkillDisassemble {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientCraftItem::SkillDisassemble {
                         skill_slot,
                         item_slot,
                     }))
@@ -1564,8 +1567,8 @@ impl GameClient {
                 npc_entity_id,
                 item_slot,
             } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientCraftItem::NpcDisassemble {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientCraftItem::NpcDisassemble {
                         npc_entity_id,
                         item_slot,
                     }))
@@ -1576,8 +1579,8 @@ impl GameClient {
                 item_slot,
                 ingredients,
             } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientCraftItem::SkillUpgradeItem {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientCraftItem::SkillUpgradeItem {
                         skill_slot,
                         item_slot,
                         ingredients,
@@ -1589,8 +1592,8 @@ impl GameClient {
                 item_slot,
                 ingredients,
             } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientCraftItem::NpcUpgradeItem {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientCraftItem::NpcUpgradeItem {
                         npc_entity_id,
                         item_slot,
                         ingredients,
@@ -1601,8 +1604,8 @@ impl GameClient {
                 use_item_slot,
                 item_slot,
             } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientRepairItemUsingItem {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientRepairItemUsingItem {
                         use_item_slot,
                         item_slot,
                     }))
@@ -1612,8 +1615,8 @@ impl GameClient {
                 npc_entity_id,
                 item_slot,
             } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientRepairItemUsingNpc {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientRepairItemUsingNpc {
                         npc_entity_id,
                         item_slot,
                     }))
@@ -0,0 +1,69 @@
+use std::net::SocketAddr;
+
+use crate::{
+    events::NetworkEvent,
+    protocol::{
+        irose::{GameClient, LoginClient, WorldClient},
+        NetworkBackend, ProtocolClient, ProtocolClientChannels,
+    },
+};
+
+/// The default backend, speaking the iROSE 112 protocol
+#[derive(Default)]
+pub struct IroseNetworkBackend;
+
+impl NetworkBackend for IroseNetworkBackend {
+    fn connect(
+        &self,
+        event: &NetworkEvent,
+        channels: ProtocolClientChannels,
+    ) -> Result<Box<dyn ProtocolClient + Send + Sync>, anyhow::Error> {
+        let ProtocolClientChannels {
+            client_message_rx,
+            server_message_tx,
+            stats,
+        } = channels;
+
+        Ok(match *event {
+            NetworkEvent::ConnectLogin { ref ip, port } => {
+                let server_address: SocketAddr = format!("{}:{}", ip, port).parse()?;
+                Box::new(LoginClient::new(
+                    server_address,
+                    client_message_rx,
+                    server_message_tx,
+                    stats,
+                ))
+            }
+            NetworkEvent::ConnectWorld {
+                ref ip,
+                port,
+                packet_codec_seed,
+                ..
+            } => {
+                let server_address: SocketAddr = format!("{}:{}", ip, port).parse()?;
+                Box::new(WorldClient::new(
+                    server_address,
+                    packet_codec_seed,
+                    client_message_rx,
+                    server_message_tx,
+                    stats,
+                ))
+            }
+            NetworkEvent::ConnectGame {
+                ref ip,
+                port,
+                packet_codec_seed,
+                ..
+            } => {
+                let server_address: SocketAddr = format!("{}:{}", ip, port).parse()?;
+                Box::new(GameClient::new(
+                    server_address,
+                    packet_codec_seed,
+                    client_message_rx,
+                    server_message_tx,
+                    stats,
+                ))
+            }
+        })
+    }
+}
@@ -22,13 +22,14 @@ use rose_network_irose::{
     ClientPacketCodec, IROSE_112_TABLE,
 };
 
-use crate::protocol::{ProtocolClient, ProtocolClientError};
+use crate::protocol::{ProtocolClient, ProtocolClientError, ProtocolStats};
 
 pub struct LoginClient {
     server_address: SocketAddr,
     client_message_rx: tokio::sync::mpsc::UnboundedReceiver<ClientMessage>,
     server_message_tx: crossbeam_channel::Sender<ServerMessage>,
     packet_codec: Box<dyn PacketCodec + Send + Sync>,
+    stats: ProtocolStats,
 }
 
 impl LoginClient {
@@ -36,12 +37,14 @@ impl LoginClient {
         server_address: SocketAddr,
         client_message_rx: tokio::sync::mpsc::UnboundedReceiver<ClientMessage>,
         server_message_tx: crossbeam_channel::Sender<ServerMessage>,
+        stats: ProtocolStats,
     ) -> Self {
         Self {
             server_address,
             client_message_rx,
             server_message_tx,
             packet_codec: Box::new(ClientPacketCodec::default(&IROSE_112_TABLE)),
+            stats,
         }
     }
 
@@ -134,29 +137,29 @@ impl LoginClient {
     ) -> Result<(), anyhow::Error> {
         match message {
             ClientMessage::ConnectionRequest { .. } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientConnect {}))
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientConnect {}))
                     .await?
             }
             ClientMessage::LoginRequest { username, password } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientLoginRequest {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientLoginRequest {
                         username: &username,
                         password_md5: &password.to_md5(),
                     }))
                     .await?
             }
             ClientMessage::GetChannelList { server_id } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientChannelList { server_id }))
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientChannelList { server_id }))
                     .await?
             }
             ClientMessage::JoinServer {
                 server_id,
                 channel_id,
             } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientSelectServer {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientSelectServer {
                         server_id,
                         channel_id,
                     }))
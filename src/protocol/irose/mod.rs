@@ -1,7 +1,9 @@
 mod game_client;
+mod irose_network_backend;
 mod login_client;
 mod world_client;
 
 pub use game_client::GameClient;
+pub use irose_network_backend::IroseNetworkBackend;
 pub use login_client::LoginClient;
 pub use world_client::WorldClient;
@@ -24,13 +24,14 @@ use rose_network_irose::{
     ClientPacketCodec, IROSE_112_TABLE,
 };
 
-use crate::protocol::{ProtocolClient, ProtocolClientError};
+use crate::protocol::{ProtocolClient, ProtocolClientError, ProtocolStats};
 
 pub struct WorldClient {
     server_address: SocketAddr,
     client_message_rx: tokio::sync::mpsc::UnboundedReceiver<ClientMessage>,
     server_message_tx: crossbeam_channel::Sender<ServerMessage>,
     packet_codec: Box<dyn PacketCodec + Send + Sync>,
+    stats: ProtocolStats,
 }
 
 impl WorldClient {
@@ -39,12 +40,14 @@ impl WorldClient {
         packet_codec_seed: u32,
         client_message_rx: tokio::sync::mpsc::UnboundedReceiver<ClientMessage>,
         server_message_tx: crossbeam_channel::Sender<ServerMessage>,
+        stats: ProtocolStats,
     ) -> Self {
         Self {
             server_address,
             client_message_rx,
             server_message_tx,
             packet_codec: Box::new(ClientPacketCodec::init(&IROSE_112_TABLE, packet_codec_seed)),
+            stats,
         }
     }
 
@@ -137,21 +140,21 @@ impl WorldClient {
                 login_token,
                 ref password,
             } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientConnectRequest {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientConnectRequest {
                         login_token,
                         password_md5: &password.to_md5(),
                     }))
                     .await?
             }
             ClientMessage::GetCharacterList => {
-                connection
-                    .write_packet(Packet::from(&PacketClientCharacterList {}))
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientCharacterList {}))
                     .await?
             }
             ClientMessage::SelectCharacter { slot, ref name } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientSelectCharacter { slot, name }))
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientSelectCharacter { slot, name }))
                     .await?
             }
             ClientMessage::CreateCharacter {
@@ -163,8 +166,8 @@ impl WorldClient {
                 birth_stone,
                 ..
             } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientCreateCharacter {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientCreateCharacter {
                         gender,
                         birth_stone: birth_stone as u8,
                         hair: hair as u8,
@@ -179,8 +182,8 @@ impl WorldClient {
                 name,
                 is_delete,
             } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientDeleteCharacter {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientDeleteCharacter {
                         slot,
                         name: &name,
                         is_delete,
@@ -188,13 +191,13 @@ impl WorldClient {
                     .await?
             }
             ClientMessage::ClanGetMemberList => {
-                connection
-                    .write_packet(Packet::from(&PacketClientClanCommand::GetMemberList))
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientClanCommand::GetMemberList))
                     .await?
             }
             ClientMessage::ClanUpdateCharacterInfo { job, level } => {
-                connection
-                    .write_packet(Packet::from(&PacketClientClanCommand::UpdateLevelAndJob {
+                self.stats
+                    .write_packet(connection, Packet::from(&PacketClientClanCommand::UpdateLevelAndJob {
                         level,
                         job,
                     }))
@@ -18,14 +18,25 @@ macro_rules! implement_protocol_client {
         #[async_trait]
         impl ProtocolClient for $x {
             async fn run_connection(&mut self) -> Result<(), anyhow::Error> {
+                self.stats.connecting();
+                let result = self.run_connection_loop().await;
+                self.stats.disconnected();
+                result
+            }
+        }
+
+        impl $x {
+            async fn run_connection_loop(&mut self) -> Result<(), anyhow::Error> {
                 let socket = TcpStream::connect(&self.server_address).await?;
                 let mut connection = Connection::new(socket, self.packet_codec.as_ref());
+                self.stats.connected();
 
                 loop {
                     tokio::select! {
                         packet = connection.read_packet() => {
                             match packet {
                                 Ok(packet) => {
+                                    self.stats.packet_received(&packet);
                                     match self.handle_packet(&packet).await {
                                         Ok(_) => {},
                                         Err(error) => {
@@ -56,3 +67,10 @@ macro_rules! implement_protocol_client {
 }
 
 pub mod irose;
+mod network_backend;
+mod packet_logger;
+mod protocol_stats;
+
+pub use network_backend::{NetworkBackend, ProtocolClientChannels};
+pub use packet_logger::{format_hex_dump, PacketDirection, PacketLogEntry, PacketLogger};
+pub use protocol_stats::{ProtocolConnectionKind, ProtocolStats, ProtocolStatsMessage};
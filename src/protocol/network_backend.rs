@@ -0,0 +1,49 @@
+use rose_game_common::messages::{client::ClientMessage, server::ServerMessage};
+
+use crate::{
+    events::NetworkEvent,
+    protocol::{ProtocolClient, ProtocolStats},
+};
+
+/// Channels connecting a protocol client on the network thread to the game.
+pub struct ProtocolClientChannels {
+    /// Messages sent by the game, to be encoded and sent to the server
+    pub client_message_rx: tokio::sync::mpsc::UnboundedReceiver<ClientMessage>,
+    /// Messages received from the server, decoded for the game
+    pub server_message_tx: crossbeam_channel::Sender<ServerMessage>,
+    /// Traffic counters for the network debug windows
+    pub stats: ProtocolStats,
+}
+
+/// Creates protocol clients for a network version, allowing a different wire
+/// protocol to be plugged in through `SystemsConfig::network_backend`.
+///
+/// Gameplay systems never talk to a backend directly, they only see the
+/// `LoginConnection`, `WorldConnection` and `GameConnection` resources which
+/// `network_thread_system` inserts for every `NetworkEvent`. To keep those
+/// systems working a backend must satisfy the following contract:
+///
+/// - `connect` is called once per `NetworkEvent`, the returned client is run on
+///   the network thread until it returns.
+/// - Every `ClientMessage` received on `client_message_rx` must be sent to the
+///   server, unsupported messages should be logged and ignored.
+/// - Every server response must be translated into the matching `ServerMessage`
+///   and sent on `server_message_tx`, e.g. `ConnectionRequestSuccess` after the
+///   connection handshake, `LoginSuccess` / `LoginError` in reply to
+///   `LoginRequest`, `JoinServerSuccess` in reply to `JoinServer`,
+///   `CharacterList` / `SelectCharacterSuccess` on the world server and
+///   `CharacterData`, `CharacterDataItems`, `CharacterDataQuest` followed by
+///   `JoinZone` on the game server.
+/// - Returning from `run_connection` drops `server_message_tx`, which the
+///   connection systems report as a disconnect. Return an error to explain why.
+/// - `client_message_rx` closing means the game dropped the connection, the
+///   client should return `ProtocolClientError::ClientInitiatedDisconnect`.
+/// - Sending and receiving through `stats` is optional, but is required for the
+///   network debug windows to show anything.
+pub trait NetworkBackend: Send + Sync {
+    fn connect(
+        &self,
+        event: &NetworkEvent,
+        channels: ProtocolClientChannels,
+    ) -> Result<Box<dyn ProtocolClient + Send + Sync>, anyhow::Error>;
+}
@@ -0,0 +1,219 @@
+use std::{
+    collections::VecDeque,
+    fmt::Write as _,
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use rose_network_common::Packet;
+
+use crate::protocol::ProtocolConnectionKind;
+
+const BYTES_PER_DUMP_LINE: usize = 16;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PacketDirection {
+    Inbound,
+    Outbound,
+}
+
+#[derive(Clone, Debug)]
+pub struct PacketLogEntry {
+    /// Time since the logger was created
+    pub time: Duration,
+    pub connection: ProtocolConnectionKind,
+    pub direction: PacketDirection,
+    pub command: u16,
+    pub size: usize,
+    pub dump: String,
+}
+
+struct PacketLoggerState {
+    opcode_filter: Vec<u16>,
+    max_entries: usize,
+    entries: VecDeque<PacketLogEntry>,
+    file: Option<BufWriter<File>>,
+}
+
+/// Records packets passing through the network thread into a ring buffer and
+/// optionally a file.
+///
+/// Shared between the network thread and the main thread, when disabled the
+/// only cost per packet is a single atomic load.
+pub struct PacketLogger {
+    enabled: AtomicBool,
+    started: Instant,
+    state: Mutex<PacketLoggerState>,
+}
+
+impl Default for PacketLogger {
+    fn default() -> Self {
+        Self {
+            enabled: AtomicBool::new(false),
+            started: Instant::now(),
+            state: Mutex::new(PacketLoggerState {
+                opcode_filter: Vec::new(),
+                max_entries: 500,
+                entries: VecDeque::new(),
+                file: None,
+            }),
+        }
+    }
+}
+
+impl PacketLogger {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Only log packets with these commands, an empty filter logs everything
+    pub fn set_opcode_filter(&self, opcode_filter: Vec<u16>) {
+        self.state.lock().unwrap().opcode_filter = opcode_filter;
+    }
+
+    pub fn set_max_entries(&self, max_entries: usize) {
+        let mut state = self.state.lock().unwrap();
+        state.max_entries = max_entries;
+        while state.entries.len() > max_entries {
+            state.entries.pop_front();
+        }
+    }
+
+    pub fn set_log_file(&self, path: Option<&Path>) -> std::io::Result<()> {
+        let file = match path {
+            Some(path) => Some(BufWriter::new(
+                File::options().create(true).append(true).open(path)?,
+            )),
+            None => None,
+        };
+
+        let mut state = self.state.lock().unwrap();
+        if let Some(mut previous_file) = state.file.take() {
+            previous_file.flush().ok();
+        }
+        state.file = file;
+        Ok(())
+    }
+
+    pub fn entries(&self) -> Vec<PacketLogEntry> {
+        self.state.lock().unwrap().entries.iter().cloned().collect()
+    }
+
+    pub fn clear(&self) {
+        self.state.lock().unwrap().entries.clear();
+    }
+
+    pub fn log(
+        &self,
+        connection: ProtocolConnectionKind,
+        direction: PacketDirection,
+        packet: &Packet,
+    ) {
+        if !self.is_enabled() {
+            return;
+        }
+
+        let mut state = self.state.lock().unwrap();
+        if !state.opcode_filter.is_empty() && !state.opcode_filter.contains(&packet.command) {
+            return;
+        }
+
+        let entry = PacketLogEntry {
+            time: self.started.elapsed(),
+            connection,
+            direction,
+            command: packet.command,
+            size: packet.data.len(),
+            dump: format_hex_dump(&packet.data[..]),
+        };
+
+        if let Some(file) = state.file.as_mut() {
+            writeln!(
+                file,
+                "[{:.3}] {:?} {:?} [{:03X}] {} bytes\n{}",
+                entry.time.as_secs_f64(),
+                entry.connection,
+                entry.direction,
+                entry.command,
+                entry.size,
+                entry.dump
+            )
+            .ok();
+            file.flush().ok();
+        }
+
+        while state.entries.len() >= state.max_entries.max(1) {
+            state.entries.pop_front();
+        }
+        state.entries.push_back(entry);
+    }
+}
+
+/// Formats bytes as lines of `offset  hex bytes  ascii`
+pub fn format_hex_dump(data: &[u8]) -> String {
+    let mut dump = String::with_capacity(data.len() * 4 + 16);
+
+    for (line_index, line) in data.chunks(BYTES_PER_DUMP_LINE).enumerate() {
+        if line_index > 0 {
+            dump.push('\n');
+        }
+
+        write!(dump, "{:04x}  ", line_index * BYTES_PER_DUMP_LINE).ok();
+        for index in 0..BYTES_PER_DUMP_LINE {
+            match line.get(index) {
+                Some(byte) => write!(dump, "{:02x} ", byte).ok(),
+                None => write!(dump, "   ").ok(),
+            };
+        }
+
+        dump.push(' ');
+        for &byte in line {
+            dump.push(if byte.is_ascii_graphic() || byte == b' ' {
+                byte as char
+            } else {
+                '.'
+            });
+        }
+    }
+
+    dump
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_hex_dump_empty() {
+        assert_eq!(format_hex_dump(&[]), "");
+    }
+
+    #[test]
+    fn test_format_hex_dump_partial_line() {
+        assert_eq!(
+            format_hex_dump(b"AB\x00"),
+            format!("0000  41 42 00 {} AB.", " ".repeat(13 * 3))
+        );
+    }
+
+    #[test]
+    fn test_format_hex_dump_multiple_lines() {
+        let data: Vec<u8> = (0x30..0x30 + 17).collect();
+        let dump = format_hex_dump(&data);
+        let lines: Vec<&str> = dump.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("0000  30 31 32"));
+        assert!(lines[0].ends_with("0123456789:;<=>?"));
+        assert_eq!(lines[1], format!("0010  40 {} @", " ".repeat(15 * 3)));
+    }
+}
@@ -0,0 +1,146 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use rose_network_common::{Connection, Packet};
+
+use crate::protocol::{PacketDirection, PacketLogger};
+
+/// Size of the iROSE packet header which is not included in `Packet::data`
+const PACKET_HEADER_SIZE: usize = 6;
+
+/// Minimum time between two packet timing samples
+const PACKET_TIMING_SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ProtocolConnectionKind {
+    Login,
+    World,
+    Game,
+}
+
+#[derive(Clone, Debug)]
+pub enum ProtocolStatsMessage {
+    Connecting(ProtocolConnectionKind),
+    Connected(ProtocolConnectionKind),
+    Disconnected(ProtocolConnectionKind),
+    PacketSent {
+        connection: ProtocolConnectionKind,
+        bytes: usize,
+    },
+    PacketReceived {
+        connection: ProtocolConnectionKind,
+        bytes: usize,
+    },
+    PacketTiming {
+        connection: ProtocolConnectionKind,
+        elapsed: Duration,
+    },
+}
+
+#[derive(Default)]
+struct PacketTimingProbe {
+    sent_at: Option<Instant>,
+    last_sample: Option<Instant>,
+}
+
+/// Traffic counters for a single protocol connection, reported back to the
+/// main thread over `stats_tx`.
+///
+/// The iROSE protocol has no ping packet with a known reply, so no real round
+/// trip time can be measured. Instead, at most once every
+/// `PACKET_TIMING_SAMPLE_INTERVAL` the next packet we send is timed until the
+/// next packet we receive from the server. The received packet is not
+/// necessarily a reply, so this is only a rough packet timing and not a ping.
+pub struct ProtocolStats {
+    connection: ProtocolConnectionKind,
+    stats_tx: crossbeam_channel::Sender<ProtocolStatsMessage>,
+    packet_timing_probe: Mutex<PacketTimingProbe>,
+    packet_logger: Arc<PacketLogger>,
+}
+
+impl ProtocolStats {
+    pub fn new(
+        connection: ProtocolConnectionKind,
+        stats_tx: crossbeam_channel::Sender<ProtocolStatsMessage>,
+        packet_logger: Arc<PacketLogger>,
+    ) -> Self {
+        Self {
+            connection,
+            stats_tx,
+            packet_timing_probe: Mutex::new(PacketTimingProbe::default()),
+            packet_logger,
+        }
+    }
+
+    pub fn connecting(&self) {
+        self.stats_tx
+            .send(ProtocolStatsMessage::Connecting(self.connection))
+            .ok();
+    }
+
+    pub fn connected(&self) {
+        self.stats_tx
+            .send(ProtocolStatsMessage::Connected(self.connection))
+            .ok();
+    }
+
+    pub fn disconnected(&self) {
+        self.stats_tx
+            .send(ProtocolStatsMessage::Disconnected(self.connection))
+            .ok();
+    }
+
+    pub async fn write_packet(
+        &self,
+        connection: &mut Connection<'_>,
+        packet: Packet,
+    ) -> Result<(), anyhow::Error> {
+        let bytes = packet.data.len() + PACKET_HEADER_SIZE;
+        self.packet_logger
+            .log(self.connection, PacketDirection::Outbound, &packet);
+        connection.write_packet(packet).await?;
+
+        self.stats_tx
+            .send(ProtocolStatsMessage::PacketSent {
+                connection: self.connection,
+                bytes,
+            })
+            .ok();
+
+        let mut packet_timing_probe = self.packet_timing_probe.lock().unwrap();
+        if packet_timing_probe.sent_at.is_none()
+            && packet_timing_probe.last_sample.map_or(true, |last_sample| {
+                last_sample.elapsed() >= PACKET_TIMING_SAMPLE_INTERVAL
+            })
+        {
+            packet_timing_probe.sent_at = Some(Instant::now());
+        }
+
+        Ok(())
+    }
+
+    pub fn packet_received(&self, packet: &Packet) {
+        self.packet_logger
+            .log(self.connection, PacketDirection::Inbound, packet);
+
+        self.stats_tx
+            .send(ProtocolStatsMessage::PacketReceived {
+                connection: self.connection,
+                bytes: packet.data.len() + PACKET_HEADER_SIZE,
+            })
+            .ok();
+
+        let mut packet_timing_probe = self.packet_timing_probe.lock().unwrap();
+        if let Some(sent_at) = packet_timing_probe.sent_at.take() {
+            self.stats_tx
+                .send(ProtocolStatsMessage::PacketTiming {
+                    connection: self.connection,
+                    elapsed: sent_at.elapsed(),
+                })
+                .ok();
+            packet_timing_probe.last_sample = Some(Instant::now());
+        }
+    }
+}
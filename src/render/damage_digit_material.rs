@@ -24,6 +24,11 @@ pub struct DamageDigitMaterial {
     #[texture(3)]
     #[sampler(4)]
     pub texture: Handle<Image>,
+
+    /// Digits are recoloured towards `tint.rgb` by `tint.a`, keeping the
+    /// brightness of the texture
+    #[uniform(5)]
+    pub tint: Vec4,
 }
 
 impl Material for DamageDigitMaterial {
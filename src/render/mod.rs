@@ -18,7 +18,7 @@ pub const TERRAIN_MESH_ATTRIBUTE_TILE_INFO: MeshVertexAttribute =
     MeshVertexAttribute::new("Vertex_TileInfo", 988347822, VertexFormat::Uint32);
 
 pub mod world_ui;
-pub use world_ui::{WorldUiRect, WorldUiRenderPlugin};
+pub use world_ui::{WorldUiOpacity, WorldUiRect, WorldUiRenderPlugin};
 
 pub mod particle_material;
 pub use particle_material::*;
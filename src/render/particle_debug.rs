@@ -1,5 +1,6 @@
 use crate::render::ParticleMaterial;
 use crate::render::ParticleRenderData;
+use crate::resources::{ParticleBudget, ParticleBudgetStats};
 use bevy::diagnostic::FrameTimeDiagnosticsPlugin;
 use bevy::prelude::*;
 
@@ -43,6 +44,8 @@ pub fn particle_performance_monitor(
     query: Query<&ParticleRenderData>,
     materials: Res<Assets<ParticleMaterial>>,
     diagnostics: Res<bevy::diagnostic::DiagnosticsStore>,
+    particle_budget: Option<Res<ParticleBudget>>,
+    particle_budget_stats: Option<Res<ParticleBudgetStats>>,
     mut last_log: Local<f32>,
     time: Res<Time>,
 ) {
@@ -73,6 +76,17 @@ pub fn particle_performance_monitor(
         particle_systems, total_particles
     );
 
+    if let (Some(particle_budget), Some(particle_budget_stats)) =
+        (particle_budget, particle_budget_stats)
+    {
+        info!(
+            "[Particle Stats] Budget: {} / {} live particles, {} emitters throttled",
+            particle_budget_stats.live_particles,
+            particle_budget.max_particles,
+            particle_budget_stats.throttled_emitters
+        );
+    }
+
     if let Some(fps) = fps {
         info!("[Particle Stats] FPS: {:.1}", fps);
 
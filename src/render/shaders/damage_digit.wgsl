@@ -22,6 +22,9 @@ var base_color_texture: texture_2d<f32>;
 @group(#{MATERIAL_BIND_GROUP}) @binding(4)
 var base_color_sampler: sampler;
 
+@group(#{MATERIAL_BIND_GROUP}) @binding(5)
+var<uniform> tint: vec4<f32>;
+
 struct VertexInput {
   @builtin(vertex_index) vertex_idx: u32,
 };
@@ -83,5 +86,7 @@ fn vertex(model: VertexInput) -> VertexOutput {
 
 @fragment
 fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
-  return textureSample(base_color_texture, base_color_sampler, in.uv);
+  let color = textureSample(base_color_texture, base_color_sampler, in.uv);
+  let luminance = dot(color.rgb, vec3<f32>(0.299, 0.587, 0.114));
+  return vec4<f32>(mix(color.rgb, tint.rgb * luminance * 2.0, tint.a), color.a);
 }
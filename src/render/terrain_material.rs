@@ -195,6 +195,8 @@ pub struct TerrainMaterial {
     pub light_color: Color,
     /// Terrain ambient light color.
     pub ambient_color: Color,
+    /// Anisotropic filtering level for the tile sampler, 1 disables it
+    pub anisotropy: u16,
 }
 
 /// Data stored alongside the prepared bind group
@@ -324,6 +326,7 @@ impl AsBindGroup for TerrainMaterial {
             mag_filter: FilterMode::Linear,
             min_filter: FilterMode::Linear,
             mipmap_filter: FilterMode::Linear,
+            anisotropy_clamp: self.anisotropy.max(1),
             ..Default::default()
         });
 
@@ -5,7 +5,7 @@ use bevy::{
     time::Time,
 };
 
-use crate::resources::RenderConfiguration;
+use crate::resources::{RenderConfiguration, TrailSettings};
 
 /// Trail effect component - temporarily disabled rendering, but kept for API compatibility
 #[derive(Component)]
@@ -47,14 +47,42 @@ impl Default for TrailEffectPositionHistory {
 pub struct TrailEffectRenderPlugin;
 
 impl bevy::app::Plugin for TrailEffectRenderPlugin {
-    fn build(&self, _app: &mut bevy::app::App) {
+    fn build(&self, app: &mut bevy::app::App) {
         // Trail effect rendering temporarily disabled for Bevy 0.14 migration
-        // The component definitions are kept for API compatibility
+        // The component definitions are kept for API compatibility, only the
+        // position history is maintained so TrailSettings applies live
+        app.init_resource::<TrailSettings>().add_systems(
+            bevy::app::Update,
+            (apply_trail_settings, initialise_trail_effects).chain(),
+        );
+    }
+}
+
+/// Drops the position history of every trail when the trail quality changes,
+/// so disabling removes all trail geometry and re-enabling starts fresh rather
+/// than stretching a segment back to where the weapon was before.
+fn apply_trail_settings(
+    mut commands: bevy::ecs::system::Commands,
+    trail_settings: bevy::ecs::system::Res<TrailSettings>,
+    query: bevy::ecs::system::Query<
+        bevy::ecs::entity::Entity,
+        bevy::ecs::query::With<TrailEffectPositionHistory>,
+    >,
+) {
+    if !trail_settings.is_changed() {
+        return;
+    }
+
+    for entity in query.iter() {
+        commands
+            .entity(entity)
+            .remove::<TrailEffectPositionHistory>();
     }
 }
 
 fn initialise_trail_effects(
     mut commands: bevy::ecs::system::Commands,
+    trail_settings: bevy::ecs::system::Res<TrailSettings>,
     query: bevy::ecs::system::Query<
         bevy::ecs::entity::Entity,
         (
@@ -63,6 +91,10 @@ fn initialise_trail_effects(
         ),
     >,
 ) {
+    if !trail_settings.enabled() {
+        return;
+    }
+
     for entity in query.iter() {
         commands
             .entity(entity)
@@ -73,6 +105,7 @@ fn initialise_trail_effects(
 #[allow(clippy::type_complexity)]
 fn update_trail_effects(
     _render_config: bevy::ecs::system::Res<RenderConfiguration>,
+    _trail_settings: bevy::ecs::system::Res<TrailSettings>,
     _time: bevy::ecs::system::Res<Time>,
     mut _query: bevy::ecs::system::Query<(
         &TrailEffect,
@@ -4,7 +4,7 @@ use std::{cmp::Ordering, ops::Range};
 
 use bevy::{
     asset::{load_internal_asset, weak_handle, AssetId, Handle},
-    color::ColorToComponents,
+    color::{Alpha, ColorToComponents},
     core_pipeline::core_3d::Transparent3d,
     ecs::{
         query::ROQueryItem,
@@ -112,6 +112,11 @@ pub struct WorldUiRect {
     pub order: u8,
 }
 
+/// Multiplies the alpha of a `WorldUiRect`, allows fading a rect out without
+/// changing its colour.
+#[derive(Component, Clone, Copy)]
+pub struct WorldUiOpacity(pub f32);
+
 pub struct ExtractedRect {
     pub world_position: Vec3,
     pub screen_offset: Vec2,
@@ -139,7 +144,7 @@ impl Default for ExtractedWorldUi {
 fn extract_world_ui_rects(
     mut extracted_world_ui: ResMut<ExtractedWorldUi>,
     images: Extract<Res<Assets<Image>>>,
-    query: Extract<Query<(&ViewVisibility, &GlobalTransform, &WorldUiRect)>>,
+    query: Extract<Query<(&ViewVisibility, &GlobalTransform, &WorldUiRect, Option<&WorldUiOpacity>)>>,
 ) {
     extracted_world_ui.rects.clear();
     let mut visible_count = 0;
@@ -147,12 +152,18 @@ fn extract_world_ui_rects(
     let mut missing_image_count = 0;
     let total_count = query.iter().len();
 
-    for (view_visibility, global_transform, rect) in query.iter() {
+    for (view_visibility, global_transform, rect, opacity) in query.iter() {
         if !view_visibility.get() {
             hidden_count += 1;
             continue;
         }
 
+        let opacity = opacity.map_or(1.0, |opacity| opacity.0);
+        if opacity <= 0.0 {
+            hidden_count += 1;
+            continue;
+        }
+
         if !images.contains(rect.image.id()) {
             missing_image_count += 1;
             continue;
@@ -166,7 +177,7 @@ fn extract_world_ui_rects(
             image_handle_id: rect.image.id(),
             uv_min: rect.uv_min,
             uv_max: rect.uv_max,
-            color: rect.color,
+            color: rect.color.with_alpha(rect.color.alpha() * opacity),
             order: rect.order,
         });
     }
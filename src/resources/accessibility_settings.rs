@@ -0,0 +1,209 @@
+use std::sync::RwLock;
+
+use bevy::prelude::Resource;
+use bevy_egui::egui;
+use serde::{Deserialize, Serialize};
+
+use crate::resources::PersistentSettings;
+
+/// Copy of the active settings for tooltip and name tag helpers which are
+/// called far from any system that could take the resource
+static CURRENT_ACCESSIBILITY: RwLock<AccessibilitySettings> = RwLock::new(AccessibilitySettings {
+    colorblind_mode: ColorblindMode::Off,
+    shape_indicators: false,
+    disable_camera_shake: false,
+    disable_motion_blur: false,
+    disable_camera_motion: false,
+    disable_health_vignette: false,
+});
+
+pub fn current_accessibility() -> AccessibilitySettings {
+    CURRENT_ACCESSIBILITY
+        .read()
+        .map_or_else(|error| *error.into_inner(), |settings| *settings)
+}
+
+pub fn set_current_accessibility(settings: AccessibilitySettings) {
+    match CURRENT_ACCESSIBILITY.write() {
+        Ok(mut current) => *current = settings,
+        Err(error) => *error.into_inner() = settings,
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ColorblindMode {
+    Off,
+    /// Reduced sensitivity to green
+    Deuteranopia,
+    /// Reduced sensitivity to red
+    Protanopia,
+    /// Reduced sensitivity to blue
+    Tritanopia,
+}
+
+impl ColorblindMode {
+    pub const ALL: [ColorblindMode; 4] = [
+        ColorblindMode::Off,
+        ColorblindMode::Deuteranopia,
+        ColorblindMode::Protanopia,
+        ColorblindMode::Tritanopia,
+    ];
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ColorblindMode::Off => "Off",
+            ColorblindMode::Deuteranopia => "Deuteranopia",
+            ColorblindMode::Protanopia => "Protanopia",
+            ColorblindMode::Tritanopia => "Tritanopia",
+        }
+    }
+
+    /// Shift the colour information this mode cannot see into the channels it
+    /// can, so colours which only differ in red and green stay distinguishable.
+    ///
+    /// Uses the daltonisation method of Fidaner, Lin and Ozguven.
+    pub fn remap(&self, color: egui::Color32) -> egui::Color32 {
+        let rgb = [color.r() as f32, color.g() as f32, color.b() as f32];
+        let lms = [
+            17.8824 * rgb[0] + 43.5161 * rgb[1] + 4.11935 * rgb[2],
+            3.45565 * rgb[0] + 27.1554 * rgb[1] + 3.86714 * rgb[2],
+            0.0299566 * rgb[0] + 0.184309 * rgb[1] + 1.46709 * rgb[2],
+        ];
+
+        let simulated_lms = match self {
+            ColorblindMode::Off => return color,
+            ColorblindMode::Protanopia => [
+                2.02344 * lms[1] - 2.52581 * lms[2],
+                lms[1],
+                lms[2],
+            ],
+            ColorblindMode::Deuteranopia => [
+                lms[0],
+                0.494207 * lms[0] + 1.24827 * lms[2],
+                lms[2],
+            ],
+            ColorblindMode::Tritanopia => [
+                lms[0],
+                lms[1],
+                -0.395913 * lms[0] + 0.801109 * lms[1],
+            ],
+        };
+
+        let simulated = [
+            0.0809444479 * simulated_lms[0] - 0.130504409 * simulated_lms[1]
+                + 0.116721066 * simulated_lms[2],
+            -0.0102485335 * simulated_lms[0] + 0.0540193266 * simulated_lms[1]
+                - 0.113614708 * simulated_lms[2],
+            -0.000365296938 * simulated_lms[0] - 0.00412161469 * simulated_lms[1]
+                + 0.693511405 * simulated_lms[2],
+        ];
+
+        let error = [
+            rgb[0] - simulated[0],
+            rgb[1] - simulated[1],
+            rgb[2] - simulated[2],
+        ];
+        let to_u8 = |value: f32| value.round().clamp(0.0, 255.0) as u8;
+
+        egui::Color32::from_rgba_unmultiplied(
+            to_u8(rgb[0]),
+            to_u8(rgb[1] + 0.7 * error[0] + error[1]),
+            to_u8(rgb[2] + 0.7 * error[0] + error[2]),
+            color.a(),
+        )
+    }
+}
+
+#[derive(Resource, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AccessibilitySettings {
+    pub colorblind_mode: ColorblindMode,
+    /// Prefix good and bad indicators with a tick or cross so they do not rely
+    /// on colour alone
+    pub shape_indicators: bool,
+    /// Never shake the camera, for players sensitive to motion
+    pub disable_camera_shake: bool,
+    /// Never blur the screen when the camera or objects move
+    pub disable_motion_blur: bool,
+    /// Never bob or roll the camera while moving
+    pub disable_camera_motion: bool,
+    /// Never tint or pulse the screen edges when hurt
+    pub disable_health_vignette: bool,
+}
+
+impl Default for AccessibilitySettings {
+    fn default() -> Self {
+        Self {
+            colorblind_mode: ColorblindMode::Off,
+            shape_indicators: false,
+            disable_camera_shake: false,
+            disable_motion_blur: false,
+            disable_camera_motion: false,
+            disable_health_vignette: false,
+        }
+    }
+}
+
+impl AccessibilitySettings {
+    /// Colour for met requirements, friendly targets and gains
+    pub fn positive_color(&self) -> egui::Color32 {
+        match self.colorblind_mode {
+            ColorblindMode::Off | ColorblindMode::Tritanopia => egui::Color32::GREEN,
+            // Blue and orange from the Okabe-Ito palette
+            ColorblindMode::Deuteranopia | ColorblindMode::Protanopia => {
+                egui::Color32::from_rgb(86, 180, 233)
+            }
+        }
+    }
+
+    /// Colour for unmet requirements, hostile targets and losses
+    pub fn negative_color(&self) -> egui::Color32 {
+        match self.colorblind_mode {
+            ColorblindMode::Off | ColorblindMode::Tritanopia => egui::Color32::RED,
+            ColorblindMode::Deuteranopia | ColorblindMode::Protanopia => {
+                egui::Color32::from_rgb(230, 159, 0)
+            }
+        }
+    }
+
+    /// Text coloured as a good or bad indicator, with a tick or cross when
+    /// shape indicators are enabled
+    pub fn indicator_text(&self, positive: bool, text: impl Into<String>) -> egui::RichText {
+        let text = text.into();
+        let (color, marker) = if positive {
+            (self.positive_color(), "✔")
+        } else {
+            (self.negative_color(), "✖")
+        };
+
+        if self.shape_indicators {
+            egui::RichText::new(format!("{} {}", marker, text)).color(color)
+        } else {
+            egui::RichText::new(text).color(color)
+        }
+    }
+}
+
+impl PersistentSettings for AccessibilitySettings {
+    const CONFIG_SECTION: &'static str = "accessibility";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remap_separates_red_and_green() {
+        assert_eq!(
+            ColorblindMode::Off.remap(egui::Color32::RED),
+            egui::Color32::RED
+        );
+
+        for mode in [ColorblindMode::Deuteranopia, ColorblindMode::Protanopia] {
+            let red = mode.remap(egui::Color32::RED);
+            let green = mode.remap(egui::Color32::GREEN);
+            assert!(red.b().abs_diff(green.b()) > 64);
+        }
+    }
+}
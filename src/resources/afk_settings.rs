@@ -0,0 +1,75 @@
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+use crate::resources::PersistentSettings;
+
+/// Auto replies start with this so two away players do not reply to each other forever
+const AFK_REPLY_PREFIX: &str = "[AFK]";
+
+#[derive(Resource, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AfkSettings {
+    /// Go AFK after `idle_seconds` without input
+    pub auto_afk: bool,
+    pub idle_seconds: f32,
+    /// Replied to whispers while AFK, `/afk <message>` replaces it
+    pub message: String,
+}
+
+impl Default for AfkSettings {
+    fn default() -> Self {
+        Self {
+            auto_afk: false,
+            idle_seconds: 300.0,
+            message: "I am away from the keyboard.".to_string(),
+        }
+    }
+}
+
+impl AfkSettings {
+    pub fn should_auto_afk(&self, idle_for: f32) -> bool {
+        self.auto_afk && idle_for >= self.idle_seconds
+    }
+}
+
+impl PersistentSettings for AfkSettings {
+    const CONFIG_SECTION: &'static str = "afk";
+}
+
+/// Chat text whispering the AFK message back to `to`
+pub fn afk_auto_reply(to: &str, message: &str) -> String {
+    format!("@{} {} {}", to, AFK_REPLY_PREFIX, message)
+}
+
+pub fn is_afk_auto_reply(text: &str) -> bool {
+    text.trim_start().starts_with(AFK_REPLY_PREFIX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auto_replies_are_recognised() {
+        let reply = afk_auto_reply("Alice", "brb");
+        assert_eq!(reply, "@Alice [AFK] brb");
+
+        let (_, whispered) = reply.split_once(' ').unwrap();
+        assert!(is_afk_auto_reply(whispered));
+        assert!(!is_afk_auto_reply("are you there?"));
+    }
+
+    #[test]
+    fn test_auto_afk_waits_for_idle_time() {
+        let mut settings = AfkSettings {
+            auto_afk: true,
+            idle_seconds: 60.0,
+            ..Default::default()
+        };
+        assert!(!settings.should_auto_afk(59.0));
+        assert!(settings.should_auto_afk(60.0));
+
+        settings.auto_afk = false;
+        assert!(!settings.should_auto_afk(1000.0));
+    }
+}
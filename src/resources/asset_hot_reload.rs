@@ -0,0 +1,73 @@
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use bevy::prelude::{AssetId, Image, Resource};
+use notify::{RecursiveMode, Watcher};
+
+use crate::vfs_path_resolver::clear_path_resolver_caches;
+
+/// How long the filesystem must be quiet before pending changes are reloaded,
+/// editors often write a file several times in quick succession
+pub const ASSET_HOT_RELOAD_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Watches `base_path` for modified game data, only inserted when
+/// `filesystem.hot_reload` is enabled in the config.
+#[derive(Resource)]
+pub struct AssetHotReload {
+    pub base_path: PathBuf,
+    pub changed_rx: crossbeam_channel::Receiver<PathBuf>,
+
+    /// Changed paths relative to `base_path`, waiting for the debounce to expire
+    pub pending: HashSet<PathBuf>,
+    pub last_change: Option<Instant>,
+
+    /// Images which have been reloaded and whose materials need refreshing
+    pub reloaded_images: HashSet<AssetId<Image>>,
+
+    _watcher: notify::RecommendedWatcher,
+}
+
+impl AssetHotReload {
+    pub fn new(base_path: &Path) -> Result<Self, notify::Error> {
+        let (changed_tx, changed_rx) = crossbeam_channel::unbounded();
+        let mut watcher =
+            notify::recommended_watcher(move |result: notify::Result<notify::Event>| {
+                let Ok(event) = result else {
+                    return;
+                };
+
+                if !matches!(
+                    event.kind,
+                    notify::EventKind::Create(_) | notify::EventKind::Modify(_)
+                ) {
+                    return;
+                }
+
+                // New and renamed files are not in the cached directory listings
+                if matches!(
+                    event.kind,
+                    notify::EventKind::Create(_)
+                        | notify::EventKind::Modify(notify::event::ModifyKind::Name(_))
+                ) {
+                    clear_path_resolver_caches();
+                }
+
+                for path in event.paths {
+                    changed_tx.send(path).ok();
+                }
+            })?;
+        watcher.watch(base_path, RecursiveMode::Recursive)?;
+
+        Ok(Self {
+            base_path: base_path.to_path_buf(),
+            changed_rx,
+            pending: HashSet::new(),
+            last_change: None,
+            reloaded_images: HashSet::new(),
+            _watcher: watcher,
+        })
+    }
+}
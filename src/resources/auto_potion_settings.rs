@@ -0,0 +1,80 @@
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+use crate::resources::PersistentSettings;
+
+/// Uses a potion from the inventory when HP or MP falls below a threshold.
+///
+/// Off by default as some servers consider using items automatically to be
+/// botting, players should check the server rules before enabling it.
+#[derive(Resource, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AutoPotionSettings {
+    pub enabled: bool,
+    /// Consumable item number used for HP, `None` to never use one
+    pub hp_item: Option<usize>,
+    /// Fraction of max HP below which the HP item is used
+    pub hp_threshold: f32,
+    /// Consumable item number used for MP, `None` to never use one
+    pub mp_item: Option<usize>,
+    /// Fraction of max MP below which the MP item is used
+    pub mp_threshold: f32,
+}
+
+impl Default for AutoPotionSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            hp_item: None,
+            hp_threshold: 0.4,
+            mp_item: None,
+            mp_threshold: 0.2,
+        }
+    }
+}
+
+impl AutoPotionSettings {
+    /// The HP item to use at `health_fraction` of max HP, if any
+    pub fn hp_item_to_use(&self, health_fraction: f32) -> Option<usize> {
+        self.item_to_use(self.hp_item, self.hp_threshold, health_fraction)
+    }
+
+    /// The MP item to use at `mana_fraction` of max MP, if any
+    pub fn mp_item_to_use(&self, mana_fraction: f32) -> Option<usize> {
+        self.item_to_use(self.mp_item, self.mp_threshold, mana_fraction)
+    }
+
+    fn item_to_use(&self, item: Option<usize>, threshold: f32, fraction: f32) -> Option<usize> {
+        item.filter(|_| self.enabled && fraction < threshold)
+    }
+}
+
+impl PersistentSettings for AutoPotionSettings {
+    const CONFIG_SECTION: &'static str = "auto_potion";
+}
+
+/// Last item used by auto-potion, shown briefly on screen
+#[derive(Resource, Default)]
+pub struct AutoPotionNotice {
+    pub text: String,
+    pub time: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_items_are_used_below_threshold_when_enabled() {
+        let mut settings = AutoPotionSettings {
+            hp_item: Some(101),
+            ..Default::default()
+        };
+        assert_eq!(settings.hp_item_to_use(0.1), None);
+
+        settings.enabled = true;
+        assert_eq!(settings.hp_item_to_use(0.1), Some(101));
+        assert_eq!(settings.hp_item_to_use(0.4), None);
+        assert_eq!(settings.mp_item_to_use(0.0), None);
+    }
+}
@@ -0,0 +1,41 @@
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+use crate::resources::PersistentSettings;
+
+/// Order the items of a bank tab are shown in, the items are never moved
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BankSort {
+    /// Bank slot order
+    #[default]
+    Slot,
+    Name,
+    Type,
+    Quantity,
+}
+
+impl BankSort {
+    pub const ALL: [BankSort; 4] = [BankSort::Slot, BankSort::Name, BankSort::Type, BankSort::Quantity];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            BankSort::Slot => "Slot",
+            BankSort::Name => "Name",
+            BankSort::Type => "Type",
+            BankSort::Quantity => "Quantity",
+        }
+    }
+}
+
+#[derive(Resource, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BankSettings {
+    /// Only items whose name contains this are shown, case insensitive
+    pub search: String,
+    pub sort: BankSort,
+}
+
+impl PersistentSettings for BankSettings {
+    const CONFIG_SECTION: &'static str = "bank";
+}
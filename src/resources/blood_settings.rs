@@ -0,0 +1,91 @@
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+use crate::resources::{BloodEffectConfig, PersistentSettings};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BloodIntensity {
+    Off,
+    Low,
+    High,
+}
+
+impl BloodIntensity {
+    pub const ALL: [BloodIntensity; 3] = [BloodIntensity::Off, BloodIntensity::Low, BloodIntensity::High];
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            BloodIntensity::Off => "Off",
+            BloodIntensity::Low => "Low",
+            BloodIntensity::High => "High",
+        }
+    }
+}
+
+/// Player facing blood options, applied to `BloodEffectConfig` by
+/// `blood_settings_system` whenever they change.
+#[derive(Resource, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BloodSettings {
+    pub intensity: BloodIntensity,
+    /// Turns off all blood and wounds regardless of the intensity
+    pub disable_gore: bool,
+}
+
+impl Default for BloodSettings {
+    fn default() -> Self {
+        Self {
+            intensity: BloodIntensity::High,
+            disable_gore: false,
+        }
+    }
+}
+
+impl BloodSettings {
+    pub fn effect_config(&self) -> BloodEffectConfig {
+        if self.disable_gore {
+            return BloodEffectConfig {
+                show_wounds: false,
+                ..BloodEffectConfig::disabled()
+            };
+        }
+
+        match self.intensity {
+            BloodIntensity::Off => BloodEffectConfig::disabled(),
+            BloodIntensity::Low => BloodEffectConfig {
+                spatter_lifetime: 10.0,
+                ..BloodEffectConfig::low_intensity()
+            },
+            BloodIntensity::High => BloodEffectConfig::default(),
+        }
+    }
+}
+
+impl PersistentSettings for BloodSettings {
+    const CONFIG_SECTION: &'static str = "blood";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disable_gore_overrides_intensity() {
+        let settings = BloodSettings {
+            intensity: BloodIntensity::High,
+            disable_gore: true,
+        };
+        let config = settings.effect_config();
+        assert!(!config.enable_blood);
+        assert!(!config.show_wounds);
+
+        let low = BloodSettings {
+            intensity: BloodIntensity::Low,
+            disable_gore: false,
+        }
+        .effect_config();
+        assert!(low.enable_blood);
+        assert!(low.spatter_lifetime < BloodEffectConfig::default().spatter_lifetime);
+    }
+}
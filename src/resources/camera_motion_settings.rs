@@ -0,0 +1,78 @@
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+use crate::resources::PersistentSettings;
+
+/// Height of the camera bob in metres at full intensity and running speed
+const CAMERA_BOB_MAX_HEIGHT: f32 = 0.05;
+
+/// Ground speed in metres per second at which the bob reaches full height
+const CAMERA_BOB_FULL_SPEED: f32 = 5.0;
+
+/// Roll in radians at full intensity when turning quickly in flight
+const CAMERA_BANK_MAX_ANGLE: f32 = 0.14;
+
+/// Turn rate in radians per second at which banking reaches its full roll
+const CAMERA_BANK_FULL_TURN_RATE: f32 = 2.5;
+
+#[derive(Resource, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CameraMotionSettings {
+    pub enabled: bool,
+    /// Strength of the camera bob while running
+    pub bob_intensity: f32,
+    /// Strength of the camera roll when turning in flight
+    pub bank_intensity: f32,
+}
+
+impl Default for CameraMotionSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            bob_intensity: 0.4,
+            bank_intensity: 0.5,
+        }
+    }
+}
+
+impl CameraMotionSettings {
+    /// Height of the bob in metres when moving at `speed` metres per second
+    pub fn bob_height(&self, speed: f32) -> f32 {
+        (speed / CAMERA_BOB_FULL_SPEED).clamp(0.0, 1.0)
+            * CAMERA_BOB_MAX_HEIGHT
+            * self.bob_intensity.max(0.0)
+    }
+
+    /// Camera roll in radians when turning at `turn_rate` radians per second,
+    /// positive turn rates roll the camera into the turn
+    pub fn bank_angle(&self, turn_rate: f32) -> f32 {
+        (turn_rate / CAMERA_BANK_FULL_TURN_RATE).clamp(-1.0, 1.0)
+            * CAMERA_BANK_MAX_ANGLE
+            * self.bank_intensity.max(0.0)
+    }
+}
+
+impl PersistentSettings for CameraMotionSettings {
+    const CONFIG_SECTION: &'static str = "camera_motion";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bob_and_bank_scale_with_motion() {
+        let settings = CameraMotionSettings {
+            enabled: true,
+            bob_intensity: 1.0,
+            bank_intensity: 1.0,
+        };
+        assert_eq!(settings.bob_height(0.0), 0.0);
+        assert!(settings.bob_height(2.0) < settings.bob_height(4.0));
+        assert_eq!(settings.bob_height(100.0), CAMERA_BOB_MAX_HEIGHT);
+
+        assert_eq!(settings.bank_angle(0.0), 0.0);
+        assert_eq!(settings.bank_angle(100.0), CAMERA_BANK_MAX_ANGLE);
+        assert_eq!(settings.bank_angle(-100.0), -CAMERA_BANK_MAX_ANGLE);
+    }
+}
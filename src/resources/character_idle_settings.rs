@@ -0,0 +1,101 @@
+use std::ops::Range;
+
+use bevy::prelude::Resource;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::resources::PersistentSettings;
+
+#[derive(Resource, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterIdleSettings {
+    pub blink_enabled: bool,
+    /// Seconds the eyes stay open between blinks
+    pub blink_interval_min: f32,
+    pub blink_interval_max: f32,
+    /// Seconds the eyes stay closed for a blink
+    pub blink_duration_min: f32,
+    pub blink_duration_max: f32,
+
+    /// Gentle rise and fall of idle characters as they breathe
+    pub breathing_enabled: bool,
+    /// Seconds for one breath
+    pub breathing_period_min: f32,
+    pub breathing_period_max: f32,
+    /// Height of the breathing motion in metres
+    pub breathing_amplitude: f32,
+
+    /// Characters further than this from the camera do not blink or breathe
+    pub max_distance: f32,
+}
+
+impl Default for CharacterIdleSettings {
+    fn default() -> Self {
+        Self {
+            blink_enabled: true,
+            blink_interval_min: 0.1,
+            blink_interval_max: 3.0,
+            blink_duration_min: 0.01,
+            blink_duration_max: 0.11,
+            breathing_enabled: true,
+            breathing_period_min: 3.0,
+            breathing_period_max: 4.5,
+            breathing_amplitude: 0.008,
+            max_distance: 60.0,
+        }
+    }
+}
+
+/// A random value from `min..max`, or `min` when the range is empty
+fn random_in_range<R: Rng>(rng: &mut R, min: f32, max: f32) -> f32 {
+    let range = Range {
+        start: min.max(0.0),
+        end: max.max(0.0),
+    };
+    if range.is_empty() {
+        range.start
+    } else {
+        rng.gen_range(range)
+    }
+}
+
+impl CharacterIdleSettings {
+    pub fn random_blink_interval<R: Rng>(&self, rng: &mut R) -> f32 {
+        random_in_range(rng, self.blink_interval_min, self.blink_interval_max)
+    }
+
+    pub fn random_blink_duration<R: Rng>(&self, rng: &mut R) -> f32 {
+        random_in_range(rng, self.blink_duration_min, self.blink_duration_max)
+    }
+
+    pub fn random_breathing_period<R: Rng>(&self, rng: &mut R) -> f32 {
+        random_in_range(rng, self.breathing_period_min, self.breathing_period_max).max(0.5)
+    }
+}
+
+impl PersistentSettings for CharacterIdleSettings {
+    const CONFIG_SECTION: &'static str = "character_idle";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resources::RngSeed;
+
+    #[test]
+    fn test_random_values_stay_in_range() {
+        let settings = CharacterIdleSettings {
+            blink_interval_min: 2.0,
+            blink_interval_max: 4.0,
+            blink_duration_min: 0.1,
+            blink_duration_max: 0.1,
+            ..Default::default()
+        };
+        let mut rng = RngSeed::new(1).rng("test");
+        for _ in 0..100 {
+            let interval = settings.random_blink_interval(&mut rng);
+            assert!((2.0..4.0).contains(&interval));
+        }
+        assert_eq!(settings.random_blink_duration(&mut rng), 0.1);
+    }
+}
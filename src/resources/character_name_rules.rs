@@ -0,0 +1,114 @@
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+/// Why a character name was refused before sending it to the server
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CharacterNameError {
+    Empty,
+    TooShort(usize),
+    TooLong(usize),
+    InvalidCharacter(char),
+    Blocked,
+}
+
+impl CharacterNameError {
+    pub fn message(&self) -> String {
+        match self {
+            CharacterNameError::Empty => "Enter a name".to_string(),
+            CharacterNameError::TooShort(min_length) => {
+                format!("Name must be at least {} characters", min_length)
+            }
+            CharacterNameError::TooLong(max_length) => {
+                format!("Name must be at most {} characters", max_length)
+            }
+            CharacterNameError::InvalidCharacter(c) => format!("Name can not contain '{}'", c),
+            CharacterNameError::Blocked => "Name is not allowed".to_string(),
+        }
+    }
+}
+
+/// Client side checks of new character names, set by the `[character_name]`
+/// config section. These only catch obvious mistakes before a round trip, the
+/// server still decides whether a name is accepted.
+#[derive(Resource, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterNameRules {
+    pub min_length: usize,
+    pub max_length: usize,
+    /// Characters allowed besides ASCII letters and digits
+    pub allowed_symbols: String,
+    /// Names containing any of these are refused, case insensitive
+    pub blocked_words: Vec<String>,
+}
+
+impl Default for CharacterNameRules {
+    fn default() -> Self {
+        Self {
+            min_length: 4,
+            max_length: 20,
+            allowed_symbols: String::new(),
+            blocked_words: Vec::new(),
+        }
+    }
+}
+
+impl CharacterNameRules {
+    pub fn validate(&self, name: &str) -> Result<(), CharacterNameError> {
+        if name.is_empty() {
+            return Err(CharacterNameError::Empty);
+        }
+
+        if let Some(c) = name
+            .chars()
+            .find(|c| !c.is_ascii_alphanumeric() && !self.allowed_symbols.contains(*c))
+        {
+            return Err(CharacterNameError::InvalidCharacter(c));
+        }
+
+        let length = name.chars().count();
+        if length < self.min_length {
+            return Err(CharacterNameError::TooShort(self.min_length));
+        }
+        if length > self.max_length {
+            return Err(CharacterNameError::TooLong(self.max_length));
+        }
+
+        let lowercase_name = name.to_lowercase();
+        if self
+            .blocked_words
+            .iter()
+            .map(|word| word.trim().to_lowercase())
+            .any(|word| !word.is_empty() && lowercase_name.contains(&word))
+        {
+            return Err(CharacterNameError::Blocked);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_name() {
+        let rules = CharacterNameRules {
+            blocked_words: vec!["Admin".to_string()],
+            ..Default::default()
+        };
+
+        assert_eq!(rules.validate("Visitor42"), Ok(()));
+        assert_eq!(rules.validate(""), Err(CharacterNameError::Empty));
+        assert_eq!(rules.validate("Abc"), Err(CharacterNameError::TooShort(4)));
+        assert_eq!(
+            rules.validate("Abcdefghijklmnopqrstu"),
+            Err(CharacterNameError::TooLong(20))
+        );
+        assert_eq!(
+            rules.validate("Bad Name"),
+            Err(CharacterNameError::InvalidCharacter(' '))
+        );
+        assert_eq!(rules.validate("TheADMIN"), Err(CharacterNameError::Blocked));
+    }
+}
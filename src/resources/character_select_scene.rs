@@ -0,0 +1,136 @@
+use bevy::prelude::{Quat, Resource, Transform, Vec3};
+use rose_data::{ZoneId, ZoneList};
+use serde::{Deserialize, Serialize};
+
+/// Zone loaded behind the login and character select screens
+pub const DEFAULT_CHARACTER_SELECT_ZONE: u16 = 4;
+
+/// Camera motion played when entering character select
+pub const DEFAULT_CHARACTER_SELECT_CAMERA_MOTION: &str = "3DDATA/TITLE/CAMERA01_INSELECT01.ZMO";
+
+/// Where a character of the character list stands
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CharacterSelectPosition {
+    pub translation: [f32; 3],
+    /// Rotation around the up axis in degrees
+    #[serde(default)]
+    pub yaw: f32,
+    #[serde(default = "default_position_scale")]
+    pub scale: f32,
+}
+
+fn default_position_scale() -> f32 {
+    1.5
+}
+
+impl CharacterSelectPosition {
+    fn new(x: f32, y: f32, z: f32) -> Self {
+        Self {
+            translation: [x, y, z],
+            yaw: 180.0,
+            scale: default_position_scale(),
+        }
+    }
+
+    pub fn transform(&self) -> Transform {
+        Transform::from_translation(Vec3::from(self.translation))
+            .with_rotation(Quat::from_rotation_y(self.yaw.to_radians()))
+            .with_scale(Vec3::splat(self.scale))
+    }
+}
+
+/// Fixed camera used instead of the camera motion
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CharacterSelectCamera {
+    pub position: [f32; 3],
+    pub look_at: [f32; 3],
+}
+
+impl CharacterSelectCamera {
+    pub fn transform(&self) -> Transform {
+        Transform::from_translation(Vec3::from(self.position))
+            .looking_at(Vec3::from(self.look_at), Vec3::Y)
+    }
+}
+
+/// The backdrop of character select, set by the `[character_select]` config
+/// section so servers can use their own scene
+#[derive(Resource, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterSelectScene {
+    pub zone_id: u16,
+    /// Camera motion played when entering character select
+    pub camera_motion: String,
+    /// Fixed camera, replaces the camera motion when set
+    pub camera: Option<CharacterSelectCamera>,
+    /// One position per character slot
+    pub positions: Vec<CharacterSelectPosition>,
+}
+
+impl Default for CharacterSelectScene {
+    fn default() -> Self {
+        Self {
+            zone_id: DEFAULT_CHARACTER_SELECT_ZONE,
+            camera_motion: DEFAULT_CHARACTER_SELECT_CAMERA_MOTION.to_string(),
+            camera: None,
+            positions: vec![
+                CharacterSelectPosition::new(5205.0, 1.0, -5205.0),
+                CharacterSelectPosition::new(5202.70, 1.0, -5206.53),
+                CharacterSelectPosition::new(5200.00, 1.0, -5207.07),
+                CharacterSelectPosition::new(5197.30, 1.0, -5206.53),
+                CharacterSelectPosition::new(5195.00, 1.0, -5205.00),
+            ],
+        }
+    }
+}
+
+impl CharacterSelectScene {
+    pub fn zone_id(&self) -> ZoneId {
+        ZoneId::new(self.zone_id)
+            .or(ZoneId::new(DEFAULT_CHARACTER_SELECT_ZONE))
+            .unwrap()
+    }
+
+    /// Replace the parts of the scene which can not be used with the defaults
+    pub fn validate(&mut self, zone_list: &ZoneList) {
+        let default = Self::default();
+
+        let zone_exists = ZoneId::new(self.zone_id).map_or(false, |zone_id| zone_list.get_zone(zone_id).is_some());
+        if !zone_exists {
+            log::warn!(
+                "[CharacterSelect] Zone {} is not in the zone list, using zone {} instead",
+                self.zone_id,
+                default.zone_id
+            );
+            self.zone_id = default.zone_id;
+        }
+
+        if self.camera_motion.trim().is_empty() {
+            self.camera_motion = default.camera_motion;
+        }
+
+        if self.positions.is_empty() {
+            log::warn!("[CharacterSelect] No character positions configured, using the default positions");
+            self.positions = default.positions;
+        }
+    }
+
+    pub fn position_transform(&self, index: usize) -> Option<Transform> {
+        self.positions.get(index).map(CharacterSelectPosition::transform)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_positions_face_camera() {
+        // The defaults reproduce the transforms used before the scene was configurable
+        let transform = CharacterSelectScene::default().position_transform(0).unwrap();
+        let expected = Quat::from_xyzw(0.0, 1.0, 0.0, 0.0);
+        assert!(transform.rotation.angle_between(expected) < 1.0e-3);
+        assert_eq!(transform.scale, Vec3::splat(1.5));
+        assert_eq!(transform.translation, Vec3::new(5205.0, 1.0, -5205.0));
+    }
+}
@@ -0,0 +1,106 @@
+use bevy::prelude::{Resource, Vec2};
+use serde::{Deserialize, Serialize};
+
+use crate::resources::PersistentSettings;
+
+/// Gap in pixels left between stacked chat bubbles
+const CHAT_BUBBLE_STACK_GAP: f32 = 4.0;
+
+#[derive(Resource, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChatBubbleSettings {
+    /// Maximum number of bubbles on screen, the oldest fade out first once
+    /// more are showing
+    pub max_visible: usize,
+    /// Multiplier on how long each bubble stays up
+    pub lifetime_scale: f32,
+    /// Opacity of the whole bubble
+    pub opacity: f32,
+    /// Opacity of the panel behind the text
+    pub background_opacity: f32,
+    /// Push overlapping bubbles upwards so they can all be read
+    pub stacking: bool,
+}
+
+impl Default for ChatBubbleSettings {
+    fn default() -> Self {
+        Self {
+            max_visible: 8,
+            lifetime_scale: 1.0,
+            opacity: 1.0,
+            background_opacity: 0.8,
+            stacking: true,
+        }
+    }
+}
+
+impl PersistentSettings for ChatBubbleSettings {
+    const CONFIG_SECTION: &'static str = "chat_bubbles";
+}
+
+/// Upwards offset in screen pixels for each bubble so that none overlap.
+///
+/// Takes `(anchor, size)` pairs where `anchor` is the bottom centre of the
+/// bubble on screen, ordered newest first. Newer bubbles keep their place and
+/// older ones are pushed up above them.
+pub fn chat_bubble_stack_offsets(bubbles: &[(Vec2, Vec2)]) -> Vec<f32> {
+    let mut offsets: Vec<f32> = Vec::with_capacity(bubbles.len());
+
+    for (index, &(anchor, size)) in bubbles.iter().enumerate() {
+        let mut offset = 0.0;
+
+        // Each move can only uncover an overlap with a bubble placed above,
+        // so at most one pass per placed bubble is required
+        for _ in 0..=index {
+            let mut moved = false;
+
+            for (&(placed_anchor, placed_size), &placed_offset) in
+                bubbles.iter().zip(offsets.iter())
+            {
+                let overlaps_x =
+                    (anchor.x - placed_anchor.x).abs() * 2.0 < size.x + placed_size.x;
+                let bottom = anchor.y - offset;
+                let top = bottom - size.y;
+                let placed_bottom = placed_anchor.y - placed_offset;
+                let placed_top = placed_bottom - placed_size.y;
+
+                if overlaps_x
+                    && top < placed_bottom + CHAT_BUBBLE_STACK_GAP
+                    && bottom > placed_top - CHAT_BUBBLE_STACK_GAP
+                {
+                    offset = anchor.y - (placed_top - CHAT_BUBBLE_STACK_GAP);
+                    moved = true;
+                }
+            }
+
+            if !moved {
+                break;
+            }
+        }
+
+        offsets.push(offset);
+    }
+
+    offsets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overlapping_bubbles_stack_upwards() {
+        let size = Vec2::new(100.0, 30.0);
+        let offsets = chat_bubble_stack_offsets(&[
+            (Vec2::new(200.0, 300.0), size),
+            (Vec2::new(220.0, 305.0), size),
+            (Vec2::new(600.0, 300.0), size),
+        ]);
+
+        assert_eq!(offsets[0], 0.0);
+        // Bottom of the second bubble sits just above the top of the first
+        assert_eq!(offsets[1], 305.0 - (270.0 - CHAT_BUBBLE_STACK_GAP));
+        // Far away bubbles are left alone
+        assert_eq!(offsets[2], 0.0);
+    }
+}
@@ -0,0 +1,109 @@
+use std::path::PathBuf;
+
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+use crate::resources::PersistentSettings;
+
+/// Frequency multiplier for a single zone, on top of the global frequency
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct ZoneChatterFrequency {
+    pub zone: u16,
+    pub frequency: f32,
+}
+
+#[derive(Resource, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChatterSettings {
+    pub enabled: bool,
+    /// Multiplier on how often monsters and NPCs chatter, 0 silences them
+    pub frequency: f32,
+    pub zone_frequency: Vec<ZoneChatterFrequency>,
+    /// Phrases containing any of these are never said, case insensitive
+    pub muted_phrases: Vec<String>,
+    /// TOML file of extra phrases, read at startup by `MonsterChatterPhrases::load`
+    pub phrases_file: Option<PathBuf>,
+}
+
+impl Default for ChatterSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            frequency: 1.0,
+            zone_frequency: Vec::new(),
+            muted_phrases: Vec::new(),
+            phrases_file: None,
+        }
+    }
+}
+
+impl ChatterSettings {
+    /// Frequency multiplier for the zone set by the zone override, or 1 without one
+    pub fn zone_multiplier(&self, zone: u16) -> f32 {
+        self.zone_frequency
+            .iter()
+            .find(|zone_frequency| zone_frequency.zone == zone)
+            .map_or(1.0, |zone_frequency| zone_frequency.frequency)
+    }
+
+    pub fn set_zone_multiplier(&mut self, zone: u16, frequency: f32) {
+        if let Some(zone_frequency) = self
+            .zone_frequency
+            .iter_mut()
+            .find(|zone_frequency| zone_frequency.zone == zone)
+        {
+            zone_frequency.frequency = frequency;
+        } else {
+            self.zone_frequency
+                .push(ZoneChatterFrequency { zone, frequency });
+        }
+    }
+
+    /// How fast chatter timers count down in the zone, 0 when chatter is off
+    pub fn rate(&self, zone: Option<u16>) -> f32 {
+        if !self.enabled {
+            return 0.0;
+        }
+
+        let zone_multiplier = zone.map_or(1.0, |zone| self.zone_multiplier(zone));
+        (self.frequency * zone_multiplier).max(0.0)
+    }
+
+    pub fn is_muted(&self, phrase: &str) -> bool {
+        let phrase = phrase.to_lowercase();
+        self.muted_phrases
+            .iter()
+            .map(|muted| muted.trim())
+            .filter(|muted| !muted.is_empty())
+            .any(|muted| phrase.contains(&muted.to_lowercase()))
+    }
+}
+
+impl PersistentSettings for ChatterSettings {
+    const CONFIG_SECTION: &'static str = "chatter";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rate_and_mute_filter() {
+        let mut settings = ChatterSettings {
+            frequency: 2.0,
+            muted_phrases: vec!["zulie".to_string(), " ".to_string()],
+            ..Default::default()
+        };
+        settings.set_zone_multiplier(1, 0.5);
+
+        assert_eq!(settings.rate(None), 2.0);
+        assert_eq!(settings.rate(Some(1)), 1.0);
+        assert_eq!(settings.rate(Some(2)), 2.0);
+
+        assert!(settings.is_muted("Your Zulie will be mine!"));
+        assert!(!settings.is_muted("Prepare to die!"));
+
+        settings.enabled = false;
+        assert_eq!(settings.rate(None), 0.0);
+    }
+}
@@ -0,0 +1,149 @@
+use std::{collections::BTreeMap, path::PathBuf, sync::Mutex};
+
+use bevy::prelude::Resource;
+use serde::Serialize;
+
+/// The config.toml the client was started with, settings changed in game are
+/// written back to their section of this file.
+#[derive(Resource, Default)]
+pub struct ConfigFile {
+    pub path: Option<PathBuf>,
+    /// Sections waiting to be written by `write_config_file_system`, the
+    /// settings save systems run in parallel so only that system touches the file.
+    pending_sections: Mutex<BTreeMap<String, toml::Value>>,
+}
+
+impl ConfigFile {
+    pub fn new(path: Option<PathBuf>) -> Self {
+        Self {
+            path,
+            pending_sections: Default::default(),
+        }
+    }
+
+    /// Queue a `[section]` to be written to the config file, replacing any
+    /// earlier queued value for the same section.
+    pub fn queue_section<T: Serialize>(&self, section: &str, value: &T) -> anyhow::Result<()> {
+        let value = toml::Value::try_from(value)?;
+        self.pending_sections
+            .lock()
+            .unwrap()
+            .insert(section.to_string(), value);
+        Ok(())
+    }
+
+    /// Write all queued sections to the config file in a single write.
+    pub fn write_pending_sections(&self) -> anyhow::Result<()> {
+        let sections = std::mem::take(&mut *self.pending_sections.lock().unwrap());
+        if sections.is_empty() {
+            return Ok(());
+        }
+        self.write_sections(sections.into_iter().collect())
+    }
+
+    /// Replace a single `[section]` of the config file, leaving all other sections as they were.
+    pub fn save_section<T: Serialize>(&self, section: &str, value: &T) -> anyhow::Result<()> {
+        self.write_sections(toml::Table::from_iter([(
+            section.to_string(),
+            toml::Value::try_from(value)?,
+        )]))
+    }
+
+    fn write_sections(&self, sections: toml::Table) -> anyhow::Result<()> {
+        let Some(path) = self.path.as_ref() else {
+            // Started without a config file, nowhere to save to
+            return Ok(());
+        };
+
+        let toml_str = match std::fs::read_to_string(path) {
+            Ok(toml_str) => toml_str,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(error) => return Err(error.into()),
+        };
+        let mut document = toml_str.parse::<toml_edit::Document>()?;
+        let mut new_document = toml::to_string_pretty(&sections)?.parse::<toml_edit::Document>()?;
+        for (section, new_item) in std::mem::take(new_document.as_table_mut()) {
+            match document.get_mut(&section) {
+                Some(existing_item) => merge_toml_item(existing_item, new_item),
+                None => {
+                    document.insert(&section, new_item);
+                }
+            }
+        }
+        std::fs::write(path, document.to_string())?;
+        Ok(())
+    }
+}
+
+/// Copy the keys of `new` into `existing`, keeping the comments and layout of
+/// keys which were already in the file.
+fn merge_toml_table(existing: &mut toml_edit::Table, new: toml_edit::Table) {
+    existing.retain(|key, _| new.contains_key(key));
+    for (key, new_item) in new {
+        match existing.get_mut(&key) {
+            Some(existing_item) => merge_toml_item(existing_item, new_item),
+            None => {
+                existing.insert(&key, new_item);
+            }
+        }
+    }
+}
+
+fn merge_toml_item(existing: &mut toml_edit::Item, new: toml_edit::Item) {
+    match new {
+        toml_edit::Item::Table(new_table) if existing.is_table() => {
+            merge_toml_table(existing.as_table_mut().unwrap(), new_table);
+        }
+        toml_edit::Item::Value(mut new_value) => {
+            if let Some(existing_value) = existing.as_value() {
+                *new_value.decor_mut() = existing_value.decor().clone();
+            }
+            *existing = toml_edit::Item::Value(new_value);
+        }
+        new => *existing = new,
+    }
+}
+
+/// A settings resource which is persisted to its own section of the config file
+/// by `save_settings_system`.
+pub trait PersistentSettings: Resource + Serialize + Clone + PartialEq {
+    const CONFIG_SECTION: &'static str;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_saving_a_section_keeps_comments_and_other_sections() {
+        let path =
+            std::env::temp_dir().join(format!("rose_config_file_test_{}.toml", std::process::id()));
+        std::fs::write(
+            &path,
+            "# Client config\n[game]\nname = \"rose\" # player name\n\n[sound]\n# Master volume\nvolume = 0.5\nmuted = false\n",
+        )
+        .unwrap();
+
+        let config_file = ConfigFile::new(Some(path.clone()));
+        config_file
+            .queue_section(
+                "sound",
+                &toml::Table::from_iter([("volume".to_string(), 1.0.into())]),
+            )
+            .unwrap();
+        config_file
+            .queue_section(
+                "extra",
+                &toml::Table::from_iter([("on".to_string(), true.into())]),
+            )
+            .unwrap();
+        config_file.write_pending_sections().unwrap();
+
+        let saved = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(saved.starts_with("# Client config\n[game]\nname = \"rose\" # player name\n"));
+        assert!(saved.contains("# Master volume\nvolume = 1.0\n"));
+        assert!(!saved.contains("muted"));
+        assert!(saved.contains("[extra]\non = true\n"));
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::{HashSet, VecDeque};
+
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+use crate::resources::PersistentSettings;
+
+/// Most conversations kept in the session history, the oldest are dropped first
+const MAX_CONVERSATION_HISTORY: usize = 30;
+
+#[derive(Resource, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConversationSettings {
+    /// Characters of NPC text revealed per second, 0 shows the whole text at once
+    pub text_speed: f32,
+    /// Show text already read this session at once
+    pub skip_seen_text: bool,
+}
+
+impl Default for ConversationSettings {
+    fn default() -> Self {
+        Self {
+            text_speed: 80.0,
+            skip_seen_text: true,
+        }
+    }
+}
+
+impl PersistentSettings for ConversationSettings {
+    const CONFIG_SECTION: &'static str = "conversation";
+}
+
+#[derive(Clone)]
+pub struct ConversationLine {
+    pub text: String,
+    /// Chosen by the player rather than said by the NPC
+    pub is_response: bool,
+}
+
+pub struct ConversationRecord {
+    pub npc_name: String,
+    pub lines: Vec<ConversationLine>,
+}
+
+/// Conversations finished in the current zone, cleared on zone change
+#[derive(Resource, Default)]
+pub struct ConversationHistory {
+    /// Oldest first
+    pub conversations: VecDeque<ConversationRecord>,
+    seen_messages: HashSet<String>,
+}
+
+impl ConversationHistory {
+    pub fn push(&mut self, record: ConversationRecord) {
+        if record.lines.is_empty() {
+            return;
+        }
+
+        if self.conversations.len() == MAX_CONVERSATION_HISTORY {
+            self.conversations.pop_front();
+        }
+        self.conversations.push_back(record);
+    }
+
+    /// Remember the message was shown, returns true when it was shown before
+    pub fn mark_seen(&mut self, message: &str) -> bool {
+        !self.seen_messages.insert(message.to_string())
+    }
+
+    pub fn clear(&mut self) {
+        self.conversations.clear();
+        self.seen_messages.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(npc_name: &str) -> ConversationRecord {
+        ConversationRecord {
+            npc_name: npc_name.to_string(),
+            lines: vec![ConversationLine {
+                text: "Hello".to_string(),
+                is_response: false,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_history_drops_oldest_conversations() {
+        let mut history = ConversationHistory::default();
+        history.push(ConversationRecord {
+            npc_name: "Empty".to_string(),
+            lines: Vec::new(),
+        });
+        assert!(history.conversations.is_empty());
+
+        for index in 0..MAX_CONVERSATION_HISTORY + 1 {
+            history.push(record(&format!("Npc {}", index)));
+        }
+        assert_eq!(history.conversations.len(), MAX_CONVERSATION_HISTORY);
+        assert_eq!(history.conversations[0].npc_name, "Npc 1");
+    }
+
+    #[test]
+    fn test_messages_are_seen_until_cleared() {
+        let mut history = ConversationHistory::default();
+        assert!(!history.mark_seen("Welcome"));
+        assert!(history.mark_seen("Welcome"));
+
+        history.clear();
+        assert!(!history.mark_seen("Welcome"));
+    }
+}
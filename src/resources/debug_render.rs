@@ -3,6 +3,8 @@ use bevy::{
     prelude::{Color, Entity, Resource},
 };
 
+use crate::components::ZoneObject;
+
 /// Resource to track render extraction diagnostics between Main World and Render World
 #[derive(Resource, Default)]
 pub struct RenderExtractionDiagnostics {
@@ -24,6 +26,30 @@ const DEBUG_RENDER_COLOR_LIST: [Color; 8] = [
     Color::Srgba(Srgba::BLACK),
 ];
 
+/// Which zone content remains visible while isolating one kind of content
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum DebugRenderIsolation {
+    #[default]
+    All,
+    Terrain,
+    Objects,
+    Effects,
+}
+
+impl DebugRenderIsolation {
+    pub fn shows(&self, zone_object: &ZoneObject) -> bool {
+        match zone_object {
+            // Sound objects have nothing to render
+            ZoneObject::SoundObject { .. } => true,
+            ZoneObject::Terrain(_) | ZoneObject::Water => {
+                matches!(self, Self::All | Self::Terrain)
+            }
+            ZoneObject::EffectObject { .. } => matches!(self, Self::All | Self::Effects),
+            _ => matches!(self, Self::All | Self::Objects),
+        }
+    }
+}
+
 #[derive(Resource)]
 pub struct DebugRenderConfig {
     pub colliders: bool,
@@ -31,6 +57,13 @@ pub struct DebugRenderConfig {
     pub bone_up: bool,
     pub directional_light_frustum: bool,
     pub directional_light_frustum_freeze: bool,
+    /// Draw zone and effect meshes as wireframe
+    pub wireframe: bool,
+    /// Replace zone and effect meshes with an additive tint to show overdraw
+    pub overdraw: bool,
+    pub isolation: DebugRenderIsolation,
+    /// Draw what the cursor ray hits and which entity it picked
+    pub mouse_pick: bool,
 }
 
 impl Default for DebugRenderConfig {
@@ -41,11 +74,20 @@ impl Default for DebugRenderConfig {
             bone_up: true,       // Enable bone up vector debug rendering
             directional_light_frustum: true, // Enable directional light frustum debug rendering
             directional_light_frustum_freeze: false, // Don't freeze frustum by default
+            wireframe: false,
+            overdraw: false,
+            isolation: DebugRenderIsolation::All,
+            mouse_pick: false,
         }
     }
 }
 
 impl DebugRenderConfig {
+    /// True while any mode which changes how the zone is drawn is enabled
+    pub fn zone_render_mode_active(&self) -> bool {
+        self.wireframe || self.overdraw || self.isolation != DebugRenderIsolation::All
+    }
+
     pub fn color_for_entity(&self, entity: Entity) -> Color {
         DEBUG_RENDER_COLOR_LIST[entity.index_u32() as usize % DEBUG_RENDER_COLOR_LIST.len()]
     }
@@ -0,0 +1,70 @@
+use bevy::prelude::{Entity, Resource};
+use serde::{Deserialize, Serialize};
+
+use crate::resources::PersistentSettings;
+
+pub const MIN_DECAL_LIFETIME: f32 = 2.0;
+pub const MAX_DECAL_LIFETIME: f32 = 120.0;
+
+#[derive(Resource, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DecalSettings {
+    /// Maximum number of transient decals across all kinds, the oldest are
+    /// recycled first once the cap is reached
+    pub max_decals: usize,
+    /// Seconds a decal lives for when its kind does not set its own lifetime
+    pub decal_lifetime: f32,
+}
+
+impl Default for DecalSettings {
+    fn default() -> Self {
+        Self {
+            max_decals: 200,
+            decal_lifetime: 20.0,
+        }
+    }
+}
+
+impl DecalSettings {
+    pub fn lifetime(&self) -> f32 {
+        self.decal_lifetime.clamp(MIN_DECAL_LIFETIME, MAX_DECAL_LIFETIME)
+    }
+}
+
+impl PersistentSettings for DecalSettings {
+    const CONFIG_SECTION: &'static str = "decals";
+}
+
+/// Decals to recycle so no more than `max_decals` remain, oldest first.
+///
+/// Takes `(entity, spawned_at)` pairs in any order.
+pub fn decals_over_cap(mut decals: Vec<(Entity, f32)>, max_decals: usize) -> Vec<Entity> {
+    if decals.len() <= max_decals {
+        return Vec::new();
+    }
+
+    decals.sort_by(|a, b| a.1.total_cmp(&b.1));
+    let excess = decals.len() - max_decals;
+    decals
+        .into_iter()
+        .take(excess)
+        .map(|(entity, _)| entity)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decals_over_cap_recycles_oldest_first() {
+        let a = Entity::from_raw_u32(1).unwrap();
+        let b = Entity::from_raw_u32(2).unwrap();
+        let c = Entity::from_raw_u32(3).unwrap();
+        let decals = vec![(a, 5.0), (b, 1.0), (c, 3.0)];
+
+        assert!(decals_over_cap(decals.clone(), 3).is_empty());
+        assert_eq!(decals_over_cap(decals.clone(), 2), vec![b]);
+        assert_eq!(decals_over_cap(decals, 1), vec![b, c]);
+    }
+}
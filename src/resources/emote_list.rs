@@ -0,0 +1,84 @@
+use bevy::prelude::Resource;
+
+use rose_data::{MotionId, SkillType};
+
+use crate::resources::GameData;
+
+pub struct Emote {
+    pub name: String,
+    /// Chat command which plays the emote, without the leading /
+    pub command: String,
+    pub motion_id: MotionId,
+}
+
+/// Emotes which can be played from the emote wheel or chat, taken from the
+/// emote skills whose motion exists in the character motion database.
+#[derive(Resource, Default)]
+pub struct EmoteList {
+    pub emotes: Vec<Emote>,
+}
+
+/// Chat command for an emote name, e.g. "Say Hello!" is /sayhello
+fn emote_command(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(|c| c.to_lowercase())
+        .collect()
+}
+
+impl EmoteList {
+    pub fn new(game_data: &GameData) -> Self {
+        let mut emotes: Vec<Emote> = Vec::new();
+
+        for skill_data in game_data.skills.iter() {
+            if !matches!(skill_data.skill_type, SkillType::Emote) {
+                continue;
+            }
+
+            let Some(motion_id) = skill_data.action_motion_id else {
+                continue;
+            };
+
+            let command = emote_command(&skill_data.name);
+            if command.is_empty()
+                || game_data
+                    .character_motion_database
+                    .find_first_character_motion(motion_id, 0, 0)
+                    .is_none()
+                || emotes
+                    .iter()
+                    .any(|emote| emote.motion_id == motion_id || emote.command == command)
+            {
+                continue;
+            }
+
+            emotes.push(Emote {
+                name: skill_data.name.to_string(),
+                command,
+                motion_id,
+            });
+        }
+
+        Self { emotes }
+    }
+
+    /// Emote played by a chat command such as "/wave"
+    pub fn find_command(&self, text: &str) -> Option<&Emote> {
+        let command = text.trim().strip_prefix('/')?;
+        self.emotes
+            .iter()
+            .find(|emote| emote.command.eq_ignore_ascii_case(command))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_emote_command_from_name() {
+        assert_eq!(emote_command("Wave"), "wave");
+        assert_eq!(emote_command("Say Hello!"), "sayhello");
+        assert_eq!(emote_command("???"), "");
+    }
+}
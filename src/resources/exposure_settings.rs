@@ -0,0 +1,81 @@
+use bevy::{
+    camera::Exposure,
+    post_process::auto_exposure::AutoExposure,
+    prelude::Resource,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::resources::PersistentSettings;
+
+pub const MIN_EXPOSURE_EV: f32 = -8.0;
+pub const MAX_EXPOSURE_EV: f32 = 8.0;
+
+#[derive(Resource, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExposureSettings {
+    /// Adapt the exposure to the brightness of the scene
+    pub auto_exposure: bool,
+    /// Exposure change in EV per second when the scene gets brighter, adapting
+    /// to darkness is slower like the eye
+    pub adaptation_speed: f32,
+    /// Lowest and highest exposure auto exposure may choose, in EV
+    pub min_ev: f32,
+    pub max_ev: f32,
+    /// Camera exposure used when auto exposure is off
+    pub manual_ev100: f32,
+}
+
+impl Default for ExposureSettings {
+    fn default() -> Self {
+        Self {
+            auto_exposure: true,
+            adaptation_speed: 3.0,
+            min_ev: MIN_EXPOSURE_EV,
+            max_ev: MAX_EXPOSURE_EV,
+            manual_ev100: Exposure::default().ev100,
+        }
+    }
+}
+
+impl ExposureSettings {
+    pub fn auto_exposure_component(&self) -> AutoExposure {
+        let min_ev = self.min_ev.clamp(MIN_EXPOSURE_EV, MAX_EXPOSURE_EV);
+        let max_ev = self.max_ev.clamp(min_ev, MAX_EXPOSURE_EV);
+        let speed = self.adaptation_speed.max(0.1);
+
+        AutoExposure {
+            range: min_ev..=max_ev,
+            speed_brighten: speed,
+            speed_darken: speed / 3.0,
+            ..Default::default()
+        }
+    }
+
+    pub fn manual_exposure(&self) -> Exposure {
+        Exposure {
+            ev100: self.manual_ev100,
+        }
+    }
+}
+
+impl PersistentSettings for ExposureSettings {
+    const CONFIG_SECTION: &'static str = "exposure";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auto_exposure_range_is_ordered() {
+        let settings = ExposureSettings {
+            min_ev: 4.0,
+            max_ev: -20.0,
+            ..Default::default()
+        };
+        let auto_exposure = settings.auto_exposure_component();
+        assert_eq!(*auto_exposure.range.start(), 4.0);
+        assert_eq!(*auto_exposure.range.end(), 4.0);
+        assert!(auto_exposure.speed_darken < auto_exposure.speed_brighten);
+    }
+}
@@ -0,0 +1,77 @@
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+use crate::resources::PersistentSettings;
+
+pub const MIN_FOG_DISTANCE_SCALE: f32 = 0.5;
+/// Fog further than this would stop hiding the edge of the render distance
+pub const MAX_FOG_DISTANCE_SCALE: f32 = 3.0;
+pub const MIN_FOG_STRENGTH: f32 = 0.5;
+pub const MAX_FOG_STRENGTH: f32 = 1.5;
+
+/// Lowest fog opacity at the far distance, zones rely on it to hide where the
+/// terrain ends
+const MIN_FOG_MAX_DENSITY: f32 = 0.4;
+
+#[derive(Resource, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FogSettings {
+    pub use_zone_default: bool,
+    /// How much further the fog starts than the zone default
+    pub distance_scale: f32,
+    /// Scale of how opaque the fog gets
+    pub strength: f32,
+}
+
+impl Default for FogSettings {
+    fn default() -> Self {
+        Self {
+            use_zone_default: true,
+            distance_scale: 1.0,
+            strength: 1.0,
+        }
+    }
+}
+
+impl FogSettings {
+    /// Fog density, min density and max density for the zone's own values
+    pub fn apply(&self, density: f32, min_density: f32, max_density: f32) -> (f32, f32, f32) {
+        if self.use_zone_default {
+            return (density, min_density, max_density);
+        }
+
+        let distance_scale = self
+            .distance_scale
+            .clamp(MIN_FOG_DISTANCE_SCALE, MAX_FOG_DISTANCE_SCALE);
+        let strength = self.strength.clamp(MIN_FOG_STRENGTH, MAX_FOG_STRENGTH);
+        let max_density = (max_density * strength).clamp(MIN_FOG_MAX_DENSITY.min(max_density), 1.0);
+        (
+            density / distance_scale,
+            (min_density * strength).min(max_density),
+            max_density,
+        )
+    }
+}
+
+impl PersistentSettings for FogSettings {
+    const CONFIG_SECTION: &'static str = "fog";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_is_clamped() {
+        let mut settings = FogSettings::default();
+        assert_eq!(settings.apply(0.002, 0.0, 0.75), (0.002, 0.0, 0.75));
+
+        settings.use_zone_default = false;
+        settings.distance_scale = 100.0;
+        settings.strength = 0.0;
+        let (density, min_density, max_density) = settings.apply(0.003, 0.1, 0.75);
+        assert_eq!(density, 0.003 / MAX_FOG_DISTANCE_SCALE);
+        assert_eq!(max_density, 0.4);
+        assert!(min_density <= max_density);
+    }
+}
@@ -0,0 +1,59 @@
+use bevy::prelude::{Entity, Resource};
+
+use rose_data::ZoneId;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum FriendStatus {
+    /// Not nearby and not in our clan, the server does not tell us about friends
+    #[default]
+    Unknown,
+    Offline,
+    Online {
+        /// Known when the friend is nearby, we only ever see our own zone
+        zone_id: Option<ZoneId>,
+    },
+}
+
+pub struct Friend {
+    pub name: String,
+    pub status: FriendStatus,
+    /// The friend's character when it is nearby, used to invite them to a party
+    pub nearby_entity: Option<Entity>,
+}
+
+/// Friends added this session. The list is local to the client, it is not
+/// synced with the server and is lost when the game is closed
+#[derive(Default, Resource)]
+pub struct FriendsList {
+    pub friends: Vec<Friend>,
+}
+
+impl FriendsList {
+    pub fn contains(&self, name: &str) -> bool {
+        self.friends
+            .iter()
+            .any(|friend| friend.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Returns false when the name is empty or already a friend
+    pub fn add(&mut self, name: &str) -> bool {
+        let name = name.trim();
+        if name.is_empty() || self.contains(name) {
+            return false;
+        }
+
+        self.friends.push(Friend {
+            name: name.to_string(),
+            status: FriendStatus::Unknown,
+            nearby_entity: None,
+        });
+        self.friends
+            .sort_by_key(|friend| friend.name.to_ascii_lowercase());
+        true
+    }
+
+    pub fn remove(&mut self, name: &str) {
+        self.friends
+            .retain(|friend| !friend.name.eq_ignore_ascii_case(name));
+    }
+}
@@ -1,4 +1,4 @@
-use bevy::prelude::{Resource, Transform};
+use bevy::prelude::Resource;
 use std::sync::Arc;
 
 use rose_data::{
@@ -31,5 +31,4 @@ pub struct GameData {
     pub zsc_event_object: ZscFile,
     pub zsc_special_object: ZscFile,
     pub stb_morph_object: StbFile,
-    pub character_select_positions: Vec<Transform>,
 }
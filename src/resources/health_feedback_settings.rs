@@ -0,0 +1,70 @@
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+use crate::resources::PersistentSettings;
+
+/// Seconds for the damage pulse to fade out
+pub const DAMAGE_PULSE_SECONDS: f32 = 0.5;
+
+#[derive(Resource, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HealthFeedbackSettings {
+    /// Tint the screen edges red when the player is hurt
+    pub enabled: bool,
+    /// Fraction of max HP below which the vignette starts to show
+    pub low_health_threshold: f32,
+    /// Opacity of the vignette at its strongest
+    pub intensity: f32,
+    /// Briefly strengthen the vignette when the player takes damage
+    pub damage_pulse: bool,
+}
+
+impl Default for HealthFeedbackSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            low_health_threshold: 0.3,
+            intensity: 0.6,
+            damage_pulse: true,
+        }
+    }
+}
+
+impl HealthFeedbackSettings {
+    /// Vignette strength from 0 to 1 for the player's HP fraction, zero at or
+    /// above the threshold and full strength at no HP
+    pub fn low_health_strength(&self, health_fraction: f32) -> f32 {
+        if !self.enabled || self.low_health_threshold <= 0.0 {
+            return 0.0;
+        }
+
+        (1.0 - health_fraction / self.low_health_threshold).clamp(0.0, 1.0)
+    }
+}
+
+impl PersistentSettings for HealthFeedbackSettings {
+    const CONFIG_SECTION: &'static str = "health_feedback";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_low_health_strength_grows_below_threshold() {
+        let settings = HealthFeedbackSettings {
+            low_health_threshold: 0.4,
+            ..Default::default()
+        };
+        assert_eq!(settings.low_health_strength(1.0), 0.0);
+        assert_eq!(settings.low_health_strength(0.4), 0.0);
+        assert!(settings.low_health_strength(0.1) > settings.low_health_strength(0.3));
+        assert_eq!(settings.low_health_strength(0.0), 1.0);
+
+        let disabled = HealthFeedbackSettings {
+            enabled: false,
+            ..settings
+        };
+        assert_eq!(disabled.low_health_strength(0.0), 0.0);
+    }
+}
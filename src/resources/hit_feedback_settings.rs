@@ -0,0 +1,106 @@
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+use crate::resources::PersistentSettings;
+
+/// Seconds a hit target flashes and holds its animation for
+pub const HIT_FLASH_SECONDS: f32 = 0.12;
+
+/// Shake trauma lost per second, a full strength shake settles in half a second
+const CAMERA_SHAKE_DECAY: f32 = 2.0;
+
+#[derive(Resource, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HitFeedbackSettings {
+    pub enabled: bool,
+    /// Spawn the hit effect at the point of impact, scaled by the damage
+    pub impact_effects: bool,
+    /// Briefly flash the target and hold its animation
+    pub hit_flash: bool,
+    /// Shake the camera when the player takes damage
+    pub camera_shake: bool,
+    pub shake_intensity: f32,
+}
+
+impl Default for HitFeedbackSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            impact_effects: true,
+            hit_flash: true,
+            camera_shake: true,
+            shake_intensity: 1.0,
+        }
+    }
+}
+
+/// Fraction of the target's max HP taken by a hit
+fn damage_fraction(damage: u32, max_health: i32) -> f32 {
+    (damage as f32 / max_health.max(1) as f32).clamp(0.0, 1.0)
+}
+
+impl HitFeedbackSettings {
+    /// Scale of the impact effect, larger for heavier hits and crits
+    pub fn impact_scale(&self, damage: u32, max_health: i32, is_critical: bool) -> f32 {
+        let scale = 0.8 + damage_fraction(damage, max_health) * 2.0;
+        let scale = if is_critical { scale * 1.5 } else { scale };
+        scale.clamp(0.8, 2.5)
+    }
+
+    /// Camera shake trauma to add when the player takes `damage`
+    pub fn shake_trauma(&self, damage: u32, max_health: i32) -> f32 {
+        if !self.enabled || !self.camera_shake {
+            return 0.0;
+        }
+
+        // Even small hits should be felt, so ease out rather than scale linearly
+        (damage_fraction(damage, max_health) * 4.0).sqrt().min(1.0) * self.shake_intensity
+    }
+}
+
+impl PersistentSettings for HitFeedbackSettings {
+    const CONFIG_SECTION: &'static str = "hit_feedback";
+}
+
+/// Accumulated camera shake, added to by hits on the player and applied by
+/// `camera_shake_system`
+#[derive(Resource, Default)]
+pub struct CameraShake {
+    pub trauma: f32,
+}
+
+impl CameraShake {
+    pub fn add_trauma(&mut self, trauma: f32) {
+        self.trauma = (self.trauma + trauma).min(1.0);
+    }
+
+    pub fn decay(&mut self, delta: f32) {
+        self.trauma = (self.trauma - CAMERA_SHAKE_DECAY * delta).max(0.0);
+    }
+
+    /// Shake strength from 0 to 1, squared so light trauma barely moves the camera
+    pub fn strength(&self) -> f32 {
+        self.trauma * self.trauma
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shake_scales_with_damage() {
+        let settings = HitFeedbackSettings::default();
+        assert_eq!(settings.shake_trauma(0, 100), 0.0);
+        assert!(settings.shake_trauma(5, 100) < settings.shake_trauma(20, 100));
+        assert_eq!(settings.shake_trauma(100, 100), 1.0);
+
+        let disabled = HitFeedbackSettings {
+            camera_shake: false,
+            ..Default::default()
+        };
+        assert_eq!(disabled.shake_trauma(100, 100), 0.0);
+
+        assert!(settings.impact_scale(10, 100, true) > settings.impact_scale(10, 100, false));
+    }
+}
@@ -0,0 +1,77 @@
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+use crate::resources::PersistentSettings;
+
+/// Seconds for the idle orbit to ease from still to full speed
+const IDLE_CAMERA_EASE_SECONDS: f32 = 3.0;
+
+#[derive(Resource, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IdleCameraSettings {
+    pub enabled: bool,
+    /// Seconds without input before the camera starts to orbit the player
+    pub idle_seconds: f32,
+    /// Orbit speed in degrees per second
+    pub orbit_speed: f32,
+}
+
+impl Default for IdleCameraSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            idle_seconds: 60.0,
+            orbit_speed: 6.0,
+        }
+    }
+}
+
+impl IdleCameraSettings {
+    /// Fraction of the orbit speed to use after `idle_for` seconds without input,
+    /// zero until the idle period has passed then easing up to one
+    pub fn orbit_ramp(&self, idle_for: f32) -> f32 {
+        if !self.enabled || idle_for < self.idle_seconds {
+            return 0.0;
+        }
+
+        let t = ((idle_for - self.idle_seconds) / IDLE_CAMERA_EASE_SECONDS).min(1.0);
+        t * t * (3.0 - 2.0 * t)
+    }
+}
+
+impl PersistentSettings for IdleCameraSettings {
+    const CONFIG_SECTION: &'static str = "idle_camera";
+}
+
+/// Time of the last keyboard or mouse input, updated by `input_activity_system`
+#[derive(Resource, Default)]
+pub struct InputActivity {
+    pub last_input_time: f32,
+}
+
+impl InputActivity {
+    pub fn idle_for(&self, now: f32) -> f32 {
+        (now - self.last_input_time).max(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_orbit_ramp_eases_in_after_idle_period() {
+        let settings = IdleCameraSettings {
+            enabled: true,
+            idle_seconds: 10.0,
+            orbit_speed: 6.0,
+        };
+        assert_eq!(settings.orbit_ramp(5.0), 0.0);
+        assert_eq!(settings.orbit_ramp(10.0), 0.0);
+        assert!(settings.orbit_ramp(11.0) > 0.0 && settings.orbit_ramp(11.0) < 1.0);
+        assert_eq!(settings.orbit_ramp(100.0), 1.0);
+
+        let disabled = IdleCameraSettings::default();
+        assert_eq!(disabled.orbit_ramp(1000.0), 0.0);
+    }
+}
@@ -0,0 +1,107 @@
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+use crate::resources::PersistentSettings;
+
+/// Levels accepted by the log filter, from least to most verbose
+pub const LOG_LEVELS: [&str; 6] = ["off", "error", "warn", "info", "debug", "trace"];
+
+/// Log level for a module and everything below it, e.g. `rose_offline_client::zone_loader`
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModuleLogLevel {
+    pub module: String,
+    pub level: String,
+}
+
+impl ModuleLogLevel {
+    pub fn new(module: &str, level: &str) -> Self {
+        Self {
+            module: module.to_string(),
+            level: level.to_string(),
+        }
+    }
+}
+
+#[derive(Resource, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LogSettings {
+    /// Level for every module without an override
+    pub level: String,
+    pub modules: Vec<ModuleLogLevel>,
+}
+
+impl Default for LogSettings {
+    fn default() -> Self {
+        Self {
+            level: "info".to_string(),
+            modules: vec![
+                ModuleLogLevel::new("wgpu", "error"),
+                ModuleLogLevel::new("naga", "error"),
+                ModuleLogLevel::new("offset_allocator", "warn"),
+                ModuleLogLevel::new("rose_offline_client::map_editor", "info"),
+            ],
+        }
+    }
+}
+
+impl LogSettings {
+    /// Filter in the `tracing_subscriber::EnvFilter` syntax
+    pub fn filter(&self) -> String {
+        std::iter::once(self.level.clone())
+            .chain(
+                self.modules
+                    .iter()
+                    .filter(|module| !module.module.is_empty())
+                    .map(|module| format!("{}={}", module.module, module.level)),
+            )
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    pub fn module_level(&self, module: &str) -> Option<&str> {
+        self.modules
+            .iter()
+            .find(|module_level| module_level.module == module)
+            .map(|module_level| module_level.level.as_str())
+    }
+
+    pub fn set_module_level(&mut self, module: &str, level: &str) {
+        if let Some(module_level) = self
+            .modules
+            .iter_mut()
+            .find(|module_level| module_level.module == module)
+        {
+            module_level.level = level.to_string();
+        } else {
+            self.modules.push(ModuleLogLevel::new(module, level));
+        }
+    }
+}
+
+impl PersistentSettings for LogSettings {
+    const CONFIG_SECTION: &'static str = "logging";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter() {
+        let mut settings = LogSettings {
+            level: "warn".to_string(),
+            modules: Vec::new(),
+        };
+        assert_eq!(settings.filter(), "warn");
+
+        settings.set_module_level("rose_offline_client::zone_loader", "error");
+        settings.set_module_level("wgpu", "off");
+        settings.set_module_level("rose_offline_client::zone_loader", "debug");
+        assert_eq!(
+            settings.filter(),
+            "warn,rose_offline_client::zone_loader=debug,wgpu=off"
+        );
+        assert_eq!(settings.module_level("wgpu"), Some("off"));
+        assert_eq!(settings.module_level("naga"), None);
+    }
+}
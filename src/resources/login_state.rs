@@ -7,4 +7,9 @@ pub enum LoginState {
     WaitServerList,
     ServerSelect,
     JoiningServer,
+    Reconnecting {
+        attempt: u32,
+        max_attempts: u32,
+        remaining_secs: f32,
+    },
 }
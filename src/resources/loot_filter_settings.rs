@@ -0,0 +1,194 @@
+use bevy::prelude::{Color, KeyCode, Resource};
+use enum_map::{enum_map, Enum, EnumMap};
+use serde::{Deserialize, Serialize};
+
+use rose_data::{Item, ItemType};
+use rose_game_common::components::DroppedItem;
+
+use crate::resources::{GameData, PersistentSettings};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Enum, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LootRarity {
+    Common,
+    Rare,
+    Unique,
+    Money,
+}
+
+impl LootRarity {
+    /// Rarity of a dropped item, using the same `rare_type` ranges as the item name colours
+    pub fn from_dropped_item(dropped_item: &DroppedItem, game_data: &GameData) -> Self {
+        let item = match dropped_item {
+            DroppedItem::Item(item) => item,
+            DroppedItem::Money(_) => return LootRarity::Money,
+        };
+
+        let Item::Equipment(_) = item else {
+            return LootRarity::Common;
+        };
+
+        if !matches!(
+            item.get_item_type(),
+            ItemType::Head
+                | ItemType::Body
+                | ItemType::Hands
+                | ItemType::Feet
+                | ItemType::Weapon
+                | ItemType::SubWeapon
+        ) {
+            return LootRarity::Common;
+        }
+
+        match game_data
+            .items
+            .get_base_item(item.get_item_reference())
+            .map(|item_data| item_data.rare_type)
+        {
+            Some(1..=20) => LootRarity::Rare,
+            Some(21) => LootRarity::Unique,
+            _ => LootRarity::Common,
+        }
+    }
+
+    pub fn beam_color(&self) -> Color {
+        match self {
+            LootRarity::Common => Color::srgb(1.0, 1.0, 1.0),
+            LootRarity::Rare => Color::srgb(0.0, 1.0, 1.0),
+            LootRarity::Unique => Color::srgb(1.0, 0.5, 1.0),
+            LootRarity::Money => Color::srgb(1.0, 1.0, 0.0),
+        }
+    }
+}
+
+/// Keys loot all can be bound to
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LootAllKey {
+    Z,
+    F,
+    H,
+    J,
+    K,
+    L,
+    R,
+    U,
+    Y,
+}
+
+impl LootAllKey {
+    pub const ALL: [LootAllKey; 9] = [
+        LootAllKey::Z,
+        LootAllKey::F,
+        LootAllKey::H,
+        LootAllKey::J,
+        LootAllKey::K,
+        LootAllKey::L,
+        LootAllKey::R,
+        LootAllKey::U,
+        LootAllKey::Y,
+    ];
+
+    pub fn key_code(&self) -> KeyCode {
+        match self {
+            LootAllKey::Z => KeyCode::KeyZ,
+            LootAllKey::F => KeyCode::KeyF,
+            LootAllKey::H => KeyCode::KeyH,
+            LootAllKey::J => KeyCode::KeyJ,
+            LootAllKey::K => KeyCode::KeyK,
+            LootAllKey::L => KeyCode::KeyL,
+            LootAllKey::R => KeyCode::KeyR,
+            LootAllKey::U => KeyCode::KeyU,
+            LootAllKey::Y => KeyCode::KeyY,
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            LootAllKey::Z => "Z",
+            LootAllKey::F => "F",
+            LootAllKey::H => "H",
+            LootAllKey::J => "J",
+            LootAllKey::K => "K",
+            LootAllKey::L => "L",
+            LootAllKey::R => "R",
+            LootAllKey::U => "U",
+            LootAllKey::Y => "Y",
+        }
+    }
+}
+
+#[derive(Resource, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LootFilterSettings {
+    /// Show the item drop name, hidden names are still shown whilst Alt is held
+    pub show_name: EnumMap<LootRarity, bool>,
+    /// Show a vertical light beam above the item drop
+    pub show_beam: EnumMap<LootRarity, bool>,
+    /// Distance from the camera beyond which beams are hidden
+    pub beam_max_distance: f32,
+    /// Width of the band before `beam_max_distance` over which beams fade out
+    pub beam_fade_distance: f32,
+    /// Picked up by loot all and auto-loot, filtered items are still picked up
+    /// by loot all whilst Shift is held
+    pub pickup: EnumMap<LootRarity, bool>,
+    /// Key which picks up every wanted item drop within `loot_all_radius`
+    pub loot_all_key: LootAllKey,
+    /// Distance from the player within which loot all picks up item drops, in metres
+    pub loot_all_radius: f32,
+    /// Pick up wanted item drops automatically when walking over them
+    pub auto_loot: bool,
+    /// Distance from the player within which auto-loot picks up item drops, in metres
+    pub auto_loot_radius: f32,
+    /// Open a Need/Greed/Pass window for equipment looted in a party. The
+    /// rolls are local and only posted to party chat, the looter keeps the item
+    pub party_loot_rolls: bool,
+}
+
+impl Default for LootFilterSettings {
+    fn default() -> Self {
+        Self {
+            show_name: enum_map! {
+                LootRarity::Common => true,
+                LootRarity::Rare => true,
+                LootRarity::Unique => true,
+                LootRarity::Money => true,
+            },
+            show_beam: enum_map! {
+                LootRarity::Common => false,
+                LootRarity::Rare => true,
+                LootRarity::Unique => true,
+                LootRarity::Money => false,
+            },
+            beam_max_distance: 80.0,
+            beam_fade_distance: 20.0,
+            pickup: enum_map! {
+                LootRarity::Common => false,
+                LootRarity::Rare => true,
+                LootRarity::Unique => true,
+                LootRarity::Money => true,
+            },
+            loot_all_key: LootAllKey::Z,
+            loot_all_radius: 10.0,
+            auto_loot: false,
+            auto_loot_radius: 1.5,
+            party_loot_rolls: false,
+        }
+    }
+}
+
+impl LootFilterSettings {
+    /// Opacity of a beam at the given distance from the camera
+    pub fn get_beam_opacity(&self, distance: f32) -> f32 {
+        if distance >= self.beam_max_distance {
+            0.0
+        } else if self.beam_fade_distance <= 0.0 {
+            1.0
+        } else {
+            ((self.beam_max_distance - distance) / self.beam_fade_distance).clamp(0.0, 1.0)
+        }
+    }
+}
+
+impl PersistentSettings for LootFilterSettings {
+    const CONFIG_SECTION: &'static str = "loot_filter";
+}
@@ -1,39 +1,83 @@
+mod accessibility_settings;
 mod account;
+mod afk_settings;
 mod app_state;
+mod auto_potion_settings;
+mod bank_settings;
+mod asset_hot_reload;
 mod blood_decal_atlas;
 mod blood_effect_config;
 mod blood_effect_runtime;
+mod blood_settings;
+mod camera_motion_settings;
+mod character_idle_settings;
+mod chat_bubble_settings;
+mod chatter_settings;
 mod character_list;
+mod character_name_rules;
+mod character_select_scene;
 mod character_select_state;
 mod client_entity_list;
+mod config_file;
+mod conversation_settings;
 mod current_zone;
 mod damage_digits_spawner;
+mod decal_settings;
 mod debug_inspector;
 mod debug_render;
+mod emote_list;
+mod exposure_settings;
 mod flight_settings;
+mod fog_settings;
+mod friends_list;
 mod game_connection;
 mod game_data;
+mod health_feedback_settings;
+mod hit_feedback_settings;
+mod idle_camera;
+mod log_settings;
 mod login_camera_animation;
 mod login_connection;
 mod login_state;
+mod loot_filter_settings;
 mod monster_chatter_phrases;
+mod mouse_pick_settings;
 mod name_tag_cache;
 mod name_tag_settings;
+mod network_logging;
+mod network_movement_settings;
+mod network_stats;
 mod network_thread;
+mod npc_store_settings;
+mod occlusion_culling_settings;
+mod particle_budget;
+mod party_marker_settings;
+mod projectile_settings;
+mod quick_use_settings;
+mod reconnect_session;
 mod render_configuration;
 mod season_materials;
 mod season_settings;
+mod rng_seed;
 mod selected_target;
 mod server_configuration;
 mod server_list;
 mod sound_cache;
 mod sound_settings;
 mod specular_texture;
+mod texture_filtering_settings;
+mod time_scale_settings;
+mod trail_settings;
+mod ui_layout_settings;
+mod ui_scale;
 mod ui_resources;
+mod ui_sound_settings;
 mod virtual_filesystem;
 mod water_settings;
+mod weather_override;
 mod wind_state;
 mod world_connection;
+mod world_pings;
 mod world_rates;
 mod world_time;
 mod zone_time;
@@ -42,43 +86,110 @@ mod zone_time;
 pub mod zone_debug_diagnostics;
 
 pub use account::Account;
+pub use afk_settings::{afk_auto_reply, is_afk_auto_reply, AfkSettings};
 pub use app_state::AppState;
+pub use auto_potion_settings::{AutoPotionNotice, AutoPotionSettings};
+pub use bank_settings::{BankSettings, BankSort};
+pub use asset_hot_reload::{AssetHotReload, ASSET_HOT_RELOAD_DEBOUNCE};
+pub use accessibility_settings::{
+    current_accessibility, set_current_accessibility, AccessibilitySettings, ColorblindMode,
+};
 pub use blood_decal_atlas::BloodDecalAtlas;
 pub use blood_effect_config::BloodEffectConfig;
 pub use blood_effect_runtime::{BloodEffectDiagnostics, BloodEffectRuntime};
+pub use blood_settings::{BloodIntensity, BloodSettings};
+pub use camera_motion_settings::CameraMotionSettings;
+pub use character_idle_settings::CharacterIdleSettings;
+pub use chat_bubble_settings::{chat_bubble_stack_offsets, ChatBubbleSettings};
+pub use chatter_settings::{ChatterSettings, ZoneChatterFrequency};
 pub use character_list::CharacterList;
+pub use character_name_rules::{CharacterNameError, CharacterNameRules};
+pub use character_select_scene::{
+    CharacterSelectCamera, CharacterSelectPosition, CharacterSelectScene,
+    DEFAULT_CHARACTER_SELECT_CAMERA_MOTION, DEFAULT_CHARACTER_SELECT_ZONE,
+};
 pub use character_select_state::CharacterSelectState;
 pub use client_entity_list::ClientEntityList;
+pub use config_file::{ConfigFile, PersistentSettings};
+pub use conversation_settings::{
+    ConversationHistory, ConversationLine, ConversationRecord, ConversationSettings,
+};
 pub use current_zone::CurrentZone;
 pub use damage_digits_spawner::{DamageDigitsSpawner, PendingDamageDigitMaterial};
+pub use decal_settings::{
+    decals_over_cap, DecalSettings, MAX_DECAL_LIFETIME, MIN_DECAL_LIFETIME,
+};
 pub use debug_inspector::DebugInspector;
-pub use debug_render::{DebugRenderConfig, RenderExtractionDiagnostics};
+pub use debug_render::{DebugRenderConfig, DebugRenderIsolation, RenderExtractionDiagnostics};
+pub use emote_list::{Emote, EmoteList};
+pub use exposure_settings::{ExposureSettings, MAX_EXPOSURE_EV, MIN_EXPOSURE_EV};
+pub use friends_list::{Friend, FriendStatus, FriendsList};
 pub use flight_settings::FlightSettings;
+pub use fog_settings::{
+    FogSettings, MAX_FOG_DISTANCE_SCALE, MAX_FOG_STRENGTH, MIN_FOG_DISTANCE_SCALE, MIN_FOG_STRENGTH,
+};
 pub use game_connection::GameConnection;
 pub use game_data::GameData;
+pub use health_feedback_settings::{HealthFeedbackSettings, DAMAGE_PULSE_SECONDS};
+pub use hit_feedback_settings::{CameraShake, HitFeedbackSettings, HIT_FLASH_SECONDS};
+pub use idle_camera::{IdleCameraSettings, InputActivity};
+pub use log_settings::{LogSettings, ModuleLogLevel, LOG_LEVELS};
 pub use login_camera_animation::LoginCameraAnimation;
 pub use login_connection::LoginConnection;
 pub use login_state::LoginState;
+pub use loot_filter_settings::{LootAllKey, LootFilterSettings, LootRarity};
 pub use monster_chatter_phrases::MonsterChatterPhrases;
+pub use mouse_pick_settings::{
+    MousePickDebug, MousePickSettings, PickContext, PickFilter, PickGroup, PickPriority,
+};
 pub use name_tag_settings::NameTagSettings;
+pub use network_logging::NetworkLogging;
+pub use network_movement_settings::NetworkMovementSettings;
+pub use network_stats::{
+    NetworkConnectionState, NetworkConnectionStats, NetworkStats,
+    NETWORK_PACKET_TIMING_HISTORY_LEN,
+};
 pub use network_thread::{run_network_thread, NetworkThread, NetworkThreadMessage};
+pub use npc_store_settings::NpcStoreSettings;
+pub use occlusion_culling_settings::{OcclusionCullingSettings, OcclusionCullingState};
+pub use particle_budget::{ParticleBudget, ParticleBudgetStats};
+pub use party_marker_settings::PartyMarkerSettings;
+pub use projectile_settings::{
+    projectile_arc_offset, projectile_flight_time, ProjectileMotion, ProjectileSettings,
+    MAX_PROJECTILE_FLIGHT_TIME,
+};
+pub use quick_use_settings::{QuickUseKey, QuickUseSettings, QUICK_USE_NUM_SLOTS};
+pub use reconnect_session::{ReconnectSession, ReconnectStage, ReconnectState};
 pub use render_configuration::RenderConfiguration;
 pub use season_materials::{setup_season_materials, SeasonMaterials};
 pub use season_settings::{FallSettings, SeasonSettings, SpringSettings, SummerSettings, WinterSettings};
+pub use rng_seed::RngSeed;
 pub use selected_target::SelectedTarget;
 pub use server_configuration::ServerConfiguration;
 pub use server_list::{ServerList, ServerListGameServer, ServerListWorldServer};
 pub use sound_cache::SoundCache;
 pub use sound_settings::SoundSettings;
 pub use specular_texture::SpecularTexture;
+pub use texture_filtering_settings::{
+    current_anisotropy, set_current_anisotropy, TextureFilteringSettings, ANISOTROPY_LEVELS,
+};
+pub use time_scale_settings::TimeScaleSettings;
+pub use trail_settings::{TrailQuality, TrailSettings};
+pub use ui_layout_settings::{
+    UiLayout, UiLayoutSettings, UiWindowLayout, DEFAULT_UI_LAYOUT_SLOT,
+};
+pub use ui_scale::{UiScale, UiScaleMode, MAX_UI_SCALE, MIN_UI_SCALE};
 pub use ui_resources::{
     load_ui_resources, ui_requested_cursor_apply_system, update_ui_resources, UiCursorType,
     UiRequestedCursor, UiResources, UiSprite, UiSpriteSheet, UiSpriteSheetType, UiTexture,
 };
+pub use ui_sound_settings::{UiSoundKind, UiSoundOverrides, UiSoundSettings, UiSoundTheme};
 pub use virtual_filesystem::VfsResource;
 pub use water_settings::WaterSettings;
+pub use weather_override::{WeatherKind, WeatherOverride};
 pub use wind_state::{WindSettings, WindState};
 pub use world_connection::WorldConnection;
+pub use world_pings::{WorldPing, WorldPingKind, WorldPings};
 pub use world_rates::WorldRates;
 pub use world_time::WorldTime;
 pub use zone_time::{ZoneTime, ZoneTimeState};
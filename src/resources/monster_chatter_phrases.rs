@@ -1,7 +1,27 @@
+use std::path::Path;
+
 use bevy::prelude::{Resource, Reflect};
+use rand::Rng;
+use serde::Deserialize;
 
 use crate::components::ClientEntityType;
 
+/// Phrases loaded from a user phrases file, e.g.
+///
+/// ```toml
+/// replace_defaults = false
+/// monster = ["Grr!"]
+/// npc = ["Lovely day for it."]
+/// ```
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct ChatterPhrasesFile {
+    /// Use only the phrases from the file instead of adding to the built in ones
+    replace_defaults: bool,
+    monster: Vec<String>,
+    npc: Vec<String>,
+}
+
 /// Resource defining chat phrases for monsters and NPCs
 #[derive(Resource, Reflect)]
 pub struct MonsterChatterPhrases {
@@ -12,7 +32,7 @@ pub struct MonsterChatterPhrases {
 }
 
 /// Phrases for hostile monsters
-#[derive(Clone, Reflect)]
+#[derive(Clone, Default, Reflect)]
 pub struct MonsterPhrases {
     /// Combat/aggressive phrases
     pub combat_phrases: Vec<String>,
@@ -26,10 +46,12 @@ pub struct MonsterPhrases {
     pub confident_phrases: Vec<String>,
     /// Monster-specific Rose Online themed phrases
     pub rose_monster_phrases: Vec<String>,
+    /// Phrases added from the user phrases file
+    pub custom_phrases: Vec<String>,
 }
 
 /// Phrases for friendly NPCs
-#[derive(Clone, Reflect)]
+#[derive(Clone, Default, Reflect)]
 pub struct NpcPhrases {
     /// Greeting phrases
     pub greeting_phrases: Vec<String>,
@@ -39,6 +61,8 @@ pub struct NpcPhrases {
     pub helpful_phrases: Vec<String>,
     /// NPC-specific Rose Online themed phrases
     pub rose_npc_phrases: Vec<String>,
+    /// Phrases added from the user phrases file
+    pub custom_phrases: Vec<String>,
 }
 
 impl Default for MonsterChatterPhrases {
@@ -48,34 +72,52 @@ impl Default for MonsterChatterPhrases {
 }
 
 impl MonsterChatterPhrases {
-    /// Get a random phrase based on entity type
-    pub fn get_random_phrase(&self, entity_type: ClientEntityType) -> &String {
-        match entity_type {
-            ClientEntityType::Monster => self.get_random_monster_phrase(),
-            ClientEntityType::Npc => self.get_random_npc_phrase(),
-            _ => &self.monster_phrases.combat_phrases[0], // Fallback
-        }
-    }
+    /// Built in phrases plus those from the phrases file at `path`, falls
+    /// back to only the built in phrases if the file cannot be read.
+    pub fn load(path: &Path) -> Self {
+        let mut phrases = Self::new();
 
-    /// Get a random monster phrase from all monster categories
-    fn get_random_monster_phrase(&self) -> &String {
-        let all: Vec<&String> = self.monster_phrases.get_all_phrases();
-        if all.is_empty() {
-            &self.monster_phrases.combat_phrases[0]
-        } else {
-            let index = (rand::random::<f32>() * all.len() as f32) as usize;
-            all[index]
+        let file = match std::fs::read_to_string(path)
+            .map_err(anyhow::Error::from)
+            .and_then(|toml_str| Ok(toml::from_str::<ChatterPhrasesFile>(&toml_str)?))
+        {
+            Ok(file) => file,
+            Err(error) => {
+                log::warn!(
+                    "Failed to load chatter phrases from {} with error: {}",
+                    path.to_string_lossy(),
+                    error
+                );
+                return phrases;
+            }
+        };
+
+        if file.replace_defaults {
+            phrases.monster_phrases = MonsterPhrases::default();
+            phrases.npc_phrases = NpcPhrases::default();
         }
+        phrases.monster_phrases.custom_phrases = file.monster;
+        phrases.npc_phrases.custom_phrases = file.npc;
+        phrases
     }
 
-    /// Get a random NPC phrase from all NPC categories
-    fn get_random_npc_phrase(&self) -> &String {
-        let all: Vec<&String> = self.npc_phrases.get_all_phrases();
-        if all.is_empty() {
-            &self.npc_phrases.greeting_phrases[0]
+    /// Get a random phrase based on entity type, only from phrases which pass `filter`
+    pub fn get_random_phrase(
+        &self,
+        entity_type: ClientEntityType,
+        filter: impl Fn(&str) -> bool,
+        rng: &mut impl Rng,
+    ) -> Option<&String> {
+        let all: Vec<&String> = match entity_type {
+            ClientEntityType::Npc => self.npc_phrases.get_all_phrases(),
+            _ => self.monster_phrases.get_all_phrases(),
+        };
+        let allowed: Vec<&String> = all.into_iter().filter(|phrase| filter(phrase)).collect();
+        if allowed.is_empty() {
+            None
         } else {
-            let index = (rand::random::<f32>() * all.len() as f32) as usize;
-            all[index]
+            let index = rng.gen_range(0..allowed.len());
+            allowed.get(index).copied()
         }
     }
 
@@ -210,6 +252,7 @@ impl MonsterChatterPhrases {
                     "This world has so many secrets...".to_string(),
                     "The Seven Planets hold many mysteries...".to_string(),
                 ],
+                custom_phrases: Vec::new(),
             },
             npc_phrases: NpcPhrases {
                 greeting_phrases: vec![
@@ -349,6 +392,7 @@ impl MonsterChatterPhrases {
                     "Winter Event has the best rewards...".to_string(),
                     "Event mounts are so rare...".to_string(),
                 ],
+                custom_phrases: Vec::new(),
             },
         }
     }
@@ -364,6 +408,7 @@ impl MonsterPhrases {
         all.extend(self.hungry_phrases.iter());
         all.extend(self.confident_phrases.iter());
         all.extend(self.rose_monster_phrases.iter());
+        all.extend(self.custom_phrases.iter());
         all
     }
 }
@@ -376,6 +421,7 @@ impl NpcPhrases {
         all.extend(self.idle_phrases.iter());
         all.extend(self.helpful_phrases.iter());
         all.extend(self.rose_npc_phrases.iter());
+        all.extend(self.custom_phrases.iter());
         all
     }
 }
@@ -0,0 +1,141 @@
+use bevy::{
+    math::Vec3,
+    prelude::{Entity, Resource},
+};
+use bevy_rapier3d::prelude::{CollisionGroups, Group};
+use serde::{Deserialize, Serialize};
+
+use crate::components::{
+    COLLISION_FILTER_CLICKABLE, COLLISION_FILTER_INSPECTABLE, COLLISION_GROUP_CHARACTER,
+    COLLISION_GROUP_ITEM_DROP, COLLISION_GROUP_NPC, COLLISION_GROUP_ZONE_EVENT_OBJECT,
+    COLLISION_GROUP_ZONE_OBJECT, COLLISION_GROUP_ZONE_TERRAIN, COLLISION_GROUP_ZONE_WARP_OBJECT,
+    COLLISION_GROUP_ZONE_WATER,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PickPriority {
+    /// Whatever the cursor ray hits first
+    #[serde(rename = "nearest")]
+    Nearest,
+    /// Characters, monsters, NPCs and item drops near the cursor ray win over
+    /// terrain and objects
+    #[serde(rename = "interactables")]
+    Interactables,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PickFilter {
+    #[serde(rename = "clickable")]
+    Clickable,
+    #[serde(rename = "inspectable")]
+    Inspectable,
+}
+
+impl PickFilter {
+    pub fn collision_filter(&self) -> Group {
+        match self {
+            PickFilter::Clickable => COLLISION_FILTER_CLICKABLE,
+            PickFilter::Inspectable => COLLISION_FILTER_INSPECTABLE,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PickGroup {
+    #[serde(rename = "character")]
+    Character,
+    #[serde(rename = "npc")]
+    Npc,
+    #[serde(rename = "item_drop")]
+    ItemDrop,
+    #[serde(rename = "zone_object")]
+    ZoneObject,
+    #[serde(rename = "terrain")]
+    Terrain,
+    #[serde(rename = "water")]
+    Water,
+    #[serde(rename = "event_object")]
+    EventObject,
+    #[serde(rename = "warp_object")]
+    WarpObject,
+}
+
+impl PickGroup {
+    pub fn collision_group(&self) -> Group {
+        match self {
+            PickGroup::Character => COLLISION_GROUP_CHARACTER,
+            PickGroup::Npc => COLLISION_GROUP_NPC,
+            PickGroup::ItemDrop => COLLISION_GROUP_ITEM_DROP,
+            PickGroup::ZoneObject => COLLISION_GROUP_ZONE_OBJECT,
+            PickGroup::Terrain => COLLISION_GROUP_ZONE_TERRAIN,
+            PickGroup::Water => COLLISION_GROUP_ZONE_WATER,
+            PickGroup::EventObject => COLLISION_GROUP_ZONE_EVENT_OBJECT,
+            PickGroup::WarpObject => COLLISION_GROUP_ZONE_WARP_OBJECT,
+        }
+    }
+}
+
+/// Which colliders the cursor can pick for one kind of interaction
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PickContext {
+    /// Colliders must have this filter set to be picked
+    pub filter: PickFilter,
+    pub groups: Vec<PickGroup>,
+}
+
+impl PickContext {
+    pub fn collision_groups(&self) -> CollisionGroups {
+        CollisionGroups::new(
+            self.filter.collision_filter(),
+            self.groups
+                .iter()
+                .fold(Group::NONE, |groups, group| groups | group.collision_group()),
+        )
+    }
+}
+
+#[derive(Resource, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MousePickSettings {
+    pub priority: PickPriority,
+    /// Radius around the cursor ray in which interactables are picked, in metres
+    pub pick_radius: f32,
+    /// How far behind the first surface hit an interactable can be and still be
+    /// picked, so targets standing in long grass or behind a fence post win
+    pub priority_distance: f32,
+    /// Colliders considered when selecting a target
+    pub target: PickContext,
+    /// Colliders considered when setting a move destination
+    pub move_destination: PickContext,
+}
+
+impl Default for MousePickSettings {
+    fn default() -> Self {
+        Self {
+            priority: PickPriority::Interactables,
+            pick_radius: 0.3,
+            priority_distance: 3.0,
+            target: PickContext {
+                filter: PickFilter::Clickable,
+                groups: vec![PickGroup::Character, PickGroup::Npc, PickGroup::ItemDrop],
+            },
+            move_destination: PickContext {
+                filter: PickFilter::Clickable,
+                groups: vec![
+                    PickGroup::ZoneObject,
+                    PickGroup::Terrain,
+                    PickGroup::EventObject,
+                    PickGroup::WarpObject,
+                ],
+            },
+        }
+    }
+}
+
+/// What the cursor ray hit last frame, drawn by the debug render
+#[derive(Resource, Default)]
+pub struct MousePickDebug {
+    pub ray_origin: Vec3,
+    pub surface_hit: Option<Vec3>,
+    pub picked: Option<(Entity, Vec3)>,
+}
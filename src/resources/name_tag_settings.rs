@@ -1,12 +1,23 @@
 use bevy::prelude::Resource;
 use enum_map::{enum_map, EnumMap};
+use serde::{Deserialize, Serialize};
 
-use crate::components::NameTagType;
+use crate::{components::NameTagType, resources::PersistentSettings};
 
-#[derive(Resource)]
+#[derive(Resource, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct NameTagSettings {
+    /// When false the name tag is only shown whilst hovered or targeted
     pub show_all: EnumMap<NameTagType, bool>,
     pub font_size: EnumMap<NameTagType, f32>,
+    /// Show a pulsing red mark on monsters which are targeting the player
+    pub show_threat_indicator: bool,
+    /// Distance from the camera beyond which name tags are hidden
+    pub max_distance: EnumMap<NameTagType, f32>,
+    /// Distance from the camera beyond which item drop names are hidden
+    pub item_drop_max_distance: f32,
+    /// Width of the band before `max_distance` over which name tags fade out
+    pub fade_distance: f32,
 }
 
 impl Default for NameTagSettings {
@@ -22,6 +33,31 @@ impl Default for NameTagSettings {
                 NameTagType::Npc => 16.0,
                 NameTagType::Monster => 16.0,
             },
+            show_threat_indicator: true,
+            max_distance: enum_map! {
+                NameTagType::Character => 80.0,
+                NameTagType::Npc => 60.0,
+                NameTagType::Monster => 60.0,
+            },
+            item_drop_max_distance: 40.0,
+            fade_distance: 10.0,
         }
     }
 }
+
+impl NameTagSettings {
+    /// Opacity of a name tag at the given distance from the camera
+    pub fn get_opacity(&self, max_distance: f32, distance: f32) -> f32 {
+        if distance >= max_distance {
+            0.0
+        } else if self.fade_distance <= 0.0 {
+            1.0
+        } else {
+            ((max_distance - distance) / self.fade_distance).clamp(0.0, 1.0)
+        }
+    }
+}
+
+impl PersistentSettings for NameTagSettings {
+    const CONFIG_SECTION: &'static str = "name_tags";
+}
@@ -0,0 +1,60 @@
+use bevy::prelude::Resource;
+
+#[derive(Resource)]
+pub struct NetworkLogging {
+    pub enabled: bool,
+    /// Hex opcodes separated by commas or spaces, empty logs every packet
+    pub opcode_filter: String,
+    pub log_to_file: bool,
+    pub log_file_path: String,
+    /// Number of packets kept in memory for the debug window
+    pub max_entries: usize,
+}
+
+impl Default for NetworkLogging {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            opcode_filter: String::new(),
+            log_to_file: false,
+            log_file_path: "packets.log".to_string(),
+            max_entries: 500,
+        }
+    }
+}
+
+impl NetworkLogging {
+    pub fn parse_opcode_filter(&self) -> Vec<u16> {
+        parse_opcode_filter(&self.opcode_filter)
+    }
+}
+
+fn parse_opcode_filter(filter: &str) -> Vec<u16> {
+    filter
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|opcode| !opcode.is_empty())
+        .filter_map(|opcode| {
+            let opcode = opcode
+                .strip_prefix("0x")
+                .or_else(|| opcode.strip_prefix("0X"))
+                .unwrap_or(opcode);
+            u16::from_str_radix(opcode, 16).ok()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_opcode_filter() {
+        assert_eq!(parse_opcode_filter(""), Vec::<u16>::new());
+        assert_eq!(parse_opcode_filter("7EC"), vec![0x7ec]);
+        assert_eq!(
+            parse_opcode_filter("0x7ec, 79F  0X7a0"),
+            vec![0x7ec, 0x79f, 0x7a0]
+        );
+        assert_eq!(parse_opcode_filter("7ec,zz,,808"), vec![0x7ec, 0x808]);
+    }
+}
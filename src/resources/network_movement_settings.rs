@@ -0,0 +1,27 @@
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+#[derive(Resource, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NetworkMovementSettings {
+    /// Predict local player movement and interpolate remote entities, disable for
+    /// servers which already stream frequent position updates
+    pub enabled: bool,
+    /// How far behind the newest server update remote entities are displayed, in seconds
+    pub interpolation_delay: f32,
+    /// Corrections up to this distance (in cm) are smoothed, anything larger snaps
+    pub max_smooth_correction: f32,
+    /// Time taken to smooth out a correction, in seconds
+    pub correction_duration: f32,
+}
+
+impl Default for NetworkMovementSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interpolation_delay: 0.1,
+            max_smooth_correction: 300.0,
+            correction_duration: 0.2,
+        }
+    }
+}
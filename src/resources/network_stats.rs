@@ -0,0 +1,122 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::Resource;
+
+use crate::protocol::ProtocolConnectionKind;
+
+/// Number of packet timing samples kept for the packet timing graph
+pub const NETWORK_PACKET_TIMING_HISTORY_LEN: usize = 120;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum NetworkConnectionState {
+    #[default]
+    Disconnected,
+    Connecting,
+    Connected,
+}
+
+#[derive(Default)]
+pub struct NetworkConnectionStats {
+    pub state: NetworkConnectionState,
+
+    pub total_packets_sent: u64,
+    pub total_bytes_sent: u64,
+    pub total_packets_received: u64,
+    pub total_bytes_received: u64,
+
+    pub packets_sent_per_sec: u32,
+    pub bytes_sent_per_sec: u32,
+    pub packets_received_per_sec: u32,
+    pub bytes_received_per_sec: u32,
+
+    window_packets_sent: u32,
+    window_bytes_sent: u32,
+    window_packets_received: u32,
+    window_bytes_received: u32,
+}
+
+impl NetworkConnectionStats {
+    pub fn record_sent(&mut self, bytes: usize) {
+        self.total_packets_sent += 1;
+        self.total_bytes_sent += bytes as u64;
+        self.window_packets_sent += 1;
+        self.window_bytes_sent += bytes as u32;
+    }
+
+    pub fn record_received(&mut self, bytes: usize) {
+        self.total_packets_received += 1;
+        self.total_bytes_received += bytes as u64;
+        self.window_packets_received += 1;
+        self.window_bytes_received += bytes as u32;
+    }
+
+    fn end_window(&mut self, window_secs: f32) {
+        let per_sec = |value: u32| (value as f32 / window_secs).round() as u32;
+        self.packets_sent_per_sec = per_sec(self.window_packets_sent);
+        self.bytes_sent_per_sec = per_sec(self.window_bytes_sent);
+        self.packets_received_per_sec = per_sec(self.window_packets_received);
+        self.bytes_received_per_sec = per_sec(self.window_bytes_received);
+
+        self.window_packets_sent = 0;
+        self.window_bytes_sent = 0;
+        self.window_packets_received = 0;
+        self.window_bytes_received = 0;
+    }
+}
+
+#[derive(Default, Resource)]
+pub struct NetworkStats {
+    pub login: NetworkConnectionStats,
+    pub world: NetworkConnectionStats,
+    pub game: NetworkConnectionStats,
+
+    /// Most recent time in milliseconds from a sent packet to the next
+    /// received packet, see `ProtocolStats`. This is not a round trip time.
+    pub packet_timing_ms: Option<f32>,
+    pub packet_timing_history: VecDeque<f32>,
+
+    window_elapsed_secs: f32,
+}
+
+impl NetworkStats {
+    pub fn connection(&self, connection: ProtocolConnectionKind) -> &NetworkConnectionStats {
+        match connection {
+            ProtocolConnectionKind::Login => &self.login,
+            ProtocolConnectionKind::World => &self.world,
+            ProtocolConnectionKind::Game => &self.game,
+        }
+    }
+
+    pub fn connection_mut(
+        &mut self,
+        connection: ProtocolConnectionKind,
+    ) -> &mut NetworkConnectionStats {
+        match connection {
+            ProtocolConnectionKind::Login => &mut self.login,
+            ProtocolConnectionKind::World => &mut self.world,
+            ProtocolConnectionKind::Game => &mut self.game,
+        }
+    }
+
+    pub fn record_packet_timing(&mut self, packet_timing_ms: f32) {
+        self.packet_timing_ms = Some(packet_timing_ms);
+        self.packet_timing_history.push_back(packet_timing_ms);
+        while self.packet_timing_history.len() > NETWORK_PACKET_TIMING_HISTORY_LEN {
+            self.packet_timing_history.pop_front();
+        }
+    }
+
+    /// Accumulates elapsed time and updates the per second rates once a full second has passed
+    pub fn update_rates(&mut self, delta_secs: f32) {
+        self.window_elapsed_secs += delta_secs;
+        if self.window_elapsed_secs < 1.0 {
+            return;
+        }
+
+        let window_secs = self.window_elapsed_secs;
+        self.login.end_window(window_secs);
+        self.world.end_window(window_secs);
+        self.game.end_window(window_secs);
+        self.window_elapsed_secs = 0.0;
+    }
+}
@@ -1,6 +1,10 @@
+use std::sync::Arc;
+
 use bevy::prelude::Resource;
 
-use crate::protocol::ProtocolClient;
+use crate::protocol::{
+    NetworkBackend, PacketLogger, ProtocolClient, ProtocolConnectionKind, ProtocolStats, ProtocolStatsMessage,
+};
 
 pub enum NetworkThreadMessage {
     RunProtocolClient(Box<dyn ProtocolClient + Send + Sync>),
@@ -10,11 +14,33 @@ pub enum NetworkThreadMessage {
 #[derive(Resource)]
 pub struct NetworkThread {
     pub control_tx: tokio::sync::mpsc::UnboundedSender<NetworkThreadMessage>,
+    pub backend: Box<dyn NetworkBackend>,
+    pub stats_tx: crossbeam_channel::Sender<ProtocolStatsMessage>,
+    pub stats_rx: crossbeam_channel::Receiver<ProtocolStatsMessage>,
+    pub packet_logger: Arc<PacketLogger>,
 }
 
 impl NetworkThread {
-    pub fn new(control_tx: tokio::sync::mpsc::UnboundedSender<NetworkThreadMessage>) -> Self {
-        Self { control_tx }
+    pub fn new(
+        control_tx: tokio::sync::mpsc::UnboundedSender<NetworkThreadMessage>,
+        backend: Box<dyn NetworkBackend>,
+    ) -> Self {
+        let (stats_tx, stats_rx) = crossbeam_channel::unbounded();
+        Self {
+            control_tx,
+            backend,
+            stats_tx,
+            stats_rx,
+            packet_logger: Arc::new(PacketLogger::default()),
+        }
+    }
+
+    pub fn protocol_stats(&self, connection: ProtocolConnectionKind) -> ProtocolStats {
+        ProtocolStats::new(
+            connection,
+            self.stats_tx.clone(),
+            self.packet_logger.clone(),
+        )
     }
 }
 
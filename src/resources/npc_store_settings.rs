@@ -0,0 +1,20 @@
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+#[derive(Resource, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NpcStoreSettings {
+    /// Ask before a transaction costing more than this many Zuly, 0 never asks
+    pub confirm_purchase_above: i64,
+    /// Number of sold items remembered per store for buy back
+    pub buyback_history: usize,
+}
+
+impl Default for NpcStoreSettings {
+    fn default() -> Self {
+        Self {
+            confirm_purchase_above: 100_000,
+            buyback_history: 20,
+        }
+    }
+}
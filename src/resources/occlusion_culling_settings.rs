@@ -0,0 +1,32 @@
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+#[derive(Resource, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OcclusionCullingSettings {
+    /// Use GPU occlusion culling when the adapter supports it, set to false to
+    /// force it off on drivers where it misbehaves
+    pub enabled: bool,
+    /// When GPU occlusion culling is not used, hide zone objects further than
+    /// `fallback_distance` from the camera instead
+    pub fallback_distance_culling: bool,
+    /// Distance at which zone objects are culled by the fallback, in metres
+    pub fallback_distance: f32,
+}
+
+impl Default for OcclusionCullingSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            fallback_distance_culling: true,
+            fallback_distance: 400.0,
+        }
+    }
+}
+
+/// Whether GPU occlusion culling ended up enabled on the camera, decided once
+/// at startup from `OcclusionCullingSettings` and the adapter capabilities.
+#[derive(Resource, Copy, Clone, Default)]
+pub struct OcclusionCullingState {
+    pub gpu_enabled: bool,
+}
@@ -0,0 +1,78 @@
+use std::collections::BTreeMap;
+
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+use crate::{components::ParticleCategory, resources::PersistentSettings};
+
+#[derive(Resource, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ParticleBudget {
+    /// Maximum number of live particles across all emitters
+    pub max_particles: usize,
+    /// Once over budget, emitters keep spawning particles only while the
+    /// particles of equal or higher priority fit within the budget
+    pub combat_priority: u8,
+    pub character_priority: u8,
+    pub ambient_priority: u8,
+}
+
+impl Default for ParticleBudget {
+    fn default() -> Self {
+        Self {
+            max_particles: 20000,
+            combat_priority: 2,
+            character_priority: 1,
+            ambient_priority: 0,
+        }
+    }
+}
+
+impl ParticleBudget {
+    pub fn priority(&self, category: ParticleCategory) -> u8 {
+        match category {
+            ParticleCategory::Combat => self.combat_priority,
+            ParticleCategory::Character => self.character_priority,
+            ParticleCategory::Ambient => self.ambient_priority,
+        }
+    }
+
+    /// Whether an emitter of `priority` may spawn another particle given the
+    /// number of live particles at each priority
+    pub fn may_emit(&self, live_by_priority: &BTreeMap<u8, usize>, priority: u8) -> bool {
+        live_by_priority
+            .range(priority..)
+            .map(|(_, &count)| count)
+            .sum::<usize>()
+            < self.max_particles
+    }
+}
+
+impl PersistentSettings for ParticleBudget {
+    const CONFIG_SECTION: &'static str = "particle_budget";
+}
+
+/// Particle counts from the last update, shown by the particle performance monitor
+#[derive(Resource, Default)]
+pub struct ParticleBudgetStats {
+    pub live_particles: usize,
+    pub throttled_emitters: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_may_emit_throttles_lower_priorities_first() {
+        let budget = ParticleBudget {
+            max_particles: 100,
+            ..Default::default()
+        };
+        let live_by_priority = BTreeMap::from([(0, 60), (1, 30), (2, 20)]);
+
+        assert!(!budget.may_emit(&live_by_priority, 0));
+        assert!(budget.may_emit(&live_by_priority, 1));
+        assert!(budget.may_emit(&live_by_priority, 2));
+    }
+}
@@ -0,0 +1,21 @@
+use bevy::prelude::Resource;
+
+#[derive(Resource)]
+pub struct PartyMarkerSettings {
+    /// Show the over-head marker above party members
+    pub enabled: bool,
+    /// Show arrows at the screen edge pointing towards off-screen party members
+    pub show_offscreen_arrows: bool,
+    /// Party members further than this distance have no marker or arrow
+    pub max_distance: f32,
+}
+
+impl Default for PartyMarkerSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            show_offscreen_arrows: true,
+            max_distance: 300.0,
+        }
+    }
+}
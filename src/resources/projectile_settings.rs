@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+
+use bevy::prelude::Resource;
+
+use rose_data::{EffectBulletMoveType, EffectId};
+
+/// Longest a projectile may fly for, kept below the pending damage expiry so
+/// the damage is still shown when the projectile lands
+pub const MAX_PROJECTILE_FLIGHT_TIME: f32 = 4.0;
+
+const MIN_PROJECTILE_FLIGHT_TIME: f32 = 0.05;
+
+/// How a projectile travels to its target
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ProjectileMotion {
+    /// Multiplier for the bullet speed from the effect data
+    pub speed_scale: f32,
+    /// Height of the arc at the middle of the flight, as a fraction of the
+    /// flight distance, zero flies in a straight line
+    pub arc_height: f32,
+    /// Follow the target as it moves, otherwise fly to where it was at launch
+    pub homing: bool,
+}
+
+impl ProjectileMotion {
+    pub const STRAIGHT: Self = Self {
+        speed_scale: 1.0,
+        arc_height: 0.0,
+        homing: true,
+    };
+}
+
+/// Projectile motion for each bullet move type from the effect data, with
+/// overrides for individual effects.
+#[derive(Resource)]
+pub struct ProjectileSettings {
+    pub linear: ProjectileMotion,
+    pub parabola: ProjectileMotion,
+    pub effect_overrides: HashMap<EffectId, ProjectileMotion>,
+}
+
+impl Default for ProjectileSettings {
+    fn default() -> Self {
+        Self {
+            linear: ProjectileMotion::STRAIGHT,
+            parabola: ProjectileMotion {
+                speed_scale: 1.0,
+                arc_height: 0.2,
+                homing: false,
+            },
+            effect_overrides: HashMap::new(),
+        }
+    }
+}
+
+impl ProjectileSettings {
+    pub fn motion(&self, effect_id: EffectId, move_type: EffectBulletMoveType) -> ProjectileMotion {
+        if let Some(motion) = self.effect_overrides.get(&effect_id) {
+            return *motion;
+        }
+
+        match move_type {
+            EffectBulletMoveType::Parabola => self.parabola,
+            EffectBulletMoveType::Linear | EffectBulletMoveType::Immediate => self.linear,
+        }
+    }
+}
+
+/// Seconds to fly `distance` at `speed`, clamped so the hit always lands
+/// before the pending damage expires
+pub fn projectile_flight_time(distance: f32, speed: f32) -> f32 {
+    if speed <= 0.0 {
+        return MAX_PROJECTILE_FLIGHT_TIME;
+    }
+
+    (distance / speed).clamp(MIN_PROJECTILE_FLIGHT_TIME, MAX_PROJECTILE_FLIGHT_TIME)
+}
+
+/// Height above the straight line between start and target at flight
+/// progress `t` from 0 to 1, peaking at `arc_height` half way
+pub fn projectile_arc_offset(arc_height: f32, t: f32) -> f32 {
+    let t = t.clamp(0.0, 1.0);
+    4.0 * arc_height * t * (1.0 - t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flight_time_and_arc() {
+        assert_eq!(projectile_flight_time(10.0, 5.0), 2.0);
+        assert_eq!(projectile_flight_time(1000.0, 5.0), MAX_PROJECTILE_FLIGHT_TIME);
+        assert_eq!(projectile_flight_time(10.0, 0.0), MAX_PROJECTILE_FLIGHT_TIME);
+
+        assert_eq!(projectile_arc_offset(2.0, 0.0), 0.0);
+        assert_eq!(projectile_arc_offset(2.0, 0.5), 2.0);
+        assert_eq!(projectile_arc_offset(2.0, 1.0), 0.0);
+    }
+}
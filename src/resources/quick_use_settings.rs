@@ -0,0 +1,148 @@
+use std::collections::BTreeMap;
+
+use bevy::prelude::{KeyCode, Resource};
+use serde::{Deserialize, Serialize};
+
+use crate::resources::PersistentSettings;
+
+pub const QUICK_USE_NUM_SLOTS: usize = 4;
+
+/// Keys a quick-use slot can be bound to
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum QuickUseKey {
+    Digit1,
+    Digit2,
+    Digit3,
+    Digit4,
+    Digit5,
+    Digit6,
+    Digit7,
+    Digit8,
+    Digit9,
+    Digit0,
+    F9,
+    F11,
+    F12,
+}
+
+impl QuickUseKey {
+    pub const ALL: [QuickUseKey; 13] = [
+        QuickUseKey::Digit1,
+        QuickUseKey::Digit2,
+        QuickUseKey::Digit3,
+        QuickUseKey::Digit4,
+        QuickUseKey::Digit5,
+        QuickUseKey::Digit6,
+        QuickUseKey::Digit7,
+        QuickUseKey::Digit8,
+        QuickUseKey::Digit9,
+        QuickUseKey::Digit0,
+        QuickUseKey::F9,
+        QuickUseKey::F11,
+        QuickUseKey::F12,
+    ];
+
+    pub fn key_code(&self) -> KeyCode {
+        match self {
+            QuickUseKey::Digit1 => KeyCode::Digit1,
+            QuickUseKey::Digit2 => KeyCode::Digit2,
+            QuickUseKey::Digit3 => KeyCode::Digit3,
+            QuickUseKey::Digit4 => KeyCode::Digit4,
+            QuickUseKey::Digit5 => KeyCode::Digit5,
+            QuickUseKey::Digit6 => KeyCode::Digit6,
+            QuickUseKey::Digit7 => KeyCode::Digit7,
+            QuickUseKey::Digit8 => KeyCode::Digit8,
+            QuickUseKey::Digit9 => KeyCode::Digit9,
+            QuickUseKey::Digit0 => KeyCode::Digit0,
+            QuickUseKey::F9 => KeyCode::F9,
+            QuickUseKey::F11 => KeyCode::F11,
+            QuickUseKey::F12 => KeyCode::F12,
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            QuickUseKey::Digit1 => "1",
+            QuickUseKey::Digit2 => "2",
+            QuickUseKey::Digit3 => "3",
+            QuickUseKey::Digit4 => "4",
+            QuickUseKey::Digit5 => "5",
+            QuickUseKey::Digit6 => "6",
+            QuickUseKey::Digit7 => "7",
+            QuickUseKey::Digit8 => "8",
+            QuickUseKey::Digit9 => "9",
+            QuickUseKey::Digit0 => "0",
+            QuickUseKey::F9 => "F9",
+            QuickUseKey::F11 => "F11",
+            QuickUseKey::F12 => "F12",
+        }
+    }
+}
+
+#[derive(Resource, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuickUseSettings {
+    /// Key for each slot, shared by all characters
+    pub keys: [QuickUseKey; QUICK_USE_NUM_SLOTS],
+    /// Consumable item number in each slot keyed by character name, 0 for an
+    /// empty slot as the config format has no way to store a missing value
+    pub characters: BTreeMap<String, [usize; QUICK_USE_NUM_SLOTS]>,
+}
+
+impl Default for QuickUseSettings {
+    fn default() -> Self {
+        Self {
+            // The end of the number row, which no other game key uses
+            keys: [
+                QuickUseKey::Digit7,
+                QuickUseKey::Digit8,
+                QuickUseKey::Digit9,
+                QuickUseKey::Digit0,
+            ],
+            characters: BTreeMap::new(),
+        }
+    }
+}
+
+impl QuickUseSettings {
+    pub fn items(&self, character_name: &str) -> [Option<usize>; QUICK_USE_NUM_SLOTS] {
+        let items = self
+            .characters
+            .get(character_name)
+            .copied()
+            .unwrap_or_default();
+        items.map(|item_number| (item_number != 0).then_some(item_number))
+    }
+
+    pub fn set_item(&mut self, character_name: &str, index: usize, item_number: Option<usize>) {
+        let items = self
+            .characters
+            .entry(character_name.to_string())
+            .or_default();
+        items[index] = item_number.unwrap_or(0);
+
+        if items.iter().all(|&item_number| item_number == 0) {
+            self.characters.remove(character_name);
+        }
+    }
+}
+
+impl PersistentSettings for QuickUseSettings {
+    const CONFIG_SECTION: &'static str = "quick_use";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_items_are_kept_per_character() {
+        let mut settings = QuickUseSettings::default();
+        settings.set_item("Alice", 1, Some(101));
+        assert_eq!(settings.items("Alice"), [None, Some(101), None, None]);
+        assert_eq!(settings.items("Bob"), [None; QUICK_USE_NUM_SLOTS]);
+
+        settings.set_item("Alice", 1, None);
+        assert!(settings.characters.is_empty());
+    }
+}
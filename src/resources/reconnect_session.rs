@@ -0,0 +1,29 @@
+use bevy::prelude::Resource;
+
+/// The server, channel and character of the current session, kept so a dropped
+/// connection can be re-established without going through the login screens
+#[derive(Resource, Default)]
+pub struct ReconnectSession {
+    pub server_id: Option<usize>,
+    pub channel_id: Option<usize>,
+    pub character_name: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ReconnectStage {
+    WaitRetry,
+    ConnectingLogin,
+    ConnectingWorld,
+    ConnectingGame,
+    JoiningZone,
+}
+
+/// Present while the game is trying to reconnect after losing connection
+#[derive(Resource)]
+pub struct ReconnectState {
+    pub attempt: u32,
+    pub max_attempts: u32,
+    pub stage: ReconnectStage,
+    /// Time remaining before the current stage is retried or times out
+    pub remaining_secs: f32,
+}
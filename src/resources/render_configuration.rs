@@ -5,4 +5,5 @@ pub struct RenderConfiguration {
     pub passthrough_terrain_textures: bool,
     pub trail_effect_duration_multiplier: f32,
     pub use_new_terrain: bool,
+    pub instanced_zone_objects: bool,
 }
@@ -1,4 +1,4 @@
-use bevy::prelude::{Entity, Resource};
+use bevy::prelude::{Entity, Resource, Vec3};
 
 use crate::resources::UiCursorType;
 
@@ -7,4 +7,6 @@ pub struct SelectedTarget {
     pub selected: Option<Entity>,
     pub hover: Option<Entity>,
     pub cursor_type: UiCursorType,
+    /// World position of the terrain or object under the cursor
+    pub hover_position: Option<Vec3>,
 }
@@ -10,4 +10,10 @@ pub struct ServerConfiguration {
     pub preset_channel_id: Option<usize>,
     pub preset_character_name: Option<String>,
     pub auto_login: bool,
+    pub auto_login_max_retries: u32,
+    pub auto_login_retry_base_delay: f32,
+    pub auto_login_retry_max_delay: f32,
+    pub reconnect: bool,
+    pub reconnect_max_attempts: u32,
+    pub reconnect_timeout: f32,
 }
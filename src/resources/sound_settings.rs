@@ -1,21 +1,59 @@
 use bevy::prelude::Resource;
 use enum_map::EnumMap;
+use serde::{Serialize, Serializer};
 
-use crate::{audio::SoundGain, components::SoundCategory};
+use crate::{audio::SoundGain, components::SoundCategory, resources::PersistentSettings, SoundConfig};
 
-#[derive(Resource)]
+#[derive(Resource, Clone, PartialEq)]
 pub struct SoundSettings {
     pub enabled: bool,
     pub global_gain: f32,
     pub gains: EnumMap<SoundCategory, f32>,
+    pub muted: EnumMap<SoundCategory, bool>,
 }
 
 impl SoundSettings {
     pub fn gain(&self, category: SoundCategory) -> SoundGain {
-        if self.enabled {
+        if self.enabled && !self.muted[category] {
             SoundGain::Ratio(self.global_gain * self.gains[category])
         } else {
             SoundGain::Ratio(0.0)
         }
     }
 }
+
+/// Saved in the same layout as the `[sound]` section it was loaded from
+impl Serialize for SoundSettings {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SoundConfig::from(self).serialize(serializer)
+    }
+}
+
+impl PersistentSettings for SoundSettings {
+    const CONFIG_SECTION: &'static str = "sound";
+}
+
+#[cfg(test)]
+mod tests {
+    use enum_map::enum_map;
+
+    use super::*;
+
+    #[test]
+    fn test_gain_is_silent_when_muted() {
+        let mut settings = SoundSettings {
+            enabled: true,
+            global_gain: 0.5,
+            gains: enum_map! { _ => 0.5 },
+            muted: enum_map! { _ => false },
+        };
+        assert!(settings.gain(SoundCategory::Ui) == SoundGain::Ratio(0.25));
+
+        settings.muted[SoundCategory::Ui] = true;
+        assert!(settings.gain(SoundCategory::Ui) == SoundGain::Ratio(0.0));
+        assert!(settings.gain(SoundCategory::NpcSounds) == SoundGain::Ratio(0.25));
+
+        settings.enabled = false;
+        assert!(settings.gain(SoundCategory::NpcSounds) == SoundGain::Ratio(0.0));
+    }
+}
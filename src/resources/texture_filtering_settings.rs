@@ -0,0 +1,69 @@
+use std::sync::atomic::{AtomicU16, Ordering};
+
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+use crate::resources::PersistentSettings;
+
+/// Anisotropy levels offered in the graphics settings
+pub const ANISOTROPY_LEVELS: [u16; 5] = [1, 2, 4, 8, 16];
+
+/// Anisotropy used for textures created from now on, read by the DDS loader and
+/// zone loader which run outside of the ECS
+static CURRENT_ANISOTROPY: AtomicU16 = AtomicU16::new(1);
+
+pub fn current_anisotropy() -> u16 {
+    CURRENT_ANISOTROPY.load(Ordering::Relaxed)
+}
+
+pub fn set_current_anisotropy(anisotropy: u16) {
+    CURRENT_ANISOTROPY.store(anisotropy.max(1), Ordering::Relaxed);
+}
+
+#[derive(Resource, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TextureFilteringSettings {
+    /// Anisotropic filtering level applied to terrain and object textures,
+    /// clamped to what the device supports
+    pub anisotropy: u16,
+}
+
+impl Default for TextureFilteringSettings {
+    fn default() -> Self {
+        Self { anisotropy: 4 }
+    }
+}
+
+impl TextureFilteringSettings {
+    /// The configured anisotropy rounded down to a supported level
+    pub fn effective_anisotropy(&self, max_supported: u16) -> u16 {
+        ANISOTROPY_LEVELS
+            .iter()
+            .copied()
+            .filter(|&level| level <= self.anisotropy.min(max_supported))
+            .max()
+            .unwrap_or(1)
+    }
+}
+
+impl PersistentSettings for TextureFilteringSettings {
+    const CONFIG_SECTION: &'static str = "texture_filtering";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_effective_anisotropy() {
+        let settings = TextureFilteringSettings { anisotropy: 16 };
+        assert_eq!(settings.effective_anisotropy(16), 16);
+        assert_eq!(settings.effective_anisotropy(1), 1);
+
+        let settings = TextureFilteringSettings { anisotropy: 6 };
+        assert_eq!(settings.effective_anisotropy(16), 4);
+
+        let settings = TextureFilteringSettings { anisotropy: 0 };
+        assert_eq!(settings.effective_anisotropy(16), 1);
+    }
+}
@@ -0,0 +1,75 @@
+use bevy::prelude::Resource;
+
+/// Debug control over how fast the zone time of day passes.
+///
+/// Only the lighting clock is changed, `WorldTime` keeps running at the server
+/// rate because quest timers are based on it.
+#[derive(Resource)]
+pub struct TimeScaleSettings {
+    /// Speed of the time of day relative to the world time
+    pub multiplier: f32,
+    /// Stop the time of day at its current value
+    pub frozen: bool,
+    /// Zone ticks added on top of the world time, changed by the multiplier,
+    /// freezing and by setting the time of day
+    pub day_offset_ticks: f32,
+}
+
+impl Default for TimeScaleSettings {
+    fn default() -> Self {
+        Self {
+            multiplier: 1.0,
+            frozen: false,
+            day_offset_ticks: 0.0,
+        }
+    }
+}
+
+impl TimeScaleSettings {
+    pub fn is_default_rate(&self) -> bool {
+        !self.frozen && self.multiplier == 1.0
+    }
+
+    /// Advance the offset for `world_ticks` of passed world time so the zone
+    /// time moves at the scaled rate
+    pub fn advance(&mut self, world_ticks: f32) {
+        let rate = if self.frozen { 0.0 } else { self.multiplier };
+        self.day_offset_ticks += (rate - 1.0) * world_ticks;
+    }
+
+    /// Zone day time in ticks for the world day time, both in `0..day_cycle`
+    pub fn zone_day_ticks(&self, world_day_ticks: f32, day_cycle: u32) -> f32 {
+        (world_day_ticks + self.day_offset_ticks).rem_euclid(day_cycle as f32)
+    }
+
+    /// Jump the zone time to `hours` into the day
+    pub fn set_time_of_day(&mut self, world_day_ticks: f32, day_cycle: u32, hours: f32) {
+        let target_ticks = (hours / 24.0) * day_cycle as f32;
+        self.day_offset_ticks = (target_ticks - world_day_ticks).rem_euclid(day_cycle as f32);
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_time_of_day_and_freeze() {
+        let mut settings = TimeScaleSettings::default();
+        settings.set_time_of_day(30.0, 160, 12.0);
+        assert_eq!(settings.zone_day_ticks(30.0, 160), 80.0);
+
+        settings.frozen = true;
+        settings.advance(5.0);
+        assert_eq!(settings.zone_day_ticks(35.0, 160), 80.0);
+
+        settings.frozen = false;
+        settings.multiplier = 2.0;
+        settings.advance(5.0);
+        assert_eq!(settings.zone_day_ticks(40.0, 160), 90.0);
+    }
+}
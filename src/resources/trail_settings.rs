@@ -0,0 +1,63 @@
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+use crate::resources::PersistentSettings;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TrailQuality {
+    Off,
+    Low,
+    High,
+}
+
+impl TrailQuality {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            TrailQuality::Off => "Off",
+            TrailQuality::Low => "Low",
+            TrailQuality::High => "High",
+        }
+    }
+}
+
+#[derive(Resource, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TrailSettings {
+    /// Quality of weapon and skill trails, Off stops drawing them entirely
+    pub quality: TrailQuality,
+}
+
+impl Default for TrailSettings {
+    fn default() -> Self {
+        Self {
+            quality: TrailQuality::High,
+        }
+    }
+}
+
+impl TrailSettings {
+    pub fn enabled(&self) -> bool {
+        self.quality != TrailQuality::Off
+    }
+
+    /// Scale applied to the distance between trail points, so low quality
+    /// trails are built from fewer segments
+    pub fn point_distance_scale(&self) -> f32 {
+        match self.quality {
+            TrailQuality::Off | TrailQuality::High => 1.0,
+            TrailQuality::Low => 2.5,
+        }
+    }
+
+    /// Minimum time between trail history updates, in seconds
+    pub fn update_interval(&self) -> f32 {
+        match self.quality {
+            TrailQuality::Off | TrailQuality::High => 0.0,
+            TrailQuality::Low => 1.0 / 30.0,
+        }
+    }
+}
+
+impl PersistentSettings for TrailSettings {
+    const CONFIG_SECTION: &'static str = "trails";
+}
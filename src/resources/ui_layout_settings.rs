@@ -0,0 +1,82 @@
+use std::collections::BTreeMap;
+
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+use crate::resources::PersistentSettings;
+
+/// Name of the layout slot loaded at startup and overwritten by reset
+pub const DEFAULT_UI_LAYOUT_SLOT: &str = "default";
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UiWindowLayout {
+    pub position: [f32; 2],
+    pub size: [f32; 2],
+}
+
+impl UiWindowLayout {
+    /// Position moved so the whole window is on a screen of the given size, or
+    /// as much of it as fits when the window is larger than the screen
+    pub fn clamped_position(&self, screen_size: [f32; 2]) -> [f32; 2] {
+        let clamp_axis = |position: f32, size: f32, screen_size: f32| {
+            position.min(screen_size - size).max(0.0)
+        };
+
+        [
+            clamp_axis(self.position[0], self.size[0], screen_size[0]),
+            clamp_axis(self.position[1], self.size[1], screen_size[1]),
+        ]
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UiLayout {
+    /// Window positions and sizes keyed by window title
+    pub windows: BTreeMap<String, UiWindowLayout>,
+    /// Titles of the windows which were open when the layout was saved
+    pub open_windows: Vec<String>,
+    pub hotbar_vertical: bool,
+}
+
+#[derive(Resource, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UiLayoutSettings {
+    /// Layout applied when the game starts
+    pub startup_slot: String,
+    pub slots: BTreeMap<String, UiLayout>,
+}
+
+impl Default for UiLayoutSettings {
+    fn default() -> Self {
+        Self {
+            startup_slot: DEFAULT_UI_LAYOUT_SLOT.to_string(),
+            slots: BTreeMap::new(),
+        }
+    }
+}
+
+impl PersistentSettings for UiLayoutSettings {
+    const CONFIG_SECTION: &'static str = "ui_layout";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clamped_position_keeps_windows_on_screen() {
+        let window = UiWindowLayout {
+            position: [1800.0, -20.0],
+            size: [300.0, 200.0],
+        };
+        assert_eq!(window.clamped_position([1920.0, 1080.0]), [1620.0, 0.0]);
+
+        let oversized = UiWindowLayout {
+            position: [100.0, 100.0],
+            size: [2000.0, 200.0],
+        };
+        assert_eq!(oversized.clamped_position([1280.0, 720.0]), [0.0, 100.0]);
+    }
+}
@@ -5,7 +5,7 @@ use bevy::{
     prelude::{
         AssetServer, Assets, Commands, Handle, Image, Query, Res, ResMut, Resource, Vec2, With,
     },
-    window::{CursorGrabMode, PrimaryWindow, Window},
+    window::{CursorGrabMode, CursorOptions, PrimaryWindow},
 };
 use bevy_egui::{egui, EguiContexts};
 use enum_map::{enum_map, Enum, EnumMap};
@@ -625,12 +625,41 @@ pub fn load_ui_resources(
 /// 3. Create CustomCursorImage structs with the image handles
 /// 4. Insert CursorIcon::Custom(CustomCursor::Image(...)) on window entities
 pub fn ui_requested_cursor_apply_system(
-    _query_window: Query<&mut Window, With<PrimaryWindow>>,
-    _ui_requested_cursor: Res<UiRequestedCursor>,
-    _ui_resources: Res<UiResources>,
-    _egui_ctx: EguiContexts,
+    query_window: Query<&CursorOptions, With<PrimaryWindow>>,
+    ui_requested_cursor: Res<UiRequestedCursor>,
+    mut egui_ctx: EguiContexts,
 ) {
-    // egui manages cursor input automatically when it wants pointer input
-    // Custom cursor implementation using Bevy 0.16's CustomCursorImage API would go here
-    // when cursor images are properly loaded
+    let Ok(ctx) = egui_ctx.ctx_mut() else {
+        return;
+    };
+
+    // Leave the cursor to egui while it is over a window or the camera has grabbed it
+    if ctx.wants_pointer_input()
+        || ctx.is_pointer_over_area()
+        || query_window
+            .single()
+            .map_or(true, |cursor_options| cursor_options.grab_mode != CursorGrabMode::None)
+    {
+        return;
+    }
+
+    // The cursor images from the exe are not loaded yet, so use the closest
+    // system cursor for each world cursor type
+    let cursor_icon = if ui_requested_cursor.moving_camera {
+        egui::CursorIcon::AllScroll
+    } else {
+        match ui_requested_cursor.world_cursor {
+            UiCursorType::Attack => egui::CursorIcon::Crosshair,
+            UiCursorType::Npc => egui::CursorIcon::Help,
+            UiCursorType::PickupItem => egui::CursorIcon::Grab,
+            UiCursorType::User => egui::CursorIcon::PointingHand,
+            UiCursorType::Wheel | UiCursorType::Repair => egui::CursorIcon::ContextMenu,
+            UiCursorType::Appraisal => egui::CursorIcon::ZoomIn,
+            UiCursorType::Left => egui::CursorIcon::ResizeWest,
+            UiCursorType::Right => egui::CursorIcon::ResizeEast,
+            UiCursorType::NoUi => egui::CursorIcon::NotAllowed,
+            UiCursorType::Default | UiCursorType::Inventory => return,
+        }
+    };
+    ctx.set_cursor_icon(cursor_icon);
 }
@@ -0,0 +1,79 @@
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+use crate::resources::PersistentSettings;
+
+pub const MIN_UI_SCALE: f32 = 0.75;
+pub const MAX_UI_SCALE: f32 = 2.0;
+
+/// Smallest window size in points the UI is laid out for, scaling is limited
+/// so the hotbar and inventory always fit on screen
+const MIN_UI_LOGICAL_WIDTH: f32 = 800.0;
+const MIN_UI_LOGICAL_HEIGHT: f32 = 600.0;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UiScaleMode {
+    /// Follow the scale factor the OS reports for the window's monitor
+    #[serde(rename = "auto")]
+    Auto,
+    /// Use `UiScale::scale` regardless of the monitor
+    #[serde(rename = "manual")]
+    Manual,
+}
+
+#[derive(Resource, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UiScale {
+    pub mode: UiScaleMode,
+    /// Physical pixels per UI point when `mode` is Manual
+    pub scale: f32,
+}
+
+impl Default for UiScale {
+    fn default() -> Self {
+        Self {
+            mode: UiScaleMode::Auto,
+            scale: 1.0,
+        }
+    }
+}
+
+impl UiScale {
+    /// Physical pixels per UI point for a window with the given scale factor and
+    /// physical size
+    pub fn pixels_per_point(&self, window_scale_factor: f32, physical_size: [f32; 2]) -> f32 {
+        let requested = match self.mode {
+            UiScaleMode::Auto => window_scale_factor,
+            UiScaleMode::Manual => self.scale,
+        }
+        .clamp(MIN_UI_SCALE, MAX_UI_SCALE);
+
+        let largest_fitting = f32::min(
+            physical_size[0] / MIN_UI_LOGICAL_WIDTH,
+            physical_size[1] / MIN_UI_LOGICAL_HEIGHT,
+        );
+        requested.min(largest_fitting).max(MIN_UI_SCALE)
+    }
+}
+
+impl PersistentSettings for UiScale {
+    const CONFIG_SECTION: &'static str = "ui_scale";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pixels_per_point_fits_small_windows() {
+        let auto = UiScale::default();
+        assert_eq!(auto.pixels_per_point(1.5, [3840.0, 2160.0]), 1.5);
+
+        let manual = UiScale {
+            mode: UiScaleMode::Manual,
+            scale: 2.0,
+        };
+        assert_eq!(manual.pixels_per_point(1.0, [3840.0, 2160.0]), 2.0);
+        assert_eq!(manual.pixels_per_point(1.0, [1280.0, 720.0]), 1.2);
+    }
+}
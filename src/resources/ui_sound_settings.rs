@@ -0,0 +1,153 @@
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+use rose_data::SoundId;
+
+use crate::resources::PersistentSettings;
+
+/// What caused a UI sound, used to mute or replace sounds of one kind
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UiSoundKind {
+    WindowOpen,
+    WindowClose,
+    ButtonClick,
+    /// Clicking a disabled button or tab
+    Error,
+    /// Previews, pings and other sounds which are always played
+    Other,
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UiSoundTheme {
+    /// The sounds set by each dialog
+    #[default]
+    Classic,
+    /// Sounds chosen from the sound database, unset kinds keep the classic sound
+    Custom,
+}
+
+/// Sound database ids used by the custom theme
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UiSoundOverrides {
+    pub window_open: Option<u16>,
+    pub window_close: Option<u16>,
+    pub button_click: Option<u16>,
+    pub error: Option<u16>,
+}
+
+impl UiSoundOverrides {
+    pub fn get_mut(&mut self, kind: UiSoundKind) -> Option<&mut Option<u16>> {
+        match kind {
+            UiSoundKind::WindowOpen => Some(&mut self.window_open),
+            UiSoundKind::WindowClose => Some(&mut self.window_close),
+            UiSoundKind::ButtonClick => Some(&mut self.button_click),
+            UiSoundKind::Error => Some(&mut self.error),
+            UiSoundKind::Other => None,
+        }
+    }
+
+    fn get(&self, kind: UiSoundKind) -> Option<u16> {
+        match kind {
+            UiSoundKind::WindowOpen => self.window_open,
+            UiSoundKind::WindowClose => self.window_close,
+            UiSoundKind::ButtonClick => self.button_click,
+            UiSoundKind::Error => self.error,
+            UiSoundKind::Other => None,
+        }
+    }
+}
+
+#[derive(Resource, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UiSoundSettings {
+    pub theme: UiSoundTheme,
+    pub window_open: bool,
+    pub window_close: bool,
+    pub button_click: bool,
+    pub error: bool,
+    pub custom_sounds: UiSoundOverrides,
+}
+
+impl Default for UiSoundSettings {
+    fn default() -> Self {
+        Self {
+            theme: UiSoundTheme::Classic,
+            window_open: true,
+            window_close: true,
+            button_click: true,
+            error: true,
+            custom_sounds: UiSoundOverrides::default(),
+        }
+    }
+}
+
+impl UiSoundSettings {
+    pub fn enabled_mut(&mut self, kind: UiSoundKind) -> Option<&mut bool> {
+        match kind {
+            UiSoundKind::WindowOpen => Some(&mut self.window_open),
+            UiSoundKind::WindowClose => Some(&mut self.window_close),
+            UiSoundKind::ButtonClick => Some(&mut self.button_click),
+            UiSoundKind::Error => Some(&mut self.error),
+            UiSoundKind::Other => None,
+        }
+    }
+
+    pub fn is_enabled(&self, kind: UiSoundKind) -> bool {
+        match kind {
+            UiSoundKind::WindowOpen => self.window_open,
+            UiSoundKind::WindowClose => self.window_close,
+            UiSoundKind::ButtonClick => self.button_click,
+            UiSoundKind::Error => self.error,
+            UiSoundKind::Other => true,
+        }
+    }
+
+    /// The sound to play in place of `sound_id` for the theme, `None` when `kind` is muted
+    pub fn resolve(&self, kind: UiSoundKind, sound_id: SoundId) -> Option<SoundId> {
+        if !self.is_enabled(kind) {
+            return None;
+        }
+
+        match self.theme {
+            UiSoundTheme::Classic => Some(sound_id),
+            UiSoundTheme::Custom => Some(
+                self.custom_sounds
+                    .get(kind)
+                    .and_then(SoundId::new)
+                    .unwrap_or(sound_id),
+            ),
+        }
+    }
+}
+
+impl PersistentSettings for UiSoundSettings {
+    const CONFIG_SECTION: &'static str = "ui_sound";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_mutes_and_overrides_by_kind() {
+        let classic_sound = SoundId::new(10).unwrap();
+        let mut settings = UiSoundSettings {
+            button_click: false,
+            custom_sounds: UiSoundOverrides {
+                window_open: Some(20),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert!(settings
+            .resolve(UiSoundKind::ButtonClick, classic_sound)
+            .is_none());
+        assert!(settings.resolve(UiSoundKind::WindowOpen, classic_sound) == Some(classic_sound));
+
+        settings.theme = UiSoundTheme::Custom;
+        assert!(settings.resolve(UiSoundKind::WindowOpen, classic_sound) == SoundId::new(20));
+        assert!(settings.resolve(UiSoundKind::WindowClose, classic_sound) == Some(classic_sound));
+        assert!(settings.resolve(UiSoundKind::Other, classic_sound) == Some(classic_sound));
+    }
+}
@@ -0,0 +1,117 @@
+use bevy::prelude::Resource;
+
+use crate::{components::Season, resources::SeasonSettings};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WeatherKind {
+    Clear,
+    Rain,
+    Snow,
+    Storm,
+    HeavyFog,
+}
+
+impl WeatherKind {
+    pub const ALL: [WeatherKind; 5] = [
+        WeatherKind::Clear,
+        WeatherKind::Rain,
+        WeatherKind::Snow,
+        WeatherKind::Storm,
+        WeatherKind::HeavyFog,
+    ];
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            WeatherKind::Clear => "Clear",
+            WeatherKind::Rain => "Rain",
+            WeatherKind::Snow => "Snow",
+            WeatherKind::Storm => "Storm",
+            WeatherKind::HeavyFog => "Heavy Fog",
+        }
+    }
+
+    /// Season whose particle system draws this weather
+    pub fn season(&self) -> Season {
+        match self {
+            WeatherKind::Clear | WeatherKind::HeavyFog => Season::None,
+            WeatherKind::Rain | WeatherKind::Storm => Season::Spring,
+            WeatherKind::Snow => Season::Winter,
+        }
+    }
+
+    /// Fog density used when the weather is selected
+    pub fn default_fog_density(&self) -> f32 {
+        match self {
+            WeatherKind::Clear => 0.0018,
+            WeatherKind::Rain | WeatherKind::Snow => 0.004,
+            WeatherKind::Storm => 0.008,
+            WeatherKind::HeavyFog => 0.02,
+        }
+    }
+}
+
+/// Debug override of the weather which replaces the season chosen in the
+/// settings until it is set back to auto. Not saved.
+#[derive(Resource)]
+pub struct WeatherOverride {
+    /// Forced weather, `None` leaves the season settings in control
+    pub weather: Option<WeatherKind>,
+    /// Scale of the particle spawn rate
+    pub intensity: f32,
+    /// Fog density written to `ZoneLighting` while overridden
+    pub fog_density: f32,
+    /// Season settings from before the override, restored when set back to auto
+    pub saved_season_settings: Option<SeasonSettings>,
+}
+
+impl Default for WeatherOverride {
+    fn default() -> Self {
+        Self {
+            weather: None,
+            intensity: 1.0,
+            fog_density: WeatherKind::Clear.default_fog_density(),
+            saved_season_settings: None,
+        }
+    }
+}
+
+impl WeatherOverride {
+    /// Season settings which draw `weather` on top of the user's `base` settings
+    pub fn season_settings(&self, weather: WeatherKind, base: &SeasonSettings) -> SeasonSettings {
+        let is_storm = weather == WeatherKind::Storm;
+        SeasonSettings {
+            enabled: true,
+            current_season: weather.season(),
+            spawn_rate: base.spawn_rate * self.intensity * if is_storm { 3.0 } else { 1.0 },
+            wind_strength: base.wind_strength * if is_storm { 4.0 } else { 1.0 },
+            ..base.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_storm_is_heavier_rain() {
+        let base = SeasonSettings {
+            enabled: false,
+            ..Default::default()
+        };
+        let weather_override = WeatherOverride {
+            intensity: 2.0,
+            ..Default::default()
+        };
+
+        let rain = weather_override.season_settings(WeatherKind::Rain, &base);
+        assert!(rain.enabled);
+        assert_eq!(rain.current_season, Season::Spring);
+        assert_eq!(rain.spawn_rate, base.spawn_rate * 2.0);
+
+        let storm = weather_override.season_settings(WeatherKind::Storm, &base);
+        assert_eq!(storm.current_season, Season::Spring);
+        assert!(storm.spawn_rate > rain.spawn_rate);
+        assert!(storm.wind_strength > rain.wind_strength);
+    }
+}
@@ -0,0 +1,101 @@
+use bevy::prelude::{Color, Entity, Resource, Vec3};
+
+/// How long a ping stays in the world
+pub const WORLD_PING_LIFETIME_SECS: f32 = 6.0;
+
+/// Most pings shown at once, placing another removes the oldest
+const MAX_WORLD_PINGS: usize = 5;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WorldPingKind {
+    AttackHere,
+    Help,
+    OnMyWay,
+    Danger,
+}
+
+impl WorldPingKind {
+    pub const ALL: [WorldPingKind; 4] = [
+        WorldPingKind::AttackHere,
+        WorldPingKind::Help,
+        WorldPingKind::OnMyWay,
+        WorldPingKind::Danger,
+    ];
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            WorldPingKind::AttackHere => "Attack Here",
+            WorldPingKind::Help => "Help",
+            WorldPingKind::OnMyWay => "On My Way",
+            WorldPingKind::Danger => "Danger",
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            WorldPingKind::AttackHere => Color::srgb(1.0, 0.35, 0.2),
+            WorldPingKind::Help => Color::srgb(0.3, 0.85, 1.0),
+            WorldPingKind::OnMyWay => Color::srgb(0.4, 1.0, 0.4),
+            WorldPingKind::Danger => Color::srgb(1.0, 0.85, 0.1),
+        }
+    }
+}
+
+pub struct WorldPing {
+    pub kind: WorldPingKind,
+    pub position: Vec3,
+    pub expires_at: f32,
+    /// Entity of the world marker, spawned by `world_ping_system`
+    pub marker_entity: Option<Entity>,
+}
+
+#[derive(Default, Resource)]
+pub struct WorldPings {
+    pub pings: Vec<WorldPing>,
+    /// Pings removed since the last update, their markers still need to be despawned
+    pub removed: Vec<WorldPing>,
+}
+
+impl WorldPings {
+    pub fn add(&mut self, kind: WorldPingKind, position: Vec3, now: f32) {
+        if self.pings.len() >= MAX_WORLD_PINGS {
+            let oldest = self.pings.remove(0);
+            self.removed.push(oldest);
+        }
+
+        self.pings.push(WorldPing {
+            kind,
+            position,
+            expires_at: now + WORLD_PING_LIFETIME_SECS,
+            marker_entity: None,
+        });
+    }
+
+    pub fn remove_expired(&mut self, now: f32) {
+        let (expired, active): (Vec<_>, Vec<_>) = std::mem::take(&mut self.pings)
+            .into_iter()
+            .partition(|ping| ping.expires_at <= now);
+        self.pings = active;
+        self.removed.extend(expired);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pings_expire_and_are_limited() {
+        let mut world_pings = WorldPings::default();
+        for i in 0..MAX_WORLD_PINGS + 2 {
+            world_pings.add(WorldPingKind::Help, Vec3::ZERO, i as f32);
+        }
+        assert_eq!(world_pings.pings.len(), MAX_WORLD_PINGS);
+        assert_eq!(world_pings.removed.len(), 2);
+        assert_eq!(world_pings.pings[0].expires_at, 2.0 + WORLD_PING_LIFETIME_SECS);
+
+        world_pings.remove_expired(4.0 + WORLD_PING_LIFETIME_SECS);
+        assert_eq!(world_pings.pings.len(), MAX_WORLD_PINGS - 3);
+        assert_eq!(world_pings.removed.len(), 5);
+    }
+}
@@ -0,0 +1,11 @@
+use bevy::prelude::Res;
+
+use crate::resources::{set_current_accessibility, AccessibilitySettings};
+
+/// Copies `AccessibilitySettings` for the tooltip and name tag helpers which
+/// read the current palette without access to the resource.
+pub fn accessibility_settings_system(accessibility_settings: Res<AccessibilitySettings>) {
+    if accessibility_settings.is_changed() {
+        set_current_accessibility(*accessibility_settings);
+    }
+}
@@ -0,0 +1,159 @@
+use bevy::{
+    platform::collections::HashSet,
+    prelude::{
+        Commands, Entity, Local, MessageReader, MessageWriter, Query, Res, ResMut, Time, With,
+    },
+};
+
+use rose_game_common::{components::CharacterInfo, messages::client::ClientMessage};
+
+use crate::{
+    components::{AfkStatus, PlayerCharacter},
+    events::{ChatboxEvent, PlayerCommandEvent},
+    resources::{afk_auto_reply, is_afk_auto_reply, AfkSettings, GameConnection, InputActivity},
+};
+
+/// Seconds after going AFK during which input does not end it, so the key
+/// pressed to send `/afk` does not immediately bring the player back
+const AFK_INPUT_GRACE_SECONDS: f32 = 1.0;
+
+/// Parses `/afk [message]` (case-insensitive), returning the optional message
+pub fn parse_afk_command(message: &str) -> Option<Option<String>> {
+    let trimmed = message.trim();
+    let (command, rest) = trimmed
+        .split_once(char::is_whitespace)
+        .unwrap_or((trimmed, ""));
+    if !command.eq_ignore_ascii_case("/afk") {
+        return None;
+    }
+
+    let rest = rest.trim();
+    Some((!rest.is_empty()).then(|| rest.to_string()))
+}
+
+pub fn afk_system(
+    mut commands: Commands,
+    mut query_player: Query<(Entity, Option<&mut AfkStatus>), With<PlayerCharacter>>,
+    mut player_command_events: MessageReader<PlayerCommandEvent>,
+    mut chatbox_events: MessageWriter<ChatboxEvent>,
+    mut afk_settings: ResMut<AfkSettings>,
+    input_activity: Res<InputActivity>,
+    time: Res<Time>,
+) {
+    let afk_command = player_command_events
+        .read()
+        .filter_map(|event| match event {
+            PlayerCommandEvent::Afk(message) => Some(message.clone()),
+            _ => None,
+        })
+        .last();
+
+    let Ok((player_entity, afk_status)) = query_player.single_mut() else {
+        return;
+    };
+    let now = time.elapsed_secs();
+
+    let go_afk = match (afk_command, afk_status) {
+        (Some(Some(message)), Some(mut afk_status)) => {
+            chatbox_events.write(ChatboxEvent::System(format!(
+                "AFK message changed to: {}",
+                message
+            )));
+            afk_status.message = message.clone();
+            afk_settings.message = message;
+            false
+        }
+        (Some(Some(message)), None) => {
+            afk_settings.message = message;
+            true
+        }
+        (Some(None), Some(_)) => {
+            commands.entity(player_entity).remove::<AfkStatus>();
+            chatbox_events.write(ChatboxEvent::System("You are no longer AFK.".to_string()));
+            false
+        }
+        (Some(None), None) => true,
+        (None, Some(afk_status)) => {
+            if input_activity.last_input_time > afk_status.since + AFK_INPUT_GRACE_SECONDS {
+                commands.entity(player_entity).remove::<AfkStatus>();
+                chatbox_events.write(ChatboxEvent::System("You are no longer AFK.".to_string()));
+            }
+            false
+        }
+        (None, None) => afk_settings.should_auto_afk(input_activity.idle_for(now)),
+    };
+
+    if go_afk {
+        chatbox_events.write(ChatboxEvent::System(format!(
+            "You are now AFK: {}",
+            afk_settings.message
+        )));
+        commands.entity(player_entity).insert(AfkStatus {
+            message: afk_settings.message.clone(),
+            since: now,
+        });
+    }
+}
+
+/// Names already auto replied to since the player went AFK
+#[derive(Default)]
+pub struct AfkRepliedTo {
+    since: f32,
+    names: HashSet<String>,
+}
+
+pub fn afk_auto_reply_system(
+    query_player: Query<(&AfkStatus, &CharacterInfo), With<PlayerCharacter>>,
+    mut chatbox_events: MessageReader<ChatboxEvent>,
+    mut replied_to: Local<AfkRepliedTo>,
+    game_connection: Option<Res<GameConnection>>,
+) {
+    let Ok((afk_status, character_info)) = query_player.single() else {
+        chatbox_events.clear();
+        return;
+    };
+
+    if replied_to.since != afk_status.since {
+        replied_to.since = afk_status.since;
+        replied_to.names.clear();
+    }
+
+    for event in chatbox_events.read() {
+        let ChatboxEvent::Whisper(from, text) = event else {
+            continue;
+        };
+
+        if *from == character_info.name
+            || is_afk_auto_reply(text)
+            || !replied_to.names.insert(from.clone())
+        {
+            continue;
+        }
+
+        if let Some(game_connection) = game_connection.as_ref() {
+            game_connection
+                .client_message_tx
+                .send(ClientMessage::Chat {
+                    text: afk_auto_reply(from, &afk_status.message),
+                })
+                .ok();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_afk_command_with_and_without_message() {
+        assert_eq!(parse_afk_command("/afk"), Some(None));
+        assert_eq!(parse_afk_command(" /AFK "), Some(None));
+        assert_eq!(
+            parse_afk_command("/afk  back in 5"),
+            Some(Some("back in 5".to_string()))
+        );
+        assert_eq!(parse_afk_command("/afkk"), None);
+        assert_eq!(parse_afk_command("afk"), None);
+    }
+}
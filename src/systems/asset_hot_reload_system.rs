@@ -0,0 +1,177 @@
+use std::time::Instant;
+
+use bevy::{
+    asset::AssetEvent,
+    pbr::{ExtendedMaterial, StandardMaterial},
+    prelude::{AssetServer, Assets, Image, Mesh, MessageReader, MessageWriter, Res, ResMut},
+};
+
+use crate::{
+    events::LoadZoneEvent,
+    render::object_material_extension::RoseObjectExtension,
+    resources::{AssetHotReload, CurrentZone, ASSET_HOT_RELOAD_DEBOUNCE},
+    vfs_asset_io::remove_from_vfs_file_cache,
+    vfs_path_resolver::normalize_vfs_path,
+};
+
+/// Files which are read directly by the zone loader, changing any of these
+/// respawns the current zone
+const ZONE_FILE_EXTENSIONS: [&str; 6] = ["IFO", "HIM", "TIL", "ZON", "LIT", "ZSC"];
+
+/// Normalized uppercase key for comparing an asset path to a changed file,
+/// ignoring the loader selection suffixes added by the model loader
+fn asset_path_key(path: &str) -> String {
+    normalize_vfs_path(
+        path.trim_end_matches(".no_skin")
+            .trim_end_matches(".zmo_texture"),
+    )
+    .to_uppercase()
+}
+
+pub fn asset_hot_reload_system(
+    hot_reload: Option<ResMut<AssetHotReload>>,
+    asset_server: Res<AssetServer>,
+    images: Res<Assets<Image>>,
+    meshes: Res<Assets<Mesh>>,
+    current_zone: Option<Res<CurrentZone>>,
+    mut load_zone_events: MessageWriter<LoadZoneEvent>,
+) {
+    let Some(mut hot_reload) = hot_reload else {
+        return;
+    };
+
+    while let Ok(path) = hot_reload.changed_rx.try_recv() {
+        if let Ok(relative_path) = path.strip_prefix(&hot_reload.base_path) {
+            let relative_path = relative_path.to_path_buf();
+            hot_reload.pending.insert(relative_path);
+            hot_reload.last_change = Some(Instant::now());
+        }
+    }
+
+    if hot_reload.last_change.map_or(true, |last_change| {
+        last_change.elapsed() < ASSET_HOT_RELOAD_DEBOUNCE
+    }) {
+        return;
+    }
+    hot_reload.last_change = None;
+
+    let mut reload_zone = false;
+    let pending: Vec<_> = hot_reload.pending.drain().collect();
+    for relative_path in pending {
+        let path_str = relative_path.to_string_lossy();
+        let key = asset_path_key(&path_str);
+        remove_from_vfs_file_cache(&path_str);
+
+        let extension = relative_path
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_uppercase())
+            .unwrap_or_default();
+
+        if ZONE_FILE_EXTENSIONS.contains(&extension.as_str()) {
+            log::info!("[HOT RELOAD] Zone file changed: {}", path_str);
+            reload_zone = true;
+            continue;
+        }
+
+        let mut reloaded = false;
+        for id in images.ids() {
+            if let Some(asset_path) = asset_server.get_path(id) {
+                if asset_path_key(&asset_path.path().to_string_lossy()) == key {
+                    asset_server.reload(asset_path);
+                    hot_reload.reloaded_images.insert(id);
+                    reloaded = true;
+                }
+            }
+        }
+
+        for id in meshes.ids() {
+            if let Some(asset_path) = asset_server.get_path(id) {
+                if asset_path_key(&asset_path.path().to_string_lossy()) == key {
+                    asset_server.reload(asset_path);
+                    reloaded = true;
+                }
+            }
+        }
+
+        if reloaded {
+            log::info!("[HOT RELOAD] Reloaded {}", path_str);
+        } else {
+            log::debug!(
+                "[HOT RELOAD] Ignoring {}, it is not used by any loaded asset",
+                path_str
+            );
+        }
+    }
+
+    if reload_zone {
+        if let Some(current_zone) = current_zone {
+            log::info!("[HOT RELOAD] Respawning zone {}", current_zone.id.get());
+            load_zone_events.write(LoadZoneEvent::new(current_zone.id));
+        }
+    }
+}
+
+/// Touches materials using a reloaded texture so their bind groups are rebuilt
+pub fn asset_hot_reload_material_system(
+    hot_reload: Option<ResMut<AssetHotReload>>,
+    mut image_events: MessageReader<AssetEvent<Image>>,
+    mut standard_materials: ResMut<Assets<StandardMaterial>>,
+    mut object_materials: ResMut<Assets<ExtendedMaterial<StandardMaterial, RoseObjectExtension>>>,
+) {
+    let Some(mut hot_reload) = hot_reload else {
+        image_events.clear();
+        return;
+    };
+
+    for event in image_events.read() {
+        let AssetEvent::Modified { id } = *event else {
+            continue;
+        };
+
+        if !hot_reload.reloaded_images.remove(&id) {
+            continue;
+        }
+
+        let uses_image = |material: &StandardMaterial| {
+            [
+                &material.base_color_texture,
+                &material.normal_map_texture,
+                &material.emissive_texture,
+            ]
+            .into_iter()
+            .any(|texture| texture.as_ref().map(|handle| handle.id()) == Some(id))
+        };
+
+        let standard_ids: Vec<_> = standard_materials
+            .iter()
+            .filter(|(_, material)| uses_image(material))
+            .map(|(material_id, _)| material_id)
+            .collect();
+        for material_id in standard_ids {
+            standard_materials.get_mut(material_id);
+        }
+
+        let object_ids: Vec<_> = object_materials
+            .iter()
+            .filter(|(_, material)| {
+                uses_image(&material.base)
+                    || material
+                        .extension
+                        .lightmap_texture
+                        .as_ref()
+                        .map(|handle| handle.id())
+                        == Some(id)
+                    || material
+                        .extension
+                        .specular_texture
+                        .as_ref()
+                        .map(|handle| handle.id())
+                        == Some(id)
+            })
+            .map(|(material_id, _)| material_id)
+            .collect();
+        for material_id in object_ids {
+            object_materials.get_mut(material_id);
+        }
+    }
+}
@@ -1,8 +1,11 @@
-use bevy::prelude::{MessageWriter, Local, Res, State};
+use bevy::prelude::{Local, MessageReader, MessageWriter, Res, ResMut, State, Time};
 
 use crate::{
-    events::{CharacterSelectEvent, LoginEvent},
-    resources::{AppState, CharacterList, CharacterSelectState, ServerConfiguration, ServerList},
+    events::{CharacterSelectEvent, LoginConnectionEvent, LoginEvent, MessageBoxEvent},
+    resources::{
+        AppState, CharacterList, CharacterSelectState, LoginState, ServerConfiguration,
+        ServerList,
+    },
 };
 
 #[derive(Default)]
@@ -12,22 +15,89 @@ pub enum AutoLoginState {
     WaitServerList,
     WaitCharacterList,
     SelectedCharacter,
+    WaitRetry { remaining_secs: f32 },
+    Stopped,
 }
 
+fn retry_delay(server_configuration: &ServerConfiguration, attempt: u32) -> f32 {
+    let exponent = attempt.saturating_sub(1).min(16) as i32;
+    (server_configuration.auto_login_retry_base_delay * 2.0f32.powi(exponent))
+        .min(server_configuration.auto_login_retry_max_delay)
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn auto_login_system(
     mut auto_login_state: Local<AutoLoginState>,
+    mut retry_attempt: Local<u32>,
     app_state: Res<State<AppState>>,
     character_list: Option<Res<CharacterList>>,
     character_select_state: Option<Res<CharacterSelectState>>,
     server_list: Option<Res<ServerList>>,
     server_configuration: Res<ServerConfiguration>,
+    mut login_state: Option<ResMut<LoginState>>,
+    mut login_connection_events: MessageReader<LoginConnectionEvent>,
     mut login_events: MessageWriter<LoginEvent>,
     mut character_select_events: MessageWriter<CharacterSelectEvent>,
+    mut message_box_events: MessageWriter<MessageBoxEvent>,
+    time: Res<Time>,
 ) {
     if !server_configuration.auto_login {
         return;
     }
 
+    for event in login_connection_events.read() {
+        match event {
+            LoginConnectionEvent::LoggedIn => {
+                *retry_attempt = 0;
+            }
+            LoginConnectionEvent::LoginRejected => {
+                // Bad credentials will not fix themselves, leave it to the user
+                log::warn!("[AUTO_LOGIN] Login rejected by server, not retrying");
+                *auto_login_state = AutoLoginState::Stopped;
+            }
+            LoginConnectionEvent::ConnectionFailed { error } => {
+                let is_logging_in = matches!(*auto_login_state, AutoLoginState::WaitServerList)
+                    || (matches!(*auto_login_state, AutoLoginState::WaitCharacterList)
+                        && matches!(app_state.get(), AppState::GameLogin));
+
+                if is_logging_in && *retry_attempt < server_configuration.auto_login_max_retries {
+                    *retry_attempt += 1;
+                    let delay = retry_delay(&server_configuration, *retry_attempt);
+                    log::info!(
+                        "[AUTO_LOGIN] Connection failed: {}, retry {} of {} in {:.1}s",
+                        error,
+                        *retry_attempt,
+                        server_configuration.auto_login_max_retries,
+                        delay
+                    );
+
+                    *auto_login_state = AutoLoginState::WaitRetry {
+                        remaining_secs: delay,
+                    };
+                    if let Some(login_state) = login_state.as_mut() {
+                        **login_state = LoginState::Reconnecting {
+                            attempt: *retry_attempt,
+                            max_attempts: server_configuration.auto_login_max_retries,
+                            remaining_secs: delay,
+                        };
+                    }
+                } else if !matches!(app_state.get(), AppState::Game) {
+                    // In game the login connection is only used by the reconnect system
+                    if is_logging_in {
+                        *auto_login_state = AutoLoginState::Stopped;
+                    }
+
+                    message_box_events.write(MessageBoxEvent::Show {
+                        message: format!("Connection to login server lost: {}", error),
+                        modal: true,
+                        ok: None,
+                        cancel: None,
+                    });
+                }
+            }
+        }
+    }
+
     match *auto_login_state {
         AutoLoginState::Login => {
             if matches!(app_state.get(), AppState::GameLogin) {
@@ -114,6 +184,30 @@ pub fn auto_login_system(
                 }
             }
         }
-        AutoLoginState::SelectedCharacter => {}
+        AutoLoginState::WaitRetry {
+            ref mut remaining_secs,
+        } => {
+            *remaining_secs -= time.delta_secs();
+            let remaining_secs = *remaining_secs;
+
+            if let Some(login_state) = login_state.as_mut() {
+                if remaining_secs > 0.0 {
+                    if let LoginState::Reconnecting {
+                        remaining_secs: ref mut login_state_remaining_secs,
+                        ..
+                    } = **login_state
+                    {
+                        *login_state_remaining_secs = remaining_secs;
+                    }
+                } else {
+                    **login_state = LoginState::Input;
+                }
+            }
+
+            if remaining_secs <= 0.0 {
+                *auto_login_state = AutoLoginState::Login;
+            }
+        }
+        AutoLoginState::SelectedCharacter | AutoLoginState::Stopped => {}
     }
 }
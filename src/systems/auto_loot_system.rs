@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+
+use bevy::{
+    input::ButtonInput,
+    prelude::{Entity, KeyCode, Local, MessageWriter, Query, Res, Time, With, Without},
+};
+use bevy_egui::EguiContexts;
+
+use rose_game_common::{components::ItemDrop, messages::client::ClientMessage};
+
+use crate::{
+    components::{ClientEntity, Command, Dead, NextCommand, PlayerCharacter, Position},
+    events::PlayerCommandEvent,
+    resources::{GameConnection, GameData, LootFilterSettings, LootRarity},
+};
+
+/// How long the player can stand idle next to a queued item drop before it is
+/// skipped, e.g. when the server refused the pickup
+const LOOT_ALL_SKIP_SECS: f32 = 1.0;
+
+/// How often auto-loot asks the server again for an item drop it could not pick up
+const AUTO_LOOT_RETRY_SECS: f32 = 1.0;
+
+#[derive(Default)]
+pub struct LootAllQueue {
+    pending: Vec<Entity>,
+    current: Option<(Entity, f32)>,
+}
+
+impl LootAllQueue {
+    fn clear(&mut self) {
+        self.pending.clear();
+        self.current = None;
+    }
+}
+
+/// Loot all queues every wanted item drop near the player and walks to each in
+/// turn, nearest first. Auto-loot asks the server to pick up wanted item drops
+/// as soon as the player is standing over them.
+pub fn auto_loot_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut egui_ctx: EguiContexts,
+    time: Res<Time>,
+    game_connection: Option<Res<GameConnection>>,
+    game_data: Res<GameData>,
+    loot_filter_settings: Res<LootFilterSettings>,
+    query_player: Query<(&Position, &Command, &NextCommand), (With<PlayerCharacter>, Without<Dead>)>,
+    query_item_drops: Query<(Entity, &ItemDrop, &Position, &ClientEntity)>,
+    mut player_command_events: MessageWriter<PlayerCommandEvent>,
+    mut loot_all_queue: Local<LootAllQueue>,
+    mut auto_loot_attempts: Local<HashMap<Entity, f32>>,
+) {
+    let Ok((player_position, player_command, player_next_command)) = query_player.single() else {
+        loot_all_queue.clear();
+        return;
+    };
+    let now = time.elapsed_secs();
+
+    // Distances are in metres, positions are in centimetres
+    let distance_to = |position: &Position| {
+        player_position.position.xy().distance(position.position.xy()) / 100.0
+    };
+    let is_wanted = |item_drop: &ItemDrop, include_filtered: bool| {
+        item_drop.item.as_ref().is_some_and(|dropped_item| {
+            include_filtered
+                || loot_filter_settings.pickup
+                    [LootRarity::from_dropped_item(dropped_item, &game_data)]
+        })
+    };
+
+    let keyboard_free = !egui_ctx
+        .ctx_mut()
+        .map_or(false, |ctx| ctx.wants_keyboard_input());
+    if keyboard_free && keyboard_input.just_pressed(loot_filter_settings.loot_all_key.key_code()) {
+        let include_filtered =
+            keyboard_input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+        let mut nearby: Vec<(Entity, f32)> = query_item_drops
+            .iter()
+            .filter(|(_, item_drop, _, _)| is_wanted(item_drop, include_filtered))
+            .map(|(entity, _, position, _)| (entity, distance_to(position)))
+            .filter(|(_, distance)| *distance <= loot_filter_settings.loot_all_radius)
+            .collect();
+
+        // Nearest last so it is popped first
+        nearby.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+        loot_all_queue.pending = nearby.into_iter().map(|(entity, _)| entity).collect();
+        loot_all_queue.current = None;
+    }
+
+    if let Some((current, queued_at)) = loot_all_queue.current {
+        let player_idle = player_command.is_stop() && player_next_command.is_none();
+        let targets_current = player_command.get_target() == Some(current)
+            || player_next_command
+                .as_ref()
+                .is_some_and(|next_command| next_command.get_target() == Some(current));
+
+        if !query_item_drops.contains(current)
+            || (player_idle && now - queued_at > LOOT_ALL_SKIP_SECS)
+        {
+            loot_all_queue.current = None;
+        } else if !player_idle && !targets_current {
+            // The player has done something else, stop looting
+            loot_all_queue.clear();
+        }
+    }
+
+    if loot_all_queue.current.is_none() {
+        while let Some(entity) = loot_all_queue.pending.pop() {
+            if let Ok((_, _, position, _)) = query_item_drops.get(entity) {
+                // Once we are close enough the command_system will send the pickup
+                player_command_events.write(PlayerCommandEvent::Move(position.clone(), Some(entity)));
+                loot_all_queue.current = Some((entity, now));
+                break;
+            }
+        }
+    }
+
+    auto_loot_attempts.retain(|entity, _| query_item_drops.contains(*entity));
+    if !loot_filter_settings.auto_loot {
+        return;
+    }
+
+    let Some(game_connection) = game_connection else {
+        return;
+    };
+
+    for (entity, item_drop, position, client_entity) in query_item_drops.iter() {
+        if distance_to(position) > loot_filter_settings.auto_loot_radius
+            || !is_wanted(item_drop, false)
+            || auto_loot_attempts
+                .get(&entity)
+                .is_some_and(|attempted_at| now - attempted_at < AUTO_LOOT_RETRY_SECS)
+        {
+            continue;
+        }
+
+        game_connection
+            .client_message_tx
+            .send(ClientMessage::PickupItemDrop {
+                target_entity_id: client_entity.id,
+            })
+            .ok();
+        auto_loot_attempts.insert(entity, now);
+    }
+}
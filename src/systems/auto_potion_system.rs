@@ -0,0 +1,131 @@
+use bevy::prelude::{Local, MessageWriter, Query, Res, ResMut, Time, With, Without};
+
+use rose_data::{ItemReference, ItemType};
+use rose_game_common::components::{
+    AbilityValues, HealthPoints, Inventory, InventoryPageType, ItemSlot, ManaPoints,
+    INVENTORY_PAGE_SIZE,
+};
+
+use crate::{
+    components::{ConsumableCooldownGroup, Cooldowns, Dead, PlayerCharacter},
+    events::{ChatboxEvent, PlayerCommandEvent},
+    resources::{AutoPotionNotice, AutoPotionSettings, GameData},
+};
+
+/// Seconds before the same potion is tried again, as the item cooldown only
+/// starts once the server confirms the item was used
+const AUTO_POTION_RETRY_SECONDS: f32 = 1.0;
+
+/// First inventory slot holding the consumable `item_number`
+pub fn find_consumable_slot(inventory: &Inventory, item_number: usize) -> Option<ItemSlot> {
+    (0..INVENTORY_PAGE_SIZE)
+        .map(|index| ItemSlot::Inventory(InventoryPageType::Consumables, index))
+        .find(|&item_slot| {
+            inventory.get_item(item_slot).map_or(false, |item| {
+                item.get_item_type() == ItemType::Consumable
+                    && item.get_item_number() == item_number
+            })
+        })
+}
+
+/// Total quantity of the consumable `item_number` across the inventory
+pub fn count_consumable_items(inventory: &Inventory, item_number: usize) -> usize {
+    (0..INVENTORY_PAGE_SIZE)
+        .filter_map(|index| {
+            inventory.get_item(ItemSlot::Inventory(InventoryPageType::Consumables, index))
+        })
+        .filter(|item| {
+            item.get_item_type() == ItemType::Consumable && item.get_item_number() == item_number
+        })
+        .map(|item| item.get_quantity() as usize)
+        .sum()
+}
+
+#[derive(Default)]
+pub struct AutoPotionState {
+    hp_last_used: Option<f32>,
+    mp_last_used: Option<f32>,
+}
+
+pub fn auto_potion_system(
+    mut state: Local<AutoPotionState>,
+    query_player: Query<
+        (
+            &HealthPoints,
+            &ManaPoints,
+            &AbilityValues,
+            &Inventory,
+            &Cooldowns,
+        ),
+        (With<PlayerCharacter>, Without<Dead>),
+    >,
+    mut player_command_events: MessageWriter<PlayerCommandEvent>,
+    mut chatbox_events: MessageWriter<ChatboxEvent>,
+    mut auto_potion_notice: ResMut<AutoPotionNotice>,
+    auto_potion_settings: Res<AutoPotionSettings>,
+    game_data: Res<GameData>,
+    time: Res<Time>,
+) {
+    if !auto_potion_settings.enabled {
+        return;
+    }
+
+    let Ok((health_points, mana_points, ability_values, inventory, cooldowns)) =
+        query_player.single()
+    else {
+        return;
+    };
+    if health_points.hp <= 0 {
+        return;
+    }
+
+    let now = time.elapsed_secs();
+    let health_fraction = health_points.hp as f32 / ability_values.get_max_health().max(1) as f32;
+    let mana_fraction = mana_points.mp as f32 / ability_values.get_max_mana().max(1) as f32;
+    let state = &mut *state;
+
+    for (item_number, last_used) in [
+        (
+            auto_potion_settings.hp_item_to_use(health_fraction),
+            &mut state.hp_last_used,
+        ),
+        (
+            auto_potion_settings.mp_item_to_use(mana_fraction),
+            &mut state.mp_last_used,
+        ),
+    ] {
+        let Some(item_number) = item_number else {
+            continue;
+        };
+
+        if last_used.map_or(false, |last_used| {
+            now - last_used < AUTO_POTION_RETRY_SECONDS
+        }) {
+            continue;
+        }
+
+        let item_reference = ItemReference::new(ItemType::Consumable, item_number);
+        if ConsumableCooldownGroup::from_item(&item_reference, &game_data)
+            .and_then(|cooldown_group| cooldowns.get_consumable_cooldown_percent(cooldown_group))
+            .is_some()
+        {
+            continue;
+        }
+
+        let Some(item_slot) = find_consumable_slot(inventory, item_number) else {
+            continue;
+        };
+
+        *last_used = Some(now);
+        player_command_events.write(PlayerCommandEvent::UseItem(item_slot));
+
+        let item_name = game_data
+            .items
+            .get_base_item(item_reference)
+            .map_or("a potion", |item_data| item_data.name.as_str());
+        let text = format!("Auto-potion used {}", item_name);
+        chatbox_events.write(ChatboxEvent::System(text.clone()));
+        auto_potion_notice.text = text;
+        auto_potion_notice.time = now;
+    }
+}
@@ -0,0 +1,328 @@
+//! Benchmark mode
+//!
+//! Once the startup zone is loaded the camera flies along the configured
+//! waypoints at a fixed speed while frame times from `FrameTimeDiagnosticsPlugin`
+//! are recorded. At the end of the path a summary is printed, optionally written
+//! as JSON, and the application exits.
+
+use std::time::Duration;
+
+use bevy::{
+    app::AppExit,
+    diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin},
+    prelude::*,
+};
+use serde::Serialize;
+
+use crate::{events::ZoneEvent, systems::FreeCamera, BenchmarkConfig};
+
+pub struct BenchmarkPlugin {
+    pub config: BenchmarkConfig,
+}
+
+impl Plugin for BenchmarkPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(BenchmarkState::new(self.config.clone()))
+            .add_systems(Update, benchmark_system);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BenchmarkPhase {
+    WaitingForZone,
+    Warmup,
+    Running,
+    Finished,
+}
+
+#[derive(Resource)]
+pub struct BenchmarkState {
+    config: BenchmarkConfig,
+    path: BenchmarkPath,
+    phase: BenchmarkPhase,
+    elapsed: Duration,
+    /// Frame time in milliseconds and the path segment it was recorded on
+    frames: Vec<(f64, usize)>,
+}
+
+impl BenchmarkState {
+    fn new(config: BenchmarkConfig) -> Self {
+        let path = BenchmarkPath::new(config.waypoints.iter().copied().map(Vec3::from).collect());
+        Self {
+            config,
+            path,
+            phase: BenchmarkPhase::WaitingForZone,
+            elapsed: Duration::ZERO,
+            frames: Vec::new(),
+        }
+    }
+}
+
+/// Waypoints of the camera path with the distance travelled at each of them
+pub struct BenchmarkPath {
+    waypoints: Vec<Vec3>,
+    distances: Vec<f32>,
+}
+
+impl BenchmarkPath {
+    pub fn new(waypoints: Vec<Vec3>) -> Self {
+        let mut distances = Vec::with_capacity(waypoints.len());
+        let mut distance = 0.0;
+        for (index, waypoint) in waypoints.iter().enumerate() {
+            if index > 0 {
+                distance += waypoints[index - 1].distance(*waypoint);
+            }
+            distances.push(distance);
+        }
+        Self { waypoints, distances }
+    }
+
+    pub fn length(&self) -> f32 {
+        self.distances.last().copied().unwrap_or(0.0)
+    }
+
+    /// Segment index, position and the point to look at after travelling a distance
+    pub fn sample(&self, distance: f32) -> Option<(usize, Vec3, Vec3)> {
+        if self.waypoints.len() < 2 {
+            return None;
+        }
+
+        let distance = distance.clamp(0.0, self.length());
+        let segment = self
+            .distances
+            .windows(2)
+            .position(|window| distance <= window[1])
+            .unwrap_or(self.waypoints.len() - 2);
+        let start = self.waypoints[segment];
+        let end = self.waypoints[segment + 1];
+        let segment_length = self.distances[segment + 1] - self.distances[segment];
+        let t = if segment_length > 0.0 {
+            (distance - self.distances[segment]) / segment_length
+        } else {
+            1.0
+        };
+        Some((segment, start.lerp(end, t), end))
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct SegmentSummary {
+    pub segment: usize,
+    pub frames: usize,
+    pub avg_frame_time_ms: f64,
+    pub max_frame_time_ms: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BenchmarkSummary {
+    pub frames: usize,
+    pub duration_secs: f64,
+    pub avg_fps: f64,
+    pub one_percent_low_fps: f64,
+    pub avg_frame_time_ms: f64,
+    pub min_frame_time_ms: f64,
+    pub max_frame_time_ms: f64,
+    pub segments: Vec<SegmentSummary>,
+}
+
+impl BenchmarkSummary {
+    /// Summarise frame times in milliseconds with the path segment they were recorded on
+    pub fn new(frames: &[(f64, usize)], segment_count: usize) -> Option<Self> {
+        if frames.is_empty() {
+            return None;
+        }
+
+        let mut frame_times: Vec<f64> = frames.iter().map(|(frame_time, _)| *frame_time).collect();
+        frame_times.sort_by(|a, b| b.total_cmp(a));
+
+        let total: f64 = frame_times.iter().sum();
+        let avg_frame_time_ms = total / frame_times.len() as f64;
+        // 1% low is the frame rate of the slowest 1% of frames
+        let slowest = &frame_times[..(frame_times.len() / 100).max(1)];
+        let slowest_avg_ms = slowest.iter().sum::<f64>() / slowest.len() as f64;
+
+        let segments = (0..segment_count)
+            .filter_map(|segment| {
+                let segment_frames: Vec<f64> = frames
+                    .iter()
+                    .filter(|(_, frame_segment)| *frame_segment == segment)
+                    .map(|(frame_time, _)| *frame_time)
+                    .collect();
+                if segment_frames.is_empty() {
+                    return None;
+                }
+                Some(SegmentSummary {
+                    segment,
+                    frames: segment_frames.len(),
+                    avg_frame_time_ms: segment_frames.iter().sum::<f64>() / segment_frames.len() as f64,
+                    max_frame_time_ms: segment_frames.iter().copied().fold(0.0, f64::max),
+                })
+            })
+            .collect();
+
+        Some(Self {
+            frames: frame_times.len(),
+            duration_secs: total / 1000.0,
+            avg_fps: 1000.0 / avg_frame_time_ms,
+            one_percent_low_fps: 1000.0 / slowest_avg_ms,
+            avg_frame_time_ms,
+            min_frame_time_ms: frame_times[frame_times.len() - 1],
+            max_frame_time_ms: frame_times[0],
+            segments,
+        })
+    }
+
+    fn print(&self) {
+        println!("=== Benchmark Summary ===");
+        println!("Frames: {} in {:.1}s", self.frames, self.duration_secs);
+        println!(
+            "FPS: avg {:.1}, 1% low {:.1}",
+            self.avg_fps, self.one_percent_low_fps
+        );
+        println!(
+            "Frame time: avg {:.2}ms, min {:.2}ms, max {:.2}ms",
+            self.avg_frame_time_ms, self.min_frame_time_ms, self.max_frame_time_ms
+        );
+        for segment in self.segments.iter() {
+            println!(
+                "Segment {}: {} frames, avg {:.2}ms, max {:.2}ms",
+                segment.segment, segment.frames, segment.avg_frame_time_ms, segment.max_frame_time_ms
+            );
+        }
+    }
+}
+
+fn finish_benchmark(state: &BenchmarkState) {
+    let Some(summary) = BenchmarkSummary::new(&state.frames, state.path.waypoints.len().saturating_sub(1)) else {
+        log::warn!("[Benchmark] No frames were recorded");
+        return;
+    };
+    summary.print();
+
+    if let Some(output_path) = state.config.output_path.as_ref() {
+        let result = serde_json::to_string_pretty(&summary)
+            .map_err(|error| error.to_string())
+            .and_then(|json| std::fs::write(output_path, json).map_err(|error| error.to_string()));
+        match result {
+            Ok(()) => log::info!("[Benchmark] Wrote summary to {}", output_path),
+            Err(error) => log::error!("[Benchmark] Failed to write summary to {}: {}", output_path, error),
+        }
+    }
+}
+
+pub fn benchmark_system(
+    mut commands: Commands,
+    mut state: ResMut<BenchmarkState>,
+    mut zone_events: MessageReader<ZoneEvent>,
+    mut query_camera: Query<(Entity, &mut Transform, Has<FreeCamera>), With<Camera3d>>,
+    diagnostics: Res<DiagnosticsStore>,
+    time: Res<Time>,
+    mut app_exit_events: MessageWriter<AppExit>,
+) {
+    match state.phase {
+        BenchmarkPhase::WaitingForZone => {
+            if zone_events.read().count() == 0 {
+                return;
+            }
+            if state.path.sample(0.0).is_none() {
+                log::error!("[Benchmark] The benchmark path needs at least two waypoints");
+                state.phase = BenchmarkPhase::Finished;
+                app_exit_events.write(AppExit::error());
+                return;
+            }
+            log::info!(
+                "[Benchmark] Zone loaded, flying {:.0}m after {:.1}s warmup",
+                state.path.length(),
+                state.config.warmup_secs
+            );
+            state.phase = BenchmarkPhase::Warmup;
+            state.elapsed = Duration::ZERO;
+        }
+        BenchmarkPhase::Warmup => {
+            state.elapsed += time.delta();
+            if state.elapsed.as_secs_f32() >= state.config.warmup_secs {
+                state.phase = BenchmarkPhase::Running;
+                state.elapsed = Duration::ZERO;
+            }
+        }
+        BenchmarkPhase::Running => {
+            state.elapsed += time.delta();
+            if let Some(frame_time) = diagnostics
+                .get(&FrameTimeDiagnosticsPlugin::FRAME_TIME)
+                .and_then(|diagnostic| diagnostic.measurement())
+            {
+                let distance = state.elapsed.as_secs_f32() * state.config.speed;
+                let segment = state.path.sample(distance).map_or(0, |(segment, _, _)| segment);
+                state.frames.push((frame_time.value, segment));
+            }
+        }
+        BenchmarkPhase::Finished => return,
+    }
+
+    let distance = state.elapsed.as_secs_f32() * state.config.speed;
+    if state.phase == BenchmarkPhase::Running && distance >= state.path.length() {
+        log::info!("[Benchmark] Finished after {} frames", state.frames.len());
+        finish_benchmark(&state);
+        state.phase = BenchmarkPhase::Finished;
+        app_exit_events.write(AppExit::Success);
+        return;
+    }
+
+    // The camera stays at the start of the path during warmup
+    let distance = if state.phase == BenchmarkPhase::Running { distance } else { 0.0 };
+    let Some((_, position, look_at)) = state.path.sample(distance) else {
+        return;
+    };
+    for (entity, mut transform, has_free_camera) in query_camera.iter_mut() {
+        if has_free_camera {
+            commands.entity(entity).remove::<FreeCamera>();
+        }
+        *transform = Transform::from_translation(position).looking_at(look_at, Vec3::Y);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_sample() {
+        let path = BenchmarkPath::new(vec![
+            Vec3::ZERO,
+            Vec3::new(10.0, 0.0, 0.0),
+            Vec3::new(10.0, 0.0, 20.0),
+        ]);
+        assert_eq!(path.length(), 30.0);
+
+        let (segment, position, look_at) = path.sample(5.0).unwrap();
+        assert_eq!(segment, 0);
+        assert_eq!(position, Vec3::new(5.0, 0.0, 0.0));
+        assert_eq!(look_at, Vec3::new(10.0, 0.0, 0.0));
+
+        let (segment, position, _) = path.sample(20.0).unwrap();
+        assert_eq!(segment, 1);
+        assert_eq!(position, Vec3::new(10.0, 0.0, 10.0));
+
+        let (segment, position, _) = path.sample(100.0).unwrap();
+        assert_eq!(segment, 1);
+        assert_eq!(position, Vec3::new(10.0, 0.0, 20.0));
+
+        assert!(BenchmarkPath::new(vec![Vec3::ZERO]).sample(0.0).is_none());
+    }
+
+    #[test]
+    fn test_summary() {
+        let mut frames = vec![(10.0, 0); 99];
+        frames.push((40.0, 1));
+        let summary = BenchmarkSummary::new(&frames, 2).unwrap();
+        assert_eq!(summary.frames, 100);
+        assert_eq!(summary.min_frame_time_ms, 10.0);
+        assert_eq!(summary.max_frame_time_ms, 40.0);
+        assert!((summary.avg_frame_time_ms - 10.3).abs() < 1e-9);
+        assert!((summary.one_percent_low_fps - 25.0).abs() < 1e-9);
+        assert_eq!(summary.segments.len(), 2);
+        assert_eq!(summary.segments[1].frames, 1);
+
+        assert!(BenchmarkSummary::new(&[], 1).is_none());
+    }
+}
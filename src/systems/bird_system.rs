@@ -11,10 +11,11 @@ use bevy::{asset::RenderAssetUsages, prelude::*};
 use bevy_mesh::{Mesh, Indices, PrimitiveTopology};
 use bevy::render::render_resource::Face;
 use bevy::pbr::{MeshMaterial3d, StandardMaterial};
-use rand::Rng;
+use rand::{rngs::StdRng, Rng};
 
 use crate::components::{Bird, BirdSettings, BirdMesh, BirdWingLeft, BirdWingRight, Zone};
 use crate::events::ZoneEvent;
+use crate::resources::RngSeed;
 
 /// Plugin for bird systems
 pub struct BirdPlugin;
@@ -44,10 +45,13 @@ pub fn spawn_birds_on_zone_system(
     settings: Res<BirdSettings>,
     mut zone_events: MessageReader<ZoneEvent>,
     zone_query: Query<(Entity, &Transform), With<Zone>>,
+    rng_seed: Res<RngSeed>,
+    mut rng: Local<Option<StdRng>>,
 ) {
     if !settings.enabled {
         return;
     }
+    let rng = rng.get_or_insert_with(|| rng_seed.rng("birds"));
     
     let mut event_count = 0;
     for event in zone_events.read() {
@@ -86,6 +90,7 @@ pub fn spawn_birds_on_zone_system(
             zone_entity,
             zone_size,
             bird_count,
+            rng,
         );
     }
     
@@ -117,8 +122,8 @@ fn spawn_birds(
     zone_entity: Entity,
     zone_size: f32,
     bird_count: usize,
+    rng: &mut impl Rng,
 ) {
-    let mut rng = rand::thread_rng();
     
     // Calculate roam radius based on zone size
     let roam_radius = zone_size * settings.roam_radius_multiplier * 0.5;
@@ -181,7 +186,7 @@ fn spawn_birds(
         let material_idx = rng.gen_range(0..bird_materials.len());
         let material = bird_materials[material_idx].clone();
         
-        let target_position = get_new_target(zone_center, roam_radius, settings.min_altitude, settings.max_altitude);
+        let target_position = get_new_target(rng, zone_center, roam_radius, settings.min_altitude, settings.max_altitude);
         
         // Initial rotation facing the target
         let direction = target_position - Vec3::new(x, y, z);
@@ -593,8 +598,7 @@ fn create_bird_wing_right_mesh(meshes: &mut ResMut<Assets<Mesh>>) -> Handle<Mesh
 }
 
 /// Gets a new random target position within roam bounds
-fn get_new_target(center: Vec3, radius: f32, min_alt: f32, max_alt: f32) -> Vec3 {
-    let mut rng = rand::thread_rng();
+fn get_new_target(rng: &mut impl Rng, center: Vec3, radius: f32, min_alt: f32, max_alt: f32) -> Vec3 {
     let angle = rng.gen::<f32>() * std::f32::consts::TAU;
     let distance = rng.gen::<f32>() * radius;
     // Clamp min/max to prevent crash
@@ -615,10 +619,13 @@ pub fn update_bird_movement_system(
     mut left_wing_query: Query<&mut Transform, (With<BirdWingLeft>, Without<Bird>, Without<BirdWingRight>)>,
     mut right_wing_query: Query<&mut Transform, (With<BirdWingRight>, Without<Bird>, Without<BirdWingLeft>)>,
     children_query: Query<&Children, With<Bird>>,
+    rng_seed: Res<RngSeed>,
+    mut rng: Local<Option<StdRng>>,
 ) {
     if !settings.enabled {
         return;
     }
+    let rng = rng.get_or_insert_with(|| rng_seed.rng("bird_targets"));
     
     let dt = time.delta_secs();
     
@@ -631,6 +638,7 @@ pub fn update_bird_movement_system(
         if distance < 2.0 {
             // Reached target, get new one
             bird.target_position = get_new_target(
+                rng,
                 bird.roam_center,
                 bird.roam_radius,
                 settings.min_altitude,
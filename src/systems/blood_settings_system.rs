@@ -0,0 +1,42 @@
+use bevy::prelude::{Commands, DetectChanges, Entity, Local, Query, Res, ResMut, With};
+
+use crate::{
+    components::{BloodSpatter, GashWounds, WoundVisual},
+    resources::{BloodEffectConfig, BloodSettings},
+};
+
+/// Applies the blood settings preset, and removes the spatters and wounds
+/// already in the world when they are turned off.
+pub fn blood_settings_system(
+    mut commands: Commands,
+    blood_settings: Res<BloodSettings>,
+    mut blood_effect_config: ResMut<BloodEffectConfig>,
+    query_spatters: Query<Entity, With<BloodSpatter>>,
+    query_wound_visuals: Query<Entity, With<WoundVisual>>,
+    query_gash_wounds: Query<Entity, With<GashWounds>>,
+    mut applied_settings: Local<Option<BloodSettings>>,
+) {
+    // Settings widgets mark the resource changed every frame they are shown, only
+    // apply the preset when a value actually changed so the advanced options stay
+    if !blood_settings.is_changed() || applied_settings.as_ref() == Some(&*blood_settings) {
+        return;
+    }
+    *applied_settings = Some(blood_settings.clone());
+
+    *blood_effect_config = blood_settings.effect_config();
+
+    if !blood_effect_config.enable_blood {
+        for entity in query_spatters.iter() {
+            commands.entity(entity).despawn();
+        }
+    }
+
+    if !blood_effect_config.enable_blood || !blood_effect_config.show_wounds {
+        for entity in query_wound_visuals.iter() {
+            commands.entity(entity).despawn();
+        }
+        for entity in query_gash_wounds.iter() {
+            commands.entity(entity).remove::<GashWounds>();
+        }
+    }
+}
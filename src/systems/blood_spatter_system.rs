@@ -11,7 +11,7 @@ use bevy::{
 };
 
 use crate::{
-    components::{BloodSpatter, Dead, DeathBloodHandled, ModelHeight},
+    components::{BloodSpatter, Dead, DeathBloodHandled, ModelHeight, TransientDecal},
     events::{BloodEffectEvent, BloodImpactProfile},
     resources::{
         BloodDecalAtlas, BloodEffectConfig, BloodEffectDiagnostics, BloodEffectRuntime,
@@ -156,6 +156,7 @@ pub fn blood_spatter_spawn_system(
     mut decal_materials: ResMut<Assets<ForwardDecalMaterial<StandardMaterial>>>,
     mut runtime: ResMut<BloodEffectRuntime>,
     mut diagnostics: ResMut<BloodEffectDiagnostics>,
+    time: Res<Time>,
 ) {
     if !config.enable_blood {
         blood_events.clear();
@@ -290,6 +291,8 @@ pub fn blood_spatter_spawn_system(
                     },
                 };
 
+                let spatter_decal =
+                    TransientDecal::new(time.elapsed_secs(), Some(config.spatter_lifetime));
                 if let Some(reuse_entity) = runtime.spatter_pool.pop() {
                     if let Ok((_, _, material_handle)) = query_spatters.get(reuse_entity) {
                         if let Some(existing_material) = decal_materials.get_mut(&material_handle.0) {
@@ -310,6 +313,7 @@ pub fn blood_spatter_spawn_system(
                                 active: true,
                             },
                             build_spatter_transform(spatter_pos, base_normal, size, rotation),
+                            spatter_decal,
                         ));
 
                         diagnostics.pooled_spatters_reused =
@@ -330,6 +334,7 @@ pub fn blood_spatter_spawn_system(
                                 active: true,
                             },
                             build_spatter_transform(spatter_pos, base_normal, size, rotation),
+                            spatter_decal,
                         ));
                     }
                 } else {
@@ -348,6 +353,7 @@ pub fn blood_spatter_spawn_system(
                             active: true,
                         },
                         build_spatter_transform(spatter_pos, base_normal, size, rotation),
+                        spatter_decal,
                     ));
                 }
                 active_spatter_count = active_spatter_count.saturating_add(1);
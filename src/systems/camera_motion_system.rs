@@ -0,0 +1,105 @@
+use std::f32::consts::{PI, TAU};
+
+use bevy::{
+    math::{Quat, Vec3},
+    prelude::{GlobalTransform, Local, Query, Res, Time, Transform},
+};
+
+use crate::{
+    components::{FacingDirection, FlightState},
+    resources::{AccessibilitySettings, CameraMotionSettings},
+    systems::OrbitCamera,
+};
+
+/// Steps per metre travelled, sets how quickly the bob cycles when running
+const CAMERA_BOB_STEPS_PER_METRE: f32 = 0.7;
+
+/// Ground speeds above this are teleports or zone changes, not running
+const CAMERA_BOB_MAX_SPEED: f32 = 40.0;
+
+/// How quickly the bob and bank ease in and out
+const CAMERA_MOTION_SMOOTHING: f32 = 6.0;
+
+#[derive(Default)]
+pub struct CameraMotionState {
+    last_position: Option<Vec3>,
+    last_facing: Option<f32>,
+    bob_phase: f32,
+    bob_height: f32,
+    bank_angle: f32,
+}
+
+/// Adds a gentle bob while running and a roll into turns while flying on top
+/// of the orbit camera, after the camera rig has set its transform for this frame.
+pub fn camera_motion_system(
+    mut state: Local<CameraMotionState>,
+    camera_motion_settings: Res<CameraMotionSettings>,
+    accessibility_settings: Res<AccessibilitySettings>,
+    mut query_camera: Query<(&OrbitCamera, &mut Transform)>,
+    query_follow: Query<(&GlobalTransform, Option<&FlightState>, Option<&FacingDirection>)>,
+    time: Res<Time>,
+) {
+    let Ok((orbit_camera, mut camera_transform)) = query_camera.single_mut() else {
+        *state = CameraMotionState::default();
+        return;
+    };
+    let Ok((follow_transform, flight_state, facing)) = query_follow.get(orbit_camera.follow_entity)
+    else {
+        *state = CameraMotionState::default();
+        return;
+    };
+
+    let delta = time.delta_secs();
+    if delta <= 0.0 {
+        return;
+    }
+
+    let position = follow_transform.translation();
+    let mut speed = state
+        .last_position
+        .map_or(0.0, |last| (position - last).with_y(0.0).length() / delta);
+    if speed > CAMERA_BOB_MAX_SPEED {
+        speed = 0.0;
+    }
+    state.last_position = Some(position);
+
+    let mut turn_rate = 0.0;
+    if let Some(facing) = facing {
+        if let Some(last_facing) = state.last_facing {
+            // Wrap to the shortest turn so crossing zero is not a full spin
+            let turn = (facing.actual - last_facing + PI).rem_euclid(TAU) - PI;
+            turn_rate = turn / delta;
+        }
+        state.last_facing = Some(facing.actual);
+    }
+
+    let enabled = camera_motion_settings.enabled && !accessibility_settings.disable_camera_motion;
+    let is_flying = flight_state.is_some_and(|flight_state| flight_state.is_flying);
+
+    let (target_bob_height, target_bank_angle) = if !enabled {
+        (0.0, 0.0)
+    } else if is_flying {
+        (0.0, camera_motion_settings.bank_angle(turn_rate))
+    } else {
+        (camera_motion_settings.bob_height(speed), 0.0)
+    };
+
+    let blend = 1.0 - (-CAMERA_MOTION_SMOOTHING * delta).exp();
+    state.bob_height += (target_bob_height - state.bob_height) * blend;
+    state.bank_angle += (target_bank_angle - state.bank_angle) * blend;
+    state.bob_phase = (state.bob_phase + speed * delta * CAMERA_BOB_STEPS_PER_METRE * TAU) % TAU;
+
+    if state.bob_height > 0.0001 {
+        // One dip per step, with a slight sway every other step
+        let bob = Vec3::new(
+            (state.bob_phase * 0.5).sin() * 0.5,
+            -(state.bob_phase.sin().abs()),
+            0.0,
+        ) * state.bob_height;
+        camera_transform.translation += camera_transform.rotation * bob;
+    }
+
+    if state.bank_angle.abs() > 0.0001 {
+        camera_transform.rotation *= Quat::from_rotation_z(state.bank_angle);
+    }
+}
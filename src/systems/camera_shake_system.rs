@@ -0,0 +1,48 @@
+use bevy::{
+    math::Vec3,
+    prelude::{Query, Res, ResMut, Time, Transform, With},
+};
+
+use crate::{
+    resources::{AccessibilitySettings, CameraShake},
+    systems::OrbitCamera,
+};
+
+/// Largest camera offset in metres at full shake strength
+const CAMERA_SHAKE_MAX_OFFSET: f32 = 0.25;
+
+/// How quickly the shake moves the camera
+const CAMERA_SHAKE_FREQUENCY: f32 = 28.0;
+
+/// Offsets the orbit camera by the current shake, after the camera rig has
+/// set its transform for this frame.
+pub fn camera_shake_system(
+    mut camera_shake: ResMut<CameraShake>,
+    accessibility_settings: Res<AccessibilitySettings>,
+    mut query_camera: Query<&mut Transform, With<OrbitCamera>>,
+    time: Res<Time>,
+) {
+    if accessibility_settings.disable_camera_shake {
+        camera_shake.trauma = 0.0;
+        return;
+    }
+
+    let strength = camera_shake.strength();
+    camera_shake.decay(time.delta_secs());
+    if strength <= 0.0 {
+        return;
+    }
+
+    // Out of phase sine waves give a smooth shake without needing a noise source
+    let t = time.elapsed_secs() * CAMERA_SHAKE_FREQUENCY;
+    let offset = Vec3::new(
+        t.sin(),
+        (t * 1.31 + 1.7).sin(),
+        (t * 0.87 + 3.1).sin(),
+    ) * CAMERA_SHAKE_MAX_OFFSET
+        * strength;
+
+    for mut transform in query_camera.iter_mut() {
+        transform.translation += offset;
+    }
+}
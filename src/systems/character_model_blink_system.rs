@@ -1,46 +1,95 @@
-use bevy::prelude::{Commands, Query, Res, Time};
-use rand::Rng;
+use std::f32::consts::TAU;
+
+use bevy::prelude::{
+    Camera3d, Commands, GlobalTransform, Local, Query, Res, Time, Transform, ViewVisibility, With,
+};
+use bevy_mesh::skinning::SkinnedMesh;
+use rand::rngs::StdRng;
 
 use crate::{
-    components::{BlinkClip, CharacterBlinkTimer, CharacterModel, CharacterModelPart, Dead},
+    components::{BlinkClip, CharacterBlinkTimer, CharacterModel, CharacterModelPart, Command, Dead},
+    resources::{CharacterIdleSettings, RngSeed},
 };
 
+/// Blinks characters and adds a gentle breathing motion while they stand idle.
+///
+/// Each character has its own random blink timing and breathing phase so a
+/// crowd never moves in unison. Characters whose face is culled or which are
+/// beyond `CharacterIdleSettings::max_distance` are skipped. The motions have
+/// no facial channels to fight with, breathing only plays while standing so it
+/// never shifts emotes, attacks or movement.
+///
+/// Runs after the skeletal animation so breathing is added on top of the pose.
 pub fn character_model_blink_system(
     mut commands: Commands,
-    mut query_characters: Query<(&CharacterModel, &mut CharacterBlinkTimer, Option<&Dead>)>,
+    mut query_characters: Query<(
+        &CharacterModel,
+        &mut CharacterBlinkTimer,
+        &GlobalTransform,
+        Option<&Command>,
+        Option<&SkinnedMesh>,
+        Option<&Dead>,
+    )>,
+    query_view_visibility: Query<&ViewVisibility>,
+    mut query_bone_transform: Query<&mut Transform>,
+    query_camera: Query<&GlobalTransform, With<Camera3d>>,
+    idle_settings: Res<CharacterIdleSettings>,
     time: Res<Time>,
+    rng_seed: Res<RngSeed>,
+    mut rng: Local<Option<StdRng>>,
 ) {
-    for (character_model, mut blink_timer, dead) in query_characters.iter_mut() {
+    let rng = rng.get_or_insert_with(|| rng_seed.rng("character_blink"));
+    let camera_position = query_camera
+        .single()
+        .ok()
+        .map(|camera_transform| camera_transform.translation());
+
+    for (character_model, mut blink_timer, transform, command, skinned_mesh, dead) in
+        query_characters.iter_mut()
+    {
+        let face_entities = &character_model.model_parts[CharacterModelPart::CharacterFace].1;
+        let culled = face_entities.first().map_or(false, |face_entity| {
+            query_view_visibility
+                .get(*face_entity)
+                .map_or(false, |view_visibility| !view_visibility.get())
+        }) || camera_position.map_or(false, |camera_position| {
+            camera_position.distance(transform.translation()) > idle_settings.max_distance
+        });
+
         let mut changed = false;
 
-        if dead.is_none() {
+        if dead.is_some() {
+            if blink_timer.is_open {
+                blink_timer.is_open = false;
+
+                // Set timer so the eyes open as soon as resurrected
+                blink_timer.closed_duration = 0.0;
+                blink_timer.timer = 0.0;
+            }
+
+            changed = true;
+        } else if !idle_settings.blink_enabled {
+            if !blink_timer.is_open {
+                blink_timer.is_open = true;
+                blink_timer.timer = 0.0;
+                changed = true;
+            }
+        } else if !culled {
             blink_timer.timer += time.delta().as_secs_f32();
 
             if blink_timer.is_open {
                 if blink_timer.timer >= blink_timer.open_duration {
                     blink_timer.is_open = false;
                     blink_timer.timer -= blink_timer.open_duration;
-                    blink_timer.closed_duration =
-                        rand::thread_rng().gen_range(CharacterBlinkTimer::BLINK_CLOSED_DURATION);
+                    blink_timer.closed_duration = idle_settings.random_blink_duration(rng);
                     changed = true;
                 }
             } else if blink_timer.timer >= blink_timer.closed_duration {
                 blink_timer.is_open = true;
                 blink_timer.timer -= blink_timer.closed_duration;
-                blink_timer.open_duration =
-                    rand::thread_rng().gen_range(CharacterBlinkTimer::BLINK_OPEN_DURATION);
+                blink_timer.open_duration = idle_settings.random_blink_interval(rng);
                 changed = true;
             }
-        } else {
-            if blink_timer.is_open {
-                blink_timer.is_open = false;
-
-                // Set timer so the eyes open as soon as resurrected
-                blink_timer.closed_duration = 0.0;
-                blink_timer.timer = 0.0;
-            }
-
-            changed = true;
         }
 
         if changed {
@@ -53,9 +102,44 @@ pub fn character_model_blink_system(
             };
 
             // Insert the BlinkClip component on all face model part entities
-            for &face_entity in character_model.model_parts[CharacterModelPart::CharacterFace].1.iter() {
+            for &face_entity in face_entities.iter() {
                 commands.entity(face_entity).insert(blink_clip);
             }
         }
+
+        let breathing = idle_settings.breathing_enabled
+            && !culled
+            && dead.is_none()
+            && command.map_or(false, |command| command.is_stop());
+        let breathing_offset = if breathing {
+            if blink_timer.breathing_period <= 0.0 {
+                blink_timer.breathing_period = idle_settings.random_breathing_period(rng);
+            }
+            blink_timer.breathing_phase = (blink_timer.breathing_phase
+                + TAU * time.delta_secs() / blink_timer.breathing_period)
+                % TAU;
+            blink_timer.breathing_phase.sin() * idle_settings.breathing_amplitude
+        } else {
+            0.0
+        };
+
+        if breathing_offset == 0.0 && blink_timer.breathing_offset == 0.0 {
+            continue;
+        }
+
+        let Some(mut root_bone_transform) = skinned_mesh
+            .and_then(|skinned_mesh| skinned_mesh.joints.first())
+            .and_then(|root_bone| query_bone_transform.get_mut(*root_bone).ok())
+        else {
+            continue;
+        };
+
+        // The animation rewrites the bone when it plays a frame, otherwise the
+        // last breathing offset is still applied and must be taken back off
+        if !root_bone_transform.is_changed() {
+            root_bone_transform.translation.y -= blink_timer.breathing_offset;
+        }
+        root_bone_transform.translation.y += breathing_offset;
+        blink_timer.breathing_offset = breathing_offset;
     }
 }
@@ -1,8 +1,9 @@
 use bevy::{
     mesh::skinning::{SkinnedMesh, SkinnedMeshInverseBindposes},
     pbr::{ExtendedMaterial, StandardMaterial},
-    prelude::{AssetServer, Assets, Changed, Commands, Entity, Or, Query, Res, ResMut},
+    prelude::{AssetServer, Assets, Changed, Commands, Entity, Local, Or, Query, Res, ResMut},
 };
+use rand::rngs::StdRng;
 
 use crate::render::object_material_extension::RoseObjectExtension;
 
@@ -14,6 +15,7 @@ use crate::{
         RemoveColliderCommand,
     },
     model_loader::ModelLoader,
+    resources::RngSeed,
 };
 
 pub fn character_model_update_system(
@@ -39,7 +41,10 @@ pub fn character_model_update_system(
     mut standard_materials: ResMut<Assets<StandardMaterial>>,
     mut object_materials: ResMut<Assets<ExtendedMaterial<StandardMaterial, RoseObjectExtension>>>,
     mut skinned_mesh_inverse_bindposes_assets: ResMut<Assets<SkinnedMeshInverseBindposes>>,
+    rng_seed: Res<RngSeed>,
+    mut rng: Local<Option<StdRng>>,
 ) {
+    let rng = rng.get_or_insert_with(|| rng_seed.rng("character_blink_timers"));
     for (
         entity,
         character_info,
@@ -117,7 +122,7 @@ pub fn character_model_update_system(
 
         let mut entity_commands = commands.entity(entity);
         entity_commands
-            .insert(CharacterBlinkTimer::new())
+            .insert(CharacterBlinkTimer::new(rng))
             .remove_and_despawn_collider();
 
         if let Some(mut current_character_model) = current_character_model {
@@ -1,13 +1,20 @@
-use std::time::{Duration, Instant};
+use std::{
+    ops::Range,
+    time::{Duration, Instant},
+};
 
 use log::info;
 
 use bevy::{
-    input::ButtonInput,
+    input::{
+        mouse::{MouseScrollUnit, MouseWheel},
+        ButtonInput,
+    },
     prelude::{
         AssetServer, Camera, Camera3d, Commands, Component, ViewVisibility, InheritedVisibility,
         Entity, MessageReader, MessageWriter, GlobalTransform, Handle, Local,
-        MouseButton, NextState, Query, Res, ResMut, Resource, Vec2, Visibility, With, World,
+        MouseButton, NextState, Quat, Query, Res, ResMut, Resource, Transform, Vec2, Vec3,
+        Time, Visibility, With, Without, World,
     },
     window::{CursorGrabMode, CursorOptions, PrimaryWindow, Window},
 };
@@ -18,8 +25,15 @@ use bevy_rapier3d::{
     prelude::{CollisionGroups, QueryFilter},
 };
 
-use rose_data::{CharacterMotionAction, ZoneId};
-use rose_game_common::messages::{client::ClientMessage, server::CreateCharacterError};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+use rose_data::{CharacterMotionAction, EquipmentIndex, ZoneId};
+use rose_game_common::{
+    components::CharacterGender,
+    messages::{
+        client::ClientMessage,
+        server::{CharacterListItem, CreateCharacterError},
+    },
+};
 
 use crate::{
     animation::{CameraAnimation, SkeletalAnimation, ZmoAsset},
@@ -29,8 +43,8 @@ use crate::{
     },
     events::{CharacterSelectEvent, GameConnectionEvent, LoadZoneEvent, MessageBoxEvent, WorldConnectionEvent},
     resources::{
-        AppState, CharacterList, CharacterSelectState, GameData, ServerConfiguration,
-        WorldConnection,
+        AppState, CharacterList, CharacterSelectScene, CharacterSelectState, CurrentZone,
+        EmoteList, GameData, ReconnectSession, RngSeed, ServerConfiguration, WorldConnection,
     },
     systems::{FreeCamera, OrbitCamera},
 };
@@ -40,18 +54,81 @@ pub struct CharacterSelectCharacter {
     pub index: usize,
 }
 
+/// Seconds between idle variations of a character which is not highlighted
+const IDLE_VARIATION_INTERVAL: Range<f32> = 8.0..16.0;
+
+/// Idle variation state of a character slot
+#[derive(Default)]
+struct CharacterSelectIdle {
+    /// Seconds until the next idle variation
+    next_variation: f32,
+    /// Variation being played, the character returns to its idle motion once it completes
+    variation: Option<Handle<ZmoAsset>>,
+}
+
 #[derive(Resource)]
 pub struct CharacterSelectModelList {
     models: Vec<(Option<String>, Entity)>,
+    idle: Vec<CharacterSelectIdle>,
     select_motion: Handle<ZmoAsset>,
 }
 
+fn spawn_character_select_slot(commands: &mut Commands, index: usize, transform: Transform) -> Entity {
+    commands
+        .spawn((
+            CharacterSelectCharacter { index },
+            transform,
+            GlobalTransform::default(),
+            Visibility::default(),
+            InheritedVisibility::default(),
+            ViewVisibility::default(),
+        ))
+        .id()
+}
+
+/// Motions a waiting character plays now and then, the spare idle motions
+/// and the emotes from the character motion database
+fn idle_variation_motions(
+    character: &CharacterListItem,
+    character_model: &CharacterModel,
+    game_data: &GameData,
+    emote_list: &EmoteList,
+    asset_server: &AssetServer,
+) -> Vec<Handle<ZmoAsset>> {
+    let weapon_motion_type = game_data
+        .items
+        .get_equipped_weapon_item_data(&character.equipment, EquipmentIndex::Weapon)
+        .map(|weapon_item_data| weapon_item_data.motion_type as usize)
+        .unwrap_or(0);
+    let gender = match character_model.gender {
+        CharacterGender::Male => 0,
+        CharacterGender::Female => 1,
+    };
+
+    let mut motions: Vec<Handle<ZmoAsset>> = [CharacterMotionAction::Stop2, CharacterMotionAction::Stop3]
+        .into_iter()
+        .map(|action| character_model.action_motions[action].clone())
+        .filter(|motion| {
+            *motion != Handle::default() && *motion != character_model.action_motions[CharacterMotionAction::Stop1]
+        })
+        .collect();
+    motions.extend(emote_list.emotes.iter().filter_map(|emote| {
+        game_data
+            .character_motion_database
+            .find_first_character_motion(emote.motion_id, weapon_motion_type, gender)
+            .map(|motion_data| asset_server.load(motion_data.path.path().to_string_lossy().into_owned()))
+    }));
+    motions
+}
+
 pub fn character_select_enter_system(
     mut commands: Commands,
     mut query_cursor_options: Query<&mut CursorOptions, With<PrimaryWindow>>,
     query_cameras: Query<Entity, With<Camera3d>>,
+    mut load_zone_events: MessageWriter<LoadZoneEvent>,
     asset_server: Res<AssetServer>,
-    game_data: Res<GameData>,
+    character_select_scene: Res<CharacterSelectScene>,
+    current_zone: Option<Res<CurrentZone>>,
 ) {
     log::info!("[CHAR_SELECT] Enter system called - setting up character select screen");
     if let Ok(mut cursor_options) = query_cursor_options.single_mut() {
@@ -59,36 +136,38 @@ pub fn character_select_enter_system(
         cursor_options.visible = true;
     }
 
+    // The backdrop zone is normally already loaded by the login screen
+    let zone_id = character_select_scene.zone_id();
+    if current_zone.map_or(true, |current_zone| current_zone.id != zone_id) {
+        load_zone_events.write(LoadZoneEvent::new(zone_id));
+    }
+
     // Reset camera
     for entity in query_cameras.iter() {
-        commands
-            .entity(entity)
-            .remove::<FreeCamera>()
-            .remove::<OrbitCamera>()
-            .insert(CameraAnimation::once(
-                asset_server.load("3DDATA/TITLE/CAMERA01_INSELECT01.ZMO"),
+        let mut camera = commands.entity(entity);
+        camera.remove::<FreeCamera>().remove::<OrbitCamera>();
+        if let Some(fixed_camera) = character_select_scene.camera.as_ref() {
+            camera
+                .remove::<CameraAnimation>()
+                .insert(fixed_camera.transform());
+        } else {
+            camera.insert(CameraAnimation::once(
+                asset_server.load(&character_select_scene.camera_motion),
             ));
+        }
     }
 
     // Reset state
     commands.insert_resource(CharacterSelectState::Entering);
+    commands.insert_resource(CharacterSelectInputState::default());
 
     // Spawn entities to use for character list models
-    let mut models = Vec::with_capacity(game_data.character_select_positions.len());
-    for (index, transform) in game_data.character_select_positions.iter().enumerate() {
-        let entity = commands
-            .spawn((
-                CharacterSelectCharacter { index },
-                *transform,
-                GlobalTransform::default(),
-                Visibility::default(),
-                InheritedVisibility::default(),
-                ViewVisibility::default(),
-            ))
-            .id();
-        models.push((None, entity));
+    let mut models = Vec::with_capacity(character_select_scene.positions.len());
+    for (index, position) in character_select_scene.positions.iter().enumerate() {
+        models.push((None, spawn_character_select_slot(&mut commands, index, position.transform())));
     }
     commands.insert_resource(CharacterSelectModelList {
+        idle: models.iter().map(|_| CharacterSelectIdle::default()).collect(),
         models,
         select_motion: asset_server.load("3DDATA/MOTION/AVATAR/EVENT_SELECT_M1.ZMO"),
     });
@@ -108,16 +187,45 @@ pub fn character_select_exit_system(
     commands.remove_resource::<CharacterSelectModelList>();
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn character_select_models_system(
     mut commands: Commands,
     mut model_list: ResMut<CharacterSelectModelList>,
     character_list: Option<Res<CharacterList>>,
     character_select_state: Res<CharacterSelectState>,
+    character_select_scene: Res<CharacterSelectScene>,
     query_characters: Query<(Option<&SkeletalAnimation>, &CharacterModel), With<SkinnedMesh>>,
+    game_data: Res<GameData>,
+    emote_list: Res<EmoteList>,
+    asset_server: Res<AssetServer>,
+    time: Res<Time>,
+    rng_seed: Res<RngSeed>,
+    mut rng: Local<Option<StdRng>>,
 ) {
+    let rng = rng.get_or_insert_with(|| rng_seed.rng("character_select_idle"));
+    let model_list = &mut *model_list;
+    let character_count = character_list
+        .as_ref()
+        .map_or(0, |character_list| character_list.characters.len());
+
+    // Clear the slots of characters which were removed from the list
+    for index in character_count..model_list.models.len() {
+        if model_list.models[index].0.is_none() {
+            continue;
+        }
+        commands.entity(model_list.models[index].1).despawn();
+        let transform = character_select_scene
+            .position_transform(index)
+            .unwrap_or_default();
+        model_list.models[index] = (None, spawn_character_select_slot(&mut commands, index, transform));
+        model_list.idle[index] = CharacterSelectIdle::default();
+    }
+
     // Ensure all character list models are up to date
     if let Some(character_list) = character_list.as_ref() {
-        for (index, character) in character_list.characters.iter().enumerate() {
+        // Characters without a configured position are not shown
+        let model_count = model_list.models.len();
+        for (index, character) in character_list.characters.iter().enumerate().take(model_count) {
             let entity = model_list.models[index].1;
 
             // If the character list has changed, recreate model
@@ -126,6 +234,12 @@ pub fn character_select_models_system(
                     .entity(model_list.models[index].1)
                     .insert((character.info.clone(), character.equipment.clone()));
                 model_list.models[index].0 = Some(character.info.name.clone());
+
+                // Stagger the variations so the characters do not move in sync
+                model_list.idle[index] = CharacterSelectIdle {
+                    next_variation: rng.gen_range(IDLE_VARIATION_INTERVAL),
+                    variation: None,
+                };
             }
 
             if let Ok((skeletal_animation, character_model)) = query_characters.get(entity) {
@@ -138,18 +252,48 @@ pub fn character_select_models_system(
                     false
                 };
 
-                let desired_motion = if deleting {
-                    &character_model.action_motions[CharacterMotionAction::Sit]
+                let idle = &mut model_list.idle[index];
+                let (desired_motion, play_once) = if deleting {
+                    idle.variation = None;
+                    (&character_model.action_motions[CharacterMotionAction::Sit], false)
                 } else if selected {
-                    &model_list.select_motion
+                    idle.variation = None;
+                    (&model_list.select_motion, false)
                 } else {
-                    &character_model.action_motions[CharacterMotionAction::Stop1]
+                    idle.next_variation -= time.delta_secs();
+                    if idle.variation.as_ref().map_or(false, |variation| {
+                        skeletal_animation.map_or(true, |animation| {
+                            animation.motion().id() == variation.id() && animation.completed()
+                        })
+                    }) {
+                        idle.variation = None;
+                    }
+
+                    if idle.variation.is_none() && idle.next_variation <= 0.0 {
+                        idle.next_variation = rng.gen_range(IDLE_VARIATION_INTERVAL);
+                        idle.variation = idle_variation_motions(
+                            character,
+                            character_model,
+                            &game_data,
+                            &emote_list,
+                            &asset_server,
+                        )
+                        .choose(rng)
+                        .cloned();
+                    }
+
+                    match idle.variation.as_ref() {
+                        Some(variation) => (variation, true),
+                        None => (&character_model.action_motions[CharacterMotionAction::Stop1], false),
+                    }
                 };
 
                 if skeletal_animation.map_or(true, |x| x.motion().id() != desired_motion.id()) {
-                    commands
-                        .entity(entity)
-                        .insert(SkeletalAnimation::repeat(desired_motion.clone(), None));
+                    commands.entity(entity).insert(if play_once {
+                        SkeletalAnimation::once(desired_motion.clone())
+                    } else {
+                        SkeletalAnimation::repeat(desired_motion.clone(), None)
+                    });
                 }
             }
         }
@@ -174,6 +318,7 @@ pub fn character_select_system(
     world_connection: Option<Res<WorldConnection>>,
     mut character_list: Option<ResMut<CharacterList>>,
     server_configuration: Res<ServerConfiguration>,
+    character_select_scene: Res<CharacterSelectScene>,
     asset_server: Res<AssetServer>,
 ) {
     let character_select_state = &mut *character_select_state;
@@ -210,13 +355,8 @@ pub fn character_select_system(
                     });
                     *character_select_state = CharacterSelectState::CharacterCreate;
                 }
-                CreateCharacterError::AlreadyExists => {
-                    message_box_events.write(MessageBoxEvent::Show {
-                        message: "Character name already exists".to_string(),
-                        modal: true,
-                        ok: None,
-                        cancel: None,
-                    });
+                CreateCharacterError::AlreadyExists | CreateCharacterError::InvalidValue => {
+                    // Shown next to the name by ui_character_create_system
                     *character_select_state = CharacterSelectState::CharacterCreate;
                 }
                 CreateCharacterError::NoMoreSlots => {
@@ -228,15 +368,6 @@ pub fn character_select_system(
                     });
                     *character_select_state = CharacterSelectState::CharacterCreate;
                 }
-                CreateCharacterError::InvalidValue => {
-                    message_box_events.write(MessageBoxEvent::Show {
-                        message: "Invalid value".to_string(),
-                        modal: true,
-                        ok: None,
-                        cancel: None,
-                    });
-                    *character_select_state = CharacterSelectState::CharacterCreate;
-                }
             },
             WorldConnectionEvent::DeleteCharacterStart { name, delete_time } => {
                 if let Some(character_list) = character_list.as_mut() {
@@ -276,6 +407,7 @@ pub fn character_select_system(
                     cancel: None,
                 });
             }
+            WorldConnectionEvent::Disconnected { .. } => {}
         }
     }
 
@@ -293,7 +425,19 @@ pub fn character_select_system(
                 *character_select_state = CharacterSelectState::CharacterSelect(None);
             }
         }
-        CharacterSelectState::CharacterSelect(_) => {}
+        CharacterSelectState::CharacterSelect(_) => {
+            // Return to the fixed camera once the camera motion back from character create is done
+            if let Some(fixed_camera) = character_select_scene.camera.as_ref() {
+                if let Ok((camera_entity, _, _, Some(camera_motion))) = query_camera.single() {
+                    if camera_motion.completed() {
+                        commands
+                            .entity(camera_entity)
+                            .remove::<CameraAnimation>()
+                            .insert(fixed_camera.transform());
+                    }
+                }
+            }
+        }
         CharacterSelectState::CharacterCreate => {}
         CharacterSelectState::CharacterCreating => {
             let ctx = egui_context.ctx_mut().unwrap();
@@ -314,7 +458,9 @@ pub fn character_select_system(
                 });
 
             for event in game_connection_events.read() {
-                let &GameConnectionEvent::Connected(zone_id) = event;
+                let &GameConnectionEvent::Connected(zone_id) = event else {
+                    continue;
+                };
 
                 // Start camera animation
                 if let Ok((camera_entity, _, _, _)) = query_camera.single() {
@@ -347,6 +493,7 @@ pub fn character_select_event_system(
     mut character_select_events: MessageReader<CharacterSelectEvent>,
     character_list: Option<Res<CharacterList>>,
     world_connection: Option<Res<WorldConnection>>,
+    mut reconnect_session: ResMut<ReconnectSession>,
 ) {
     for event in character_select_events.read() {
         match event {
@@ -377,6 +524,9 @@ pub fn character_select_event_system(
                                         .ok();
                                 }
 
+                                reconnect_session.character_name =
+                                    Some(selected_character.info.name.clone());
+
                                 *character_select_state =
                                     CharacterSelectState::ConnectingGameServer;
                             }
@@ -413,44 +563,148 @@ pub fn character_select_event_system(
     }
 }
 
+/// Radians the highlighted character turns per pixel dragged
+const DRAG_ROTATE_SPEED: f32 = 0.01;
+
+/// Fraction of the distance to the highlighted character the camera moves at full zoom
+const MAX_ZOOM_DISTANCE: f32 = 0.7;
+
+/// Height above the character position the camera zooms towards
+const ZOOM_TARGET_HEIGHT: f32 = 2.5;
+
 /// Resource to track character select input state (cursor position and last click time)
 #[derive(Resource, Default)]
 pub struct CharacterSelectInputState {
     pub cursor_position: Option<Vec2>,
     pub last_click_time: Option<Instant>,
     pub selected_character_index: Option<usize>,
+    /// Rotation added to each character slot by dragging, kept when switching characters
+    pub slot_yaw: Vec<f32>,
+    /// Camera zoom towards the highlighted character, 0 is the default framing
+    pub zoom: f32,
+    /// Character the zoom applies to, the zoom resets when another is highlighted
+    zoom_index: Option<usize>,
+    /// Camera transform before zooming, restored when the zoom returns to 0
+    zoom_base: Option<Transform>,
 }
 
 /// Combined system for character selection input handling
 #[allow(clippy::too_many_arguments)]
 pub fn character_select_input_system(
-    _character_select_state: Res<CharacterSelectState>,
+    character_select_state: Res<CharacterSelectState>,
+    character_select_scene: Res<CharacterSelectScene>,
     mouse_button_input: Res<ButtonInput<MouseButton>>,
+    mut mouse_wheel_events: MessageReader<MouseWheel>,
     rapier_context: ReadRapierContext,
     mut input_state: ResMut<CharacterSelectInputState>,
     mut egui_ctx: EguiContexts,
     query_window: Query<&Window, With<PrimaryWindow>>,
-    query_camera: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    mut query_camera: Query<
+        (&Camera, &GlobalTransform, &mut Transform, Option<&CameraAnimation>),
+        (With<Camera3d>, Without<CharacterSelectCharacter>),
+    >,
+    mut query_characters: Query<(&CharacterSelectCharacter, &mut Transform)>,
     query_entities: Query<(Option<&ColliderParent>, Option<&CharacterSelectCharacter>)>,
     mut character_select_events: MessageWriter<CharacterSelectEvent>,
 ) {
-    // Get the single rapier context
-    let Ok(rapier_context) = rapier_context.single() else {
-        return;
+    let input_state = &mut *input_state;
+    let highlighted_index = match *character_select_state {
+        CharacterSelectState::CharacterSelect(Some(index)) => Some(index),
+        _ => None,
     };
+    input_state
+        .slot_yaw
+        .resize(character_select_scene.positions.len(), 0.0);
 
     // Only process input when egui is not using the mouse
     let ctx = egui_ctx.ctx_mut().unwrap();
-    if ctx.is_pointer_over_area() || ctx.is_using_pointer() {
-        return;
+    let pointer_over_egui = ctx.is_pointer_over_area() || ctx.is_using_pointer();
+    let cursor_position = query_window
+        .single()
+        .ok()
+        .and_then(|window| window.cursor_position());
+    let camera_animating = query_camera
+        .single()
+        .ok()
+        .and_then(|(_, _, _, camera_animation)| camera_animation)
+        .map_or(false, |camera_animation| !camera_animation.completed());
+
+    if let Some(index) = highlighted_index.filter(|_| !pointer_over_egui) {
+        // Drag to turn the highlighted character
+        if mouse_button_input.pressed(MouseButton::Left)
+            && !mouse_button_input.just_pressed(MouseButton::Left)
+        {
+            if let (Some(previous), Some(current)) = (input_state.cursor_position, cursor_position) {
+                if let Some(yaw) = input_state.slot_yaw.get_mut(index) {
+                    *yaw += (current.x - previous.x) * DRAG_ROTATE_SPEED;
+                }
+            }
+        }
+
+        // Wheel to zoom towards it
+        for event in mouse_wheel_events.read() {
+            let amount = match event.unit {
+                MouseScrollUnit::Line => event.y * 0.1,
+                MouseScrollUnit::Pixel => event.y * 0.002,
+            };
+            if !camera_animating {
+                input_state.zoom = (input_state.zoom + amount).clamp(0.0, 1.0);
+            }
+        }
+    } else {
+        mouse_wheel_events.clear();
+    }
+
+    for (character, mut transform) in query_characters.iter_mut() {
+        let (Some(position), Some(yaw)) = (
+            character_select_scene.position_transform(character.index),
+            input_state.slot_yaw.get(character.index),
+        ) else {
+            continue;
+        };
+        let rotation = position.rotation * Quat::from_rotation_y(*yaw);
+        if transform.rotation != rotation {
+            transform.rotation = rotation;
+        }
     }
 
-    // Get cursor position from window
-    let Ok(window) = query_window.single() else {
+    if input_state.zoom_index != highlighted_index {
+        input_state.zoom_index = highlighted_index;
+        input_state.zoom = 0.0;
+    }
+
+    if let Ok((_, _, mut camera_transform, _)) = query_camera.single_mut() {
+        if camera_animating {
+            // A camera motion took over, e.g. to character create, forget the zoom
+            input_state.zoom = 0.0;
+            input_state.zoom_base = None;
+        } else if let Some(target) = highlighted_index
+            .and_then(|index| character_select_scene.position_transform(index))
+            .filter(|_| input_state.zoom > 0.0)
+        {
+            let base = *input_state.zoom_base.get_or_insert(*camera_transform);
+            let target = target.translation + Vec3::new(0.0, ZOOM_TARGET_HEIGHT, 0.0);
+            let look_at_target = base.looking_at(target, Vec3::Y).rotation;
+            camera_transform.translation = base
+                .translation
+                .lerp(target, input_state.zoom * MAX_ZOOM_DISTANCE);
+            camera_transform.rotation = base.rotation.slerp(look_at_target, input_state.zoom);
+        } else if let Some(base) = input_state.zoom_base.take() {
+            *camera_transform = base;
+        }
+    }
+
+    let Ok(rapier_context) = rapier_context.single() else {
+        input_state.cursor_position = cursor_position;
         return;
     };
 
-    let Some(cursor_position) = window.cursor_position() else {
+    if pointer_over_egui {
+        input_state.cursor_position = cursor_position;
+        return;
+    }
+
+    let Some(cursor_position) = cursor_position else {
         input_state.cursor_position = None;
         return;
     };
@@ -471,7 +725,7 @@ pub fn character_select_input_system(
     input_state.last_click_time = Some(now);
 
     // Get camera for raycasting
-    let Ok((camera, camera_transform)) = query_camera.single() else {
+    let Ok((camera, camera_transform, _, _)) = query_camera.single() else {
         return;
     };
 
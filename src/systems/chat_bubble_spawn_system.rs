@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use bevy::{
     asset::RenderAssetUsages,
+    color::Alpha,
     image::ImageSampler,
     log::warn,
     prelude::{
@@ -21,8 +22,9 @@ use crate::{
         ChatBubble, ChatBubbleBackground, ChatBubbleEntity, ChatBubbleText, ClientEntityName,
         ModelHeight,
     },
-    events::ChatBubbleEvent,
+    events::{ChatBubbleEvent, ChatBubbleType},
     render::WorldUiRect,
+    resources::ChatBubbleSettings,
 };
 
 const CHAT_BUBBLE_PADDING: f32 = 8.0;
@@ -33,12 +35,25 @@ const CHAT_BUBBLE_VERTICAL_OFFSET: f32 = 0.5;
 const CHAT_BUBBLE_DEFAULT_HEIGHT: f32 = 2.0;
 const CHAT_BUBBLE_MAX_WIDTH: f32 = 250.0;
 const CHAT_BUBBLE_FONT_SIZE: f32 = 14.0;
+const CHAT_BUBBLE_CORNER_RADIUS: f32 = 8.0;
+const CHAT_BUBBLE_RIM_WIDTH: f32 = 2.0;
+const CHAT_BUBBLE_RIM_DARKEN: f32 = 70.0;
+
+/// Signed distance in pixels from `point` to the edge of a rounded rectangle
+/// covering `0..size`, negative inside
+fn rounded_rect_distance(point: Vec2, size: Vec2, radius: f32) -> f32 {
+    let half_size = size / 2.0;
+    let radius = radius.min(half_size.x).min(half_size.y);
+    let q = (point - half_size).abs() - half_size + Vec2::splat(radius);
+    q.max(Vec2::ZERO).length() + q.x.max(q.y).min(0.0) - radius
+}
 
 struct PendingChatBubble {
     target_entity: Entity,
     text: String,
     duration: f32,
     color: Color,
+    bubble_type: ChatBubbleType,
     galley: Arc<egui::Galley>,
 }
 
@@ -55,6 +70,7 @@ pub fn chat_bubble_spawn_system(
     query_existing_bubble: Query<(Entity, &ChatBubbleEntity)>,
     query_camera: Query<Entity, (With<Camera>, With<PrimaryEguiContext>)>,
     mut egui_context: EguiContexts,
+    chat_bubble_settings: Res<ChatBubbleSettings>,
     mut images: ResMut<Assets<Image>>,
     mut pending_cache: Local<ChatBubblePendingCache>,
 ) {
@@ -112,8 +128,9 @@ pub fn chat_bubble_spawn_system(
         new_pending.push(PendingChatBubble {
             target_entity,
             text: event.text.clone(),
-            duration: event.duration,
+            duration: event.duration * chat_bubble_settings.lifetime_scale.max(0.1),
             color: event.color,
+            bubble_type: event.bubble_type,
             galley,
         });
     }
@@ -127,6 +144,7 @@ pub fn chat_bubble_spawn_system(
             text,
             duration,
             color,
+            bubble_type,
             galley,
         } = pending;
 
@@ -279,43 +297,24 @@ pub fn chat_bubble_spawn_system(
         let bg_data_len = (bg_width * bg_height * 4) as usize;
         let mut bg_data = vec![0u8; bg_data_len];
 
+        // Rounded panel with a soft darker rim, so the bubble reads against busy scenes
+        let panel_size = text_size.floor();
         for y in 0..bg_height {
             for x in 0..bg_width {
                 let idx = ((y * bg_width + x) * 4) as usize;
-                let corner_radius = 8.0;
-                let x_f = x as f32;
-                let y_f = y as f32;
-                let in_corner = (x_f < corner_radius && y_f < corner_radius)
-                    || (x_f > bg_width as f32 - corner_radius && y_f < corner_radius)
-                    || (x_f < corner_radius && y_f > bg_height as f32 - corner_radius)
-                    || (x_f > bg_width as f32 - corner_radius
-                        && y_f > bg_height as f32 - corner_radius);
-
-                let alpha = if in_corner {
-                    let cx = if x_f < corner_radius {
-                        corner_radius
-                    } else {
-                        bg_width as f32 - corner_radius
-                    };
-                    let cy = if y_f < corner_radius {
-                        corner_radius
-                    } else {
-                        bg_height as f32 - corner_radius
-                    };
-                    let dist = ((x_f - cx).powi(2) + (y_f - cy).powi(2)).sqrt();
-                    if dist < corner_radius {
-                        255u8
-                    } else {
-                        0u8
-                    }
-                } else {
-                    255u8
-                };
-
-                bg_data[idx] = 255;
-                bg_data[idx + 1] = 255;
-                bg_data[idx + 2] = 255;
-                bg_data[idx + 3] = alpha;
+                let distance = rounded_rect_distance(
+                    Vec2::new(x as f32 + 0.5, y as f32 + 0.5),
+                    panel_size,
+                    CHAT_BUBBLE_CORNER_RADIUS,
+                );
+                let coverage = (0.5 - distance).clamp(0.0, 1.0);
+                let rim = ((distance + CHAT_BUBBLE_RIM_WIDTH) / CHAT_BUBBLE_RIM_WIDTH).clamp(0.0, 1.0);
+                let shade = (255.0 - rim * CHAT_BUBBLE_RIM_DARKEN) as u8;
+
+                bg_data[idx] = shade;
+                bg_data[idx + 1] = shade;
+                bg_data[idx + 2] = shade;
+                bg_data[idx + 3] = (coverage * 255.0) as u8;
             }
         }
 
@@ -339,7 +338,7 @@ pub fn chat_bubble_spawn_system(
         let bubble_entity = commands
             .spawn((
                 ChatBubbleEntity { target_entity },
-                ChatBubble::new(target_entity, text.clone(), duration),
+                ChatBubble::new(target_entity, text.clone(), duration, text_size, bubble_type),
                 NoFrustumCulling,
                 Visibility::Inherited,
                 VisibilityClass::default(),
@@ -360,7 +359,8 @@ pub fn chat_bubble_spawn_system(
                 screen_size: text_size,
                 uv_min: Vec2::new(0.0, 0.0),
                 uv_max: Vec2::new(bg_uv_x1, bg_uv_y1),
-                color: CHAT_BUBBLE_BACKGROUND_COLOR,
+                color: CHAT_BUBBLE_BACKGROUND_COLOR
+                    .with_alpha(chat_bubble_settings.background_opacity.clamp(0.0, 1.0)),
                 order: CHAT_BUBBLE_ORDER_BACKGROUND,
             },
             Transform::default(),
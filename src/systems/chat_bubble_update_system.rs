@@ -2,24 +2,46 @@ use bevy::prelude::*;
 
 use crate::{
     components::{ChatBubble, ChatBubbleEntity, ChatBubbleText, ChatBubbleBackground},
-    render::WorldUiRect,
+    render::{WorldUiOpacity, WorldUiRect},
+    resources::{chat_bubble_stack_offsets, ChatBubbleSettings, NameTagSettings},
 };
 
-/// System that updates chat bubble lifetimes and handles fade-out effects
+/// Seconds over which bubbles beyond the visible cap fade out
+const CHAT_BUBBLE_OVERFLOW_FADE_SECONDS: f32 = 0.5;
+
+/// How quickly a bubble slides to its new stacked position
+const CHAT_BUBBLE_STACK_SPEED: f32 = 12.0;
+
+/// System that updates chat bubble lifetimes and handles fade-out effects,
+/// fades bubbles with distance and stacks overlapping bubbles upwards
 pub fn chat_bubble_update_system(
     mut commands: Commands,
     time: Res<Time<Virtual>>,
-    mut query_bubbles: Query<(Entity, &mut ChatBubble), With<ChatBubbleEntity>>,
+    chat_bubble_settings: Res<ChatBubbleSettings>,
+    name_tag_settings: Res<NameTagSettings>,
+    query_camera: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    mut query_bubbles: Query<(Entity, &mut ChatBubble, &GlobalTransform), With<ChatBubbleEntity>>,
     query_children: Query<&Children, With<ChatBubbleEntity>>,
     // Use Without<> to make queries disjoint and avoid Bevy error B0001
-    mut query_text_rects: Query<&mut WorldUiRect, (With<ChatBubbleText>, Without<ChatBubbleBackground>)>,
-    mut query_bg_rects: Query<&mut WorldUiRect, (With<ChatBubbleBackground>, Without<ChatBubbleText>)>,
+    mut query_text_rects: Query<
+        (&mut WorldUiRect, Option<&mut WorldUiOpacity>),
+        (With<ChatBubbleText>, Without<ChatBubbleBackground>),
+    >,
+    mut query_bg_rects: Query<
+        (&mut WorldUiRect, Option<&mut WorldUiOpacity>),
+        (With<ChatBubbleBackground>, Without<ChatBubbleText>),
+    >,
 ) {
     let delta = time.delta_secs();
+    let camera = query_camera.single().ok();
 
-    for (bubble_entity, mut chat_bubble) in query_bubbles.iter_mut() {
+    // (entity, age, distance opacity, screen anchor)
+    let mut bubbles: Vec<(Entity, f32, f32, Option<Vec2>)> = Vec::new();
+
+    for (bubble_entity, mut chat_bubble, global_transform) in query_bubbles.iter_mut() {
         // Update remaining time
         chat_bubble.remaining_time -= delta;
+        chat_bubble.age += delta;
 
         // Check if bubble should be despawned
         if chat_bubble.remaining_time <= 0.0 {
@@ -27,24 +49,101 @@ pub fn chat_bubble_update_system(
             continue;
         }
 
+        let (distance_opacity, anchor) = if let Some((camera, camera_transform)) = camera {
+            let position = global_transform.translation();
+            let distance_opacity = name_tag_settings.get_opacity(
+                name_tag_settings.max_distance[chat_bubble.name_tag_type()],
+                camera_transform.translation().distance(position),
+            );
+            // World UI rects are offset in physical pixels
+            let anchor = camera
+                .world_to_viewport(camera_transform, position)
+                .ok()
+                .map(|anchor| anchor * camera.target_scaling_factor().unwrap_or(1.0));
+            (distance_opacity, anchor)
+        } else {
+            (1.0, None)
+        };
+
+        bubbles.push((bubble_entity, chat_bubble.age, distance_opacity, anchor));
+    }
+
+    // Newest first, bubbles beyond the cap fade out oldest first
+    bubbles.sort_by(|a, b| a.1.total_cmp(&b.1));
+    let overflow = bubbles
+        .iter()
+        .filter(|(_, _, distance_opacity, _)| *distance_opacity > 0.0)
+        .map(|(entity, ..)| *entity)
+        .skip(chat_bubble_settings.max_visible)
+        .collect::<Vec<_>>();
+    for entity in overflow {
+        if let Ok((_, mut chat_bubble, _)) = query_bubbles.get_mut(entity) {
+            chat_bubble.fade_out(CHAT_BUBBLE_OVERFLOW_FADE_SECONDS);
+        }
+    }
+
+    let stacked_bubbles = bubbles
+        .iter()
+        .filter(|(_, _, distance_opacity, _)| *distance_opacity > 0.0)
+        .filter_map(|(entity, _, _, anchor)| {
+            let (_, chat_bubble, _) = query_bubbles.get(*entity).ok()?;
+            Some((*entity, ((*anchor)?, chat_bubble.size)))
+        })
+        .collect::<Vec<_>>();
+    let stack_offsets = if chat_bubble_settings.stacking {
+        chat_bubble_stack_offsets(
+            &stacked_bubbles
+                .iter()
+                .map(|(_, rect)| *rect)
+                .collect::<Vec<_>>(),
+        )
+    } else {
+        vec![0.0; stacked_bubbles.len()]
+    };
+    let stack_blend = 1.0 - (-CHAT_BUBBLE_STACK_SPEED * delta).exp();
+
+    for (bubble_entity, _, distance_opacity, _) in bubbles {
+        let Ok((_, mut chat_bubble, _)) = query_bubbles.get_mut(bubble_entity) else {
+            continue;
+        };
+
+        let target_offset = stacked_bubbles
+            .iter()
+            .position(|(entity, _)| *entity == bubble_entity)
+            .map_or(chat_bubble.stack_offset, |index| stack_offsets[index]);
+        chat_bubble.stack_offset += (target_offset - chat_bubble.stack_offset) * stack_blend;
+
         // Calculate fade alpha
-        let fade_alpha = chat_bubble.get_fade_alpha();
+        let opacity = chat_bubble.get_fade_alpha()
+            * distance_opacity
+            * chat_bubble_settings.opacity.clamp(0.0, 1.0);
+        let screen_offset = Vec2::new(
+            -chat_bubble.size.x / 2.0,
+            -chat_bubble.size.y - chat_bubble.stack_offset,
+        );
 
         // Update child rects if we can get them
         if let Ok(children) = query_children.get(bubble_entity) {
             for child in children.iter() {
-                // Update text rect
-                if let Ok(mut rect) = query_text_rects.get_mut(child) {
-                    let base_color = rect.color;
-                    let srgba = base_color.to_srgba();
-                    rect.color = Color::srgba(srgba.red, srgba.green, srgba.blue, srgba.alpha * fade_alpha);
+                let rect = if let Ok(rect) = query_text_rects.get_mut(child) {
+                    rect
+                } else if let Ok(rect) = query_bg_rects.get_mut(child) {
+                    rect
+                } else {
+                    continue;
+                };
+                let (mut rect, child_opacity) = rect;
+
+                if rect.screen_offset != screen_offset {
+                    rect.screen_offset = screen_offset;
                 }
 
-                // Update background rect
-                if let Ok(mut rect) = query_bg_rects.get_mut(child) {
-                    let base_color = rect.color;
-                    let srgba = base_color.to_srgba();
-                    rect.color = Color::srgba(srgba.red, srgba.green, srgba.blue, srgba.alpha * fade_alpha);
+                if let Some(mut child_opacity) = child_opacity {
+                    if child_opacity.0 != opacity {
+                        child_opacity.0 = opacity;
+                    }
+                } else {
+                    commands.entity(child).try_insert(WorldUiOpacity(opacity));
                 }
             }
         }
@@ -1,8 +1,9 @@
 use std::sync::Arc;
 
 use bevy::{
+    ecs::system::SystemParam,
     math::Vec3Swizzles,
-    prelude::{Assets, Entity, MessageReader, Local, Query, Res, With},
+    prelude::{Assets, Entity, MessageReader, Local, Query, Res, ResMut, Time, With},
 };
 use bevy_egui::{egui, EguiContexts};
 use rose_file_readers::{ConFile, ConMessageType};
@@ -10,8 +11,11 @@ use rose_game_common::components::QuestState;
 
 use crate::{
     components::{ClanMembership, ClientEntity, ClientEntityName, PlayerCharacter, Position},
-    events::{BankEvent, ChatboxEvent, ClanDialogEvent, ConversationDialogEvent, NpcStoreEvent, SystemFuncEvent},
-    resources::{GameData, UiResources, UiSprite},
+    events::{BankEvent, ChatboxEvent, ClanDialogEvent, ConversationDialogEvent, LoadZoneEvent, NpcStoreEvent, SystemFuncEvent},
+    resources::{
+        ConversationHistory, ConversationLine, ConversationRecord, ConversationSettings, GameData,
+        UiResources, UiSprite,
+    },
     scripting::{
         lua4::{Lua4Function, Lua4VM, Lua4VMError, Lua4VMRustClosures, Lua4Value},
         LuaGameConstants, LuaGameFunctions, LuaQuestFunctions, LuaUserValueEntity,
@@ -36,10 +40,93 @@ pub struct GeneratedDialog {
 
 pub struct ConversationDialogState {
     pub owner_entity: Option<Entity>,
+    pub npc_name: String,
     pub con_file: ConFile,
     pub generated_dialog: GeneratedDialog,
     pub lua_vm: Lua4VM,
     pub event_object_handle: Arc<dyn std::any::Any + Send + Sync>,
+    /// Everything said so far, so earlier messages can be read again
+    pub lines: Vec<ConversationLine>,
+    /// Characters of the current message shown so far
+    pub revealed_chars: f32,
+}
+
+impl ConversationDialogState {
+    /// Record the message now shown and restart its reveal, text already seen
+    /// this session is shown at once when the settings allow it
+    fn show_message(
+        &mut self,
+        history: &mut ConversationHistory,
+        settings: &ConversationSettings,
+    ) {
+        let text = &self.generated_dialog.message.text;
+        if self
+            .lines
+            .last()
+            .map_or(false, |line| !line.is_response && line.text == *text)
+        {
+            return;
+        }
+
+        let seen = history.mark_seen(text);
+        self.revealed_chars = if settings.skip_seen_text && seen {
+            f32::MAX
+        } else {
+            0.0
+        };
+        self.lines.push(ConversationLine {
+            text: text.clone(),
+            is_response: false,
+        });
+    }
+}
+
+/// Grouped conversation history parameters for conversation_dialog_system to avoid parameter count limit
+#[derive(SystemParam)]
+pub struct ConversationHistoryParams<'w, 's> {
+    pub history: ResMut<'w, ConversationHistory>,
+    pub settings: Res<'w, ConversationSettings>,
+    pub load_zone_events: MessageReader<'w, 's, LoadZoneEvent>,
+    pub time: Res<'w, Time>,
+}
+
+fn end_conversation(
+    current_dialog_state: &mut Option<ConversationDialogState>,
+    history: &mut ConversationHistory,
+) {
+    if let Some(dialog_state) = current_dialog_state.take() {
+        history.push(ConversationRecord {
+            npc_name: dialog_state.npc_name,
+            lines: dialog_state.lines,
+        });
+    }
+}
+
+/// The first `num_chars` characters of `job`, used to reveal text gradually
+fn truncate_layout_job(job: &egui::text::LayoutJob, num_chars: usize) -> egui::text::LayoutJob {
+    let end = job
+        .text
+        .char_indices()
+        .nth(num_chars)
+        .map_or(job.text.len(), |(index, _)| index);
+
+    let mut truncated = job.clone();
+    truncated.text.truncate(end);
+    truncated.sections.retain(|section| section.byte_range.start < end);
+    for section in truncated.sections.iter_mut() {
+        section.byte_range.end = section.byte_range.end.min(end);
+    }
+    truncated
+}
+
+fn ui_add_conversation_lines(ui: &mut egui::Ui, lines: &[ConversationLine]) {
+    for line in lines {
+        if line.is_response {
+            ui.label(egui::RichText::new(format!("> {}", line.text)).weak().italics());
+        } else {
+            ui.label(&line.text);
+        }
+    }
 }
 
 pub struct LuaVMContext<'a, 'w1, 's1, 'w2, 's2> {
@@ -78,6 +165,7 @@ fn create_conversation_dialog(
     con_file: ConFile,
     user_context: &mut LuaVMContext,
     owner_entity: Option<Entity>,
+    npc_name: String,
 ) -> Option<ConversationDialogState> {
     let mut lua_vm = Lua4VM::new();
 
@@ -100,10 +188,13 @@ fn create_conversation_dialog(
 
     Some(ConversationDialogState {
         owner_entity,
+        npc_name,
         con_file,
         event_object_handle: Arc::new(LuaUserValueEntity { owner_entity }),
         generated_dialog: Default::default(),
         lua_vm,
+        lines: Vec::new(),
+        revealed_chars: 0.0,
     })
 }
 
@@ -353,6 +444,7 @@ struct UiConversationDialogSprites {
 pub struct UiConversationDialogState {
     dialog_instance: DialogInstance,
     sprites: Option<UiConversationDialogSprites>,
+    history_open: bool,
 }
 
 impl Default for UiConversationDialogState {
@@ -360,6 +452,7 @@ impl Default for UiConversationDialogState {
         Self {
             dialog_instance: DialogInstance::new("DLGDIALOG.XML"),
             sprites: None,
+            history_open: false,
         }
     }
 }
@@ -381,8 +474,21 @@ pub fn conversation_dialog_system(
     vfs_resource: Res<VfsResource>,
     ui_resources: Res<UiResources>,
     dialog_assets: Res<Assets<Dialog>>,
+    history_params: ConversationHistoryParams,
 ) {
+    let ConversationHistoryParams {
+        mut history,
+        settings,
+        mut load_zone_events,
+        time,
+    } = history_params;
+    let current_dialog_state = &mut *current_dialog_state;
     let ui_state = &mut *ui_state;
+
+    if load_zone_events.read().last().is_some() {
+        history.clear();
+    }
+
     let dialog = if let Some(dialog) = ui_state
         .dialog_instance
         .get_mut(&dialog_assets, &ui_resources)
@@ -425,14 +531,18 @@ pub fn conversation_dialog_system(
             }
             ConversationDialogEvent::OpenEventDialog(con_file_path) => (None, con_file_path),
         };
-        *current_dialog_state = None;
+        end_conversation(current_dialog_state, &mut history);
+
+        let npc_name = owner_entity
+            .and_then(|entity| query_name.get(entity).ok())
+            .map_or_else(|| "Event Dialog".to_string(), |name| name.name.clone());
 
         if let Some(mut next_dialog_state) = vfs_resource
             .vfs
             .read_file::<ConFile, _>(con_file_path)
             .ok()
             .and_then(|con_file| {
-                create_conversation_dialog(con_file, &mut user_context, owner_entity)
+                create_conversation_dialog(con_file, &mut user_context, owner_entity, npc_name)
             })
         {
             let check_open_function =
@@ -467,6 +577,7 @@ pub fn conversation_dialog_system(
                                 &game_data,
                                 0,
                             ) {
+                                next_dialog_state.show_message(&mut history, &settings);
                                 *current_dialog_state = Some(next_dialog_state);
                             }
                         }
@@ -489,16 +600,66 @@ pub fn conversation_dialog_system(
                     &game_data,
                     0,
                 ) {
+                    next_dialog_state.show_message(&mut history, &settings);
                     *current_dialog_state = Some(next_dialog_state);
                 }
             }
         }
     }
 
+    let ctx = egui_context.ctx_mut().unwrap();
+    if !ctx.wants_keyboard_input()
+        && ctx.input_mut(|input| input.consume_key(egui::Modifiers::ALT, egui::Key::L))
+    {
+        ui_state.history_open = !ui_state.history_open;
+    }
+
+    egui::Window::new("Conversation History")
+        .open(&mut ui_state.history_open)
+        .default_width(320.0)
+        .default_height(360.0)
+        .show(ctx, |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                if let Some(dialog_state) = current_dialog_state.as_ref() {
+                    egui::CollapsingHeader::new(format!("{} (current)", dialog_state.npc_name))
+                        .default_open(true)
+                        .show(ui, |ui| ui_add_conversation_lines(ui, &dialog_state.lines));
+                }
+
+                // One entry per NPC, most recently spoken to first
+                let mut npc_names: Vec<&str> = Vec::new();
+                for record in history.conversations.iter().rev() {
+                    if !npc_names.contains(&record.npc_name.as_str()) {
+                        npc_names.push(&record.npc_name);
+                    }
+                }
+
+                for npc_name in npc_names {
+                    egui::CollapsingHeader::new(npc_name).show(ui, |ui| {
+                        for record in history
+                            .conversations
+                            .iter()
+                            .rev()
+                            .filter(|record| record.npc_name == npc_name)
+                        {
+                            ui_add_conversation_lines(ui, &record.lines);
+                            ui.separator();
+                        }
+                    });
+                }
+
+                if current_dialog_state.is_none() && history.conversations.is_empty() {
+                    ui.label("No conversations in this zone yet.");
+                }
+            });
+        });
+
     // Render the dialog if one is active
     if let Some(dialog_state) = current_dialog_state.as_mut() {
         let mut selected_response = None;
         let mut open = true;
+        let mut reveal_message = false;
+        let mut toggle_history = false;
 
         // If player has moved away from NPC, close the dialog
         if let (Ok(player_position), Some(npc_position)) = (
@@ -508,13 +669,22 @@ pub fn conversation_dialog_system(
                 .and_then(|entity| query_position.get(entity).ok()),
         ) {
             if npc_position.position.xy().distance(player_position.xy()) > 400.0 {
-                *current_dialog_state = None;
+                end_conversation(current_dialog_state, &mut history);
                 return;
             }
         }
 
+        if settings.text_speed > 0.0 {
+            dialog_state.revealed_chars += time.delta_secs() * settings.text_speed;
+        } else {
+            dialog_state.revealed_chars = f32::MAX;
+        }
+        let message_revealed = dialog_state.revealed_chars
+            >= dialog_state.generated_dialog.message.text.chars().count() as f32;
+
         let (message_galley, num_message_middle, num_response_middles) =
             egui_context.ctx_mut().unwrap().fonts_mut(|fonts| {
+                // The dialog is sized for the whole message so it does not grow while revealing
                 let message_galley =
                     fonts.layout_job(dialog_state.generated_dialog.message.clone());
 
@@ -534,6 +704,15 @@ pub fn conversation_dialog_system(
                     ((response_size_y + dialog_sprites.answer_middle.height - 1.0)
                         / dialog_sprites.answer_middle.height) as usize;
 
+                let message_galley = if message_revealed {
+                    message_galley
+                } else {
+                    fonts.layout_job(truncate_layout_job(
+                        &dialog_state.generated_dialog.message,
+                        dialog_state.revealed_chars as usize,
+                    ))
+                };
+
                 (message_galley, num_message_middle, num_response_middles)
             });
 
@@ -545,11 +724,8 @@ pub fn conversation_dialog_system(
             + num_response_middles as f32 * dialog_sprites.answer_middle.height
             + dialog_sprites.answer_bottom.height;
 
-        let title = dialog_state
-            .owner_entity
-            .and_then(|entity| query_name.get(entity).ok())
-            .map(|name| name.as_str())
-            .unwrap_or("Event Dialog");
+        let title = dialog_state.npc_name.as_str();
+        let dialog_width = dialog.width;
 
         let mut response_close_button = None;
         let screen_size = egui_context
@@ -587,6 +763,20 @@ pub fn conversation_dialog_system(
                             ),
                         );
 
+                        if ui
+                            .put(
+                                egui::Rect::from_min_size(
+                                    ui.min_rect().min + egui::vec2(dialog_width - 70.0, 2.0),
+                                    egui::vec2(36.0, 16.0),
+                                ),
+                                egui::Button::new("Log").small(),
+                            )
+                            .on_hover_text("Read this and earlier conversations again (Alt+L)")
+                            .clicked()
+                        {
+                            toggle_history = true;
+                        }
+
                         let mut pos = ui.min_rect().min + egui::vec2(0.0, 24.0);
                         dialog_sprites.message_top.draw(ui, pos);
                         pos.y += dialog_sprites.message_top.height - 1.0;
@@ -617,7 +807,17 @@ pub fn conversation_dialog_system(
                             egui::pos2(message_rect_min.x + 25.0 + 300.0, pos.y),
                         );
                         ui.allocate_ui_at_rect(message_rect, |ui| {
-                            ui.horizontal_top(|ui| ui.add(egui::Label::new(message_galley.clone())))
+                            ui.horizontal_top(|ui| {
+                                if ui
+                                    .add(
+                                        egui::Label::new(message_galley.clone())
+                                            .sense(egui::Sense::click()),
+                                    )
+                                    .clicked()
+                                {
+                                    reveal_message = true;
+                                }
+                            })
                         });
 
                         let mut response_rect_min = response_rect_min + egui::vec2(15.0, 5.0);
@@ -659,9 +859,13 @@ pub fn conversation_dialog_system(
             open = false;
         }
 
+        if toggle_history {
+            ui_state.history_open = !ui_state.history_open;
+        }
+
         if !open {
             // User closed the dialog
-            *current_dialog_state = None;
+            end_conversation(current_dialog_state, &mut history);
             return;
         }
 
@@ -685,15 +889,38 @@ pub fn conversation_dialog_system(
                         selected_response = Some(index);
                     }
                 }
+
+                if input.key_pressed(egui::Key::Space) {
+                    reveal_message = true;
+                }
             });
         }
 
+        // The first click finishes the message so it cannot be skipped past unread
+        if selected_response.is_some() && !message_revealed {
+            reveal_message = true;
+            selected_response = None;
+        }
+
+        if reveal_message {
+            dialog_state.revealed_chars = f32::MAX;
+        }
+
         if let Some(selected_response) = selected_response {
             if let Some(selected_response) = dialog_state
                 .generated_dialog
                 .responses
                 .get(selected_response)
             {
+                let response_text = &selected_response.text.text;
+                dialog_state.lines.push(ConversationLine {
+                    text: response_text
+                        .split_once(". ")
+                        .map_or(response_text.as_str(), |(_, text)| text)
+                        .to_string(),
+                    is_response: true,
+                });
+
                 let click_action_function = &selected_response.action_function;
                 if !click_action_function.is_empty() {
                     if let Err(error) = dialog_state.lua_vm.call_global_closure(
@@ -719,7 +946,9 @@ pub fn conversation_dialog_system(
                     &game_data,
                     selected_response.menu_index,
                 ) {
-                    *current_dialog_state = None;
+                    end_conversation(current_dialog_state, &mut history);
+                } else {
+                    dialog_state.show_message(&mut history, &settings);
                 }
             }
         }
@@ -2,7 +2,7 @@ use bevy::{
     asset::RenderAssetUsages,
     math::{Vec3Swizzles, Vec4},
     pbr::MeshMaterial3d,
-    prelude::{Commands, Entity, GlobalTransform, Query, ResMut, Assets, Mesh, Mesh3d},
+    prelude::{Commands, Entity, GlobalTransform, Query, Res, ResMut, Assets, Mesh, Mesh3d},
     render::storage::ShaderStorageBuffer,
     mesh::PrimitiveTopology,
 };
@@ -11,7 +11,7 @@ use crate::{
     animation::TransformAnimation, 
     components::DamageDigits, 
     render::DamageDigitRenderData,
-    resources::PendingDamageDigitMaterial,
+    resources::{AccessibilitySettings, ColorblindMode, DamageDigitsSpawner, PendingDamageDigitMaterial},
     render::DamageDigitMaterial,
 };
 
@@ -26,7 +26,17 @@ pub fn create_damage_digit_material_system(
     mut materials: ResMut<Assets<DamageDigitMaterial>>,
     mut storage_buffers: ResMut<Assets<ShaderStorageBuffer>>,
     mut meshes: ResMut<Assets<Mesh>>,
+    damage_digits_spawner: Res<DamageDigitsSpawner>,
+    accessibility_settings: Res<AccessibilitySettings>,
 ) {
+    // Damage taken by the player is drawn in red, recolour it when red is hard to see
+    let player_damage_tint = if accessibility_settings.colorblind_mode == ColorblindMode::Off {
+        Vec4::ZERO
+    } else {
+        let [r, g, b, _] = accessibility_settings.negative_color().to_normalized_gamma_f32();
+        Vec4::new(r, g, b, 1.0)
+    };
+
     let entity_count = query.iter().count();
    // log::info!("[CREATE_DAMAGE_DIGIT_MATERIAL] Found {} entities with PendingDamageDigitMaterial", entity_count);
     
@@ -45,6 +55,11 @@ pub fn create_damage_digit_material_system(
             sizes: sizes_buffer,
             uvs: uvs_buffer,
             texture: pending.texture.clone(),
+            tint: if pending.texture == damage_digits_spawner.texture_damage_player {
+                player_damage_tint
+            } else {
+                Vec4::ZERO
+            },
         });
        // log::info!("[CREATE_DAMAGE_DIGIT_MATERIAL] Created DamageDigitMaterial for entity {:?} with handle {:?}", entity, material);
         
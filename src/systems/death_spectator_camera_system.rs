@@ -0,0 +1,133 @@
+use bevy::{
+    input::ButtonInput,
+    math::Vec3,
+    prelude::{
+        Commands, Component, Entity, GlobalTransform, Has, KeyCode, MouseButton, Query, Res,
+        Time, With, Without,
+    },
+};
+use bevy_egui::EguiContexts;
+use dolly::prelude::YawPitch;
+
+use crate::{
+    components::{Dead, PartyInfo, PlayerCharacter},
+    resources::ClientEntityList,
+    systems::{get_party_member_entities, OrbitCamera},
+};
+
+/// Offset used to look at a corpse lying on the ground
+const SPECTATE_CORPSE_OFFSET: Vec3 = Vec3::new(0.0, 0.5, 0.0);
+
+/// Maximum distance of a party member from our corpse to be spectated
+const SPECTATE_PARTY_MAX_DISTANCE: f32 = 60.0;
+
+/// Seconds without camera input before the camera starts orbiting automatically
+const AUTO_ORBIT_IDLE_SECONDS: f32 = 2.0;
+const AUTO_ORBIT_DEGREES_PER_SECOND: f32 = 8.0;
+
+/// Added to the orbit camera while the player is dead, stores the camera
+/// configuration which is restored on respawn.
+#[derive(Component)]
+pub struct DeathSpectatorCamera {
+    pub saved_follow_entity: Entity,
+    pub saved_follow_offset: Vec3,
+    pub saved_follow_distance: f32,
+    pub spectate_entity: Entity,
+    pub idle_time: f32,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn death_spectator_camera_system(
+    mut commands: Commands,
+    mut query_camera: Query<(Entity, &mut OrbitCamera, Option<&mut DeathSpectatorCamera>)>,
+    query_player: Query<(Entity, &GlobalTransform, Has<Dead>, Option<&PartyInfo>), With<PlayerCharacter>>,
+    query_living: Query<&GlobalTransform, (Without<Dead>, Without<PlayerCharacter>)>,
+    client_entity_list: Res<ClientEntityList>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    mut egui_ctx: EguiContexts,
+    time: Res<Time>,
+) {
+    let Ok((camera_entity, mut orbit_camera, spectator)) = query_camera.single_mut() else {
+        return;
+    };
+    let Ok((player_entity, player_transform, player_dead, party_info)) = query_player.single()
+    else {
+        return;
+    };
+
+    let Some(mut spectator) = spectator else {
+        if player_dead {
+            commands.entity(camera_entity).insert(DeathSpectatorCamera {
+                saved_follow_entity: orbit_camera.follow_entity,
+                saved_follow_offset: orbit_camera.follow_offset,
+                saved_follow_distance: orbit_camera.follow_distance,
+                spectate_entity: player_entity,
+                idle_time: 0.0,
+            });
+            orbit_camera.follow_entity = player_entity;
+            orbit_camera.follow_offset = SPECTATE_CORPSE_OFFSET;
+        }
+        return;
+    };
+
+    if !player_dead {
+        // Respawned, restore the camera as it was before we died
+        orbit_camera.follow_entity = spectator.saved_follow_entity;
+        orbit_camera.follow_offset = spectator.saved_follow_offset;
+        orbit_camera.follow_distance = spectator.saved_follow_distance;
+        commands.entity(camera_entity).remove::<DeathSpectatorCamera>();
+        return;
+    }
+
+    // Build the list of targets we can spectate, our corpse is always first
+    let mut spectate_targets = vec![player_entity];
+    if let Some(party_info) = party_info {
+        for member_entity in get_party_member_entities(party_info, &client_entity_list) {
+            if query_living.get(member_entity).map_or(false, |member_transform| {
+                member_transform
+                    .translation()
+                    .distance(player_transform.translation())
+                    <= SPECTATE_PARTY_MAX_DISTANCE
+            }) {
+                spectate_targets.push(member_entity);
+            }
+        }
+    }
+
+    let current_index = spectate_targets
+        .iter()
+        .position(|entity| *entity == spectator.spectate_entity);
+    let wants_keyboard_input = egui_ctx.ctx_mut().unwrap().wants_keyboard_input();
+    let next_index = if !wants_keyboard_input && keyboard_input.just_pressed(KeyCode::Tab) {
+        current_index.map_or(0, |index| (index + 1) % spectate_targets.len())
+    } else {
+        // Fall back to our corpse if the spectated member died or moved away
+        current_index.unwrap_or(0)
+    };
+
+    let spectate_entity = spectate_targets[next_index];
+    if spectate_entity != spectator.spectate_entity || orbit_camera.follow_entity != spectate_entity {
+        spectator.spectate_entity = spectate_entity;
+        orbit_camera.follow_entity = spectate_entity;
+        orbit_camera.follow_offset = if spectate_entity == player_entity {
+            SPECTATE_CORPSE_OFFSET
+        } else {
+            spectator.saved_follow_offset
+        };
+    }
+
+    // Slowly orbit around the target while the player is not looking around
+    if mouse_buttons.pressed(MouseButton::Right) {
+        spectator.idle_time = 0.0;
+    } else {
+        spectator.idle_time += time.delta_secs();
+
+        if spectator.idle_time > AUTO_ORBIT_IDLE_SECONDS {
+            orbit_camera
+                .rig
+                .driver_mut::<YawPitch>()
+                .rotate_yaw_pitch(AUTO_ORBIT_DEGREES_PER_SECOND * time.delta_secs(), 0.0);
+        }
+    }
+}
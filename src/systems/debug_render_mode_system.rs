@@ -0,0 +1,150 @@
+use bevy::{
+    color::Color,
+    light::NotShadowCaster,
+    pbr::{wireframe::Wireframe, MeshMaterial3d, StandardMaterial},
+    prelude::{
+        Assets, ChildOf, Commands, Component, Entity, Handle, Has, Local, Mesh3d,
+        Or, Query, Res, ResMut, Transform, Visibility, With,
+    },
+    render::alpha::AlphaMode,
+};
+
+use crate::{
+    components::{EffectMesh, ZoneObject},
+    resources::DebugRenderConfig,
+};
+
+/// Stores the visibility an entity had before a debug render mode hid it
+#[derive(Component)]
+pub struct DebugRenderHidden {
+    visibility: Visibility,
+}
+
+/// Mesh drawn in place of `source` while the overdraw heatmap is enabled
+#[derive(Component)]
+pub struct DebugOverdrawProxy {
+    source: Entity,
+}
+
+/// Marks an entity which currently has an overdraw proxy
+#[derive(Component)]
+pub struct DebugOverdrawSource {
+    proxy: Entity,
+}
+
+/// Applies the wireframe, overdraw and isolation debug render modes to zone
+/// content, restoring the original visibility once they are turned off.
+///
+/// The overdraw heatmap hides each mesh and spawns a sibling with the same
+/// mesh and an unlit additive material, so every fragment drawn adds a little
+/// more tint and heavily layered areas stand out.
+pub fn debug_render_mode_system(
+    mut commands: Commands,
+    debug_render_config: Res<DebugRenderConfig>,
+    mut overdraw_material: Local<Option<Handle<StandardMaterial>>>,
+    mut standard_materials: ResMut<Assets<StandardMaterial>>,
+    mut query_entities: Query<
+        (
+            Entity,
+            Option<&ZoneObject>,
+            Option<&Mesh3d>,
+            &Transform,
+            Option<&ChildOf>,
+            &mut Visibility,
+            Option<&DebugRenderHidden>,
+            Option<&DebugOverdrawSource>,
+            Has<Wireframe>,
+        ),
+        Or<(With<ZoneObject>, With<EffectMesh>)>,
+    >,
+    query_proxies: Query<(Entity, &DebugOverdrawProxy)>,
+) {
+    if !debug_render_config.is_changed() && !debug_render_config.zone_render_mode_active() {
+        return;
+    }
+
+    // Remove proxies whose source mesh has been despawned
+    for (proxy_entity, proxy) in query_proxies.iter() {
+        if !query_entities.contains(proxy.source) {
+            commands.entity(proxy_entity).despawn();
+        }
+    }
+
+    for (
+        entity,
+        zone_object,
+        mesh,
+        transform,
+        child_of,
+        mut visibility,
+        hidden,
+        overdraw_source,
+        has_wireframe,
+    ) in query_entities.iter_mut()
+    {
+        let isolated = zone_object.is_some_and(|zone_object| {
+            !debug_render_config.isolation.shows(zone_object)
+        });
+        let overdraw = debug_render_config.overdraw && mesh.is_some() && !isolated;
+
+        if isolated || overdraw {
+            if hidden.is_none() {
+                commands.entity(entity).insert(DebugRenderHidden {
+                    visibility: *visibility,
+                });
+                *visibility = Visibility::Hidden;
+            }
+        } else if let Some(hidden) = hidden {
+            *visibility = hidden.visibility;
+            commands.entity(entity).remove::<DebugRenderHidden>();
+        }
+
+        match (overdraw, overdraw_source, mesh) {
+            (true, None, Some(mesh)) => {
+                let material = overdraw_material
+                    .get_or_insert_with(|| {
+                        standard_materials.add(StandardMaterial {
+                            base_color: Color::srgb(0.12, 0.04, 0.01),
+                            unlit: true,
+                            double_sided: true,
+                            cull_mode: None,
+                            alpha_mode: AlphaMode::Add,
+                            ..Default::default()
+                        })
+                    })
+                    .clone();
+
+                let mut proxy_commands = commands.spawn((
+                    DebugOverdrawProxy { source: entity },
+                    mesh.clone(),
+                    MeshMaterial3d(material),
+                    *transform,
+                    Visibility::Inherited,
+                    NotShadowCaster,
+                ));
+                if let Some(child_of) = child_of {
+                    proxy_commands.insert(ChildOf(child_of.parent()));
+                }
+                let proxy = proxy_commands.id();
+                commands
+                    .entity(entity)
+                    .insert(DebugOverdrawSource { proxy });
+            }
+            (false, Some(overdraw_source), _) => {
+                if let Ok(mut proxy_commands) = commands.get_entity(overdraw_source.proxy) {
+                    proxy_commands.despawn();
+                }
+                commands.entity(entity).remove::<DebugOverdrawSource>();
+            }
+            _ => {}
+        }
+
+        if mesh.is_some() && debug_render_config.wireframe != has_wireframe {
+            if debug_render_config.wireframe {
+                commands.entity(entity).insert(Wireframe);
+            } else {
+                commands.entity(entity).remove::<Wireframe>();
+            }
+        }
+    }
+}
@@ -7,7 +7,7 @@ use bevy::core_pipeline::core_3d::{Opaque3d, Transparent3d};
 use bevy::render::render_phase::ViewSortedRenderPhases;
 use bevy::render::view::ViewUniformOffset;
 use bevy::pbr::{ExtendedMaterial, MeshMaterial3d};
-use crate::components::{Zone, ZoneObject};
+use crate::components::{Zone, ZoneInstancingStats, ZoneObject};
 use crate::render::{
     ParticleMaterial, DamageDigitMaterial, RoseEffectExtension
 };
@@ -120,6 +120,7 @@ pub fn render_diagnostics_system(
     damage_digit_material_assets: Res<Assets<DamageDigitMaterial>>,
     images: Res<Assets<Image>>,
     windows: Query<&Window>,
+    zone_instancing_stats: Query<&ZoneInstancingStats>,
 ) {
     use bevy::log::info;
     
@@ -257,6 +258,15 @@ pub fn render_diagnostics_system(
     info!("[RENDER DIAGNOSTICS]   ParticleMaterials: {}", particle_material_assets.len());
     info!("[RENDER DIAGNOSTICS]   DamageDigitMaterials: {}", damage_digit_material_assets.len());
     info!("[RENDER DIAGNOSTICS]   Images: {}", images.len());
+
+    for stats in zone_instancing_stats.iter() {
+        info!(
+            "[RENDER DIAGNOSTICS] Instanced zone objects: {} parts in {} draw batches ({} draw calls saved)",
+            stats.parts,
+            stats.draw_batches,
+            stats.parts.saturating_sub(stats.draw_batches)
+        );
+    }
     
     if mesh_assets.len() == 0 {
         warn!("[RENDER DIAGNOSTICS] WARNING: No mesh assets loaded!");
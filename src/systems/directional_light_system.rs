@@ -1,19 +1,65 @@
 use bevy::{
-    light::DirectionalLightShadowMap,
+    light::{DirectionalLightShadowMap, FogVolume, VolumetricLight},
     prelude::{Camera, DirectionalLight, Entity, GlobalTransform, Mat4, Query, Res, Vec3, With},
 };
 
-use crate::components::PlayerCharacter;
+use crate::{
+    components::PlayerCharacter,
+    render::VolumetricFogVolume,
+    resources::{ZoneTime, ZoneTimeState},
+    ui::PostProcessingSettings,
+};
 
 const PROJECTION_HALF_SIZE: f32 = 40.0;
 const PROJECTION_HALF_DEPTH: f32 = 100.0;
 
+/// Light shaft intensity with the sun overhead and at the horizon
+const LIGHT_SHAFT_NOON_INTENSITY: f32 = 0.25;
+const LIGHT_SHAFT_HORIZON_INTENSITY: f32 = 2.0;
+
+/// Sun elevation in radians over which the shafts fade out as it sets
+const LIGHT_SHAFT_HORIZON_FADE: f32 = 0.08;
+
+/// Volumetric light shaft intensity for a sun `elevation` radians above the
+/// horizon, strongest when the sun is low and gone once it has set
+pub fn light_shaft_intensity(elevation: f32) -> f32 {
+    if elevation <= 0.0 {
+        return 0.0;
+    }
+
+    let low_sun = (1.0 - elevation.sin()).powi(2);
+    let horizon_fade = (elevation / LIGHT_SHAFT_HORIZON_FADE).min(1.0);
+    (LIGHT_SHAFT_NOON_INTENSITY
+        + (LIGHT_SHAFT_HORIZON_INTENSITY - LIGHT_SHAFT_NOON_INTENSITY) * low_sun)
+        * horizon_fade
+}
+
 pub fn directional_light_system(
     query_player: Query<&GlobalTransform, With<PlayerCharacter>>,
     query_light: Query<&GlobalTransform, With<DirectionalLight>>,
+    query_sun: Query<&GlobalTransform, (With<DirectionalLight>, With<VolumetricLight>)>,
+    mut query_fog_volume: Query<&mut FogVolume, With<VolumetricFogVolume>>,
     views: Query<(Entity, &GlobalTransform), With<Camera>>,
     shadow_map: Res<DirectionalLightShadowMap>,
+    zone_time: Res<ZoneTime>,
+    post_processing_settings: Res<PostProcessingSettings>,
 ) {
+    // Light shafts follow the sun, dramatic at dawn and dusk and off at night
+    if let Ok(sun_transform) = query_sun.single() {
+        let elevation = (-sun_transform.forward().y).asin();
+        let intensity = if zone_time.state == ZoneTimeState::Night {
+            0.0
+        } else {
+            light_shaft_intensity(elevation) * post_processing_settings.light_shaft_intensity
+        };
+
+        for mut fog_volume in query_fog_volume.iter_mut() {
+            if fog_volume.light_intensity != intensity {
+                fog_volume.light_intensity = intensity;
+            }
+        }
+    }
+
     let lookat_position = if let Ok(player_transform) = query_player.single() {
         player_transform.translation()
     } else if let Ok((_, camera_transform)) = views.single() {
@@ -44,3 +90,17 @@ pub fn directional_light_system(
         let _ = (shadow_map, view_transform, view_projection, views);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_light_shafts_strongest_at_low_sun() {
+        let noon = light_shaft_intensity(std::f32::consts::FRAC_PI_2);
+        let dusk = light_shaft_intensity(0.2);
+        assert!(dusk > noon);
+        assert_eq!(light_shaft_intensity(-0.1), 0.0);
+        assert!(light_shaft_intensity(0.01) < dusk);
+    }
+}
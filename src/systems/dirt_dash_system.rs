@@ -4,11 +4,12 @@ use bevy::{
     pbr::{MeshMaterial3d, StandardMaterial},
     render::alpha::AlphaMode,
 };
-use rand::Rng;
+use rand::{rngs::StdRng, Rng};
 
 use crate::components::{
     Command, CommandMove, DirtDashEffect, DirtDashParticle, DirtDashSettings, Position,
 };
+use crate::resources::RngSeed;
 
 /// Resource holding the shared mesh and material handles for dirt particles
 #[derive(Resource)]
@@ -73,9 +74,11 @@ pub fn dirt_dash_spawn_system(
         &mut DirtDashEffect,
     )>,
     particle_count: Query<(), With<DirtDashParticle>>,
+    rng_seed: Res<RngSeed>,
+    mut rng: Local<Option<StdRng>>,
 ) {
     let delta_time = time.delta_secs();
-    let mut rng = rand::thread_rng();
+    let rng = rng.get_or_insert_with(|| rng_seed.rng("dirt_dash"));
 
     // Performance check: skip if too many particles exist
     let current_particle_count = particle_count.iter().count();
@@ -0,0 +1,31 @@
+use bevy::{
+    post_process::auto_exposure::AutoExposure,
+    prelude::{Added, Camera3d, Commands, DetectChanges, Entity, Query, Res, With},
+};
+
+use crate::resources::ExposureSettings;
+
+/// Switches the 3d cameras between auto exposure and the manual exposure
+pub fn exposure_settings_system(
+    mut commands: Commands,
+    exposure_settings: Res<ExposureSettings>,
+    query_cameras: Query<Entity, With<Camera3d>>,
+    query_added_cameras: Query<(), Added<Camera3d>>,
+) {
+    if !exposure_settings.is_changed() && query_added_cameras.is_empty() {
+        return;
+    }
+
+    for entity in query_cameras.iter() {
+        if exposure_settings.auto_exposure {
+            commands
+                .entity(entity)
+                .insert(exposure_settings.auto_exposure_component());
+        } else {
+            commands
+                .entity(entity)
+                .remove::<AutoExposure>()
+                .insert(exposure_settings.manual_exposure());
+        }
+    }
+}
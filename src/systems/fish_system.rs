@@ -13,9 +13,10 @@ use bevy::pbr::{MeshMaterial3d, StandardMaterial};
 use bevy::render::alpha::AlphaMode;
 use bevy::asset::RenderAssetUsages;
 use bevy_mesh::{Mesh, Indices, PrimitiveTopology};
-use rand::Rng;
+use rand::{rngs::StdRng, Rng};
 
 use crate::components::{Fish, FishSettings, FishWaterRef, WaterSpawnedEvent};
+use crate::resources::RngSeed;
 
 /// System to spawn fish when water is created
 pub fn spawn_fish_on_water_system(
@@ -25,7 +26,10 @@ pub fn spawn_fish_on_water_system(
     mut materials: ResMut<Assets<StandardMaterial>>,
     settings: Res<FishSettings>,
     zone_query: Query<Entity, With<crate::components::Zone>>,
+    rng_seed: Res<RngSeed>,
+    mut rng: Local<Option<StdRng>>,
 ) {
+    let rng = rng.get_or_insert_with(|| rng_seed.rng("fish"));
     let mut event_count = 0;
     for event in events.read() {
         event_count += 1;
@@ -48,6 +52,7 @@ pub fn spawn_fish_on_water_system(
             &mut meshes,
             &mut materials,
             &settings,
+            rng,
         );
     }
     
@@ -67,8 +72,8 @@ fn spawn_fish_in_water(
     meshes: &mut ResMut<Assets<Mesh>>,
     materials: &mut ResMut<Assets<StandardMaterial>>,
     settings: &Res<FishSettings>,
+    rng: &mut impl Rng,
 ) {
-    let mut rng = rand::thread_rng();
     
     log::info!(
         "[FISH] Spawning {} fish in water at {:?} with extents {:?}",
@@ -153,7 +158,7 @@ fn spawn_fish_in_water(
         let speed = rng.gen_range(min_speed..max_speed);
         
         // Random initial target
-        let target = pick_new_target(water_center, water_half_extents, settings.boundary_margin, depth);
+        let target = pick_new_target(rng, water_center, water_half_extents, settings.boundary_margin, depth);
         
         // Random rotation
         let rotation = Quat::from_rotation_y(rng.gen_range(0.0..std::f32::consts::TAU));
@@ -324,13 +329,12 @@ fn create_fish_mesh(meshes: &mut ResMut<Assets<Mesh>>) -> Handle<Mesh> {
 
 /// Pick a new random target position within water bounds
 fn pick_new_target(
+    rng: &mut impl Rng,
     water_center: Vec3,
     water_half_extents: Vec2,
     boundary_margin: f32,
     depth: f32,
 ) -> Vec3 {
-    let mut rng = rand::thread_rng();
-    
     Vec3::new(
         water_center.x + rng.gen_range(-water_half_extents.x..water_half_extents.x) * boundary_margin,
         water_center.y - depth, // Stay at same depth
@@ -343,8 +347,10 @@ pub fn update_fish_movement_system(
     time: Res<Time>,
     settings: Res<FishSettings>,
     mut query: Query<(&mut Transform, &mut Fish)>,
+    rng_seed: Res<RngSeed>,
+    mut rng: Local<Option<StdRng>>,
 ) {
-    let mut rng = rand::thread_rng();
+    let rng = rng.get_or_insert_with(|| rng_seed.rng("fish_movement"));
     
     for (mut transform, mut fish) in query.iter_mut() {
         let delta = time.delta_secs();
@@ -362,6 +368,7 @@ pub fn update_fish_movement_system(
             let new_depth = fish.depth + rng.gen_range(-0.3..0.3);
             fish.depth = new_depth.clamp(settings.min_depth, settings.max_depth);
             fish.target_position = pick_new_target(
+                rng,
                 fish.water_center,
                 fish.water_half_extents,
                 settings.boundary_margin,
@@ -423,6 +430,7 @@ pub fn update_fish_movement_system(
             || transform.translation.z >= max_z - 0.5
         {
             fish.target_position = pick_new_target(
+                rng,
                 fish.water_center,
                 fish.water_half_extents,
                 settings.boundary_margin,
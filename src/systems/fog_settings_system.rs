@@ -0,0 +1,37 @@
+use bevy::prelude::{Local, Res, ResMut};
+
+use crate::{render::ZoneLighting, resources::FogSettings};
+
+#[derive(Default)]
+pub struct FogSettingsState {
+    /// Fog values the zone lighting had before the player's settings were applied
+    zone_fog: (f32, f32, f32),
+    /// Fog values last written by this system
+    written: Option<(f32, f32, f32)>,
+}
+
+/// Applies the player's fog settings on top of the zone fog, any value the
+/// zone lighting changes to is taken as the new zone default.
+pub fn fog_settings_system(
+    fog_settings: Res<FogSettings>,
+    mut zone_lighting: ResMut<ZoneLighting>,
+    mut state: Local<FogSettingsState>,
+) {
+    let current = (
+        zone_lighting.fog_density,
+        zone_lighting.fog_min_density,
+        zone_lighting.fog_max_density,
+    );
+    if state.written != Some(current) {
+        state.zone_fog = current;
+    }
+
+    let (zone_density, zone_min_density, zone_max_density) = state.zone_fog;
+    let fog = fog_settings.apply(zone_density, zone_min_density, zone_max_density);
+    if fog != current {
+        zone_lighting.fog_density = fog.0;
+        zone_lighting.fog_min_density = fog.1;
+        zone_lighting.fog_max_density = fog.2;
+    }
+    state.written = Some(fog);
+}
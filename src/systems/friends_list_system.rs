@@ -0,0 +1,47 @@
+use bevy::prelude::{Entity, Query, Res, ResMut, With, Without};
+
+use rose_game_common::components::CharacterInfo;
+
+use crate::{
+    components::{Clan, ClientEntityName, PlayerCharacter},
+    resources::{CurrentZone, FriendStatus, FriendsList},
+};
+
+/// Update the status of friends from what the client can see, nearby
+/// characters are online in our zone and clan members report their channel
+pub fn friends_list_system(
+    mut friends_list: ResMut<FriendsList>,
+    query_characters: Query<
+        (Entity, &ClientEntityName),
+        (With<CharacterInfo>, Without<PlayerCharacter>),
+    >,
+    query_player_clan: Query<&Clan, With<PlayerCharacter>>,
+    current_zone: Option<Res<CurrentZone>>,
+) {
+    let player_clan = query_player_clan.single().ok();
+
+    for friend in friends_list.friends.iter_mut() {
+        friend.nearby_entity = query_characters
+            .iter()
+            .find(|(_, name)| name.name == friend.name)
+            .map(|(entity, _)| entity);
+
+        friend.status = if friend.nearby_entity.is_some() {
+            FriendStatus::Online {
+                zone_id: current_zone.as_ref().map(|current_zone| current_zone.id),
+            }
+        } else if let Some(member) = player_clan.and_then(|clan| {
+            clan.members
+                .iter()
+                .find(|member| member.name == friend.name)
+        }) {
+            if member.channel_id.is_some() {
+                FriendStatus::Online { zone_id: None }
+            } else {
+                FriendStatus::Offline
+            }
+        } else {
+            FriendStatus::Unknown
+        };
+    }
+}
@@ -48,7 +48,7 @@ use crate::{
         DirtDashEffect, FacingDirection, ItemDropOwner, ItemDropRemainingTime, MonsterSeparation,
         NextCommand, PartyInfo, PartyOwner, PassiveRecoveryTime, PendingDamage, PendingDamageList,
         PendingSkillEffect, PendingSkillEffectList, PendingSkillTarget, PendingSkillTargetList,
-        PersonalStore, PlayerCharacter, Position, VisibleStatusEffects,
+        PersonalStore, PlayerCharacter, Position, ServerPositionUpdate, VisibleStatusEffects,
     },
     events::{
         BankEvent, ChatBubbleEvent, ChatBubbleType, ChatboxEvent, ClientEntityEvent,
@@ -56,7 +56,8 @@ use crate::{
         QuestTriggerEvent, UseItemEvent,
     },
     resources::{
-        AppState, ClientEntityList, CurrentZone, GameConnection, GameData, WorldRates, WorldTime,
+        AppState, ClientEntityList, CurrentZone, GameConnection, GameData, NetworkMovementSettings,
+        WorldRates, WorldTime,
     },
 };
 
@@ -735,20 +736,43 @@ pub fn game_connection_system(
                 position,
             }) => {
                 if let Some(entity) = client_entity_list.get(entity_id) {
-                    commands
-                        .entity(entity)
-                        .insert(NextCommand::with_move(position, None, None));
+                    commands.queue(move |world: &mut World| {
+                        let prediction_enabled =
+                            world.resource::<NetworkMovementSettings>().enabled;
+                        let Ok(mut entity_mut) = world.get_entity_mut(entity) else {
+                            return;
+                        };
+
+                        if prediction_enabled {
+                            entity_mut.insert(ServerPositionUpdate { position });
+                        } else {
+                            entity_mut.insert(NextCommand::with_move(position, None, None));
+                        }
+                    });
                 }
             }
             Ok(ServerMessage::StopMoveEntity {
                 entity_id,
-                x: _,
-                y: _,
-                z: _,
+                x,
+                y,
+                z,
             }) => {
-                // TODO: Lerp to XYZ ?
                 if let Some(entity) = client_entity_list.get(entity_id) {
                     commands.entity(entity).insert(NextCommand::with_stop());
+
+                    let is_player = client_entity_list.player_entity == Some(entity);
+                    commands.queue(move |world: &mut World| {
+                        // The local player stops where it predicted, only remote entities lerp to the stop position
+                        if is_player || !world.resource::<NetworkMovementSettings>().enabled {
+                            return;
+                        }
+
+                        if let Ok(mut entity_mut) = world.get_entity_mut(entity) {
+                            entity_mut.insert(ServerPositionUpdate {
+                                position: Vec3::new(x, y, z as f32),
+                            });
+                        }
+                    });
                 }
             }
             Ok(ServerMessage::AttackEntity {
@@ -1491,6 +1515,25 @@ pub fn game_connection_system(
                     }
 
                     commands.queue(move |world: &mut World| {
+                        // Equipment looted in a party is rolled for by the party
+                        if matches!(item, Item::Equipment(_))
+                            && world
+                                .get_entity(player_entity)
+                                .is_ok_and(|player| player.contains::<PartyInfo>())
+                        {
+                            if let Some(looter) = world
+                                .get::<CharacterInfo>(player_entity)
+                                .map(|character_info| character_info.name.clone())
+                            {
+                                world.resource_mut::<Messages<PartyEvent>>().write(
+                                    PartyEvent::LootRollStart {
+                                        looter,
+                                        item: item.clone(),
+                                    },
+                                );
+                            }
+                        }
+
                         let mut player = world.entity_mut(player_entity);
                         if let Some(mut inventory) = player.get_mut::<Inventory>() {
                             if let Some(inventory_slot) = inventory.get_item_slot_mut(item_slot) {
@@ -2354,18 +2397,26 @@ pub fn game_connection_system(
                     .get_base_item(item.get_item_reference())
                     .map(|item_data| item_data.name.as_str().to_owned());
 
+                // The player's own pickups start their roll from PickupDropItem
+                let is_player =
+                    member_entity.is_some() && member_entity == client_entity_list.player_entity;
+
                 if let (Some(member_entity), Some(item_name)) = (member_entity, item_name) {
                     commands.queue(move |world: &mut World| {
                         if let Ok(member) = world.get_entity(member_entity) {
                             if let Some(member_entity_name) = member.get::<ClientEntityName>() {
-                                let chat_message = format!(
-                                    "{} has earned {}.",
-                                    member_entity_name.as_str(),
-                                    item_name
-                                );
+                                let looter = member_entity_name.to_string();
+                                let chat_message =
+                                    format!("{} has earned {}.", looter, item_name);
                                 let _ = world
                                     .resource_mut::<Messages<ChatboxEvent>>()
                                     .write(ChatboxEvent::System(chat_message));
+
+                                if matches!(item, Item::Equipment(_)) && !is_player {
+                                    world
+                                        .resource_mut::<Messages<PartyEvent>>()
+                                        .write(PartyEvent::LootRollStart { looter, item });
+                                }
                             }
                         }
                     });
@@ -2750,6 +2801,13 @@ pub fn game_connection_system(
                                 member.channel_id = Some(channel_id);
                             }
                         }
+
+                        world
+                            .resource_mut::<Messages<ChatboxEvent>>()
+                            .write(ChatboxEvent::ClanNotice(format!(
+                                "{} has come online (channel {}).",
+                                name, channel_id
+                            )));
                     });
                 }
             }
@@ -2762,6 +2820,10 @@ pub fn game_connection_system(
                                 member.channel_id = None;
                             }
                         }
+
+                        world
+                            .resource_mut::<Messages<ChatboxEvent>>()
+                            .write(ChatboxEvent::ClanNotice(format!("{} has gone offline.", name)));
                     });
                 }
             }
@@ -2877,12 +2939,17 @@ pub fn game_connection_system(
 
     if let Err(error) = result {
         log::warn!("Game server connection error: {}", error);
-        message_box_events.write(MessageBoxEvent::Show {
-            message: format!("Connection to game server lost: {}", error),
-            modal: true,
-            ok: None,
-            cancel: None,
-        });
+        game_connection_events.write(GameConnectionEvent::Disconnected(error.to_string()));
+
+        // In game the reconnect system reports the connection status itself
+        if !matches!(app_state_current.get(), AppState::Game) {
+            message_box_events.write(MessageBoxEvent::Show {
+                message: format!("Connection to game server lost: {}", error),
+                modal: true,
+                ok: None,
+                cancel: None,
+            });
+        }
         commands.remove_resource::<GameConnection>();
     }
 }
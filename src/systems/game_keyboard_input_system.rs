@@ -2,7 +2,7 @@ use bevy::{
     input::ButtonInput,
     math::{Vec2, Vec3},
     prelude::{
-        Camera3d, KeyCode, Local, MessageWriter, Query, Res, State, Time, Transform, With,
+        Camera3d, KeyCode, Local, MessageWriter, Query, Res, State, Time, Transform, With, Without,
     },
     window::{CursorGrabMode, CursorOptions, PrimaryWindow},
 };
@@ -10,14 +10,18 @@ use bevy_egui::EguiContexts;
 use rose_game_common::components::MoveSpeed;
 
 use crate::{
-    components::{BoatState, FlightState, PlayerCharacter, Position},
+    components::{BoatState, Dead, FlightState, PlayerCharacter, Position},
     events::PlayerCommandEvent,
     resources::AppState,
+    systems::FreeCamera,
 };
 
 const WASD_MOVE_COMMAND_INTERVAL_SECS: f32 = 0.10;
 const WASD_MOVE_COMMAND_LEAD_TIME_SECS: f32 = 0.25;
 
+/// Sits down to rest, or stands back up
+const SIT_TOGGLE_KEY: KeyCode = KeyCode::KeyX;
+
 /// Keyboard movement input (W/A/S/D) for player character movement.
 ///
 /// This sends periodic `PlayerCommandEvent::Move` commands while movement keys are held,
@@ -26,8 +30,8 @@ pub fn game_keyboard_input_system(
     app_state: Res<State<AppState>>,
     keyboard_input: Res<ButtonInput<KeyCode>>,
     query_window: Query<&CursorOptions, With<PrimaryWindow>>,
-    query_camera: Query<&Transform, With<Camera3d>>,
-    query_player: Query<(&Position, &MoveSpeed, Option<&FlightState>, Option<&BoatState>), With<PlayerCharacter>>,
+    query_camera: Query<&Transform, (With<Camera3d>, Without<FreeCamera>)>,
+    query_player: Query<(&Position, &MoveSpeed, Option<&FlightState>, Option<&BoatState>), (With<PlayerCharacter>, Without<Dead>)>,
     mut egui_ctx: EguiContexts,
     time: Res<Time>,
     mut move_command_cooldown: Local<f32>,
@@ -51,6 +55,7 @@ pub fn game_keyboard_input_system(
         return;
     }
 
+    // No movement while the camera is detached from the player as a free camera
     let Ok(camera_transform) = query_camera.single() else {
         return;
     };
@@ -66,6 +71,10 @@ pub fn game_keyboard_input_system(
         return;
     }
 
+    if keyboard_input.just_pressed(SIT_TOGGLE_KEY) {
+        player_command_events.write(PlayerCommandEvent::SitToggle);
+    }
+
     let camera_rotation = camera_transform.rotation;
 
     // Build camera-relative movement vectors on the ground plane.
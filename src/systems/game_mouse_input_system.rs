@@ -1,39 +1,67 @@
+use std::time::{Duration, Instant};
+
 use bevy::{
+    color::Color,
     input::ButtonInput,
-    math::Vec3,
+    math::{primitives::Cylinder, Isometry3d, Quat, Ray3d, Vec2, Vec3},
     prelude::{
-        BevyError, Camera, Camera3d, Entity, MessageWriter, GlobalTransform, Local, MouseButton, Query, Res, ResMut,
-        State, With,
+        BevyError, Camera, Camera3d, Entity, MessageWriter, GlobalTransform, Gizmos, Local, MouseButton, Query, Res,
+        ResMut, State, With, Without,
     },
     window::{CursorGrabMode, CursorOptions, PrimaryWindow, Window},
 };
 use bevy_egui::EguiContexts;
 use bevy_rapier3d::{
+    geometry::ShapeCastOptions,
     plugin::context::systemparams::ReadRapierContext,
-    prelude::{CollisionGroups, QueryFilter},
+    prelude::{Collider, CollisionGroups, QueryFilter, RapierContext},
+    rapier::prelude::Shape,
 };
 
 use rose_game_common::components::{ItemDrop, Team};
 
 use crate::{
     components::{
-        BoatState, ColliderParent, ClientEntity, ClientEntityType, FlightState, PlayerCharacter,
-        Position, ZoneObject,
-        COLLISION_FILTER_CLICKABLE, COLLISION_GROUP_PHYSICS_TOY, COLLISION_GROUP_PLAYER,
+        BoatState, ColliderParent, ClientEntity, ClientEntityType, Dead, FlightState, ModelHeight,
+        PlayerCharacter, Position, ZoneObject,
     },
     events::{MoveDestinationEffectEvent, PlayerCommandEvent},
-    resources::{AppState, SelectedTarget, UiCursorType},
+    resources::{
+        AppState, DebugRenderConfig, MousePickDebug, MousePickSettings, PickPriority,
+        SelectedTarget, UiCursorType, UiRequestedCursor,
+    },
+    systems::FreeCamera,
 };
 
 pub type PlayerQuery<'w> = (Entity, &'w Team, Option<&'w FlightState>, Option<&'w BoatState>);
 
+/// Furthest distance the cursor ray can pick anything at
+const MAX_PICK_DISTANCE: f32 = 10000000.0;
+
+/// How often the cursor pick is repeated while neither the cursor nor the
+/// camera moves, so targets walking under a still cursor are still noticed
+const STILL_CURSOR_PICK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Radius of the outline drawn around the hovered entity, in metres
+const HOVER_HIGHLIGHT_RADIUS: f32 = 0.5;
+
+/// Last cursor pick, reused while the cursor and camera stay still
+#[derive(Default)]
+pub struct CursorPickCache {
+    cursor_position: Vec2,
+    camera_transform: GlobalTransform,
+    last_pick_at: Option<Instant>,
+    surface_hit: Option<Vec3>,
+    pick: Option<(Entity, f32)>,
+}
+
 /// Game mouse input system - handles mouse clicks for movement, attacking, and interaction
 /// This system has been refactored to reduce the number of parameters to 10
 pub fn game_mouse_input_system(
     app_state: Res<State<AppState>>,
     mouse_button_input: Res<ButtonInput<MouseButton>>,
     query_window: Query<(&Window, &CursorOptions), With<PrimaryWindow>>,
-    query_camera: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    query_camera: Query<(&Camera, &GlobalTransform), (With<Camera3d>, Without<FreeCamera>)>,
     rapier_context: ReadRapierContext,
     mut egui_ctx: EguiContexts,
     query_hit_entity: Query<(
@@ -43,20 +71,28 @@ pub fn game_mouse_input_system(
         Option<&ZoneObject>,
         Option<&ClientEntity>,
     )>,
-    query_player: Query<PlayerQuery, With<PlayerCharacter>>,
+    query_player: Query<PlayerQuery, (With<PlayerCharacter>, Without<Dead>)>,
     query_collider_parent: Query<&ColliderParent>,
     mut player_command_events: MessageWriter<PlayerCommandEvent>,
     mut selected_target: ResMut<SelectedTarget>,
+    mouse_pick_settings: Res<MousePickSettings>,
+    mut mouse_pick_debug: ResMut<MousePickDebug>,
+    mut ui_requested_cursor: ResMut<UiRequestedCursor>,
+    mut pick_cache: Local<CursorPickCache>,
 ) -> Result<(), BevyError> {
     let Ok(rapier_context) = rapier_context.single() else {
         return Ok(());
     };
+    *mouse_pick_debug = MousePickDebug::default();
     
     // Check if we're in the game state
     if *app_state.get() != AppState::Game {
         return Ok(());
     }
     selected_target.hover = None;
+    selected_target.hover_position = None;
+    selected_target.cursor_type = UiCursorType::Default;
+    ui_requested_cursor.world_cursor = UiCursorType::Default;
 
     let Ok((window, cursor_options)) = query_window.single() else {
         return Ok(());
@@ -88,25 +124,42 @@ pub fn game_mouse_input_system(
     let is_sailing = player_boat_state.map_or(false, |boat| boat.active);
     let movement_locked = is_flying || is_sailing;
     
+    // No movement or targeting while the camera is detached from the player as a free camera
     let Ok((camera, camera_transform)) = query_camera.single() else {
         return Ok(());
     };
 
     if let Ok(ray) = camera.viewport_to_world(camera_transform, cursor_position) {
-        if let Some((collider_entity, distance)) = rapier_context.cast_ray(
-            ray.origin,
-            *ray.direction,
-            10000000.0,
-            false,
-            QueryFilter::new().groups(CollisionGroups::new(
-                COLLISION_FILTER_CLICKABLE,
-                !COLLISION_GROUP_PLAYER & !COLLISION_GROUP_PHYSICS_TOY,
-            )),
-        ) {
+        let cursor_still = pick_cache.cursor_position == cursor_position
+            && pick_cache.camera_transform == *camera_transform
+            && pick_cache
+                .last_pick_at
+                .is_some_and(|last_pick_at| last_pick_at.elapsed() < STILL_CURSOR_PICK_INTERVAL);
+
+        let pick = if cursor_still && !mouse_button_input.just_pressed(MouseButton::Left) {
+            pick_cache.pick
+        } else {
+            let (surface_hit, pick) =
+                pick_under_cursor(&rapier_context, &mouse_pick_settings, ray);
+            *pick_cache = CursorPickCache {
+                cursor_position,
+                camera_transform: *camera_transform,
+                last_pick_at: Some(Instant::now()),
+                surface_hit: surface_hit.map(|(_, distance)| ray.get_point(distance)),
+                pick,
+            };
+            pick
+        };
+        mouse_pick_debug.ray_origin = ray.origin;
+        mouse_pick_debug.surface_hit = pick_cache.surface_hit;
+        selected_target.hover_position = pick_cache.surface_hit;
+
+        if let Some((collider_entity, distance)) = pick {
             let hit_position = ray.get_point(distance);
             let hit_entity = query_collider_parent
                 .get(collider_entity)
                 .map_or(collider_entity, |collider_parent| collider_parent.entity);
+            mouse_pick_debug.picked = Some((hit_entity, hit_position));
 
             if let Ok((
                 hit_team,
@@ -141,6 +194,13 @@ pub fn game_mouse_input_system(
                     }
                 }
 
+                if matches!(
+                    hit_zone_object,
+                    Some(ZoneObject::EventObject(_) | ZoneObject::EventObjectPart(_))
+                ) {
+                    selected_target.cursor_type = UiCursorType::Wheel;
+                }
+
                 if hit_zone_object.is_some() {
                     // Only allow terrain click-to-move when NOT flying
                     if !movement_locked && mouse_button_input.just_pressed(MouseButton::Left) {
@@ -197,5 +257,107 @@ pub fn game_mouse_input_system(
             }
         }
     }
+    ui_requested_cursor.world_cursor = selected_target.cursor_type;
     Ok(())
 }
+
+/// Casts the cursor ray against the pick contexts, returning the first surface
+/// hit and the collider picked
+fn pick_under_cursor(
+    rapier_context: &RapierContext<'_>,
+    mouse_pick_settings: &MousePickSettings,
+    ray: Ray3d,
+) -> (Option<(Entity, f32)>, Option<(Entity, f32)>) {
+    let target_groups = mouse_pick_settings.target.collision_groups();
+    let move_groups = mouse_pick_settings.move_destination.collision_groups();
+    let surface_hit = rapier_context.cast_ray(
+        ray.origin,
+        *ray.direction,
+        MAX_PICK_DISTANCE,
+        false,
+        QueryFilter::new().groups(CollisionGroups::new(
+            target_groups.memberships | move_groups.memberships,
+            target_groups.filters | move_groups.filters,
+        )),
+    );
+
+    // Sweep a small ball along the ray so interactables close to the cursor
+    // win over the terrain or object directly under it
+    let mut pick = surface_hit;
+    if mouse_pick_settings.priority == PickPriority::Interactables
+        && mouse_pick_settings.pick_radius > 0.0
+    {
+        let pick_ball = Collider::ball(mouse_pick_settings.pick_radius);
+        if let Some((collider_entity, hit)) = rapier_context.cast_shape(
+            ray.origin,
+            Quat::IDENTITY,
+            *ray.direction,
+            <&dyn Shape>::from(&pick_ball),
+            ShapeCastOptions {
+                max_time_of_impact: surface_hit.map_or(MAX_PICK_DISTANCE, |(_, distance)| {
+                    distance + mouse_pick_settings.priority_distance
+                }),
+                target_distance: 0.0,
+                compute_impact_geometry_on_penetration: false,
+                stop_at_penetration: true,
+            },
+            QueryFilter::new().groups(target_groups),
+        ) {
+            pick = Some((collider_entity, hit.time_of_impact));
+        }
+    }
+
+    (surface_hit, pick)
+}
+
+/// Outlines the entity under the cursor, tinted by what clicking it will do
+pub fn hover_highlight_system(
+    mut gizmos: Gizmos,
+    selected_target: Res<SelectedTarget>,
+    query_hover: Query<(&GlobalTransform, Option<&ModelHeight>)>,
+) {
+    let Some((transform, model_height)) = selected_target
+        .hover
+        .and_then(|entity| query_hover.get(entity).ok())
+    else {
+        return;
+    };
+
+    let color = match selected_target.cursor_type {
+        UiCursorType::Attack => Color::srgb(1.0, 0.25, 0.2),
+        UiCursorType::Npc => Color::srgb(1.0, 0.85, 0.3),
+        UiCursorType::PickupItem => Color::srgb(0.4, 1.0, 0.4),
+        _ => Color::srgb(0.6, 0.8, 1.0),
+    };
+    let height = model_height.map_or(0.5, |model_height| model_height.height);
+    gizmos.primitive_3d(
+        &Cylinder::new(HOVER_HIGHLIGHT_RADIUS, height),
+        Isometry3d::from_translation(transform.translation() + Vec3::Y * height * 0.5),
+        color,
+    );
+}
+
+/// Draws the cursor ray, the first surface it hits and the picked entity
+pub fn mouse_pick_debug_system(
+    mut gizmos: Gizmos,
+    debug_render_config: Res<DebugRenderConfig>,
+    mouse_pick_settings: Res<MousePickSettings>,
+    mouse_pick_debug: Res<MousePickDebug>,
+) {
+    if !debug_render_config.mouse_pick {
+        return;
+    }
+
+    if let Some(surface_hit) = mouse_pick_debug.surface_hit {
+        gizmos.line(mouse_pick_debug.ray_origin, surface_hit, Color::WHITE);
+        gizmos.sphere(Isometry3d::from_translation(surface_hit), 0.1, Color::srgb(1.0, 1.0, 0.0));
+    }
+
+    if let Some((_, picked_position)) = mouse_pick_debug.picked {
+        gizmos.sphere(
+            Isometry3d::from_translation(picked_position),
+            mouse_pick_settings.pick_radius.max(0.1),
+            Color::srgb(0.0, 1.0, 0.0),
+        );
+    }
+}
@@ -9,7 +9,7 @@ use crate::{
     components::PlayerCharacter,
     events::ZoneEvent,
     resources::GameConnection,
-    systems::{FreeCamera, OrbitCamera},
+    systems::{DeathSpectatorCamera, FreeCamera, OrbitCamera},
 };
 
 pub fn game_state_enter_system(
@@ -39,6 +39,7 @@ pub fn game_state_enter_system(
             .entity(entity)
             .remove::<FreeCamera>()
             .remove::<CameraAnimation>()
+            .remove::<DeathSpectatorCamera>()
             .insert(OrbitCamera::new(
                 player_entity,
                 Vec3::new(0.0, 1.7, 0.0),
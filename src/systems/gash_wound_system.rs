@@ -6,13 +6,14 @@
 use bevy::prelude::*;
 use bevy::math::primitives::Rectangle;
 use bevy_mesh::skinning::SkinnedMesh;
+use rand::{rngs::StdRng, Rng};
 
 use rose_game_common::components::{AbilityValues, HealthPoints};
 
 use crate::{
     components::{Dead, GashWounds, ModelHeight, WoundVisual},
     events::BloodEffectEvent,
-    resources::{BloodDecalAtlas, BloodEffectConfig, BloodEffectDiagnostics},
+    resources::{BloodDecalAtlas, BloodEffectConfig, BloodEffectDiagnostics, RngSeed},
 };
 
 fn normalize_or(value: Vec3, fallback: Vec3) -> Vec3 {
@@ -29,10 +30,10 @@ fn tangent_from_normal(normal: Vec3) -> Vec3 {
     normalize_or(normal.cross(up), Vec3::X)
 }
 
-fn random_local_wound_pose(_model_height: f32) -> (Vec3, Vec3) {
-    let y = -0.04 + rand::random::<f32>() * 0.18;
-    let angle = rand::random::<f32>() * std::f32::consts::TAU;
-    let radial = 0.05 + rand::random::<f32>() * 0.16;
+fn random_local_wound_pose<R: Rng>(_model_height: f32, rng: &mut R) -> (Vec3, Vec3) {
+    let y = -0.04 + rng.gen::<f32>() * 0.18;
+    let angle = rng.gen::<f32>() * std::f32::consts::TAU;
+    let radial = 0.05 + rng.gen::<f32>() * 0.16;
     let x = radial * angle.cos();
     let z = radial * angle.sin();
     let normal = normalize_or(Vec3::new(x, 0.05, z), Vec3::Z);
@@ -57,10 +58,13 @@ pub fn wound_visibility_system(
     >,
     mut blood_events: MessageWriter<BloodEffectEvent>,
     config: Res<BloodEffectConfig>,
+    rng_seed: Res<RngSeed>,
+    mut rng: Local<Option<StdRng>>,
 ) {
     if !config.enable_blood || !config.show_wounds {
         return;
     }
+    let rng = rng.get_or_insert_with(|| rng_seed.rng("wounds"));
 
     for (entity, hp, ability_values, wounds, model_height) in query.iter_mut() {
         let max_hp = ability_values.get_max_health();
@@ -80,7 +84,7 @@ pub fn wound_visibility_system(
                     let to_add = target_seed_count.saturating_sub(wounds.wound_count);
                     let model_h = model_height.map_or(1.8, |h| h.height);
                     for _ in 0..to_add {
-                        let (wound_pos, wound_normal) = random_local_wound_pose(model_h);
+                        let (wound_pos, wound_normal) = random_local_wound_pose(model_h, rng);
                         blood_events.write(BloodEffectEvent::show_wound(
                             entity,
                             wound_pos,
@@ -96,7 +100,7 @@ pub fn wound_visibility_system(
             let model_h = model_height.map_or(1.8, |h| h.height);
             let target_seed_count = config.max_wounds_per_entity.min(3).max(1);
             for _ in 0..target_seed_count {
-                let (wound_pos, wound_normal) = random_local_wound_pose(model_h);
+                let (wound_pos, wound_normal) = random_local_wound_pose(model_h, rng);
                 blood_events.write(BloodEffectEvent::show_wound(
                     entity,
                     wound_pos,
@@ -124,11 +128,14 @@ pub fn wound_spawn_system(
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     mut diagnostics: ResMut<BloodEffectDiagnostics>,
+    rng_seed: Res<RngSeed>,
+    mut rng: Local<Option<StdRng>>,
 ) {
     if !config.enable_blood || !config.show_wounds {
         blood_events.clear();
         return;
     }
+    let rng = rng.get_or_insert_with(|| rng_seed.rng("wound_spawn"));
 
     for event in blood_events.read() {
         match event {
@@ -146,13 +153,13 @@ pub fn wound_spawn_system(
                 }
 
                 let wound_size = (config.wound_min_size
-                    + rand::random::<f32>() * (config.wound_max_size - config.wound_min_size).max(0.001))
+                    + rng.gen::<f32>() * (config.wound_max_size - config.wound_min_size).max(0.001))
                     .max(0.16);
 
                 let wound_texture = if atlas.wound_textures.is_empty() {
                     None
                 } else {
-                    let idx = rand::random::<usize>() % atlas.wound_textures.len();
+                    let idx = rng.gen::<usize>() % atlas.wound_textures.len();
                     atlas.wound_textures.get(idx).cloned()
                 };
 
@@ -169,7 +176,7 @@ pub fn wound_spawn_system(
                         let max_index_exclusive = ((len * 9) / 10).max(min_index + 1).min(len);
                         let range_len = max_index_exclusive.saturating_sub(min_index);
                         let chosen_index = if range_len > 0 {
-                            min_index + (rand::random::<usize>() % range_len)
+                            min_index + (rng.gen::<usize>() % range_len)
                         } else {
                             len / 2
                         };
@@ -179,7 +186,7 @@ pub fn wound_spawn_system(
                     }
                 } else if let Ok(model_height) = query_model_height.get(*entity) {
                     let h = model_height.height.max(0.8);
-                    local_wound_position.y = h * (0.35 + rand::random::<f32>() * 0.35);
+                    local_wound_position.y = h * (0.35 + rng.gen::<f32>() * 0.35);
                 }
 
                 let mesh = meshes.add(Mesh::from(Rectangle::new(1.0, 1.0)));
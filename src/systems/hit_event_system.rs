@@ -1,10 +1,11 @@
 use bevy::{
     ecs::query::QueryData,
     prelude::{
-        Commands, Entity, GlobalTransform, MessageReader, MessageWriter, Query, Res, ResMut,
-        Vec3,
+        Commands, Entity, GlobalTransform, Local, MessageReader, MessageWriter, Query, Res,
+        ResMut, Transform, Vec3,
     },
 };
+use rand::{rngs::StdRng, Rng};
 
 use rose_game_common::{
     components::{AbilityValues, HealthPoints, ManaPoints, MoveSpeed, StatusEffects},
@@ -13,12 +14,15 @@ use rose_game_common::{
 
 use crate::{
     components::{
-        ClientEntity, ClientEntityType, Dead, DeathBloodHandled, ModelHeight, NextCommand,
-        PendingDamageList,
+        ClientEntity, ClientEntityType, Dead, DeathBloodHandled, HitFlash, ModelHeight,
+        NextCommand, PendingDamageList,
         PendingSkillEffectList, PendingSkillTargetList,
     },
     events::{BloodEffectEvent, HitEvent, SpawnEffectData, SpawnEffectEvent},
-    resources::{BloodEffectConfig, ClientEntityList, DamageDigitsSpawner, GameData},
+    resources::{
+        BloodEffectConfig, CameraShake, ClientEntityList, DamageDigitsSpawner, GameData,
+        HitFeedbackSettings, RngSeed,
+    },
 };
 
 fn normalize_or(value: Vec3, fallback: Vec3) -> Vec3 {
@@ -30,10 +34,10 @@ fn normalize_or(value: Vec3, fallback: Vec3) -> Vec3 {
     }
 }
 
-fn random_local_wound_pose(_model_height: f32) -> (Vec3, Vec3) {
-    let y = -0.04 + rand::random::<f32>() * 0.18;
-    let angle = rand::random::<f32>() * std::f32::consts::TAU;
-    let radial = 0.05 + rand::random::<f32>() * 0.16;
+fn random_local_wound_pose<R: Rng>(_model_height: f32, rng: &mut R) -> (Vec3, Vec3) {
+    let y = -0.04 + rng.gen::<f32>() * 0.18;
+    let angle = rng.gen::<f32>() * std::f32::consts::TAU;
+    let radial = 0.05 + rng.gen::<f32>() * 0.16;
     let x = radial * angle.cos();
     let z = radial * angle.sin();
     let normal = normalize_or(Vec3::new(x, 0.05, z), Vec3::Z);
@@ -108,7 +112,12 @@ pub fn hit_event_system(
     damage_digits_spawner: Res<DamageDigitsSpawner>,
     game_data: Res<GameData>,
     blood_config: Res<BloodEffectConfig>,
+    hit_feedback_settings: Res<HitFeedbackSettings>,
+    mut camera_shake: ResMut<CameraShake>,
+    rng_seed: Res<RngSeed>,
+    mut rng: Local<Option<StdRng>>,
 ) {
+    let rng = rng.get_or_insert_with(|| rng_seed.rng("hit_wounds"));
     for event in hit_events.read() {
         let defender = query_defender.get_mut(event.defender).ok();
         if defender.is_none() {
@@ -156,7 +165,7 @@ pub fn hit_event_system(
                 );
             }
 
-            if has_damage && damage.amount > 0 {
+            if has_damage && damage.amount > 0 && blood_config.enable_blood {
                 let defender_pos = defender.global_transform.translation();
                 let impact_direction = query_transform
                     .get(event.attacker)
@@ -183,11 +192,11 @@ pub fn hit_event_system(
                     ));
                 }
 
-                if blood_config.enable_blood && blood_config.show_wounds {
+                if blood_config.show_wounds {
                     let model_h = defender.model_height.map_or(1.8, |h| h.height);
                     let wound_events = if is_killed { 3 } else { 2 };
                     for _ in 0..wound_events {
-                        let (wound_position, wound_normal) = random_local_wound_pose(model_h);
+                        let (wound_position, wound_normal) = random_local_wound_pose(model_h, rng);
                         blood_effect_events.write(BloodEffectEvent::show_wound(
                             defender.entity,
                             wound_position,
@@ -198,24 +207,58 @@ pub fn hit_event_system(
             }
         }
 
+        if hit_feedback_settings.enabled && has_damage && damage.amount > 0 {
+            if hit_feedback_settings.hit_flash {
+                commands
+                    .entity(defender.entity)
+                    .insert(HitFlash::new(damage.is_critical));
+            }
+
+            if client_entity_list.player_entity == Some(defender.entity) {
+                camera_shake.add_trauma(hit_feedback_settings.shake_trauma(
+                    damage.amount,
+                    defender.ability_values.get_max_health(),
+                ));
+            }
+        }
+
         if let Some(effect_data) = event
             .effect_id
             .and_then(|id| game_data.effect_database.get_effect(id))
         {
+            // Spawn at the point of impact scaled by the damage, or at the feet as before
+            let impact_transform =
+                (hit_feedback_settings.enabled && hit_feedback_settings.impact_effects).then(|| {
+                    let model_height = defender.model_height.map_or(1.8, |h| h.height);
+                    let scale = hit_feedback_settings.impact_scale(
+                        damage.amount,
+                        defender.ability_values.get_max_health(),
+                        damage.is_critical,
+                    );
+                    Transform::from_translation(
+                        defender.global_transform.translation() + Vec3::Y * model_height * 0.5,
+                    )
+                    .with_scale(Vec3::splat(scale))
+                });
+            let hit_effect_event = |effect_file_id| match impact_transform {
+                Some(transform) => SpawnEffectEvent::WithTransform(
+                    transform,
+                    SpawnEffectData::with_file_id(effect_file_id),
+                ),
+                None => SpawnEffectEvent::AtEntity(
+                    defender.entity,
+                    SpawnEffectData::with_file_id(effect_file_id),
+                ),
+            };
+
             if damage.is_critical {
                 if let Some(effect_file_id) = effect_data.hit_effect_critical {
-                    spawn_effect_events.write(SpawnEffectEvent::AtEntity(
-                        defender.entity,
-                        SpawnEffectData::with_file_id(effect_file_id),
-                    ));
+                    spawn_effect_events.write(hit_effect_event(effect_file_id));
                 }
             }
 
             if let Some(effect_file_id) = effect_data.hit_effect_normal {
-                spawn_effect_events.write(SpawnEffectEvent::AtEntity(
-                    defender.entity,
-                    SpawnEffectData::with_file_id(effect_file_id),
-                ));
+                spawn_effect_events.write(hit_effect_event(effect_file_id));
             }
         }
 
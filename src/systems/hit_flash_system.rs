@@ -0,0 +1,59 @@
+use bevy::{
+    color::Color,
+    math::{primitives::Cylinder, Isometry3d, Vec3},
+    prelude::{Commands, Entity, Gizmos, GlobalTransform, Query, Res, Time},
+};
+
+use crate::{
+    animation::SkeletalAnimation,
+    components::{HitFlash, ModelHeight},
+    resources::HIT_FLASH_SECONDS,
+};
+
+const HIT_FLASH_RADIUS: f32 = 0.55;
+
+/// Flashes entities which were just hit and holds their animation until the
+/// flash has faded, giving each hit a moment of weight.
+pub fn hit_flash_system(
+    mut commands: Commands,
+    mut gizmos: Gizmos,
+    time: Res<Time>,
+    mut query: Query<(
+        Entity,
+        &mut HitFlash,
+        &GlobalTransform,
+        Option<&ModelHeight>,
+        Option<&mut SkeletalAnimation>,
+    )>,
+) {
+    for (entity, mut hit_flash, transform, model_height, skeletal_animation) in query.iter_mut() {
+        hit_flash.remaining -= time.delta_secs();
+
+        if hit_flash.remaining <= 0.0 {
+            if let Some(mut skeletal_animation) = skeletal_animation {
+                skeletal_animation.set_paused(false);
+            }
+            commands.entity(entity).remove::<HitFlash>();
+            continue;
+        }
+
+        if let Some(mut skeletal_animation) = skeletal_animation {
+            if !skeletal_animation.paused() {
+                skeletal_animation.set_paused(true);
+            }
+        }
+
+        let alpha = (hit_flash.remaining / HIT_FLASH_SECONDS).clamp(0.0, 1.0) * 0.8;
+        let color = if hit_flash.is_critical {
+            Color::srgba(1.0, 0.85, 0.3, alpha)
+        } else {
+            Color::srgba(1.0, 1.0, 1.0, alpha)
+        };
+        let height = model_height.map_or(1.8, |model_height| model_height.height);
+        gizmos.primitive_3d(
+            &Cylinder::new(HIT_FLASH_RADIUS, height),
+            Isometry3d::from_translation(transform.translation() + Vec3::Y * height * 0.5),
+            color,
+        );
+    }
+}
@@ -0,0 +1,29 @@
+use bevy::{
+    input::{
+        mouse::{MouseMotion, MouseWheel},
+        ButtonInput,
+    },
+    prelude::{KeyCode, MessageReader, MouseButton, Res, ResMut, Time},
+};
+
+use crate::resources::InputActivity;
+
+pub fn input_activity_system(
+    mut input_activity: ResMut<InputActivity>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    mut mouse_motion_events: MessageReader<MouseMotion>,
+    mut mouse_wheel_events: MessageReader<MouseWheel>,
+    time: Res<Time>,
+) {
+    let moved = mouse_motion_events.read().count() > 0;
+    let scrolled = mouse_wheel_events.read().count() > 0;
+
+    if moved
+        || scrolled
+        || keyboard_input.get_pressed().next().is_some()
+        || mouse_buttons.get_pressed().next().is_some()
+    {
+        input_activity.last_input_time = time.elapsed_secs();
+    }
+}
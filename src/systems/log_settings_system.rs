@@ -0,0 +1,17 @@
+use bevy::prelude::{DetectChanges, Res};
+
+use crate::{logging::set_log_filter, resources::LogSettings};
+
+/// Applies changed log settings to the running session's log filter, the
+/// initial filter was already set when logging was initialized
+pub fn log_settings_system(log_settings: Res<LogSettings>) {
+    if !log_settings.is_changed() || log_settings.is_added() {
+        return;
+    }
+
+    let filter = log_settings.filter();
+    match set_log_filter(&filter) {
+        Ok(()) => log::info!("[Logging] Log filter set to {}", filter),
+        Err(error) => log::warn!("[Logging] Failed to set log filter {}: {}", filter, error),
+    }
+}
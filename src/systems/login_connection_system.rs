@@ -7,9 +7,10 @@ use rose_game_common::{
 use rose_network_common::ConnectionError;
 
 use crate::{
-    events::{MessageBoxEvent, NetworkEvent},
+    events::{LoginConnectionEvent, MessageBoxEvent, NetworkEvent},
     resources::{
-        Account, LoginConnection, ServerList, ServerListGameServer, ServerListWorldServer,
+        Account, LoginConnection, ReconnectState, ServerConfiguration, ServerList,
+        ServerListGameServer, ServerListWorldServer,
     },
 };
 
@@ -18,14 +19,18 @@ pub fn login_connection_system(
     account: Option<Res<Account>>,
     login_connection: Option<Res<LoginConnection>>,
     mut server_list: Option<ResMut<ServerList>>,
+    server_configuration: Res<ServerConfiguration>,
+    reconnect_state: Option<Res<ReconnectState>>,
     mut network_events: MessageWriter<NetworkEvent>,
     mut message_box_events: MessageWriter<MessageBoxEvent>,
+    mut login_connection_events: MessageWriter<LoginConnectionEvent>,
 ) {
     if login_connection.is_none() {
         return;
     }
 
     let login_connection = login_connection.unwrap();
+    let mut login_rejected = false;
     let result: Result<(), anyhow::Error> = loop {
         match login_connection.server_message_rx.try_recv() {
             Ok(ServerMessage::ConnectionRequestSuccess {
@@ -62,8 +67,10 @@ pub fn login_connection_system(
                     });
                 }
                 commands.insert_resource(ServerList { world_servers });
+                login_connection_events.write(LoginConnectionEvent::LoggedIn);
             }
             Ok(ServerMessage::LoginError { error }) => {
+                login_rejected = true;
                 break Err(error.into());
             }
             Ok(ServerMessage::ChannelList {
@@ -120,12 +127,24 @@ pub fn login_connection_system(
 
     if let Err(error) = result {
         log::warn!("Login server connection error: {}", error);
-        message_box_events.write(MessageBoxEvent::Show {
-            message: format!("Connection to login server lost: {}", error),
-            modal: true,
-            ok: None,
-            cancel: None,
-        });
+
+        // Auto login and reconnect retry transient errors and report their own status
+        if login_rejected {
+            login_connection_events.write(LoginConnectionEvent::LoginRejected);
+        } else {
+            login_connection_events.write(LoginConnectionEvent::ConnectionFailed {
+                error: error.to_string(),
+            });
+        }
+
+        if reconnect_state.is_none() && (login_rejected || !server_configuration.auto_login) {
+            message_box_events.write(MessageBoxEvent::Show {
+                message: format!("Connection to login server lost: {}", error),
+                modal: true,
+                ok: None,
+                cancel: None,
+            });
+        }
         commands.remove_resource::<LoginConnection>();
     }
 }
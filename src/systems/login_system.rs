@@ -6,13 +6,15 @@ use bevy::{
 };
 use bevy_egui::{egui, EguiContexts};
 
-use rose_data::ZoneId;
 use rose_game_common::messages::client::ClientMessage;
 
 use crate::{
     animation::{CameraAnimation, ZmoAsset},
     events::{LoadZoneEvent, LoginEvent, NetworkEvent},
-    resources::{Account, LoginCameraAnimation, LoginConnection, LoginState, ServerConfiguration, ServerList},
+    resources::{
+        Account, CharacterSelectScene, LoginCameraAnimation, LoginConnection, LoginState,
+        ReconnectSession, ServerConfiguration, ServerList,
+    },
     systems::{FreeCamera, OrbitCamera},
 };
 
@@ -22,6 +24,7 @@ pub fn login_state_enter_system(
     mut query_cursor_options: Query<&mut CursorOptions, With<PrimaryWindow>>,
     query_cameras: Query<Entity, With<Camera3d>>,
     login_camera_animation: Option<Res<LoginCameraAnimation>>,
+    character_select_scene: Res<CharacterSelectScene>,
     asset_server: Res<AssetServer>,
 ) {
     // log::info!("[LOGIN SYSTEM] login_state_enter_system running");
@@ -56,8 +59,8 @@ pub fn login_state_enter_system(
     commands.insert_resource(LoginState::Input);
     // log::info!("[LOGIN SYSTEM] LoginState::Input inserted");
 
-    loaded_zone.write(LoadZoneEvent::new(ZoneId::new(4).unwrap()));
-    // log::info!("[LOGIN SYSTEM] LoadZoneEvent sent for zone 4");
+    // The character select backdrop zone is loaded behind the login screen too
+    loaded_zone.write(LoadZoneEvent::new(character_select_scene.zone_id()));
 }
 
 pub fn login_state_exit_system(mut commands: Commands) {
@@ -94,7 +97,11 @@ pub fn login_system(
         }
     }
 
-    if !matches!(*login_state, LoginState::Input) && login_connection.is_none() {
+    if !matches!(
+        *login_state,
+        LoginState::Input | LoginState::Reconnecting { .. }
+    ) && login_connection.is_none()
+    {
         // When we lose login server connection, return to login
         // log::info!("[LOGIN SYSTEM] Setting LoginState::Input (connection lost)");
         *login_state = LoginState::Input;
@@ -126,6 +133,22 @@ pub fn login_system(
                     ui.label("Connecting to channel");
                 });
         }
+        LoginState::Reconnecting {
+            attempt,
+            max_attempts,
+            remaining_secs,
+        } => {
+            egui::Window::new("Connecting...")
+                .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+                .collapsible(false)
+                .show(&*ctx, |ui| {
+                    ui.label(format!(
+                        "Reconnecting, attempt {} of {}…",
+                        attempt, max_attempts
+                    ));
+                    ui.label(format!("Retrying in {:.0}s", remaining_secs.ceil()));
+                });
+        }
         _ => {
             //log::trace!("[LOGIN SYSTEM] No UI to render for state: {:?}", *login_state);
         }
@@ -138,6 +161,7 @@ pub fn login_event_system(
     mut login_events: MessageReader<LoginEvent>,
     login_connection: Option<Res<LoginConnection>>,
     server_configuration: Res<ServerConfiguration>,
+    mut reconnect_session: ResMut<ReconnectSession>,
     mut network_events: MessageWriter<NetworkEvent>,
 ) {
     for event in login_events.read() {
@@ -171,6 +195,9 @@ pub fn login_event_system(
                         .ok();
                 }
                 *login_state = LoginState::JoiningServer;
+
+                reconnect_session.server_id = Some(server_id);
+                reconnect_session.channel_id = Some(channel_id);
             }
         }
     }
@@ -0,0 +1,113 @@
+use bevy::{
+    light::NotShadowCaster,
+    math::primitives::Cylinder,
+    pbr::{ExtendedMaterial, StandardMaterial},
+    prelude::{
+        Alpha, Assets, Camera3d, ChildOf, Commands, Entity, GlobalTransform, Handle, Local,
+        Mesh, Mesh3d, MeshMaterial3d, Query, Res, ResMut, Transform, Vec3, Visibility, With,
+    },
+    render::alpha::AlphaMode,
+};
+
+use rose_game_common::components::ItemDrop;
+
+use crate::{
+    components::{LootBeam, LootBeamEntity},
+    render::RoseEffectExtension,
+    resources::{GameData, LootFilterSettings, LootRarity},
+};
+
+const LOOT_BEAM_HEIGHT: f32 = 6.0;
+const LOOT_BEAM_RADIUS: f32 = 0.08;
+const LOOT_BEAM_ALPHA: f32 = 0.6;
+
+type LootBeamMaterial = ExtendedMaterial<StandardMaterial, RoseEffectExtension>;
+
+pub fn loot_beam_system(
+    mut commands: Commands,
+    query_item_drops: Query<(Entity, &ItemDrop, &GlobalTransform, Option<&LootBeamEntity>)>,
+    mut query_beams: Query<(&mut LootBeam, &MeshMaterial3d<LootBeamMaterial>)>,
+    query_camera: Query<&GlobalTransform, With<Camera3d>>,
+    game_data: Res<GameData>,
+    loot_filter_settings: Res<LootFilterSettings>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<LootBeamMaterial>>,
+    mut beam_mesh: Local<Option<Handle<Mesh>>>,
+) {
+    let Ok(camera_transform) = query_camera.single() else {
+        return;
+    };
+
+    for (entity, item_drop, global_transform, beam_entity) in query_item_drops.iter() {
+        let rarity = item_drop
+            .item
+            .as_ref()
+            .map(|dropped_item| LootRarity::from_dropped_item(dropped_item, &game_data));
+        let wanted_rarity = rarity.filter(|rarity| loot_filter_settings.show_beam[*rarity]);
+
+        if let Some(beam_entity) = beam_entity {
+            match query_beams.get_mut(beam_entity.0) {
+                Ok((mut beam, material)) if Some(beam.rarity) == wanted_rarity => {
+                    let opacity = loot_filter_settings.get_beam_opacity(
+                        camera_transform
+                            .translation()
+                            .distance(global_transform.translation()),
+                    );
+
+                    // Only touch the material when the opacity has visibly changed
+                    if (beam.opacity - opacity).abs() > 0.01 {
+                        beam.opacity = opacity;
+                        if let Some(material) = materials.get_mut(&material.0) {
+                            material.base.base_color = beam
+                                .rarity
+                                .beam_color()
+                                .with_alpha(LOOT_BEAM_ALPHA * opacity);
+                        }
+                    }
+                    continue;
+                }
+                _ => {
+                    // Beam is no longer wanted, or the item drop changed rarity
+                    commands.entity(beam_entity.0).despawn();
+                    commands.entity(entity).remove::<LootBeamEntity>();
+                }
+            }
+        }
+
+        let Some(rarity) = wanted_rarity else {
+            continue;
+        };
+
+        let mesh = beam_mesh
+            .get_or_insert_with(|| meshes.add(Cylinder::new(LOOT_BEAM_RADIUS, LOOT_BEAM_HEIGHT)))
+            .clone();
+        let material = materials.add(ExtendedMaterial {
+            base: StandardMaterial {
+                base_color: rarity.beam_color().with_alpha(0.0),
+                unlit: true,
+                alpha_mode: AlphaMode::Add,
+                double_sided: true,
+                cull_mode: None,
+                ..Default::default()
+            },
+            extension: RoseEffectExtension::default(),
+        });
+
+        let beam_entity = commands
+            .spawn((
+                LootBeam {
+                    rarity,
+                    opacity: 0.0,
+                },
+                Mesh3d(mesh),
+                MeshMaterial3d(material),
+                NotShadowCaster,
+                Visibility::default(),
+                Transform::from_translation(Vec3::new(0.0, LOOT_BEAM_HEIGHT / 2.0, 0.0)),
+                GlobalTransform::default(),
+                ChildOf(entity),
+            ))
+            .id();
+        commands.entity(entity).insert(LootBeamEntity(beam_entity));
+    }
+}
@@ -1,12 +1,21 @@
 mod ability_values_system;
+mod accessibility_settings_system;
+mod afk_system;
 mod animation_effect_system;
 mod animation_sound_system;
+mod asset_hot_reload_system;
 mod auto_login_system;
+mod auto_potion_system;
+mod auto_loot_system;
 mod background_music_system;
+mod benchmark_system;
 mod bird_system;
 mod boat_buoyancy_system;
 mod boat_spawn_system;
+mod blood_settings_system;
 mod blood_spatter_system;
+mod camera_motion_system;
+mod camera_shake_system;
 mod character_model_add_collider_system;
 mod gash_wound_system;
 mod character_model_blink_system;
@@ -23,17 +32,22 @@ mod command_system;
 mod conversation_dialog_system;
 mod cooldown_system;
 mod damage_digit_render_system;
+mod death_spectator_camera_system;
 mod dirt_dash_system;
 mod debug_inspector_system;
+mod debug_render_mode_system;
 mod debug_rendering_system;
 mod directional_light_system;
 mod effect_system;
+mod exposure_settings_system;
 mod facing_direction_system;
 mod fish_system;
 mod flight_command_system;
 mod flight_movement_system;
 mod flight_pose_system;
 mod flight_toggle_system;
+mod fog_settings_system;
+mod friends_list_system;
 mod free_camera_system;
 
 // Wing spawn system for angelic wings
@@ -43,25 +57,36 @@ mod game_keyboard_input_system;
 mod game_mouse_input_system;
 mod game_system;
 mod hit_event_system;
+mod hit_flash_system;
+mod input_activity_system;
 mod item_drop_model_system;
+mod log_settings_system;
 mod login_connection_system;
 mod login_system;
+mod loot_beam_system;
 mod model_viewer_system;
 mod monster_chatter_system;
 mod monster_separation_system;
 mod move_destination_effect_system;
 mod move_speed_command_system;
 mod move_speed_set_system;
+mod name_tag_fade_system;
 mod name_tag_system;
 mod name_tag_update_color_system;
 mod name_tag_update_healthbar_system;
+mod name_tag_update_threat_system;
 mod name_tag_visibility_system;
+mod network_logging_system;
+mod network_movement_system;
+mod network_stats_system;
 mod network_thread_system;
 mod npc_idle_sound_system;
 mod npc_model_add_collider_system;
 mod npc_model_system;
+mod occlusion_culling_fallback_system;
 mod orbit_camera_system;
 mod particle_sequence_system;
+mod party_marker_system;
 mod passive_recovery_system;
 mod pending_damage_system;
 mod pending_skill_effect_system;
@@ -72,20 +97,29 @@ mod player_command_system;
 mod projectile_system;
 mod quest_scroll_event_system;
 mod quest_trigger_system;
+mod reconnect_system;
+mod resting_system;
 mod sail_camera_system;
+mod save_settings_system;
 mod sailing_movement_system;
 mod spawn_effect_system;
 mod spawn_projectile_system;
 mod status_effect_system;
 mod systemfunc_event_system;
+mod threat_state_system;
+mod time_scale_system;
+mod transient_decal_system;
+mod ui_scale_system;
 mod update_position_system;
 mod use_item_event_system;
 mod vehicle_model_system;
 mod vehicle_sound_system;
 mod visible_status_effects_system;
+mod weather_override_system;
 mod wind_system;
 mod wind_effect_system;
 mod world_connection_system;
+mod world_ping_system;
 mod world_time_system;
 pub mod zone_time_system;
 mod zone_viewer_system;
@@ -94,10 +128,16 @@ mod zone_viewer_system;
 pub mod season;
 
 pub use ability_values_system::ability_values_system;
+pub use accessibility_settings_system::accessibility_settings_system;
+pub use afk_system::{afk_auto_reply_system, afk_system, parse_afk_command};
 pub use animation_effect_system::animation_effect_system;
 pub use animation_sound_system::animation_sound_system;
+pub use asset_hot_reload_system::{asset_hot_reload_material_system, asset_hot_reload_system};
 pub use auto_login_system::auto_login_system;
+pub use auto_potion_system::{auto_potion_system, count_consumable_items, find_consumable_slot};
+pub use auto_loot_system::auto_loot_system;
 pub use background_music_system::background_music_system;
+pub use benchmark_system::{benchmark_system, BenchmarkPlugin};
 pub use character_model_add_collider_system::character_model_add_collider_system;
 pub use character_model_blink_system::character_model_blink_system;
 pub use character_model_system::character_model_update_system;
@@ -119,8 +159,10 @@ pub use command_system::command_system;
 pub use conversation_dialog_system::conversation_dialog_system;
 pub use cooldown_system::cooldown_system;
 pub use damage_digit_render_system::{damage_digit_render_system, create_damage_digit_material_system};
+pub use death_spectator_camera_system::{death_spectator_camera_system, DeathSpectatorCamera};
 pub use dirt_dash_system::{DirtDashPlugin, dirt_dash_spawn_system, dirt_dash_particle_update_system};
 pub use debug_inspector_system::DebugInspectorPlugin;
+pub use debug_render_mode_system::debug_render_mode_system;
 pub use debug_rendering_system::{
     debug_entity_visibility,
     render_diagnostics_system,
@@ -144,52 +186,77 @@ pub use debug_rendering_system::{
 };
 pub use directional_light_system::directional_light_system;
 pub use effect_system::effect_system;
+pub use exposure_settings_system::exposure_settings_system;
 pub use facing_direction_system::facing_direction_system;
 pub use fish_system::{FishPlugin, spawn_fish_on_water_system, update_fish_movement_system};
 pub use bird_system::{BirdPlugin, spawn_birds_on_zone_system, update_bird_movement_system};
 pub use boat_buoyancy_system::boat_buoyancy_system;
+pub use camera_motion_system::camera_motion_system;
+pub use camera_shake_system::camera_shake_system;
 pub use boat_spawn_system::{boat_toggle_system, ensure_boat_state_system, is_boat_command};
+pub use blood_settings_system::blood_settings_system;
 pub use blood_spatter_system::{BloodSpatterPlugin, blood_spatter_on_death_system, blood_spatter_spawn_system, blood_spatter_fade_system};
 pub use gash_wound_system::{GashWoundPlugin, wound_visibility_system, wound_spawn_system, wound_cleanup_system};
 pub use flight_command_system::{is_fly_command, flight_command_system};
 pub use flight_movement_system::flight_movement_system;
 pub use flight_pose_system::{flight_pose_system, flight_pose_blend_update_system};
 pub use flight_toggle_system::{flight_toggle_system, ensure_flight_state_system};
+pub use fog_settings_system::fog_settings_system;
+pub use friends_list_system::friends_list_system;
 pub use wing_spawn_system::{WingSpawnPlugin, wing_spawn_system, wing_animation_system};
 pub use free_camera_system::{free_camera_system, FreeCamera};
 pub use game_connection_system::game_connection_system;
 pub use game_keyboard_input_system::game_keyboard_input_system;
-pub use game_mouse_input_system::game_mouse_input_system;
+pub use game_mouse_input_system::{
+    game_mouse_input_system, hover_highlight_system, mouse_pick_debug_system,
+};
 pub use game_system::{game_state_enter_system, game_zone_change_system};
 pub use hit_event_system::hit_event_system;
+pub use hit_flash_system::hit_flash_system;
+pub use input_activity_system::input_activity_system;
 pub use item_drop_model_system::{item_drop_model_add_collider_system, item_drop_model_system};
+pub use log_settings_system::log_settings_system;
 pub use login_connection_system::login_connection_system;
 pub use login_system::{
     login_event_system, login_state_enter_system, login_state_exit_system, login_system,
 };
+pub use loot_beam_system::loot_beam_system;
 pub use model_viewer_system::{
-    model_viewer_enter_system, model_viewer_exit_system, model_viewer_system,
+    model_viewer_enter_system, model_viewer_equipment_system, model_viewer_exit_system,
+    model_viewer_normals_system, model_viewer_system, model_viewer_turntable_system,
 };
 pub use monster_chatter_system::{monster_chatter_system, add_monster_chatter_system};
 pub use monster_separation_system::monster_separation_system;
 pub use move_destination_effect_system::move_destination_effect_system;
 pub use move_speed_command_system::{parse_move_speed_command, move_speed_command_system};
 pub use move_speed_set_system::move_speed_set_system;
+pub use name_tag_fade_system::name_tag_fade_system;
 pub use name_tag_system::name_tag_system;
 pub use name_tag_update_color_system::name_tag_update_color_system;
 pub use name_tag_update_healthbar_system::name_tag_update_healthbar_system;
+pub use name_tag_update_threat_system::name_tag_update_threat_system;
 pub use name_tag_visibility_system::name_tag_visibility_system;
+pub use network_logging_system::network_logging_system;
+pub use network_movement_system::{
+    network_movement_playback_system, network_movement_reconcile_system,
+};
+pub use network_stats_system::network_stats_system;
 pub use network_thread_system::network_thread_system;
 pub use npc_idle_sound_system::npc_idle_sound_system;
 pub use npc_model_add_collider_system::npc_model_add_collider_system;
 pub use npc_model_system::npc_model_update_system;
+pub use occlusion_culling_fallback_system::{
+    gpu_occlusion_culling_unsupported_reason, occlusion_culling_fallback_system,
+};
 pub use orbit_camera_system::{orbit_camera_system, OrbitCamera};
 pub use particle_sequence_system::{
+    particle_category_system,
     particle_sequence_system,
     particle_storage_buffer_update_system,
     create_default_particle_texture,
     DefaultParticleTexture,
 };
+pub use party_marker_system::{get_party_member_entities, party_marker_system};
 pub use passive_recovery_system::passive_recovery_system;
 pub use pending_damage_system::pending_damage_system;
 pub use pending_skill_effect_system::pending_skill_effect_system;
@@ -199,20 +266,29 @@ pub use ping_command_system::{is_ping_command, ping_command_system, ping_respons
 pub use player_command_system::player_command_system;
 pub use projectile_system::projectile_system;
 pub use quest_trigger_system::quest_trigger_system;
+pub use reconnect_system::reconnect_system;
+pub use resting_system::resting_system;
 pub use sail_camera_system::sail_camera_system;
+pub use save_settings_system::{save_settings_system, write_config_file_system};
 pub use sailing_movement_system::sailing_movement_system;
 pub use spawn_effect_system::spawn_effect_system;
 pub use spawn_projectile_system::spawn_projectile_system;
 pub use status_effect_system::status_effect_system;
 pub use systemfunc_event_system::system_func_event_system;
+pub use threat_state_system::threat_state_system;
+pub use time_scale_system::time_scale_system;
+pub use transient_decal_system::transient_decal_system;
+pub use ui_scale_system::ui_scale_system;
 pub use update_position_system::update_position_system;
 pub use use_item_event_system::use_item_event_system;
 pub use vehicle_model_system::vehicle_model_system;
 pub use vehicle_sound_system::vehicle_sound_system;
 pub use visible_status_effects_system::visible_status_effects_system;
+pub use weather_override_system::weather_override_system;
 pub use wind_system::{sync_vegetation_wind_system, wind_update_system};
 pub use wind_effect_system::{WindEffectPlugin, wind_emitter_spawn_system, wind_particle_spawn_system, wind_particle_update_system};
 pub use world_connection_system::world_connection_system;
+pub use world_ping_system::world_ping_system;
 pub use world_time_system::world_time_system;
 pub use zone_time_system::{color_grading_time_of_day_system, zone_time_system};
 pub use zone_viewer_system::zone_viewer_enter_system;
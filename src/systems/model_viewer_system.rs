@@ -1,15 +1,22 @@
 use std::cmp::Ordering;
 
 use bevy::{
-    math::Vec3,
+    math::{Mat4, Quat, Vec3},
+    pbr::{wireframe::WireframeConfig, ExtendedMaterial, MeshMaterial3d, StandardMaterial},
     prelude::{
-        Camera3d, Color, Commands, Entity, GlobalAmbientLight, GlobalTransform, Query, Res, ResMut,
-        Resource, Transform, Visibility, With,
+        Assets, Camera, Camera3d, ClearColorConfig, Color, Commands, Entity, Gizmos,
+        GlobalAmbientLight, GlobalTransform, Mesh, Mesh3d, Or, Query, Res, ResMut, Resource,
+        Transform, Visibility, With,
     },
+    time::Time,
 };
 use bevy_camera::visibility::{ViewVisibility, InheritedVisibility};
+use bevy_mesh::{
+    skinning::{SkinnedMesh, SkinnedMeshInverseBindposes},
+    VertexAttributeValues,
+};
 use bevy_egui::{egui, EguiContexts};
-use enum_map::{enum_map, EnumMap};
+use enum_map::{enum_map, Enum, EnumMap};
 use rand::{prelude::SliceRandom, Rng};
 
 use rose_data::{
@@ -19,8 +26,11 @@ use rose_data::{
 use rose_game_common::components::{CharacterGender, CharacterInfo, Equipment, Npc};
 
 use crate::{
-    animation::{CameraAnimation, SkeletalAnimation},
-    components::{CharacterModel, ClientEntityName, ModelHeight, NameTagType, NpcModel},
+    animation::{CameraAnimation, SkeletalAnimation, ZmoAsset},
+    components::{
+        CharacterModel, CharacterModelPart, ClientEntityName, ModelHeight, NameTagType, NpcModel,
+    },
+    render::object_material_extension::RoseObjectExtension,
     resources::{DamageDigitsSpawner, GameData, NameTagSettings},
     systems::{FreeCamera, OrbitCamera},
     ui::UiStateDebugWindows,
@@ -28,6 +38,14 @@ use crate::{
 
 const CHARACTER_SPACING: f32 = 7.5;
 const NPC_SPACING: f32 = 7.5;
+const DEFAULT_BACKGROUND_COLOR: [f32; 3] = [0.0, 0.0, 0.02];
+const DYEABLE_MODEL_PARTS: [CharacterModelPart; 5] = [
+    CharacterModelPart::Head,
+    CharacterModelPart::Body,
+    CharacterModelPart::Hands,
+    CharacterModelPart::Feet,
+    CharacterModelPart::Back,
+];
 
 #[derive(Resource)]
 pub struct ModelViewerState {
@@ -41,6 +59,23 @@ pub struct ModelViewerState {
     characters: Vec<Entity>,
     num_characters: usize,
     max_num_characters: usize,
+
+    turntable_enabled: bool,
+    turntable_speed: f32,
+
+    character_motion: CharacterMotionAction,
+    npc_motion: NpcMotionAction,
+    animation_paused: bool,
+    animation_speed: f32,
+
+    background_color: [f32; 3],
+    show_wireframe: bool,
+    show_normals: bool,
+    normal_length: f32,
+
+    preview_character: usize,
+    dye_enabled: bool,
+    dye_color: [f32; 3],
 }
 
 pub fn model_viewer_enter_system(
@@ -89,6 +124,23 @@ pub fn model_viewer_enter_system(
         characters: Vec::new(),
         num_characters: 1,
         max_num_characters: 500,
+
+        turntable_enabled: false,
+        turntable_speed: 30.0,
+
+        character_motion: CharacterMotionAction::Stop1,
+        npc_motion: NpcMotionAction::Stop,
+        animation_paused: false,
+        animation_speed: 1.0,
+
+        background_color: DEFAULT_BACKGROUND_COLOR,
+        show_wireframe: false,
+        show_normals: false,
+        normal_length: 0.1,
+
+        preview_character: 0,
+        dye_enabled: false,
+        dye_color: [1.0, 1.0, 1.0],
     });
 
     // Reset ambient light
@@ -114,6 +166,8 @@ pub fn model_viewer_exit_system(
     mut commands: Commands,
     model_viewer_state: ResMut<ModelViewerState>,
     mut name_tag_settings: ResMut<NameTagSettings>,
+    mut query_cameras: Query<&mut Camera, With<Camera3d>>,
+    wireframe_config: Option<ResMut<WireframeConfig>>,
 ) {
     for entity in model_viewer_state.characters.iter() {
         commands.entity(*entity).despawn();
@@ -125,6 +179,16 @@ pub fn model_viewer_exit_system(
 
     // Restore default NameTagSettings
     *name_tag_settings = NameTagSettings::default();
+
+    // Restore background and wireframe
+    let [r, g, b] = DEFAULT_BACKGROUND_COLOR;
+    for mut camera in query_cameras.iter_mut() {
+        camera.clear_color = ClearColorConfig::Custom(Color::srgb(r, g, b));
+    }
+
+    if let Some(mut wireframe_config) = wireframe_config {
+        wireframe_config.global = false;
+    }
 }
 
 pub fn model_viewer_system(
@@ -137,6 +201,13 @@ pub fn model_viewer_system(
     damage_digits_spawner: Res<DamageDigitsSpawner>,
     query_damage_character_model: Query<(&GlobalTransform, &ModelHeight), With<CharacterModel>>,
     query_damage_npc_model: Query<(&GlobalTransform, &ModelHeight), With<NpcModel>>,
+    mut query_skeletal_animation: Query<
+        &mut SkeletalAnimation,
+        Or<(With<CharacterModel>, With<NpcModel>)>,
+    >,
+    mut query_cameras: Query<&mut Camera, With<Camera3d>>,
+    motion_assets: Res<Assets<ZmoAsset>>,
+    wireframe_config: Option<ResMut<WireframeConfig>>,
 ) {
     let ctx = egui_context.ctx_mut().unwrap();
     egui::Window::new("Model Viewer").show(&*ctx, |ui| {
@@ -315,43 +386,438 @@ pub fn model_viewer_system(
     });
 
     egui::Window::new("Animation").show(&*egui_context.ctx_mut().unwrap(), |ui| {
-        let mut animation_button =
-            |name: &str, character_action: CharacterMotionAction, npc_action: NpcMotionAction| {
-                if ui.button(name).clicked() {
-                    for (entity, character_model) in query_character_model.iter() {
-                        commands.entity(entity).insert(SkeletalAnimation::repeat(
-                            character_model.action_motions[character_action].clone(),
-                            None,
-                        ));
+        let mut play_character_motion = None;
+        let mut play_npc_motion = None;
+
+        egui::Grid::new("model_viewer_animation_grid")
+            .num_columns(2)
+            .show(ui, |ui| {
+                ui.label("Character Motion:");
+                egui::ComboBox::from_id_salt("model_viewer_character_motion")
+                    .selected_text(format!("{:?}", ui_state.character_motion))
+                    .show_ui(ui, |ui| {
+                        // List the motions of the first character, as every character
+                        // model shares the same motion database
+                        if let Some((_, character_model)) = query_character_model.iter().next() {
+                            for (action, _) in character_model.action_motions.iter() {
+                                let selected =
+                                    ui_state.character_motion.into_usize() == action.into_usize();
+                                if ui
+                                    .selectable_label(selected, format!("{:?}", action))
+                                    .clicked()
+                                {
+                                    play_character_motion = Some(action);
+                                }
+                            }
+                        }
+                    });
+                ui.end_row();
+
+                ui.label("NPC Motion:");
+                egui::ComboBox::from_id_salt("model_viewer_npc_motion")
+                    .selected_text(format!("{:?}", ui_state.npc_motion))
+                    .show_ui(ui, |ui| {
+                        if let Some((_, npc_model)) = query_npc_model.iter().next() {
+                            for (action, _) in npc_model.action_motions.iter() {
+                                let selected =
+                                    ui_state.npc_motion.into_usize() == action.into_usize();
+                                if ui
+                                    .selectable_label(selected, format!("{:?}", action))
+                                    .clicked()
+                                {
+                                    play_npc_motion = Some(action);
+                                }
+                            }
+                        }
+                    });
+                ui.end_row();
+            });
+
+        if let Some(action) = play_character_motion {
+            ui_state.character_motion = action;
+            for (entity, character_model) in query_character_model.iter() {
+                let mut animation = SkeletalAnimation::repeat(
+                    character_model.action_motions[action].clone(),
+                    None,
+                )
+                .with_animation_speed(ui_state.animation_speed);
+                animation.set_paused(ui_state.animation_paused);
+                commands.entity(entity).insert(animation);
+            }
+        }
+
+        if let Some(action) = play_npc_motion {
+            ui_state.npc_motion = action;
+            for (entity, npc_model) in query_npc_model.iter() {
+                let mut animation =
+                    SkeletalAnimation::repeat(npc_model.action_motions[action].clone(), None)
+                        .with_animation_speed(ui_state.animation_speed);
+                animation.set_paused(ui_state.animation_paused);
+                commands.entity(entity).insert(animation);
+            }
+        }
+
+        ui.separator();
+
+        // Use the first loaded animation to drive the frame slider, other models are
+        // scrubbed to the same relative position within their own motion
+        let current_frame = query_skeletal_animation.iter().find_map(|animation| {
+            motion_assets.get(animation.motion()).map(|zmo_asset| {
+                (
+                    animation.current_frame_index() as f32 + animation.current_frame_fract(),
+                    zmo_asset.num_frames,
+                )
+            })
+        });
+
+        ui.horizontal(|ui| {
+            let play_pause_label = if ui_state.animation_paused {
+                "▶ Play"
+            } else {
+                "⏸ Pause"
+            };
+
+            if ui.button(play_pause_label).clicked() {
+                ui_state.animation_paused = !ui_state.animation_paused;
+                for mut animation in query_skeletal_animation.iter_mut() {
+                    animation.set_paused(ui_state.animation_paused);
+                }
+            }
+
+            if let Some((mut frame, num_frames)) = current_frame {
+                let max_frame = num_frames.saturating_sub(1) as f32;
+                if ui
+                    .add(
+                        egui::Slider::new(&mut frame, 0.0..=max_frame)
+                            .step_by(1.0)
+                            .text("Frame"),
+                    )
+                    .changed()
+                {
+                    let fraction = if max_frame > 0.0 {
+                        frame / num_frames as f32
+                    } else {
+                        0.0
+                    };
+
+                    ui_state.animation_paused = true;
+                    for mut animation in query_skeletal_animation.iter_mut() {
+                        if let Some(zmo_asset) = motion_assets.get(animation.motion()) {
+                            animation.set_paused(true);
+                            animation.seek_to_frame(fraction * zmo_asset.num_frames as f32);
+                        }
                     }
+                }
+            } else {
+                ui.label("No animation loaded");
+            }
+        });
+
+        if ui
+            .add(
+                egui::Slider::new(&mut ui_state.animation_speed, 0.05..=4.0)
+                    .logarithmic(true)
+                    .text("Speed"),
+            )
+            .changed()
+        {
+            for mut animation in query_skeletal_animation.iter_mut() {
+                // Re-seek to the current frame so changing speed does not jump the animation
+                let frame = animation.current_frame_index() as f32 + animation.current_frame_fract();
+                animation.set_animation_speed(ui_state.animation_speed);
+                animation.seek_to_frame(frame);
+            }
+        }
+
+        ui.separator();
+
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut ui_state.turntable_enabled, "Turntable");
+            ui.add_enabled(
+                ui_state.turntable_enabled,
+                egui::Slider::new(&mut ui_state.turntable_speed, -180.0..=180.0).suffix("°/s"),
+            );
+        });
+
+        ui.horizontal(|ui| {
+            ui.label("Background:");
+            if ui
+                .color_edit_button_rgb(&mut ui_state.background_color)
+                .changed()
+            {
+                let [r, g, b] = ui_state.background_color;
+                for mut camera in query_cameras.iter_mut() {
+                    camera.clear_color = ClearColorConfig::Custom(Color::srgb(r, g, b));
+                }
+            }
+        });
+
+        if let Some(mut wireframe_config) = wireframe_config {
+            if ui
+                .checkbox(&mut ui_state.show_wireframe, "Show Wireframe")
+                .changed()
+            {
+                wireframe_config.global = ui_state.show_wireframe;
+            }
+        }
+
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut ui_state.show_normals, "Show Normals");
+            ui.add_enabled(
+                ui_state.show_normals,
+                egui::Slider::new(&mut ui_state.normal_length, 0.01..=0.5).text("Length"),
+            );
+        });
+    });
+}
+
+pub fn model_viewer_turntable_system(
+    ui_state: Res<ModelViewerState>,
+    mut query_transform: Query<&mut Transform>,
+    time: Res<Time>,
+) {
+    if !ui_state.turntable_enabled {
+        return;
+    }
+
+    let rotation = Quat::from_rotation_y(ui_state.turntable_speed.to_radians() * time.delta_secs());
+    for entity in ui_state.characters.iter().chain(ui_state.npcs.iter()) {
+        if let Ok(mut transform) = query_transform.get_mut(*entity) {
+            transform.rotation = rotation * transform.rotation;
+        }
+    }
+}
+
+pub fn model_viewer_normals_system(
+    ui_state: Res<ModelViewerState>,
+    query_meshes: Query<(
+        &Mesh3d,
+        &GlobalTransform,
+        &ViewVisibility,
+        Option<&SkinnedMesh>,
+    )>,
+    query_joints: Query<&GlobalTransform>,
+    meshes: Res<Assets<Mesh>>,
+    inverse_bindposes: Res<Assets<SkinnedMeshInverseBindposes>>,
+    mut gizmos: Gizmos,
+) {
+    if !ui_state.show_normals {
+        return;
+    }
+
+    let normal_color = Color::srgb(0.2, 0.6, 1.0);
 
-                    for (entity, npc_model) in query_npc_model.iter() {
-                        commands.entity(entity).insert(SkeletalAnimation::repeat(
-                            npc_model.action_motions[npc_action].clone(),
-                            None,
-                        ));
+    for (mesh3d, global_transform, view_visibility, skinned_mesh) in query_meshes.iter() {
+        if !view_visibility.get() {
+            continue;
+        }
+
+        let Some(mesh) = meshes.get(&mesh3d.0) else {
+            continue;
+        };
+
+        let (
+            Some(VertexAttributeValues::Float32x3(positions)),
+            Some(VertexAttributeValues::Float32x3(normals)),
+        ) = (
+            mesh.attribute(Mesh::ATTRIBUTE_POSITION),
+            mesh.attribute(Mesh::ATTRIBUTE_NORMAL),
+        )
+        else {
+            continue;
+        };
+
+        // Skinned meshes are deformed on the GPU, so skin the vertices on the CPU here
+        // to draw the normals in the animated pose
+        let skinning = skinned_mesh.and_then(|skinned_mesh| {
+            let inverse_bindposes = inverse_bindposes.get(&skinned_mesh.inverse_bindposes)?;
+            let joint_matrices = skinned_mesh
+                .joints
+                .iter()
+                .zip(inverse_bindposes.iter())
+                .map(|(joint, inverse_bindpose)| {
+                    query_joints.get(*joint).map_or(Mat4::IDENTITY, |joint_transform| {
+                        joint_transform.to_matrix() * *inverse_bindpose
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            match (
+                mesh.attribute(Mesh::ATTRIBUTE_JOINT_INDEX),
+                mesh.attribute(Mesh::ATTRIBUTE_JOINT_WEIGHT),
+            ) {
+                (
+                    Some(VertexAttributeValues::Uint16x4(joint_indices)),
+                    Some(VertexAttributeValues::Float32x4(joint_weights)),
+                ) => Some((joint_matrices, joint_indices, joint_weights)),
+                _ => None,
+            }
+        });
+
+        for (vertex_index, (position, normal)) in positions.iter().zip(normals.iter()).enumerate()
+        {
+            let model_matrix = if let Some((joint_matrices, joint_indices, joint_weights)) =
+                skinning.as_ref()
+            {
+                let mut skin_matrix = Mat4::ZERO;
+                for (joint_index, joint_weight) in joint_indices[vertex_index]
+                    .iter()
+                    .zip(joint_weights[vertex_index].iter())
+                {
+                    if let Some(joint_matrix) = joint_matrices.get(*joint_index as usize) {
+                        skin_matrix += *joint_matrix * *joint_weight;
                     }
                 }
+                skin_matrix
+            } else {
+                global_transform.to_matrix()
             };
 
-        animation_button("Stop", CharacterMotionAction::Stop1, NpcMotionAction::Stop);
-        animation_button("Walk", CharacterMotionAction::Walk, NpcMotionAction::Move);
-        animation_button("Run", CharacterMotionAction::Run, NpcMotionAction::Run);
-        animation_button(
-            "Attack 1",
-            CharacterMotionAction::Attack,
-            NpcMotionAction::Attack,
-        );
-        animation_button(
-            "Attack 2",
-            CharacterMotionAction::Attack2,
-            NpcMotionAction::Attack,
-        );
-        animation_button(
-            "Attack 3",
-            CharacterMotionAction::Attack3,
-            NpcMotionAction::Attack,
+            let start = model_matrix.transform_point3(Vec3::from(*position));
+            let direction = model_matrix
+                .transform_vector3(Vec3::from(*normal))
+                .normalize_or_zero();
+            gizmos.line(start, start + direction * ui_state.normal_length, normal_color);
+        }
+    }
+}
+
+pub fn model_viewer_equipment_system(
+    mut ui_state: ResMut<ModelViewerState>,
+    mut query_equipment: Query<(&mut Equipment, Option<&CharacterModel>)>,
+    query_part_materials: Query<
+        &MeshMaterial3d<ExtendedMaterial<StandardMaterial, RoseObjectExtension>>,
+    >,
+    mut object_materials: ResMut<Assets<ExtendedMaterial<StandardMaterial, RoseObjectExtension>>>,
+    game_data: Res<GameData>,
+    mut egui_context: EguiContexts,
+) {
+    if ui_state.characters.is_empty() {
+        return;
+    }
+    ui_state.preview_character = ui_state
+        .preview_character
+        .min(ui_state.characters.len() - 1);
+    let preview_entity = ui_state.characters[ui_state.preview_character];
+
+    let Ok((mut equipment, character_model)) = query_equipment.get_mut(preview_entity) else {
+        return;
+    };
+
+    egui::Window::new("Equipment Preview").show(egui_context.ctx_mut().unwrap(), |ui| {
+        let max_preview_character = ui_state.characters.len() - 1;
+        ui.add(
+            egui::Slider::new(&mut ui_state.preview_character, 0..=max_preview_character)
+                .text("Character"),
         );
-        animation_button("Die", CharacterMotionAction::Die, NpcMotionAction::Die);
+
+        ui.separator();
+
+        let mut new_equipment = None;
+        egui::Grid::new("model_viewer_equipment_grid")
+            .num_columns(2)
+            .show(ui, |ui| {
+                for (equipment_index, valid_items) in ui_state.valid_items.iter() {
+                    let equipped_item = equipment.equipped_items[equipment_index]
+                        .as_ref()
+                        .map(|equipment_item| equipment_item.item);
+                    let item_name = |item: ItemReference| {
+                        game_data
+                            .items
+                            .get_base_item(item)
+                            .map_or("Unknown", |item_data| item_data.name.as_str())
+                    };
+
+                    ui.label(format!("{:?}:", equipment_index));
+                    egui::ComboBox::from_id_salt((
+                        "model_viewer_equipment",
+                        equipment_index.into_usize(),
+                    ))
+                        .width(200.0)
+                        .selected_text(equipped_item.map_or("None", item_name))
+                        .show_ui(ui, |ui| {
+                            if ui
+                                .selectable_label(equipped_item.is_none(), "None")
+                                .clicked()
+                            {
+                                new_equipment = Some((equipment_index, None));
+                            }
+
+                            for item in valid_items.iter() {
+                                if ui
+                                    .selectable_label(
+                                        equipped_item == Some(*item),
+                                        format!("{} ({})", item_name(*item), item.item_number),
+                                    )
+                                    .clicked()
+                                {
+                                    new_equipment = Some((equipment_index, Some(*item)));
+                                }
+                            }
+                        });
+                    ui.end_row();
+                }
+            });
+
+        if let Some((equipment_index, item)) = new_equipment {
+            equipment.equipped_items[equipment_index] =
+                item.and_then(|item| EquipmentItem::new(item, 0));
+
+            // If has a two-handed weapon equipped, cannot have a sub weapon equipped
+            if let Some(equipped_weapon) = equipment.equipped_items[EquipmentIndex::Weapon].as_ref()
+            {
+                if let Some(item_data) = game_data.items.get_base_item(equipped_weapon.item) {
+                    if item_data.class.is_two_handed_weapon() {
+                        if matches!(equipment_index, EquipmentIndex::SubWeapon) {
+                            equipment.equipped_items[EquipmentIndex::Weapon] = None;
+                        } else {
+                            equipment.equipped_items[EquipmentIndex::SubWeapon] = None;
+                        }
+                    }
+                }
+            }
+        }
+
+        if ui.button("Reset to Naked").clicked() {
+            *equipment = Equipment::default();
+        }
+
+        ui.separator();
+
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut ui_state.dye_enabled, "Dye");
+            ui.add_enabled_ui(ui_state.dye_enabled, |ui| {
+                ui.color_edit_button_rgb(&mut ui_state.dye_color);
+            });
+        });
     });
+
+    // Tint the dyeable parts of the preview character, checked every frame so parts
+    // respawned by an equipment change pick up the current dye
+    let Some(character_model) = character_model else {
+        return;
+    };
+    let dye_color = if ui_state.dye_enabled {
+        let [r, g, b] = ui_state.dye_color;
+        Color::srgb(r, g, b)
+    } else {
+        Color::WHITE
+    };
+
+    for model_part in DYEABLE_MODEL_PARTS {
+        for part_entity in character_model.model_parts[model_part].1.iter() {
+            let Ok(material_handle) = query_part_materials.get(*part_entity) else {
+                continue;
+            };
+
+            let needs_update = object_materials
+                .get(&material_handle.0)
+                .is_some_and(|material| material.base.base_color != dye_color);
+            if needs_update {
+                if let Some(material) = object_materials.get_mut(&material_handle.0) {
+                    material.base.base_color = dye_color;
+                }
+            }
+        }
+    }
 }
@@ -1,13 +1,18 @@
 use bevy::{log::info, prelude::*};
+use rand::{rngs::StdRng, Rng};
 
 use rose_game_common::components::Npc;
 
 use crate::{
     components::{MonsterChatter, ClientEntityName, ClientEntity, ClientEntityType, ModelHeight},
     events::{ChatBubbleEvent, ChatBubbleType},
-    resources::MonsterChatterPhrases,
+    resources::{ChatterSettings, CurrentZone, MonsterChatterPhrases, RngSeed},
 };
 
+/// Seconds after entering a zone before anyone starts to chatter, so the
+/// NPCs streaming in with the zone do not all talk at once
+const CHATTER_ZONE_GRACE_SECONDS: f32 = 5.0;
+
 /// System that makes monsters and NPCs occasionally say random phrases
 pub fn monster_chatter_system(
     mut commands: Commands,
@@ -15,8 +20,29 @@ pub fn monster_chatter_system(
     mut query_entities: Query<(Entity, &mut MonsterChatter, Option<&ClientEntityName>, Option<&ClientEntity>, Option<&ModelHeight>), With<Npc>>,
     mut chat_bubble_events: MessageWriter<ChatBubbleEvent>,
     phrases: Res<MonsterChatterPhrases>,
+    chatter_settings: Res<ChatterSettings>,
+    current_zone: Option<Res<CurrentZone>>,
+    mut last_zone: Local<Option<u16>>,
+    mut zone_grace: Local<f32>,
+    rng_seed: Res<RngSeed>,
+    mut rng: Local<Option<StdRng>>,
 ) {
-    let delta = time.delta_secs();
+    let rng = rng.get_or_insert_with(|| rng_seed.rng("chatter"));
+
+    let zone = current_zone.map(|current_zone| current_zone.id.get());
+    if zone != *last_zone {
+        *last_zone = zone;
+        *zone_grace = CHATTER_ZONE_GRACE_SECONDS;
+    }
+    *zone_grace = (*zone_grace - time.delta_secs()).max(0.0);
+
+    // Timers are scaled rather than reset so changing the frequency or zone
+    // does not restart everyone's countdown at once
+    let rate = chatter_settings.rate(zone);
+    if rate <= 0.0 {
+        return;
+    }
+    let delta = time.delta_secs() * rate;
     let mut events_sent = 0;
 
     for (entity, mut chatter, name, client_entity, _model_height) in query_entities.iter_mut() {
@@ -24,14 +50,15 @@ pub fn monster_chatter_system(
         chatter.time_until_next_chat -= delta;
 
         // Check if it's time to chat
-        if chatter.time_until_next_chat <= 0.0 {
+        if chatter.time_until_next_chat <= 0.0 && *zone_grace <= 0.0 {
             // Get entity type (default to Monster if no ClientEntity component)
             let entity_type = client_entity
                 .map(|ce| ce.entity_type)
                 .unwrap_or(ClientEntityType::Monster);
 
-            // Get a random phrase based on entity type
-            let phrase = phrases.get_random_phrase(entity_type);
+            // Get a random phrase based on entity type, skipping muted phrases
+            let phrase = phrases
+                .get_random_phrase(entity_type, |phrase| !chatter_settings.is_muted(phrase), rng);
 
             // Get entity name or use default based on type
             let entity_name = name.map(|n| n.name.clone()).unwrap_or_else(|| {
@@ -50,18 +77,20 @@ pub fn monster_chatter_system(
                 _ => ChatBubbleType::Monster,
             };
 
-            // Send chat bubble event
-            chat_bubble_events.write(
-                ChatBubbleEvent::new(entity_name, phrase.clone())
-                    .with_entity(entity)
-                    .with_duration(10.0)
-                    .with_color(Color::BLACK)
-                    .with_bubble_type(bubble_type)
-            );
-            events_sent += 1;
+            // Send chat bubble event, unless every phrase has been muted
+            if let Some(phrase) = phrase {
+                chat_bubble_events.write(
+                    ChatBubbleEvent::new(entity_name, phrase.clone())
+                        .with_entity(entity)
+                        .with_duration(10.0)
+                        .with_color(Color::BLACK)
+                        .with_bubble_type(bubble_type)
+                );
+                events_sent += 1;
+            }
 
             // Reset timer with random interval
-            chatter.time_until_next_chat = rand::random::<f32>()
+            chatter.time_until_next_chat = rng.gen::<f32>()
                 * (chatter.max_interval - chatter.min_interval)
                 + chatter.min_interval;
         }
@@ -76,8 +105,12 @@ pub fn monster_chatter_system(
 pub fn add_monster_chatter_system(
     mut commands: Commands,
     query_npcs: Query<Entity, (With<Npc>, Without<MonsterChatter>)>,
+    rng_seed: Res<RngSeed>,
+    mut rng: Local<Option<StdRng>>,
 ) {
+    let rng = rng.get_or_insert_with(|| rng_seed.rng("chatter_timers"));
     for entity in query_npcs.iter() {
-        commands.entity(entity).insert(MonsterChatter::default());
+        // 30-120 seconds between chats
+        commands.entity(entity).insert(MonsterChatter::new(30.0, 120.0, rng));
     }
 }
@@ -2,7 +2,7 @@ use bevy::{
     pbr::{ExtendedMaterial, StandardMaterial},
     prelude::{
         AssetServer, Assets, Commands, ViewVisibility, InheritedVisibility, Entity, MessageReader,
-        GlobalTransform, Local, Res, ResMut, Transform, Visibility, Mesh,
+        GlobalTransform, Local, Res, ResMut, Time, Transform, Visibility, Mesh,
     },
     render::{
         alpha::AlphaMode,
@@ -11,6 +11,7 @@ use bevy::{
 use rose_data::EffectFileId;
 
 use crate::{
+    components::TransientDecal,
     effect_loader::{spawn_effect, EffectCache},
     events::MoveDestinationEffectEvent,
     render::{ParticleMaterial, RoseEffectExtension},
@@ -34,12 +35,16 @@ pub fn move_destination_effect_system(
     mut particle_materials: ResMut<Assets<ParticleMaterial>>,
     mut storage_buffers: ResMut<Assets<bevy::render::storage::ShaderStorageBuffer>>,
     mut meshes: ResMut<Assets<Mesh>>,
+    time: Res<Time>,
 ) {
     for event in events.read() {
         match event {
             MoveDestinationEffectEvent::Show { position } => {
+                // The effect may already have expired or been cleared by a zone change
                 if let Some(last_effect_entity) = state.last_effect_entity.take() {
-                    commands.entity(last_effect_entity).despawn();
+                    if let Ok(mut entity_commands) = commands.get_entity(last_effect_entity) {
+                        entity_commands.despawn();
+                    }
                 }
 
                 if let Some(effect_file_path) = game_data
@@ -55,6 +60,7 @@ pub fn move_destination_effect_system(
                             InheritedVisibility::default(),
                             Visibility::default(),
                             ViewVisibility::default(),
+                            TransientDecal::new(time.elapsed_secs(), None),
                         ))
                         .id();
                     state.last_effect_entity = Some(effect_entity);
@@ -77,7 +83,9 @@ pub fn move_destination_effect_system(
             }
             MoveDestinationEffectEvent::Hide => {
                 if let Some(last_effect_entity) = state.last_effect_entity.take() {
-                    commands.entity(last_effect_entity).despawn();
+                    if let Ok(mut entity_commands) = commands.get_entity(last_effect_entity) {
+                        entity_commands.despawn();
+                    }
                 }
             }
         }
@@ -0,0 +1,47 @@
+use bevy::prelude::{
+    Camera3d, ChildOf, Children, Commands, GlobalTransform, Query, Res, With,
+};
+
+use crate::{
+    components::NameTag,
+    render::WorldUiOpacity,
+    resources::{NameTagSettings, SelectedTarget},
+};
+
+pub fn name_tag_fade_system(
+    mut commands: Commands,
+    query_camera: Query<&GlobalTransform, With<Camera3d>>,
+    query_name_tags: Query<(&NameTag, &ChildOf, &GlobalTransform, &Children)>,
+    mut query_opacity: Query<&mut WorldUiOpacity>,
+    selected_target: Res<SelectedTarget>,
+    name_tag_settings: Res<NameTagSettings>,
+) {
+    let Ok(camera_transform) = query_camera.single() else {
+        return;
+    };
+    let camera_position = camera_transform.translation();
+
+    for (name_tag, parent, global_transform, children) in query_name_tags.iter() {
+        let opacity = if selected_target.hover == Some(parent.0)
+            || selected_target.selected == Some(parent.0)
+        {
+            // Hovered or targeted name tags are never faded
+            1.0
+        } else {
+            name_tag_settings.get_opacity(
+                name_tag_settings.max_distance[name_tag.name_tag_type],
+                camera_position.distance(global_transform.translation()),
+            )
+        };
+
+        for &child in children.iter() {
+            if let Ok(mut child_opacity) = query_opacity.get_mut(child) {
+                if child_opacity.0 != opacity {
+                    child_opacity.0 = opacity;
+                }
+            } else {
+                commands.entity(child).try_insert(WorldUiOpacity(opacity));
+            }
+        }
+    }
+}
@@ -9,8 +9,8 @@ use bevy::{
     platform::collections::HashMap,
     prelude::{
         Assets, Changed, ChildOf, Color, Commands, Entity, MessageReader,
-        GlobalTransform, Handle, Image, Local, Query, Res, ResMut, Transform, Vec2, Vec3,
-        Visibility, With, Without,
+        GlobalTransform, Handle, Image, Local, Or, Query, RemovedComponents, Res, ResMut,
+        Transform, Vec2, Vec3, Visibility, With, Without,
     },
     render::{
         render_resource::{Extent3d, TextureDimension, TextureFormat},
@@ -23,19 +23,25 @@ use rose_game_common::components::{Level, Npc, Team};
 
 use crate::{
     components::{
-        ClientEntityName, ModelHeight, NameTag, NameTagEntity, NameTagHealthbarBackground,
-        NameTagHealthbarForeground, NameTagName, NameTagTargetMark, NameTagType, PlayerCharacter,
+        AfkStatus, ClientEntityName, ModelHeight, NameTag, NameTagEntity,
+        NameTagHealthbarBackground, NameTagHealthbarForeground, NameTagName, NameTagTargetMark,
+        NameTagThreatMark, NameTagType, PlayerCharacter,
     },
     events::LoadZoneEvent,
     render::WorldUiRect,
-    resources::{GameData, NameTagSettings, UiResources, UiSpriteSheetType},
+    resources::{
+        current_accessibility, GameData, NameTagSettings, UiResources, UiSpriteSheetType,
+    },
 };
 
 const ORDER_HEALTH_BACKGROUND: u8 = 0;
 const ORDER_HEALTH_FOREGROUND: u8 = 1;
 const ORDER_NAME: u8 = 2;
 const ORDER_TARGET_MARK: u8 = 2;
+const ORDER_THREAT_MARK: u8 = 3;
 const MAX_NAME_ROWS: usize = 2;
+/// Longer AFK messages are cut short on the name tag
+const MAX_AFK_NAME_TAG_CHARS: usize = 32;
 
 pub struct NameTagData {
     pub image: Handle<Image>,
@@ -71,6 +77,17 @@ pub struct NameTagObjectQuery<'w> {
     npc: Option<&'w Npc>,
     level: Option<&'w Level>,
     team: Option<&'w Team>,
+    afk: Option<&'w AfkStatus>,
+}
+
+impl NameTagObjectQueryItem<'_, '_, '_> {
+    /// Name tags are cached by their text, which includes the AFK message
+    fn cache_key(&self) -> String {
+        match self.afk {
+            Some(afk) => format!("{}\n{}", self.name.name, afk.message),
+            None => self.name.name.clone(),
+        }
+    }
 }
 
 pub fn get_monster_name_tag_color(
@@ -81,7 +98,7 @@ pub fn get_monster_name_tag_color(
     let level_diff = player_level.map_or(1, |level| level.level) as i32
         - monster_level.map_or(1, |level| level.level) as i32;
 
-    if monster_team.map_or(false, |team| team.id == Team::DEFAULT_NPC_TEAM_ID) {
+    let color = if monster_team.map_or(false, |team| team.id == Team::DEFAULT_NPC_TEAM_ID) {
         egui::Color32::GREEN
     } else if level_diff <= -23 {
         egui::Color32::from_rgb(224, 149, 255)
@@ -101,7 +118,9 @@ pub fn get_monster_name_tag_color(
         egui::Color32::from_rgb(202, 243, 255)
     } else {
         egui::Color32::from_rgb(217, 217, 217)
-    }
+    };
+
+    current_accessibility().colorblind_mode.remap(color)
 }
 
 fn create_pending_nametag(
@@ -112,19 +131,37 @@ fn create_pending_nametag(
     name_tag_type: NameTagType,
 ) -> NameTagPendingData {
     let layout_job = match name_tag_type {
-        NameTagType::Character => egui::epaint::text::LayoutJob::single_section(
-            object.name.name.clone(),
-            egui::TextFormat::simple(
-                egui::FontId::proportional(name_tag_settings.font_size[name_tag_type]),
-                if object.team.map_or(false, |team| {
-                    Some(team.id) != player.map(|player| player.team.id)
-                }) {
-                    egui::Color32::RED
-                } else {
-                    egui::Color32::WHITE
-                },
-            ),
-        ),
+        NameTagType::Character => {
+            let font_id = egui::FontId::proportional(name_tag_settings.font_size[name_tag_type]);
+            let mut layout_job = egui::epaint::text::LayoutJob::single_section(
+                object.name.name.clone(),
+                egui::TextFormat::simple(
+                    font_id.clone(),
+                    if object.team.map_or(false, |team| {
+                        Some(team.id) != player.map(|player| player.team.id)
+                    }) {
+                        current_accessibility().negative_color()
+                    } else {
+                        egui::Color32::WHITE
+                    },
+                ),
+            );
+
+            if let Some(afk) = object.afk {
+                let mut message: String =
+                    afk.message.chars().take(MAX_AFK_NAME_TAG_CHARS).collect();
+                if message.len() < afk.message.len() {
+                    message.push_str("...");
+                }
+
+                layout_job.append(
+                    &format!("\n<AFK> {}", message),
+                    0.0,
+                    egui::TextFormat::simple(font_id, egui::Color32::from_rgb(180, 180, 180)),
+                );
+            }
+            layout_job
+        }
         NameTagType::Monster => egui::epaint::text::LayoutJob::single_section(
             object.name.name.clone(),
             egui::TextFormat::simple(
@@ -438,7 +475,11 @@ pub fn name_tag_system(
     mut commands: Commands,
     mut name_tag_cache: Local<NameTagCache>,
     query_add: Query<NameTagObjectQuery, Without<NameTagEntity>>,
-    query_changed: Query<(Entity, Option<&NameTagEntity>), Changed<ClientEntityName>>,
+    query_changed: Query<
+        (Entity, Option<&NameTagEntity>),
+        Or<(Changed<ClientEntityName>, Changed<AfkStatus>)>,
+    >,
+    mut removed_afk: RemovedComponents<AfkStatus>,
     query_player: Query<PlayerQuery, With<PlayerCharacter>>,
     query_nametags: Query<(Entity, &NameTagEntity)>,
     query_camera: Query<Entity, (With<Camera>, With<PrimaryEguiContext>)>,
@@ -474,7 +515,13 @@ pub fn name_tag_system(
         return;
     }
 
-    for (entity, name_tag_entity) in query_changed.iter() {
+    let removed_afk_entities: Vec<Entity> = removed_afk.read().collect();
+    for (entity, name_tag_entity) in query_changed.iter().chain(
+        removed_afk_entities
+            .iter()
+            .filter_map(|&entity| query_nametags.get(entity).ok())
+            .map(|(entity, name_tag_entity)| (entity, Some(name_tag_entity))),
+    ) {
         // Despawn previous name tag
         if let Some(name_tag_entity) = name_tag_entity {
             commands.entity(entity).remove::<NameTagEntity>();
@@ -511,8 +558,8 @@ pub fn name_tag_system(
             NameTagType::Character
         };
 
-        let name_tag_data = if let Some(name_tag_data) = name_tag_cache.cache.get(&object.name.name)
-        {
+        let cache_key = object.cache_key();
+        let name_tag_data = if let Some(name_tag_data) = name_tag_cache.cache.get(&cache_key) {
             name_tag_data
         } else if let Some(pending_name_tag_data) = name_tag_cache.pending.remove(&object.entity) {
             if let Some(name_tag_data) = create_nametag_data(
@@ -523,10 +570,8 @@ pub fn name_tag_system(
                 pending_name_tag_data.clone(),
                 object.entity,
             ) {
-                name_tag_cache
-                    .cache
-                    .insert(object.name.name.clone(), name_tag_data);
-                name_tag_cache.cache.get(&object.name.name).unwrap()
+                name_tag_cache.cache.insert(cache_key.clone(), name_tag_data);
+                name_tag_cache.cache.get(&cache_key).unwrap()
             } else {
                 // Re-insert pending data to try again next frame
                 name_tag_cache.pending.insert(object.entity, pending_name_tag_data);
@@ -731,6 +776,28 @@ pub fn name_tag_system(
                 .insert(ChildOf(name_tag_entity));
         }
 
+        if matches!(name_tag_type, NameTagType::Monster) {
+            // Threat marks reuse the target mark sprite tinted red, they are shown by
+            // name_tag_update_threat_system while the monster is targeting the player.
+            for rect in target_marks.iter() {
+                commands
+                    .spawn((
+                        NameTagThreatMark,
+                        NoFrustumCulling,
+                        WorldUiRect {
+                            color: Color::srgb(1.0, 0.15, 0.15),
+                            order: ORDER_THREAT_MARK,
+                            ..rect.clone()
+                        },
+                        Transform::default(),
+                        GlobalTransform::default(),
+                        Visibility::Hidden,
+                        VisibilityClass::default(),
+                    ))
+                    .insert(ChildOf(name_tag_entity));
+            }
+        }
+
         for rect in target_marks.drain(..) {
             commands
                 .spawn((
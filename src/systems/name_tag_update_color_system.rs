@@ -1,7 +1,7 @@
 use bevy::{
     color::Srgba,
     ecs::query::QueryData,
-    prelude::{Changed, ChildOf, Children, Color, Or, Query, With},
+    prelude::{Changed, ChildOf, Children, Color, Or, Query, Res, With},
 };
 
 use rose_game_common::components::{Level, Team};
@@ -9,6 +9,7 @@ use rose_game_common::components::{Level, Team};
 use crate::{
     components::{NameTag, NameTagName, NameTagType, PlayerCharacter},
     render::WorldUiRect,
+    resources::AccessibilitySettings,
     systems::name_tag_system::get_monster_name_tag_color,
 };
 
@@ -19,12 +20,18 @@ pub struct PlayerQuery<'w> {
 }
 
 pub fn name_tag_update_color_system(
-    query_player: Query<PlayerQuery, (With<PlayerCharacter>, Or<(Changed<Level>, Changed<Team>)>)>,
+    accessibility_settings: Res<AccessibilitySettings>,
+    query_player: Query<PlayerQuery, With<PlayerCharacter>>,
+    query_player_changed: Query<(), (With<PlayerCharacter>, Or<(Changed<Level>, Changed<Team>)>)>,
     query_nametags: Query<(&ChildOf, &NameTag, &Children)>,
     query_level: Query<&Level>,
     query_team: Query<&Team>,
     mut query_name_rects: Query<&mut WorldUiRect, With<NameTagName>>,
 ) {
+    if query_player_changed.is_empty() && !accessibility_settings.is_changed() {
+        return;
+    }
+
     let player = if let Ok(player) = query_player.single() {
         player
     } else {
@@ -39,7 +46,8 @@ pub fn name_tag_update_color_system(
                     .get(parent.0)
                     .map_or(false, |team| team.id != player.team.id)
                 {
-                    Color::Srgba(Srgba::RED)
+                    let color = accessibility_settings.negative_color().to_array();
+                    Color::srgb_u8(color[0], color[1], color[2])
                 } else {
                     Color::Srgba(Srgba::WHITE)
                 }
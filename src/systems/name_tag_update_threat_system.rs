@@ -0,0 +1,52 @@
+use bevy::{
+    color::Alpha,
+    prelude::{ChildOf, Children, Color, Query, Res, Visibility, With},
+};
+
+use crate::{
+    components::{NameTag, NameTagThreatMark, ThreatState},
+    render::WorldUiRect,
+    resources::{AccessibilitySettings, NameTagSettings},
+};
+
+/// Pulse frequency of the threat mark in cycles per second
+const THREAT_PULSE_FREQUENCY: f32 = 1.5;
+
+pub fn name_tag_update_threat_system(
+    query_nametags: Query<(&ChildOf, &Children), With<NameTag>>,
+    query_threat: Query<&ThreatState>,
+    mut query_threat_marks: Query<(&mut Visibility, &mut WorldUiRect), With<NameTagThreatMark>>,
+    name_tag_settings: Res<NameTagSettings>,
+    accessibility_settings: Res<AccessibilitySettings>,
+) {
+    let threat_color = accessibility_settings.negative_color().to_array();
+    for (parent, children) in query_nametags.iter() {
+        let threat_state = if name_tag_settings.show_threat_indicator {
+            query_threat.get(parent.0).ok()
+        } else {
+            None
+        };
+
+        for &child_entity in children.iter() {
+            let Ok((mut visibility, mut rect)) = query_threat_marks.get_mut(child_entity) else {
+                continue;
+            };
+
+            if let Some(threat_state) = threat_state {
+                let pulse = 0.5
+                    + 0.5
+                        * (threat_state.pulse_time * THREAT_PULSE_FREQUENCY * std::f32::consts::TAU)
+                            .sin();
+                let alpha = 0.6 + 0.4 * pulse;
+                rect.color = Color::srgb_u8(threat_color[0], threat_color[1], threat_color[2])
+                    .with_alpha(alpha);
+
+                if *visibility != Visibility::Inherited {
+                    *visibility = Visibility::Inherited;
+                }
+            } else if *visibility != Visibility::Hidden {
+                *visibility = Visibility::Hidden;
+            }
+        }
+    }
+}
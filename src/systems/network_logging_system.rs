@@ -0,0 +1,34 @@
+use std::path::Path;
+
+use bevy::prelude::{DetectChanges, DetectChangesMut, Res, ResMut};
+
+use crate::resources::{NetworkLogging, NetworkThread};
+
+pub fn network_logging_system(
+    mut network_logging: ResMut<NetworkLogging>,
+    network_thread: Res<NetworkThread>,
+) {
+    if !network_logging.is_changed() {
+        return;
+    }
+
+    let packet_logger = &network_thread.packet_logger;
+    packet_logger.set_opcode_filter(network_logging.parse_opcode_filter());
+    packet_logger.set_max_entries(network_logging.max_entries);
+
+    let log_file_path = if network_logging.enabled && network_logging.log_to_file {
+        Some(Path::new(&network_logging.log_file_path))
+    } else {
+        None
+    };
+    if let Err(error) = packet_logger.set_log_file(log_file_path) {
+        log::error!(
+            "Failed to open packet log file {}: {}",
+            network_logging.log_file_path,
+            error
+        );
+        network_logging.bypass_change_detection().log_to_file = false;
+    }
+
+    packet_logger.set_enabled(network_logging.enabled);
+}
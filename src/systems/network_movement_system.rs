@@ -0,0 +1,152 @@
+use bevy::{
+    math::Vec3Swizzles,
+    prelude::{Commands, Entity, Has, Query, Res, Time, Without},
+};
+
+use crate::{
+    components::{
+        Command, PlayerCharacter, Position, PositionCorrection, RemotePositionBuffer,
+        ServerPositionUpdate,
+    },
+    resources::NetworkMovementSettings,
+};
+
+/// Remote positions closer than this (in cm) are considered reached
+const REMOTE_POSITION_EPSILON: f32 = 1.0;
+
+/// Reconciles authoritative server positions against the client simulation.
+///
+/// The local player is already moving on its predicted path, so small errors
+/// are smoothed out over `correction_duration` and large errors snap. Remote
+/// entities have the position buffered for `network_movement_playback_system`.
+pub fn network_movement_reconcile_system(
+    mut commands: Commands,
+    mut query: Query<(
+        Entity,
+        &ServerPositionUpdate,
+        &mut Position,
+        Option<&mut RemotePositionBuffer>,
+        Has<PlayerCharacter>,
+    )>,
+    network_movement_settings: Res<NetworkMovementSettings>,
+    time: Res<Time>,
+) {
+    for (entity, server_position, mut position, remote_position_buffer, is_player) in
+        query.iter_mut()
+    {
+        commands.entity(entity).remove::<ServerPositionUpdate>();
+
+        if is_player {
+            let delta = server_position.position.xy() - position.xy();
+
+            if delta.length() > network_movement_settings.max_smooth_correction {
+                position.x = server_position.position.x;
+                position.y = server_position.position.y;
+                commands.entity(entity).remove::<PositionCorrection>();
+            } else {
+                commands.entity(entity).insert(PositionCorrection {
+                    remaining: delta.extend(0.0),
+                    remaining_secs: network_movement_settings.correction_duration,
+                });
+            }
+        } else {
+            let sample = (time.elapsed_secs_f64(), server_position.position);
+
+            if let Some(mut remote_position_buffer) = remote_position_buffer {
+                remote_position_buffer.samples.push_back(sample);
+            } else {
+                let mut remote_position_buffer = RemotePositionBuffer::default();
+                remote_position_buffer.samples.push_back(sample);
+                commands.entity(entity).insert(remote_position_buffer);
+            }
+        }
+    }
+}
+
+/// Applies pending player corrections and plays back buffered remote positions
+/// `interpolation_delay` seconds behind the server.
+pub fn network_movement_playback_system(
+    mut commands: Commands,
+    mut query_corrections: Query<(Entity, &mut Position, &mut PositionCorrection)>,
+    mut query_remote: Query<
+        (&mut Position, &Command, &mut RemotePositionBuffer),
+        (Without<PlayerCharacter>, Without<PositionCorrection>),
+    >,
+    network_movement_settings: Res<NetworkMovementSettings>,
+    time: Res<Time>,
+) {
+    let delta_secs = time.delta_secs();
+
+    if !network_movement_settings.enabled {
+        for (entity, _, _) in query_corrections.iter() {
+            commands.entity(entity).remove::<PositionCorrection>();
+        }
+
+        for (_, _, mut remote_position_buffer) in query_remote.iter_mut() {
+            remote_position_buffer.samples.clear();
+        }
+        return;
+    }
+
+    for (entity, mut position, mut correction) in query_corrections.iter_mut() {
+        let fraction = if correction.remaining_secs <= delta_secs {
+            1.0
+        } else {
+            delta_secs / correction.remaining_secs
+        };
+        let step = correction.remaining * fraction;
+
+        position.x += step.x;
+        position.y += step.y;
+        correction.remaining -= step;
+        correction.remaining_secs -= delta_secs;
+
+        if correction.remaining_secs <= 0.0 {
+            commands.entity(entity).remove::<PositionCorrection>();
+        }
+    }
+
+    let playback_time =
+        time.elapsed_secs_f64() - network_movement_settings.interpolation_delay as f64;
+    let interpolation_fraction = if network_movement_settings.correction_duration > 0.0 {
+        (delta_secs / network_movement_settings.correction_duration).min(1.0)
+    } else {
+        1.0
+    };
+
+    for (mut position, command, mut remote_position_buffer) in query_remote.iter_mut() {
+        // Skip ahead to the newest sample which is due for playback
+        while remote_position_buffer.samples.len() > 1
+            && remote_position_buffer.samples[1].0 <= playback_time
+        {
+            remote_position_buffer.samples.pop_front();
+        }
+
+        let Some(&(received_time, target)) = remote_position_buffer.samples.front() else {
+            continue;
+        };
+
+        if received_time > playback_time {
+            continue;
+        }
+
+        // A moving entity is driven by its move command, not by position samples
+        if matches!(command, Command::Move(_)) {
+            remote_position_buffer.samples.pop_front();
+            continue;
+        }
+
+        let delta = target.xy() - position.xy();
+        let distance = delta.length();
+        if distance <= REMOTE_POSITION_EPSILON
+            || distance > network_movement_settings.max_smooth_correction
+        {
+            position.x = target.x;
+            position.y = target.y;
+            remote_position_buffer.samples.pop_front();
+        } else {
+            position.x += delta.x * interpolation_fraction;
+            position.y += delta.y * interpolation_fraction;
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use bevy::prelude::{Res, ResMut, Time};
+
+use crate::{
+    protocol::ProtocolStatsMessage,
+    resources::{NetworkConnectionState, NetworkStats, NetworkThread},
+};
+
+pub fn network_stats_system(
+    network_thread: Res<NetworkThread>,
+    mut network_stats: ResMut<NetworkStats>,
+    time: Res<Time>,
+) {
+    while let Ok(message) = network_thread.stats_rx.try_recv() {
+        match message {
+            ProtocolStatsMessage::Connecting(connection) => {
+                network_stats.connection_mut(connection).state =
+                    NetworkConnectionState::Connecting;
+            }
+            ProtocolStatsMessage::Connected(connection) => {
+                network_stats.connection_mut(connection).state = NetworkConnectionState::Connected;
+            }
+            ProtocolStatsMessage::Disconnected(connection) => {
+                network_stats.connection_mut(connection).state =
+                    NetworkConnectionState::Disconnected;
+            }
+            ProtocolStatsMessage::PacketSent { connection, bytes } => {
+                network_stats.connection_mut(connection).record_sent(bytes);
+            }
+            ProtocolStatsMessage::PacketReceived { connection, bytes } => {
+                network_stats
+                    .connection_mut(connection)
+                    .record_received(bytes);
+            }
+            ProtocolStatsMessage::PacketTiming { elapsed, .. } => {
+                network_stats.record_packet_timing(elapsed.as_secs_f32() * 1000.0);
+            }
+        }
+    }
+
+    network_stats.update_rates(time.delta_secs());
+}
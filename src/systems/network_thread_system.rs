@@ -7,7 +7,7 @@ use rose_game_common::{
 
 use crate::{
     events::NetworkEvent,
-    protocol::irose,
+    protocol::{ProtocolClientChannels, ProtocolConnectionKind},
     resources::{
         GameConnection, LoginConnection, NetworkThread, NetworkThreadMessage, WorldConnection,
     },
@@ -19,53 +19,47 @@ pub fn network_thread_system(
     mut network_events: MessageReader<NetworkEvent>,
 ) {
     for event in network_events.read() {
-        match *event {
-            NetworkEvent::ConnectLogin { ref ip, port } => {
-                let (server_message_tx, server_message_rx) =
-                    crossbeam_channel::unbounded::<ServerMessage>();
-                let (client_message_tx, client_message_rx) =
-                    tokio::sync::mpsc::unbounded_channel::<ClientMessage>();
-                let server_address = format!("{}:{}", ip, port).parse().unwrap();
+        let (server_message_tx, server_message_rx) =
+            crossbeam_channel::unbounded::<ServerMessage>();
+        let (client_message_tx, client_message_rx) =
+            tokio::sync::mpsc::unbounded_channel::<ClientMessage>();
+        let connection = match event {
+            NetworkEvent::ConnectLogin { .. } => ProtocolConnectionKind::Login,
+            NetworkEvent::ConnectWorld { .. } => ProtocolConnectionKind::World,
+            NetworkEvent::ConnectGame { .. } => ProtocolConnectionKind::Game,
+        };
 
+        // If the backend fails to connect the server channel is dropped, which
+        // the connection systems will report as a disconnect
+        match network_thread.backend.connect(
+            event,
+            ProtocolClientChannels {
+                client_message_rx,
+                server_message_tx,
+                stats: network_thread.protocol_stats(connection),
+            },
+        ) {
+            Ok(client) => {
                 network_thread
                     .control_tx
-                    .send(NetworkThreadMessage::RunProtocolClient(Box::new(
-                        irose::LoginClient::new(
-                            server_address,
-                            client_message_rx,
-                            server_message_tx,
-                        ),
-                    )))
+                    .send(NetworkThreadMessage::RunProtocolClient(client))
                     .ok();
+            }
+            Err(error) => {
+                log::error!("Failed to create {:?} connection: {}", connection, error);
+            }
+        }
 
+        match *event {
+            NetworkEvent::ConnectLogin { .. } => {
                 commands
                     .insert_resource(LoginConnection::new(client_message_tx, server_message_rx));
             }
             NetworkEvent::ConnectWorld {
-                ref ip,
-                port,
-                packet_codec_seed,
                 login_token,
                 ref password,
+                ..
             } => {
-                let (server_message_tx, server_message_rx) =
-                    crossbeam_channel::unbounded::<ServerMessage>();
-                let (client_message_tx, client_message_rx) =
-                    tokio::sync::mpsc::unbounded_channel::<ClientMessage>();
-                let server_address = format!("{}:{}", ip, port).parse().unwrap();
-
-                network_thread
-                    .control_tx
-                    .send(NetworkThreadMessage::RunProtocolClient(Box::new(
-                        irose::WorldClient::new(
-                            server_address,
-                            packet_codec_seed,
-                            client_message_rx,
-                            server_message_tx,
-                        ),
-                    )))
-                    .ok();
-
                 commands.insert_resource(WorldConnection::new(
                     client_message_tx,
                     server_message_rx,
@@ -74,30 +68,10 @@ pub fn network_thread_system(
                 ));
             }
             NetworkEvent::ConnectGame {
-                ref ip,
-                port,
-                packet_codec_seed,
                 login_token,
                 ref password,
+                ..
             } => {
-                let (server_message_tx, server_message_rx) =
-                    crossbeam_channel::unbounded::<ServerMessage>();
-                let (client_message_tx, client_message_rx) =
-                    tokio::sync::mpsc::unbounded_channel::<ClientMessage>();
-                let server_address = format!("{}:{}", ip, port).parse().unwrap();
-
-                network_thread
-                    .control_tx
-                    .send(NetworkThreadMessage::RunProtocolClient(Box::new(
-                        irose::GameClient::new(
-                            server_address,
-                            packet_codec_seed,
-                            client_message_rx,
-                            server_message_tx,
-                        ),
-                    )))
-                    .ok();
-
                 commands.insert_resource(GameConnection::new(
                     client_message_tx,
                     server_message_rx,
@@ -0,0 +1,74 @@
+use bevy::{
+    camera::visibility::VisibilityRange,
+    prelude::{Added, Commands, Entity, Mesh3d, Query, Res, With, Without},
+    render::{
+        render_resource::{DownlevelFlags, WgpuFeatures},
+        renderer::{RenderAdapter, RenderDevice},
+    },
+};
+
+use crate::{
+    components::ZoneObject,
+    resources::{OcclusionCullingSettings, OcclusionCullingState},
+};
+
+/// Returns why GPU occlusion culling cannot run on this adapter, or None if it
+/// is supported. Bevy's implementation relies on GPU preprocessing and
+/// indirect draws, which many older and integrated GPUs do not provide.
+pub fn gpu_occlusion_culling_unsupported_reason(
+    render_device: Option<&RenderDevice>,
+    render_adapter: Option<&RenderAdapter>,
+) -> Option<&'static str> {
+    let (Some(render_device), Some(render_adapter)) = (render_device, render_adapter) else {
+        return Some("render device is not available");
+    };
+
+    if !render_adapter
+        .get_downlevel_capabilities()
+        .flags
+        .contains(DownlevelFlags::COMPUTE_SHADERS)
+    {
+        return Some("compute shaders are not supported");
+    }
+
+    let features = render_device.features();
+    if !features.contains(WgpuFeatures::INDIRECT_FIRST_INSTANCE) {
+        return Some("indirect first instance is not supported");
+    }
+
+    if !features.contains(WgpuFeatures::MULTI_DRAW_INDIRECT_COUNT) {
+        return Some("multi draw indirect count is not supported");
+    }
+
+    None
+}
+
+/// When GPU occlusion culling is off, give large zone objects a visibility
+/// range so distant buildings and decorations are culled on the CPU instead.
+pub fn occlusion_culling_fallback_system(
+    mut commands: Commands,
+    occlusion_culling_settings: Res<OcclusionCullingSettings>,
+    occlusion_culling_state: Res<OcclusionCullingState>,
+    query_added: Query<
+        (Entity, &ZoneObject),
+        (Added<ZoneObject>, With<Mesh3d>, Without<VisibilityRange>),
+    >,
+) {
+    if occlusion_culling_state.gpu_enabled
+        || !occlusion_culling_settings.fallback_distance_culling
+    {
+        return;
+    }
+
+    for (entity, zone_object) in query_added.iter() {
+        if matches!(
+            zone_object,
+            ZoneObject::CnstObjectPart(_) | ZoneObject::DecoObjectPart(_)
+        ) {
+            commands.entity(entity).insert(VisibilityRange::abrupt(
+                0.0,
+                occlusion_culling_settings.fallback_distance,
+            ));
+        }
+    }
+}
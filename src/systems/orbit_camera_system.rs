@@ -19,8 +19,12 @@ use bevy_rapier3d::{
 };
 use dolly::prelude::{Arm, CameraRig, LeftHanded, Position, Smooth, YawPitch};
 
-use crate::components::{
-    COLLISION_FILTER_COLLIDABLE, COLLISION_FILTER_MOVEABLE, COLLISION_GROUP_PHYSICS_TOY,
+use crate::{
+    components::{
+        ThreatState, COLLISION_FILTER_COLLIDABLE, COLLISION_FILTER_MOVEABLE,
+        COLLISION_GROUP_PHYSICS_TOY,
+    },
+    resources::{IdleCameraSettings, InputActivity},
 };
 
 #[derive(Component)]
@@ -69,6 +73,8 @@ impl OrbitCamera {
 pub struct CameraControlState {
     pub is_dragging: bool,
     pub saved_cursor_position: Option<Vec2>,
+    /// Yaw in radians the idle orbit has turned the camera around the player
+    pub idle_yaw_offset: f32,
 }
 
 pub fn orbit_camera_system(
@@ -82,6 +88,9 @@ pub fn orbit_camera_system(
     mouse_buttons: Res<ButtonInput<MouseButton>>,
     time: Res<Time>,
     rapier_context: ReadRapierContext,
+    idle_camera_settings: Res<IdleCameraSettings>,
+    input_activity: Res<InputActivity>,
+    query_threat: Query<(), With<ThreatState>>,
 ) -> Result<(), BevyError> {
     let Ok(rapier_context) = rapier_context.single() else {
         return Ok(());
@@ -270,7 +279,33 @@ pub fn orbit_camera_system(
         calculated_transform.rotation.v.z,
         calculated_transform.rotation.s,
     );
-    
+
+    // Slowly orbit the player while idle, the offset is applied on top of the rig
+    // so any input returns the camera to where the player left it
+    let orbit_ramp = if query_threat.is_empty() {
+        idle_camera_settings.orbit_ramp(input_activity.idle_for(time.elapsed_secs()))
+    } else {
+        0.0
+    };
+    if orbit_ramp > 0.0 {
+        control_state.idle_yaw_offset = (control_state.idle_yaw_offset
+            + idle_camera_settings.orbit_speed.to_radians()
+                * orbit_ramp
+                * time.delta().as_secs_f32())
+        .rem_euclid(std::f32::consts::TAU);
+    } else {
+        control_state.idle_yaw_offset = 0.0;
+    }
+
+    if control_state.idle_yaw_offset != 0.0 {
+        let idle_rotation = Quat::from_rotation_y(control_state.idle_yaw_offset);
+        let pivot = orbit_camera.rig.driver::<Position>().position;
+        let follow_position = Vec3::new(pivot.x, pivot.y, pivot.z);
+        camera_transform.translation =
+            follow_position + idle_rotation * (camera_transform.translation - follow_position);
+        camera_transform.rotation = idle_rotation * camera_transform.rotation;
+    }
+
     Ok(())
 }
 
@@ -1,23 +1,27 @@
-use std::ops::RangeInclusive;
+use std::{collections::BTreeMap, ops::RangeInclusive};
 
 use bevy::{
     asset::{AssetServer, Assets, Handle, LoadState, RenderAssetUsages},
     log::{debug, error, info, warn},
     math::{Quat, Vec2, Vec3, Vec4},
-    prelude::{Commands, Component, Entity, GlobalTransform, Mesh3d, MeshMaterial3d, Query, Res, ResMut, Resource, Time, Transform},
+    prelude::{
+        Added, ChildOf, Commands, Component, Entity, GlobalTransform, Has, Local, Mesh3d,
+        MeshMaterial3d, Query, Res, ResMut, Resource, Time, Transform, Without,
+    },
     render::{
         alpha::AlphaMode, render_resource::{Extent3d, TextureDimension, TextureFormat}, storage::ShaderStorageBuffer
     },
 };
 use bevy_image::{Image, ImageSampler};
 use bevy_mesh::{Indices, Mesh, PrimitiveTopology};
-use rand::Rng;
+use rand::{rngs::StdRng, Rng};
 
 use rose_file_readers::{PtlKeyframeData, PtlUpdateCoords};
 
 use crate::{
-    components::{ActiveParticle, ParticleSequence},
+    components::{ActiveParticle, ParticleCategory, ParticleSequence, ZoneObject},
     render::{ParticleMaterial, ParticleRenderData},
+    resources::{ParticleBudget, ParticleBudgetStats, RngSeed},
 };
 
 /// Resource holding the default white particle texture
@@ -321,20 +325,79 @@ fn apply_keyframes<R: Rng>(
     }
 }
 
+/// Gives new particle emitters the category of the effect they belong to.
+/// Effects inside zone objects are ambient, anything without a category set on
+/// one of its ancestors is treated as a character effect.
+pub fn particle_category_system(
+    mut commands: Commands,
+    query_added: Query<Entity, (Added<ParticleSequence>, Without<ParticleCategory>)>,
+    query_ancestors: Query<(Option<&ParticleCategory>, Has<ZoneObject>, Option<&ChildOf>)>,
+) {
+    for entity in query_added.iter() {
+        let mut category = ParticleCategory::Character;
+        let mut current = Some(entity);
+
+        while let Some(ancestor) = current {
+            let Ok((ancestor_category, is_zone_object, child_of)) = query_ancestors.get(ancestor)
+            else {
+                break;
+            };
+
+            if let Some(ancestor_category) = ancestor_category {
+                category = *ancestor_category;
+                break;
+            }
+
+            if is_zone_object {
+                category = ParticleCategory::Ambient;
+                break;
+            }
+
+            current = child_of.map(|child_of| child_of.parent());
+        }
+
+        commands.entity(entity).insert(category);
+    }
+}
+
 pub fn particle_sequence_system(
     mut commands: Commands,
     time: Res<Time>,
+    particle_budget: Res<ParticleBudget>,
+    mut particle_budget_stats: ResMut<ParticleBudgetStats>,
     mut query: Query<(
         Entity,
         &GlobalTransform,
         &mut ParticleSequence,
         &mut ParticleRenderData,
+        Option<&ParticleCategory>,
     )>,
+    rng_seed: Res<RngSeed>,
+    mut rng: Local<Option<StdRng>>,
 ) {
-    let mut rng = rand::thread_rng();
+    let rng = rng.get_or_insert_with(|| rng_seed.rng("particles"));
     let delta_time = time.delta_secs();
 
-    for (entity, global_transform, mut particle_sequence, mut particle_render_data) in query.iter_mut() {
+    // Count live particles per priority so the budget can throttle the lowest
+    // priority emitters first
+    let mut live_by_priority = BTreeMap::<u8, usize>::new();
+    for (_, _, particle_sequence, _, category) in query.iter() {
+        let priority =
+            particle_budget.priority(category.copied().unwrap_or(ParticleCategory::Character));
+        *live_by_priority.entry(priority).or_default() += particle_sequence.particles.len();
+    }
+    let mut throttled_emitters = 0;
+
+    for (entity, global_transform, mut particle_sequence, mut particle_render_data, category) in
+        query.iter_mut()
+    {
+        let priority =
+            particle_budget.priority(category.copied().unwrap_or(ParticleCategory::Character));
+
+        if !particle_sequence.keyframe_start_times_selected {
+            particle_sequence.select_keyframe_start_times(rng);
+        }
+
         if particle_sequence.start_delay > 0.0 {
             particle_sequence.start_delay -= delta_time;
             if particle_sequence.start_delay > 0.0 {
@@ -353,27 +416,31 @@ pub fn particle_sequence_system(
                     4.8_f32 * particle_sequence.particles[particle_index].gravity_local
                 } else {
                     Vec3::new(
-                        rng_gen_range(&mut rng, &particle_sequence.gravity_x),
-                        rng_gen_range(&mut rng, &particle_sequence.gravity_y),
-                        rng_gen_range(&mut rng, &particle_sequence.gravity_z),
+                        rng_gen_range(rng, &particle_sequence.gravity_x),
+                        rng_gen_range(rng, &particle_sequence.gravity_y),
+                        rng_gen_range(rng, &particle_sequence.gravity_z),
                     )
                 };
 
                 particle_sequence.particles[particle_index].velocity += gravity * delta_time;
 
-                apply_keyframes(&mut rng, &mut particle_sequence, particle_index);
+                apply_keyframes(rng, &mut particle_sequence, particle_index);
             }
         }
 
         // Cleanup any dead particles
+        let num_particles_before = particle_sequence.particles.len();
         particle_sequence
             .particles
             .retain(|particle| particle.age < particle.life);
+        if let Some(live) = live_by_priority.get_mut(&priority) {
+            *live -= num_particles_before - particle_sequence.particles.len();
+        }
 
         // Spawn any new particles
         if !particle_sequence.finished {
             particle_sequence.emit_counter +=
-                delta_time * rng_gen_range(&mut rng, &particle_sequence.emit_rate);
+                delta_time * rng_gen_range(rng, &particle_sequence.emit_rate);
 
             if particle_sequence.num_loops > 0 {
                 let particle_limit = particle_sequence.num_loops * particle_sequence.num_particles;
@@ -384,18 +451,28 @@ pub fn particle_sequence_system(
                 }
             }
 
+            if particle_sequence.emit_counter > 1.0
+                && !particle_budget.may_emit(&live_by_priority, priority)
+            {
+                // Over budget, skip this emission rather than saving it up
+                // for a burst once there is room again
+                particle_sequence.emit_counter = particle_sequence.emit_counter.min(1.0);
+                throttled_emitters += 1;
+            }
+
             // Spawn new particles
             while particle_sequence.emit_counter > 1.0
                 && particle_sequence.particles.len() < particle_sequence.num_particles as usize
+                && particle_budget.may_emit(&live_by_priority, priority)
             {
                 log::info!("[PARTICLE SEQUENCE] Spawning particle: {} -> {} particles, emit_rate={:?}", 
                     particle_sequence.particles.len(), particle_sequence.particles.len() + 1,
                     particle_sequence.emit_rate);
                 
                 let mut position = Vec3::new(
-                    rng_gen_range(&mut rng, &particle_sequence.emit_radius_x),
-                    rng_gen_range(&mut rng, &particle_sequence.emit_radius_y),
-                    rng_gen_range(&mut rng, &particle_sequence.emit_radius_z),
+                    rng_gen_range(rng, &particle_sequence.emit_radius_x),
+                    rng_gen_range(rng, &particle_sequence.emit_radius_y),
+                    rng_gen_range(rng, &particle_sequence.emit_radius_z),
                 );
                 let mut gravity_local = Vec3::default();
                 let mut world_direction = None;
@@ -411,9 +488,9 @@ pub fn particle_sequence_system(
                     );
                     world_direction = Some(rotation);
                     gravity_local = rotation.inverse().mul_vec3(Vec3::new(
-                        rng_gen_range(&mut rng, &particle_sequence.gravity_x),
-                        rng_gen_range(&mut rng, &particle_sequence.gravity_y),
-                        rng_gen_range(&mut rng, &particle_sequence.gravity_z),
+                        rng_gen_range(rng, &particle_sequence.gravity_x),
+                        rng_gen_range(rng, &particle_sequence.gravity_y),
+                        rng_gen_range(rng, &particle_sequence.gravity_z),
                     ));
                     position = rotation.mul_vec3(position);
                     position.x += global_translation.x * 100.0;
@@ -421,7 +498,7 @@ pub fn particle_sequence_system(
                     position.z += global_translation.y * 100.0;
                 }
 
-                let life = rng_gen_range(&mut rng, &particle_sequence.particle_life);
+                let life = rng_gen_range(rng, &particle_sequence.particle_life);
                 let particle_index = particle_sequence.particles.len();
                 particle_sequence.particles.push(ActiveParticle::new(
                     life,
@@ -431,10 +508,11 @@ pub fn particle_sequence_system(
                 ));
 
                 // Apply initial keyframes
-                apply_keyframes(&mut rng, &mut particle_sequence, particle_index);
+                apply_keyframes(rng, &mut particle_sequence, particle_index);
 
                 particle_sequence.num_emitted += 1;
                 particle_sequence.emit_counter -= 1.0;
+                *live_by_priority.entry(priority).or_default() += 1;
             }
         }
 
@@ -489,6 +567,9 @@ pub fn particle_sequence_system(
             continue;
         }
     }
+
+    particle_budget_stats.live_particles = live_by_priority.values().sum();
+    particle_budget_stats.throttled_emitters = throttled_emitters;
 }
 
 /// Updates GPU storage buffers with particle render data.
@@ -0,0 +1,205 @@
+use bevy::{
+    platform::collections::HashSet,
+    prelude::{
+        ChildOf, Children, Color, Commands, Entity, GlobalTransform, Query, Res, Transform, Vec2,
+        Vec3, Visibility, With,
+    },
+};
+use bevy_camera::visibility::{NoFrustumCulling, VisibilityClass};
+
+use rose_game_common::{
+    components::{AbilityValues, HealthPoints},
+    messages::server::PartyMemberInfo,
+};
+
+use crate::{
+    components::{
+        ModelHeight, PartyInfo, PartyMemberMarker, PartyMemberMarkerEntity,
+        PartyMemberMarkerHealthbar, PlayerCharacter,
+    },
+    render::WorldUiRect,
+    resources::{ClientEntityList, PartyMarkerSettings, UiResources},
+};
+
+const ORDER_MARKER_BACKGROUND: u8 = 0;
+const ORDER_MARKER_FOREGROUND: u8 = 1;
+
+/// Screen space offset of the marker above the entity's name tag
+const MARKER_OFFSET_Y: f32 = 42.0;
+
+/// Returns the entities of all online party members which are in the current zone
+pub fn get_party_member_entities(
+    party_info: &PartyInfo,
+    client_entity_list: &ClientEntityList,
+) -> Vec<Entity> {
+    party_info
+        .members
+        .iter()
+        .filter_map(|member| match member {
+            PartyMemberInfo::Online(member_info) => client_entity_list.get(member_info.entity_id),
+            PartyMemberInfo::Offline(_) => None,
+        })
+        .filter(|entity| Some(*entity) != client_entity_list.player_entity)
+        .collect()
+}
+
+pub fn party_marker_system(
+    mut commands: Commands,
+    query_player: Query<(&GlobalTransform, Option<&PartyInfo>), With<PlayerCharacter>>,
+    query_member: Query<(&GlobalTransform, Option<&ModelHeight>, Option<&PartyMemberMarkerEntity>)>,
+    query_marked: Query<(Entity, &PartyMemberMarkerEntity)>,
+    query_health: Query<(&HealthPoints, &AbilityValues)>,
+    query_marker_children: Query<&Children, With<PartyMemberMarker>>,
+    mut query_healthbar: Query<(&PartyMemberMarkerHealthbar, &mut WorldUiRect)>,
+    mut query_visibility: Query<&mut Visibility, With<PartyMemberMarker>>,
+    client_entity_list: Res<ClientEntityList>,
+    party_marker_settings: Res<PartyMarkerSettings>,
+    ui_resources: Res<UiResources>,
+) {
+    let Ok((player_transform, party_info)) = query_player.single() else {
+        return;
+    };
+
+    let members: HashSet<Entity> = if party_marker_settings.enabled {
+        party_info
+            .map(|party_info| get_party_member_entities(party_info, &client_entity_list))
+            .unwrap_or_default()
+            .into_iter()
+            .collect()
+    } else {
+        HashSet::default()
+    };
+
+    // Remove markers from entities which are no longer in our party
+    for (entity, marker_entity) in query_marked.iter() {
+        if !members.contains(&entity) {
+            commands.entity(entity).remove::<PartyMemberMarkerEntity>();
+            commands.entity(marker_entity.0).despawn();
+        }
+    }
+
+    for &member_entity in members.iter() {
+        let Ok((member_transform, model_height, marker_entity)) = query_member.get(member_entity)
+        else {
+            continue;
+        };
+
+        let Some(marker_entity) = marker_entity else {
+            if let Some(marker_entity) =
+                spawn_party_marker(&mut commands, &ui_resources, model_height)
+            {
+                commands
+                    .entity(member_entity)
+                    .insert(PartyMemberMarkerEntity(marker_entity))
+                    .add_child(marker_entity);
+            }
+            continue;
+        };
+
+        let distance = player_transform
+            .translation()
+            .distance(member_transform.translation());
+        if let Ok(mut visibility) = query_visibility.get_mut(marker_entity.0) {
+            let target_visibility = if distance <= party_marker_settings.max_distance {
+                Visibility::Inherited
+            } else {
+                Visibility::Hidden
+            };
+
+            if *visibility != target_visibility {
+                *visibility = target_visibility;
+            }
+        }
+
+        // Update the health bar from the same data used by the name tag health bars
+        let Ok((health_points, ability_values)) = query_health.get(member_entity) else {
+            continue;
+        };
+        let health_percent = (health_points.hp as f32
+            / ability_values.get_max_health().max(1) as f32)
+            .clamp(0.0, 1.0);
+
+        if let Ok(children) = query_marker_children.get(marker_entity.0) {
+            for &child in children.iter() {
+                if let Ok((healthbar, mut rect)) = query_healthbar.get_mut(child) {
+                    rect.uv_max.x =
+                        healthbar.uv_min_x + health_percent * (healthbar.uv_max_x - healthbar.uv_min_x);
+                    rect.screen_size.x = healthbar.full_width * health_percent;
+                }
+            }
+        }
+    }
+}
+
+fn spawn_party_marker(
+    commands: &mut Commands,
+    ui_resources: &UiResources,
+    model_height: Option<&ModelHeight>,
+) -> Option<Entity> {
+    let (foreground_sprite, foreground_image) = ui_resources
+        .get_sprite(0, "UI18_GUAGE_HP")
+        .zip(ui_resources.get_sprite_image(0, "UI18_GUAGE_HP"))?;
+    let (background_sprite, background_image) = ui_resources
+        .get_sprite(0, "UI18_GUAGE_HP_BASE")
+        .zip(ui_resources.get_sprite_image(0, "UI18_GUAGE_HP_BASE"))?;
+
+    let bar_size = Vec2::new(background_sprite.width, background_sprite.height);
+    let screen_offset = Vec2::new(-bar_size.x / 2.0, MARKER_OFFSET_Y);
+    let height = model_height.map_or(2.0, |model_height| model_height.height);
+
+    let marker_entity = commands
+        .spawn((
+            PartyMemberMarker,
+            NoFrustumCulling,
+            Visibility::Inherited,
+            VisibilityClass::default(),
+            Transform::from_translation(Vec3::new(0.0, height, 0.0)),
+            GlobalTransform::default(),
+        ))
+        .id();
+
+    commands
+        .spawn((
+            NoFrustumCulling,
+            WorldUiRect {
+                screen_offset,
+                screen_size: bar_size,
+                image: background_image.clone(),
+                uv_min: Vec2::new(background_sprite.uv.min.x, background_sprite.uv.min.y),
+                uv_max: Vec2::new(background_sprite.uv.max.x, background_sprite.uv.max.y),
+                color: Color::WHITE,
+                order: ORDER_MARKER_BACKGROUND,
+            },
+            Transform::default(),
+            GlobalTransform::default(),
+            Visibility::Inherited,
+            VisibilityClass::default(),
+        ))
+        .insert(ChildOf(marker_entity));
+
+    commands
+        .spawn((
+            PartyMemberMarkerHealthbar {
+                uv_min_x: foreground_sprite.uv.min.x,
+                uv_max_x: foreground_sprite.uv.max.x,
+                full_width: bar_size.x,
+            },
+            NoFrustumCulling,
+            WorldUiRect {
+                screen_offset,
+                screen_size: bar_size,
+                image: foreground_image.clone(),
+                uv_min: Vec2::new(foreground_sprite.uv.min.x, foreground_sprite.uv.min.y),
+                uv_max: Vec2::new(foreground_sprite.uv.max.x, foreground_sprite.uv.max.y),
+                color: Color::srgb(0.45, 0.85, 1.0),
+                order: ORDER_MARKER_FOREGROUND,
+            },
+            Transform::default(),
+            GlobalTransform::default(),
+            Visibility::Inherited,
+            VisibilityClass::default(),
+        ))
+        .insert(ChildOf(marker_entity));
+
+    Some(marker_entity)
+}
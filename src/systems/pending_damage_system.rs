@@ -1,13 +1,14 @@
 use bevy::prelude::{
-    Commands, Entity, GlobalTransform, MessageWriter, Query, Res, ResMut, Time, Vec3, With,
+    Commands, Entity, GlobalTransform, Local, MessageWriter, Query, Res, ResMut, Time, Vec3, With,
 };
+use rand::{rngs::StdRng, Rng};
 
 use rose_game_common::{components::HealthPoints, data::Damage};
 
 use crate::{
     components::{ClientEntity, Dead, DeathBloodHandled, NextCommand, PendingDamageList},
     events::{BloodEffectEvent, BloodImpactProfile},
-    resources::{BloodEffectConfig, ClientEntityList, DamageDigitsSpawner},
+    resources::{BloodEffectConfig, ClientEntityList, DamageDigitsSpawner, RngSeed},
 };
 
 fn normalize_or(value: Vec3, fallback: Vec3) -> Vec3 {
@@ -19,17 +20,18 @@ fn normalize_or(value: Vec3, fallback: Vec3) -> Vec3 {
     }
 }
 
-fn random_local_wound_pose(_model_height: f32) -> (Vec3, Vec3) {
-    let y = -0.04 + rand::random::<f32>() * 0.18;
-    let angle = rand::random::<f32>() * std::f32::consts::TAU;
-    let radial = 0.05 + rand::random::<f32>() * 0.16;
+fn random_local_wound_pose<R: Rng>(_model_height: f32, rng: &mut R) -> (Vec3, Vec3) {
+    let y = -0.04 + rng.gen::<f32>() * 0.18;
+    let angle = rng.gen::<f32>() * std::f32::consts::TAU;
+    let radial = 0.05 + rng.gen::<f32>() * 0.16;
     let x = radial * angle.cos();
     let z = radial * angle.sin();
     let normal = normalize_or(Vec3::new(x, 0.05, z), Vec3::Z);
     (Vec3::new(x, y, z), normal)
 }
 
-// After 5 seconds, expire pending damage and apply immediately
+// After 5 seconds, expire pending damage and apply immediately, projectiles
+// always land before this, see MAX_PROJECTILE_FLIGHT_TIME
 const MAX_DAMAGE_AGE: f32 = 5.0;
 
 fn apply_damage(
@@ -87,8 +89,11 @@ pub fn pending_damage_system(
     mut client_entity_list: ResMut<ClientEntityList>,
     damage_digits_spawner: Res<DamageDigitsSpawner>,
     blood_config: Res<BloodEffectConfig>,
+    rng_seed: Res<RngSeed>,
+    mut rng: Local<Option<StdRng>>,
 ) {
     let _ = &query_transform;
+    let rng = rng.get_or_insert_with(|| rng_seed.rng("pending_damage"));
 
     // log::info!("[PENDING_DAMAGE_SYSTEM] System running, processing entities...");
     let delta_time = time.delta_secs();
@@ -147,7 +152,7 @@ pub fn pending_damage_system(
                     model_height,
                 );
 
-                if pending_damage.damage.amount > 0 {
+                if pending_damage.damage.amount > 0 && blood_config.enable_blood {
                     let defender_pos = global_transform.translation();
                     let impact_direction = pending_damage
                         .attacker
@@ -173,11 +178,11 @@ pub fn pending_damage_system(
                         ));
                     }
 
-                    if blood_config.enable_blood && blood_config.show_wounds {
+                    if blood_config.show_wounds {
                         let model_h = model_height.map_or(1.8, |h| h.height);
                         let wound_events = if pending_damage.is_kill { 3 } else { 2 };
                         for _ in 0..wound_events {
-                            let (wound_position, wound_normal) = random_local_wound_pose(model_h);
+                            let (wound_position, wound_normal) = random_local_wound_pose(model_h, rng);
                             blood_effect_events.write(BloodEffectEvent::show_wound(
                                 entity,
                                 wound_position,
@@ -2,11 +2,11 @@ use std::time::Duration;
 
 use bevy::{
     math::Vec3Swizzles,
-    prelude::{Entity, MessageReader, MessageWriter, Query, Res, With},
+    prelude::{Commands, Entity, MessageReader, MessageWriter, Query, Res, With},
 };
 
 use rose_data::{
-    AmmoIndex, EquipmentIndex, ItemClass, ItemType, SkillBasicCommand, SkillCooldown,
+    AmmoIndex, EquipmentIndex, Item, ItemClass, ItemType, SkillBasicCommand, SkillCooldown,
     SkillTargetFilter, SkillType, VehiclePartIndex,
 };
 use rose_game_common::{
@@ -17,15 +17,16 @@ use rose_game_common::{
 use crate::{
     components::{
         Bank, Clan, ClientEntity, ClientEntityType, Command, ConsumableCooldownGroup, Cooldowns,
-        PartyInfo, PlayerCharacter, Position,
+        Dead, NextCommand, PartyInfo, PlayerCharacter, Position, ThreatState,
     },
     events::{ChatboxEvent, PlayerCommandEvent, QuestScrollEvent},
-    resources::{GameConnection, GameData, SelectedTarget},
+    resources::{GameConnection, GameData, NetworkMovementSettings, SelectedTarget},
     ui::UiStateInventory,
 };
 
 #[allow(clippy::too_many_arguments)]
 pub fn player_command_system(
+    mut commands: Commands,
     mut player_command_events: MessageReader<PlayerCommandEvent>,
     mut query_player: Query<(
         Entity,
@@ -38,7 +39,10 @@ pub fn player_command_system(
         &Team,
         Option<&Clan>,
         Option<&PartyInfo>,
+        &Command,
     ), With<PlayerCharacter>>,
+    query_player_dead: Query<(), (With<PlayerCharacter>, With<Dead>)>,
+    query_threat: Query<(), With<ThreatState>>,
     query_client_entity: Query<&ClientEntity>,
     query_dropped_items: Query<(&ClientEntity, &Position), With<ItemDrop>>,
     query_team: Query<(&ClientEntity, &Team)>,
@@ -55,12 +59,19 @@ pub fn player_command_system(
     game_data: Res<GameData>,
     selected_target: Res<SelectedTarget>,
     ui_state_inventory: Option<Res<UiStateInventory>>,
+    network_movement_settings: Res<NetworkMovementSettings>,
 ) {
+    if !query_player_dead.is_empty() {
+        // Only the camera can be controlled while dead
+        player_command_events.clear();
+        return;
+    }
+
     let query_player_result = query_player.single_mut();
     if query_player_result.is_err() {
         return;
     }
-    let (player_entity, player_bank, player_cooldowns, mut player_hotbar, player_inventory, player_position, player_skill_list, player_team, player_clan, player_party_info) = query_player_result.unwrap();
+    let (player_entity, player_bank, player_cooldowns, mut player_hotbar, player_inventory, player_position, player_skill_list, player_team, player_clan, player_party_info, player_command) = query_player_result.unwrap();
 
     for event in player_command_events.read() {
         let mut event = event.clone();
@@ -223,10 +234,15 @@ pub fn player_command_system(
                                         .ok();
                                 }
                             }
+                            Some(SkillBasicCommand::Trade) => {
+                                // The server has no player to player trade messages
+                                chatbox_events.write(ChatboxEvent::System(
+                                    "Trading is not supported by this server.".to_string(),
+                                ));
+                            }
                             /*
                             Some(SkillBasicCommand::AutoTarget) => {}
                             Some(SkillBasicCommand::AddFriend) => {}
-                            Some(SkillBasicCommand::Trade) => {}
                             Some(SkillBasicCommand::PrivateStore) => {}
                             Some(SkillBasicCommand::SelfTarget) => {}
                             Some(SkillBasicCommand::VehiclePassengerInvite) => {}
@@ -808,7 +824,11 @@ pub fn player_command_system(
             }
             PlayerCommandEvent::Move(position, target_entity) => {
                 //log::info!("[RESPAWN_MOVE_DIAG] PlayerCommandEvent::Move received: position=({}, {}, {})", position.x, position.y, position.z);
-                
+                if player_command.is_sit() {
+                    // Seated players must stand up before they can move
+                    continue;
+                }
+
                 let target_entity_id = target_entity
                     .and_then(|target_entity| query_client_entity.get(target_entity).ok())
                     .map(|target_client_entity| target_client_entity.id);
@@ -824,6 +844,15 @@ pub fn player_command_system(
                             z: position.z as u16,
                         })
                         .ok();
+
+                    // Start moving straight away rather than waiting for the server to echo it back
+                    if network_movement_settings.enabled {
+                        commands.entity(player_entity).insert(NextCommand::with_move(
+                            position,
+                            target_entity,
+                            None,
+                        ));
+                    }
                 } else {
                     log::warn!("[RESPAWN_MOVE_DIAG] No game connection available!");
                 }
@@ -878,6 +907,27 @@ pub fn player_command_system(
                     }
                 }
             }
+            PlayerCommandEvent::BankWithdrawItemWithQuantity(bank_slot, quantity) => {
+                if let Some(mut item) = player_bank
+                    .and_then(|bank| bank.slots.get(bank_slot))
+                    .and_then(|x| x.clone())
+                {
+                    if let Item::Stackable(stackable_item) = &mut item {
+                        stackable_item.quantity = stackable_item.quantity.min(quantity as u32);
+                    }
+
+                    if let Some(game_connection) = game_connection.as_ref() {
+                        game_connection
+                            .client_message_tx
+                            .send(ClientMessage::BankWithdrawItem {
+                                bank_slot,
+                                item,
+                                is_premium: false,
+                            })
+                            .ok();
+                    }
+                }
+            }
             PlayerCommandEvent::LevelUpSkill(skill_slot) => {
                 if let Some(game_connection) = game_connection.as_ref() {
                     game_connection
@@ -917,7 +967,42 @@ pub fn player_command_system(
                     }
                 }
             }
+            PlayerCommandEvent::Emote(motion_id) => {
+                // Emotes would be cut short by the next attack or move
+                if !(player_command.is_stop() || player_command.is_emote())
+                    || !query_threat.is_empty()
+                {
+                    chatbox_events.write(ChatboxEvent::System(
+                        "You cannot do that while moving or in combat".to_string(),
+                    ));
+                    continue;
+                }
+
+                if let Some(game_connection) = game_connection.as_ref() {
+                    // The server sends the emote back to us and everyone nearby
+                    game_connection
+                        .client_message_tx
+                        .send(ClientMessage::UseEmote {
+                            motion_id,
+                            is_stop: true,
+                        })
+                        .ok();
+                } else {
+                    commands
+                        .entity(player_entity)
+                        .insert(NextCommand::with_emote(motion_id, true));
+                }
+            }
+            PlayerCommandEvent::SitToggle => {
+                if let Some(game_connection) = game_connection.as_ref() {
+                    game_connection
+                        .client_message_tx
+                        .send(ClientMessage::SitToggle)
+                        .ok();
+                }
+            }
             PlayerCommandEvent::UseHotbar(_, _) => {} // Handled above
+            PlayerCommandEvent::Afk(_) => {} // Handled by afk_system
         }
     }
 }
@@ -7,8 +7,9 @@ use bevy_mesh::skinning::SkinnedMesh;
 use rose_data::EffectBulletMoveType;
 
 use crate::{
-    components::{DummyBoneOffset, Projectile, ProjectileParabola, ProjectileTarget},
+    components::{DummyBoneOffset, Projectile, ProjectileFlight, ProjectileTarget},
     events::{BloodImpactProfile, HitEvent},
+    resources::{projectile_arc_offset, projectile_flight_time},
 };
 
 pub fn projectile_system(
@@ -46,49 +47,34 @@ pub fn projectile_system(
         let mut target_translation = target_translation.unwrap();
         target_translation.y += 0.5;
 
-        let (complete, move_vec) = match projectile.move_type {
-            EffectBulletMoveType::Linear => {
-                let distance = transform.translation.distance(target_translation);
-                let direction = target_translation - transform.translation;
-                let move_distance = projectile.move_speed * time.delta_secs();
-
-                (
-                    move_distance + 0.1 >= distance,
-                    move_distance * direction.normalize(),
-                )
-            }
-            EffectBulletMoveType::Parabola => {
-                let move_speed = projectile.move_speed;
-                let parabola = projectile.parabola.get_or_insert_with(|| {
+        let delta = time.delta_secs();
+        let (complete, next_translation) = match projectile.move_type {
+            EffectBulletMoveType::Immediate => (true, transform.translation),
+            EffectBulletMoveType::Linear | EffectBulletMoveType::Parabola => {
+                let motion = projectile.motion;
+                let speed = projectile.move_speed * motion.speed_scale;
+                let flight = projectile.flight.get_or_insert_with(|| {
                     let distance = transform.translation.distance(target_translation);
-                    let travel_time = distance / move_speed;
-                    let velocity_y = travel_time * 98.0 / 2.0;
-
-                    let mut move_vec =
-                        move_speed * (target_translation - transform.translation).normalize();
-                    move_vec.y = velocity_y;
 
-                    ProjectileParabola {
-                        start_y: transform.translation.y,
-                        end_y: target_translation.y,
-                        velocity_y,
-                        move_vec,
+                    ProjectileFlight {
+                        start: transform.translation,
+                        fixed_target: (!motion.homing).then_some(target_translation),
+                        arc_height: motion.arc_height * distance,
                         current_time: 0.0,
-                        total_time: travel_time,
+                        total_time: projectile_flight_time(distance, speed),
                     }
                 });
 
-                parabola.velocity_y -= 98.0 * time.delta_secs();
-                parabola.move_vec.y = parabola.velocity_y;
-                parabola.current_time += time.delta_secs();
+                // Flight time is fixed at launch so the hit lands on time however
+                // far a homing projectile has to curve to follow its target
+                flight.current_time += delta;
+                let t = (flight.current_time / flight.total_time).min(1.0);
+                let end = flight.fixed_target.unwrap_or(target_translation);
+                let translation = flight.start.lerp(end, t)
+                    + Vec3::Y * projectile_arc_offset(flight.arc_height, t);
 
-                let mut move_vec = parabola.move_vec * time.delta_secs();
-                move_vec.y += ((parabola.end_y - parabola.start_y) / parabola.total_time)
-                    * time.delta_secs();
-
-                (parabola.current_time >= parabola.total_time, move_vec)
+                (t >= 1.0, translation)
             }
-            EffectBulletMoveType::Immediate => (true, Vec3::default()),
         };
 
         if complete {
@@ -120,10 +106,12 @@ pub fn projectile_system(
             continue;
         }
 
-        // Update transform
+        // Update transform, facing along the direction of travel
         let mut transform = *transform;
-        transform.translation += move_vec;
-        transform.rotation = Quat::from_rotation_arc(Vec3::X, move_vec.normalize());
+        if let Some(direction) = (next_translation - transform.translation).try_normalize() {
+            transform.rotation = Quat::from_rotation_arc(Vec3::X, direction);
+        }
+        transform.translation = next_translation;
         commands.entity(entity).insert(transform);
     }
 }
@@ -0,0 +1,279 @@
+use bevy::{
+    math::Vec3,
+    prelude::{
+        Camera3d, Commands, Entity, MessageReader, MessageWriter, NextState, Or, Query, Res,
+        ResMut, Time, With,
+    },
+};
+
+use rose_game_common::messages::client::ClientMessage;
+
+use crate::{
+    animation::CameraAnimation,
+    components::{ClientEntity, PlayerCharacter},
+    events::{
+        GameConnectionEvent, LoadZoneEvent, LoginConnectionEvent, MessageBoxEvent, NetworkEvent,
+        WorldConnectionEvent,
+    },
+    resources::{
+        AppState, CharacterList, ClientEntityList, CurrentZone, GameConnection, LoginConnection,
+        ReconnectSession, ReconnectStage, ReconnectState, ServerConfiguration, ServerList,
+        WorldConnection,
+    },
+    systems::{DeathSpectatorCamera, FreeCamera, OrbitCamera},
+};
+
+const RECONNECT_RETRY_DELAY_SECS: f32 = 3.0;
+
+fn close_connections(commands: &mut Commands) {
+    commands.remove_resource::<LoginConnection>();
+    commands.remove_resource::<WorldConnection>();
+    commands.remove_resource::<GameConnection>();
+    commands.remove_resource::<ServerList>();
+    commands.remove_resource::<CharacterList>();
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn reconnect_system(
+    mut commands: Commands,
+    reconnect_state: Option<ResMut<ReconnectState>>,
+    reconnect_session: Res<ReconnectSession>,
+    server_configuration: Res<ServerConfiguration>,
+    connections: (
+        Option<Res<LoginConnection>>,
+        Option<Res<WorldConnection>>,
+        Option<Res<GameConnection>>,
+        Option<Res<ServerList>>,
+        Option<Res<CharacterList>>,
+    ),
+    current_zone: Option<Res<CurrentZone>>,
+    mut client_entity_list: ResMut<ClientEntityList>,
+    query_client_entities: Query<Entity, Or<(With<ClientEntity>, With<PlayerCharacter>)>>,
+    query_player: Query<Entity, (With<PlayerCharacter>, With<ClientEntity>)>,
+    query_cameras: Query<Entity, With<Camera3d>>,
+    mut connection_events: (
+        MessageReader<GameConnectionEvent>,
+        MessageReader<WorldConnectionEvent>,
+        MessageReader<LoginConnectionEvent>,
+    ),
+    mut network_events: MessageWriter<NetworkEvent>,
+    mut load_zone_events: MessageWriter<LoadZoneEvent>,
+    mut message_box_events: MessageWriter<MessageBoxEvent>,
+    mut app_state_next: ResMut<NextState<AppState>>,
+    time: Res<Time>,
+) {
+    let (login_connection, world_connection, game_connection, server_list, character_list) =
+        connections;
+    let (game_connection_events, world_connection_events, login_connection_events) =
+        &mut connection_events;
+
+    let mut connected_zone = None;
+    let mut disconnect_error = None;
+    for event in game_connection_events.read() {
+        match event {
+            &GameConnectionEvent::Connected(zone_id) => connected_zone = Some(zone_id),
+            GameConnectionEvent::Disconnected(error) => {
+                disconnect_error = Some(format!("Connection to game server lost: {}", error))
+            }
+        }
+    }
+    for event in world_connection_events.read() {
+        if let WorldConnectionEvent::Disconnected { error } = event {
+            disconnect_error = Some(format!("Connection to world server lost: {}", error));
+        }
+    }
+
+    let mut attempt_failed = false;
+    for event in login_connection_events.read() {
+        match event {
+            LoginConnectionEvent::LoggedIn => {}
+            LoginConnectionEvent::ConnectionFailed { .. } | LoginConnectionEvent::LoginRejected => {
+                attempt_failed = true;
+            }
+        }
+    }
+
+    let Some(mut reconnect_state) = reconnect_state else {
+        let Some(disconnect_error) = disconnect_error else {
+            return;
+        };
+
+        let can_reconnect = server_configuration.reconnect
+            && server_configuration.reconnect_max_attempts > 0
+            && reconnect_session.server_id.is_some()
+            && reconnect_session.channel_id.is_some()
+            && reconnect_session.character_name.is_some();
+        if !can_reconnect {
+            message_box_events.write(MessageBoxEvent::Show {
+                message: disconnect_error,
+                modal: true,
+                ok: None,
+                cancel: None,
+            });
+            return;
+        }
+
+        log::warn!("[RECONNECT] {}, attempting to reconnect", disconnect_error);
+        close_connections(&mut commands);
+
+        // The server will send us our character and everything around it again
+        for entity in query_client_entities.iter() {
+            commands.entity(entity).despawn();
+        }
+        client_entity_list.clear();
+        client_entity_list.player_entity = None;
+        client_entity_list.player_entity_id = None;
+
+        commands.insert_resource(ReconnectState {
+            attempt: 1,
+            max_attempts: server_configuration.reconnect_max_attempts,
+            stage: ReconnectStage::WaitRetry,
+            remaining_secs: RECONNECT_RETRY_DELAY_SECS,
+        });
+        return;
+    };
+
+    if disconnect_error.is_some() {
+        attempt_failed = true;
+    }
+
+    reconnect_state.remaining_secs -= time.delta_secs();
+    if reconnect_state.stage != ReconnectStage::WaitRetry && reconnect_state.remaining_secs <= 0.0
+    {
+        log::warn!("[RECONNECT] Timed out in stage {:?}", reconnect_state.stage);
+        attempt_failed = true;
+    }
+
+    if attempt_failed {
+        close_connections(&mut commands);
+
+        if reconnect_state.attempt >= reconnect_state.max_attempts {
+            log::warn!(
+                "[RECONNECT] Failed to reconnect after {} attempts",
+                reconnect_state.attempt
+            );
+            commands.remove_resource::<ReconnectState>();
+            message_box_events.write(MessageBoxEvent::Show {
+                message: format!(
+                    "Connection to the server was lost and could not be re-established after {} attempts.",
+                    reconnect_state.attempt
+                ),
+                modal: true,
+                ok: None,
+                cancel: None,
+            });
+
+            // Character select will send us back to login if the world server is gone too
+            app_state_next.set(AppState::GameCharacterSelect);
+            return;
+        }
+
+        reconnect_state.attempt += 1;
+        reconnect_state.stage = ReconnectStage::WaitRetry;
+        reconnect_state.remaining_secs = RECONNECT_RETRY_DELAY_SECS;
+        return;
+    }
+
+    let (Some(server_id), Some(channel_id), Some(character_name)) = (
+        reconnect_session.server_id,
+        reconnect_session.channel_id,
+        reconnect_session.character_name.as_ref(),
+    ) else {
+        commands.remove_resource::<ReconnectState>();
+        return;
+    };
+
+    match reconnect_state.stage {
+        ReconnectStage::WaitRetry => {
+            if reconnect_state.remaining_secs <= 0.0 {
+                log::info!(
+                    "[RECONNECT] Attempt {} of {}",
+                    reconnect_state.attempt,
+                    reconnect_state.max_attempts
+                );
+                network_events.write(NetworkEvent::ConnectLogin {
+                    ip: server_configuration.ip.clone(),
+                    port: server_configuration.port.parse::<u16>().unwrap_or(29000),
+                });
+                reconnect_state.stage = ReconnectStage::ConnectingLogin;
+                reconnect_state.remaining_secs = server_configuration.reconnect_timeout;
+            }
+        }
+        ReconnectStage::ConnectingLogin => {
+            if let (Some(login_connection), Some(_)) = (login_connection, server_list) {
+                login_connection
+                    .client_message_tx
+                    .send(ClientMessage::JoinServer {
+                        server_id,
+                        channel_id,
+                    })
+                    .ok();
+                reconnect_state.stage = ReconnectStage::ConnectingWorld;
+                reconnect_state.remaining_secs = server_configuration.reconnect_timeout;
+            }
+        }
+        ReconnectStage::ConnectingWorld => {
+            if let (Some(world_connection), Some(character_list)) =
+                (world_connection, character_list)
+            {
+                let Some(slot) = character_list
+                    .characters
+                    .iter()
+                    .position(|character| &character.info.name == character_name)
+                else {
+                    // Our character is gone, retrying will not bring it back
+                    reconnect_state.attempt = reconnect_state.max_attempts;
+                    reconnect_state.remaining_secs = 0.0;
+                    return;
+                };
+
+                world_connection
+                    .client_message_tx
+                    .send(ClientMessage::SelectCharacter {
+                        slot: slot as u8,
+                        name: character_name.clone(),
+                    })
+                    .ok();
+                reconnect_state.stage = ReconnectStage::ConnectingGame;
+                reconnect_state.remaining_secs = server_configuration.reconnect_timeout;
+            }
+        }
+        ReconnectStage::ConnectingGame => {
+            if let Some(zone_id) = connected_zone {
+                // If we are back in the same zone there is no need to load it again
+                if current_zone
+                    .as_ref()
+                    .map_or(false, |current_zone| current_zone.id == zone_id)
+                {
+                    if let Some(game_connection) = game_connection.as_ref() {
+                        game_connection
+                            .client_message_tx
+                            .send(ClientMessage::JoinZoneRequest)
+                            .ok();
+                    }
+                } else {
+                    load_zone_events.write(LoadZoneEvent::new(zone_id));
+                }
+
+                reconnect_state.stage = ReconnectStage::JoiningZone;
+                reconnect_state.remaining_secs = server_configuration.reconnect_timeout;
+            }
+        }
+        ReconnectStage::JoiningZone => {
+            if let Ok(player_entity) = query_player.single() {
+                log::info!("[RECONNECT] Reconnected to game server");
+
+                for camera_entity in query_cameras.iter() {
+                    commands
+                        .entity(camera_entity)
+                        .remove::<FreeCamera>()
+                        .remove::<CameraAnimation>()
+                        .remove::<DeathSpectatorCamera>()
+                        .insert(OrbitCamera::new(player_entity, Vec3::new(0.0, 1.7, 0.0), 15.0));
+                }
+
+                commands.remove_resource::<ReconnectState>();
+            }
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use std::f32::consts::FRAC_PI_2;
+
+use bevy::{
+    color::Color,
+    math::{Isometry3d, Quat, Vec3},
+    prelude::{Commands, Entity, Gizmos, GlobalTransform, MessageReader, MessageWriter, Query, Res, Time, With},
+};
+
+use rose_game_common::components::{HealthPoints, ManaPoints};
+
+use crate::{
+    components::{Command, CommandSit, PlayerCharacter, Resting},
+    events::{HitEvent, PlayerCommandEvent},
+};
+
+/// Radius of the glow drawn at the feet of a resting player, in metres
+const RESTING_GLOW_RADIUS: f32 = 0.6;
+
+/// Seconds for the outer glow ring to grow from the player and fade out
+const RESTING_GLOW_PERIOD: f32 = 2.0;
+
+/// Tracks whether the player is resting, stands them back up when they take
+/// damage and draws a soft glow at their feet while they rest.
+///
+/// The server recovers HP and MP faster for seated characters, the client only
+/// shows the state.
+pub fn resting_system(
+    mut commands: Commands,
+    mut gizmos: Gizmos,
+    time: Res<Time>,
+    mut query_player: Query<
+        (
+            Entity,
+            &Command,
+            &HealthPoints,
+            &ManaPoints,
+            &GlobalTransform,
+            Option<&mut Resting>,
+        ),
+        With<PlayerCharacter>,
+    >,
+    mut hit_events: MessageReader<HitEvent>,
+    mut player_command_events: MessageWriter<PlayerCommandEvent>,
+) {
+    let Ok((player_entity, command, health_points, mana_points, transform, resting)) =
+        query_player.single_mut()
+    else {
+        hit_events.clear();
+        return;
+    };
+
+    let Some(mut resting) = resting else {
+        if matches!(command, Command::Sit(CommandSit::Sit)) {
+            commands
+                .entity(player_entity)
+                .insert(Resting::new(health_points.hp, mana_points.mp));
+        }
+        hit_events.clear();
+        return;
+    };
+
+    if !command.is_sit() {
+        commands.entity(player_entity).remove::<Resting>();
+        hit_events.clear();
+        return;
+    }
+
+    let took_damage = hit_events
+        .read()
+        .any(|hit_event| hit_event.defender == player_entity && hit_event.apply_damage);
+    if took_damage && !resting.stand_requested {
+        player_command_events.write(PlayerCommandEvent::SitToggle);
+        resting.stand_requested = true;
+    }
+
+    let feet = Isometry3d::new(
+        transform.translation() + Vec3::Y * 0.05,
+        Quat::from_rotation_x(FRAC_PI_2),
+    );
+    let pulse = (time.elapsed_secs() / RESTING_GLOW_PERIOD).fract();
+    gizmos.circle(
+        feet,
+        RESTING_GLOW_RADIUS,
+        Color::srgba(0.5, 1.0, 0.6, 0.35),
+    );
+    gizmos.circle(
+        feet,
+        RESTING_GLOW_RADIUS * (1.0 + pulse),
+        Color::srgba(0.5, 1.0, 0.6, 0.35 * (1.0 - pulse)),
+    );
+}
@@ -0,0 +1,72 @@
+use bevy::prelude::{Local, Res, Time};
+
+use crate::resources::{ConfigFile, PersistentSettings};
+
+/// Seconds to wait after the last change before writing to disk, so dragging
+/// a slider does not rewrite the config file every frame.
+const SAVE_DELAY_SECONDS: f32 = 1.0;
+
+pub struct SaveSettingsState<T> {
+    last_seen: Option<T>,
+    last_saved: Option<T>,
+    save_timer: Option<f32>,
+}
+
+impl<T> Default for SaveSettingsState<T> {
+    fn default() -> Self {
+        Self {
+            last_seen: None,
+            last_saved: None,
+            save_timer: None,
+        }
+    }
+}
+
+pub fn save_settings_system<T: PersistentSettings>(
+    mut state: Local<SaveSettingsState<T>>,
+    settings: Res<T>,
+    config_file: Res<ConfigFile>,
+    time: Res<Time>,
+) {
+    if state.last_saved.is_none() {
+        // The initial settings were loaded from the config file
+        state.last_saved = Some(settings.clone());
+        state.last_seen = Some(settings.clone());
+        return;
+    }
+
+    if settings.is_changed() && state.last_seen.as_ref() != Some(&*settings) {
+        state.last_seen = Some(settings.clone());
+        state.save_timer = Some(SAVE_DELAY_SECONDS);
+    }
+
+    let Some(save_timer) = state.save_timer.as_mut() else {
+        return;
+    };
+    *save_timer -= time.delta_secs();
+    if *save_timer > 0.0 {
+        return;
+    }
+    state.save_timer = None;
+
+    if state.last_saved.as_ref() == Some(&*settings) {
+        return;
+    }
+
+    match config_file.queue_section(T::CONFIG_SECTION, &*settings) {
+        Ok(()) => state.last_saved = Some(settings.clone()),
+        Err(error) => log::warn!(
+            "Failed to save [{}] settings to config file with error: {}",
+            T::CONFIG_SECTION,
+            error
+        ),
+    }
+}
+
+/// Writes the sections queued by every `save_settings_system` to the config
+/// file, so only one system ever writes to it.
+pub fn write_config_file_system(config_file: Res<ConfigFile>) {
+    if let Err(error) = config_file.write_pending_sections() {
+        log::warn!("Failed to write config file with error: {}", error);
+    }
+}
@@ -9,7 +9,7 @@ use bevy_mesh::skinning::SkinnedMesh;
 use rose_file_readers::VfsPath;
 
 use crate::{
-    components::DummyBoneOffset,
+    components::{DummyBoneOffset, ParticleCategory},
     effect_loader::{spawn_effect, EffectCache},
     events::{SpawnEffect, SpawnEffectData, SpawnEffectEvent},
     resources::GameData,
@@ -51,7 +51,7 @@ pub fn spawn_effect_system(
             SpawnEffectEvent::InEntity(effect_entity, spawn_effect_data) => {
                 if let Some(effect_file_path) = get_effect_file_path(spawn_effect_data, &game_data) {
                     log::info!("[SPAWN EFFECT SYSTEM] Spawning effect InEntity: {}", effect_file_path.path().to_string_lossy());
-                    spawn_effect(
+                    if let Some(effect_entity) = spawn_effect(
                         &vfs_resource.vfs,
                         &mut commands,
                         &asset_server,
@@ -64,7 +64,9 @@ pub fn spawn_effect_system(
                         Some(*effect_entity),
                         Some(&effect_cache),
                         None, // No position for InEntity effects
-                    );
+                    ) {
+                        commands.entity(effect_entity).insert(ParticleCategory::Combat);
+                    }
                 } else {
                     log::warn!("[SPAWN EFFECT SYSTEM] No effect file path found for InEntity event");
                 }
@@ -87,11 +89,10 @@ pub fn spawn_effect_system(
                             Some(&effect_cache),
                             Some(at_global_transform.translation()),
                         ) {
-                            commands
-                                .entity(effect_entity)
-                                .insert(Transform::from_translation(
-                                    at_global_transform.translation(),
-                                ));
+                            commands.entity(effect_entity).insert((
+                                Transform::from_translation(at_global_transform.translation()),
+                                ParticleCategory::Combat,
+                            ));
                         }
                     }
                 }
@@ -126,6 +127,9 @@ pub fn spawn_effect_system(
                         Some(&effect_cache),
                         None, // No position for OnEntity effects (child of entity)
                     ) {
+                        commands
+                            .entity(effect_entity)
+                            .insert(ParticleCategory::Combat);
                         commands.entity(link_entity).add_child(effect_entity);
                     }
                 }
@@ -147,7 +151,9 @@ pub fn spawn_effect_system(
                         Some(&effect_cache),
                         Some(transform.translation),
                     ) {
-                        commands.entity(effect_entity).insert(*transform);
+                        commands
+                            .entity(effect_entity)
+                            .insert((*transform, ParticleCategory::Combat));
                     }
                 }
             }
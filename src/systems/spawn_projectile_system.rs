@@ -9,7 +9,7 @@ use bevy_mesh::skinning::SkinnedMesh;
 use crate::{
     components::{CharacterModel, CharacterModelPart, DummyBoneOffset, Projectile},
     events::{SpawnEffectData, SpawnEffectEvent, SpawnProjectileEvent},
-    resources::GameData,
+    resources::{GameData, ProjectileSettings},
 };
 
 pub fn spawn_projectile_system(
@@ -20,6 +20,7 @@ pub fn spawn_projectile_system(
     query_skeleton: Query<(&SkinnedMesh, &DummyBoneOffset)>,
     mut spawn_effect_events: MessageWriter<SpawnEffectEvent>,
     game_data: Res<GameData>,
+    projectile_settings: Res<ProjectileSettings>,
 ) {
     for event in events.read() {
         let mut source_global_transform = None;
@@ -61,8 +62,9 @@ pub fn spawn_projectile_system(
                     skill_id: event.source_skill_id,
                     move_type: event.move_type,
                     move_speed: event.move_speed,
+                    motion: projectile_settings.motion(event.effect_id, event.move_type),
                     apply_damage: event.apply_damage,
-                    parabola: None,
+                    flight: None,
                     target: event.target,
                 },
                 Transform::from_translation(source_global_transform.translation()),
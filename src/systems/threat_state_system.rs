@@ -0,0 +1,92 @@
+use bevy::{
+    ecs::query::QueryData,
+    prelude::{Commands, Entity, MessageReader, Query, Res, Time, With, Without},
+};
+
+use rose_game_common::components::Npc;
+
+use crate::{
+    components::{Command, CommandCastSkillTarget, Dead, NextCommand, PlayerCharacter, ThreatState},
+    events::HitEvent,
+};
+
+/// How long a monster keeps its threat state after it stops targeting the player
+const THREAT_TIMEOUT_SECONDS: f32 = 4.0;
+
+#[derive(QueryData)]
+#[query_data(mutable)]
+pub struct MonsterThreatQuery<'w> {
+    entity: Entity,
+    command: &'w Command,
+    next_command: Option<&'w NextCommand>,
+    threat_state: Option<&'w mut ThreatState>,
+}
+
+fn is_command_targeting(command: &Command, target: Entity) -> bool {
+    match command {
+        Command::Attack(attack) => attack.target == target,
+        Command::Move(command_move) => command_move.target == Some(target),
+        Command::CastSkill(cast_skill) => matches!(
+            cast_skill.skill_target,
+            Some(CommandCastSkillTarget::Entity(skill_target)) if skill_target == target
+        ),
+        _ => false,
+    }
+}
+
+pub fn threat_state_system(
+    mut commands: Commands,
+    mut query_monsters: Query<MonsterThreatQuery, (With<Npc>, Without<Dead>)>,
+    query_dead_threat: Query<Entity, (With<ThreatState>, With<Dead>)>,
+    query_player: Query<Entity, With<PlayerCharacter>>,
+    mut hit_events: MessageReader<HitEvent>,
+    time: Res<Time>,
+) {
+    // Dead monsters can no longer threaten anyone
+    for entity in query_dead_threat.iter() {
+        commands.entity(entity).remove::<ThreatState>();
+    }
+
+    let Ok(player_entity) = query_player.single() else {
+        hit_events.clear();
+        return;
+    };
+
+    let mut attackers = Vec::new();
+    for event in hit_events.read() {
+        if event.defender == player_entity {
+            attackers.push(event.attacker);
+        }
+    }
+
+    let delta = time.delta_secs();
+    for mut monster in query_monsters.iter_mut() {
+        let targeting_player = is_command_targeting(monster.command, player_entity)
+            || monster
+                .next_command
+                .and_then(|next_command| next_command.as_ref())
+                .map_or(false, |command| is_command_targeting(command, player_entity))
+            || attackers.contains(&monster.entity);
+
+        match monster.threat_state.as_mut() {
+            Some(threat_state) => {
+                threat_state.pulse_time += delta;
+
+                if targeting_player {
+                    threat_state.time_since_aggro = 0.0;
+                } else {
+                    threat_state.time_since_aggro += delta;
+
+                    if threat_state.time_since_aggro > THREAT_TIMEOUT_SECONDS {
+                        commands.entity(monster.entity).remove::<ThreatState>();
+                    }
+                }
+            }
+            None => {
+                if targeting_player {
+                    commands.entity(monster.entity).insert(ThreatState::default());
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use bevy::{
+    ecs::prelude::{Res, ResMut},
+    prelude::Time,
+};
+
+use rose_data::WORLD_TICK_DURATION;
+
+use crate::resources::TimeScaleSettings;
+
+pub fn time_scale_system(time: Res<Time>, mut time_scale: ResMut<TimeScaleSettings>) {
+    if time_scale.is_default_rate() {
+        return;
+    }
+
+    time_scale.advance(time.delta_secs() / WORLD_TICK_DURATION.as_secs_f32());
+}
@@ -0,0 +1,50 @@
+use bevy::prelude::{Commands, Entity, MessageReader, Query, Res, ResMut, Time};
+
+use crate::{
+    components::TransientDecal,
+    events::LoadZoneEvent,
+    resources::{decals_over_cap, BloodEffectRuntime, DecalSettings},
+};
+
+/// Seconds an expired decal is kept before it is despawned, decals which fade
+/// themselves are hidden and pooled at the end of their lifetime and this
+/// gives the pool a chance to reuse them first
+const DECAL_DESPAWN_GRACE_SECONDS: f32 = 5.0;
+
+/// Despawns transient decals once they have expired, recycles the oldest when
+/// there are more than `DecalSettings::max_decals` and clears them all when
+/// the zone changes.
+pub fn transient_decal_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    decal_settings: Res<DecalSettings>,
+    query_decals: Query<(Entity, &TransientDecal)>,
+    mut blood_effect_runtime: ResMut<BloodEffectRuntime>,
+    mut load_zone_events: MessageReader<LoadZoneEvent>,
+) {
+    let zone_changed = load_zone_events.read().count() > 0;
+    let now = time.elapsed_secs();
+    let default_lifetime = decal_settings.lifetime();
+
+    let mut despawned = Vec::new();
+    let mut live_decals = Vec::new();
+    for (entity, decal) in query_decals.iter() {
+        if zone_changed
+            || now >= decal.expires_at(default_lifetime) + DECAL_DESPAWN_GRACE_SECONDS
+        {
+            despawned.push(entity);
+        } else {
+            live_decals.push((entity, decal.spawned_at));
+        }
+    }
+    despawned.extend(decals_over_cap(live_decals, decal_settings.max_decals));
+
+    for &entity in despawned.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    // Drop pooled entities which were despawned here or by another system
+    blood_effect_runtime.spatter_pool.retain(|entity| {
+        !despawned.contains(entity) && query_decals.contains(*entity)
+    });
+}
@@ -0,0 +1,40 @@
+use bevy::{
+    prelude::{Query, Res, With},
+    window::{PrimaryWindow, Window},
+};
+use bevy_egui::{EguiContextSettings, PrimaryEguiContext};
+
+use crate::resources::UiScale;
+
+/// Applies `UiScale` to the egui context. bevy_egui multiplies the context
+/// scale factor by the window scale factor, so divide it back out to get the
+/// requested pixels per point.
+///
+/// Name tags and chat bubbles are rasterised with the egui pixels per point,
+/// so they scale together with the rest of the UI.
+pub fn ui_scale_system(
+    ui_scale: Res<UiScale>,
+    query_window: Query<&Window, With<PrimaryWindow>>,
+    mut query_egui_settings: Query<&mut EguiContextSettings, With<PrimaryEguiContext>>,
+) {
+    let Ok(window) = query_window.single() else {
+        return;
+    };
+    let Ok(mut egui_settings) = query_egui_settings.single_mut() else {
+        return;
+    };
+
+    let window_scale_factor = window.scale_factor();
+    let pixels_per_point = ui_scale.pixels_per_point(
+        window_scale_factor,
+        [
+            window.physical_width() as f32,
+            window.physical_height() as f32,
+        ],
+    );
+    let scale_factor = pixels_per_point / window_scale_factor;
+
+    if (egui_settings.scale_factor - scale_factor).abs() > f32::EPSILON {
+        egui_settings.scale_factor = scale_factor;
+    }
+}
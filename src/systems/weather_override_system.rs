@@ -0,0 +1,40 @@
+use bevy::prelude::ResMut;
+
+use crate::{
+    render::ZoneLighting,
+    resources::{SeasonSettings, WeatherOverride},
+};
+
+/// Applies the debug weather override on top of the season settings and zone
+/// fog, restoring the season settings when the override is set back to auto.
+pub fn weather_override_system(
+    mut weather_override: ResMut<WeatherOverride>,
+    mut season_settings: ResMut<SeasonSettings>,
+    mut zone_lighting: ResMut<ZoneLighting>,
+) {
+    let Some(weather) = weather_override.weather else {
+        if let Some(saved_season_settings) = weather_override.saved_season_settings.take() {
+            *season_settings = saved_season_settings;
+        }
+        return;
+    };
+
+    if weather_override.saved_season_settings.is_none() {
+        weather_override.saved_season_settings = Some(season_settings.clone());
+    }
+
+    let target = weather_override.season_settings(
+        weather,
+        weather_override.saved_season_settings.as_ref().unwrap(),
+    );
+    // Only write on change, the season cleanup system despawns particles on change
+    if season_settings.current_season != target.current_season
+        || season_settings.enabled != target.enabled
+        || season_settings.spawn_rate != target.spawn_rate
+        || season_settings.wind_strength != target.wind_strength
+    {
+        *season_settings = target;
+    }
+
+    zone_lighting.fog_density = weather_override.fog_density;
+}
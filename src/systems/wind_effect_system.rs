@@ -3,12 +3,12 @@ use bevy::{
     pbr::{MeshMaterial3d, StandardMaterial},
     render::alpha::AlphaMode,
 };
-use rand::Rng;
+use rand::{rngs::StdRng, Rng};
 
 use crate::components::{
     FacingDirection, FlightState, PlayerCharacter, Position, WindEffectEmitter, WindEffectParticle,
 };
-use crate::resources::FlightSettings;
+use crate::resources::{FlightSettings, RngSeed};
 
 /// Resource holding the shared mesh and material handles for wind particles
 #[derive(Resource)]
@@ -111,9 +111,11 @@ pub fn wind_particle_spawn_system(
     flight_query: Query<(&FlightState, &Position, &FacingDirection), With<PlayerCharacter>>,
     mut emitter_query: Query<&mut WindEffectEmitter>,
     particle_count: Query<(), With<WindEffectParticle>>,
+    rng_seed: Res<RngSeed>,
+    mut rng: Local<Option<StdRng>>,
 ) {
     let delta_time = time.delta_secs();
-    let mut rng = rand::thread_rng();
+    let rng = rng.get_or_insert_with(|| rng_seed.rng("wind_effect"));
 
     // Performance limit for particles
     const MAX_WIND_PARTICLES: usize = 200;
@@ -5,7 +5,7 @@ use rose_network_common::ConnectionError;
 
 use crate::{
     events::{MessageBoxEvent, NetworkEvent, WorldConnectionEvent},
-    resources::{Account, AppState, CharacterList, WorldConnection},
+    resources::{Account, AppState, CharacterList, ReconnectState, WorldConnection},
 };
 
 pub fn world_connection_system(
@@ -14,6 +14,7 @@ pub fn world_connection_system(
     account: Option<Res<Account>>,
     app_state_current: Res<State<AppState>>,
     mut app_state_next: ResMut<NextState<AppState>>,
+    reconnect_state: Option<Res<ReconnectState>>,
     mut network_events: MessageWriter<NetworkEvent>,
     mut world_connection_events: MessageWriter<WorldConnectionEvent>,
     mut message_box_events: MessageWriter<MessageBoxEvent>,
@@ -46,7 +47,10 @@ pub fn world_connection_system(
             Ok(ServerMessage::CharacterList {
                 character_list: characters,
             }) => {
-                if !matches!(app_state_current.get(), AppState::GameCharacterSelect) {
+                // While reconnecting we stay in game and select the character again
+                if !matches!(app_state_current.get(), AppState::GameCharacterSelect)
+                    && reconnect_state.is_none()
+                {
                     app_state_next.set(AppState::GameCharacterSelect);
                 }
 
@@ -99,12 +103,19 @@ pub fn world_connection_system(
 
     if let Err(error) = result {
         log::warn!("World server connection error: {}", error);
-        message_box_events.write(MessageBoxEvent::Show {
-            message: format!("Connection to world server lost: {}", error),
-            modal: true,
-            ok: None,
-            cancel: None,
+        world_connection_events.write(WorldConnectionEvent::Disconnected {
+            error: error.to_string(),
         });
+
+        // In game the reconnect system reports the connection status itself
+        if !matches!(app_state_current.get(), AppState::Game) {
+            message_box_events.write(MessageBoxEvent::Show {
+                message: format!("Connection to world server lost: {}", error),
+                modal: true,
+                ok: None,
+                cancel: None,
+            });
+        }
         commands.remove_resource::<WorldConnection>();
     }
 }
@@ -0,0 +1,71 @@
+use bevy::prelude::{Commands, GlobalTransform, Res, ResMut, Time, Transform, Vec2, Visibility};
+use bevy_camera::visibility::{NoFrustumCulling, VisibilityClass};
+
+use crate::{
+    render::{WorldUiOpacity, WorldUiRect},
+    resources::{UiResources, WorldPings},
+};
+
+/// Markers fade out over the last part of their lifetime
+const WORLD_PING_FADE_SECS: f32 = 1.0;
+
+/// Spawns a world marker for each new ping, fades them out as they expire and
+/// despawns the markers of removed pings.
+pub fn world_ping_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    ui_resources: Res<UiResources>,
+    mut world_pings: ResMut<WorldPings>,
+) {
+    let now = time.elapsed_secs();
+    world_pings.remove_expired(now);
+
+    for ping in world_pings.removed.drain(..) {
+        if let Some(marker_entity) = ping.marker_entity {
+            commands.entity(marker_entity).despawn();
+        }
+    }
+
+    let marker_sprite = ui_resources
+        .get_sprite(0, "UI00_TARGETMARK")
+        .zip(ui_resources.get_sprite_image(0, "UI00_TARGETMARK"));
+
+    for ping in world_pings.pings.iter_mut() {
+        let opacity = ((ping.expires_at - now) / WORLD_PING_FADE_SECS).clamp(0.0, 1.0);
+
+        if let Some(marker_entity) = ping.marker_entity {
+            commands
+                .entity(marker_entity)
+                .try_insert(WorldUiOpacity(opacity));
+            continue;
+        }
+
+        let Some((sprite, image)) = marker_sprite.as_ref() else {
+            continue;
+        };
+
+        // Twice the size of the target mark so it stands out from name tags
+        let size = Vec2::new(sprite.width, sprite.height) * 2.0;
+        ping.marker_entity = Some(
+            commands
+                .spawn((
+                    NoFrustumCulling,
+                    WorldUiRect {
+                        image: image.clone(),
+                        screen_offset: -size / 2.0,
+                        screen_size: size,
+                        uv_min: Vec2::new(sprite.uv.min.x, sprite.uv.min.y),
+                        uv_max: Vec2::new(sprite.uv.max.x, sprite.uv.max.y),
+                        color: ping.kind.color(),
+                        order: 0,
+                    },
+                    WorldUiOpacity(opacity),
+                    Transform::from_translation(ping.position),
+                    GlobalTransform::from_translation(ping.position),
+                    Visibility::Inherited,
+                    VisibilityClass::default(),
+                ))
+                .id(),
+        );
+    }
+}
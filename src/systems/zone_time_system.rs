@@ -11,7 +11,7 @@ use rose_data::{SkyboxState, WORLD_TICK_DURATION};
 use crate::{
     components::NightTimeEffect,
     render::ZoneLighting,
-    resources::{CurrentZone, GameData, WorldTime, ZoneTime, ZoneTimeState},
+    resources::{CurrentZone, GameData, TimeScaleSettings, WorldTime, ZoneTime, ZoneTimeState},
 };
 
 // Note: ZoneLighting is now used from resources::CurrentZone (via zone_lighting.rs)
@@ -86,6 +86,7 @@ pub fn zone_time_system(
     current_zone: Option<Res<CurrentZone>>,
     game_data: Res<GameData>,
     world_time: Res<WorldTime>,
+    time_scale: Res<TimeScaleSettings>,
     mut zone_time: ResMut<ZoneTime>,
     mut query_night_effects: Query<Entity, With<NightTimeEffect>>,
     mut query_visibility: Query<&mut Visibility>,
@@ -170,10 +171,10 @@ pub fn zone_time_system(
     let (day_time, partial_tick) = if let Some(overwrite_time) = zone_time.debug_overwrite_time {
         (overwrite_time, 0.0)
     } else {
-        (
-            world_day_time % safe_day_cycle,
-            world_time.time_since_last_tick.as_secs_f32() / WORLD_TICK_DURATION.as_secs_f32(),
-        )
+        let world_day_ticks = (world_day_time % safe_day_cycle) as f32
+            + world_time.time_since_last_tick.as_secs_f32() / WORLD_TICK_DURATION.as_secs_f32();
+        let zone_day_ticks = time_scale.zone_day_ticks(world_day_ticks, safe_day_cycle);
+        (zone_day_ticks as u32 % safe_day_cycle, zone_day_ticks.fract())
     };
     
     // Convert day_time to hours for easier debugging (assuming day_cycle represents 24 hours)
@@ -1,7 +1,10 @@
 mod dialog_loader;
 mod drag_and_drop_slot;
+mod radial_menu;
+mod stat_breakdown;
 mod tooltips;
 mod ui_admin_menu_system;
+mod ui_auto_potion_system;
 mod ui_bank_system;
 mod ui_character_create_system;
 mod ui_character_info_system;
@@ -10,6 +13,7 @@ mod ui_character_select_system;
 mod ui_chatbox_system;
 mod ui_clan_system;
 mod ui_create_clan;
+mod ui_debug_animation_events_system;
 mod ui_debug_camera_info_system;
 mod ui_debug_client_entity_list_system;
 mod ui_debug_command_viewer_system;
@@ -18,30 +22,43 @@ mod ui_debug_dialog_list;
 mod ui_debug_effect_list;
 mod ui_debug_entity_inspector_system;
 mod ui_debug_item_list_system;
+mod ui_debug_network_system;
 mod ui_debug_npc_list_system;
+mod ui_debug_log_system;
+mod ui_debug_packet_log_system;
 mod ui_debug_physics;
 mod ui_debug_render_system;
 mod ui_debug_skill_list_system;
+mod ui_debug_weather_system;
 mod ui_debug_window_system;
 mod ui_debug_zone_lighting_system;
 mod ui_debug_zone_list_system;
 mod ui_debug_zone_time_system;
 mod ui_drag_and_drop_system;
+mod ui_emote_wheel_system;
+mod ui_friends_system;
 mod ui_game_menu_system;
+mod ui_health_vignette_system;
 mod ui_hotbar_system;
 mod ui_inventory_system;
 mod ui_item_drop_name_system;
+mod ui_layout_system;
+mod ui_log_console_system;
 mod ui_login_system;
 mod ui_message_box_system;
 mod ui_minimap_system;
 mod ui_npc_store_system;
 mod ui_number_input_dialog_system;
+mod ui_party_marker_system;
 mod ui_party_option_system;
 mod ui_quest_scroll_system;
 mod ui_party_system;
 mod ui_personal_store_system;
+mod ui_ping_wheel_system;
 mod ui_player_info_system;
 mod ui_quest_list_system;
+mod ui_quick_use_system;
+mod ui_reconnect_system;
 mod ui_respawn_system;
 mod ui_sailing_hud_system;
 mod ui_selected_target_system;
@@ -54,10 +71,11 @@ mod ui_status_effects_system;
 mod ui_window_sound_system;
 pub mod widgets;
 
-#[derive(Default, Resource)]
+#[derive(Resource)]
 pub struct UiStateWindows {
     pub character_info_open: bool,
     pub clan_open: bool,
+    pub friends_open: bool,
     pub inventory_open: bool,
     pub skill_list_open: bool,
     pub skill_tree_open: bool,
@@ -73,13 +91,52 @@ pub struct UiStateWindows {
 
     // Test ui
     pub selected_target_ui_open: bool,
+
+    // Minimap preferences
+    pub minimap_overview_open: bool,
+    pub minimap_zoom: f32,
+    pub minimap_rotate_with_player: bool,
+
+    // Hotbar preferences
+    pub hotbar_vertical: bool,
+}
+
+impl Default for UiStateWindows {
+    fn default() -> Self {
+        Self {
+            character_info_open: false,
+            clan_open: false,
+            friends_open: false,
+            inventory_open: false,
+            skill_list_open: false,
+            skill_tree_open: false,
+            quest_list_open: false,
+            settings_open: false,
+            menu_open: false,
+            party_open: false,
+            party_options_open: false,
+            bank_open: false,
+            create_clan_open: false,
+            selected_target_ui_open: false,
+            minimap_overview_open: false,
+            minimap_zoom: 1.5,
+            minimap_rotate_with_player: false,
+            hotbar_vertical: false,
+        }
+    }
 }
 
 use bevy::prelude::Resource;
 pub use dialog_loader::{load_dialog_sprites_system, DialogInstance, DialogLoader};
 pub use drag_and_drop_slot::{DragAndDropId, DragAndDropSlot};
+pub use radial_menu::show_radial_menu;
+pub use stat_breakdown::{
+    ui_add_stat_breakdown, AbilityValuesBreakdown, AbilityValuesBreakdownQuery,
+    AbilityValuesBreakdownQueryItem,
+};
 pub use tooltips::{get_item_name_color, ui_add_item_tooltip, ui_add_skill_tooltip};
 pub use ui_admin_menu_system::{admin_menu_keyboard_system, ui_admin_menu_system, UiStateAdminMenu};
+pub use ui_auto_potion_system::ui_auto_potion_system;
 pub use ui_bank_system::ui_bank_system;
 pub use ui_character_create_system::ui_character_create_system;
 pub use ui_character_info_system::ui_character_info_system;
@@ -88,6 +145,7 @@ pub use ui_character_select_system::ui_character_select_system;
 pub use ui_chatbox_system::ui_chatbox_system;
 pub use ui_clan_system::ui_clan_system;
 pub use ui_create_clan::ui_create_clan_system;
+pub use ui_debug_animation_events_system::ui_debug_animation_events_system;
 pub use ui_debug_camera_info_system::ui_debug_camera_info_system;
 pub use ui_debug_client_entity_list_system::ui_debug_client_entity_list_system;
 pub use ui_debug_command_viewer_system::ui_debug_command_viewer_system;
@@ -96,30 +154,43 @@ pub use ui_debug_dialog_list::ui_debug_dialog_list_system;
 pub use ui_debug_effect_list::ui_debug_effect_list_system;
 pub use ui_debug_entity_inspector_system::ui_debug_entity_inspector_system;
 pub use ui_debug_item_list_system::ui_debug_item_list_system;
+pub use ui_debug_network_system::ui_debug_network_system;
+pub use ui_debug_log_system::ui_debug_log_system;
 pub use ui_debug_npc_list_system::ui_debug_npc_list_system;
+pub use ui_debug_packet_log_system::ui_debug_packet_log_system;
 pub use ui_debug_physics::ui_debug_physics_system;
 pub use ui_debug_render_system::ui_debug_render_system;
 pub use ui_debug_skill_list_system::ui_debug_skill_list_system;
+pub use ui_debug_weather_system::ui_debug_weather_system;
 pub use ui_debug_window_system::{ui_debug_menu_system, UiStateDebugWindows};
 pub use ui_debug_zone_lighting_system::ui_debug_zone_lighting_system;
 pub use ui_debug_zone_list_system::ui_debug_zone_list_system;
 pub use ui_debug_zone_time_system::ui_debug_zone_time_system;
 pub use ui_drag_and_drop_system::{ui_drag_and_drop_system, UiStateDragAndDrop};
+pub use ui_emote_wheel_system::ui_emote_wheel_system;
+pub use ui_friends_system::ui_friends_system;
 pub use ui_game_menu_system::ui_game_menu_system;
+pub use ui_health_vignette_system::ui_health_vignette_system;
 pub use ui_hotbar_system::ui_hotbar_system;
 pub use ui_inventory_system::{ui_inventory_system, UiStateInventory};
 pub use ui_item_drop_name_system::ui_item_drop_name_system;
+pub use ui_layout_system::{ui_layout_system, UiLayoutEvent};
+pub use ui_log_console_system::ui_log_console_system;
 pub use ui_login_system::ui_login_system;
 pub use ui_message_box_system::ui_message_box_system;
 pub use ui_minimap_system::ui_minimap_system;
 pub use ui_npc_store_system::ui_npc_store_system;
 pub use ui_number_input_dialog_system::ui_number_input_dialog_system;
+pub use ui_party_marker_system::{paint_offscreen_arrow, ui_party_marker_system};
 pub use ui_party_option_system::ui_party_option_system;
 pub use ui_party_system::ui_party_system;
+pub use ui_ping_wheel_system::ui_ping_wheel_system;
 pub use ui_quest_scroll_system::ui_quest_scroll_system;
 pub use ui_personal_store_system::ui_personal_store_system;
 pub use ui_player_info_system::ui_player_info_system;
 pub use ui_quest_list_system::ui_quest_list_system;
+pub use ui_quick_use_system::ui_quick_use_system;
+pub use ui_reconnect_system::ui_reconnect_system;
 pub use ui_respawn_system::ui_respawn_system;
 pub use ui_sailing_hud_system::ui_sailing_hud_system;
 pub use ui_selected_target_system::ui_selected_target_system;
@@ -0,0 +1,98 @@
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+use bevy_egui::egui;
+
+/// Pointer distance from the menu centre below which no option is hovered
+const RADIAL_MENU_DEAD_ZONE: f32 = 20.0;
+
+pub struct RadialMenuResponse {
+    /// Option under the pointer
+    pub hovered: Option<usize>,
+    /// Option which was clicked this frame
+    pub clicked: Option<usize>,
+    /// The centre was clicked, or the menu was right clicked
+    pub cancelled: bool,
+}
+
+/// Option under the pointer for a menu with `count` options laid out
+/// clockwise from the top
+pub fn radial_menu_hovered(center: egui::Pos2, pointer: egui::Pos2, count: usize) -> Option<usize> {
+    let offset = pointer - center;
+    if count == 0 || offset.length() < RADIAL_MENU_DEAD_ZONE {
+        return None;
+    }
+
+    let segment_angle = TAU / count as f32;
+    let angle = (offset.y.atan2(offset.x) + FRAC_PI_2 + segment_angle / 2.0).rem_euclid(TAU);
+    Some(((angle / segment_angle) as usize).min(count - 1))
+}
+
+/// Shows a radial menu centred at `center`, the area takes pointer input so
+/// clicks on the menu do not reach the game world.
+pub fn show_radial_menu(
+    ctx: &egui::Context,
+    id: egui::Id,
+    center: egui::Pos2,
+    radius: f32,
+    options: &[(&str, egui::Color32)],
+) -> RadialMenuResponse {
+    let hovered = ctx
+        .input(|input| input.pointer.hover_pos())
+        .and_then(|pointer| radial_menu_hovered(center, pointer, options.len()));
+    let size = egui::Vec2::splat(radius * 2.0);
+    let mut clicked = None;
+    let mut cancelled = false;
+
+    egui::Area::new(id)
+        .fixed_pos(center - size / 2.0)
+        .order(egui::Order::Foreground)
+        .show(ctx, |ui| {
+            let (rect, response) = ui.allocate_exact_size(size, egui::Sense::click());
+            let painter = ui.painter();
+            painter.circle_filled(rect.center(), radius, egui::Color32::from_black_alpha(160));
+
+            let segment_angle = TAU / options.len().max(1) as f32;
+            for (index, (label, color)) in options.iter().enumerate() {
+                let angle = index as f32 * segment_angle - FRAC_PI_2;
+                let label_pos = rect.center() + egui::vec2(angle.cos(), angle.sin()) * radius * 0.65;
+
+                if hovered == Some(index) {
+                    painter.circle_filled(label_pos, 24.0, color.gamma_multiply(0.4));
+                }
+                painter.text(
+                    label_pos,
+                    egui::Align2::CENTER_CENTER,
+                    *label,
+                    egui::FontId::proportional(13.0),
+                    *color,
+                );
+            }
+
+            if response.clicked() {
+                clicked = hovered;
+                cancelled |= hovered.is_none();
+            }
+            cancelled |= response.secondary_clicked();
+        });
+
+    RadialMenuResponse {
+        hovered,
+        clicked,
+        cancelled,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hovered_option_is_clockwise_from_top() {
+        let center = egui::pos2(100.0, 100.0);
+        assert_eq!(radial_menu_hovered(center, egui::pos2(100.0, 40.0), 4), Some(0));
+        assert_eq!(radial_menu_hovered(center, egui::pos2(160.0, 100.0), 4), Some(1));
+        assert_eq!(radial_menu_hovered(center, egui::pos2(100.0, 160.0), 4), Some(2));
+        assert_eq!(radial_menu_hovered(center, egui::pos2(40.0, 100.0), 4), Some(3));
+        assert_eq!(radial_menu_hovered(center, egui::pos2(105.0, 100.0), 4), None);
+    }
+}
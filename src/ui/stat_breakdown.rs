@@ -0,0 +1,114 @@
+use bevy::ecs::query::QueryData;
+use bevy_egui::egui;
+
+use rose_game_common::components::{
+    AbilityValues, BasicStats, CharacterInfo, Equipment, Level, SkillList, StatusEffects,
+};
+
+use crate::resources::{current_accessibility, GameData};
+
+/// Components the ability values of a character are calculated from
+#[derive(QueryData)]
+pub struct AbilityValuesBreakdownQuery<'w> {
+    pub character_info: &'w CharacterInfo,
+    pub level: &'w Level,
+    pub equipment: &'w Equipment,
+    pub basic_stats: &'w BasicStats,
+    pub skill_list: &'w SkillList,
+    pub status_effects: &'w StatusEffects,
+}
+
+/// Ability values calculated with one source added at a time, so the
+/// difference between two steps is how much that source contributes
+pub struct AbilityValuesBreakdown {
+    base: AbilityValues,
+    equipment: AbilityValues,
+    passive_skills: AbilityValues,
+    total: AbilityValues,
+}
+
+impl AbilityValuesBreakdown {
+    pub fn new(game_data: &GameData, player: &AbilityValuesBreakdownQueryItem<'_, '_, '_>) -> Self {
+        let calculator = &game_data.ability_value_calculator;
+        let no_equipment = Equipment::default();
+        let no_skills = SkillList::default();
+        let no_status_effects = StatusEffects::default();
+
+        Self {
+            base: calculator.calculate(
+                player.character_info,
+                player.level,
+                &no_equipment,
+                player.basic_stats,
+                &no_skills,
+                &no_status_effects,
+            ),
+            equipment: calculator.calculate(
+                player.character_info,
+                player.level,
+                player.equipment,
+                player.basic_stats,
+                &no_skills,
+                &no_status_effects,
+            ),
+            passive_skills: calculator.calculate(
+                player.character_info,
+                player.level,
+                player.equipment,
+                player.basic_stats,
+                player.skill_list,
+                &no_status_effects,
+            ),
+            total: calculator.calculate(
+                player.character_info,
+                player.level,
+                player.equipment,
+                player.basic_stats,
+                player.skill_list,
+                player.status_effects,
+            ),
+        }
+    }
+}
+
+/// Show where a stat comes from, e.g. as the hover ui of the label showing it
+pub fn ui_add_stat_breakdown(
+    ui: &mut egui::Ui,
+    name: &str,
+    breakdown: &AbilityValuesBreakdown,
+    get_value: impl Fn(&AbilityValues) -> i32,
+) {
+    let accessibility = current_accessibility();
+    let base = get_value(&breakdown.base);
+    let equipment = get_value(&breakdown.equipment);
+    let passive_skills = get_value(&breakdown.passive_skills);
+    let total = get_value(&breakdown.total);
+
+    ui.label(egui::RichText::new(format!("{}: {}", name, total)).strong());
+    egui::Grid::new("stat_breakdown").num_columns(2).show(ui, |ui| {
+        ui.label("Base");
+        ui.label(format!("{}", base));
+        ui.end_row();
+
+        for (source, value) in [
+            ("Equipment", equipment - base),
+            ("Passive skills", passive_skills - equipment),
+            ("Status effects", total - passive_skills),
+        ] {
+            if value == 0 {
+                continue;
+            }
+
+            ui.label(source);
+            ui.colored_label(
+                if value > 0 {
+                    accessibility.positive_color()
+                } else {
+                    accessibility.negative_color()
+                },
+                format!("{:+}", value),
+            );
+            ui.end_row();
+        }
+    });
+}
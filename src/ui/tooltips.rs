@@ -14,7 +14,10 @@ use rose_game_common::components::{
     UnionMembership,
 };
 
-use crate::{bundles::ability_values_get_value, resources::GameData};
+use crate::{
+    bundles::ability_values_get_value,
+    resources::{current_accessibility, GameData},
+};
 
 const TOOLTIP_MAX_WIDTH: f32 = 300.0;
 
@@ -168,7 +171,7 @@ fn add_equipment_item_add_appraisal(
     let is_gem = equipment_item.gem > 300;
     if !is_gem && !equipment_item.is_appraised {
         ui.colored_label(
-            egui::Color32::RED,
+            current_accessibility().negative_color(),
             game_data.client_strings.item_requires_appraisal.as_str(),
         );
     } else if let Some(gem_item_data) = game_data.items.get_gem_item(equipment_item.gem as usize) {
@@ -204,25 +207,22 @@ fn add_item_equip_requirement(
     player: Option<&<PlayerTooltipQuery as QueryData>::Item<'_, '_>>,
     item_data: &BaseItemData,
 ) {
+    let accessibility = current_accessibility();
     if let Some(job_class_id) = item_data.equip_job_class_requirement {
         if let Some(job_class) = game_data.job_class.get(job_class_id) {
-            let color = if player.map_or(true, |player| {
+            let met = player.map_or(true, |player| {
                 job_class
                     .jobs
                     .contains(&JobId::new(player.character_info.job))
-            }) {
-                egui::Color32::GREEN
-            } else {
-                egui::Color32::RED
-            };
+            });
 
-            ui.colored_label(
-                color,
+            ui.label(accessibility.indicator_text(
+                met,
                 format!(
                     "[{}: {}]",
                     game_data.client_strings.equip_require_job, job_class.name
                 ),
-            );
+            ));
         }
     }
 
@@ -233,12 +233,12 @@ fn add_item_equip_requirement(
                 .string_database
                 .get_ability_type(AbilityType::Union)
         );
-        let mut union_color = egui::Color32::RED;
+        let mut union_met = false;
         for union_id in item_data.equip_union_requirement.iter() {
             if let Some(player) = player {
                 if let Some(current_union) = player.union_membership.current_union {
                     if current_union == *union_id {
-                        union_color = egui::Color32::GREEN;
+                        union_met = true;
                     }
                 }
             }
@@ -246,11 +246,11 @@ fn add_item_equip_requirement(
             write!(&mut union_text, " {}", union_id).ok();
         }
         union_text.push(']');
-        ui.colored_label(union_color, union_text);
+        ui.label(accessibility.indicator_text(union_met, union_text));
     }
 
     for &(ability_type, value) in item_data.equip_ability_requirement.iter() {
-        let mut color = egui::Color32::RED;
+        let mut met = false;
 
     if let Some(player) = player {
         if let Some(current_value) = ability_values_get_value(
@@ -271,19 +271,19 @@ fn add_item_equip_requirement(
             Some(player.union_membership),
         ) {
                 if current_value >= value as i32 {
-                    color = egui::Color32::GREEN;
+                    met = true;
                 }
             }
         }
 
-        ui.colored_label(
-            color,
+        ui.label(accessibility.indicator_text(
+            met,
             format!(
                 "[{} {}]",
                 game_data.string_database.get_ability_type(ability_type),
                 value
             ),
-        );
+        ));
     }
 }
 
@@ -742,7 +742,12 @@ fn add_skill_description(ui: &mut egui::Ui, skill_data: &SkillData) {
     ui.label(&skill_data.description);
 }
 
-fn add_skill_power(ui: &mut egui::Ui, game_data: &GameData, skill_data: &SkillData) {
+fn add_skill_power(
+    ui: &mut egui::Ui,
+    game_data: &GameData,
+    player: Option<&<PlayerTooltipQuery as QueryData>::Item<'_, '_>>,
+    skill_data: &SkillData,
+) {
     let damage_type: &str = match skill_data.damage_type {
         0 => game_data.client_strings.skill_damage_type_0.as_str(),
         1 => game_data.client_strings.skill_damage_type_1.as_str(),
@@ -755,6 +760,63 @@ fn add_skill_power(ui: &mut egui::Ui, game_data: &GameData, skill_data: &SkillDa
         "{}: {} ({})",
         game_data.client_strings.skill_power, damage_type, skill_data.power
     ));
+
+    if let Some(player) = player {
+        add_skill_effective_damage(ui, game_data, player.ability_values, skill_data);
+    }
+}
+
+/// Number of hits sampled to estimate the damage range of a skill
+const SKILL_DAMAGE_SAMPLES: usize = 32;
+
+/// Damage of the skill with the player's current stats against a target with
+/// the same defence as the player. Damage rolls are random so the range is
+/// sampled once per set of stats and kept in egui memory, which also keeps
+/// the tooltip from flickering.
+fn add_skill_effective_damage(
+    ui: &mut egui::Ui,
+    game_data: &GameData,
+    ability_values: &AbilityValues,
+    skill_data: &SkillData,
+) {
+    if skill_data.power == 0 {
+        return;
+    }
+
+    let id = egui::Id::new((
+        "skill_effective_damage",
+        skill_data.id.get(),
+        ability_values.get_attack_power(),
+        ability_values.get_hit(),
+        ability_values.get_critical(),
+        ability_values.get_defence(),
+        ability_values.get_resistance(),
+        ability_values.get_avoid(),
+        ability_values.get_intelligence(),
+    ));
+    let (min_damage, max_damage) = ui.ctx().memory_mut(|memory| {
+        *memory.data.get_temp_mut_or_insert_with(id, || {
+            let amounts: Vec<_> = (0..SKILL_DAMAGE_SAMPLES)
+                .map(|_| {
+                    game_data
+                        .ability_value_calculator
+                        .calculate_skill_damage(ability_values, ability_values, skill_data, 1)
+                        .amount
+                })
+                .collect();
+            (
+                amounts.iter().copied().min().unwrap_or_default(),
+                amounts.iter().copied().max().unwrap_or_default(),
+            )
+        })
+    });
+
+    if max_damage > 0 {
+        ui.colored_label(
+            egui::Color32::from_rgb(255, 200, 100),
+            format!("Damage vs. equal defence: {} ~ {}", min_damage, max_damage),
+        );
+    }
 }
 
 fn add_skill_recover_xp(ui: &mut egui::Ui, game_data: &GameData, skill_data: &SkillData) {
@@ -770,12 +832,13 @@ fn add_skill_require_ability(
     player: Option<&<PlayerTooltipQuery as QueryData>::Item<'_, '_>>,
     skill_data: &SkillData,
 ) {
+    let accessibility = current_accessibility();
     if skill_data.required_ability.is_empty() {
         return;
     }
 
     for &(ability_type, value) in skill_data.required_ability.iter() {
-        let mut color = egui::Color32::RED;
+        let mut met = false;
 
         if let Some(player) = player {
             if let Some(current_value) = ability_values_get_value(
@@ -796,19 +859,19 @@ fn add_skill_require_ability(
                 Some(player.union_membership),
             ) {
                 if current_value >= value {
-                    color = egui::Color32::GREEN;
+                    met = true;
                 }
             }
         }
-        ui.colored_label(
-            color,
+        ui.label(accessibility.indicator_text(
+            met,
             format!(
                 "[{}: {} {}]",
                 game_data.client_strings.skill_require_ability,
                 game_data.string_database.get_ability_type(ability_type),
                 value
             ),
-        );
+        ));
     }
 }
 
@@ -818,25 +881,22 @@ fn add_skill_require_job(
     player: Option<&<PlayerTooltipQuery as QueryData>::Item<'_, '_>>,
     skill_data: &SkillData,
 ) {
+    let accessibility = current_accessibility();
     if let Some(job_class_id) = skill_data.required_job_class {
         if let Some(job_class) = game_data.job_class.get(job_class_id) {
-            let color = if player.map_or(true, |player| {
+            let met = player.map_or(true, |player| {
                 job_class
                     .jobs
                     .contains(&JobId::new(player.character_info.job))
-            }) {
-                egui::Color32::GREEN
-            } else {
-                egui::Color32::RED
-            };
+            });
 
-            ui.colored_label(
-                color,
+            ui.label(accessibility.indicator_text(
+                met,
                 format!(
                     "[{}: {}]",
                     game_data.client_strings.skill_require_job, job_class.name
                 ),
-            );
+            ));
         }
     }
 }
@@ -847,6 +907,7 @@ fn add_skill_require_skill(
     player: Option<&<PlayerTooltipQuery as QueryData>::Item<'_, '_>>,
     skill_data: &SkillData,
 ) {
+    let accessibility = current_accessibility();
     if skill_data.required_skills.is_empty() {
         return;
     }
@@ -855,7 +916,7 @@ fn add_skill_require_skill(
         if let Some(required_skill_data) = game_data.skills.get_skill(
             SkillId::new(required_skill_id.get() + required_level.max(1) as u16 - 1).unwrap(),
         ) {
-            let mut color = egui::Color32::RED;
+            let mut met = false;
 
             if let Some(player) = player {
                 if let Some((_, _, skill_level)) = player.skill_list.find_skill_level(
@@ -865,13 +926,13 @@ fn add_skill_require_skill(
                         .unwrap_or(required_skill_id),
                 ) {
                     if skill_level >= required_level as u32 {
-                        color = egui::Color32::GREEN;
+                        met = true;
                     }
                 }
             }
 
-            ui.colored_label(
-                color,
+            ui.label(accessibility.indicator_text(
+                met,
                 format!(
                     "[{}: {} ({}: {})]",
                     game_data.client_strings.skill_require_skill,
@@ -881,7 +942,7 @@ fn add_skill_require_skill(
                         .get_ability_type(AbilityType::Level),
                     required_level
                 ),
-            );
+            ));
         }
     }
 }
@@ -892,27 +953,24 @@ fn add_skill_require_skill_point(
     player: Option<&<PlayerTooltipQuery as QueryData>::Item<'_, '_>>,
     skill_data: &SkillData,
 ) {
+    let accessibility = current_accessibility();
     if skill_data.learn_point_cost == 0 {
         // Clan skills cannot be identified - no SkillType::Clan variant exists
         // All skills with learn_point_cost == 0 are skipped
         return;
     }
 
-    let color = if player.map_or(true, |player| {
+    let met = player.map_or(true, |player| {
         player.skill_points.points >= skill_data.learn_point_cost
-    }) {
-        egui::Color32::GREEN
-    } else {
-        egui::Color32::RED
-    };
+    });
 
-    ui.colored_label(
-        color,
+    ui.label(accessibility.indicator_text(
+        met,
         format!(
             "[{}: {}]",
             game_data.client_strings.skill_learn_point_cost, skill_data.learn_point_cost
         ),
-    );
+    ));
 }
 
 fn add_skill_require_equipment(
@@ -921,12 +979,13 @@ fn add_skill_require_equipment(
     player: Option<&<PlayerTooltipQuery as QueryData>::Item<'_, '_>>,
     skill_data: &SkillData,
 ) {
+    let accessibility = current_accessibility();
     if skill_data.required_ability.is_empty() {
         return;
     }
 
     let mut text = format!("[{}:", game_data.client_strings.skill_require_ability);
-    let mut color = egui::Color32::RED;
+    let mut met = false;
 
     for &item_class in skill_data.required_equipment_class.iter() {
         write!(
@@ -945,7 +1004,7 @@ fn add_skill_require_equipment(
             {
                 if let Some(item_data) = game_data.items.get_base_item(equipment.item) {
                     if item_class == item_data.class {
-                        color = egui::Color32::GREEN;
+                        met = true;
                     }
                 }
             }
@@ -953,7 +1012,7 @@ fn add_skill_require_equipment(
     }
 
     text.push(']');
-    ui.colored_label(color, text);
+    ui.label(accessibility.indicator_text(met, text));
 }
 
 fn add_skill_requirements(
@@ -1011,6 +1070,39 @@ fn add_skill_status_effects(
                 text.push(' ');
             }
             write!(text, "{}%", skill_add_ability.rate).ok();
+
+            // Percentage bonuses depend on the current stat, show what it
+            // works out to for the player right now
+            if let Some(player) = player {
+                let current_value = ability_values_get_value(
+                    skill_add_ability.ability_type,
+                    player.ability_values,
+                    Some(player.character_info),
+                    Some(player.experience_points),
+                    Some(player.guild_membership),
+                    Some(player.health_points),
+                    Some(player.inventory),
+                    Some(player.level),
+                    Some(player.mana_points),
+                    Some(player.move_speed),
+                    Some(player.skill_points),
+                    Some(player.stamina),
+                    Some(player.stat_points),
+                    Some(player.team),
+                    Some(player.union_membership),
+                );
+
+                if let Some(current_value) = current_value {
+                    let effective_value = game_data
+                        .ability_value_calculator
+                        .calculate_skill_adjust_value(
+                            skill_add_ability,
+                            player.ability_values.get_intelligence(),
+                            current_value,
+                        );
+                    write!(text, " (= {:+})", effective_value).ok();
+                }
+            }
         }
     };
 
@@ -1144,8 +1236,9 @@ fn add_skill_use_ability_value(
     player: Option<&<PlayerTooltipQuery as QueryData>::Item<'_, '_>>,
     skill_data: &SkillData,
 ) {
+    let accessibility = current_accessibility();
     for &(ability_type, mut value) in skill_data.use_ability.iter() {
-       let mut color = egui::Color32::RED;
+       let mut met = false;
 
             if let Some(player) = player {
                 if matches!(ability_type, AbilityType::Mana) {
@@ -1171,20 +1264,20 @@ fn add_skill_use_ability_value(
                     Some(player.union_membership),
                 ) {
                     if current_value >= value {
-                        color = egui::Color32::GREEN;
+                        met = true;
                     }
                 }
             }
 
-        ui.colored_label(
-            color,
+        ui.label(accessibility.indicator_text(
+            met,
             format!(
                 "[{}: {} {}]",
                 game_data.client_strings.skill_cost_ability,
                 game_data.string_database.get_ability_type(ability_type),
                 value
             ),
-        );
+        ));
     }
 }
 
@@ -1262,7 +1355,7 @@ pub fn ui_add_skill_tooltip(
                 add_skill_type_and_target(ui, game_data, skill_data);
                 add_skill_use_ability_value(ui, game_data, player, skill_data);
 
-                add_skill_power(ui, game_data, skill_data);
+                add_skill_power(ui, game_data, player, skill_data);
                 add_skill_status_effects(ui, game_data, player, skill_data);
 
                 add_skill_requirements(ui, game_data, player, skill_data);
@@ -1291,7 +1384,7 @@ pub fn ui_add_skill_tooltip(
                 add_skill_type_and_target(ui, game_data, skill_data);
                 add_skill_use_ability_value(ui, game_data, player, skill_data);
 
-                add_skill_power(ui, game_data, skill_data);
+                add_skill_power(ui, game_data, player, skill_data);
                 add_skill_cast_range(ui, game_data, skill_data);
                 add_skill_status_effects(ui, game_data, player, skill_data);
 
@@ -1321,7 +1414,7 @@ pub fn ui_add_skill_tooltip(
                 add_skill_type_and_target(ui, game_data, skill_data);
                 add_skill_use_ability_value(ui, game_data, player, skill_data);
 
-                add_skill_power(ui, game_data, skill_data);
+                add_skill_power(ui, game_data, player, skill_data);
                 add_skill_cast_range(ui, game_data, skill_data);
                 add_skill_aoe_range(ui, game_data, skill_data);
                 add_skill_status_effects(ui, game_data, player, skill_data);
@@ -1493,7 +1586,7 @@ pub fn ui_add_skill_tooltip(
                 add_skill_type_and_target(ui, game_data, skill_data);
                 add_skill_use_ability_value(ui, game_data, player, skill_data);
 
-                add_skill_power(ui, game_data, skill_data);
+                add_skill_power(ui, game_data, player, skill_data);
                 add_skill_aoe_range(ui, game_data, skill_data);
                 add_skill_status_effects(ui, game_data, player, skill_data);
 
@@ -1523,7 +1616,7 @@ pub fn ui_add_skill_tooltip(
                 add_skill_type_and_target(ui, game_data, skill_data);
                 add_skill_use_ability_value(ui, game_data, player, skill_data);
 
-                add_skill_power(ui, game_data, skill_data);
+                add_skill_power(ui, game_data, player, skill_data);
                 add_skill_steal_ability_value(ui, game_data, skill_data);
                 add_skill_status_effects(ui, game_data, player, skill_data);
 
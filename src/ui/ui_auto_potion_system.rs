@@ -0,0 +1,48 @@
+use bevy::prelude::{Res, Time};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::resources::{AutoPotionNotice, AutoPotionSettings};
+
+/// Seconds the auto-potion note stays on screen, fading out over the last second
+const NOTICE_SECONDS: f32 = 3.0;
+
+pub fn ui_auto_potion_system(
+    mut egui_context: EguiContexts,
+    auto_potion_notice: Res<AutoPotionNotice>,
+    auto_potion_settings: Res<AutoPotionSettings>,
+    time: Res<Time>,
+) {
+    if !auto_potion_settings.enabled || auto_potion_notice.text.is_empty() {
+        return;
+    }
+
+    let age = time.elapsed_secs() - auto_potion_notice.time;
+    if !(0.0..NOTICE_SECONDS).contains(&age) {
+        return;
+    }
+    let alpha = (NOTICE_SECONDS - age).min(1.0);
+
+    let ctx = egui_context.ctx_mut().unwrap();
+    let screen_rect = ctx.input(|input| input.screen_rect());
+    let position = egui::pos2(screen_rect.center().x, screen_rect.height() * 0.7);
+    let painter = ctx.layer_painter(egui::LayerId::new(
+        egui::Order::Background,
+        egui::Id::new("auto_potion_notice"),
+    ));
+    let font_id = egui::FontId::proportional(16.0);
+
+    painter.text(
+        position + egui::vec2(1.0, 1.0),
+        egui::Align2::CENTER_CENTER,
+        &auto_potion_notice.text,
+        font_id.clone(),
+        egui::Color32::BLACK.gamma_multiply(alpha),
+    );
+    painter.text(
+        position,
+        egui::Align2::CENTER_CENTER,
+        &auto_potion_notice.text,
+        font_id,
+        egui::Color32::from_rgb(255, 220, 120).gamma_multiply(alpha),
+    );
+}
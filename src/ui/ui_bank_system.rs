@@ -1,19 +1,25 @@
-use bevy::ecs::query::QueryData;
+use std::cmp::Ordering;
+
+use bevy::ecs::{query::QueryData, system::SystemParam};
 use bevy::{
     math::Vec3Swizzles,
-    prelude::{Assets, Entity, Local, MessageReader, MessageWriter, Query, Res, ResMut, With},
+    prelude::{
+        Assets, Entity, Local, MessageReader, MessageWriter, Messages, Query, Res, ResMut, With,
+        World,
+    },
 };
 use bevy_egui::{egui, EguiContexts};
 
+use rose_data::Item;
 use rose_game_common::{
-    components::{CharacterInfo, ItemSlot},
+    components::{CharacterInfo, Inventory, InventoryPageType, ItemSlot, INVENTORY_PAGE_SIZE},
     messages::client::ClientMessage,
 };
 
 use crate::{
     components::{Bank, PlayerCharacter, Position},
-    events::{BankEvent, PlayerCommandEvent},
-    resources::{ClientEntityList, GameConnection, GameData, UiResources},
+    events::{BankEvent, NumberInputDialogEvent, PlayerCommandEvent},
+    resources::{BankSettings, BankSort, ClientEntityList, GameConnection, GameData, UiResources},
     ui::{
         tooltips::PlayerTooltipQuery,
         ui_add_item_tooltip,
@@ -32,6 +38,20 @@ const IID_BTN_TAB4: i32 = 34;
 const BANK_SLOTS_PER_PAGE: usize = 40;
 const BANK_SLOTS_PER_ROW: usize = 8;
 
+const DEPOSIT_PAGE_TYPES: [(InventoryPageType, &str); 4] = [
+    (InventoryPageType::Equipment, "Equipment"),
+    (InventoryPageType::Consumables, "Consumables"),
+    (InventoryPageType::Materials, "Materials"),
+    (InventoryPageType::Vehicles, "Vehicles"),
+];
+
+/// Grouped message writers for ui_bank_system to avoid parameter count limit
+#[derive(SystemParam)]
+pub struct BankMessageWriters<'w> {
+    pub player_command_events: MessageWriter<'w, PlayerCommandEvent>,
+    pub number_input_dialog_events: MessageWriter<'w, NumberInputDialogEvent>,
+}
+
 pub struct UiStateBank {
     bank_entity: Option<Entity>,
     current_page: i32,
@@ -50,18 +70,14 @@ fn ui_add_bank_slot(
     ui: &mut egui::Ui,
     bank_slot_index: usize,
     pos: egui::Pos2,
-    player: &(&Bank, &CharacterInfo, &Position),
+    bank: &Bank,
     player_tooltip_data: Option<&<PlayerTooltipQuery as QueryData>::Item<'_, '_>>,
     game_data: &GameData,
     ui_resources: &UiResources,
     ui_state_dnd: &mut UiStateDragAndDrop,
-    player_command_events: &mut MessageWriter<PlayerCommandEvent>,
+    message_writers: &mut BankMessageWriters,
 ) {
-    let item = player
-        .0
-        .slots
-        .get(bank_slot_index)
-        .and_then(|x| x.as_ref());
+    let item = bank.slots.get(bank_slot_index).and_then(|x| x.as_ref());
 
     let mut dropped_item = None;
     let response = ui
@@ -92,16 +108,101 @@ fn ui_add_bank_slot(
         .inner;
 
     if let Some(item) = item {
+        let item_quantity = item.get_quantity() as usize;
+        let response = response.context_menu(|ui| {
+            if ui.button("Withdraw").clicked() {
+                message_writers
+                    .player_command_events
+                    .write(PlayerCommandEvent::BankWithdrawItem(bank_slot_index));
+            }
+
+            if item.is_stackable_item() && item_quantity > 1 && ui.button("Withdraw...").clicked() {
+                message_writers
+                    .number_input_dialog_events
+                    .write(NumberInputDialogEvent::Show {
+                        max_value: Some(item_quantity),
+                        modal: false,
+                        ok: Some(Box::new(move |commands, quantity| {
+                            commands.queue(move |world: &mut World| {
+                                world.resource_mut::<Messages<PlayerCommandEvent>>().write(
+                                    PlayerCommandEvent::BankWithdrawItemWithQuantity(
+                                        bank_slot_index,
+                                        quantity,
+                                    ),
+                                );
+                            });
+                        })),
+                        cancel: None,
+                    });
+            }
+        });
+
         response.on_hover_ui(|ui| {
             ui_add_item_tooltip(ui, game_data, player_tooltip_data, item);
         });
     }
 
     if let Some(DragAndDropId::Inventory(dropped_inventory_slot)) = dropped_item {
-        player_command_events.write(PlayerCommandEvent::BankDepositItem(dropped_inventory_slot));
+        message_writers
+            .player_command_events
+            .write(PlayerCommandEvent::BankDepositItem(dropped_inventory_slot));
+    }
+}
+
+fn bank_item_name<'a>(item: &Item, game_data: &'a GameData) -> &'a str {
+    game_data
+        .items
+        .get_base_item(item.get_item_reference())
+        .map_or("", |item_data| item_data.name.as_str())
+}
+
+fn compare_bank_items(a: &Item, b: &Item, sort: BankSort, game_data: &GameData) -> Ordering {
+    match sort {
+        BankSort::Slot => Ordering::Equal,
+        BankSort::Name => bank_item_name(a, game_data).cmp(bank_item_name(b, game_data)),
+        BankSort::Type => (a.get_item_type() as usize, a.get_item_reference().item_number)
+            .cmp(&(b.get_item_type() as usize, b.get_item_reference().item_number)),
+        BankSort::Quantity => b.get_quantity().cmp(&a.get_quantity()),
     }
 }
 
+/// Bank slots of a tab in the order they are drawn. Matching items come first in
+/// the chosen order followed by the empty slots, `None` positions are left blank
+/// for the items hidden by the search.
+fn bank_tab_view(
+    bank: &Bank,
+    tab_index: usize,
+    bank_settings: &BankSettings,
+    game_data: &GameData,
+) -> Vec<Option<usize>> {
+    let tab_slots = tab_index * BANK_SLOTS_PER_PAGE..(tab_index + 1) * BANK_SLOTS_PER_PAGE;
+    let search = bank_settings.search.trim().to_lowercase();
+    if search.is_empty() && bank_settings.sort == BankSort::Slot {
+        return tab_slots.map(Some).collect();
+    }
+
+    let item = |slot_index: usize| bank.slots.get(slot_index).and_then(|x| x.as_ref());
+    let mut items: Vec<usize> = tab_slots
+        .clone()
+        .filter(|&slot_index| {
+            item(slot_index).map_or(false, |item| {
+                search.is_empty() || bank_item_name(item, game_data).to_lowercase().contains(&search)
+            })
+        })
+        .collect();
+    items.sort_by(|&a, &b| {
+        compare_bank_items(item(a).unwrap(), item(b).unwrap(), bank_settings.sort, game_data)
+    });
+
+    let mut view: Vec<Option<usize>> = items
+        .into_iter()
+        .chain(tab_slots.filter(|&slot_index| item(slot_index).is_none()))
+        .map(Some)
+        .collect();
+    view.resize(BANK_SLOTS_PER_PAGE, None);
+    view
+}
+
 pub fn ui_bank_system(
     mut egui_context: EguiContexts,
     mut ui_state: Local<UiStateBank>,
@@ -114,10 +215,11 @@ pub fn ui_bank_system(
     client_entity_list: Res<ClientEntityList>,
     game_connection: Option<Res<GameConnection>>,
     game_data: Res<GameData>,
-    query_player: Query<(&Bank, &CharacterInfo, &Position), With<PlayerCharacter>>,
+    mut bank_settings: ResMut<BankSettings>,
+    query_player: Query<(&Bank, &CharacterInfo, &Position, &Inventory), With<PlayerCharacter>>,
     query_player_tooltip: Query<PlayerTooltipQuery, With<PlayerCharacter>>,
     query_position: Query<&Position>,
-    mut player_command_events: MessageWriter<PlayerCommandEvent>,
+    mut message_writers: BankMessageWriters,
 ) {
     let dialog = if let Some(dialog) = dialog_assets.get(&ui_resources.dialog_bank) {
         dialog
@@ -178,9 +280,10 @@ pub fn ui_bank_system(
     }
 
     let mut response_close_button = None;
+    let bank = player.0;
 
     let ctx = egui_context.ctx_mut().unwrap();
-    egui::Window::new("Bank")
+    let window_response = egui::Window::new("Bank")
         .frame(egui::Frame::none())
         .title_bar(false)
         .resizable(false)
@@ -234,8 +337,11 @@ pub fn ui_bank_system(
                         _ => 0,
                     };
 
-                    for slot in 0..BANK_SLOTS_PER_PAGE {
-                        let slot_index = tab_index * BANK_SLOTS_PER_PAGE + slot;
+                    let view = bank_tab_view(bank, tab_index, &bank_settings, &game_data);
+                    for (slot, slot_index) in view.into_iter().enumerate() {
+                        let Some(slot_index) = slot_index else {
+                            continue;
+                        };
                         let slot_column = slot % BANK_SLOTS_PER_ROW;
                         let slot_row = slot / BANK_SLOTS_PER_ROW;
                         let pos = egui::pos2(
@@ -247,18 +353,81 @@ pub fn ui_bank_system(
                             ui,
                             slot_index,
                             pos,
-                            &player,
+                            bank,
                             player_tooltip_data.as_ref(),
                             &game_data,
                             &ui_resources,
                             &mut ui_state_dnd,
-                            &mut player_command_events,
+                            &mut message_writers,
                         );
                     }
                 },
             );
         });
 
+    // Search, sort and quick deposit below the bank dialog
+    let mut deposit_page_types: Vec<InventoryPageType> = Vec::new();
+    if let Some(window_response) = window_response {
+        let used_slots = bank.slots.iter().filter(|slot| slot.is_some()).count();
+
+        egui::Area::new(egui::Id::new("bank_tools"))
+            .order(egui::Order::Foreground)
+            .fixed_pos(window_response.response.rect.left_bottom())
+            .show(&*ctx, |ui| {
+                egui::Frame::window(ui.style()).show(ui, |ui| {
+                    ui.set_width(dialog.width - 16.0);
+                    ui.horizontal(|ui| {
+                        ui.label("Search:");
+                        ui.add(egui::TextEdit::singleline(&mut bank_settings.search).desired_width(110.0));
+                        if !bank_settings.search.is_empty() && ui.small_button("x").clicked() {
+                            bank_settings.search.clear();
+                        }
+
+                        let mut sort = bank_settings.sort;
+                        egui::ComboBox::from_id_salt("bank_sort")
+                            .width(80.0)
+                            .selected_text(sort.name())
+                            .show_ui(ui, |ui| {
+                                for option in BankSort::ALL {
+                                    ui.selectable_value(&mut sort, option, option.name());
+                                }
+                            });
+                        if sort != bank_settings.sort {
+                            bank_settings.sort = sort;
+                        }
+                    });
+
+                    ui.horizontal(|ui| {
+                        ui.label(format!("Used: {} / {}", used_slots, bank.slots.len()));
+
+                        ui.menu_button("Deposit", |ui| {
+                            if ui.button("All").clicked() {
+                                deposit_page_types.extend(DEPOSIT_PAGE_TYPES.iter().map(|(page_type, _)| *page_type));
+                            }
+                            ui.separator();
+                            for (page_type, name) in DEPOSIT_PAGE_TYPES {
+                                if ui.button(format!("All {}", name)).clicked() {
+                                    deposit_page_types.push(page_type);
+                                }
+                            }
+                        });
+                    });
+                });
+            });
+    }
+
+    // Each item goes through the normal deposit request, the server decides where it goes
+    for page_type in deposit_page_types {
+        for index in 0..INVENTORY_PAGE_SIZE {
+            let item_slot = ItemSlot::Inventory(page_type, index);
+            if player.3.get_item(item_slot).is_some() {
+                message_writers
+                    .player_command_events
+                    .write(PlayerCommandEvent::BankDepositItem(item_slot));
+            }
+        }
+    }
+
     if response_close_button.map_or(false, |r| r.clicked()) {
         ui_state_windows.bank_open = false;
     }
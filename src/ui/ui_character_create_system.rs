@@ -1,5 +1,5 @@
 use bevy::{
-    ecs::message::MessageWriter,
+    ecs::message::{MessageReader, MessageWriter},
     prelude::{
         AssetServer, Assets, Camera3d, Commands, Entity,
         GlobalTransform, Local, Quat, Query, Res, ResMut, Transform, Vec3, Visibility,
@@ -11,12 +11,13 @@ use bevy_egui::{egui, EguiContexts};
 use rose_data::ZoneId;
 use rose_game_common::{
     components::{CharacterGender, CharacterInfo, Equipment},
-    messages::client::ClientMessage,
+    messages::{client::ClientMessage, server::CreateCharacterError},
 };
 
 use crate::{
     animation::CameraAnimation,
-    resources::{CharacterSelectState, UiResources, WorldConnection},
+    events::WorldConnectionEvent,
+    resources::{CharacterNameRules, CharacterSelectState, UiResources, WorldConnection},
     ui::{
         widgets::{DataBindings, Dialog, DrawTextTrait},
         UiSoundEvent,
@@ -65,6 +66,9 @@ pub struct UiCharacterCreateState {
     face_index: usize,
     startpos_index: usize,
     birthstone_index: usize,
+    /// Showing the summary of the new character before sending it to the server
+    confirming: bool,
+    /// Name rejected by the server, shown under the name until it is edited
     error_message: String,
 }
 
@@ -79,6 +83,7 @@ impl Default for UiCharacterCreateState {
             face_index: 0,
             startpos_index: 0,
             birthstone_index: 0,
+            confirming: false,
             error_message: String::new(),
         }
     }
@@ -96,8 +101,26 @@ pub fn ui_character_create_system(
     dialog_assets: Res<Assets<Dialog>>,
     ui_resources: Res<UiResources>,
     world_connection: Option<Res<WorldConnection>>,
+    character_name_rules: Res<CharacterNameRules>,
+    mut world_connection_events: MessageReader<WorldConnectionEvent>,
 ) {
     let ui_state = &mut *ui_state;
+
+    // Name rejections are shown in the dialog, other errors use a message box
+    for event in world_connection_events.read() {
+        if let WorldConnectionEvent::CreateCharacterError { error } = event {
+            match error {
+                CreateCharacterError::AlreadyExists => {
+                    ui_state.error_message = "Character name already exists".to_string();
+                }
+                CreateCharacterError::InvalidValue => {
+                    ui_state.error_message = "Character name was refused by the server".to_string();
+                }
+                _ => {}
+            }
+        }
+    }
+
     if !matches!(
         *character_select_state,
         CharacterSelectState::CharacterCreate
@@ -107,6 +130,7 @@ pub fn ui_character_create_system(
         }
 
         ui_state.initial_focus_set = false;
+        ui_state.confirming = false;
         return;
     }
 
@@ -135,6 +159,7 @@ pub fn ui_character_create_system(
     let mut response_next_hair = None;
     let mut response_next_startpos = None;
     let mut response_next_birthstone = None;
+    let name_validation = character_name_rules.validate(&ui_state.name);
 
     let ctx = egui_context.ctx_mut().unwrap();
     let screen_size = ctx
@@ -154,6 +179,7 @@ pub fn ui_character_create_system(
                 DataBindings {
                     sound_events: Some(&mut ui_sound_events),
                     text: &mut [(IID_EDITBOX, &mut ui_state.name)],
+                    enabled: &mut [(IID_BTN_OK, name_validation.is_ok() && !ui_state.confirming)],
                     response: &mut [
                         (IID_BTN_OK, &mut response_ok),
                         (IID_BTN_CANCEL, &mut response_cancel),
@@ -199,6 +225,31 @@ pub fn ui_character_create_system(
             )
         });
 
+    if let Some(response_editbox) = response_editbox.as_ref() {
+        if response_editbox.changed() {
+            ui_state.error_message.clear();
+        }
+
+        // Nothing to complain about until something has been typed
+        let feedback = if !ui_state.error_message.is_empty() {
+            Some(ui_state.error_message.clone())
+        } else if ui_state.name.is_empty() {
+            None
+        } else {
+            name_validation.as_ref().err().map(|error| error.message())
+        };
+
+        if let Some(feedback) = feedback {
+            egui::Area::new(egui::Id::new("character_create_name_feedback"))
+                .order(egui::Order::Foreground)
+                .interactable(false)
+                .fixed_pos(response_editbox.rect.left_bottom() + egui::vec2(0.0, 2.0))
+                .show(&*ctx, |ui| {
+                    ui.label(egui::RichText::new(feedback).color(egui::Color32::from_rgb(255, 90, 90)));
+                });
+        }
+    }
+
     if !ui_state.initial_focus_set {
         if let Some(response_editbox) = response_editbox {
             if !response_editbox.has_focus() {
@@ -282,22 +333,78 @@ pub fn ui_character_create_system(
         }
     }
 
-    if response_ok.map_or(false, |r| r.clicked()) && ui_state.name.len() > 3 {
-        world_connection
-            .client_message_tx
-            .send(ClientMessage::CreateCharacter {
-                gender: ui_state.gender,
-                birth_stone: ui_state.birthstone_index as i32,
-                hair: CREATE_CHARACTER_HAIR_LIST[ui_state.hair_index],
-                face: CREATE_CHARACTER_FACE_LIST[ui_state.face_index],
-                name: ui_state.name.clone(),
-                start_point: ui_state.startpos_index as i32,
-                hair_color: 1,
-                weapon_type: 0,
-            })
-            .ok();
-
-        *character_select_state = CharacterSelectState::CharacterCreating;
+    if response_ok.map_or(false, |r| r.clicked()) && name_validation.is_ok() {
+        ui_state.confirming = true;
+    }
+
+    if ui_state.confirming {
+        let mut create = false;
+        let mut back = false;
+
+        egui::Window::new("Create Character?")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .show(&*ctx, |ui| {
+                egui::Grid::new("character_create_summary")
+                    .num_columns(2)
+                    .spacing([24.0, 4.0])
+                    .show(ui, |ui| {
+                        ui.label("Name");
+                        ui.label(&ui_state.name);
+                        ui.end_row();
+
+                        ui.label("Sex");
+                        ui.label(match ui_state.gender {
+                            CharacterGender::Male => "Male",
+                            CharacterGender::Female => "Female",
+                        });
+                        ui.end_row();
+
+                        ui.label("Face");
+                        ui.label(format!("{}", ui_state.face_index + 1));
+                        ui.end_row();
+
+                        ui.label("Hair");
+                        ui.label(format!("{}", ui_state.hair_index + 1));
+                        ui.end_row();
+
+                        ui.label("Start Position");
+                        ui.label(CREATE_CHARACTER_STARTPOS_LIST[ui_state.startpos_index]);
+                        ui.end_row();
+
+                        ui.label("Birthstone");
+                        ui.label(CREATE_CHARACTER_BIRTHSTONE_LIST[ui_state.birthstone_index]);
+                        ui.end_row();
+                    });
+
+                ui.separator();
+                ui.horizontal(|ui| {
+                    create = ui.button("Create").clicked();
+                    back = ui.button("Back").clicked();
+                });
+            });
+
+        if create {
+            world_connection
+                .client_message_tx
+                .send(ClientMessage::CreateCharacter {
+                    gender: ui_state.gender,
+                    birth_stone: ui_state.birthstone_index as i32,
+                    hair: CREATE_CHARACTER_HAIR_LIST[ui_state.hair_index],
+                    face: CREATE_CHARACTER_FACE_LIST[ui_state.face_index],
+                    name: ui_state.name.clone(),
+                    start_point: ui_state.startpos_index as i32,
+                    hair_color: 1,
+                    weapon_type: 0,
+                })
+                .ok();
+
+            ui_state.confirming = false;
+            *character_select_state = CharacterSelectState::CharacterCreating;
+        } else if back {
+            ui_state.confirming = false;
+        }
     }
 
     if response_cancel.map_or(false, |r| r.clicked()) {
@@ -308,22 +415,11 @@ pub fn ui_character_create_system(
                     asset_server.load("3DDATA/TITLE/CAMERA01_OUTCREATE01.ZMO"),
                 ));
         }
+        ui_state.confirming = false;
+        ui_state.error_message.clear();
         *character_select_state = CharacterSelectState::CharacterSelect(None);
     }
 
-    if !ui_state.error_message.is_empty() {
-        /*
-        // TODO: Show error message box
-          ui.label(
-            egui::RichText::new(format!(
-                "Error: {}",
-                ui_state.error_message
-            ))
-            .color(egui::Color32::RED),
-        );
-        */
-    }
-
     if let Some(create_character_entity) = ui_state.entity {
         if let Ok(mut create_character_info) =
             query_create_character_info.get_mut(create_character_entity)
@@ -5,6 +5,7 @@ use bevy::{
 };
 use bevy_egui::{egui, EguiContexts};
 
+use rose_data::AbilityType;
 use rose_game_common::{
     components::{
         AbilityValues, BasicStatType, BasicStats, CharacterInfo, ExperiencePoints, Level,
@@ -18,7 +19,8 @@ use crate::{
     resources::{GameConnection, GameData, UiResources},
     ui::{
         widgets::{DataBindings, Dialog, DrawTextTrait},
-        UiSoundEvent, UiStateWindows,
+        ui_add_stat_breakdown, AbilityValuesBreakdown, AbilityValuesBreakdownQuery, UiSoundEvent,
+        UiStateWindows,
     },
 };
 
@@ -70,6 +72,7 @@ pub struct PlayerQuery<'w> {
 pub fn ui_character_info_system(
     mut egui_context: EguiContexts,
     query_player: Query<PlayerQuery, With<PlayerCharacter>>,
+    query_player_breakdown: Query<AbilityValuesBreakdownQuery, With<PlayerCharacter>>,
     mut ui_state: Local<UiStateCharacterInfo>,
     mut ui_state_windows: ResMut<UiStateWindows>,
     mut ui_sound_events: MessageWriter<UiSoundEvent>,
@@ -90,6 +93,25 @@ pub fn ui_character_info_system(
         return;
     };
 
+    let player_breakdown = query_player_breakdown.single().ok();
+
+    // Hovering a stat shows how the stat is made up, calculated on hover so it
+    // always reflects the current equipment and status effects
+    let stat_tooltip = |response: egui::Response,
+                        ability_type: AbilityType,
+                        get_value: fn(&AbilityValues) -> i32| {
+        if let Some(player_breakdown) = player_breakdown.as_ref() {
+            response.on_hover_ui(|ui| {
+                ui_add_stat_breakdown(
+                    ui,
+                    game_data.string_database.get_ability_type(ability_type),
+                    &AbilityValuesBreakdown::new(&game_data, player_breakdown),
+                    get_value,
+                );
+            });
+        }
+    };
+
     let ui_state = &mut *ui_state;
     let mut response_close_button = None;
     let mut response_raise_str_button = None;
@@ -156,62 +178,114 @@ pub fn ui_character_info_system(
                         );
                     }
                     Some(&mut IID_TAB_ABILITY) => {
-                        ui.add_label_at(
-                            egui::pos2(58.0, 67.0),
-                            &format!("{}", player.ability_values.get_strength()),
+                        stat_tooltip(
+                            ui.add_label_at(
+                                egui::pos2(58.0, 67.0),
+                                &format!("{}", player.ability_values.get_strength()),
+                            ),
+                            AbilityType::Strength,
+                            |x| x.get_strength(),
                         );
-                        ui.add_label_at(
-                            egui::pos2(58.0, 88.0),
-                            &format!("{}", player.ability_values.get_dexterity()),
+                        stat_tooltip(
+                            ui.add_label_at(
+                                egui::pos2(58.0, 88.0),
+                                &format!("{}", player.ability_values.get_dexterity()),
+                            ),
+                            AbilityType::Dexterity,
+                            |x| x.get_dexterity(),
                         );
-                        ui.add_label_at(
-                            egui::pos2(58.0, 109.0),
-                            &format!("{}", player.ability_values.get_intelligence()),
+                        stat_tooltip(
+                            ui.add_label_at(
+                                egui::pos2(58.0, 109.0),
+                                &format!("{}", player.ability_values.get_intelligence()),
+                            ),
+                            AbilityType::Intelligence,
+                            |x| x.get_intelligence(),
                         );
-                        ui.add_label_at(
-                            egui::pos2(58.0, 130.0),
-                            &format!("{}", player.ability_values.get_concentration()),
+                        stat_tooltip(
+                            ui.add_label_at(
+                                egui::pos2(58.0, 130.0),
+                                &format!("{}", player.ability_values.get_concentration()),
+                            ),
+                            AbilityType::Concentration,
+                            |x| x.get_concentration(),
                         );
-                        ui.add_label_at(
-                            egui::pos2(58.0, 151.0),
-                            &format!("{}", player.ability_values.get_charm()),
+                        stat_tooltip(
+                            ui.add_label_at(
+                                egui::pos2(58.0, 151.0),
+                                &format!("{}", player.ability_values.get_charm()),
+                            ),
+                            AbilityType::Charm,
+                            |x| x.get_charm(),
                         );
-                        ui.add_label_at(
-                            egui::pos2(58.0, 172.0),
-                            &format!("{}", player.ability_values.get_sense()),
+                        stat_tooltip(
+                            ui.add_label_at(
+                                egui::pos2(58.0, 172.0),
+                                &format!("{}", player.ability_values.get_sense()),
+                            ),
+                            AbilityType::Sense,
+                            |x| x.get_sense(),
                         );
                         ui.add_label_at(
                             egui::pos2(69.0, 211.0),
                             &format!("{}", player.stat_points.points),
                         );
 
-                        ui.add_label_at(
-                            egui::pos2(171.0, 67.0),
-                            &format!("{}", player.ability_values.get_attack_power()),
+                        stat_tooltip(
+                            ui.add_label_at(
+                                egui::pos2(171.0, 67.0),
+                                &format!("{}", player.ability_values.get_attack_power()),
+                            ),
+                            AbilityType::Attack,
+                            |x| x.get_attack_power(),
                         );
-                        ui.add_label_at(
-                            egui::pos2(171.0, 88.0),
-                            &format!("{}", player.ability_values.get_defence()),
+                        stat_tooltip(
+                            ui.add_label_at(
+                                egui::pos2(171.0, 88.0),
+                                &format!("{}", player.ability_values.get_defence()),
+                            ),
+                            AbilityType::Defence,
+                            |x| x.get_defence(),
                         );
-                        ui.add_label_at(
-                            egui::pos2(171.0, 109.0),
-                            &format!("{}", player.ability_values.get_resistance()),
+                        stat_tooltip(
+                            ui.add_label_at(
+                                egui::pos2(171.0, 109.0),
+                                &format!("{}", player.ability_values.get_resistance()),
+                            ),
+                            AbilityType::Resistance,
+                            |x| x.get_resistance(),
                         );
-                        ui.add_label_at(
-                            egui::pos2(171.0, 130.0),
-                            &format!("{}", player.ability_values.get_hit()),
+                        stat_tooltip(
+                            ui.add_label_at(
+                                egui::pos2(171.0, 130.0),
+                                &format!("{}", player.ability_values.get_hit()),
+                            ),
+                            AbilityType::Hit,
+                            |x| x.get_hit(),
                         );
-                        ui.add_label_at(
-                            egui::pos2(171.0, 151.0),
-                            &format!("{}", player.ability_values.get_critical()),
+                        stat_tooltip(
+                            ui.add_label_at(
+                                egui::pos2(171.0, 151.0),
+                                &format!("{}", player.ability_values.get_critical()),
+                            ),
+                            AbilityType::Critical,
+                            |x| x.get_critical(),
                         );
-                        ui.add_label_at(
-                            egui::pos2(171.0, 172.0),
-                            &format!("{}", player.ability_values.get_avoid()),
+                        stat_tooltip(
+                            ui.add_label_at(
+                                egui::pos2(171.0, 172.0),
+                                &format!("{}", player.ability_values.get_avoid()),
+                            ),
+                            AbilityType::Avoid,
+                            |x| x.get_avoid(),
                         );
-                        ui.add_label_at(
-                            egui::pos2(171.0, 193.0),
-                            &format!("{}", player.ability_values.get_attack_speed()),
+                        stat_tooltip(
+                            ui.add_label_at(
+                                egui::pos2(171.0, 193.0),
+                                &format!("{}", player.ability_values.get_attack_speed()),
+                            ),
+                            AbilityType::AttackSpeed,
+                            |x| x.get_attack_speed(),
                         );
                         ui.add_label_at(
                             egui::pos2(171.0, 214.0),
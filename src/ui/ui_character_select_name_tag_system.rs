@@ -1,13 +1,14 @@
 use bevy::prelude::{Camera, Camera3d, GlobalTransform, Query, Res, Vec3, With};
 use bevy_egui::{egui, EguiContexts};
 
-use crate::resources::{CharacterList, CharacterSelectState, GameData};
+use crate::resources::{CharacterList, CharacterSelectScene, CharacterSelectState, GameData};
 
 pub fn ui_character_select_name_tag_system(
     mut egui_context: EguiContexts,
     query_camera: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
     character_list: Option<Res<CharacterList>>,
     character_select_state: Res<CharacterSelectState>,
+    character_select_scene: Res<CharacterSelectScene>,
     game_data: Res<GameData>,
 ) {
     for (camera, camera_transform) in query_camera.iter() {
@@ -16,10 +17,12 @@ pub fn ui_character_select_name_tag_system(
                 .as_ref()
                 .and_then(|character_list| character_list.characters.get(index))
             {
+                let Some(position) = character_select_scene.position_transform(index) else {
+                    continue;
+                };
                 if let Ok(screen_pos) = camera.world_to_viewport(
                     camera_transform,
-                    game_data.character_select_positions[index].translation
-                        + Vec3::new(0.0, 4.0, 0.0),
+                    position.translation + Vec3::new(0.0, 4.0, 0.0),
                 ) {
                     if let Ok(ctx) = egui_context.ctx_mut() {
                         let screen_size = ctx.input(|input| input.screen_rect().size());
@@ -7,7 +7,7 @@ use bevy_egui::{egui, EguiContexts};
 use crate::{
     animation::CameraAnimation,
     events::CharacterSelectEvent,
-    resources::{CharacterList, CharacterSelectState, GameData, UiResources},
+    resources::{CharacterList, CharacterSelectScene, CharacterSelectState, UiResources},
     ui::{
         widgets::{DataBindings, Dialog, Widget},
         DialogInstance, UiSoundEvent,
@@ -40,7 +40,7 @@ pub fn ui_character_select_system(
     character_list: Option<Res<CharacterList>>,
     asset_server: Res<AssetServer>,
     dialog_assets: Res<Assets<Dialog>>,
-    game_data: Res<GameData>,
+    character_select_scene: Res<CharacterSelectScene>,
     ui_resources: Res<UiResources>,
     mut ui_sound_events: MessageWriter<UiSoundEvent>,
     mut character_select_events: MessageWriter<CharacterSelectEvent>,
@@ -119,7 +119,7 @@ pub fn ui_character_select_system(
 
     if response_create_button.map_or(false, |r| r.clicked())
         && character_list.as_ref().map_or(true, |character_list| {
-            character_list.characters.len() < game_data.character_select_positions.len()
+            character_list.characters.len() < character_select_scene.positions.len()
         })
     {
         if let Ok(camera_entity) = query_camera.single() {
@@ -1,4 +1,4 @@
-use std::time::Instant;
+use std::{collections::VecDeque, time::Instant};
 
 use bevy::prelude::{
     Assets, Entity, Local, MessageReader, MessageWriter, Query, Res, ResMut, Resource, With,
@@ -11,10 +11,12 @@ use crate::{
     components::PlayerCharacter,
     events::{
         BoardBoatEvent, ChatboxEvent, DisembarkBoatEvent, FlightToggleEvent, MoveSpeedSetEvent,
-        PingRequestEvent, PingState,
+        PingRequestEvent, PingState, PlayerCommandEvent,
+    },
+    resources::{EmoteList, GameConnection, UiResources},
+    systems::{
+        is_boat_command, is_fly_command, is_ping_command, parse_afk_command, parse_chat_input,
     },
-    resources::{GameConnection, UiResources},
-    systems::{is_boat_command, is_fly_command, is_ping_command, parse_chat_input},
     ui::{
         widgets::{DataBindings, Dialog},
         UiSoundEvent,
@@ -70,8 +72,12 @@ pub struct UiStateChatbox {
     textbox_text: String,
     textbox_layout_job: egui::text::LayoutJob,
     cleanup_layout_text_counter: usize,
+    /// Clan chat and clan notices as (timestamp, text, color), shown by the clan tab
+    clan_lines: VecDeque<(String, String, egui::Color32)>,
     selected_channel: i32,
     show_command_help: bool,
+    /// Focus the textbox next frame, e.g. after starting a whisper
+    focus_textbox: bool,
 }
 
 impl Default for UiStateChatbox {
@@ -80,12 +86,51 @@ impl Default for UiStateChatbox {
             textbox_text: Default::default(),
             textbox_layout_job: Default::default(),
             cleanup_layout_text_counter: 0,
+            clan_lines: VecDeque::new(),
             selected_channel: IID_BTN_ALL,
             show_command_help: false,
+            focus_textbox: false,
         }
     }
 }
 
+fn push_clan_line(
+    ui_state_chatbox: &mut UiStateChatbox,
+    timestamp: String,
+    line: String,
+    color: egui::Color32,
+) {
+    if ui_state_chatbox.clan_lines.len() == MAX_CHATBOX_ENTRIES {
+        ui_state_chatbox.clan_lines.pop_front();
+    }
+    ui_state_chatbox.clan_lines.push_back((timestamp, line, color));
+}
+
+fn clan_layout_job(
+    clan_lines: &VecDeque<(String, String, egui::Color32)>,
+) -> egui::text::LayoutJob {
+    let mut layout_job = egui::text::LayoutJob::default();
+    for (timestamp, line, color) in clan_lines.iter() {
+        layout_job.append(
+            &format!("[{}] ", timestamp),
+            0.0,
+            egui::TextFormat {
+                color: CHAT_COLOR_TIMESTAMP,
+                ..Default::default()
+            },
+        );
+        layout_job.append(
+            line,
+            0.0,
+            egui::TextFormat {
+                color: *color,
+                ..Default::default()
+            },
+        );
+    }
+    layout_job
+}
+
 pub fn ui_chatbox_system(
     mut egui_context: EguiContexts,
     mut ui_state_chatbox: Local<UiStateChatbox>,
@@ -101,6 +146,8 @@ pub fn ui_chatbox_system(
     mut ping_request_events: MessageWriter<PingRequestEvent>,
     mut ping_state: ResMut<PingState>,
     player_query: Query<Entity, With<PlayerCharacter>>,
+    emote_list: Res<EmoteList>,
+    mut player_command_events: MessageWriter<PlayerCommandEvent>,
 ) {
     let ui_state_chatbox = &mut *ui_state_chatbox;
     let dialog = if let Some(dialog) = dialog_assets.get(&ui_resources.dialog_chatbox) {
@@ -113,6 +160,13 @@ pub fn ui_chatbox_system(
     let timestamp = local_time.format("%H:%M:%S");
 
     for event in chatbox_events.read() {
+        if let ChatboxEvent::StartWhisper(name) = event {
+            ui_state_chatbox.textbox_text = format!("@{} ", name);
+            ui_state_chatbox.selected_channel = IID_BTN_WHISPER;
+            ui_state_chatbox.focus_textbox = true;
+            continue;
+        }
+
         if ui_state_chatbox.textbox_layout_job.sections.len() == MAX_CHATBOX_ENTRIES {
             ui_state_chatbox.textbox_layout_job.sections.remove(0);
             ui_state_chatbox.cleanup_layout_text_counter += 1;
@@ -184,14 +238,28 @@ pub fn ui_chatbox_system(
                 );
             }
             ChatboxEvent::Clan(name, text) => {
+                let line = format!("{}> {}\n", name, text);
                 ui_state_chatbox.textbox_layout_job.append(
-                    &format!("{}> {}\n", name, text),
+                    &line,
                     0.0,
                     egui::TextFormat {
                         color: CHAT_COLOR_CLAN,
                         ..Default::default()
                     },
                 );
+                push_clan_line(ui_state_chatbox, timestamp.to_string(), line, CHAT_COLOR_CLAN);
+            }
+            ChatboxEvent::ClanNotice(text) => {
+                let line = format!("{}\n", text);
+                ui_state_chatbox.textbox_layout_job.append(
+                    &line,
+                    0.0,
+                    egui::TextFormat {
+                        color: CHAT_COLOR_SYSTEM,
+                        ..Default::default()
+                    },
+                );
+                push_clan_line(ui_state_chatbox, timestamp.to_string(), line, CHAT_COLOR_SYSTEM);
             }
             ChatboxEvent::Allied(name, text) => {
                 ui_state_chatbox.textbox_layout_job.append(
@@ -243,6 +311,7 @@ pub fn ui_chatbox_system(
                     },
                 );
             }
+            ChatboxEvent::StartWhisper(_) => {}
         }
     }
 
@@ -328,7 +397,11 @@ pub fn ui_chatbox_system(
                                 .auto_shrink([false; 2])
                                 .stick_to_bottom(true)
                                 .show(ui, |ui| {
-                                    ui.label(ui_state_chatbox.textbox_layout_job.clone());
+                                    if ui_state_chatbox.selected_channel == IID_BTN_CLAN {
+                                        ui.label(clan_layout_job(&ui_state_chatbox.clan_lines));
+                                    } else {
+                                        ui.label(ui_state_chatbox.textbox_layout_job.clone());
+                                    }
                                 });
                         },
                     );
@@ -373,6 +446,19 @@ pub fn ui_chatbox_system(
                                     ui.end_row();
                                     ui.label("  /boat - Toggle sailing mode");
                                     ui.end_row();
+                                    ui.label("  /sit - Sit down or stand up");
+                                    ui.end_row();
+                                    ui.label("  /afk [message] - Toggle away, whispers get the message");
+                                    ui.end_row();
+                                    if !emote_list.emotes.is_empty() {
+                                        let emote_commands: Vec<String> = emote_list
+                                            .emotes
+                                            .iter()
+                                            .map(|emote| format!("/{}", emote.command))
+                                            .collect();
+                                        ui.label(format!("  {} - Emotes", emote_commands.join(" ")));
+                                        ui.end_row();
+                                    }
                                     
                                     // Server-side commands - Character
                                     ui.label(egui::RichText::new("Server-side - Character:").strong().color(egui::Color32::from_rgb(150, 255, 150)));
@@ -471,6 +557,11 @@ pub fn ui_chatbox_system(
     }
 
     if let Some(response) = response_editbox {
+        if ui_state_chatbox.focus_textbox {
+            response.request_focus();
+            ui_state_chatbox.focus_textbox = false;
+        }
+
         if response
             .ctx
             .input(|input| input.key_pressed(egui::Key::Enter))
@@ -509,20 +600,18 @@ pub fn ui_chatbox_system(
                                 .ok();
                         }
                         ui_state_chatbox.textbox_text.clear();
-                    } else if is_ping_command(&ui_state_chatbox.textbox_text) {
-                        // Handle /ping command client-side
-                        // Record the timestamp and send a ping request
-                        ping_state.pending_ping_timestamp = Some(Instant::now());
-
-                        // Send a chat message to server to measure RTT
-                        if let Some(game_connection) = game_connection.as_ref() {
-                            game_connection
-                                .client_message_tx
-                                .send(ClientMessage::Chat {
-                                    text: "/ping".to_string(),
-                                })
-                                .ok();
-                        }
+                    } else if ui_state_chatbox.textbox_text.trim().eq_ignore_ascii_case("/sit") {
+                        player_command_events.write(PlayerCommandEvent::SitToggle);
+                        ui_state_chatbox.textbox_text.clear();
+                    } else if let Some(message) =
+                        parse_afk_command(&ui_state_chatbox.textbox_text)
+                    {
+                        player_command_events.write(PlayerCommandEvent::Afk(message));
+                        ui_state_chatbox.textbox_text.clear();
+                    } else if let Some(emote) =
+                        emote_list.find_command(&ui_state_chatbox.textbox_text)
+                    {
+                        player_command_events.write(PlayerCommandEvent::Emote(emote.motion_id));
                         ui_state_chatbox.textbox_text.clear();
                     } else {
                         // Check if this is a move speed command
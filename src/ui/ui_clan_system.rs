@@ -4,7 +4,7 @@ use bevy_egui::{egui, EguiContexts};
 use rose_data::ClanMemberPosition;
 
 use crate::{
-    components::{Clan, ClanMembership, PlayerCharacter},
+    components::{Clan, ClanMember, ClanMembership, PlayerCharacter},
     resources::{GameData, UiResources},
     ui::{
         widgets::{DataBindings, Dialog, DrawTextTrait},
@@ -58,6 +58,19 @@ impl Default for UiStateClan {
     }
 }
 
+/// Roster order: online members first, then by rank from the master down
+fn sorted_clan_members(clan: &Clan) -> Vec<&ClanMember> {
+    let mut members: Vec<&ClanMember> = clan.members.iter().collect();
+    members.sort_by(|a, b| {
+        b.channel_id
+            .is_some()
+            .cmp(&a.channel_id.is_some())
+            .then((b.position as u8).cmp(&(a.position as u8)))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    members
+}
+
 pub fn ui_clan_system(
     mut egui_context: EguiContexts,
     query_clan: Query<(&Clan, &ClanMembership), With<PlayerCharacter>>,
@@ -96,6 +109,7 @@ pub fn ui_clan_system(
                     1
                 };
             let member_scrollbar_range = 0..clan.members.len() as i32;
+            let members = sorted_clan_members(clan);
 
             dialog.draw(
                 ui,
@@ -121,7 +135,11 @@ pub fn ui_clan_system(
                                     egui::Sense::click(),
                                 );
 
-                                if let Some(member) = clan.members.get(index as usize) {
+                                let Some(member) = members.get(index as usize) else {
+                                    return response;
+                                };
+
+                                let name_color =
                                     if matches!(member.position, ClanMemberPosition::Penalty) {
                                         egui::Color32::RED
                                     } else if is_selected {
@@ -130,20 +148,48 @@ pub fn ui_clan_system(
                                         egui::Color32::BLACK
                                     };
 
-                                    ui.add_label_at(
-                                        egui::pos2(2.0, 2.0),
-                                        egui::RichText::new(format!(
-                                            "{} ({})",
-                                            member.name,
-                                            game_data
-                                                .string_database
-                                                .get_clan_member_position(member.position)
-                                        ))
-                                        .color(egui::Color32::BLACK),
-                                    );
-                                }
-
-                                response
+                                ui.add_label_at(
+                                    egui::pos2(2.0, 2.0),
+                                    egui::RichText::new(format!(
+                                        "{} ({})",
+                                        member.name,
+                                        game_data
+                                            .string_database
+                                            .get_clan_member_position(member.position)
+                                    ))
+                                    .color(name_color),
+                                );
+
+                                let (status, status_color) = match member.channel_id {
+                                    Some(channel_id) => (
+                                        format!("Ch {}", channel_id),
+                                        egui::Color32::from_rgb(0, 140, 30),
+                                    ),
+                                    None => ("Offline".to_string(), egui::Color32::GRAY),
+                                };
+                                ui.add_label_at(
+                                    egui::pos2(150.0, 2.0),
+                                    egui::RichText::new(status).color(status_color),
+                                );
+
+                                response.on_hover_ui(|ui| {
+                                    ui.label(egui::RichText::new(&member.name).strong());
+                                    ui.label(format!(
+                                        "{} {}",
+                                        game_data.string_database.get_job_name(member.job),
+                                        member.level.level
+                                    ));
+                                    ui.label(format!(
+                                        "Contribution: {}",
+                                        member.contribution.0
+                                    ));
+                                    match member.channel_id {
+                                        Some(channel_id) => {
+                                            ui.label(format!("Online on channel {}", channel_id))
+                                        }
+                                        None => ui.label("Offline"),
+                                    };
+                                })
                             },
                         ),
                     )],
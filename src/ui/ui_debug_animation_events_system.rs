@@ -0,0 +1,151 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::{AssetServer, Entity, Local, MessageReader, Query, Res, ResMut, Time, With};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::{
+    animation::{AnimationFrameEvent, SkeletalAnimation},
+    components::PlayerCharacter,
+    resources::SelectedTarget,
+    ui::UiStateDebugWindows,
+};
+
+/// Most events kept in the log, the oldest are dropped first
+const ANIMATION_EVENT_LOG_SIZE: usize = 200;
+
+struct AnimationEventLogEntry {
+    time: f32,
+    entity: Entity,
+    motion: String,
+    frame: usize,
+    late_by: f32,
+    flags: String,
+}
+
+#[derive(Default, PartialEq, Eq, Clone, Copy)]
+enum AnimationEventFilter {
+    #[default]
+    Player,
+    SelectedTarget,
+    All,
+}
+
+#[derive(Default)]
+pub struct UiStateDebugAnimationEvents {
+    entries: VecDeque<AnimationEventLogEntry>,
+    filter: AnimationEventFilter,
+    paused: bool,
+    log_to_console: bool,
+}
+
+/// Lists which animation events fired on which motion frame, for tuning the
+/// timing of effects and sounds
+pub fn ui_debug_animation_events_system(
+    mut egui_context: EguiContexts,
+    mut ui_state_debug_windows: ResMut<UiStateDebugWindows>,
+    mut ui_state: Local<UiStateDebugAnimationEvents>,
+    mut animation_frame_events: MessageReader<AnimationFrameEvent>,
+    query_player: Query<Entity, With<PlayerCharacter>>,
+    query_animation: Query<&SkeletalAnimation>,
+    selected_target: Res<SelectedTarget>,
+    asset_server: Res<AssetServer>,
+    time: Res<Time>,
+) {
+    if !ui_state_debug_windows.debug_ui_open || !ui_state_debug_windows.animation_events_open {
+        animation_frame_events.clear();
+        return;
+    }
+
+    let ui_state = &mut *ui_state;
+    let player_entity = query_player.single().ok();
+    for event in animation_frame_events.read() {
+        let show = match ui_state.filter {
+            AnimationEventFilter::Player => player_entity == Some(event.entity),
+            AnimationEventFilter::SelectedTarget => selected_target.selected == Some(event.entity),
+            AnimationEventFilter::All => true,
+        };
+        if !show || ui_state.paused {
+            continue;
+        }
+
+        let motion = query_animation
+            .get(event.entity)
+            .ok()
+            .and_then(|animation| asset_server.get_path(animation.motion().id()))
+            .map_or_else(|| "?".to_string(), |path| path.to_string());
+        let entry = AnimationEventLogEntry {
+            time: time.elapsed_secs(),
+            entity: event.entity,
+            motion,
+            frame: event.frame,
+            late_by: event.late_by,
+            flags: format!("{:?}", event.flags),
+        };
+
+        if ui_state.log_to_console {
+            log::info!(
+                "[ANIMATION EVENT] {:?} {} frame {} (+{:.1}ms) {}",
+                entry.entity,
+                entry.motion,
+                entry.frame,
+                entry.late_by * 1000.0,
+                entry.flags
+            );
+        }
+
+        if ui_state.entries.len() >= ANIMATION_EVENT_LOG_SIZE {
+            ui_state.entries.pop_front();
+        }
+        ui_state.entries.push_back(entry);
+    }
+
+    egui::Window::new("Animation Events")
+        .open(&mut ui_state_debug_windows.animation_events_open)
+        .default_width(560.0)
+        .show(egui_context.ctx_mut().unwrap(), |ui| {
+            ui.horizontal(|ui| {
+                ui.selectable_value(&mut ui_state.filter, AnimationEventFilter::Player, "Player");
+                ui.selectable_value(
+                    &mut ui_state.filter,
+                    AnimationEventFilter::SelectedTarget,
+                    "Selected Target",
+                );
+                ui.selectable_value(&mut ui_state.filter, AnimationEventFilter::All, "All");
+                ui.separator();
+                ui.checkbox(&mut ui_state.paused, "Pause");
+                ui.checkbox(&mut ui_state.log_to_console, "Log to console");
+                if ui.button("Clear").clicked() {
+                    ui_state.entries.clear();
+                }
+            });
+
+            ui.separator();
+
+            egui::ScrollArea::vertical()
+                .stick_to_bottom(true)
+                .show(ui, |ui| {
+                    egui::Grid::new("animation_events_grid")
+                        .num_columns(6)
+                        .striped(true)
+                        .show(ui, |ui| {
+                            ui.label("Time");
+                            ui.label("Entity");
+                            ui.label("Motion");
+                            ui.label("Frame");
+                            ui.label("Late");
+                            ui.label("Flags");
+                            ui.end_row();
+
+                            for entry in ui_state.entries.iter() {
+                                ui.label(format!("{:.2}", entry.time));
+                                ui.label(format!("{:?}", entry.entity));
+                                ui.label(&entry.motion);
+                                ui.label(format!("{}", entry.frame));
+                                ui.label(format!("{:.1}ms", entry.late_by * 1000.0));
+                                ui.label(&entry.flags);
+                                ui.end_row();
+                            }
+                        });
+                });
+        });
+}
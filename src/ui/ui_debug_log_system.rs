@@ -0,0 +1,109 @@
+use bevy::prelude::{Local, ResMut};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::{
+    resources::{LogSettings, LOG_LEVELS},
+    ui::UiStateDebugWindows,
+};
+
+/// Noisy modules offered for a quick override without typing their path
+const COMMON_MODULES: [&str; 4] = [
+    "rose_offline_client::zone_loader",
+    "rose_offline_client::map_editor",
+    "rose_offline_client::vfs_asset_io",
+    "rose_offline_client::systems::network_thread_system",
+];
+
+fn level_combo(ui: &mut egui::Ui, id: impl std::hash::Hash, level: &str) -> Option<&'static str> {
+    let mut selected = None;
+    egui::ComboBox::from_id_salt(id)
+        .selected_text(level)
+        .show_ui(ui, |ui| {
+            for option in LOG_LEVELS {
+                if ui.selectable_label(option == level, option).clicked() {
+                    selected = Some(option);
+                }
+            }
+        });
+    selected.filter(|selected| *selected != level)
+}
+
+pub fn ui_debug_log_system(
+    mut egui_context: EguiContexts,
+    mut ui_state_debug_windows: ResMut<UiStateDebugWindows>,
+    mut log_settings: ResMut<LogSettings>,
+    mut new_module: Local<String>,
+) {
+    if !ui_state_debug_windows.debug_ui_open {
+        return;
+    }
+
+    egui::Window::new("Log Levels")
+        .open(&mut ui_state_debug_windows.log_levels_open)
+        .show(egui_context.ctx_mut().unwrap(), |ui| {
+            ui.horizontal(|ui| {
+                ui.label("Global:");
+                if let Some(level) = level_combo(ui, "log_level_global", &log_settings.level) {
+                    log_settings.level = level.to_string();
+                }
+            });
+
+            ui.separator();
+
+            let mut remove_index = None;
+            let mut changed_level = None;
+            egui::Grid::new("log_level_modules")
+                .num_columns(3)
+                .striped(true)
+                .show(ui, |ui| {
+                    for (index, module) in log_settings.modules.iter().enumerate() {
+                        ui.label(&module.module);
+                        if let Some(level) = level_combo(ui, ("log_level_module", index), &module.level) {
+                            changed_level = Some((index, level));
+                        }
+                        if ui.small_button("x").on_hover_text("Use the global level").clicked() {
+                            remove_index = Some(index);
+                        }
+                        ui.end_row();
+                    }
+                });
+            // Only borrow mutably on change, the settings are saved when changed
+            if let Some((index, level)) = changed_level {
+                log_settings.modules[index].level = level.to_string();
+            }
+            if let Some(index) = remove_index {
+                log_settings.modules.remove(index);
+            }
+
+            ui.separator();
+
+            ui.horizontal(|ui| {
+                ui.text_edit_singleline(&mut *new_module)
+                    .on_hover_text("Module path, e.g. rose_offline_client::zone_loader");
+                let module = new_module.trim().to_string();
+                if ui
+                    .add_enabled(!module.is_empty(), egui::Button::new("Add"))
+                    .clicked()
+                {
+                    let level = log_settings.level.clone();
+                    log_settings.set_module_level(&module, &level);
+                    new_module.clear();
+                }
+            });
+
+            ui.horizontal_wrapped(|ui| {
+                ui.label("Quiet:");
+                for module in COMMON_MODULES {
+                    let name = module.rsplit("::").next().unwrap_or(module);
+                    let is_quiet = log_settings.module_level(module) == Some("warn");
+                    if ui
+                        .add_enabled(!is_quiet, egui::Button::new(name))
+                        .on_hover_text(format!("Only log warnings and errors from {}", module))
+                        .clicked()
+                    {
+                        log_settings.set_module_level(module, "warn");
+                    }
+                }
+            });
+        });
+}
@@ -0,0 +1,147 @@
+use bevy::prelude::{Res, ResMut};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::{
+    protocol::ProtocolConnectionKind,
+    resources::{NetworkConnectionState, NetworkStats, NETWORK_PACKET_TIMING_HISTORY_LEN},
+    ui::UiStateDebugWindows,
+};
+
+fn format_bytes(bytes: u64) -> String {
+    if bytes >= 1024 * 1024 {
+        format!("{:.2} MiB", bytes as f64 / (1024.0 * 1024.0))
+    } else if bytes >= 1024 {
+        format!("{:.1} KiB", bytes as f64 / 1024.0)
+    } else {
+        format!("{} B", bytes)
+    }
+}
+
+fn packet_timing_graph(ui: &mut egui::Ui, network_stats: &NetworkStats) {
+    let (rect, _) = ui.allocate_exact_size(
+        egui::vec2(ui.available_width().max(240.0), 80.0),
+        egui::Sense::hover(),
+    );
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 2.0, egui::Color32::from_black_alpha(160));
+
+    let max_packet_timing = network_stats
+        .packet_timing_history
+        .iter()
+        .copied()
+        .fold(100.0f32, f32::max);
+    let points: Vec<egui::Pos2> = network_stats
+        .packet_timing_history
+        .iter()
+        .enumerate()
+        .map(|(index, packet_timing)| {
+            egui::pos2(
+                rect.left()
+                    + rect.width() * index as f32 / (NETWORK_PACKET_TIMING_HISTORY_LEN - 1) as f32,
+                rect.bottom() - rect.height() * (packet_timing / max_packet_timing).min(1.0),
+            )
+        })
+        .collect();
+
+    if points.len() > 1 {
+        painter.add(egui::Shape::line(
+            points,
+            egui::Stroke::new(1.5, egui::Color32::LIGHT_GREEN),
+        ));
+    }
+
+    painter.text(
+        rect.left_top() + egui::vec2(4.0, 2.0),
+        egui::Align2::LEFT_TOP,
+        format!("{:.0} ms", max_packet_timing),
+        egui::FontId::monospace(10.0),
+        egui::Color32::GRAY,
+    );
+}
+
+pub fn ui_debug_network_system(
+    mut egui_context: EguiContexts,
+    mut ui_state_debug_windows: ResMut<UiStateDebugWindows>,
+    network_stats: Res<NetworkStats>,
+) {
+    if !ui_state_debug_windows.debug_ui_open {
+        return;
+    }
+
+    egui::Window::new("Network")
+        .open(&mut ui_state_debug_windows.network_open)
+        .resizable(false)
+        .show(egui_context.ctx_mut().unwrap(), |ui| {
+            egui::Grid::new("network_packet_timing_grid")
+                .num_columns(2)
+                .show(ui, |ui| {
+                    ui.label("Packet timing:").on_hover_text(
+                        "Time from a sent packet to the next received packet, not a round trip",
+                    );
+                    if let Some(packet_timing_ms) = network_stats.packet_timing_ms {
+                        ui.label(format!("{:.0} ms", packet_timing_ms));
+                    } else {
+                        ui.label("-");
+                    }
+                    ui.end_row();
+                });
+
+            packet_timing_graph(ui, &network_stats);
+
+            ui.separator();
+
+            egui::Grid::new("network_connection_grid")
+                .num_columns(6)
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.label("Connection");
+                    ui.label("State");
+                    ui.label("Sent/s");
+                    ui.label("Recv/s");
+                    ui.label("Total Sent");
+                    ui.label("Total Recv");
+                    ui.end_row();
+
+                    for (name, connection) in [
+                        ("Login", ProtocolConnectionKind::Login),
+                        ("World", ProtocolConnectionKind::World),
+                        ("Game", ProtocolConnectionKind::Game),
+                    ] {
+                        let stats = network_stats.connection(connection);
+                        ui.label(name);
+                        match stats.state {
+                            NetworkConnectionState::Disconnected => {
+                                ui.colored_label(egui::Color32::GRAY, "Disconnected")
+                            }
+                            NetworkConnectionState::Connecting => {
+                                ui.colored_label(egui::Color32::YELLOW, "Connecting")
+                            }
+                            NetworkConnectionState::Connected => {
+                                ui.colored_label(egui::Color32::GREEN, "Connected")
+                            }
+                        };
+                        ui.label(format!(
+                            "{} pk, {}",
+                            stats.packets_sent_per_sec,
+                            format_bytes(stats.bytes_sent_per_sec as u64)
+                        ));
+                        ui.label(format!(
+                            "{} pk, {}",
+                            stats.packets_received_per_sec,
+                            format_bytes(stats.bytes_received_per_sec as u64)
+                        ));
+                        ui.label(format!(
+                            "{} pk, {}",
+                            stats.total_packets_sent,
+                            format_bytes(stats.total_bytes_sent)
+                        ));
+                        ui.label(format!(
+                            "{} pk, {}",
+                            stats.total_packets_received,
+                            format_bytes(stats.total_bytes_received)
+                        ));
+                        ui.end_row();
+                    }
+                });
+        });
+}
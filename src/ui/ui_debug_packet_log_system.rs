@@ -0,0 +1,100 @@
+use bevy::prelude::{Res, ResMut};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::{
+    protocol::PacketDirection,
+    resources::{NetworkLogging, NetworkThread},
+    ui::UiStateDebugWindows,
+};
+
+pub fn ui_debug_packet_log_system(
+    mut egui_context: EguiContexts,
+    mut ui_state_debug_windows: ResMut<UiStateDebugWindows>,
+    mut network_logging: ResMut<NetworkLogging>,
+    network_thread: Res<NetworkThread>,
+) {
+    if !ui_state_debug_windows.debug_ui_open || !ui_state_debug_windows.packet_log_open {
+        return;
+    }
+
+    egui::Window::new("Packet Log")
+        .open(&mut ui_state_debug_windows.packet_log_open)
+        .default_width(560.0)
+        .show(egui_context.ctx_mut().unwrap(), |ui| {
+            egui::Grid::new("packet_log_settings_grid")
+                .num_columns(2)
+                .show(ui, |ui| {
+                    ui.label("Logging:");
+                    let mut enabled = network_logging.enabled;
+                    if ui.checkbox(&mut enabled, "Enabled").changed() {
+                        network_logging.enabled = enabled;
+                    }
+                    ui.end_row();
+
+                    ui.label("Opcode Filter:");
+                    let mut opcode_filter = network_logging.opcode_filter.clone();
+                    if ui
+                        .add(
+                            egui::TextEdit::singleline(&mut opcode_filter)
+                                .hint_text("e.g. 7EC, 79F"),
+                        )
+                        .changed()
+                    {
+                        network_logging.opcode_filter = opcode_filter;
+                    }
+                    ui.end_row();
+
+                    ui.label("Log File:");
+                    ui.horizontal(|ui| {
+                        let mut log_to_file = network_logging.log_to_file;
+                        if ui.checkbox(&mut log_to_file, "").changed() {
+                            network_logging.log_to_file = log_to_file;
+                        }
+
+                        let mut log_file_path = network_logging.log_file_path.clone();
+                        if ui
+                            .add_enabled(
+                                !network_logging.log_to_file,
+                                egui::TextEdit::singleline(&mut log_file_path),
+                            )
+                            .changed()
+                        {
+                            network_logging.log_file_path = log_file_path;
+                        }
+                    });
+                    ui.end_row();
+                });
+
+            if ui.button("Clear").clicked() {
+                network_thread.packet_logger.clear();
+            }
+
+            ui.separator();
+
+            let entries = network_thread.packet_logger.entries();
+            egui::ScrollArea::vertical()
+                .stick_to_bottom(true)
+                .auto_shrink([false, false])
+                .show(ui, |ui| {
+                    for (index, entry) in entries.iter().enumerate() {
+                        let direction = match entry.direction {
+                            PacketDirection::Inbound => "<-",
+                            PacketDirection::Outbound => "->",
+                        };
+
+                        egui::CollapsingHeader::new(format!(
+                            "[{:.3}] {:?} {} [{:03X}] {} bytes",
+                            entry.time.as_secs_f64(),
+                            entry.connection,
+                            direction,
+                            entry.command,
+                            entry.size
+                        ))
+                        .id_salt(("packet_log_entry", index, entry.time))
+                        .show(ui, |ui| {
+                            ui.label(egui::RichText::new(&entry.dump).monospace());
+                        });
+                    }
+                });
+        });
+}
@@ -6,7 +6,10 @@ use bevy_egui::{egui, EguiContexts};
 
 use crate::{
     components::{EventObject, WarpObject},
-    resources::DebugRenderConfig,
+    dds_image_loader::{
+        clear_dds_load_failures, dds_downsample_stats, dds_load_failures, dds_mipmap_stats,
+    },
+    resources::{DebugRenderConfig, DebugRenderIsolation},
     ui::UiStateDebugWindows,
 };
 
@@ -70,6 +73,80 @@ pub fn ui_debug_render_system(
                 // This functionality needs to be reimplemented with new ExtendedMaterial pattern
             }
 
+            ui.separator();
+            ui.checkbox(&mut debug_render_config.mouse_pick, "Show Cursor Pick");
+            ui.checkbox(&mut debug_render_config.wireframe, "Wireframe");
+            ui.checkbox(&mut debug_render_config.overdraw, "Overdraw Heatmap");
+            egui::ComboBox::from_label("Isolate")
+                .selected_text(match debug_render_config.isolation {
+                    DebugRenderIsolation::All => "Everything",
+                    DebugRenderIsolation::Terrain => "Only Terrain",
+                    DebugRenderIsolation::Objects => "Only Objects",
+                    DebugRenderIsolation::Effects => "Only Effects",
+                })
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(
+                        &mut debug_render_config.isolation,
+                        DebugRenderIsolation::All,
+                        "Everything",
+                    );
+                    ui.selectable_value(
+                        &mut debug_render_config.isolation,
+                        DebugRenderIsolation::Terrain,
+                        "Only Terrain",
+                    );
+                    ui.selectable_value(
+                        &mut debug_render_config.isolation,
+                        DebugRenderIsolation::Objects,
+                        "Only Objects",
+                    );
+                    ui.selectable_value(
+                        &mut debug_render_config.isolation,
+                        DebugRenderIsolation::Effects,
+                        "Only Effects",
+                    );
+                });
+
+            ui.separator();
+            let mipmap_stats = dds_mipmap_stats();
+            ui.label(format!(
+                "Texture mipmaps: {} shipped, {} generated, {} without",
+                mipmap_stats.shipped, mipmap_stats.generated, mipmap_stats.without
+            ));
+
+            let downsample_stats = dds_downsample_stats();
+            ui.label(format!(
+                "Textures downsampled: {} ({:.1} MB saved)",
+                downsample_stats.textures,
+                downsample_stats.bytes_saved as f64 / (1024.0 * 1024.0)
+            ));
+
+            let texture_failures = dds_load_failures();
+            egui::CollapsingHeader::new(format!(
+                "Texture Load Failures ({})",
+                texture_failures.len()
+            ))
+            .id_salt("texture_load_failures")
+            .show(ui, |ui| {
+                if texture_failures.is_empty() {
+                    ui.label("No textures have failed to load.");
+                    return;
+                }
+
+                if ui.button("Clear").clicked() {
+                    clear_dds_load_failures();
+                }
+
+                egui::ScrollArea::vertical()
+                    .max_height(200.0)
+                    .show(ui, |ui| {
+                        for failure in texture_failures.iter() {
+                            ui.label(&failure.path)
+                                .on_hover_text(&failure.reason);
+                        }
+                    });
+            });
+
             ui.separator();
             // TODO: GizmoConfig fields changed in Bevy 0.13 - line_width and depth_bias no longer exist
             // ui.label("Gizmo line width:");
@@ -0,0 +1,60 @@
+use bevy::prelude::ResMut;
+use bevy_egui::{egui, EguiContexts};
+
+use crate::{
+    resources::{WeatherKind, WeatherOverride},
+    ui::UiStateDebugWindows,
+};
+
+pub fn ui_debug_weather_system(
+    mut egui_context: EguiContexts,
+    mut ui_state_debug_windows: ResMut<UiStateDebugWindows>,
+    mut weather_override: ResMut<WeatherOverride>,
+) {
+    if !ui_state_debug_windows.debug_ui_open {
+        return;
+    }
+
+    egui::Window::new("Weather")
+        .open(&mut ui_state_debug_windows.weather_open)
+        .show(egui_context.ctx_mut().unwrap(), |ui| {
+            ui.horizontal_wrapped(|ui| {
+                ui.selectable_value(&mut weather_override.weather, None, "Auto")
+                    .on_hover_text("Use the season from the settings");
+                for kind in WeatherKind::ALL {
+                    if ui
+                        .selectable_value(
+                            &mut weather_override.weather,
+                            Some(kind),
+                            kind.display_name(),
+                        )
+                        .clicked()
+                    {
+                        weather_override.fog_density = kind.default_fog_density();
+                    }
+                }
+            });
+
+            ui.separator();
+
+            ui.add_enabled_ui(weather_override.weather.is_some(), |ui| {
+                egui::Grid::new("weather_override_grid")
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        ui.label("Intensity:");
+                        ui.add(
+                            egui::Slider::new(&mut weather_override.intensity, 0.0..=3.0)
+                                .suffix("x"),
+                        );
+                        ui.end_row();
+
+                        ui.label("Fog Density:");
+                        ui.add(
+                            egui::Slider::new(&mut weather_override.fog_density, 0.0..=0.05)
+                                .logarithmic(true),
+                        );
+                        ui.end_row();
+                    });
+            });
+        });
+}
@@ -30,6 +30,7 @@ impl Default for DebugCameraType {
 pub struct UiStateDebugWindows {
     pub debug_ui_open: bool,
 
+    pub animation_events_open: bool,
     pub camera_info_open: bool,
     pub client_entity_list_open: bool,
     pub command_viewer_open: bool,
@@ -37,10 +38,14 @@ pub struct UiStateDebugWindows {
     pub dialog_list_open: bool,
     pub effect_list_open: bool,
     pub item_list_open: bool,
+    pub log_levels_open: bool,
+    pub network_open: bool,
     pub npc_list_open: bool,
     pub object_inspector_open: bool,
+    pub packet_log_open: bool,
     pub physics_open: bool,
     pub skill_list_open: bool,
+    pub weather_open: bool,
     pub zone_list_open: bool,
     pub zone_lighting_open: bool,
     pub zone_time_open: bool,
@@ -51,6 +56,13 @@ pub struct UiStateDebugMenu {
     selected_camera_type: DebugCameraType,
 }
 
+fn attach_orbit_camera(commands: &mut Commands, camera_entity: Entity, player_entity: Entity) {
+    commands
+        .entity(camera_entity)
+        .remove::<FreeCamera>()
+        .insert(OrbitCamera::new(player_entity, Vec3::new(0.0, 1.7, 0.0), 17.0));
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn ui_debug_menu_system(
     mut commands: Commands,
@@ -69,6 +81,18 @@ pub fn ui_debug_menu_system(
         ui_state_debug_windows.debug_ui_open = !ui_state_debug_windows.debug_ui_open;
     }
 
+    // Snap a detached free camera back to the player
+    if keyboard.just_pressed(KeyCode::Home)
+        && ui_state_debug_menu.selected_camera_type == DebugCameraType::Free
+    {
+        if let Ok(player_entity) = query_player.single() {
+            for (camera_entity, _) in query_cameras.iter() {
+                attach_orbit_camera(&mut commands, camera_entity, player_entity);
+            }
+            ui_state_debug_menu.selected_camera_type = DebugCameraType::Orbit;
+        }
+    }
+
     if !ui_state_debug_windows.debug_ui_open {
         return;
     }
@@ -127,25 +151,27 @@ pub fn ui_debug_menu_system(
                     );
                 }
 
-                ui.selectable_value(
-                    &mut ui_state_debug_menu.selected_camera_type,
-                    DebugCameraType::Free,
-                    "Free",
-                );
+                // Taking control of the camera during gameplay is only for debug builds
+                if player_entity.is_none() {
+                    ui.selectable_value(
+                        &mut ui_state_debug_menu.selected_camera_type,
+                        DebugCameraType::Free,
+                        "Free",
+                    );
+                } else if cfg!(debug_assertions) {
+                    ui.selectable_value(
+                        &mut ui_state_debug_menu.selected_camera_type,
+                        DebugCameraType::Free,
+                        "Free (Home to return)",
+                    );
+                }
 
                 if ui_state_debug_menu.selected_camera_type != previous_camera_type {
                     for (camera_entity, camera_transform) in query_cameras.iter() {
                         match ui_state_debug_menu.selected_camera_type {
                             DebugCameraType::Orbit => {
                                 if let Some(player_entity) = player_entity {
-                                    commands
-                                        .entity(camera_entity)
-                                        .remove::<FreeCamera>()
-                                        .insert(OrbitCamera::new(
-                                            player_entity,
-                                            Vec3::new(0.0, 1.7, 0.0),
-                                            17.0,
-                                        ));
+                                    attach_orbit_camera(&mut commands, camera_entity, player_entity);
                                 }
                             }
                             DebugCameraType::Free => {
@@ -191,6 +217,9 @@ pub fn ui_debug_menu_system(
                 ui.checkbox(&mut ui_state_debug_windows.dialog_list_open, "Dialog List");
                 ui.checkbox(&mut ui_state_debug_windows.effect_list_open, "Effect List");
                 ui.checkbox(&mut ui_state_debug_windows.item_list_open, "Item List");
+                ui.checkbox(&mut ui_state_debug_windows.log_levels_open, "Log Levels");
+                ui.checkbox(&mut ui_state_debug_windows.network_open, "Network");
+                ui.checkbox(&mut ui_state_debug_windows.packet_log_open, "Packet Log");
                 ui.checkbox(&mut ui_state_debug_windows.npc_list_open, "NPC List");
                 ui.checkbox(&mut ui_state_debug_windows.skill_list_open, "Skill List");
                 ui.checkbox(&mut ui_state_debug_windows.zone_list_open, "Zone List");
@@ -199,6 +228,11 @@ pub fn ui_debug_menu_system(
                     "Zone Lighting",
                 );
                 ui.checkbox(&mut ui_state_debug_windows.zone_time_open, "Zone Time");
+                ui.checkbox(&mut ui_state_debug_windows.weather_open, "Weather");
+                ui.checkbox(
+                    &mut ui_state_debug_windows.animation_events_open,
+                    "Animation Events",
+                );
                 ui.checkbox(
                     &mut ui_state_debug_windows.client_entity_list_open,
                     "Client Entity List",
@@ -3,14 +3,23 @@ use bevy_egui::{egui, EguiContexts};
 use rose_data::WORLD_TICK_DURATION;
 
 use crate::{
-    resources::{CurrentZone, GameData, WorldTime, ZoneTime},
+    resources::{CurrentZone, GameData, TimeScaleSettings, WorldTime, ZoneTime},
     ui::UiStateDebugWindows,
 };
 
+/// Times of day which can be jumped to, in hours
+const TIME_OF_DAY_PRESETS: [(&str, f32); 4] = [
+    ("Dawn", 6.0),
+    ("Noon", 12.0),
+    ("Dusk", 18.0),
+    ("Midnight", 0.0),
+];
+
 #[derive(Default)]
 pub struct UiStateDebugZoneTime {
     pub overwrite_time_enabled: bool,
     pub overwrite_time_value: u32,
+    pub set_time_hours: f32,
 }
 
 pub fn ui_debug_zone_time_system(
@@ -21,6 +30,7 @@ pub fn ui_debug_zone_time_system(
     game_data: Res<GameData>,
     world_time: Res<WorldTime>,
     mut zone_time: ResMut<ZoneTime>,
+    mut time_scale: ResMut<TimeScaleSettings>,
 ) {
     if !ui_state_debug_windows.debug_ui_open {
         return;
@@ -35,6 +45,9 @@ pub fn ui_debug_zone_time_system(
         return;
     }
     let zone_data = zone_data.unwrap();
+    let day_cycle = zone_data.day_cycle.max(1);
+    let world_day_ticks = (world_time.ticks.get_world_time() % day_cycle) as f32
+        + world_time.time_since_last_tick.as_secs_f32() / WORLD_TICK_DURATION.as_secs_f32();
 
     egui::Window::new("Zone Time")
         .open(&mut ui_state_debug_windows.zone_time_open)
@@ -80,6 +93,55 @@ pub fn ui_debug_zone_time_system(
 
             ui.separator();
 
+            egui::Grid::new("zone_time_scale_grid")
+                .num_columns(2)
+                .show(ui, |ui| {
+                    ui.label("Time Scale:");
+                    ui.horizontal(|ui| {
+                        ui.add(
+                            egui::Slider::new(&mut time_scale.multiplier, 0.0..=500.0)
+                                .logarithmic(true)
+                                .suffix("x"),
+                        );
+                        ui.checkbox(&mut time_scale.frozen, "Freeze");
+                    });
+                    ui.end_row();
+
+                    ui.label("Time of Day:");
+                    ui.horizontal(|ui| {
+                        for (name, hours) in TIME_OF_DAY_PRESETS {
+                            if ui.button(name).clicked() {
+                                time_scale.set_time_of_day(world_day_ticks, day_cycle, hours);
+                            }
+                        }
+                    });
+                    ui.end_row();
+
+                    ui.label("Set Time:");
+                    ui.horizontal(|ui| {
+                        ui.add(
+                            egui::Slider::new(
+                                &mut ui_state_debug_zone_time.set_time_hours,
+                                0.0..=24.0,
+                            )
+                            .suffix("h"),
+                        );
+                        if ui.button("Set").clicked() {
+                            time_scale.set_time_of_day(
+                                world_day_ticks,
+                                day_cycle,
+                                ui_state_debug_zone_time.set_time_hours,
+                            );
+                        }
+                        if ui.button("Reset").clicked() {
+                            time_scale.reset();
+                        }
+                    });
+                    ui.end_row();
+                });
+
+            ui.separator();
+
             egui::Grid::new("zone_time_state_grid")
                 .num_columns(2)
                 .show(ui, |ui| {
@@ -0,0 +1,78 @@
+use bevy::{
+    input::ButtonInput,
+    prelude::{KeyCode, Local, MessageWriter, Res},
+};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::{events::PlayerCommandEvent, resources::EmoteList, ui::show_radial_menu};
+
+/// Hold to open the emote wheel at the cursor, release over an emote or click it to play
+const EMOTE_WHEEL_KEY: KeyCode = KeyCode::KeyT;
+
+const EMOTE_WHEEL_RADIUS: f32 = 130.0;
+
+/// The first option of the wheel toggles sitting, the rest are the emotes
+const EMOTE_WHEEL_SIT_INDEX: usize = 0;
+
+pub fn ui_emote_wheel_system(
+    mut egui_context: EguiContexts,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    emote_list: Res<EmoteList>,
+    mut player_command_events: MessageWriter<PlayerCommandEvent>,
+    mut emote_wheel_center: Local<Option<egui::Pos2>>,
+) {
+    let Ok(ctx) = egui_context.ctx_mut() else {
+        return;
+    };
+
+    if emote_wheel_center.is_none()
+        && !ctx.wants_keyboard_input()
+        && keyboard_input.just_pressed(EMOTE_WHEEL_KEY)
+    {
+        *emote_wheel_center = ctx.input(|input| input.pointer.hover_pos());
+    }
+
+    let Some(center) = *emote_wheel_center else {
+        return;
+    };
+
+    let mut options = vec![("Sit", egui::Color32::LIGHT_GRAY)];
+    options.extend(
+        emote_list
+            .emotes
+            .iter()
+            .map(|emote| (emote.name.as_str(), egui::Color32::WHITE)),
+    );
+
+    let response = show_radial_menu(
+        ctx,
+        egui::Id::new("emote_wheel"),
+        center,
+        EMOTE_WHEEL_RADIUS,
+        &options,
+    );
+    let mut selected = response.clicked;
+    if keyboard_input.just_released(EMOTE_WHEEL_KEY) {
+        // Releasing over the centre keeps the wheel open so an emote can be clicked
+        selected = selected.or(response.hovered);
+    }
+
+    match selected {
+        Some(EMOTE_WHEEL_SIT_INDEX) => {
+            player_command_events.write(PlayerCommandEvent::SitToggle);
+        }
+        Some(index) => {
+            if let Some(emote) = emote_list.emotes.get(index - 1) {
+                player_command_events.write(PlayerCommandEvent::Emote(emote.motion_id));
+            }
+        }
+        None => {}
+    }
+
+    if selected.is_some()
+        || response.cancelled
+        || ctx.input(|input| input.key_pressed(egui::Key::Escape))
+    {
+        *emote_wheel_center = None;
+    }
+}
@@ -0,0 +1,157 @@
+use bevy::prelude::{Local, MessageWriter, Query, Res, ResMut, With, Without};
+use bevy_egui::{egui, EguiContexts};
+
+use rose_game_common::messages::client::ClientMessage;
+
+use crate::{
+    components::{ClientEntity, ClientEntityName, ClientEntityType, PartyInfo, PlayerCharacter},
+    events::ChatboxEvent,
+    resources::{
+        current_accessibility, FriendStatus, FriendsList, GameConnection, GameData, SelectedTarget,
+    },
+    ui::UiStateWindows,
+};
+
+#[derive(Default)]
+pub struct UiStateFriends {
+    add_name: String,
+}
+
+pub fn ui_friends_system(
+    mut egui_context: EguiContexts,
+    mut ui_state: Local<UiStateFriends>,
+    mut ui_state_windows: ResMut<UiStateWindows>,
+    mut friends_list: ResMut<FriendsList>,
+    mut chatbox_events: MessageWriter<ChatboxEvent>,
+    query_player: Query<Option<&PartyInfo>, With<PlayerCharacter>>,
+    query_client_entity: Query<&ClientEntity>,
+    query_target: Query<(&ClientEntity, &ClientEntityName), Without<PlayerCharacter>>,
+    selected_target: Res<SelectedTarget>,
+    game_connection: Option<Res<GameConnection>>,
+    game_data: Res<GameData>,
+) {
+    if !ui_state_windows.friends_open {
+        return;
+    }
+
+    let player_in_party = query_player
+        .single()
+        .map_or(false, |party_info| party_info.is_some());
+    let accessibility = current_accessibility();
+    let mut remove_friend = None;
+
+    egui::Window::new("Friends")
+        .open(&mut ui_state_windows.friends_open)
+        .resizable(false)
+        .default_width(260.0)
+        .show(egui_context.ctx_mut().unwrap(), |ui| {
+            ui.horizontal(|ui| {
+                let response = ui.add(
+                    egui::TextEdit::singleline(&mut ui_state.add_name)
+                        .hint_text("Character name")
+                        .desired_width(140.0),
+                );
+                let submitted =
+                    response.lost_focus() && ui.input(|input| input.key_pressed(egui::Key::Enter));
+
+                if ui.button("Add").clicked() || submitted {
+                    if friends_list.add(&ui_state.add_name) {
+                        ui_state.add_name.clear();
+                    }
+                }
+
+                let target_name = selected_target
+                    .selected
+                    .and_then(|entity| query_target.get(entity).ok())
+                    .filter(|(client_entity, _)| {
+                        client_entity.entity_type == ClientEntityType::Character
+                    })
+                    .map(|(_, name)| name.name.clone());
+                if ui
+                    .add_enabled(target_name.is_some(), egui::Button::new("Add Target"))
+                    .clicked()
+                {
+                    if let Some(target_name) = target_name {
+                        friends_list.add(&target_name);
+                    }
+                }
+            });
+
+            ui.separator();
+
+            if friends_list.friends.is_empty() {
+                ui.label("No friends added yet.");
+                return;
+            }
+
+            egui::Grid::new("friends_list")
+                .num_columns(4)
+                .striped(true)
+                .show(ui, |ui| {
+                    for friend in friends_list.friends.iter() {
+                        let (status, status_color) = match friend.status {
+                            FriendStatus::Online { zone_id } => (
+                                zone_id
+                                    .and_then(|zone_id| game_data.zone_list.get_zone(zone_id))
+                                    .map_or_else(
+                                        || "Online".to_string(),
+                                        |zone_data| zone_data.name.to_string(),
+                                    ),
+                                accessibility.positive_color(),
+                            ),
+                            FriendStatus::Offline => ("Offline".to_string(), egui::Color32::GRAY),
+                            FriendStatus::Unknown => {
+                                ("Unknown".to_string(), egui::Color32::DARK_GRAY)
+                            }
+                        };
+
+                        ui.label(&friend.name);
+                        ui.colored_label(status_color, status);
+
+                        let can_whisper = !matches!(friend.status, FriendStatus::Offline);
+                        if ui
+                            .add_enabled(can_whisper, egui::Button::new("Whisper"))
+                            .clicked()
+                        {
+                            chatbox_events.write(ChatboxEvent::StartWhisper(friend.name.clone()));
+                        }
+
+                        ui.horizontal(|ui| {
+                            let invite_entity = friend
+                                .nearby_entity
+                                .and_then(|entity| query_client_entity.get(entity).ok());
+                            if ui
+                                .add_enabled(invite_entity.is_some(), egui::Button::new("Invite"))
+                                .on_disabled_hover_text("Only nearby friends can be invited")
+                                .clicked()
+                            {
+                                if let (Some(client_entity), Some(game_connection)) =
+                                    (invite_entity, game_connection.as_ref())
+                                {
+                                    let message = if player_in_party {
+                                        ClientMessage::PartyInvite {
+                                            invited_entity_id: client_entity.id,
+                                        }
+                                    } else {
+                                        ClientMessage::PartyCreate {
+                                            invited_entity_id: client_entity.id,
+                                        }
+                                    };
+
+                                    game_connection.client_message_tx.send(message).ok();
+                                }
+                            }
+
+                            if ui.button("Remove").clicked() {
+                                remove_friend = Some(friend.name.clone());
+                            }
+                        });
+                        ui.end_row();
+                    }
+                });
+        });
+
+    if let Some(name) = remove_friend {
+        friends_list.remove(&name);
+    }
+}
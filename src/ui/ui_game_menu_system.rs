@@ -131,12 +131,7 @@ pub fn ui_game_menu_system(
     }
 
     if response_button_community.map_or(false, |r| r.clicked()) {
-        message_box_events.write(MessageBoxEvent::Show {
-            message: "Community features are not yet implemented.".to_string(),
-            modal: true,
-            ok: None,
-            cancel: None,
-        });
+        ui_state_windows.friends_open = !ui_state_windows.friends_open;
         ui_state_windows.menu_open = false;
     }
 
@@ -186,6 +181,10 @@ pub fn ui_game_menu_system(
                 ui_state_windows.clan_open = !ui_state_windows.clan_open;
             }
 
+            if input.consume_key(egui::Modifiers::ALT, egui::Key::C) {
+                ui_state_windows.friends_open = !ui_state_windows.friends_open;
+            }
+
             if input.consume_key(egui::Modifiers::ALT, egui::Key::S) {
                 ui_state_windows.skill_list_open = !ui_state_windows.skill_list_open;
             }
@@ -0,0 +1,106 @@
+use bevy::prelude::{Entity, Local, MessageReader, Query, Res, Time, With};
+use bevy_egui::{egui, EguiContexts};
+
+use rose_game_common::components::{AbilityValues, HealthPoints};
+
+use crate::{
+    components::PlayerCharacter,
+    events::HitEvent,
+    resources::{AccessibilitySettings, HealthFeedbackSettings, DAMAGE_PULSE_SECONDS},
+};
+
+/// Width of the vignette as a fraction of the shorter screen side
+const VIGNETTE_WIDTH: f32 = 0.3;
+
+/// Extra strength added by a damage pulse on top of the low health tint
+const DAMAGE_PULSE_STRENGTH: f32 = 0.5;
+
+/// A frame around the screen edge fading from `color` to transparent
+fn vignette_mesh(screen_rect: egui::Rect, width: f32, color: egui::Color32) -> egui::Mesh {
+    let outer = screen_rect;
+    let inner = screen_rect.shrink(width);
+    let corners = |rect: egui::Rect| {
+        [
+            rect.left_top(),
+            rect.right_top(),
+            rect.right_bottom(),
+            rect.left_bottom(),
+        ]
+    };
+
+    let mut mesh = egui::Mesh::default();
+    for (outer, inner) in corners(outer).into_iter().zip(corners(inner)) {
+        mesh.colored_vertex(outer, color);
+        mesh.colored_vertex(inner, egui::Color32::TRANSPARENT);
+    }
+
+    // One quad per edge between consecutive corners
+    for corner in 0..4u32 {
+        let next = (corner + 1) % 4;
+        let (outer_a, inner_a) = (corner * 2, corner * 2 + 1);
+        let (outer_b, inner_b) = (next * 2, next * 2 + 1);
+        mesh.add_triangle(outer_a, outer_b, inner_b);
+        mesh.add_triangle(outer_a, inner_b, inner_a);
+    }
+
+    mesh
+}
+
+pub fn ui_health_vignette_system(
+    mut egui_context: EguiContexts,
+    mut damage_pulse: Local<f32>,
+    mut hit_events: MessageReader<HitEvent>,
+    query_player: Query<(Entity, &HealthPoints, &AbilityValues), With<PlayerCharacter>>,
+    health_feedback_settings: Res<HealthFeedbackSettings>,
+    accessibility_settings: Res<AccessibilitySettings>,
+    time: Res<Time>,
+) {
+    let Ok((player_entity, health_points, ability_values)) = query_player.single() else {
+        hit_events.clear();
+        return;
+    };
+
+    let player_hit = hit_events
+        .read()
+        .any(|event| event.defender == player_entity && event.apply_damage);
+
+    if !health_feedback_settings.enabled || accessibility_settings.disable_health_vignette {
+        *damage_pulse = 0.0;
+        return;
+    }
+
+    if player_hit && health_feedback_settings.damage_pulse {
+        *damage_pulse = 1.0;
+    } else {
+        *damage_pulse = (*damage_pulse - time.delta_secs() / DAMAGE_PULSE_SECONDS).max(0.0);
+    }
+
+    // The respawn dialog is shown instead once the player has died
+    if health_points.hp <= 0 {
+        return;
+    }
+
+    let health_fraction = health_points.hp as f32 / ability_values.get_max_health().max(1) as f32;
+    let strength = (health_feedback_settings.low_health_strength(health_fraction)
+        + *damage_pulse * DAMAGE_PULSE_STRENGTH)
+        .min(1.0);
+    if strength <= 0.0 {
+        return;
+    }
+
+    let ctx = egui_context.ctx_mut().unwrap();
+    let screen_rect = ctx.input(|input| input.screen_rect());
+    let color = accessibility_settings
+        .negative_color()
+        .gamma_multiply(strength * health_feedback_settings.intensity);
+
+    ctx.layer_painter(egui::LayerId::new(
+        egui::Order::Background,
+        egui::Id::new("health_vignette"),
+    ))
+    .add(egui::Shape::mesh(vignette_mesh(
+        screen_rect,
+        screen_rect.width().min(screen_rect.height()) * VIGNETTE_WIDTH,
+        color,
+    )));
+}
@@ -18,6 +18,7 @@ use crate::{
         ui_inventory_system::GetItem,
         widgets::{DataBindings, Dialog, Widget},
         DialogInstance, DragAndDropId, DragAndDropSlot, UiSoundEvent, UiStateDragAndDrop,
+        UiStateWindows,
     },
 };
 
@@ -33,7 +34,6 @@ const IID_NUMBER: i32 = 20;
 pub struct UiStateHotBar {
     dialog_instance: DialogInstance,
     current_page: usize,
-    is_vertical: bool,
 }
 
 impl Default for UiStateHotBar {
@@ -41,7 +41,6 @@ impl Default for UiStateHotBar {
         Self {
             dialog_instance: DialogInstance::new("DLGQUICKBAR.XML"),
             current_page: 0,
-            is_vertical: false,
         }
     }
 }
@@ -199,6 +198,7 @@ pub fn ui_hotbar_system(
     mut egui_context: EguiContexts,
     mut ui_state_hot_bar: Local<UiStateHotBar>,
     mut ui_state_dnd: ResMut<UiStateDragAndDrop>,
+    mut ui_state_windows: ResMut<UiStateWindows>,
     mut ui_sound_events: MessageWriter<UiSoundEvent>,
     mut query_player: Query<PlayerQuery, With<PlayerCharacter>>,
     query_player_tooltip: Query<PlayerTooltipQuery, With<PlayerCharacter>>,
@@ -254,7 +254,7 @@ pub fn ui_hotbar_system(
     let mut response_hnext_button = None;
     let mut response_vprev_button = None;
     let mut response_vnext_button = None;
-    let is_vertical = ui_state_hot_bar.is_vertical;
+    let is_vertical = ui_state_windows.hotbar_vertical;
 
     let screen_size = egui_context
         .ctx_mut().unwrap()
@@ -298,7 +298,7 @@ pub fn ui_hotbar_system(
 
                     for i in 0..HOTBAR_PAGE_SIZE {
                         let hotbar_index = (current_page, i);
-                        let pos = if ui_state_hot_bar.is_vertical {
+                        let pos = if ui_state_windows.hotbar_vertical {
                             egui::vec2(2.0, 39.0 + (41.0) * i as f32 + (2 * i / 8) as f32 * 10.0)
                         } else {
                             egui::vec2(39.0 + (41.0) * i as f32 + (2 * i / 8) as f32 * 9.0, 20.0)
@@ -339,10 +339,10 @@ pub fn ui_hotbar_system(
     }
 
     if response_rotate_button.map_or(false, |r| r.clicked()) {
-        ui_state_hot_bar.is_vertical = !ui_state_hot_bar.is_vertical;
+        ui_state_windows.hotbar_vertical = !ui_state_windows.hotbar_vertical;
 
         if let Some(Widget::Button(button)) = dialog.get_widget_mut(IID_BTN_ROTATE) {
-            if ui_state_hot_bar.is_vertical {
+            if ui_state_windows.hotbar_vertical {
                 button.x = 17.0;
                 button.y = 377.0;
             } else {
@@ -352,7 +352,7 @@ pub fn ui_hotbar_system(
         }
 
         if let Some(Widget::Image(sprite)) = dialog.get_widget_mut(IID_NUMBER) {
-            if ui_state_hot_bar.is_vertical {
+            if ui_state_windows.hotbar_vertical {
                 sprite.x = 21.0;
                 sprite.y = 20.0;
             } else {
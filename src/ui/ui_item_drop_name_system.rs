@@ -1,18 +1,25 @@
 use std::sync::Arc;
 
-use bevy::prelude::{Camera, Camera3d, GlobalTransform, Local, Query, Res, Vec2, With};
+use bevy::{
+    input::ButtonInput,
+    prelude::{Camera, Camera3d, GlobalTransform, KeyCode, Local, Query, Res, Vec2, With},
+};
 use bevy_egui::{egui, EguiContexts};
 
 use rose_data::Item;
 use rose_game_common::components::{DroppedItem, ItemDrop};
 
-use crate::{resources::GameData, ui::get_item_name_color};
+use crate::{
+    resources::{GameData, LootFilterSettings, LootRarity, NameTagSettings},
+    ui::get_item_name_color,
+};
 
 pub struct ItemDropName {
     screen_z: f32,
     pos: egui::Pos2,
     galley: Arc<egui::Galley>,
     colour: egui::Color32,
+    opacity: f32,
 }
 
 pub fn ui_item_drop_name_system(
@@ -20,6 +27,9 @@ pub fn ui_item_drop_name_system(
     query_camera: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
     query_item_drop: Query<(&ItemDrop, &GlobalTransform)>,
     game_data: Res<GameData>,
+    name_tag_settings: Res<NameTagSettings>,
+    loot_filter_settings: Res<LootFilterSettings>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
     mut visible_names: Local<Vec<ItemDropName>>,
 ) {
     let ctx = egui_context.ctx_mut().unwrap();
@@ -34,6 +44,9 @@ pub fn ui_item_drop_name_system(
         return;
     };
 
+    // Holding Alt shows the names of all item drops, including filtered ones
+    let show_filtered = keyboard_input.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]);
+
     visible_names.clear();
     visible_names.reserve(32);
 
@@ -41,6 +54,23 @@ pub fn ui_item_drop_name_system(
         let Some(dropped_item) = &item_drop.item else {
             continue;
         };
+        if !show_filtered
+            && !loot_filter_settings.show_name
+                [LootRarity::from_dropped_item(dropped_item, &game_data)]
+        {
+            continue;
+        }
+
+        let opacity = name_tag_settings.get_opacity(
+            name_tag_settings.item_drop_max_distance,
+            camera_transform
+                .translation()
+                .distance(global_transform.translation()),
+        );
+        if opacity <= 0.0 {
+            continue;
+        }
+
         let Some(ndc_space_coords) =
             camera.world_to_ndc(camera_transform, global_transform.translation())
         else {
@@ -86,6 +116,7 @@ pub fn ui_item_drop_name_system(
             pos,
             galley,
             colour,
+            opacity,
         });
     }
 
@@ -101,8 +132,15 @@ pub fn ui_item_drop_name_system(
         tooltip_painter.add(egui::epaint::RectShape {
             rect,
             corner_radius: egui::epaint::CornerRadius::ZERO,
-            fill: style.visuals.window_fill,
-            stroke: style.visuals.window_stroke,
+            fill: style.visuals.window_fill.gamma_multiply(visible_name.opacity),
+            stroke: egui::Stroke::new(
+                style.visuals.window_stroke.width,
+                style
+                    .visuals
+                    .window_stroke
+                    .color
+                    .gamma_multiply(visible_name.opacity),
+            ),
             stroke_kind: egui::epaint::StrokeKind::Outside,
             round_to_pixels: Some(false),
             blur_width: 0.0,
@@ -112,7 +150,7 @@ pub fn ui_item_drop_name_system(
             pos: visible_name.pos,
             galley: visible_name.galley,
             underline: egui::Stroke::NONE,
-            override_text_color: Some(visible_name.colour),
+            override_text_color: Some(visible_name.colour.gamma_multiply(visible_name.opacity)),
             angle: 0.0,
             fallback_color: egui::Color32::TRANSPARENT,
             opacity_factor: 1.0,
@@ -0,0 +1,166 @@
+use bevy::prelude::{Local, Message, MessageReader, ResMut};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::{
+    resources::{UiLayout, UiLayoutSettings, UiWindowLayout, DEFAULT_UI_LAYOUT_SLOT},
+    ui::UiStateWindows,
+};
+
+/// Titles of the movable windows whose position is stored in a layout
+const LAYOUT_WINDOWS: [&str; 10] = [
+    "Bank",
+    "Character Info",
+    "Clan",
+    "Friends",
+    "Hot Bar",
+    "Inventory",
+    "Quest List",
+    "Settings",
+    "Skill Tree",
+    "Skills",
+];
+
+#[derive(Message)]
+pub enum UiLayoutEvent {
+    Save(String),
+    Load(String),
+    Delete(String),
+    Reset,
+}
+
+fn window_open_flag<'a>(ui_state_windows: &'a mut UiStateWindows, title: &str) -> Option<&'a mut bool> {
+    match title {
+        "Character Info" => Some(&mut ui_state_windows.character_info_open),
+        "Clan" => Some(&mut ui_state_windows.clan_open),
+        "Friends" => Some(&mut ui_state_windows.friends_open),
+        "Inventory" => Some(&mut ui_state_windows.inventory_open),
+        "Quest List" => Some(&mut ui_state_windows.quest_list_open),
+        "Skill Tree" => Some(&mut ui_state_windows.skill_tree_open),
+        "Skills" => Some(&mut ui_state_windows.skill_list_open),
+        _ => None,
+    }
+}
+
+fn capture_layout(ctx: &egui::Context, ui_state_windows: &mut UiStateWindows) -> UiLayout {
+    let mut layout = UiLayout {
+        hotbar_vertical: ui_state_windows.hotbar_vertical,
+        ..Default::default()
+    };
+
+    for title in LAYOUT_WINDOWS {
+        if let Some(rect) = ctx.memory(|memory| memory.area_rect(egui::Id::new(title))) {
+            layout.windows.insert(
+                title.to_string(),
+                UiWindowLayout {
+                    position: [rect.min.x, rect.min.y],
+                    size: [rect.width(), rect.height()],
+                },
+            );
+        }
+
+        if window_open_flag(ui_state_windows, title).is_some_and(|open| *open) {
+            layout.open_windows.push(title.to_string());
+        }
+    }
+
+    layout
+}
+
+fn apply_layout(
+    ctx: &egui::Context,
+    ui_state_windows: &mut UiStateWindows,
+    layout: &UiLayout,
+    screen_size: [f32; 2],
+) {
+    ctx.memory_mut(|memory| {
+        let areas = memory.areas_mut();
+
+        for (title, window_layout) in layout.windows.iter() {
+            // Layouts saved at a larger resolution may put windows off screen
+            let [x, y] = window_layout.clamped_position(screen_size);
+            let id = egui::Id::new(title.as_str());
+            let mut state = areas.get(id).copied().unwrap_or(egui::AreaState {
+                pivot_pos: None,
+                pivot: egui::Align2::LEFT_TOP,
+                size: None,
+                interactable: true,
+                last_became_visible_at: None,
+            });
+            state.pivot_pos = Some(egui::pos2(x, y));
+            state.pivot = egui::Align2::LEFT_TOP;
+            areas.set_state(egui::LayerId::new(egui::Order::Middle, id), state);
+        }
+    });
+
+    for title in LAYOUT_WINDOWS {
+        let open = layout.open_windows.iter().any(|open_title| open_title == title);
+        if let Some(flag) = window_open_flag(ui_state_windows, title) {
+            *flag = open;
+        }
+    }
+    ui_state_windows.hotbar_vertical = layout.hotbar_vertical;
+}
+
+fn reset_layout(ctx: &egui::Context, ui_state_windows: &mut UiStateWindows) {
+    // Forgetting the area positions puts every window back at its default position
+    ctx.memory_mut(|memory| memory.reset_areas());
+
+    for title in LAYOUT_WINDOWS {
+        if let Some(flag) = window_open_flag(ui_state_windows, title) {
+            *flag = false;
+        }
+    }
+    ui_state_windows.hotbar_vertical = false;
+}
+
+/// Saves and loads the window layout slots requested from the settings window,
+/// and applies the startup layout once the screen size is known.
+pub fn ui_layout_system(
+    mut egui_context: EguiContexts,
+    mut ui_state_windows: ResMut<UiStateWindows>,
+    mut ui_layout_settings: ResMut<UiLayoutSettings>,
+    mut ui_layout_events: MessageReader<UiLayoutEvent>,
+    mut startup_layout_applied: Local<bool>,
+) {
+    let Ok(ctx) = egui_context.ctx_mut() else {
+        return;
+    };
+    let screen_size = ctx.input(|input| input.screen_rect().size());
+    if screen_size.x <= 0.0 || screen_size.y <= 0.0 {
+        return;
+    }
+    let screen_size = [screen_size.x, screen_size.y];
+
+    if !*startup_layout_applied {
+        *startup_layout_applied = true;
+
+        if let Some(layout) = ui_layout_settings.slots.get(&ui_layout_settings.startup_slot) {
+            apply_layout(ctx, &mut ui_state_windows, layout, screen_size);
+        }
+    }
+
+    for event in ui_layout_events.read() {
+        match event {
+            UiLayoutEvent::Save(name) => {
+                let layout = capture_layout(ctx, &mut ui_state_windows);
+                ui_layout_settings.slots.insert(name.clone(), layout);
+            }
+            UiLayoutEvent::Load(name) => {
+                if let Some(layout) = ui_layout_settings.slots.get(name) {
+                    apply_layout(ctx, &mut ui_state_windows, layout, screen_size);
+                } else {
+                    log::warn!("UI layout {} does not exist", name);
+                }
+            }
+            UiLayoutEvent::Delete(name) => {
+                ui_layout_settings.slots.remove(name);
+                if ui_layout_settings.startup_slot == *name {
+                    ui_layout_settings.startup_slot = DEFAULT_UI_LAYOUT_SLOT.to_string();
+                }
+            }
+            UiLayoutEvent::Reset => {
+                reset_layout(ctx, &mut ui_state_windows);
+            }
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use std::collections::VecDeque;
+
+use bevy::{
+    input::ButtonInput,
+    prelude::{KeyCode, Local, Res},
+};
+use bevy_egui::{egui, EguiContexts};
+use tracing::Level;
+
+use crate::logging::{log_line_count, read_new_log_lines, LogLine};
+
+const LEVELS: [Level; 5] = [Level::ERROR, Level::WARN, Level::INFO, Level::DEBUG, Level::TRACE];
+
+pub struct UiStateLogConsole {
+    open: bool,
+    lines: VecDeque<LogLine>,
+    seen: u64,
+    /// Most verbose level shown
+    max_level: Level,
+    search: String,
+    auto_scroll: bool,
+}
+
+impl Default for UiStateLogConsole {
+    fn default() -> Self {
+        Self {
+            open: false,
+            lines: VecDeque::new(),
+            seen: 0,
+            max_level: Level::INFO,
+            search: String::new(),
+            auto_scroll: true,
+        }
+    }
+}
+
+fn level_color(level: Level) -> egui::Color32 {
+    match level {
+        Level::ERROR => egui::Color32::from_rgb(255, 90, 90),
+        Level::WARN => egui::Color32::from_rgb(255, 200, 80),
+        Level::INFO => egui::Color32::from_rgb(220, 220, 220),
+        Level::DEBUG => egui::Color32::from_rgb(150, 150, 150),
+        _ => egui::Color32::from_rgb(110, 110, 110),
+    }
+}
+
+fn line_matches(line: &LogLine, max_level: Level, search: &str) -> bool {
+    // Levels compare by verbosity, ERROR is the smallest
+    line.level <= max_level
+        && (search.is_empty()
+            || line.message.to_lowercase().contains(search)
+            || line.target.to_lowercase().contains(search))
+}
+
+/// In-game console showing recent log output, toggled with the ` key
+pub fn ui_log_console_system(
+    mut egui_context: EguiContexts,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut ui_state: Local<UiStateLogConsole>,
+) {
+    let Ok(ctx) = egui_context.ctx_mut() else {
+        return;
+    };
+
+    if keyboard.just_pressed(KeyCode::Backquote) && !ctx.wants_keyboard_input() {
+        ui_state.open = !ui_state.open;
+    }
+    if !ui_state.open {
+        return;
+    }
+
+    if log_line_count() != ui_state.seen {
+        let ui_state = &mut *ui_state;
+        ui_state.seen = read_new_log_lines(ui_state.seen, &mut ui_state.lines);
+    }
+
+    let mut open = true;
+    egui::Window::new("Log Console")
+        .open(&mut open)
+        .default_size([900.0, 400.0])
+        .resizable(true)
+        .show(ctx, |ui| {
+            let ui_state = &mut *ui_state;
+            let mut copy = false;
+
+            ui.horizontal(|ui| {
+                ui.label("Level:");
+                egui::ComboBox::from_id_salt("log_console_level")
+                    .selected_text(ui_state.max_level.as_str())
+                    .show_ui(ui, |ui| {
+                        for level in LEVELS {
+                            ui.selectable_value(&mut ui_state.max_level, level, level.as_str());
+                        }
+                    });
+
+                ui.label("Search:");
+                ui.text_edit_singleline(&mut ui_state.search);
+                ui.checkbox(&mut ui_state.auto_scroll, "Auto-scroll");
+                if ui
+                    .button("Copy")
+                    .on_hover_text("Copy the shown lines to the clipboard")
+                    .clicked()
+                {
+                    copy = true;
+                }
+                if ui.button("Clear").clicked() {
+                    ui_state.lines.clear();
+                }
+            });
+
+            ui.separator();
+
+            let search = ui_state.search.to_lowercase();
+            let lines: Vec<&LogLine> = ui_state
+                .lines
+                .iter()
+                .filter(|line| line_matches(line, ui_state.max_level, &search))
+                .collect();
+
+            if copy {
+                let text = lines
+                    .iter()
+                    .map(|line| line.to_string())
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                ui.ctx().copy_text(text);
+            }
+
+            let row_height = ui.text_style_height(&egui::TextStyle::Monospace);
+            egui::ScrollArea::both()
+                .auto_shrink([false, false])
+                .stick_to_bottom(ui_state.auto_scroll)
+                .show_rows(ui, row_height, lines.len(), |ui, row_range| {
+                    for line in &lines[row_range] {
+                        ui.label(
+                            egui::RichText::new(line.to_string())
+                                .monospace()
+                                .color(level_color(line.level)),
+                        );
+                    }
+                });
+        });
+
+    if !open {
+        ui_state.open = false;
+    }
+}
@@ -5,10 +5,9 @@ use bevy::{
     input::ButtonInput,
     math::{Vec2, Vec3Swizzles},
     prelude::{
-        AssetServer, Camera3d, Handle, Image, KeyCode, Local, MessageWriter, Query, Res, Transform,
-        Vec3, With, Without,
+        AssetServer, Camera3d, Handle, Image, KeyCode, Local, MessageWriter, Query, Res, ResMut,
+        Transform, Vec3, With, Without,
     },
-    window::PrimaryWindow,
 };
 use bevy_egui::{egui, EguiContexts};
 
@@ -20,7 +19,7 @@ use crate::{
     resources::{CurrentZone, GameData, UiResources, UiSpriteSheetType},
     ui::{
         widgets::{DataBindings, Dialog, Widget},
-        UiSoundEvent,
+        UiSoundEvent, UiStateWindows,
     },
     zone_loader::{ZoneLoaderAsset, ZoneNpc},
 };
@@ -48,12 +47,12 @@ const DEFAULT_WINDOW_SIZE: Vec2 = Vec2::new(200.0, 200.0);
 const MIN_WINDOW_SIZE: Vec2 = Vec2::new(150.0, 150.0);
 const MAX_WINDOW_SIZE: Vec2 = Vec2::new(800.0, 800.0);
 const CENTERED_WINDOW_SIZE: Vec2 = Vec2::new(600.0, 600.0);
-const DEFAULT_ZOOM: f32 = 1.5;
 const MIN_ZOOM: f32 = 0.5;
 const MAX_ZOOM: f32 = 4.0;
 const TITLE_BAR_HEIGHT: f32 = 21.0;
 const TOGGLE_BAR_HEIGHT: f32 = 24.0;
 const COORDS_BAR_HEIGHT: f32 = 17.0;
+const OVERVIEW_MAX_SIZE: Vec2 = Vec2::new(700.0, 620.0);
 
 #[derive(Default)]
 pub struct UiStateMinimap {
@@ -76,10 +75,12 @@ pub struct UiStateMinimap {
     pub window_size: Vec2,
     pub first_frame: bool,
 
-    // Zoom state
-    pub zoom_level: f32,
+    // Zoom level and rotation are stored in UiStateWindows
     pub follow_player: bool,
 
+    // Waypoint set by clicking on the zone overview, in world coordinates
+    pub waypoint: Option<Vec2>,
+
     // Icon visibility toggles
     pub show_players: bool,
     pub show_npcs: bool,
@@ -100,7 +101,6 @@ impl UiStateMinimap {
     fn new() -> Self {
         Self {
             window_size: DEFAULT_WINDOW_SIZE,
-            zoom_level: DEFAULT_ZOOM,
             follow_player: true,
             show_players: true,
             show_npcs: true,
@@ -166,7 +166,7 @@ pub fn ui_minimap_system(
     ui_resources: Res<UiResources>,
     dialog_assets: Res<Assets<Dialog>>,
     keyboard: Res<ButtonInput<KeyCode>>,
-    query_window: Query<&bevy::window::Window, With<PrimaryWindow>>,
+    mut ui_state_windows: ResMut<UiStateWindows>,
 ) {
     // Initialize state on first frame
     if ui_state.first_frame {
@@ -222,6 +222,7 @@ pub fn ui_minimap_system(
             ui_state.minimap_texture = Default::default();
             ui_state.minimap_image_size = Default::default();
             ui_state.scroll = Vec2::ZERO; // Reset scroll on zone change
+            ui_state.waypoint = None;
             ui_state.image_scale = 1.0; // Reset scale on zone change
             ui_state.scaled_outline_pixels = ORIGINAL_MAP_OUTLINE_PIXELS;
 
@@ -412,16 +413,33 @@ pub fn ui_minimap_system(
                 player_position.map(|p| map_relative_position(ui_state, p.position));
 
             // Calculate visible area based on zoom level
-            let zoom = ui_state.zoom_level;
+            let zoom = ui_state_windows.minimap_zoom;
             let visible_width = minimap_size.x / zoom;
             let visible_height = minimap_size.y / zoom;
 
-            // Map absolute position calculation (accounting for zoom and scroll)
+            // When rotating with the player the map is rotated around the centre of the
+            // minimap so that the camera facing direction always points up
+            let rotate_with_player = ui_state_windows.minimap_rotate_with_player;
+            let map_rotation = if rotate_with_player { -camera_angle } else { 0.0 };
+            let (map_rotation_sin, map_rotation_cos) = map_rotation.sin_cos();
+            let minimap_center = Vec2::new(minimap_rect.center().x, minimap_rect.center().y);
+            let rotate_screen_position = |screen_pos: Vec2| -> Vec2 {
+                let offset = screen_pos - minimap_center;
+                minimap_center
+                    + Vec2::new(
+                        offset.x * map_rotation_cos - offset.y * map_rotation_sin,
+                        offset.x * map_rotation_sin + offset.y * map_rotation_cos,
+                    )
+            };
+
+            // Map absolute position calculation (accounting for zoom, scroll and rotation)
             let map_absolute_position = |ui_state: &UiStateMinimap, position: Vec3| -> Vec2 {
                 let map_pos = map_relative_position(ui_state, position);
                 // Convert map coordinates to screen coordinates with zoom
-                Vec2::new(minimap_rect.min.x, minimap_rect.min.y)
-                    + (map_pos - ui_state.scroll) * zoom
+                rotate_screen_position(
+                    Vec2::new(minimap_rect.min.x, minimap_rect.min.y)
+                        + (map_pos - ui_state.scroll) * zoom,
+                )
             };
 
             if !minimised {
@@ -439,24 +457,31 @@ pub fn ui_minimap_system(
                 });
 
                 if zoom_delta != 0.0 && response.hovered() {
-                    let old_zoom = ui_state.zoom_level;
-                    ui_state.zoom_level = (ui_state.zoom_level * (1.0 + zoom_delta))
+                    let old_zoom = ui_state_windows.minimap_zoom;
+                    ui_state_windows.minimap_zoom = (ui_state_windows.minimap_zoom * (1.0 + zoom_delta))
                         .clamp(MIN_ZOOM, MAX_ZOOM);
 
-                    // Zoom towards cursor position
-                    if let Some(cursor_pos) = response.hover_pos() {
+                    // Zoom towards cursor position, a rotating map always stays centred on the player
+                    if let Some(cursor_pos) = response.hover_pos().filter(|_| !rotate_with_player) {
                         let cursor_offset = Vec2::new(
                             cursor_pos.x - minimap_rect.min.x,
                             cursor_pos.y - minimap_rect.min.y,
                         );
                         let map_cursor_pos = ui_state.scroll + cursor_offset / old_zoom;
-                        let new_map_cursor_pos = ui_state.scroll + cursor_offset / ui_state.zoom_level;
+                        let new_map_cursor_pos = ui_state.scroll + cursor_offset / ui_state_windows.minimap_zoom;
                         ui_state.scroll += (map_cursor_pos - new_map_cursor_pos);
                     }
                 }
 
                 // Handle dragging
-                if response.dragged() {
+                if rotate_with_player {
+                    // Rotating map is always centred on the player
+                    if let Some(target_center) = minimap_player_pos {
+                        let zoom = ui_state_windows.minimap_zoom;
+                        ui_state.scroll.x = target_center.x - minimap_size.x / zoom / 2.0;
+                        ui_state.scroll.y = target_center.y - minimap_size.y / zoom / 2.0;
+                    }
+                } else if response.dragged() {
                     let delta = ui.input(|input| input.pointer.delta());
                     ui_state.scroll.x -= delta.x / zoom;
                     ui_state.scroll.y -= delta.y / zoom;
@@ -470,10 +495,12 @@ pub fn ui_minimap_system(
                 }
 
                 // Clamp scroll to valid bounds
-                let max_scroll_x = (image_size.x - visible_width).max(0.0);
-                let max_scroll_y = (image_size.y - visible_height).max(0.0);
-                ui_state.scroll.x = ui_state.scroll.x.clamp(0.0, max_scroll_x);
-                ui_state.scroll.y = ui_state.scroll.y.clamp(0.0, max_scroll_y);
+                if !rotate_with_player {
+                    let max_scroll_x = (image_size.x - visible_width).max(0.0);
+                    let max_scroll_y = (image_size.y - visible_height).max(0.0);
+                    ui_state.scroll.x = ui_state.scroll.x.clamp(0.0, max_scroll_x);
+                    ui_state.scroll.y = ui_state.scroll.y.clamp(0.0, max_scroll_y);
+                }
 
                 // Calculate UV coordinates for the visible portion
                 let minimap_uv = egui::Rect::from_min_max(
@@ -488,7 +515,35 @@ pub fn ui_minimap_system(
                 );
 
                 // Draw map texture
-                if ui.is_rect_visible(minimap_rect) {
+                if ui.is_rect_visible(minimap_rect) && rotate_with_player {
+                    // Cover the whole minimap once rotated by drawing a square around the
+                    // centre with the minimap diagonal as its size
+                    let zoom = ui_state_windows.minimap_zoom;
+                    let half_extent = minimap_size.length() / 2.0;
+                    let painter = ui.painter_at(minimap_rect);
+                    painter.rect_filled(minimap_rect, 0.0, egui::Color32::BLACK);
+
+                    let mut mesh = egui::epaint::Mesh::with_texture(ui_state.minimap_texture);
+                    for corner in [
+                        Vec2::new(-half_extent, -half_extent),
+                        Vec2::new(half_extent, -half_extent),
+                        Vec2::new(-half_extent, half_extent),
+                        Vec2::new(half_extent, half_extent),
+                    ] {
+                        let screen_pos = minimap_center + corner;
+                        let map_pos = ui_state.scroll
+                            + (screen_pos - Vec2::new(minimap_rect.min.x, minimap_rect.min.y))
+                                / zoom;
+                        mesh.vertices.push(egui::epaint::Vertex {
+                            pos: rotate_screen_position(screen_pos).to_array().into(),
+                            uv: egui::pos2(map_pos.x / image_size.x, map_pos.y / image_size.y),
+                            color: egui::Color32::WHITE,
+                        });
+                    }
+                    mesh.add_triangle(0, 1, 2);
+                    mesh.add_triangle(2, 1, 3);
+                    painter.add(egui::epaint::Shape::mesh(mesh));
+                } else if ui.is_rect_visible(minimap_rect) {
                     let mut mesh = egui::epaint::Mesh::with_texture(ui_state.minimap_texture);
                     mesh.add_rect_with_uv(minimap_rect, minimap_uv, egui::Color32::WHITE);
                     ui.painter().add(egui::epaint::Shape::mesh(mesh));
@@ -541,7 +596,7 @@ pub fn ui_minimap_system(
             );
 
             if !minimised {
-                let zoom = ui_state.zoom_level;
+                let zoom = ui_state_windows.minimap_zoom;
 
                 // Get icon sprites
                 let enemy_character_icon =
@@ -678,13 +733,25 @@ pub fn ui_minimap_system(
                     }
                 }
 
+                // Draw waypoint marker
+                if let Some(waypoint) = ui_state.waypoint {
+                    let waypoint_pos =
+                        map_absolute_position(ui_state, Vec3::new(waypoint.x, waypoint.y, 0.0));
+                    let waypoint_pos = egui::pos2(waypoint_pos.x, waypoint_pos.y);
+                    if minimap_rect.contains(waypoint_pos) {
+                        draw_waypoint_marker(ui.painter(), waypoint_pos);
+                    }
+                }
+
                 // Draw player position arrow texture on a rotated rectangle to face camera position
                 if let Some(minimap_player_pos) = minimap_player_pos {
                     let minimap_player_sprite = ui_resources.get_minimap_player_sprite().unwrap();
                     let player_icon_size =
                         Vec2::new(minimap_player_sprite.width, minimap_player_sprite.height);
-                    let minimap_player_pos_screen = Vec2::new(minimap_rect.min.x, minimap_rect.min.y)
-                        + (minimap_player_pos - ui_state.scroll) * zoom;
+                    let minimap_player_pos_screen = rotate_screen_position(
+                        Vec2::new(minimap_rect.min.x, minimap_rect.min.y)
+                            + (minimap_player_pos - ui_state.scroll) * zoom,
+                    );
                     let widget_rect = egui::Rect::from_min_size(
                         (minimap_player_pos_screen - player_icon_size / 2.0)
                             .to_array()
@@ -701,8 +768,8 @@ pub fn ui_minimap_system(
                                 );
 
                                 // Calculate rotated rectangle from camera angle
-                                let sin_a = camera_angle.sin();
-                                let cos_a = camera_angle.cos();
+                                let sin_a = (camera_angle + map_rotation).sin();
+                                let cos_a = (camera_angle + map_rotation).cos();
 
                                 let mut corners = [
                                     [-player_icon_size.x / 2.0, -player_icon_size.y / 2.0],
@@ -810,7 +877,7 @@ pub fn ui_minimap_system(
                         ui.separator();
 
                         // Zoom indicator
-                        ui.label(egui::RichText::new(format!("🔍 {:.1}x", ui_state.zoom_level)).color(egui::Color32::WHITE).size(10.0));
+                        ui.label(egui::RichText::new(format!("🔍 {:.1}x", ui_state_windows.minimap_zoom)).color(egui::Color32::WHITE).size(10.0));
 
                         // Follow player toggle
                         let follow_text = if ui_state.follow_player { "📍" } else { "⭕" };
@@ -823,6 +890,23 @@ pub fn ui_minimap_system(
                         if ui.small(center_text).on_hover_text("Toggle centered mode (ALT+M)").clicked() {
                             ui_state.is_centered = !ui_state.is_centered;
                         }
+
+                        // Rotation lock toggle
+                        let rotate_text = if ui_state_windows.minimap_rotate_with_player { "🔄" } else { "⬆" };
+                        if ui
+                            .small(rotate_text)
+                            .on_hover_text("Toggle rotate with player / fixed north-up")
+                            .clicked()
+                        {
+                            ui_state_windows.minimap_rotate_with_player =
+                                !ui_state_windows.minimap_rotate_with_player;
+                        }
+
+                        // Zone overview
+                        if ui.small("🗺").on_hover_text("Open zone overview").clicked() {
+                            ui_state_windows.minimap_overview_open =
+                                !ui_state_windows.minimap_overview_open;
+                        }
                     });
                 });
 
@@ -841,11 +925,21 @@ pub fn ui_minimap_system(
 
                 ui.allocate_ui_at_rect(player_xy_rect.shrink(2.0), |ui| {
                     if let Some(player_position) = player_position {
-                        ui.label(format!(
-                            "{:0>4}, {:0>4}",
-                            (player_position.position.x / 100.0) as i32,
-                            (player_position.position.y / 100.0) as i32
-                        ));
+                        ui.horizontal(|ui| {
+                            ui.label(format!(
+                                "{:0>4}, {:0>4}",
+                                (player_position.position.x / 100.0) as i32,
+                                (player_position.position.y / 100.0) as i32
+                            ));
+
+                            if let Some(waypoint) = ui_state.waypoint {
+                                ui.label(format!(
+                                    "⚑ {}m",
+                                    (waypoint.distance(player_position.position.xy()) / 100.0)
+                                        as i32
+                                ));
+                            }
+                        });
                     }
                 });
             }
@@ -866,4 +960,129 @@ pub fn ui_minimap_system(
     {
         ui_state.is_minimised = !ui_state.is_minimised;
     }
+
+    // Zone overview window, shows the whole zone map at once
+    let Some(image_size) = ui_state.minimap_image_size else {
+        return;
+    };
+    let overview_scale = (OVERVIEW_MAX_SIZE.x / image_size.x)
+        .min(OVERVIEW_MAX_SIZE.y / image_size.y)
+        .min(1.0);
+    let overview_size = image_size * overview_scale;
+    let mut overview_open = ui_state_windows.minimap_overview_open;
+
+    egui::Window::new("Zone Map")
+        .open(&mut overview_open)
+        .resizable(false)
+        .collapsible(false)
+        .show(egui_context.ctx_mut().unwrap(), |ui| {
+            let (overview_rect, response) = ui.allocate_exact_size(
+                overview_size.to_array().into(),
+                egui::Sense::click(),
+            );
+            let overview_position = |ui_state: &UiStateMinimap, position: Vec3| -> egui::Pos2 {
+                let map_pos = map_relative_position(ui_state, position) * overview_scale;
+                overview_rect.min + egui::vec2(map_pos.x, map_pos.y)
+            };
+
+            let mut mesh = egui::epaint::Mesh::with_texture(ui_state.minimap_texture);
+            mesh.add_rect_with_uv(
+                overview_rect,
+                egui::Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(1.0, 1.0)),
+                egui::Color32::WHITE,
+            );
+            ui.painter().add(egui::epaint::Shape::mesh(mesh));
+
+            // Zone NPCs are static so there is nothing to track between frames
+            for &ZoneNpc {
+                npc_id,
+                position: npc_position,
+            } in current_zone_data.npcs.iter()
+            {
+                let Some(npc_data) = game_data.npcs.get_npc(npc_id) else {
+                    continue;
+                };
+                let Some(icon_image) = ui_resources.get_sprite_by_index(
+                    UiSpriteSheetType::StateIcon,
+                    npc_data.npc_minimap_icon_index as usize,
+                ) else {
+                    continue;
+                };
+
+                let icon_pos = overview_position(ui_state, npc_position);
+                icon_image.draw(
+                    ui,
+                    icon_pos - egui::vec2(icon_image.width / 2.0, icon_image.height / 2.0),
+                );
+            }
+
+            if let Some(party_icon) =
+                ui_resources.get_sprite(UiSpriteSheetType::Ui as i32, "ID_MINIMAP_PARTYMEMBER")
+            {
+                for (character_info, character_position, _) in query_characters.iter() {
+                    if player_party.map_or(false, |player_party| {
+                        player_party
+                            .members
+                            .iter()
+                            .any(|member| member.get_character_id() == character_info.unique_id)
+                    }) {
+                        let icon_pos = overview_position(ui_state, character_position.position);
+                        party_icon.draw(
+                            ui,
+                            icon_pos - egui::vec2(party_icon.width / 2.0, party_icon.height / 2.0),
+                        );
+                    }
+                }
+            }
+
+            if let Some(player_position) = player_position {
+                ui.painter().circle(
+                    overview_position(ui_state, player_position.position),
+                    4.0,
+                    egui::Color32::WHITE,
+                    egui::Stroke::new(1.0, egui::Color32::BLACK),
+                );
+            }
+
+            if let Some(waypoint) = ui_state.waypoint {
+                draw_waypoint_marker(
+                    ui.painter(),
+                    overview_position(ui_state, Vec3::new(waypoint.x, waypoint.y, 0.0)),
+                );
+            }
+
+            // Left click sets the waypoint, right click clears it
+            if response.clicked() {
+                if let Some(click_pos) = response.interact_pointer_pos() {
+                    let map_pos = (click_pos - overview_rect.min) / overview_scale;
+                    ui_state.waypoint = Some(Vec2::new(
+                        ui_state.min_world_pos.x
+                            + (map_pos.x - ui_state.scaled_outline_pixels)
+                                * ui_state.distance_per_pixel,
+                        ui_state.min_world_pos.y
+                            - (map_pos.y - ui_state.scaled_outline_pixels)
+                                * ui_state.distance_per_pixel,
+                    ));
+                }
+            } else if response.secondary_clicked() {
+                ui_state.waypoint = None;
+            }
+
+            ui.label("Left click to set a waypoint, right click to clear it.");
+        });
+
+    ui_state_windows.minimap_overview_open = overview_open;
+}
+
+fn draw_waypoint_marker(painter: &egui::Painter, pos: egui::Pos2) {
+    painter.circle(
+        pos,
+        5.0,
+        egui::Color32::from_rgb(255, 200, 0),
+        egui::Stroke::new(1.5, egui::Color32::BLACK),
+    );
+    painter.line_segment(
+        [pos, pos - egui::vec2(0.0, 12.0)],
+        egui::Stroke::new(1.5, egui::Color32::from_rgb(255, 200, 0)),
+    );
 }
@@ -1,4 +1,5 @@
 use bevy::{
+    ecs::system::SystemParam,
     math::Vec3Swizzles,
     prelude::{
         Assets, Entity, Local, MessageReader, MessageWriter, Messages, Query, Res, ResMut, With, World,
@@ -6,7 +7,7 @@ use bevy::{
 };
 use bevy_egui::{egui, EguiContexts};
 
-use rose_data::{Item, NpcData, NpcStoreTabData, NpcStoreTabId};
+use rose_data::{Item, NpcData, NpcId, NpcStoreTabData, NpcStoreTabId};
 use rose_game_common::{
     components::{AbilityValues, Inventory, InventoryPageType, ItemSlot, Npc, INVENTORY_PAGE_SIZE},
     messages::{
@@ -19,7 +20,8 @@ use crate::{
     components::{PlayerCharacter, Position},
     events::{MessageBoxEvent, NpcStoreEvent, NumberInputDialogEvent},
     resources::{
-        ClientEntityList, GameConnection, GameData, UiResources, UiSpriteSheetType, WorldRates,
+        ClientEntityList, GameConnection, GameData, NpcStoreSettings, UiResources,
+        UiSpriteSheetType, WorldRates,
     },
     ui::{
         tooltips::{PlayerTooltipQuery, PlayerTooltipQueryItem},
@@ -57,12 +59,22 @@ struct PendingSellItem {
     quantity: usize,
 }
 
+/// An item sold to a store this session, the server has no buy back so it can
+/// only be bought again when the store sells the same item
+struct SoldItem {
+    npc_id: NpcId,
+    item: Item,
+    value: i64,
+}
+
 pub struct UiNpcStoreState {
     owner_entity: Option<(Entity, ClientEntityId)>,
     current_tab_index: i32,
     store_tabs: [Option<(NpcStoreTabId, String)>; 4],
     buy_list: [Option<PendingBuyItem>; NUM_BUY_ITEMS],
     sell_list: [Option<PendingSellItem>; NUM_SELL_ITEMS],
+    /// Most recent last, kept when the store is closed
+    sold_items: Vec<SoldItem>,
 }
 
 impl Default for UiNpcStoreState {
@@ -73,7 +85,114 @@ impl Default for UiNpcStoreState {
             store_tabs: Default::default(),
             buy_list: Default::default(),
             sell_list: Default::default(),
+            sold_items: Vec::new(),
+        }
+    }
+}
+
+/// Grouped message writers for ui_npc_store_system to avoid parameter count limit
+#[derive(SystemParam)]
+pub struct NpcStoreMessageWriters<'w> {
+    pub number_input_dialog_events: MessageWriter<'w, NumberInputDialogEvent>,
+    pub message_box_events: MessageWriter<'w, MessageBoxEvent>,
+}
+
+fn item_sell_price(
+    item: &Item,
+    player: Option<&(&AbilityValues, &Inventory, &Position, &PlayerCharacter)>,
+    game_data: &GameData,
+    world_rates: Option<&Res<WorldRates>>,
+) -> i64 {
+    game_data
+        .ability_value_calculator
+        .calculate_npc_store_item_sell_price(
+            &game_data.items,
+            item,
+            player.map_or(0, |player| player.0.get_npc_store_sell_rate()),
+            world_rates.map_or(0, |x| x.world_price_rate),
+            world_rates.map_or(0, |x| x.item_price_rate),
+            world_rates.map_or(0, |x| x.town_price_rate),
+        )
+        .unwrap_or(0) as i64
+        * item.get_quantity() as i64
+}
+
+/// Store tab and slot where the store sells the item
+fn find_store_item(npc_data: &NpcData, game_data: &GameData, item: &Item) -> Option<(usize, usize)> {
+    npc_data
+        .store_tabs
+        .iter()
+        .enumerate()
+        .find_map(|(store_tab_index, store_tab_id)| {
+            let store_tab = game_data.npcs.get_store_tab((*store_tab_id)?)?;
+            store_tab
+                .items
+                .iter()
+                .find(|(_, item_reference)| **item_reference == item.get_item_reference())
+                .map(|(store_tab_slot, _)| (store_tab_index, *store_tab_slot as usize))
+        })
+}
+
+fn send_npc_store_transaction(
+    ui_state: &mut UiNpcStoreState,
+    npc_id: NpcId,
+    player: Option<&(&AbilityValues, &Inventory, &Position, &PlayerCharacter)>,
+    game_connection: Option<&Res<GameConnection>>,
+    game_data: &GameData,
+    world_rates: Option<&Res<WorldRates>>,
+    npc_store_settings: &NpcStoreSettings,
+) {
+    let Some((_, npc_entity_id)) = ui_state.owner_entity else {
+        return;
+    };
+
+    let mut buy_items = Vec::new();
+    let mut sell_items = Vec::new();
+
+    for pending_buy_item in ui_state.buy_list.iter_mut().filter_map(|x| x.take()) {
+        buy_items.push(NpcStoreBuyItem {
+            tab_index: pending_buy_item.store_tab_index,
+            item_index: pending_buy_item.store_tab_slot,
+            quantity: pending_buy_item.quantity,
+        });
+    }
+
+    for pending_sell_item in ui_state.sell_list.iter_mut().filter_map(|x| x.take()) {
+        if let Some(item) = player.and_then(|player| player.1.get_item(pending_sell_item.item_slot)) {
+            ui_state.sold_items.push(SoldItem {
+                npc_id,
+                item: item.clone(),
+                value: item_sell_price(item, player, game_data, world_rates),
+            });
+        }
+        sell_items.push((pending_sell_item.item_slot, pending_sell_item.quantity));
+    }
+
+    // Forget the oldest sales of this store beyond the history length
+    let store_sales = ui_state
+        .sold_items
+        .iter()
+        .filter(|sold_item| sold_item.npc_id == npc_id)
+        .count();
+    let mut forget = store_sales.saturating_sub(npc_store_settings.buyback_history);
+    ui_state.sold_items.retain(|sold_item| {
+        if forget > 0 && sold_item.npc_id == npc_id {
+            forget -= 1;
+            false
+        } else {
+            true
         }
+    });
+
+    if let Some(game_connection) = game_connection {
+        game_connection
+            .client_message_tx
+            .send(ClientMessage::NpcStoreTransaction {
+                npc_entity_id,
+                buy_items,
+                sell_items,
+            })
+            .ok();
     }
 }
 
@@ -321,22 +440,7 @@ fn ui_add_sell_item_slot(
             .and_then(|pending_sell_item| player.1.get_item(pending_sell_item.item_slot))
     });
 
-    let item_price = if let Some(item) = item {
-        game_data
-            .ability_value_calculator
-            .calculate_npc_store_item_sell_price(
-                &game_data.items,
-                item,
-                player.map_or(0, |player| player.0.get_npc_store_sell_rate()),
-                world_rates.map_or(0, |x| x.world_price_rate),
-                world_rates.map_or(0, |x| x.item_price_rate),
-                world_rates.map_or(0, |x| x.town_price_rate),
-            )
-            .unwrap_or(0) as i64
-            * item.get_quantity() as i64
-    } else {
-        0
-    };
+    let item_price = item.map_or(0, |item| item_sell_price(item, player, game_data, world_rates));
 
     let mut dropped_item = None;
     let response = ui
@@ -398,8 +502,8 @@ pub fn ui_npc_store_system(
     dialog_assets: Res<Assets<Dialog>>,
     ui_resources: Res<UiResources>,
     world_rates: Option<Res<WorldRates>>,
-    mut number_input_dialog_events: MessageWriter<NumberInputDialogEvent>,
-    mut message_box_events: MessageWriter<MessageBoxEvent>,
+    npc_store_settings: Res<NpcStoreSettings>,
+    mut message_writers: NpcStoreMessageWriters,
 ) {
     let ui_state = &mut *ui_state;
     let store_dialog = if let Some(dialog) = dialog_assets.get(&ui_resources.dialog_npc_store) {
@@ -414,10 +518,14 @@ pub fn ui_npc_store_system(
             return;
         };
 
+    let mut confirmed_transaction = false;
     for event in npc_store_events.read() {
         match *event {
             NpcStoreEvent::OpenClientEntityStore(client_entity_id) => {
-                *ui_state = UiNpcStoreState::default();
+                *ui_state = UiNpcStoreState {
+                    sold_items: std::mem::take(&mut ui_state.sold_items),
+                    ..Default::default()
+                };
 
                 if let Some(owner_entity) = client_entity_list.get(client_entity_id) {
                     if let Ok(npc) = query_npc.get(owner_entity) {
@@ -462,6 +570,9 @@ pub fn ui_npc_store_system(
                     buy_slot.take();
                 }
             }
+            NpcStoreEvent::ConfirmTransaction => {
+                confirmed_transaction = true;
+            }
         }
     }
 
@@ -566,7 +677,7 @@ pub fn ui_npc_store_system(
                                     &game_data,
                                     &ui_resources,
                                     world_rates.as_ref(),
-                                    &mut number_input_dialog_events,
+                                    &mut message_writers.number_input_dialog_events,
                                 );
                             }
                         }
@@ -577,7 +688,7 @@ pub fn ui_npc_store_system(
 
     let mut transaction_cost = 0;
 
-    egui::Window::new("NPC Transaction")
+    let transaction_window = egui::Window::new("NPC Transaction")
         .frame(egui::Frame::none())
         .title_bar(false)
         .resizable(false)
@@ -643,10 +754,124 @@ pub fn ui_npc_store_system(
             );
         });
 
-    if response_ok.map_or(false, |x| x.clicked()) {
-        let can_afford_transaction =
-            player.map_or(true, |player| transaction_cost <= player.1.money.0);
+    let player_money = player.map(|player| player.1.money.0);
+    let can_afford_transaction = player_money.map_or(true, |money| transaction_cost <= money);
+
+    // Running total of the transaction below the transaction dialog
+    if let Some(transaction_window) = transaction_window {
+        if transaction_cost != 0 {
+            egui::Area::new(egui::Id::new("npc_store_transaction_total"))
+                .order(egui::Order::Foreground)
+                .interactable(false)
+                .fixed_pos(transaction_window.response.rect.left_bottom())
+                .show(egui_context.ctx_mut().unwrap(), |ui| {
+                    egui::Frame::window(ui.style()).show(ui, |ui| {
+                        if transaction_cost > 0 {
+                            ui.label(format!("Total cost: {} Zuly", transaction_cost));
+                        } else {
+                            ui.label(format!("Total received: {} Zuly", -transaction_cost));
+                        }
+
+                        if let Some(money) = player_money {
+                            if can_afford_transaction {
+                                ui.label(format!("Zuly after: {}", money - transaction_cost));
+                            } else {
+                                ui.colored_label(
+                                    egui::Color32::from_rgb(255, 90, 90),
+                                    format!("Not enough Zuly, {} short", transaction_cost - money),
+                                );
+                            }
+                        }
+                    });
+                });
+        }
+    }
 
+    // Items sold to this store, added back to the buy list when the store sells them
+    let npc_id = npc.as_ref().map(|npc| npc.0.id);
+    if ui_state
+        .sold_items
+        .iter()
+        .any(|sold_item| Some(sold_item.npc_id) == npc_id)
+    {
+        let mut buy_back = None;
+
+        egui::Window::new("Recently Sold")
+            .resizable(false)
+            .default_open(false)
+            .default_pos([
+                screen_size.x / 2.0 + 5.0,
+                (screen_size.y + store_dialog.height) / 2.0 + 5.0,
+            ])
+            .show(egui_context.ctx_mut().unwrap(), |ui| {
+                egui::Grid::new("npc_store_sold_items")
+                    .num_columns(3)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for (index, sold_item) in ui_state
+                            .sold_items
+                            .iter()
+                            .enumerate()
+                            .rev()
+                            .filter(|(_, sold_item)| Some(sold_item.npc_id) == npc_id)
+                        {
+                            let name = game_data
+                                .items
+                                .get_base_item(sold_item.item.get_item_reference())
+                                .map_or("?", |item_data| item_data.name.as_str());
+                            if sold_item.item.is_stackable_item() {
+                                ui.label(format!("{} x{}", name, sold_item.item.get_quantity()));
+                            } else {
+                                ui.label(name);
+                            }
+                            ui.label(format!("Sold for {}", sold_item.value));
+
+                            let store_item = find_store_item(npc_data, &game_data, &sold_item.item);
+                            let response = ui.add_enabled(store_item.is_some(), egui::Button::new("Buy Back"));
+                            if response.clicked() {
+                                buy_back = store_item.map(|store_item| (index, store_item));
+                            }
+                            if store_item.is_some() {
+                                response.on_hover_text("Adds the item to the buy list at the store price");
+                            } else {
+                                response.on_disabled_hover_text("This store does not sell this item");
+                            }
+                            ui.end_row();
+                        }
+                    });
+            });
+
+        if let Some((index, (store_tab_index, store_tab_slot))) = buy_back {
+            if let Some(slot) = ui_state.buy_list.iter_mut().find(|slot| slot.is_none()) {
+                let sold_item = ui_state.sold_items.remove(index);
+                *slot = Some(PendingBuyItem {
+                    store_tab_index,
+                    store_tab_slot,
+                    quantity: if sold_item.item.is_stackable_item() {
+                        sold_item.item.get_quantity() as usize
+                    } else {
+                        1
+                    },
+                });
+            }
+        }
+    }
+
+    if confirmed_transaction {
+        if let Some(npc_id) = npc_id {
+            send_npc_store_transaction(
+                ui_state,
+                npc_id,
+                player.as_ref(),
+                game_connection.as_ref(),
+                &game_data,
+                world_rates.as_ref(),
+                &npc_store_settings,
+            );
+        }
+    }
+
+    if response_ok.map_or(false, |x| x.clicked()) {
         // Check inventory space for buy items
         let has_inventory_space = player.map_or(true, |player| {
             let inventory = &player.1;
@@ -667,40 +892,41 @@ pub fn ui_npc_store_system(
         });
 
         if can_afford_transaction && has_inventory_space {
-            let mut buy_items = Vec::new();
-            let mut sell_items = Vec::new();
-
-            for pending_buy_item in ui_state.buy_list.iter_mut().filter_map(|x| x.take()) {
-                buy_items.push(NpcStoreBuyItem {
-                    tab_index: pending_buy_item.store_tab_index,
-                    item_index: pending_buy_item.store_tab_slot,
-                    quantity: pending_buy_item.quantity,
+            if npc_store_settings.confirm_purchase_above > 0
+                && transaction_cost > npc_store_settings.confirm_purchase_above
+            {
+                message_writers.message_box_events.write(MessageBoxEvent::Show {
+                    message: format!("Spend {} Zuly on this transaction?", transaction_cost),
+                    modal: true,
+                    ok: Some(Box::new(|commands| {
+                        commands.queue(|world: &mut World| {
+                            world
+                                .resource_mut::<Messages<NpcStoreEvent>>()
+                                .write(NpcStoreEvent::ConfirmTransaction);
+                        });
+                    })),
+                    cancel: Some(Box::new(|_| {})),
                 });
-            }
-
-            for pending_sell_item in ui_state.sell_list.iter_mut().filter_map(|x| x.take()) {
-                sell_items.push((pending_sell_item.item_slot, pending_sell_item.quantity));
-            }
-
-            if let Some(game_connection) = game_connection {
-                game_connection
-                    .client_message_tx
-                    .send(ClientMessage::NpcStoreTransaction {
-                        npc_entity_id: ui_state.owner_entity.unwrap().1,
-                        buy_items,
-                        sell_items,
-                    })
-                    .ok();
+            } else if let Some(npc_id) = npc_id {
+                send_npc_store_transaction(
+                    ui_state,
+                    npc_id,
+                    player.as_ref(),
+                    game_connection.as_ref(),
+                    &game_data,
+                    world_rates.as_ref(),
+                    &npc_store_settings,
+                );
             }
         } else if !can_afford_transaction {
-            message_box_events.write(MessageBoxEvent::Show {
+            message_writers.message_box_events.write(MessageBoxEvent::Show {
                 message: "You do not have enough Zuly for this transaction.".to_string(),
                 modal: true,
                 ok: Some(Box::new(|_| {})),
                 cancel: None,
             });
         } else {
-            message_box_events.write(MessageBoxEvent::Show {
+            message_writers.message_box_events.write(MessageBoxEvent::Show {
                 message: "You do not have enough inventory space for this transaction.".to_string(),
                 modal: true,
                 ok: Some(Box::new(|_| {})),
@@ -43,6 +43,28 @@ pub struct ActiveNumberInputDialog {
     cancel: Option<Box<dyn FnOnce(&mut Commands) + Send + Sync>>,
 }
 
+/// Drops anything that is not a digit and clamps the entered amount to `max_value`
+fn clamp_number_input(text: &mut String, max_value: Option<usize>) {
+    text.retain(|c| c.is_ascii_digit());
+
+    if let Some(max_value) = max_value {
+        // A value which does not parse at this point is too large for usize
+        if text
+            .parse::<usize>()
+            .map_or(!text.is_empty(), |value| value > max_value)
+        {
+            *text = max_value.to_string();
+        }
+    }
+}
+
+/// The entered amount, `None` when it is empty, zero or above `max_value`
+fn parse_number_input(text: &str, max_value: Option<usize>) -> Option<usize> {
+    text.parse::<usize>()
+        .ok()
+        .filter(|&value| value > 0 && max_value.map_or(true, |max_value| value <= max_value))
+}
+
 #[derive(Default)]
 pub struct UiStateMessageBox {
     active: Option<ActiveNumberInputDialog>,
@@ -132,6 +154,10 @@ pub fn ui_number_input_dialog_system(
     let mut response_button_8 = None;
     let mut response_button_9 = None;
     let mut response_editbox = None;
+    let mut set_value = None;
+
+    let max_value = active_dialog.max_value;
+    let current_value = parse_number_input(&active_dialog.current_value, max_value);
 
     let mut area = egui::Area::new(egui::Id::new("num_input_dlg"))
         .movable(true)
@@ -157,7 +183,8 @@ pub fn ui_number_input_dialog_system(
             ui,
             DataBindings {
                 sound_events: Some(&mut ui_sound_events),
-                visible: &mut [(IID_BTN_MAX, active_dialog.max_value.is_some())],
+                visible: &mut [(IID_BTN_MAX, max_value.is_some())],
+                enabled: &mut [(IID_BTN_OK, current_value.is_some())],
                 text: &mut [(IID_EDITBOX, &mut active_dialog.current_value)],
                 response: &mut [
                     (IID_BTN_OK, &mut response_button_ok),
@@ -181,6 +208,58 @@ pub fn ui_number_input_dialog_system(
             |_ui, _bindings| {},
         );
 
+        let current_value = current_value.unwrap_or(0);
+        egui::Frame::window(ui.style()).show(ui, |ui| {
+            ui.set_width(dialog.width - 16.0);
+
+            if let Some(max_value) = max_value.filter(|&max_value| max_value > 1) {
+                let mut slider_value = current_value.clamp(1, max_value);
+                if ui
+                    .add(egui::Slider::new(&mut slider_value, 1..=max_value).show_value(false))
+                    .changed()
+                {
+                    set_value = Some(slider_value);
+                }
+            }
+
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(current_value > 1, egui::Button::new("-1"))
+                    .clicked()
+                {
+                    set_value = Some(current_value - 1);
+                }
+
+                if ui
+                    .add_enabled(
+                        max_value.map_or(true, |max_value| current_value < max_value),
+                        egui::Button::new("+1"),
+                    )
+                    .clicked()
+                {
+                    set_value = Some(current_value + 1);
+                }
+
+                if let Some(max_value) = max_value {
+                    if ui.button("Half").clicked() {
+                        set_value = Some((max_value / 2).max(1));
+                    }
+
+                    if ui.button("All").clicked() {
+                        set_value = Some(max_value);
+                    }
+                }
+            });
+
+            if current_value == 0 {
+                let hint = match max_value {
+                    Some(max_value) => format!("Enter an amount from 1 to {}", max_value),
+                    None => "Enter an amount of at least 1".to_string(),
+                };
+                ui.weak(hint);
+            }
+        });
+
         response
     });
 
@@ -270,22 +349,23 @@ pub fn ui_number_input_dialog_system(
         }
     }
 
-    if response_button_ok.map_or(false, |x| x.clicked()) {
-        let active = ui_state.active.take().unwrap();
-        let mut value = active.current_value.parse::<usize>().unwrap_or(0);
+    if let Some(value) = set_value {
+        active_dialog.current_value = format!("{}", value);
+        move_cursor_to_position(response_editbox.as_ref(), active_dialog.current_value.len());
+    }
 
-        if let Some(max_value) = active.max_value {
-            if value > max_value {
-                value = max_value;
-            }
-        }
+    let entered_value = active_dialog.current_value.clone();
+    clamp_number_input(&mut active_dialog.current_value, max_value);
+    if active_dialog.current_value != entered_value {
+        move_cursor_to_position(response_editbox.as_ref(), active_dialog.current_value.len());
+    }
 
-        if value > 0 {
-            if let Some(ok) = active.ok {
-                ok(&mut commands, value);
-            }
-        } else if let Some(cancel) = active.cancel {
-            cancel(&mut commands);
+    let confirmed_value = parse_number_input(&active_dialog.current_value, max_value);
+    if response_button_ok.map_or(false, |x| x.clicked()) && confirmed_value.is_some() {
+        let active = ui_state.active.take().unwrap();
+
+        if let (Some(ok), Some(value)) = (active.ok, confirmed_value) {
+            ok(&mut commands, value);
         }
     } else if response_button_cancel.map_or(false, |x| x.clicked()) {
         let active = ui_state.active.take().unwrap();
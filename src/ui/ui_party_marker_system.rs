@@ -0,0 +1,190 @@
+use bevy::prelude::{Camera, Camera3d, GlobalTransform, Query, Res, Vec2, Vec3, With};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::{
+    components::{ClientEntityName, ModelHeight, PartyInfo, PlayerCharacter},
+    resources::{ClientEntityList, PartyMarkerSettings},
+    systems::get_party_member_entities,
+};
+
+/// Distance in pixels between the screen edge and off-screen arrows
+const ARROW_EDGE_MARGIN: f32 = 48.0;
+const ARROW_SIZE: f32 = 12.0;
+
+/// Screen space height of the member name above the marker anchor, just over
+/// the health bar spawned by `party_marker_system`
+const MARKER_NAME_OFFSET_Y: f32 = 56.0;
+
+pub fn ui_party_marker_system(
+    mut egui_context: EguiContexts,
+    query_camera: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    query_player: Query<(&GlobalTransform, Option<&PartyInfo>), With<PlayerCharacter>>,
+    query_member: Query<(
+        &GlobalTransform,
+        Option<&ClientEntityName>,
+        Option<&ModelHeight>,
+    )>,
+    client_entity_list: Res<ClientEntityList>,
+    party_marker_settings: Res<PartyMarkerSettings>,
+) {
+    if !party_marker_settings.enabled {
+        return;
+    }
+
+    let Ok((player_transform, Some(party_info))) = query_player.single() else {
+        return;
+    };
+    let Ok((camera, camera_transform)) = query_camera.single() else {
+        return;
+    };
+
+    let ctx = egui_context.ctx_mut().unwrap();
+    let screen_size = ctx.input(|input| input.screen_rect().size());
+    let painter = ctx.layer_painter(egui::LayerId::new(
+        egui::Order::Background,
+        egui::Id::new("party_marker_arrows"),
+    ));
+
+    for member_entity in get_party_member_entities(party_info, &client_entity_list) {
+        let Ok((member_transform, member_name, model_height)) = query_member.get(member_entity)
+        else {
+            continue;
+        };
+
+        let member_position = member_transform.translation();
+        let distance = player_transform.translation().distance(member_position);
+        if distance > party_marker_settings.max_distance {
+            continue;
+        }
+
+        if let Some(member_name) = member_name {
+            let height = model_height.map_or(2.0, |model_height| model_height.height);
+            paint_marker_name(
+                &painter,
+                screen_size,
+                camera,
+                camera_transform,
+                member_position + Vec3::Y * height,
+                &member_name.name,
+            );
+        }
+
+        if !party_marker_settings.show_offscreen_arrows {
+            continue;
+        }
+
+        let text = match member_name {
+            Some(name) => format!("{} ({:.0}m)", name.name, distance),
+            None => format!("{:.0}m", distance),
+        };
+        paint_offscreen_arrow(
+            &painter,
+            screen_size,
+            camera,
+            camera_transform,
+            member_position,
+            egui::Color32::from_rgb(115, 215, 255),
+            text,
+        );
+    }
+}
+
+/// Paints the member name above their world marker, if it is on screen
+fn paint_marker_name(
+    painter: &egui::Painter,
+    screen_size: egui::Vec2,
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+    marker_position: Vec3,
+    name: &str,
+) {
+    let Some(ndc_space_coords) = camera.world_to_ndc(camera_transform, marker_position) else {
+        return;
+    };
+    if ndc_space_coords.z < 0.0
+        || ndc_space_coords.z > 1.0
+        || ndc_space_coords.x.abs() > 1.0
+        || ndc_space_coords.y.abs() > 1.0
+    {
+        return;
+    }
+
+    let pos = egui::pos2(
+        (ndc_space_coords.x + 1.0) / 2.0 * screen_size.x,
+        (1.0 - ndc_space_coords.y) / 2.0 * screen_size.y - MARKER_NAME_OFFSET_Y,
+    );
+    let font_id = egui::FontId::proportional(12.0);
+    painter.text(
+        pos + egui::vec2(1.0, 1.0),
+        egui::Align2::CENTER_BOTTOM,
+        name,
+        font_id.clone(),
+        egui::Color32::BLACK,
+    );
+    painter.text(
+        pos,
+        egui::Align2::CENTER_BOTTOM,
+        name,
+        font_id,
+        egui::Color32::from_rgb(115, 215, 255),
+    );
+}
+
+/// Paints an arrow at the screen edge pointing towards an off-screen world
+/// position, with a label next to it. Does nothing if the position is on screen.
+pub fn paint_offscreen_arrow(
+    painter: &egui::Painter,
+    screen_size: egui::Vec2,
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+    world_position: Vec3,
+    colour: egui::Color32,
+    text: String,
+) {
+    if let Some(ndc_space_coords) = camera.world_to_ndc(camera_transform, world_position) {
+        if ndc_space_coords.z >= 0.0
+            && ndc_space_coords.z <= 1.0
+            && ndc_space_coords.x.abs() <= 1.0
+            && ndc_space_coords.y.abs() <= 1.0
+        {
+            // On screen, the world marker is enough
+            return;
+        }
+    }
+
+    // Use the view space direction so positions behind the camera still point the right way
+    let view_position = camera_transform
+        .affine()
+        .inverse()
+        .transform_point3(world_position);
+    let Some(direction) = Vec2::new(view_position.x, -view_position.y).try_normalize() else {
+        return;
+    };
+
+    let screen_center = egui::pos2(screen_size.x / 2.0, screen_size.y / 2.0);
+    let half_extents = Vec2::new(
+        (screen_size.x / 2.0 - ARROW_EDGE_MARGIN).max(0.0),
+        (screen_size.y / 2.0 - ARROW_EDGE_MARGIN).max(0.0),
+    );
+    let scale = (half_extents.x / direction.x.abs()).min(half_extents.y / direction.y.abs());
+    let arrow_pos = screen_center + egui::vec2(direction.x, direction.y) * scale;
+
+    let forward = egui::vec2(direction.x, direction.y);
+    let side = egui::vec2(-direction.y, direction.x);
+    painter.add(egui::Shape::convex_polygon(
+        vec![
+            arrow_pos + forward * ARROW_SIZE,
+            arrow_pos - forward * ARROW_SIZE * 0.6 + side * ARROW_SIZE * 0.8,
+            arrow_pos - forward * ARROW_SIZE * 0.6 - side * ARROW_SIZE * 0.8,
+        ],
+        colour,
+        egui::Stroke::new(1.0, egui::Color32::BLACK),
+    ));
+    painter.text(
+        arrow_pos - forward * ARROW_SIZE * 2.0,
+        egui::Align2::CENTER_CENTER,
+        text,
+        egui::FontId::proportional(12.0),
+        colour,
+    );
+}
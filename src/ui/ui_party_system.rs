@@ -1,6 +1,11 @@
-use bevy::prelude::{Assets, Entity, Local, MessageReader, MessageWriter, Query, Res, ResMut, With};
+use bevy::{
+    ecs::system::SystemParam,
+    prelude::{Assets, Entity, Local, MessageReader, MessageWriter, Query, Res, ResMut, Time, With},
+};
 use bevy_egui::{egui, EguiContexts};
+use rand::Rng;
 
+use rose_data::Item;
 use rose_game_common::{
     components::{AbilityValues, CharacterInfo, HealthPoints, Level},
     messages::{
@@ -10,9 +15,13 @@ use rose_game_common::{
 
 use crate::{
     components::{ClientEntity, ClientEntityName, PartyInfo, PartyOwner, PlayerCharacter},
-    events::PartyEvent,
-    resources::{ClientEntityList, GameConnection, SelectedTarget, UiResources},
+    events::{LootRollChoice, PartyEvent},
+    resources::{
+        ClientEntityList, GameConnection, GameData, LootFilterSettings, SelectedTarget,
+        UiResources,
+    },
     ui::{
+        get_item_name_color, ui_add_item_tooltip,
         widgets::{Dialog, Gauge},
         UiSoundEvent,
     },
@@ -36,8 +45,51 @@ pub struct PendingPartyInvite {
     name: String,
 }
 
+/// How long the player has to roll before passing automatically
+const LOOT_ROLL_DURATION: f32 = 30.0;
+
+/// How long the player's roll is shown after choosing
+const LOOT_ROLL_RESULT_DURATION: f32 = 8.0;
+
+/// Roll for an item looted by a party member. The server has no loot rolls,
+/// so they are cosmetic: the player's roll is shown and posted to party chat,
+/// no winner is picked and the looter keeps the item. Only opened when
+/// `LootFilterSettings::party_loot_rolls` is enabled.
+pub struct PartyLootRoll {
+    /// Identifies the drop, identical items looted twice are rolled for separately
+    id: u32,
+    looter: String,
+    item: Item,
+    item_name: String,
+    started_at: f32,
+    /// When the player chose, their choice and roll from 1 to 100, 0 for a pass
+    choice: Option<(f32, LootRollChoice, u32)>,
+}
+
+impl PartyLootRoll {
+    fn choose(&mut self, choice: LootRollChoice, now: f32) -> u32 {
+        let roll = if choice == LootRollChoice::Pass {
+            0
+        } else {
+            rand::thread_rng().gen_range(1..=100)
+        };
+        self.choice = Some((now, choice, roll));
+        roll
+    }
+}
+
+/// Grouped resources for the loot rolls of ui_party_system to avoid parameter count limit
+#[derive(SystemParam)]
+pub struct PartyLootRollParams<'w> {
+    time: Res<'w, Time>,
+    game_data: Res<'w, GameData>,
+    loot_filter_settings: Res<'w, LootFilterSettings>,
+}
+
 pub struct UiStatePartySystem {
     pending_invites: Vec<PendingPartyInvite>,
+    loot_rolls: Vec<PartyLootRoll>,
+    next_loot_roll_id: u32,
     party_xp_gauge: Gauge,
     party_member_health_gauge: Gauge,
     selected_party_member_index: Option<usize>,
@@ -47,6 +99,8 @@ impl Default for UiStatePartySystem {
     fn default() -> Self {
         Self {
             pending_invites: Default::default(),
+            loot_rolls: Vec::new(),
+            next_loot_roll_id: 0,
             party_xp_gauge: Gauge {
                 id: IID_PARTY_XP_GAUGE,
                 x: 96.0,
@@ -86,17 +140,19 @@ pub fn ui_party_system(
     ui_resources: Res<UiResources>,
     dialog_assets: Res<Assets<Dialog>>,
     mut selected_target: ResMut<SelectedTarget>,
+    mut loot_roll_params: PartyLootRollParams,
 ) {
     let player = if let Ok(player) = query_player.single() {
         player
     } else {
         return;
     };
+    let now = loot_roll_params.time.elapsed_secs();
 
     // Add any new incoming invites
     for event in party_events.read() {
-        match *event {
-            PartyEvent::InvitedCreate(entity) => {
+        match event {
+            &PartyEvent::InvitedCreate(entity) => {
                 if let Ok((client_entity, client_entity_name)) = query_invite.get(entity) {
                     ui_state.pending_invites.push(PendingPartyInvite {
                         is_create: true,
@@ -105,7 +161,7 @@ pub fn ui_party_system(
                     });
                 }
             }
-            PartyEvent::InvitedJoin(entity) => {
+            &PartyEvent::InvitedJoin(entity) => {
                 if let Ok((client_entity, client_entity_name)) = query_invite.get(entity) {
                     ui_state.pending_invites.push(PendingPartyInvite {
                         is_create: false,
@@ -114,9 +170,44 @@ pub fn ui_party_system(
                     });
                 }
             }
+            PartyEvent::LootRollStart { looter, item } => {
+                let Some(item_data) = loot_roll_params
+                    .game_data
+                    .items
+                    .get_base_item(item.get_item_reference())
+                else {
+                    continue;
+                };
+
+                if player.5.is_some() && loot_roll_params.loot_filter_settings.party_loot_rolls {
+                    let id = ui_state.next_loot_roll_id;
+                    ui_state.next_loot_roll_id = id.wrapping_add(1);
+                    ui_state.loot_rolls.push(PartyLootRoll {
+                        id,
+                        looter: looter.clone(),
+                        item: item.clone(),
+                        item_name: item_data.name.to_string(),
+                        started_at: now,
+                        choice: None,
+                    });
+                }
+            }
         }
     }
 
+    update_party_loot_rolls(
+        &mut ui_state.loot_rolls,
+        player.5.is_some() && loot_roll_params.loot_filter_settings.party_loot_rolls,
+        now,
+    );
+    ui_party_loot_roll_windows(
+        egui_context.ctx_mut().unwrap(),
+        &mut ui_state.loot_rolls,
+        now,
+        &loot_roll_params.game_data,
+        game_connection.as_deref(),
+    );
+
     let mut i = 0;
     while i != ui_state.pending_invites.len() {
         let mut window_open = true;
@@ -415,3 +506,134 @@ pub fn ui_party_system(
         }
     }
 }
+
+/// Pass on the loot rolls the player did not choose for in time and close
+/// the windows of rolls chosen a while ago. Rolls are dropped when the party is
+/// left or loot rolls are turned off.
+fn update_party_loot_rolls(loot_rolls: &mut Vec<PartyLootRoll>, in_party: bool, now: f32) {
+    if !in_party {
+        loot_rolls.clear();
+        return;
+    }
+
+    for loot_roll in loot_rolls
+        .iter_mut()
+        .filter(|loot_roll| loot_roll.choice.is_none())
+    {
+        if now - loot_roll.started_at >= LOOT_ROLL_DURATION {
+            loot_roll.choose(LootRollChoice::Pass, now);
+        }
+    }
+
+    loot_rolls.retain(|loot_roll| {
+        loot_roll.choice.map_or(true, |(chosen_at, _, _)| {
+            now - chosen_at < LOOT_ROLL_RESULT_DURATION
+        })
+    });
+}
+
+/// Text of the party chat message showing the player's roll to the party,
+/// it is only ever read by players
+fn loot_roll_party_chat(loot_roll: &PartyLootRoll, choice: LootRollChoice, roll: u32) -> String {
+    if choice == LootRollChoice::Pass {
+        format!("#Pass on {} looted by {}", loot_roll.item_name, loot_roll.looter)
+    } else {
+        format!(
+            "#{} {} for {} looted by {}",
+            choice.name(),
+            roll,
+            loot_roll.item_name,
+            loot_roll.looter
+        )
+    }
+}
+
+fn ui_party_loot_roll_windows(
+    ctx: &egui::Context,
+    loot_rolls: &mut [PartyLootRoll],
+    now: f32,
+    game_data: &GameData,
+    game_connection: Option<&GameConnection>,
+) {
+    for (index, loot_roll) in loot_rolls.iter_mut().enumerate() {
+        let mut chosen = None;
+
+        egui::Window::new("Loot Roll")
+            .id(egui::Id::new(("party_loot_roll", loot_roll.id)))
+            .collapsible(false)
+            .resizable(false)
+            .default_pos(
+                ctx.input(|input| input.screen_rect().center())
+                    + egui::vec2(0.0, 40.0 * index as f32),
+            )
+            .show(ctx, |ui| {
+                let item_data = game_data
+                    .items
+                    .get_base_item(loot_roll.item.get_item_reference());
+                let mut item_name = egui::RichText::new(&loot_roll.item_name).strong();
+                if let Some(item_data) = item_data {
+                    item_name = item_name.color(get_item_name_color(
+                        loot_roll.item.get_item_type(),
+                        item_data,
+                    ));
+                }
+                ui.label(item_name).on_hover_ui(|ui| {
+                    ui_add_item_tooltip(ui, game_data, None, &loot_roll.item);
+                });
+                ui.label(format!("Looted by {}", loot_roll.looter));
+
+                match loot_roll.choice {
+                    None => {
+                        let remaining =
+                            (LOOT_ROLL_DURATION - (now - loot_roll.started_at)).max(0.0);
+                        ui.add(
+                            egui::ProgressBar::new(remaining / LOOT_ROLL_DURATION)
+                                .text(format!("{:.0}s", remaining.ceil())),
+                        );
+                        ui.ctx().request_repaint();
+
+                        ui.horizontal(|ui| {
+                            for choice in [
+                                LootRollChoice::Need,
+                                LootRollChoice::Greed,
+                                LootRollChoice::Pass,
+                            ] {
+                                if ui.button(choice.name()).clicked() {
+                                    chosen = Some(choice);
+                                }
+                            }
+                        });
+                    }
+                    Some((_, LootRollChoice::Pass, _)) => {
+                        ui.label("You passed");
+                    }
+                    Some((_, choice, roll)) => {
+                        ui.label(
+                            egui::RichText::new(format!("You rolled {} ({})", roll, choice.name()))
+                                .color(egui::Color32::YELLOW),
+                        );
+                    }
+                }
+
+                if loot_roll.choice.is_some() {
+                    ui.weak(format!(
+                        "The party's rolls are shown in party chat, {} keeps the item",
+                        loot_roll.looter
+                    ));
+                }
+            });
+
+        if let Some(choice) = chosen {
+            let roll = loot_roll.choose(choice, now);
+
+            if let Some(game_connection) = game_connection {
+                game_connection
+                    .client_message_tx
+                    .send(ClientMessage::Chat {
+                        text: loot_roll_party_chat(loot_roll, choice, roll),
+                    })
+                    .ok();
+            }
+        }
+    }
+}
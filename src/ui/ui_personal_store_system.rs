@@ -11,7 +11,7 @@ use rose_game_common::{components::Money, messages::client::ClientMessage};
 use crate::{
     components::{ClientEntity, PersonalStore, PlayerCharacter, Position},
     events::{MessageBoxEvent, PersonalStoreEvent},
-    resources::{GameConnection, GameData, UiResources},
+    resources::{GameConnection, GameData, UiResources, WorldRates},
     ui::{
         tooltips::{PlayerTooltipQuery, PlayerTooltipQueryItem},
         ui_add_item_tooltip,
@@ -27,11 +27,16 @@ const IID_RADIOBOX: i32 = 30;
 const IID_BTN_SELL: i32 = 31;
 const IID_BTN_BUY: i32 = 32;
 
+/// Unit prices this many times the NPC store price are flagged as mispriced
+const MISPRICED_FACTOR: i64 = 10;
+
 pub struct UiPersonalStoreState {
     store_owner: Option<Entity>,
     store_sell_items: [Option<(Item, Money)>; 30],
     store_buy_items: [Option<(Item, Money)>; 30],
     selected_tab: i32,
+    /// Kept whilst browsing between stores
+    search: String,
 }
 
 impl Default for UiPersonalStoreState {
@@ -41,10 +46,64 @@ impl Default for UiPersonalStoreState {
             store_sell_items: Default::default(),
             store_buy_items: Default::default(),
             selected_tab: IID_BTN_SELL,
+            search: String::new(),
         }
     }
 }
 
+impl UiPersonalStoreState {
+    fn close(&mut self) {
+        *self = Self {
+            search: std::mem::take(&mut self.search),
+            ..Default::default()
+        };
+    }
+}
+
+fn item_matches_search(item: &Item, search: &str, game_data: &GameData) -> bool {
+    search.is_empty()
+        || game_data
+            .items
+            .get_base_item(item.get_item_reference())
+            .map_or(false, |item_data| item_data.name.to_lowercase().contains(search))
+}
+
+/// Price of a single item of the stack
+fn unit_price(item: &Item, price: &Money) -> i64 {
+    price.0 / (item.get_quantity() as i64).max(1)
+}
+
+/// What a NPC store charges for and pays for a single item, used as a
+/// reference for player prices
+fn npc_reference_prices(
+    item: &Item,
+    game_data: &GameData,
+    world_rates: Option<&Res<WorldRates>>,
+) -> (i64, i64) {
+    let buy_price = game_data
+        .ability_value_calculator
+        .calculate_npc_store_item_buy_price(
+            &game_data.items,
+            item.get_item_reference(),
+            0,
+            world_rates.map_or(100, |x| x.item_price_rate),
+            world_rates.map_or(100, |x| x.town_price_rate),
+        )
+        .unwrap_or(0) as i64;
+    let sell_price = game_data
+        .ability_value_calculator
+        .calculate_npc_store_item_sell_price(
+            &game_data.items,
+            item,
+            0,
+            world_rates.map_or(0, |x| x.world_price_rate),
+            world_rates.map_or(0, |x| x.item_price_rate),
+            world_rates.map_or(0, |x| x.town_price_rate),
+        )
+        .unwrap_or(0) as i64;
+    (buy_price, sell_price)
+}
+
 fn ui_add_store_item_slot(
     ui: &mut egui::Ui,
     ui_state_dnd: &mut UiStateDragAndDrop,
@@ -57,9 +116,12 @@ fn ui_add_store_item_slot(
     player_tooltip_data: Option<&PlayerTooltipQueryItem<'_, '_, '_>>,
     game_data: &GameData,
     ui_resources: &UiResources,
+    world_rates: Option<&Res<WorldRates>>,
     message_box_events: &mut MessageWriter<MessageBoxEvent>,
 ) {
     let item_data = game_data.items.get_base_item(item.get_item_reference());
+    let is_stack = item.is_stackable_item() && item.get_quantity() > 1;
+    let unit_price = unit_price(item, price);
 
     let mut dropped_item = None;
     let response = ui
@@ -87,12 +149,19 @@ fn ui_add_store_item_slot(
     if is_sell_item && response.double_clicked() {
         let item = item.clone();
 
+        let name = item_data.as_ref().map(|x| x.name.as_str()).unwrap_or("");
         message_box_events.write(MessageBoxEvent::Show {
-            message: format!(
-                "Are you sure you want to buy {} for {} Zuly?",
-                item_data.as_ref().map(|x| x.name.as_str()).unwrap_or(""),
-                price.0
-            ),
+            message: if is_stack {
+                format!(
+                    "Are you sure you want to buy {} {} for {} Zuly ({} each)?",
+                    item.get_quantity(),
+                    name,
+                    price.0,
+                    unit_price
+                )
+            } else {
+                format!("Are you sure you want to buy {} for {} Zuly?", name, price.0)
+            },
             modal: false,
             ok: Some(Box::new(move |commands| {
                 commands.queue(move |world: &mut World| {
@@ -116,6 +185,25 @@ fn ui_add_store_item_slot(
         } else {
             ui.colored_label(egui::Color32::GREEN, format!("Price: {}", price.0));
         }
+        if is_stack {
+            ui.label(format!("Price per unit: {}", unit_price));
+        }
+
+        // Compare with NPC stores, buyers and sellers are warned about the obvious outliers
+        let (npc_buy_price, npc_sell_price) = npc_reference_prices(item, game_data, world_rates);
+        if npc_buy_price > 0 {
+            ui.label(format!("NPC store price: {}", npc_buy_price));
+        }
+        let warning = if is_sell_item && npc_buy_price > 0 && unit_price > npc_buy_price * MISPRICED_FACTOR {
+            Some("Far above the NPC store price")
+        } else if !is_sell_item && unit_price < npc_sell_price {
+            Some("NPC stores pay more for this item")
+        } else {
+            None
+        };
+        if let Some(warning) = warning {
+            ui.colored_label(egui::Color32::from_rgb(255, 90, 90), warning);
+        }
     });
 }
 
@@ -132,6 +220,7 @@ pub fn ui_personal_store_system(
     dialog_assets: Res<Assets<Dialog>>,
     game_connection: Option<Res<GameConnection>>,
     game_data: Res<GameData>,
+    world_rates: Option<Res<WorldRates>>,
     mut message_box_events: MessageWriter<MessageBoxEvent>,
 ) {
     let ui_state = &mut *ui_state;
@@ -140,7 +229,7 @@ pub fn ui_personal_store_system(
         match event {
             &PersonalStoreEvent::OpenEntityStore(entity) => {
                 // Close previous store
-                *ui_state = Default::default();
+                ui_state.close();
 
                 // Open new store and request item list
                 if let Ok((client_entity, _, _)) = query_personal_store.get(entity) {
@@ -234,7 +323,7 @@ pub fn ui_personal_store_system(
         if let Ok(personal_store) = query_personal_store.get(personal_store_entity) {
             personal_store
         } else {
-            *ui_state = Default::default();
+            ui_state.close();
             return;
         };
 
@@ -246,7 +335,7 @@ pub fn ui_personal_store_system(
             .distance(personal_store_position.position.xy())
             > 1100.0
         {
-            *ui_state = Default::default();
+            ui_state.close();
             return;
         }
     }
@@ -263,8 +352,9 @@ pub fn ui_personal_store_system(
     let player_tooltip_data = query_player_tooltip.single().ok();
 
     let mut response_close_button = None;
+    let search = ui_state.search.trim().to_lowercase();
 
-    egui::Window::new("Personal Store")
+    let window_response = egui::Window::new("Personal Store")
         .frame(egui::Frame::none())
         .title_bar(false)
         .resizable(false)
@@ -287,8 +377,10 @@ pub fn ui_personal_store_system(
                             for y in 0..6 {
                                 for x in 0..5 {
                                     let slot_index = (y * 5 + x) as usize;
-                                    if let Some((item, price)) =
-                                        ui_state.store_sell_items[(y * 5 + x) as usize].as_ref()
+                                    if let Some((item, price)) = ui_state.store_sell_items
+                                        [slot_index]
+                                        .as_ref()
+                                        .filter(|(item, _)| item_matches_search(item, &search, &game_data))
                                     {
                                         ui_add_store_item_slot(
                                             ui,
@@ -305,6 +397,7 @@ pub fn ui_personal_store_system(
                                             player_tooltip_data.as_ref(),
                                             &game_data,
                                             &ui_resources,
+                                            world_rates.as_ref(),
                                             &mut message_box_events,
                                         );
                                     }
@@ -315,8 +408,10 @@ pub fn ui_personal_store_system(
                             for y in 0..6 {
                                 for x in 0..5 {
                                     let slot_index = (y * 5 + x) as usize;
-                                    if let Some((item, price)) =
-                                        ui_state.store_buy_items[slot_index].as_ref()
+                                    if let Some((item, price)) = ui_state.store_buy_items
+                                        [slot_index]
+                                        .as_ref()
+                                        .filter(|(item, _)| item_matches_search(item, &search, &game_data))
                                     {
                                         ui_add_store_item_slot(
                                             ui,
@@ -333,6 +428,7 @@ pub fn ui_personal_store_system(
                                             player_tooltip_data.as_ref(),
                                             &game_data,
                                             &ui_resources,
+                                            world_rates.as_ref(),
                                             &mut message_box_events,
                                         );
                                     }
@@ -345,7 +441,24 @@ pub fn ui_personal_store_system(
             );
         });
 
+    if let Some(window_response) = window_response {
+        egui::Area::new(egui::Id::new("personal_store_search"))
+            .order(egui::Order::Foreground)
+            .fixed_pos(window_response.response.rect.left_bottom())
+            .show(egui_context.ctx_mut().unwrap(), |ui| {
+                egui::Frame::window(ui.style()).show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label("Search:");
+                        ui.add(egui::TextEdit::singleline(&mut ui_state.search).desired_width(120.0));
+                        if !ui_state.search.is_empty() && ui.small_button("x").clicked() {
+                            ui_state.search.clear();
+                        }
+                    });
+                });
+            });
+    }
+
     if response_close_button.map_or(false, |x| x.clicked()) {
-        *ui_state = Default::default();
+        ui_state.close();
     }
 }
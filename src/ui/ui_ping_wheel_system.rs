@@ -0,0 +1,130 @@
+use bevy::{
+    input::ButtonInput,
+    prelude::{Camera, Camera3d, GlobalTransform, KeyCode, Local, MessageWriter, Query, Res, ResMut, Time, Vec3, With},
+};
+use bevy_egui::{egui, EguiContexts};
+
+use rose_data::SoundId;
+
+use crate::{
+    components::PlayerCharacter,
+    resources::{SelectedTarget, WorldPingKind, WorldPings},
+    ui::{paint_offscreen_arrow, show_radial_menu, UiSoundEvent},
+};
+
+/// Hold to open the ping wheel at the cursor, release over an option or click it to ping
+const PING_WHEEL_KEY: KeyCode = KeyCode::KeyG;
+
+const PING_WHEEL_RADIUS: f32 = 90.0;
+
+/// Sound played when a ping is placed
+const PING_SOUND_ID: u16 = 653;
+
+pub struct PingWheelState {
+    /// Screen position the wheel was opened at
+    center: egui::Pos2,
+    /// World position under the cursor when the wheel was opened
+    world_position: Vec3,
+}
+
+fn ping_color(kind: WorldPingKind) -> egui::Color32 {
+    let [r, g, b, _] = kind.color().to_srgba().to_u8_array();
+    egui::Color32::from_rgb(r, g, b)
+}
+
+/// Radial ping menu and the screen edge arrows for off-screen pings.
+///
+/// The server has no message for pings, so they are only shown to the local player.
+pub fn ui_ping_wheel_system(
+    mut egui_context: EguiContexts,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+    selected_target: Res<SelectedTarget>,
+    query_camera: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    query_player: Query<&GlobalTransform, With<PlayerCharacter>>,
+    mut world_pings: ResMut<WorldPings>,
+    mut ui_sound_events: MessageWriter<UiSoundEvent>,
+    mut ping_wheel: Local<Option<PingWheelState>>,
+) {
+    let Ok(ctx) = egui_context.ctx_mut() else {
+        return;
+    };
+
+    if ping_wheel.is_none()
+        && !ctx.wants_keyboard_input()
+        && keyboard_input.just_pressed(PING_WHEEL_KEY)
+    {
+        if let (Some(pointer), Some(world_position)) = (
+            ctx.input(|input| input.pointer.hover_pos()),
+            selected_target.hover_position,
+        ) {
+            *ping_wheel = Some(PingWheelState {
+                center: pointer,
+                world_position,
+            });
+        }
+    }
+
+    if let Some(state) = ping_wheel.as_ref() {
+        let options: Vec<(&str, egui::Color32)> = WorldPingKind::ALL
+            .iter()
+            .map(|kind| (kind.display_name(), ping_color(*kind)))
+            .collect();
+        let response = show_radial_menu(
+            ctx,
+            egui::Id::new("ping_wheel"),
+            state.center,
+            PING_WHEEL_RADIUS,
+            &options,
+        );
+        let mut selected = response.clicked.map(|index| WorldPingKind::ALL[index]);
+        let hovered = response.hovered.map(|index| WorldPingKind::ALL[index]);
+        let cancelled =
+            response.cancelled || ctx.input(|input| input.key_pressed(egui::Key::Escape));
+
+        if keyboard_input.just_released(PING_WHEEL_KEY) {
+            // Releasing over the centre keeps the wheel open so an option can be clicked
+            selected = selected.or(hovered);
+        }
+
+        if let Some(kind) = selected {
+            world_pings.add(kind, state.world_position, time.elapsed_secs());
+            if let Some(sound_id) = SoundId::new(PING_SOUND_ID) {
+                ui_sound_events.write(UiSoundEvent::new(sound_id));
+            }
+        }
+
+        if selected.is_some() || cancelled {
+            *ping_wheel = None;
+        }
+    }
+
+    if world_pings.pings.is_empty() {
+        return;
+    }
+
+    let (Ok((camera, camera_transform)), Ok(player_transform)) =
+        (query_camera.single(), query_player.single())
+    else {
+        return;
+    };
+
+    let screen_size = ctx.input(|input| input.screen_rect().size());
+    let painter = ctx.layer_painter(egui::LayerId::new(
+        egui::Order::Background,
+        egui::Id::new("world_ping_arrows"),
+    ));
+
+    for ping in world_pings.pings.iter() {
+        let distance = player_transform.translation().distance(ping.position);
+        paint_offscreen_arrow(
+            &painter,
+            screen_size,
+            camera,
+            camera_transform,
+            ping.position,
+            ping_color(ping.kind),
+            format!("{} ({:.0}m)", ping.kind.display_name(), distance),
+        );
+    }
+}
@@ -6,11 +6,12 @@ use rose_game_common::components::{
 };
 
 use crate::{
-    components::PlayerCharacter,
+    components::{PlayerCharacter, Resting},
     resources::{GameData, SelectedTarget, UiResources},
     ui::{
         tooltips::{PlayerTooltipQuery, PlayerTooltipQueryItem},
-        ui_add_item_tooltip,
+        ui_add_item_tooltip, ui_add_stat_breakdown, AbilityValuesBreakdown,
+        AbilityValuesBreakdownQuery,
         widgets::{DataBindings, Dialog, DrawTextTrait},
         DragAndDropId, DragAndDropSlot, UiSoundEvent, UiStateWindows,
     },
@@ -94,6 +95,8 @@ pub fn ui_player_info_system(
     mut ui_sound_events: MessageWriter<UiSoundEvent>,
     query_player: Query<(Entity, &AbilityValues, &CharacterInfo, &Level, &HealthPoints, &ManaPoints, &ExperiencePoints, &Equipment), With<PlayerCharacter>>,
     query_player_tooltip: Query<PlayerTooltipQuery, With<PlayerCharacter>>,
+    query_player_resting: Query<&Resting, With<PlayerCharacter>>,
+    query_player_breakdown: Query<AbilityValuesBreakdownQuery, With<PlayerCharacter>>,
     game_data: Res<GameData>,
     ui_resources: Res<UiResources>,
     dialog_assets: Res<Assets<Dialog>>,
@@ -113,6 +116,8 @@ pub fn ui_player_info_system(
     let player_tooltip_data = query_player_tooltip.single().ok();
 
     let mut response_menu_button = None;
+    let mut response_hp_gauge = None;
+    let mut response_mp_gauge = None;
 
     let response = egui::Window::new("Player Info")
         .anchor(egui::Align2::LEFT_TOP, [0.0, 0.0])
@@ -133,7 +138,11 @@ pub fn ui_player_info_system(
                 ui,
                 DataBindings {
                     sound_events: Some(&mut ui_sound_events),
-                    response: &mut [(IID_BTN_MENU, &mut response_menu_button)],
+                    response: &mut [
+                        (IID_BTN_MENU, &mut response_menu_button),
+                        (IID_GAUGE_HP, &mut response_hp_gauge),
+                        (IID_GAUGE_MP, &mut response_mp_gauge),
+                    ],
                     gauge: &mut [
                         (
                             IID_GAUGE_HP,
@@ -178,6 +187,23 @@ pub fn ui_player_info_system(
                             )),
                     );
 
+                    if let Ok(resting) = query_player_resting.single() {
+                        let recovered_hp = (player.4.hp - resting.start_hp).max(0);
+                        let recovered_mp = (player.5.mp - resting.start_mp).max(0);
+                        ui.add_label_in(
+                            egui::Rect::from_min_max(
+                                egui::pos2(15.0, dialog.height),
+                                egui::pos2(230.0, dialog.height + 17.0),
+                            ),
+                            egui::RichText::new(format!(
+                                "Zz Resting  +{} HP  +{} MP",
+                                recovered_hp, recovered_mp
+                            ))
+                            .color(egui::Color32::from_rgb(140, 255, 160)),
+                        )
+                        .on_hover_text("HP and MP recover faster while sitting");
+                    }
+
                     add_equipped_weapon_slot(
                         ui,
                         egui::pos2(186.0, 36.0),
@@ -196,6 +222,30 @@ pub fn ui_player_info_system(
         }
     }
 
+    if let Ok(player_breakdown) = query_player_breakdown.single() {
+        if let Some(response) = response_hp_gauge {
+            response.on_hover_ui(|ui| {
+                ui_add_stat_breakdown(
+                    ui,
+                    "Max HP",
+                    &AbilityValuesBreakdown::new(&game_data, &player_breakdown),
+                    |x| x.get_max_health(),
+                );
+            });
+        }
+
+        if let Some(response) = response_mp_gauge {
+            response.on_hover_ui(|ui| {
+                ui_add_stat_breakdown(
+                    ui,
+                    "Max MP",
+                    &AbilityValuesBreakdown::new(&game_data, &player_breakdown),
+                    |x| x.get_max_mana(),
+                );
+            });
+        }
+    }
+
     if response_menu_button.map_or(false, |r| r.clicked()) {
         ui_state_windows.menu_open = !ui_state_windows.menu_open;
     }
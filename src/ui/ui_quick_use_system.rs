@@ -0,0 +1,151 @@
+use bevy::{
+    ecs::message::MessageWriter,
+    prelude::{ButtonInput, KeyCode, Query, Res, ResMut, With},
+};
+use bevy_egui::{egui, EguiContexts};
+
+use rose_data::{ItemReference, ItemType};
+use rose_game_common::components::{CharacterInfo, Inventory};
+
+use crate::{
+    components::{ConsumableCooldownGroup, Cooldowns, PlayerCharacter},
+    events::PlayerCommandEvent,
+    resources::{GameData, QuickUseSettings, UiResources, UiSpriteSheetType},
+    systems::{count_consumable_items, find_consumable_slot},
+    ui::{DragAndDropId, DragAndDropSlot, UiStateDragAndDrop},
+};
+
+fn quick_use_drag_accepts(drag_source: &DragAndDropId) -> bool {
+    matches!(drag_source, DragAndDropId::Inventory(_))
+}
+
+pub fn ui_quick_use_system(
+    mut egui_context: EguiContexts,
+    mut ui_state_dnd: ResMut<UiStateDragAndDrop>,
+    mut quick_use_settings: ResMut<QuickUseSettings>,
+    query_player: Query<(&CharacterInfo, &Inventory, &Cooldowns), With<PlayerCharacter>>,
+    mut player_command_events: MessageWriter<PlayerCommandEvent>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    game_data: Res<GameData>,
+    ui_resources: Res<UiResources>,
+) {
+    let Ok((character_info, inventory, cooldowns)) = query_player.single() else {
+        return;
+    };
+
+    let ctx = egui_context.ctx_mut().unwrap();
+    let use_quick_use_index = if !ctx.wants_keyboard_input() {
+        quick_use_settings
+            .keys
+            .iter()
+            .position(|key| keyboard_input.just_pressed(key.key_code()))
+    } else {
+        None
+    };
+
+    let items = quick_use_settings.items(&character_info.name);
+    let keys = quick_use_settings.keys;
+    let screen_rect = ctx.input(|input| input.screen_rect());
+
+    egui::Window::new("Quick Use")
+        .title_bar(false)
+        .resizable(false)
+        .default_pos(egui::pos2(
+            screen_rect.center().x + 220.0,
+            screen_rect.bottom() - 110.0,
+        ))
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                for (index, item_number) in items.into_iter().enumerate() {
+                    let item_reference = item_number
+                        .map(|item_number| ItemReference::new(ItemType::Consumable, item_number));
+                    let item_data = item_reference
+                        .and_then(|item_reference| game_data.items.get_base_item(item_reference));
+                    let quantity = item_number
+                        .map(|item_number| count_consumable_items(inventory, item_number));
+                    let sprite = item_data.and_then(|item_data| {
+                        ui_resources.get_sprite_by_index(
+                            UiSpriteSheetType::Item,
+                            item_data.icon_index as usize,
+                        )
+                    });
+                    let cooldown_percent = item_reference
+                        .and_then(|item_reference| {
+                            ConsumableCooldownGroup::from_item(&item_reference, &game_data)
+                        })
+                        .and_then(|cooldown_group| {
+                            cooldowns.get_consumable_cooldown_percent(cooldown_group)
+                        });
+
+                    let mut dropped_item = None;
+                    let response = ui
+                        .vertical(|ui| {
+                            let response = ui.add(DragAndDropSlot::new(
+                                DragAndDropId::NotDraggable,
+                                sprite,
+                                None,
+                                false,
+                                quantity,
+                                cooldown_percent,
+                                quick_use_drag_accepts,
+                                &mut ui_state_dnd.dragged_item,
+                                &mut dropped_item,
+                                [40.0, 40.0],
+                            ));
+
+                            // Grey out the slot once the last one has been used
+                            if quantity == Some(0) {
+                                ui.painter().rect_filled(
+                                    response.rect,
+                                    0.0,
+                                    egui::Color32::from_black_alpha(160),
+                                );
+                            }
+
+                            ui.vertical_centered(|ui| {
+                                ui.small(keys[index].display_name());
+                            });
+
+                            response
+                        })
+                        .inner;
+
+                    if quantity.map_or(false, |quantity| quantity > 0)
+                        && (use_quick_use_index == Some(index) || response.clicked())
+                    {
+                        if let Some(item_slot) = item_number
+                            .and_then(|item_number| find_consumable_slot(inventory, item_number))
+                        {
+                            player_command_events.write(PlayerCommandEvent::UseItem(item_slot));
+                        }
+                    }
+
+                    if response.secondary_clicked() {
+                        quick_use_settings.set_item(&character_info.name, index, None);
+                    }
+
+                    if let Some(item_data) = item_data {
+                        response.on_hover_text(format!(
+                            "{} [{}]\nRight click to clear",
+                            item_data.name,
+                            keys[index].display_name()
+                        ));
+                    } else {
+                        response.on_hover_text("Drag a consumable here from the inventory");
+                    }
+
+                    if let Some(DragAndDropId::Inventory(item_slot)) = dropped_item {
+                        if let Some(item) = inventory.get_item(item_slot) {
+                            if item.get_item_type() == ItemType::Consumable {
+                                quick_use_settings.set_item(
+                                    &character_info.name,
+                                    index,
+                                    Some(item.get_item_number()),
+                                );
+                            }
+                        }
+                    }
+                }
+            });
+        });
+}
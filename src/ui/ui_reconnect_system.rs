@@ -0,0 +1,45 @@
+use bevy::prelude::Res;
+use bevy_egui::{egui, EguiContexts};
+
+use crate::resources::{ReconnectStage, ReconnectState};
+
+pub fn ui_reconnect_system(
+    mut egui_context: EguiContexts,
+    reconnect_state: Option<Res<ReconnectState>>,
+) {
+    let Some(reconnect_state) = reconnect_state else {
+        return;
+    };
+
+    egui::Window::new("Connection lost")
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .collapsible(false)
+        .resizable(false)
+        .show(egui_context.ctx_mut().unwrap(), |ui| {
+            ui.label(format!(
+                "Connection lost — Reconnecting, attempt {} of {}…",
+                reconnect_state.attempt, reconnect_state.max_attempts
+            ));
+
+            match reconnect_state.stage {
+                ReconnectStage::WaitRetry => {
+                    ui.label(format!(
+                        "Retrying in {:.0}s",
+                        reconnect_state.remaining_secs.max(0.0).ceil()
+                    ));
+                }
+                ReconnectStage::ConnectingLogin => {
+                    ui.label("Logging in");
+                }
+                ReconnectStage::ConnectingWorld => {
+                    ui.label("Connecting to channel");
+                }
+                ReconnectStage::ConnectingGame => {
+                    ui.label("Connecting to game");
+                }
+                ReconnectStage::JoiningZone => {
+                    ui.label("Joining zone");
+                }
+            }
+        });
+}
@@ -4,8 +4,9 @@ use bevy_egui::{egui, EguiContexts};
 use rose_game_common::messages::client::ClientMessage;
 
 use crate::{
-    components::{Dead, PlayerCharacter},
+    components::{ClientEntityName, Dead, PartyInfo, PlayerCharacter},
     resources::{GameConnection, UiResources},
+    systems::DeathSpectatorCamera,
     ui::{
         widgets::{DataBindings, Dialog},
         UiSoundEvent,
@@ -16,7 +17,9 @@ const IID_BTN_SAVE_POSITION: i32 = 3;
 const IID_BTN_REVIVE_POSITION: i32 = 4;
 
 pub fn ui_respawn_system(
-    query_player_dead: Query<&Dead, With<PlayerCharacter>>,
+    query_player_dead: Query<(&Dead, Option<&PartyInfo>), With<PlayerCharacter>>,
+    query_spectator: Query<&DeathSpectatorCamera>,
+    query_name: Query<&ClientEntityName>,
     dialog_assets: Res<Assets<Dialog>>,
     ui_resources: Res<UiResources>,
     mut ui_sound_events: MessageWriter<UiSoundEvent>,
@@ -64,6 +67,26 @@ pub fn ui_respawn_system(
             )
         });
 
+    let has_party = query_player_dead
+        .single()
+        .map_or(false, |(_, party_info)| party_info.is_some());
+    if has_party {
+        let spectating_name = query_spectator
+            .single()
+            .ok()
+            .and_then(|spectator| query_name.get(spectator.spectate_entity).ok());
+
+        egui::Area::new(egui::Id::new("respawn_spectate_hint"))
+            .anchor(egui::Align2::CENTER_BOTTOM, [0.0, -120.0])
+            .interactable(false)
+            .show(egui_context.ctx_mut().unwrap(), |ui| {
+                if let Some(name) = spectating_name {
+                    ui.label(format!("Spectating: {}", name.name));
+                }
+                ui.label("Press Tab to spectate nearby party members");
+            });
+    }
+
     if response_save_position.map_or(false, |x| x.clicked()) {
         if let Some(game_connection) = game_connection.as_ref() {
             game_connection
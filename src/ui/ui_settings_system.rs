@@ -1,23 +1,83 @@
 use bevy::ecs::system::SystemParam;
-use bevy::prelude::{Color, Local, Query, Res, ResMut, Resource};
+use bevy::prelude::{Color, Local, MessageWriter, Query, Res, ResMut, Resource, With};
+use bevy::render::renderer::RenderAdapter;
 use bevy_egui::{egui, EguiContexts};
 use bevy_post_process::dof::DepthOfFieldMode;
 
+use rose_data::{ItemReference, ItemType, SoundId};
+use rose_game_common::components::{Inventory, InventoryPageType, ItemSlot, INVENTORY_PAGE_SIZE};
+
 use crate::{
     audio::SoundGain,
     components::{
-        BirdSettings, DirtDashSettings, FishSettings, Season, SoundCategory, WindSwaySettings,
+        BirdSettings, ConsumableCooldownGroup, DirtDashSettings, FishSettings, NameTagType,
+        PlayerCharacter, Season, SoundCategory, WindSwaySettings,
     },
-    graphics::GraphicsSettings,
+    graphics::{max_supported_anisotropy, GraphicsSettings, MAX_MOTION_BLUR_SAMPLES},
     render::{SkyMode, SkySettings, StarrySkySettings, VolumetricCloudSettings, ZoneLighting},
     resources::{
-        BloodEffectConfig, SeasonSettings, SoundSettings, SummerSettings, WaterSettings, ZoneTime,
-        ZoneTimeState,
+        AccessibilitySettings, AfkSettings, AutoPotionSettings, BloodEffectConfig, BloodIntensity, BloodSettings, CameraMotionSettings, CharacterIdleSettings, ChatBubbleSettings, ChatterSettings, ConversationSettings, CurrentZone, ColorblindMode, DecalSettings, ExposureSettings, FogSettings, GameData, HealthFeedbackSettings, HitFeedbackSettings, IdleCameraSettings, LootAllKey, LootFilterSettings, LootRarity, NameTagSettings, ParticleBudget, PartyMarkerSettings, QuickUseKey, QuickUseSettings, SeasonSettings, SoundSettings, SummerSettings,
+        TextureFilteringSettings, TrailQuality, TrailSettings, UiLayoutSettings, UiScale, UiScaleMode, UiSoundKind, UiSoundSettings, UiSoundTheme, WaterSettings, ZoneTime, ZoneTimeState, ANISOTROPY_LEVELS,
+        MAX_DECAL_LIFETIME, MAX_EXPOSURE_EV, MAX_FOG_DISTANCE_SCALE, MAX_FOG_STRENGTH, MAX_UI_SCALE, MIN_DECAL_LIFETIME, MIN_EXPOSURE_EV, MIN_FOG_DISTANCE_SCALE, MIN_FOG_STRENGTH,
+        MIN_UI_SCALE,
     },
     terrain::TerrainEnhancementSettings,
-    ui::UiStateWindows,
+    ui::{UiLayoutEvent, UiSoundEvent, UiStateWindows},
 };
 
+/// Short footstep sound played when a volume slider is released
+const VOLUME_PREVIEW_SOUND_ID: u16 = 653;
+
+/// Picks an auto-potion item from the consumables in the inventory of the wanted cooldown group
+fn ui_add_auto_potion_item(
+    ui: &mut egui::Ui,
+    id_salt: &str,
+    selected_item: &mut Option<usize>,
+    inventory: Option<&Inventory>,
+    game_data: &GameData,
+    is_wanted: impl Fn(ConsumableCooldownGroup) -> bool,
+) {
+    let item_name = |item_number: usize| {
+        game_data
+            .items
+            .get_base_item(ItemReference::new(ItemType::Consumable, item_number))
+            .map_or_else(|| format!("Item {}", item_number), |item_data| item_data.name.to_string())
+    };
+
+    let mut item_numbers: Vec<usize> = Vec::new();
+    if let Some(inventory) = inventory {
+        for index in 0..INVENTORY_PAGE_SIZE {
+            let Some(item) = inventory.get_item(ItemSlot::Inventory(InventoryPageType::Consumables, index))
+            else {
+                continue;
+            };
+
+            let item_reference = item.get_item_reference();
+            if ConsumableCooldownGroup::from_item(&item_reference, game_data).map_or(false, &is_wanted)
+                && !item_numbers.contains(&item_reference.item_number)
+            {
+                item_numbers.push(item_reference.item_number);
+            }
+        }
+    }
+
+    // Keep the chosen item listed after the last one has been used
+    if let Some(item_number) = *selected_item {
+        if !item_numbers.contains(&item_number) {
+            item_numbers.push(item_number);
+        }
+    }
+
+    egui::ComboBox::from_id_salt(id_salt)
+        .selected_text(selected_item.map_or_else(|| "None".to_string(), |item_number| item_name(item_number)))
+        .show_ui(ui, |ui| {
+            ui.selectable_value(selected_item, None, "None");
+            for item_number in item_numbers {
+                ui.selectable_value(selected_item, Some(item_number), item_name(item_number));
+            }
+        });
+}
+
 /// Blend mode for starry sky rendering
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum SkyBlendMode {
@@ -87,6 +147,8 @@ pub struct PostProcessingSettings {
     pub dof_enabled: bool,
     /// Whether volumetric fog is enabled
     pub volumetric_fog_enabled: bool,
+    /// Multiplier of the sun angle based light shaft intensity
+    pub light_shaft_intensity: f32,
     /// Whether color grading is enabled
     pub color_grading_enabled: bool,
 }
@@ -99,6 +161,7 @@ impl Default for PostProcessingSettings {
             ssao_enabled: true,
             dof_enabled: false,
             volumetric_fog_enabled: true,
+            light_shaft_intensity: 1.0,
             color_grading_enabled: false,
         }
     }
@@ -107,6 +170,8 @@ impl Default for PostProcessingSettings {
 #[derive(Copy, Clone, PartialEq, Debug)]
 enum SettingsPage {
     Sound,
+    NameTags,
+    Loot,
     Blood,
     Sky,
     Stars,
@@ -123,16 +188,19 @@ enum SettingsPage {
     PostProcessing,
     Graphics,
     Terrain,
+    Interface,
 }
 
 pub struct UiStateSettings {
     page: SettingsPage,
+    layout_name: String,
 }
 
 impl Default for UiStateSettings {
     fn default() -> Self {
         Self {
             page: SettingsPage::Sound,
+            layout_name: String::new(),
         }
     }
 }
@@ -177,7 +245,14 @@ pub struct SettingsSystemParams<'w, 's> {
     pub ui_state_windows: ResMut<'w, UiStateWindows>,
     pub ui_state_settings: Local<'s, UiStateSettings>,
     pub sound_settings: ResMut<'w, SoundSettings>,
+    pub ui_sound_settings: ResMut<'w, UiSoundSettings>,
     pub blood_effect_config: ResMut<'w, BloodEffectConfig>,
+    pub blood_settings: ResMut<'w, BloodSettings>,
+    pub decal_settings: ResMut<'w, DecalSettings>,
+    pub hit_feedback_settings: ResMut<'w, HitFeedbackSettings>,
+    pub health_feedback_settings: ResMut<'w, HealthFeedbackSettings>,
+    pub camera_motion_settings: ResMut<'w, CameraMotionSettings>,
+    pub character_idle_settings: ResMut<'w, CharacterIdleSettings>,
     pub query_sounds: Query<'w, 's, (&'static SoundCategory, &'static mut SoundGain)>,
     pub sky_settings: ResMut<'w, SkySettings>,
     pub starry_sky_settings: ResMut<'w, StarrySkySettings>,
@@ -196,6 +271,30 @@ pub struct SettingsSystemParams<'w, 's> {
     pub graphics_settings: ResMut<'w, GraphicsSettings>,
     pub terrain_settings: ResMut<'w, TerrainEnhancementSettings>,
     pub zone_time: Option<Res<'w, ZoneTime>>,
+    pub name_tag_settings: ResMut<'w, NameTagSettings>,
+    pub chat_bubble_settings: ResMut<'w, ChatBubbleSettings>,
+    pub chatter_settings: ResMut<'w, ChatterSettings>,
+    pub current_zone: Option<Res<'w, CurrentZone>>,
+    pub party_marker_settings: ResMut<'w, PartyMarkerSettings>,
+    pub loot_filter_settings: ResMut<'w, LootFilterSettings>,
+    pub texture_filtering_settings: ResMut<'w, TextureFilteringSettings>,
+    pub trail_settings: ResMut<'w, TrailSettings>,
+    pub particle_budget: ResMut<'w, ParticleBudget>,
+    pub ui_layout_settings: ResMut<'w, UiLayoutSettings>,
+    pub ui_scale: ResMut<'w, UiScale>,
+    pub accessibility_settings: ResMut<'w, AccessibilitySettings>,
+    pub idle_camera_settings: ResMut<'w, IdleCameraSettings>,
+    pub afk_settings: ResMut<'w, AfkSettings>,
+    pub conversation_settings: ResMut<'w, ConversationSettings>,
+    pub auto_potion_settings: ResMut<'w, AutoPotionSettings>,
+    pub quick_use_settings: ResMut<'w, QuickUseSettings>,
+    pub query_player_inventory: Query<'w, 's, &'static Inventory, With<PlayerCharacter>>,
+    pub game_data: Res<'w, GameData>,
+    pub fog_settings: ResMut<'w, FogSettings>,
+    pub exposure_settings: ResMut<'w, ExposureSettings>,
+    pub ui_layout_events: MessageWriter<'w, UiLayoutEvent>,
+    pub ui_sound_events: MessageWriter<'w, UiSoundEvent>,
+    pub render_adapter: Option<Res<'w, RenderAdapter>>,
 }
 
 pub fn ui_settings_system(mut params: SettingsSystemParams) {
@@ -204,7 +303,14 @@ pub fn ui_settings_system(mut params: SettingsSystemParams) {
         mut ui_state_windows,
         mut ui_state_settings,
         mut sound_settings,
+        mut ui_sound_settings,
         mut blood_effect_config,
+        mut blood_settings,
+        mut decal_settings,
+        mut hit_feedback_settings,
+        mut health_feedback_settings,
+        mut camera_motion_settings,
+        mut character_idle_settings,
         mut query_sounds,
         mut sky_settings,
         mut starry_sky_settings,
@@ -223,6 +329,30 @@ pub fn ui_settings_system(mut params: SettingsSystemParams) {
         mut graphics_settings,
         mut terrain_settings,
         zone_time,
+        mut name_tag_settings,
+        mut chat_bubble_settings,
+        mut chatter_settings,
+        current_zone,
+        mut party_marker_settings,
+        mut loot_filter_settings,
+        mut texture_filtering_settings,
+        mut trail_settings,
+        mut particle_budget,
+        mut ui_layout_settings,
+        mut ui_scale,
+        mut accessibility_settings,
+        mut idle_camera_settings,
+        mut afk_settings,
+        mut conversation_settings,
+        mut auto_potion_settings,
+        mut quick_use_settings,
+        query_player_inventory,
+        game_data,
+        mut fog_settings,
+        mut exposure_settings,
+        mut ui_layout_events,
+        mut ui_sound_events,
+        render_adapter,
     } = params;
 
     egui::Window::new("Settings")
@@ -231,6 +361,12 @@ pub fn ui_settings_system(mut params: SettingsSystemParams) {
         .show(egui_context.ctx_mut().unwrap(), |ui| {
             ui.horizontal(|ui| {
                 ui.selectable_value(&mut ui_state_settings.page, SettingsPage::Sound, "Sound");
+                ui.selectable_value(
+                    &mut ui_state_settings.page,
+                    SettingsPage::NameTags,
+                    "Name Tags",
+                );
+                ui.selectable_value(&mut ui_state_settings.page, SettingsPage::Loot, "Loot");
                 ui.selectable_value(&mut ui_state_settings.page, SettingsPage::Blood, "Blood");
                 ui.selectable_value(
                     &mut ui_state_settings.page,
@@ -307,6 +443,11 @@ pub fn ui_settings_system(mut params: SettingsSystemParams) {
                     SettingsPage::Terrain,
                     "Terrain",
                 );
+                ui.selectable_value(
+                    &mut ui_state_settings.page,
+                    SettingsPage::Interface,
+                    "Interface",
+                );
             });
 
             ui.separator();
@@ -314,35 +455,41 @@ pub fn ui_settings_system(mut params: SettingsSystemParams) {
             match ui_state_settings.page {
                 SettingsPage::Sound => {
                     egui::Grid::new("sound_settings_gain")
-                        .num_columns(2)
+                        .num_columns(3)
                         .show(ui, |ui| {
                             let mut gain_changed = false;
-
-                            ui.label("Sound:");
-                            gain_changed |= ui
-                                .checkbox(&mut sound_settings.enabled, "Enabled")
-                                .changed();
-                            ui.end_row();
+                            let mut preview_category = None;
 
                             ui.label("Global Volume:");
-                            gain_changed |= ui
-                                .add(
-                                    egui::Slider::new(&mut sound_settings.global_gain, 0.0..=1.0)
-                                        .show_value(true),
-                                )
-                                .changed();
+                            let response = ui.add(
+                                egui::Slider::new(&mut sound_settings.global_gain, 0.0..=1.0)
+                                    .show_value(true),
+                            );
+                            gain_changed |= response.changed();
+                            if response.drag_stopped() {
+                                preview_category = Some(SoundCategory::Ui);
+                            }
+
+                            let mut mute_all = !sound_settings.enabled;
+                            if ui.checkbox(&mut mute_all, "Mute All").changed() {
+                                sound_settings.enabled = !mute_all;
+                                gain_changed = true;
+                            }
                             ui.end_row();
 
                             let mut add_category_slider = |text: &str, category| {
                                 ui.label(text);
-                                gain_changed |= ui
-                                    .add(
-                                        egui::Slider::new(
-                                            &mut sound_settings.gains[category],
-                                            0.0..=1.0,
-                                        )
+                                let response = ui.add(
+                                    egui::Slider::new(&mut sound_settings.gains[category], 0.0..=1.0)
                                         .show_value(true),
-                                    )
+                                );
+                                gain_changed |= response.changed();
+                                if response.drag_stopped() {
+                                    preview_category = Some(category);
+                                }
+
+                                gain_changed |= ui
+                                    .checkbox(&mut sound_settings.muted[category], "Mute")
                                     .changed();
                                 ui.end_row();
                             };
@@ -353,6 +500,15 @@ pub fn ui_settings_system(mut params: SettingsSystemParams) {
                             add_category_slider("Player Combat:", SoundCategory::PlayerCombat);
                             add_category_slider("Other Combat:", SoundCategory::OtherCombat);
                             add_category_slider("NPC Sounds:", SoundCategory::NpcSounds);
+                            add_category_slider("Interface:", SoundCategory::Ui);
+
+                            if let Some(category) = preview_category {
+                                // Play a short sound at the new level once the slider is released
+                                if let Some(sound_id) = SoundId::new(VOLUME_PREVIEW_SOUND_ID) {
+                                    ui_sound_events
+                                        .write(UiSoundEvent::with_category(sound_id, category));
+                                }
+                            }
 
                             if gain_changed {
                                 for (category, mut gain) in query_sounds.iter_mut() {
@@ -364,8 +520,371 @@ pub fn ui_settings_system(mut params: SettingsSystemParams) {
                                 }
                             }
                         });
+
+                    ui.separator();
+                    ui.label("Interface Sounds");
+                    egui::Grid::new("ui_sound_settings")
+                        .num_columns(3)
+                        .show(ui, |ui| {
+                            ui.label("Theme:");
+                            egui::ComboBox::from_id_salt("ui_sound_theme")
+                                .selected_text(match ui_sound_settings.theme {
+                                    UiSoundTheme::Classic => "Classic",
+                                    UiSoundTheme::Custom => "Custom",
+                                })
+                                .show_ui(ui, |ui| {
+                                    ui.selectable_value(&mut ui_sound_settings.theme, UiSoundTheme::Classic, "Classic");
+                                    ui.selectable_value(&mut ui_sound_settings.theme, UiSoundTheme::Custom, "Custom");
+                                })
+                                .response
+                                .on_hover_text("Custom replaces each sound with one chosen from the sound database");
+                            ui.end_row();
+
+                            let custom_theme = ui_sound_settings.theme == UiSoundTheme::Custom;
+                            for (label, kind) in [
+                                ("Window Open:", UiSoundKind::WindowOpen),
+                                ("Window Close:", UiSoundKind::WindowClose),
+                                ("Button Click:", UiSoundKind::ButtonClick),
+                                ("Error:", UiSoundKind::Error),
+                            ] {
+                                ui.label(label);
+                                if let Some(enabled) = ui_sound_settings.enabled_mut(kind) {
+                                    ui.checkbox(enabled, "Enabled");
+                                }
+
+                                if let Some(custom_sound) = ui_sound_settings.custom_sounds.get_mut(kind) {
+                                    ui.add_enabled_ui(custom_theme, |ui| {
+                                        ui.horizontal(|ui| {
+                                            let mut use_custom = custom_sound.is_some();
+                                            if ui.checkbox(&mut use_custom, "Sound:").changed() {
+                                                *custom_sound = use_custom.then_some(VOLUME_PREVIEW_SOUND_ID);
+                                            }
+
+                                            if let Some(custom_sound_id) = custom_sound.as_mut() {
+                                                ui.add(egui::DragValue::new(custom_sound_id).range(1..=u16::MAX));
+                                                if ui.small_button("Play").clicked() {
+                                                    if let Some(sound_id) = SoundId::new(*custom_sound_id) {
+                                                        ui_sound_events.write(UiSoundEvent::with_category(
+                                                            sound_id,
+                                                            SoundCategory::Ui,
+                                                        ));
+                                                    }
+                                                }
+                                            }
+                                        });
+                                    });
+                                }
+                                ui.end_row();
+                            }
+                        });
+                    ui.label("Sounds missing from the sound database keep the classic sound.");
+                }
+                SettingsPage::NameTags => {
+                    egui::Grid::new("name_tag_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("Threat Indicator:");
+                            ui.checkbox(&mut name_tag_settings.show_threat_indicator, "Enabled");
+                            ui.end_row();
+
+                            let name_tag_types = [
+                                ("Players", NameTagType::Character),
+                                ("NPCs", NameTagType::Npc),
+                                ("Monsters", NameTagType::Monster),
+                            ];
+                            for (label, name_tag_type) in name_tag_types {
+                                ui.label(format!("{} Always Show:", label));
+                                ui.checkbox(
+                                    &mut name_tag_settings.show_all[name_tag_type],
+                                    "Enabled",
+                                );
+                                ui.end_row();
+
+                                ui.label(format!("{} Max Distance:", label));
+                                ui.add(
+                                    egui::Slider::new(
+                                        &mut name_tag_settings.max_distance[name_tag_type],
+                                        5.0..=200.0,
+                                    )
+                                    .show_value(true),
+                                );
+                                ui.end_row();
+                            }
+
+                            ui.label("Item Drops Max Distance:");
+                            ui.add(
+                                egui::Slider::new(
+                                    &mut name_tag_settings.item_drop_max_distance,
+                                    5.0..=200.0,
+                                )
+                                .show_value(true),
+                            );
+                            ui.end_row();
+
+                            ui.label("Fade Distance:");
+                            ui.add(
+                                egui::Slider::new(&mut name_tag_settings.fade_distance, 0.0..=50.0)
+                                    .show_value(true),
+                            );
+                            ui.end_row();
+                        });
+
+                    ui.separator();
+                    ui.label("The threat indicator marks monsters which are targeting you.");
+                    ui.label("Name tags which are not always shown appear on hover or target.");
+
+                    ui.separator();
+                    ui.label("Chat Bubbles");
+                    egui::Grid::new("chat_bubble_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("Max Visible:");
+                            ui.add(
+                                egui::Slider::new(&mut chat_bubble_settings.max_visible, 1..=20)
+                                    .show_value(true),
+                            )
+                            .on_hover_text("The oldest bubbles fade out first when more are showing");
+                            ui.end_row();
+
+                            ui.label("Lifetime:");
+                            ui.add(
+                                egui::Slider::new(&mut chat_bubble_settings.lifetime_scale, 0.25..=3.0)
+                                    .suffix("x")
+                                    .show_value(true),
+                            );
+                            ui.end_row();
+
+                            ui.label("Opacity:");
+                            ui.add(
+                                egui::Slider::new(&mut chat_bubble_settings.opacity, 0.1..=1.0)
+                                    .show_value(true),
+                            );
+                            ui.end_row();
+
+                            ui.label("Background Opacity:");
+                            ui.add(
+                                egui::Slider::new(&mut chat_bubble_settings.background_opacity, 0.0..=1.0)
+                                    .show_value(true),
+                            );
+                            ui.end_row();
+
+                            ui.label("Stack Overlapping:");
+                            ui.checkbox(&mut chat_bubble_settings.stacking, "Enabled");
+                            ui.end_row();
+                        });
+                    ui.label("Chat bubbles fade out with the name tag max distance of the speaker.");
+
+                    ui.separator();
+                    ui.label("Monster & NPC Chatter");
+                    egui::Grid::new("chatter_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("Chatter:");
+                            ui.checkbox(&mut chatter_settings.enabled, "Enabled");
+                            ui.end_row();
+
+                            ui.label("Frequency:");
+                            ui.add_enabled(
+                                chatter_settings.enabled,
+                                egui::Slider::new(&mut chatter_settings.frequency, 0.0..=4.0)
+                                    .suffix("x")
+                                    .show_value(true),
+                            );
+                            ui.end_row();
+
+                            if let Some(current_zone) = current_zone.as_ref() {
+                                let zone = current_zone.id.get();
+                                let mut zone_frequency = chatter_settings.zone_multiplier(zone);
+                                ui.label("This Zone:");
+                                if ui
+                                    .add_enabled(
+                                        chatter_settings.enabled,
+                                        egui::Slider::new(&mut zone_frequency, 0.0..=4.0)
+                                            .suffix("x")
+                                            .show_value(true),
+                                    )
+                                    .on_hover_text("Frequency multiplier for the current zone only")
+                                    .changed()
+                                {
+                                    chatter_settings.set_zone_multiplier(zone, zone_frequency);
+                                }
+                                ui.end_row();
+                            }
+                        });
+
+                    ui.label("Muted phrases, one per line:");
+                    let mut muted_phrases = chatter_settings.muted_phrases.join("\n");
+                    if ui
+                        .add(
+                            egui::TextEdit::multiline(&mut muted_phrases)
+                                .desired_rows(3)
+                                .hint_text("Phrases containing these words are never said"),
+                        )
+                        .changed()
+                    {
+                        chatter_settings.muted_phrases =
+                            muted_phrases.split('\n').map(str::to_string).collect();
+                    }
+                    if let Some(phrases_file) = chatter_settings.phrases_file.as_ref() {
+                        ui.label(format!("Extra phrases from {}", phrases_file.to_string_lossy()));
+                    }
+
+                    ui.separator();
+                    egui::Grid::new("party_marker_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("Party Markers:");
+                            ui.checkbox(&mut party_marker_settings.enabled, "Enabled");
+                            ui.end_row();
+
+                            ui.label("Off-screen Arrows:");
+                            ui.checkbox(
+                                &mut party_marker_settings.show_offscreen_arrows,
+                                "Enabled",
+                            );
+                            ui.end_row();
+
+                            ui.label("Max Distance:");
+                            ui.add(
+                                egui::Slider::new(
+                                    &mut party_marker_settings.max_distance,
+                                    25.0..=1000.0,
+                                )
+                                .show_value(true),
+                            );
+                            ui.end_row();
+                        });
+                }
+                SettingsPage::Loot => {
+                    egui::Grid::new("loot_filter_settings")
+                        .num_columns(4)
+                        .show(ui, |ui| {
+                            ui.label("");
+                            ui.label("Name");
+                            ui.label("Beam");
+                            ui.label("Pickup");
+                            ui.end_row();
+
+                            let rarities = [
+                                ("Common:", LootRarity::Common),
+                                ("Rare:", LootRarity::Rare),
+                                ("Unique:", LootRarity::Unique),
+                                ("Zuly:", LootRarity::Money),
+                            ];
+                            for (label, rarity) in rarities {
+                                ui.label(label);
+                                ui.checkbox(&mut loot_filter_settings.show_name[rarity], "");
+                                ui.checkbox(&mut loot_filter_settings.show_beam[rarity], "");
+                                ui.checkbox(&mut loot_filter_settings.pickup[rarity], "");
+                                ui.end_row();
+                            }
+                        });
+
+                    egui::Grid::new("loot_beam_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("Beam Max Distance:");
+                            ui.add(
+                                egui::Slider::new(
+                                    &mut loot_filter_settings.beam_max_distance,
+                                    10.0..=300.0,
+                                )
+                                .show_value(true),
+                            );
+                            ui.end_row();
+
+                            ui.label("Beam Fade Distance:");
+                            ui.add(
+                                egui::Slider::new(
+                                    &mut loot_filter_settings.beam_fade_distance,
+                                    0.0..=100.0,
+                                )
+                                .show_value(true),
+                            );
+                            ui.end_row();
+
+                            ui.label("Loot All Key:");
+                            egui::ComboBox::from_id_salt("loot_all_key")
+                                .selected_text(loot_filter_settings.loot_all_key.display_name())
+                                .show_ui(ui, |ui| {
+                                    for option in LootAllKey::ALL {
+                                        ui.selectable_value(
+                                            &mut loot_filter_settings.loot_all_key,
+                                            option,
+                                            option.display_name(),
+                                        );
+                                    }
+                                });
+                            ui.end_row();
+
+                            ui.label("Loot All Radius:");
+                            ui.add(
+                                egui::Slider::new(
+                                    &mut loot_filter_settings.loot_all_radius,
+                                    1.0..=30.0,
+                                )
+                                .show_value(true),
+                            );
+                            ui.end_row();
+
+                            ui.label("Auto-Loot:");
+                            ui.checkbox(&mut loot_filter_settings.auto_loot, "Enabled");
+                            ui.end_row();
+
+                            ui.label("Auto-Loot Radius:");
+                            ui.add_enabled(
+                                loot_filter_settings.auto_loot,
+                                egui::Slider::new(
+                                    &mut loot_filter_settings.auto_loot_radius,
+                                    0.5..=5.0,
+                                )
+                                .show_value(true),
+                            );
+                            ui.end_row();
+
+                            ui.label("Party Loot Rolls:");
+                            ui.checkbox(&mut loot_filter_settings.party_loot_rolls, "Enabled")
+                                .on_hover_text(
+                                    "Roll for equipment looted in a party. Rolls are posted to party chat, the looter keeps the item.",
+                                );
+                            ui.end_row();
+                        });
+
+                    ui.separator();
+                    ui.label("Hold Alt to show the names of all item drops.");
+                    ui.label(format!(
+                        "Press {} to loot all nearby item drops, hold Shift to include filtered ones.",
+                        loot_filter_settings.loot_all_key.display_name()
+                    ));
                 }
                 SettingsPage::Blood => {
+                    egui::Grid::new("blood_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("Blood:");
+                            ui.add_enabled_ui(!blood_settings.disable_gore, |ui| {
+                                egui::ComboBox::from_id_salt("blood_intensity")
+                                    .selected_text(blood_settings.intensity.display_name())
+                                    .show_ui(ui, |ui| {
+                                        for intensity in BloodIntensity::ALL {
+                                            ui.selectable_value(
+                                                &mut blood_settings.intensity,
+                                                intensity,
+                                                intensity.display_name(),
+                                            );
+                                        }
+                                    });
+                            });
+                            ui.end_row();
+
+                            ui.label("Disable Gore:");
+                            ui.checkbox(&mut blood_settings.disable_gore, "Enabled")
+                                .on_hover_text("No blood or wounds, for streaming or younger audiences");
+                            ui.end_row();
+                        });
+
+                    ui.separator();
+                    ui.label("Advanced, reset when the options above change");
                     egui::Grid::new("blood_effect_settings")
                         .num_columns(2)
                         .show(ui, |ui| {
@@ -1717,6 +2236,38 @@ pub fn ui_settings_system(mut params: SettingsSystemParams) {
                             ui.checkbox(&mut post_processing_settings.volumetric_fog_enabled, "Enabled");
                             ui.end_row();
 
+                            ui.label("Motion Blur:");
+                            ui.add_enabled(
+                                !accessibility_settings.disable_motion_blur,
+                                egui::Checkbox::new(&mut graphics_settings.motion_blur_enabled, "Enabled"),
+                            )
+                            .on_disabled_hover_text("Turned off in the accessibility settings");
+                            ui.end_row();
+
+                            ui.label("Motion Blur Shutter:");
+                            ui.add(
+                                egui::Slider::new(&mut graphics_settings.motion_blur_intensity, 0.0..=1.0)
+                                    .show_value(true),
+                            )
+                            .on_hover_text("Fraction of the longest shutter, half a frame");
+                            ui.end_row();
+
+                            ui.label("Motion Blur Samples:");
+                            ui.add(
+                                egui::Slider::new(&mut graphics_settings.motion_blur_samples, 1..=MAX_MOTION_BLUR_SAMPLES)
+                                    .show_value(true),
+                            );
+                            ui.end_row();
+
+                            ui.label("Light Shafts:");
+                            ui.add(
+                                egui::Slider::new(&mut post_processing_settings.light_shaft_intensity, 0.0..=3.0)
+                                    .suffix("x")
+                                    .show_value(true),
+                            )
+                            .on_hover_text("Strength of the sun's light shafts, strongest at dawn and dusk");
+                            ui.end_row();
+
                             ui.label("Color Grading:");
                             ui.checkbox(&mut post_processing_settings.color_grading_enabled, "Enabled");
                             ui.end_row();
@@ -1764,6 +2315,43 @@ pub fn ui_settings_system(mut params: SettingsSystemParams) {
                             });
                     });
 
+                    // === Fog Section ===
+                    ui.collapsing("Fog", |ui| {
+                        egui::Grid::new("graphics_fog")
+                            .num_columns(2)
+                            .show(ui, |ui| {
+                                ui.label("Use Zone Default:");
+                                ui.checkbox(&mut fog_settings.use_zone_default, "Enabled");
+                                ui.end_row();
+
+                                let enabled = !fog_settings.use_zone_default;
+                                ui.label("Fog Distance:");
+                                ui.add_enabled(
+                                    enabled,
+                                    egui::Slider::new(
+                                        &mut fog_settings.distance_scale,
+                                        MIN_FOG_DISTANCE_SCALE..=MAX_FOG_DISTANCE_SCALE,
+                                    )
+                                    .suffix("x")
+                                    .show_value(true),
+                                )
+                                .on_hover_text("Higher values let you see further before the fog");
+                                ui.end_row();
+
+                                ui.label("Fog Strength:");
+                                ui.add_enabled(
+                                    enabled,
+                                    egui::Slider::new(
+                                        &mut fog_settings.strength,
+                                        MIN_FOG_STRENGTH..=MAX_FOG_STRENGTH,
+                                    )
+                                    .suffix("x")
+                                    .show_value(true),
+                                );
+                                ui.end_row();
+                            });
+                    });
+
                     // === Shadows Section ===
                     ui.collapsing("Shadows", |ui| {
                         egui::Grid::new("graphics_shadows")
@@ -1824,6 +2412,34 @@ pub fn ui_settings_system(mut params: SettingsSystemParams) {
                                     .show_value(true));
                                 ui.end_row();
 
+                                ui.label("Auto Exposure:");
+                                ui.checkbox(&mut exposure_settings.auto_exposure, "Enabled")
+                                    .on_hover_text("Adapt to bright and dark areas over time");
+                                ui.end_row();
+
+                                let auto_exposure = exposure_settings.auto_exposure;
+                                ui.label("Adaptation Speed:");
+                                ui.add_enabled(auto_exposure, egui::Slider::new(&mut exposure_settings.adaptation_speed, 0.5..=10.0)
+                                    .show_value(true));
+                                ui.end_row();
+
+                                ui.label("Exposure Range:");
+                                ui.horizontal(|ui| {
+                                    let max_ev = exposure_settings.max_ev;
+                                    ui.add_enabled(auto_exposure, egui::Slider::new(&mut exposure_settings.min_ev, MIN_EXPOSURE_EV..=max_ev)
+                                        .suffix(" EV"));
+                                    let min_ev = exposure_settings.min_ev;
+                                    ui.add_enabled(auto_exposure, egui::Slider::new(&mut exposure_settings.max_ev, min_ev..=MAX_EXPOSURE_EV)
+                                        .suffix(" EV"));
+                                });
+                                ui.end_row();
+
+                                ui.label("Manual Exposure:");
+                                ui.add_enabled(!auto_exposure, egui::Slider::new(&mut exposure_settings.manual_ev100, 5.0..=15.0)
+                                    .suffix(" EV100")
+                                    .show_value(true));
+                                ui.end_row();
+
                                 ui.label("Tonemapping:");
                                 egui::ComboBox::from_id_salt("tonemapping")
                                     .selected_text(graphics_settings.tonemapping.display_name())
@@ -1856,7 +2472,11 @@ pub fn ui_settings_system(mut params: SettingsSystemParams) {
                                 ui.end_row();
 
                                 ui.label("Motion Blur:");
-                                ui.checkbox(&mut graphics_settings.motion_blur_enabled, "Enabled");
+                                ui.add_enabled(
+                                    !accessibility_settings.disable_motion_blur,
+                                    egui::Checkbox::new(&mut graphics_settings.motion_blur_enabled, "Enabled"),
+                                )
+                                .on_disabled_hover_text("Turned off in the accessibility settings");
                                 ui.end_row();
 
                                 ui.label("Motion Blur Intensity:");
@@ -1864,6 +2484,12 @@ pub fn ui_settings_system(mut params: SettingsSystemParams) {
                                     .show_value(true));
                                 ui.end_row();
 
+                                ui.label("Motion Blur Samples:");
+                                ui.add(egui::Slider::new(&mut graphics_settings.motion_blur_samples, 1..=MAX_MOTION_BLUR_SAMPLES)
+                                    .show_value(true))
+                                    .on_hover_text("More samples give a smoother blur at a higher cost");
+                                ui.end_row();
+
                                 ui.label("SSAO:");
                                 ui.checkbox(&mut graphics_settings.ssao_enabled, "Enabled");
                                 ui.end_row();
@@ -1883,6 +2509,49 @@ pub fn ui_settings_system(mut params: SettingsSystemParams) {
                                 ui.label("Depth of Field:");
                                 ui.checkbox(&mut graphics_settings.dof_enabled, "Enabled");
                                 ui.end_row();
+
+                                let mut trail_quality = trail_settings.quality;
+                                ui.label("Weapon Trails:");
+                                egui::ComboBox::from_id_salt("trail_quality")
+                                    .selected_text(trail_quality.display_name())
+                                    .show_ui(ui, |ui| {
+                                        ui.selectable_value(&mut trail_quality, TrailQuality::Off, "Off");
+                                        ui.selectable_value(&mut trail_quality, TrailQuality::Low, "Low");
+                                        ui.selectable_value(&mut trail_quality, TrailQuality::High, "High");
+                                    });
+                                if trail_quality != trail_settings.quality {
+                                    trail_settings.quality = trail_quality;
+                                }
+                                ui.end_row();
+
+                                ui.label("Particle Budget:");
+                                ui.add(egui::Slider::new(&mut particle_budget.max_particles, 1000..=50000)
+                                    .show_value(true));
+                                ui.end_row();
+
+                                ui.label("Max Decals:");
+                                ui.add(egui::Slider::new(&mut decal_settings.max_decals, 20..=1000)
+                                    .show_value(true))
+                                    .on_hover_text("Blood spatters and ground markers, the oldest are removed first");
+                                ui.end_row();
+
+                                ui.label("Decal Lifetime:");
+                                ui.add(egui::Slider::new(&mut decal_settings.decal_lifetime, MIN_DECAL_LIFETIME..=MAX_DECAL_LIFETIME)
+                                    .text("s")
+                                    .show_value(true));
+                                ui.end_row();
+
+                                ui.label("Combat Particle Priority:");
+                                ui.add(egui::Slider::new(&mut particle_budget.combat_priority, 0..=3));
+                                ui.end_row();
+
+                                ui.label("Character Particle Priority:");
+                                ui.add(egui::Slider::new(&mut particle_budget.character_priority, 0..=3));
+                                ui.end_row();
+
+                                ui.label("Ambient Particle Priority:");
+                                ui.add(egui::Slider::new(&mut particle_budget.ambient_priority, 0..=3));
+                                ui.end_row();
                             });
                     });
 
@@ -1901,6 +2570,25 @@ pub fn ui_settings_system(mut params: SettingsSystemParams) {
                                         ui.selectable_value(&mut graphics_settings.texture_quality, TextureQuality::Ultra, "Ultra");
                                     });
                                 ui.end_row();
+
+                                let max_anisotropy = max_supported_anisotropy(render_adapter.as_deref());
+                                let mut anisotropy = texture_filtering_settings.anisotropy;
+                                ui.label("Anisotropic Filtering:");
+                                egui::ComboBox::from_id_salt("anisotropy")
+                                    .selected_text(match texture_filtering_settings.effective_anisotropy(max_anisotropy) {
+                                        1 => "Off".to_string(),
+                                        level => format!("{}x", level),
+                                    })
+                                    .show_ui(ui, |ui| {
+                                        for level in ANISOTROPY_LEVELS.iter().copied().filter(|&level| level <= max_anisotropy) {
+                                            let text = if level == 1 { "Off".to_string() } else { format!("{}x", level) };
+                                            ui.selectable_value(&mut anisotropy, level, text);
+                                        }
+                                    });
+                                if anisotropy != texture_filtering_settings.anisotropy {
+                                    texture_filtering_settings.anisotropy = anisotropy;
+                                }
+                                ui.end_row();
                             });
                     });
 
@@ -1990,6 +2678,520 @@ pub fn ui_settings_system(mut params: SettingsSystemParams) {
                     ui.label("Tip: Higher shadow quality improves visual fidelity but reduces FPS.");
                     ui.label("Changes to MSAA and VSync may require restart to take full effect.");
                 }
+                SettingsPage::Interface => {
+                    egui::Grid::new("ui_scale_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("UI Scale:");
+                            ui.horizontal(|ui| {
+                                ui.selectable_value(&mut ui_scale.mode, UiScaleMode::Auto, "Auto");
+                                ui.selectable_value(
+                                    &mut ui_scale.mode,
+                                    UiScaleMode::Manual,
+                                    "Manual",
+                                );
+                            });
+                            ui.end_row();
+
+                            ui.label("Scale:");
+                            ui.add_enabled(
+                                ui_scale.mode == UiScaleMode::Manual,
+                                egui::Slider::new(&mut ui_scale.scale, MIN_UI_SCALE..=MAX_UI_SCALE)
+                                    .step_by(0.05)
+                                    .suffix("x")
+                                    .show_value(true),
+                            );
+                            ui.end_row();
+                        });
+
+                    ui.separator();
+                    ui.label("Accessibility");
+                    egui::Grid::new("accessibility_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("Colorblind Mode:");
+                            egui::ComboBox::from_id_salt("colorblind_mode")
+                                .selected_text(accessibility_settings.colorblind_mode.display_name())
+                                .show_ui(ui, |ui| {
+                                    for mode in ColorblindMode::ALL {
+                                        ui.selectable_value(
+                                            &mut accessibility_settings.colorblind_mode,
+                                            mode,
+                                            mode.display_name(),
+                                        );
+                                    }
+                                });
+                            ui.end_row();
+
+                            ui.label("Shape Indicators:");
+                            ui.checkbox(&mut accessibility_settings.shape_indicators, "Enabled")
+                                .on_hover_text("Mark good and bad values with a tick or cross as well as colour");
+                            ui.end_row();
+
+                            ui.label("Disable Motion Blur:");
+                            ui.checkbox(&mut accessibility_settings.disable_motion_blur, "Enabled")
+                                .on_hover_text("Never blur the screen, whatever the graphics settings");
+                            ui.end_row();
+
+                            ui.label("Disable Camera Shake:");
+                            ui.checkbox(&mut accessibility_settings.disable_camera_shake, "Enabled")
+                                .on_hover_text("Never shake the camera, whatever the hit feedback settings");
+                            ui.end_row();
+
+                            ui.label("Disable Health Vignette:");
+                            ui.checkbox(&mut accessibility_settings.disable_health_vignette, "Enabled")
+                                .on_hover_text("Never tint or pulse the screen edges red, whatever the health feedback settings");
+                            ui.end_row();
+
+                            ui.label("Disable Camera Motion:");
+                            ui.checkbox(&mut accessibility_settings.disable_camera_motion, "Enabled")
+                                .on_hover_text("Never bob the camera when running or roll it when flying");
+                            ui.end_row();
+
+                            ui.label("Preview:");
+                            ui.horizontal(|ui| {
+                                ui.label(accessibility_settings.indicator_text(true, "Requirement met"));
+                                ui.label(accessibility_settings.indicator_text(false, "Requirement not met"));
+                            });
+                            ui.end_row();
+
+                            ui.label("");
+                            ui.horizontal(|ui| {
+                                // A sample of the monster name colours, from much weaker to much stronger
+                                for color in [
+                                    egui::Color32::from_rgb(255, 113, 107),
+                                    egui::Color32::from_rgb(255, 166, 107),
+                                    egui::Color32::from_rgb(255, 228, 122),
+                                    egui::Color32::from_rgb(150, 255, 122),
+                                    egui::Color32::from_rgb(137, 243, 255),
+                                ] {
+                                    ui.label(
+                                        egui::RichText::new("Monster")
+                                            .color(accessibility_settings.colorblind_mode.remap(color)),
+                                    );
+                                }
+                                ui.label(
+                                    egui::RichText::new("Hostile Player")
+                                        .color(accessibility_settings.negative_color()),
+                                );
+                            });
+                            ui.end_row();
+                        });
+
+                    ui.separator();
+                    ui.label("Idle Camera");
+                    egui::Grid::new("idle_camera_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("Orbit when idle:");
+                            ui.checkbox(&mut idle_camera_settings.enabled, "Enabled")
+                                .on_hover_text("Slowly orbit the camera around your character after a period without input, not while in combat");
+                            ui.end_row();
+
+                            ui.label("Idle time:");
+                            ui.add_enabled(
+                                idle_camera_settings.enabled,
+                                egui::Slider::new(&mut idle_camera_settings.idle_seconds, 10.0..=300.0)
+                                    .suffix("s")
+                                    .show_value(true),
+                            );
+                            ui.end_row();
+
+                            ui.label("Orbit speed:");
+                            ui.add_enabled(
+                                idle_camera_settings.enabled,
+                                egui::Slider::new(&mut idle_camera_settings.orbit_speed, 1.0..=30.0)
+                                    .suffix("°/s")
+                                    .show_value(true),
+                            );
+                            ui.end_row();
+                        });
+
+                    ui.separator();
+                    ui.label("Away From Keyboard");
+                    egui::Grid::new("afk_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("AFK when idle:");
+                            ui.checkbox(&mut afk_settings.auto_afk, "Enabled")
+                                .on_hover_text("Go AFK after a period without input, any input brings you back");
+                            ui.end_row();
+
+                            ui.label("Idle time:");
+                            ui.add_enabled(
+                                afk_settings.auto_afk,
+                                egui::Slider::new(&mut afk_settings.idle_seconds, 60.0..=1800.0)
+                                    .suffix("s")
+                                    .show_value(true),
+                            );
+                            ui.end_row();
+
+                            ui.label("Message:");
+                            ui.text_edit_singleline(&mut afk_settings.message)
+                                .on_hover_text("Whispers are answered with this while AFK");
+                            ui.end_row();
+                        });
+                    ui.label("Type /afk [message] in chat to go AFK yourself.");
+
+                    ui.separator();
+                    ui.label("Conversations");
+                    egui::Grid::new("conversation_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("Text speed:");
+                            ui.add(
+                                egui::Slider::new(&mut conversation_settings.text_speed, 0.0..=200.0)
+                                    .suffix(" chars/s")
+                                    .show_value(true),
+                            )
+                            .on_hover_text("How fast NPC text appears, 0 shows it all at once");
+                            ui.end_row();
+
+                            ui.label("Skip read text:");
+                            ui.checkbox(&mut conversation_settings.skip_seen_text, "Enabled")
+                                .on_hover_text("Show text already read in this zone all at once");
+                            ui.end_row();
+                        });
+                    ui.label("Click the text or press Space to show it all, Alt+L opens the history.");
+
+                    ui.separator();
+                    ui.label("Auto-Potion");
+                    let player_inventory = query_player_inventory.single().ok();
+                    egui::Grid::new("auto_potion_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("Auto-Potion:");
+                            ui.checkbox(&mut auto_potion_settings.enabled, "Enabled")
+                                .on_hover_text("Use the chosen potion from your inventory when HP or MP runs low");
+                            ui.end_row();
+
+                            let enabled = auto_potion_settings.enabled;
+                            ui.label("HP Potion:");
+                            ui.add_enabled_ui(enabled, |ui| {
+                                ui_add_auto_potion_item(
+                                    ui,
+                                    "auto_potion_hp_item",
+                                    &mut auto_potion_settings.hp_item,
+                                    player_inventory,
+                                    &game_data,
+                                    |group| matches!(group, ConsumableCooldownGroup::HealthRecovery),
+                                );
+                            });
+                            ui.end_row();
+
+                            ui.label("Use Below HP:");
+                            let mut hp_percent = auto_potion_settings.hp_threshold * 100.0;
+                            if ui
+                                .add_enabled(
+                                    enabled,
+                                    egui::Slider::new(&mut hp_percent, 5.0..=90.0).suffix("%").show_value(true),
+                                )
+                                .changed()
+                            {
+                                auto_potion_settings.hp_threshold = hp_percent / 100.0;
+                            }
+                            ui.end_row();
+
+                            ui.label("MP Potion:");
+                            ui.add_enabled_ui(enabled, |ui| {
+                                ui_add_auto_potion_item(
+                                    ui,
+                                    "auto_potion_mp_item",
+                                    &mut auto_potion_settings.mp_item,
+                                    player_inventory,
+                                    &game_data,
+                                    |group| matches!(group, ConsumableCooldownGroup::ManaRecovery),
+                                );
+                            });
+                            ui.end_row();
+
+                            ui.label("Use Below MP:");
+                            let mut mp_percent = auto_potion_settings.mp_threshold * 100.0;
+                            if ui
+                                .add_enabled(
+                                    enabled,
+                                    egui::Slider::new(&mut mp_percent, 5.0..=90.0).suffix("%").show_value(true),
+                                )
+                                .changed()
+                            {
+                                auto_potion_settings.mp_threshold = mp_percent / 100.0;
+                            }
+                            ui.end_row();
+                        });
+                    ui.colored_label(
+                        egui::Color32::YELLOW,
+                        "Some servers treat using items automatically as botting, check the server rules before enabling this.",
+                    );
+
+                    ui.separator();
+                    ui.label("Quick Use");
+                    egui::Grid::new("quick_use_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            for (index, key) in quick_use_settings.keys.iter_mut().enumerate() {
+                                ui.label(format!("Slot {} Key:", index + 1));
+                                egui::ComboBox::from_id_salt(("quick_use_key", index))
+                                    .selected_text(key.display_name())
+                                    .show_ui(ui, |ui| {
+                                        for option in QuickUseKey::ALL {
+                                            ui.selectable_value(key, option, option.display_name());
+                                        }
+                                    });
+                                ui.end_row();
+                            }
+                        });
+                    ui.label("Drag a consumable from the inventory onto a quick use slot, right click a slot to clear it.");
+
+                    ui.separator();
+                    ui.label("Hit Feedback");
+                    egui::Grid::new("hit_feedback_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("Hit Feedback:");
+                            ui.checkbox(&mut hit_feedback_settings.enabled, "Enabled");
+                            ui.end_row();
+
+                            ui.label("Impact Effects:");
+                            ui.add_enabled(
+                                hit_feedback_settings.enabled,
+                                egui::Checkbox::new(&mut hit_feedback_settings.impact_effects, "Enabled"),
+                            )
+                            .on_hover_text("Show hit effects where the blow lands, larger for heavy hits and crits");
+                            ui.end_row();
+
+                            ui.label("Hit Flash:");
+                            ui.add_enabled(
+                                hit_feedback_settings.enabled,
+                                egui::Checkbox::new(&mut hit_feedback_settings.hit_flash, "Enabled"),
+                            )
+                            .on_hover_text("Briefly flash and freeze the target when it is hit");
+                            ui.end_row();
+
+                            ui.label("Camera Shake:");
+                            ui.add_enabled(
+                                hit_feedback_settings.enabled && !accessibility_settings.disable_camera_shake,
+                                egui::Checkbox::new(&mut hit_feedback_settings.camera_shake, "Enabled"),
+                            )
+                            .on_hover_text("Shake the camera when you take damage, scaled by the damage");
+                            ui.end_row();
+
+                            ui.label("Shake Intensity:");
+                            ui.add_enabled(
+                                hit_feedback_settings.enabled
+                                    && hit_feedback_settings.camera_shake
+                                    && !accessibility_settings.disable_camera_shake,
+                                egui::Slider::new(&mut hit_feedback_settings.shake_intensity, 0.0..=2.0)
+                                    .show_value(true),
+                            );
+                            ui.end_row();
+                        });
+
+                    ui.separator();
+                    ui.label("Health Feedback");
+                    let health_vignette_enabled =
+                        health_feedback_settings.enabled && !accessibility_settings.disable_health_vignette;
+                    egui::Grid::new("health_feedback_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("Low Health Vignette:");
+                            ui.add_enabled(
+                                !accessibility_settings.disable_health_vignette,
+                                egui::Checkbox::new(&mut health_feedback_settings.enabled, "Enabled"),
+                            )
+                            .on_hover_text("Tint the screen edges red as your HP runs low");
+                            ui.end_row();
+
+                            ui.label("Health Threshold:");
+                            let mut threshold_percent = health_feedback_settings.low_health_threshold * 100.0;
+                            if ui
+                                .add_enabled(
+                                    health_vignette_enabled,
+                                    egui::Slider::new(&mut threshold_percent, 5.0..=75.0)
+                                        .suffix("%")
+                                        .show_value(true),
+                                )
+                                .on_hover_text("The vignette shows below this much of your max HP")
+                                .changed()
+                            {
+                                health_feedback_settings.low_health_threshold = threshold_percent / 100.0;
+                            }
+                            ui.end_row();
+
+                            ui.label("Intensity:");
+                            ui.add_enabled(
+                                health_vignette_enabled,
+                                egui::Slider::new(&mut health_feedback_settings.intensity, 0.1..=1.0)
+                                    .show_value(true),
+                            );
+                            ui.end_row();
+
+                            ui.label("Damage Pulse:");
+                            ui.add_enabled(
+                                health_vignette_enabled,
+                                egui::Checkbox::new(&mut health_feedback_settings.damage_pulse, "Enabled"),
+                            )
+                            .on_hover_text("Briefly pulse the vignette when you take damage");
+                            ui.end_row();
+                        });
+
+                    ui.separator();
+                    ui.label("Camera Motion");
+                    egui::Grid::new("camera_motion_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            let allowed = !accessibility_settings.disable_camera_motion;
+
+                            ui.label("Camera Motion:");
+                            ui.add_enabled(
+                                allowed,
+                                egui::Checkbox::new(&mut camera_motion_settings.enabled, "Enabled"),
+                            );
+                            ui.end_row();
+
+                            ui.label("Running Bob:");
+                            ui.add_enabled(
+                                allowed && camera_motion_settings.enabled,
+                                egui::Slider::new(&mut camera_motion_settings.bob_intensity, 0.0..=1.0)
+                                    .show_value(true),
+                            )
+                            .on_hover_text("Bob the camera with your steps, stronger the faster you move");
+                            ui.end_row();
+
+                            ui.label("Flight Banking:");
+                            ui.add_enabled(
+                                allowed && camera_motion_settings.enabled,
+                                egui::Slider::new(&mut camera_motion_settings.bank_intensity, 0.0..=1.0)
+                                    .show_value(true),
+                            )
+                            .on_hover_text("Roll the camera into turns while flying");
+                            ui.end_row();
+                        });
+
+                    ui.separator();
+                    ui.label("Character Idle");
+                    egui::Grid::new("character_idle_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("Blinking:");
+                            ui.checkbox(&mut character_idle_settings.blink_enabled, "Enabled");
+                            ui.end_row();
+
+                            ui.label("Blink interval:");
+                            ui.add_enabled_ui(character_idle_settings.blink_enabled, |ui| {
+                                ui.horizontal(|ui| {
+                                    let max = character_idle_settings.blink_interval_max;
+                                    ui.add(
+                                        egui::DragValue::new(&mut character_idle_settings.blink_interval_min)
+                                            .range(0.0..=max)
+                                            .speed(0.05)
+                                            .suffix("s"),
+                                    );
+                                    ui.label("to");
+                                    let min = character_idle_settings.blink_interval_min;
+                                    ui.add(
+                                        egui::DragValue::new(&mut character_idle_settings.blink_interval_max)
+                                            .range(min..=10.0)
+                                            .speed(0.05)
+                                            .suffix("s"),
+                                    );
+                                });
+                            });
+                            ui.end_row();
+
+                            ui.label("Blink duration:");
+                            ui.add_enabled_ui(character_idle_settings.blink_enabled, |ui| {
+                                ui.horizontal(|ui| {
+                                    let max = character_idle_settings.blink_duration_max;
+                                    ui.add(
+                                        egui::DragValue::new(&mut character_idle_settings.blink_duration_min)
+                                            .range(0.0..=max)
+                                            .speed(0.005)
+                                            .suffix("s"),
+                                    );
+                                    ui.label("to");
+                                    let min = character_idle_settings.blink_duration_min;
+                                    ui.add(
+                                        egui::DragValue::new(&mut character_idle_settings.blink_duration_max)
+                                            .range(min..=0.5)
+                                            .speed(0.005)
+                                            .suffix("s"),
+                                    );
+                                });
+                            });
+                            ui.end_row();
+
+                            ui.label("Breathing:");
+                            ui.checkbox(&mut character_idle_settings.breathing_enabled, "Enabled")
+                                .on_hover_text("Gentle rise and fall of characters standing idle");
+                            ui.end_row();
+
+                            ui.label("Breathing amplitude:");
+                            ui.add_enabled(
+                                character_idle_settings.breathing_enabled,
+                                egui::Slider::new(&mut character_idle_settings.breathing_amplitude, 0.0..=0.03)
+                                    .suffix("m")
+                                    .show_value(true),
+                            );
+                            ui.end_row();
+
+                            ui.label("Max distance:");
+                            ui.add(
+                                egui::Slider::new(&mut character_idle_settings.max_distance, 10.0..=200.0)
+                                    .suffix("m")
+                                    .show_value(true),
+                            )
+                            .on_hover_text("Characters further from the camera do not blink or breathe");
+                            ui.end_row();
+                        });
+
+                    ui.separator();
+                    ui.label("Layouts");
+                    ui.horizontal(|ui| {
+                        ui.label("Name:");
+                        ui.text_edit_singleline(&mut ui_state_settings.layout_name);
+
+                        let name = ui_state_settings.layout_name.trim();
+                        if ui
+                            .add_enabled(!name.is_empty(), egui::Button::new("Save"))
+                            .clicked()
+                        {
+                            ui_layout_events.write(UiLayoutEvent::Save(name.to_string()));
+                        }
+                    });
+
+                    ui.separator();
+
+                    let ui_layout_settings = &mut *ui_layout_settings;
+                    egui::Grid::new("ui_layout_slots")
+                        .num_columns(4)
+                        .show(ui, |ui| {
+                            for name in ui_layout_settings.slots.keys() {
+                                ui.label(name);
+                                ui.radio_value(
+                                    &mut ui_layout_settings.startup_slot,
+                                    name.clone(),
+                                    "Startup",
+                                );
+                                if ui.button("Load").clicked() {
+                                    ui_layout_events.write(UiLayoutEvent::Load(name.clone()));
+                                }
+                                if ui.button("Delete").clicked() {
+                                    ui_layout_events.write(UiLayoutEvent::Delete(name.clone()));
+                                }
+                                ui.end_row();
+                            }
+                        });
+
+                    if ui_layout_settings.slots.is_empty() {
+                        ui.label("No saved layouts.");
+                    }
+
+                    ui.separator();
+                    if ui.button("Reset Layout").clicked() {
+                        ui_layout_events.write(UiLayoutEvent::Reset);
+                    }
+                    ui.label("Windows outside the screen are moved back into view on load.");
+                }
                 SettingsPage::Terrain => {
                     egui::Grid::new("terrain_settings")
                         .num_columns(2)
@@ -10,7 +10,7 @@ use crate::{
     bundles::ability_values_get_value,
     components::{Cooldowns, PlayerCharacter},
     events::PlayerCommandEvent,
-    resources::{GameData, UiResources},
+    resources::{AccessibilitySettings, GameData, UiResources},
     ui::{
         tooltips::{PlayerTooltipQuery, SkillTooltipType},
         ui_add_skill_tooltip,
@@ -126,6 +126,7 @@ pub fn ui_skill_list_system(
     game_data: Res<GameData>,
     ui_resources: Res<UiResources>,
     dialog_assets: Res<Assets<Dialog>>,
+    accessibility: Res<AccessibilitySettings>,
 ) {
     let ui_state_skill_list = &mut *ui_state_skill_list;
     let dialog = if let Some(dialog) = dialog_assets.get(&ui_resources.dialog_skill_list) {
@@ -278,7 +279,7 @@ pub fn ui_skill_list_system(
                                             for &(ability_type, mut value) in
                                                 skill_data.use_ability.iter()
                                             {
-                                                let mut color = egui::Color32::RED;
+                                                let mut color = accessibility.negative_color();
 
                                                 if let Some(player_tooltip_data) =
                                                      player_tooltip_data_ref.as_ref()
@@ -314,7 +315,7 @@ pub fn ui_skill_list_system(
                                                          )
                                                         {
                                                             if current_value >= value {
-                                                                color = egui::Color32::GREEN;
+                                                                color = accessibility.positive_color();
                                                             }
                                                         }
                                                    }
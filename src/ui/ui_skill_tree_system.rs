@@ -1,13 +1,18 @@
 use bevy::ecs::query::QueryData;
-use bevy::prelude::{Assets, Entity, MessageWriter, Local, Query, Res, ResMut, With};
+use bevy::prelude::{Assets, Entity, MessageWriter, Local, Messages, Query, Res, ResMut, With, World};
 use bevy_egui::{egui, EguiContexts};
 
 use rose_data::SkillId;
-use rose_game_common::components::{CharacterInfo, SkillList, SkillPoints};
+use rose_game_common::components::{
+    AbilityValues, BasicStats, CharacterInfo, Equipment, Level, SkillList, SkillPoints, SkillSlot,
+    StatusEffects,
+};
 
 use crate::{
+    bundles::ability_values_get_value,
     components::PlayerCharacter,
-    resources::{GameData, UiResources, UiSpriteSheetType},
+    events::{MessageBoxEvent, PlayerCommandEvent},
+    resources::{current_accessibility, GameData, UiResources, UiSpriteSheetType},
     ui::{
         tooltips::{PlayerTooltipQuery, SkillTooltipType},
         ui_add_skill_tooltip,
@@ -24,11 +29,167 @@ const IID_TEXT_MUSE: i32 = 22;
 const IID_TEXT_HOWKER: i32 = 23;
 const IID_TEXT_DEALER: i32 = 24;
 
+/// Tentative skill level ups, nothing is sent to the server until the plan is applied
+#[derive(Default)]
+pub struct SkillPlan {
+    /// Planned level ups in the order they were planned as (skill slot, base skill, new level)
+    steps: Vec<(SkillSlot, SkillId, u32)>,
+}
+
+impl SkillPlan {
+    fn planned_level(&self, base_skill_id: SkillId) -> Option<u32> {
+        self.steps
+            .iter()
+            .filter(|(_, step_skill_id, _)| *step_skill_id == base_skill_id)
+            .map(|(_, _, level)| *level)
+            .max()
+    }
+
+    /// Skill points used by the plan
+    fn points(&self, game_data: &GameData) -> u32 {
+        self.steps
+            .iter()
+            .filter_map(|(_, base_skill_id, level)| {
+                game_data
+                    .skills
+                    .get_skill(SkillId::new(base_skill_id.get() + *level as u16 - 1)?)
+            })
+            .map(|skill_data| skill_data.learn_point_cost)
+            .sum()
+    }
+
+    /// Skill list with the plan applied, used to preview ability values
+    fn apply_to(&self, skill_list: &SkillList) -> SkillList {
+        let mut skill_list = skill_list.clone();
+        for &(skill_slot, base_skill_id, level) in self.steps.iter() {
+            if let Some(slot) = skill_list.get_slot_mut(skill_slot) {
+                *slot = SkillId::new(base_skill_id.get() + level as u16 - 1);
+            }
+        }
+        skill_list
+    }
+
+    /// Drop the steps the player has reached, e.g. after the plan was applied
+    fn prune(&mut self, skill_list: &SkillList, game_data: &GameData) {
+        self.steps.retain(|&(_, base_skill_id, level)| {
+            skill_list
+                .find_skill_level(&game_data.skills, base_skill_id)
+                .map_or(true, |(_, _, learned_level)| learned_level < level)
+        });
+    }
+}
+
+enum SkillPlanAction {
+    Add { base_skill_id: SkillId, limit_level: u32 },
+    Remove { base_skill_id: SkillId },
+}
+
 #[derive(Default)]
 pub struct UiStateSkillTree {
     skill_tree: Option<(u16, Dialog)>, // (job id, dialog)
+    planning: bool,
+    plan: SkillPlan,
+}
+
+/// The next level of the skill which can be added to the plan, or why it can not be
+fn next_planned_level(
+    base_skill_id: SkillId,
+    limit_level: u32,
+    plan: &SkillPlan,
+    skill_list: &SkillList,
+    skill_points: &SkillPoints,
+    player_tooltip_data: Option<&<PlayerTooltipQuery as QueryData>::Item<'_, '_>>,
+    game_data: &GameData,
+) -> Result<(SkillSlot, u32), String> {
+    let Some((skill_slot, _, learned_level)) =
+        skill_list.find_skill_level(&game_data.skills, base_skill_id)
+    else {
+        return Err("Learn this skill before planning it".to_string());
+    };
+
+    let next_level = plan.planned_level(base_skill_id).unwrap_or(learned_level) + 1;
+    let next_skill_data = SkillId::new(base_skill_id.get() + next_level as u16 - 1)
+        .and_then(|skill_id| game_data.skills.get_skill(skill_id))
+        .filter(|skill_data| skill_data.level == next_level);
+    let Some(next_skill_data) = next_skill_data.filter(|_| limit_level == 0 || next_level <= limit_level)
+    else {
+        return Err("Maximum level reached".to_string());
+    };
+
+    let remaining_points = skill_points.points.saturating_sub(plan.points(game_data));
+    if remaining_points < next_skill_data.learn_point_cost {
+        return Err(format!(
+            "Needs {} skill points, {} left",
+            next_skill_data.learn_point_cost, remaining_points
+        ));
+    }
+
+    for &(required_skill_id, required_level) in next_skill_data.required_skills.iter() {
+        let required_base_skill_id = SkillId::new(required_skill_id.get() + required_level.max(1) as u16 - 1)
+            .and_then(|skill_id| game_data.skills.get_skill(skill_id))
+            .and_then(|skill_data| skill_data.base_skill_id)
+            .unwrap_or(required_skill_id);
+        let level = plan.planned_level(required_base_skill_id).or_else(|| {
+            skill_list
+                .find_skill_level(&game_data.skills, required_base_skill_id)
+                .map(|(_, _, level)| level)
+        });
+
+        if level.unwrap_or(0) < required_level as u32 {
+            let name = game_data
+                .skills
+                .get_skill(required_base_skill_id)
+                .map_or("?", |skill_data| skill_data.name.as_str());
+            return Err(format!("Requires {} level {}", name, required_level));
+        }
+    }
+
+    if let Some(player) = player_tooltip_data {
+        for &(ability_type, value) in next_skill_data.required_ability.iter() {
+            let current_value = ability_values_get_value(
+                ability_type,
+                player.ability_values,
+                Some(player.character_info),
+                Some(player.experience_points),
+                Some(player.guild_membership),
+                Some(player.health_points),
+                Some(player.inventory),
+                Some(player.level),
+                Some(player.mana_points),
+                Some(player.move_speed),
+                Some(player.skill_points),
+                Some(player.stamina),
+                Some(player.stat_points),
+                Some(player.team),
+                Some(player.union_membership),
+            );
+
+            if current_value.map_or(false, |current_value| current_value < value) {
+                return Err(format!(
+                    "Requires {} {}",
+                    game_data.string_database.get_ability_type(ability_type),
+                    value
+                ));
+            }
+        }
+    }
+
+    Ok((skill_slot, next_level))
 }
 
+/// Ability values shown in the plan preview
+const PLAN_PREVIEW_VALUES: [(&str, fn(&AbilityValues) -> i32); 9] = [
+    ("Attack", |x| x.get_attack_power()),
+    ("Defence", |x| x.get_defence()),
+    ("Magic Resistance", |x| x.get_resistance()),
+    ("Accuracy", |x| x.get_hit()),
+    ("Critical", |x| x.get_critical()),
+    ("Dodge", |x| x.get_avoid()),
+    ("Attack Speed", |x| x.get_attack_speed()),
+    ("Max HP", |x| x.get_max_health()),
+    ("Max MP", |x| x.get_max_mana()),
+];
+
 fn ui_add_skill_tree_slot(
     ui: &mut egui::Ui,
     pos: egui::Pos2,
@@ -37,6 +198,8 @@ fn ui_add_skill_tree_slot(
     player_tooltip_data: Option<&<PlayerTooltipQuery as QueryData>::Item<'_, '_>>,
     game_data: &GameData,
     ui_resources: &UiResources,
+    plan: Option<&SkillPlan>,
+    plan_actions: &mut Vec<SkillPlanAction>,
 ) -> egui::Response {
     let base_skill_id = if let Some(base_skill_id) = SkillId::new(skill.id as u16) {
         base_skill_id
@@ -52,10 +215,15 @@ fn ui_add_skill_tree_slot(
             .response;
     };
 
-    let learned_level = if let Some((_, _, level)) = player
+    let current_level = player
         .1
         .find_skill_level(&game_data.skills, base_skill_id)
-    {
+        .map(|(_, _, level)| level);
+    let planned_level = plan
+        .and_then(|plan| plan.planned_level(base_skill_id))
+        .filter(|planned_level| Some(*planned_level) > current_level);
+
+    let learned_level = if let Some(level) = planned_level.or(current_level) {
         if level < skill.level {
             None
         } else if skill.limit_level > 0 {
@@ -107,6 +275,36 @@ fn ui_add_skill_tree_slot(
         // player_command_events.send(PlayerCommandEvent::UseSkill(skill_slot));
     }
 
+    if let Some(plan) = plan {
+        // Planned levels are outlined so they can not be mistaken for learned ones
+        if let Some(planned_level) = planned_level.filter(|level| *level >= skill.level) {
+            let planned_color = egui::Color32::from_rgb(90, 200, 255);
+            ui.painter().rect_stroke(
+                response.rect.shrink(1.0),
+                2.0,
+                egui::Stroke::new(2.0, planned_color),
+                egui::StrokeKind::Inside,
+            );
+            ui.painter().text(
+                response.rect.left_bottom() + egui::vec2(3.0, -2.0),
+                egui::Align2::LEFT_BOTTOM,
+                format!("+{}", planned_level - current_level.unwrap_or(0)),
+                egui::FontId::proportional(12.0),
+                planned_color,
+            );
+        }
+
+        if response.clicked() {
+            plan_actions.push(SkillPlanAction::Add {
+                base_skill_id,
+                limit_level: skill.limit_level,
+            });
+        }
+        if response.secondary_clicked() && plan.planned_level(base_skill_id).is_some() {
+            plan_actions.push(SkillPlanAction::Remove { base_skill_id });
+        }
+    }
+
     if let Some(skill_data) = skill_data {
         response.on_hover_ui(|ui| {
             ui_add_skill_tooltip(
@@ -116,6 +314,35 @@ fn ui_add_skill_tree_slot(
                 player_tooltip_data,
                 skill_data.id,
             );
+
+            if let Some(plan) = plan {
+                ui.separator();
+                if let Some(planned_level) = planned_level {
+                    ui.colored_label(
+                        egui::Color32::from_rgb(90, 200, 255),
+                        format!("Planned level: {}", planned_level),
+                    );
+                }
+                match next_planned_level(
+                    base_skill_id,
+                    skill.limit_level,
+                    plan,
+                    player.1,
+                    player.2,
+                    player_tooltip_data,
+                    game_data,
+                ) {
+                    Ok((_, next_level)) => {
+                        ui.label(format!("Click to plan level {}", next_level));
+                    }
+                    Err(reason) => {
+                        ui.colored_label(current_accessibility().negative_color(), reason);
+                    }
+                }
+                if planned_level.is_some() {
+                    ui.label("Right click to remove a planned level");
+                }
+            }
         })
     } else {
         response
@@ -128,6 +355,8 @@ fn draw_skill_slots(
     player_tooltip_data: Option<&<PlayerTooltipQuery as QueryData>::Item<'_, '_>>,
     game_data: &GameData,
     ui_resources: &UiResources,
+    plan: Option<&SkillPlan>,
+    plan_actions: &mut Vec<SkillPlanAction>,
     widgets: &[Widget],
 ) {
     for skill in widgets.iter().filter_map(|x| match x {
@@ -142,6 +371,8 @@ fn draw_skill_slots(
             player_tooltip_data,
             game_data,
             ui_resources,
+            plan,
+            plan_actions,
         );
 
         draw_skill_slots(
@@ -150,6 +381,8 @@ fn draw_skill_slots(
             player_tooltip_data,
             game_data,
             ui_resources,
+            plan,
+            plan_actions,
             &skill.widgets,
         );
     }
@@ -162,9 +395,11 @@ pub fn ui_skill_tree_system(
     mut ui_sound_events: MessageWriter<UiSoundEvent>,
     mut query_player: Query<(Entity, &CharacterInfo, &SkillList, &SkillPoints), With<PlayerCharacter>>,
     query_player_tooltip: Query<PlayerTooltipQuery, With<PlayerCharacter>>,
+    query_player_stats: Query<(&Level, &Equipment, &BasicStats, &StatusEffects, &AbilityValues), With<PlayerCharacter>>,
     game_data: Res<GameData>,
     ui_resources: Res<UiResources>,
     dialog_assets: Res<Assets<Dialog>>,
+    mut message_box_events: MessageWriter<MessageBoxEvent>,
 ) {
     let ui_state = &mut *ui_state;
     let dialog = if let Some(dialog) = dialog_assets.get(&ui_resources.dialog_skill_tree) {
@@ -201,6 +436,8 @@ pub fn ui_skill_tree_system(
         };
         ui_state.skill_tree = Some((character_info.job, skill_tree.clone()));
     }
+    ui_state.plan.prune(skill_list, &game_data);
+    let plan = ui_state.planning.then_some(&ui_state.plan);
     let skill_tree = if let Some((_, skill_tree)) = ui_state.skill_tree.as_mut() {
         skill_tree
     } else {
@@ -209,8 +446,9 @@ pub fn ui_skill_tree_system(
 
     let mut response_close_button = None;
     let mut select_base_skill_index = None;
+    let mut plan_actions = Vec::new();
 
-    egui::Window::new("Skill Tree")
+    let window_response = egui::Window::new("Skill Tree")
         .frame(egui::Frame::none())
         .open(&mut ui_state_windows.skill_tree_open)
         .title_bar(false)
@@ -242,6 +480,9 @@ pub fn ui_skill_tree_system(
                                 player_tooltip_data.as_ref(),
                                 &game_data,
                                 &ui_resources,
+                                // Other base skills are selected by clicking them, not planned
+                                plan.filter(|_| index == 0),
+                                &mut plan_actions,
                             )
                             .clicked()
                                 && index != 0
@@ -260,6 +501,8 @@ pub fn ui_skill_tree_system(
                             player_tooltip_data.as_ref(),
                             &game_data,
                             &ui_resources,
+                            plan,
+                            &mut plan_actions,
                             &base_skill.widgets,
                         );
                     }
@@ -287,6 +530,139 @@ pub fn ui_skill_tree_system(
         }
     }
 
+    for action in plan_actions {
+        match action {
+            SkillPlanAction::Add {
+                base_skill_id,
+                limit_level,
+            } => {
+                if let Ok((skill_slot, level)) = next_planned_level(
+                    base_skill_id,
+                    limit_level,
+                    &ui_state.plan,
+                    skill_list,
+                    skill_points,
+                    player_tooltip_data.as_ref(),
+                    &game_data,
+                ) {
+                    ui_state.plan.steps.push((skill_slot, base_skill_id, level));
+                }
+            }
+            SkillPlanAction::Remove { base_skill_id } => {
+                if let Some(index) = ui_state
+                    .plan
+                    .steps
+                    .iter()
+                    .rposition(|(_, step_skill_id, _)| *step_skill_id == base_skill_id)
+                {
+                    ui_state.plan.steps.remove(index);
+                }
+            }
+        }
+    }
+
+    // Planning controls and the preview of the planned build below the skill tree
+    let mut apply_plan = false;
+    if let Some(window_response) = window_response.filter(|_| ui_state_windows.skill_tree_open) {
+        let planned_points = ui_state.plan.points(&game_data);
+
+        egui::Area::new(egui::Id::new("skill_tree_planner"))
+            .order(egui::Order::Foreground)
+            .fixed_pos(window_response.response.rect.left_bottom())
+            .show(egui_context.ctx_mut().unwrap(), |ui| {
+                egui::Frame::window(ui.style()).show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.checkbox(&mut ui_state.planning, "Plan")
+                            .on_hover_text("Click skills to plan level ups without spending skill points");
+                        if ui_state.planning {
+                            ui.label(format!(
+                                "Skill points: {} ({} planned)",
+                                skill_points.points.saturating_sub(planned_points),
+                                planned_points
+                            ));
+                        } else {
+                            ui.label(format!("Skill points: {}", skill_points.points));
+                        }
+                    });
+
+                    if !ui_state.planning || ui_state.plan.steps.is_empty() {
+                        return;
+                    }
+
+                    if let Ok((level, equipment, basic_stats, status_effects, ability_values)) =
+                        query_player_stats.single()
+                    {
+                        let accessibility = current_accessibility();
+                        let planned_ability_values = game_data.ability_value_calculator.calculate(
+                            character_info,
+                            level,
+                            equipment,
+                            basic_stats,
+                            &ui_state.plan.apply_to(skill_list),
+                            status_effects,
+                        );
+
+                        egui::Grid::new("skill_plan_preview")
+                            .num_columns(2)
+                            .show(ui, |ui| {
+                                for (name, get_value) in PLAN_PREVIEW_VALUES {
+                                    let current = get_value(ability_values);
+                                    let planned = get_value(&planned_ability_values);
+                                    if current == planned {
+                                        continue;
+                                    }
+
+                                    ui.label(name);
+                                    ui.colored_label(
+                                        if planned > current {
+                                            accessibility.positive_color()
+                                        } else {
+                                            accessibility.negative_color()
+                                        },
+                                        format!("{} -> {}", current, planned),
+                                    );
+                                    ui.end_row();
+                                }
+                            });
+                    }
+
+                    ui.horizontal(|ui| {
+                        if ui.button("Reset Plan").clicked() {
+                            ui_state.plan.steps.clear();
+                        }
+                        apply_plan = ui.button("Apply").clicked();
+                    });
+                });
+            });
+    }
+
+    if apply_plan {
+        let skill_slots: Vec<SkillSlot> = ui_state
+            .plan
+            .steps
+            .iter()
+            .map(|(skill_slot, _, _)| *skill_slot)
+            .collect();
+
+        message_box_events.write(MessageBoxEvent::Show {
+            message: format!(
+                "Spend {} skill points on {} skill levels? Skill points can not be refunded.",
+                ui_state.plan.points(&game_data),
+                skill_slots.len()
+            ),
+            modal: true,
+            ok: Some(Box::new(move |commands| {
+                commands.queue(move |world: &mut World| {
+                    let mut player_command_events = world.resource_mut::<Messages<PlayerCommandEvent>>();
+                    for skill_slot in skill_slots {
+                        player_command_events.write(PlayerCommandEvent::LevelUpSkill(skill_slot));
+                    }
+                });
+            })),
+            cancel: Some(Box::new(|_| {})),
+        });
+    }
+
     if response_close_button.map_or(false, |r| r.clicked()) {
         ui_state_windows.skill_tree_open = false;
     }
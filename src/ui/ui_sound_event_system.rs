@@ -5,17 +5,41 @@ use rose_data::SoundId;
 use crate::{
     audio::GlobalSound,
     components::SoundCategory,
-    resources::{GameData, SoundCache, SoundSettings},
+    resources::{GameData, SoundCache, SoundSettings, UiSoundKind, UiSoundSettings},
 };
 
 #[derive(Message)]
 pub struct UiSoundEvent {
     sound_id: SoundId,
+    category: SoundCategory,
+    kind: UiSoundKind,
 }
 
 impl UiSoundEvent {
     pub fn new(sound_id: SoundId) -> Self {
-        Self { sound_id }
+        Self {
+            sound_id,
+            category: SoundCategory::Ui,
+            kind: UiSoundKind::Other,
+        }
+    }
+
+    /// Play a sound which can be muted or replaced by the UI sound settings
+    pub fn with_kind(sound_id: SoundId, kind: UiSoundKind) -> Self {
+        Self {
+            sound_id,
+            category: SoundCategory::Ui,
+            kind,
+        }
+    }
+
+    /// Play a sound at the volume of another category, used to preview volume changes
+    pub fn with_category(sound_id: SoundId, category: SoundCategory) -> Self {
+        Self {
+            sound_id,
+            category,
+            kind: UiSoundKind::Other,
+        }
     }
 }
 
@@ -24,14 +48,24 @@ pub fn ui_sound_event_system(
     asset_server: Res<AssetServer>,
     mut ui_sound_events: MessageReader<UiSoundEvent>,
     sound_settings: Res<SoundSettings>,
+    ui_sound_settings: Res<UiSoundSettings>,
     game_data: Res<GameData>,
     sound_cache: ResMut<SoundCache>,
 ) {
     for event in ui_sound_events.read() {
-        if let Some(sound_data) = game_data.sounds.get_sound(event.sound_id) {
+        let Some(sound_id) = ui_sound_settings.resolve(event.kind, event.sound_id) else {
+            continue;
+        };
+
+        // Fall back to the classic sound when a theme sound is missing from the database
+        if let Some(sound_data) = game_data
+            .sounds
+            .get_sound(sound_id)
+            .or_else(|| game_data.sounds.get_sound(event.sound_id))
+        {
             commands.spawn((
-                SoundCategory::Ui,
-                sound_settings.gain(SoundCategory::Ui),
+                event.category,
+                sound_settings.gain(event.category),
                 GlobalSound::new(sound_cache.load(sound_data, &asset_server)),
             ));
         }
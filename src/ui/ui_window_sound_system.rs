@@ -2,7 +2,7 @@ use bevy::asset::Asset;
 use bevy::prelude::{Assets, MessageWriter, Handle, Local, Res};
 
 use crate::{
-    resources::UiResources,
+    resources::{UiResources, UiSoundKind},
     ui::{widgets::Dialog, UiSoundEvent, UiStateWindows},
 };
 
@@ -17,14 +17,14 @@ pub fn ui_window_sound_system(
         |state: &mut bool, next_state: bool, dialog_asset: &Handle<Dialog>| {
             if *state != next_state {
                 if let Some(dialog) = dialog_assets.get(dialog_asset) {
-                    let sound_id = if next_state {
-                        dialog.show_sound_id
+                    let (sound_id, kind) = if next_state {
+                        (dialog.show_sound_id, UiSoundKind::WindowOpen)
                     } else {
-                        dialog.hide_sound_id
+                        (dialog.hide_sound_id, UiSoundKind::WindowClose)
                     };
 
                     if let Some(sound_id) = sound_id {
-                        ui_sound_events.write(UiSoundEvent::new(sound_id));
+                        ui_sound_events.write(UiSoundEvent::with_kind(sound_id, kind));
                     }
                 }
 
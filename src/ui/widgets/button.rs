@@ -3,7 +3,7 @@ use serde::Deserialize;
 
 use rose_data::SoundId;
 
-use crate::resources::{UiResources, UiSprite};
+use crate::resources::{UiResources, UiSoundKind, UiSprite};
 
 use super::{dialog::deserialize_sound_id, DataBindings, DrawWidget, LoadWidget};
 
@@ -139,7 +139,7 @@ impl DrawWidget for Button {
 
             if response.clicked() {
                 if let Some(click_sound_id) = self.click_sound_id {
-                    bindings.emit_sound(click_sound_id);
+                    bindings.emit_sound(click_sound_id, UiSoundKind::ButtonClick);
                 }
             }
 
@@ -5,7 +5,7 @@ use bevy_egui::egui;
 
 use rose_data::SoundId;
 
-use crate::ui::UiSoundEvent;
+use crate::{resources::UiSoundKind, ui::UiSoundEvent};
 
 #[derive(Default)]
 pub struct DataBindings<'a, 'w> {
@@ -43,9 +43,9 @@ pub struct DataBindings<'a, 'w> {
 }
 
 impl<'a, 'w> DataBindings<'a, 'w> {
-    pub fn emit_sound(&mut self, sound_id: SoundId) {
+    pub fn emit_sound(&mut self, sound_id: SoundId, kind: UiSoundKind) {
         if let Some(sound_events) = self.sound_events.as_mut() {
-            sound_events.write(UiSoundEvent::new(sound_id));
+            sound_events.write(UiSoundEvent::with_kind(sound_id, kind));
         }
     }
 
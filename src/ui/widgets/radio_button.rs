@@ -3,7 +3,7 @@ use serde::Deserialize;
 
 use rose_data::SoundId;
 
-use crate::resources::{UiResources, UiSprite};
+use crate::resources::{UiResources, UiSoundKind, UiSprite};
 
 use super::{dialog::deserialize_sound_id, DataBindings, DrawWidget, LoadWidget};
 
@@ -107,7 +107,7 @@ impl DrawWidget for RadioButton {
                     *selected = self.id;
                     response.mark_changed();
                 } else if let Some(disable_sound_id) = self.disable_sound_id {
-                    bindings.emit_sound(disable_sound_id);
+                    bindings.emit_sound(disable_sound_id, UiSoundKind::Error);
                 }
             }
 
@@ -3,7 +3,7 @@ use serde::Deserialize;
 
 use rose_data::SoundId;
 
-use crate::resources::{UiResources, UiSprite};
+use crate::resources::{UiResources, UiSoundKind, UiSprite};
 
 use super::{dialog::deserialize_sound_id, DataBindings, DrawWidget, LoadWidget};
 
@@ -98,7 +98,7 @@ impl DrawWidget for TabButton {
                     **current_tab = self.tab_id;
                 }
             } else if let Some(disable_sound_id) = self.disable_sound_id {
-                bindings.emit_sound(disable_sound_id);
+                bindings.emit_sound(disable_sound_id, UiSoundKind::Error);
             }
         }
 
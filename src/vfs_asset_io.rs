@@ -15,7 +15,10 @@ use std::{
 };
 use rose_file_readers::{VfsFile, VirtualFilesystem};
 
-use crate::resources::VfsResource;
+use crate::{
+    resources::VfsResource,
+    vfs_path_resolver::{normalize_vfs_path, CaseInsensitivePathResolver},
+};
 
 /// Formats bytes into human-readable string (e.g., "1.5 MB", "256 KB")
 pub fn format_bytes(bytes: usize) -> String {
@@ -196,6 +199,15 @@ pub fn clear_vfs_file_cache() {
     }
 }
 
+/// Remove a single file from the global VFS file cache, so the next read sees
+/// the current contents on disk. Matches regardless of case or path separator.
+pub fn remove_from_vfs_file_cache(path: &str) {
+    let key = normalize_vfs_path(path).to_uppercase();
+    if let Ok(mut cache) = get_file_cache().write() {
+        cache.retain(|cached_path, _| normalize_vfs_path(cached_path).to_uppercase() != key);
+    }
+}
+
 /// Get cache statistics
 pub fn get_vfs_cache_stats() -> (usize, usize) {
     if let Ok(cache) = get_file_cache().read() {
@@ -212,6 +224,8 @@ pub struct VfsAssetIo {
     vfs: Arc<VirtualFilesystem>,
     /// Base path for real filesystem fallback - files here take priority over VFS
     base_path: PathBuf,
+    /// Case insensitive lookups of files under `base_path`
+    base_path_resolver: CaseInsensitivePathResolver,
     read_stats: std::sync::Mutex<VfsReadStats>,
     /// Whether to use the global file cache (default: true)
     use_cache: bool,
@@ -222,6 +236,7 @@ impl VfsAssetIo {
         log::info!("[VFS ASSET IO] Creating new VfsAssetIo instance with base_path: {:?}", base_path);
         Self {
             vfs,
+            base_path_resolver: CaseInsensitivePathResolver::new(base_path.clone()),
             base_path,
             read_stats: std::sync::Mutex::new(VfsReadStats::default()),
             use_cache: true,
@@ -234,6 +249,7 @@ impl VfsAssetIo {
         log::info!("[VFS ASSET IO] Creating new VfsAssetIo instance (no cache) with base_path: {:?}", base_path);
         Self {
             vfs,
+            base_path_resolver: CaseInsensitivePathResolver::new(base_path.clone()),
             base_path,
             read_stats: std::sync::Mutex::new(VfsReadStats::default()),
             use_cache: false,
@@ -352,8 +368,7 @@ impl AssetReader for VfsAssetIo {
 
             // PRIORITY: Real filesystem takes priority over VFS
             // This allows saved map editor modifications to be loaded instead of original VFS files
-            let real_filesystem_path = self.base_path.join(path_str);
-            if real_filesystem_path.exists() {
+            if let Some(real_filesystem_path) = self.base_path_resolver.resolve(path_str) {
                 match std::fs::read(&real_filesystem_path) {
                     Ok(data) => {
                         log::info!("[VFS] Loaded from real filesystem: {} (size: {})", path_str, format_bytes(data.len()));
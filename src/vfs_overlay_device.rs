@@ -0,0 +1,41 @@
+use std::path::PathBuf;
+
+use rose_file_readers::{VfsFile, VfsPath, VirtualFilesystemDevice};
+
+use crate::vfs_path_resolver::CaseInsensitiveHostDevice;
+
+/// Mod directory which overrides files from the devices below it.
+///
+/// Files are only served if they exist in the overlay directory, anything else
+/// falls through to the next device in the chain. Overlays are always placed at
+/// the top of the device chain, in the order they are declared in the config.
+///
+/// Lookups are done by the wrapped `CaseInsensitiveHostDevice`, this only adds
+/// logging of which files the overlay overrides.
+pub struct OverlayFilesystemDevice {
+    layer: CaseInsensitiveHostDevice,
+}
+
+impl OverlayFilesystemDevice {
+    pub fn new(root_path: PathBuf) -> Self {
+        Self {
+            layer: CaseInsensitiveHostDevice::new(root_path),
+        }
+    }
+}
+
+impl VirtualFilesystemDevice for OverlayFilesystemDevice {
+    fn open_file(&self, vfs_path: &VfsPath) -> Result<VfsFile, anyhow::Error> {
+        let file = self.layer.open_file(vfs_path)?;
+        log::debug!(
+            "[VFS OVERLAY] {} served from {}",
+            vfs_path.path().to_string_lossy(),
+            self.layer.root_path().to_string_lossy()
+        );
+        Ok(file)
+    }
+
+    fn exists(&self, vfs_path: &VfsPath) -> bool {
+        self.layer.exists(vfs_path)
+    }
+}
@@ -0,0 +1,229 @@
+use std::{
+    collections::HashMap,
+    ffi::OsString,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+};
+
+use rose_file_readers::{VfsFile, VfsPath, VirtualFilesystemDevice};
+
+/// Converts a game asset path to the canonical form used for lookups:
+/// forward slashes, no duplicate separators and no leading `./` or `/`.
+pub fn normalize_vfs_path(path: &str) -> String {
+    let mut normalized = String::with_capacity(path.len());
+
+    for component in path.split(['/', '\\']) {
+        if component.is_empty() || component == "." {
+            continue;
+        }
+
+        if !normalized.is_empty() {
+            normalized.push('/');
+        }
+        normalized.push_str(component);
+    }
+
+    normalized
+}
+
+/// Bumped by `clear_path_resolver_caches`, every resolver drops its cache the
+/// next time it sees a new value
+static CACHE_GENERATION: AtomicUsize = AtomicUsize::new(0);
+
+/// Forget the cached lookups of every resolver, for when files are added or
+/// renamed on disk. Safe to call from any thread, such as a file watcher.
+pub fn clear_path_resolver_caches() {
+    CACHE_GENERATION.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Resolves asset paths against a host directory ignoring case, so data
+/// extracted with mixed case file names still loads on case sensitive
+/// filesystems. Resolved paths, misses and directory listings are cached to
+/// avoid scanning the same directory repeatedly.
+///
+/// Overlay devices sit in front of the game data, so most of their lookups
+/// miss. Files written after startup, such as map editor saves, are only
+/// picked up after `clear_path_resolver_caches`.
+pub struct CaseInsensitivePathResolver {
+    root_path: PathBuf,
+    resolved: Mutex<HashMap<String, Option<PathBuf>>>,
+    directories: Mutex<HashMap<PathBuf, HashMap<String, OsString>>>,
+    cache_generation: AtomicUsize,
+}
+
+impl CaseInsensitivePathResolver {
+    pub fn new(root_path: PathBuf) -> Self {
+        Self {
+            root_path,
+            resolved: Mutex::new(HashMap::new()),
+            directories: Mutex::new(HashMap::new()),
+            cache_generation: AtomicUsize::new(CACHE_GENERATION.load(Ordering::Relaxed)),
+        }
+    }
+
+    pub fn root_path(&self) -> &Path {
+        &self.root_path
+    }
+
+    /// Returns the real path on disk for `path`, or None if it does not exist
+    pub fn resolve(&self, path: &str) -> Option<PathBuf> {
+        let normalized = normalize_vfs_path(path);
+        if normalized.is_empty() {
+            return None;
+        }
+
+        let generation = CACHE_GENERATION.load(Ordering::Relaxed);
+        if self.cache_generation.swap(generation, Ordering::Relaxed) != generation {
+            self.clear_cache();
+        }
+
+        let key = normalized.to_uppercase();
+        match self.resolved.lock().unwrap().get(&key) {
+            // Skip entries whose file has since been removed or renamed
+            Some(Some(resolved)) if resolved.is_file() => return Some(resolved.clone()),
+            Some(None) => return None,
+            _ => {}
+        }
+
+        // Most lookups already have the correct case
+        let direct_path = self.root_path.join(&normalized);
+        let resolved = if direct_path.is_file() {
+            Some(direct_path)
+        } else {
+            self.resolve_components(&normalized)
+        };
+
+        self.resolved.lock().unwrap().insert(key, resolved.clone());
+        resolved
+    }
+
+    fn resolve_components(&self, normalized: &str) -> Option<PathBuf> {
+        let mut current_path = self.root_path.clone();
+
+        for component in normalized.split('/') {
+            let entry_name = self.find_directory_entry(&current_path, component)?;
+            current_path.push(entry_name);
+        }
+
+        current_path.is_file().then_some(current_path)
+    }
+
+    fn find_directory_entry(&self, directory: &Path, name: &str) -> Option<OsString> {
+        let key = name.to_uppercase();
+        self.directories
+            .lock()
+            .unwrap()
+            .entry(directory.to_path_buf())
+            .or_insert_with(|| read_directory_entries(directory))
+            .get(&key)
+            .cloned()
+    }
+
+    /// Forget cached lookups, for when files are added or renamed on disk
+    pub fn clear_cache(&self) {
+        self.resolved.lock().unwrap().clear();
+        self.directories.lock().unwrap().clear();
+    }
+}
+
+fn read_directory_entries(directory: &Path) -> HashMap<String, OsString> {
+    std::fs::read_dir(directory)
+        .map(|read_dir| {
+            read_dir
+                .filter_map(|entry| entry.ok())
+                .map(|entry| {
+                    let file_name = entry.file_name();
+                    (file_name.to_string_lossy().to_uppercase(), file_name)
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Host directory device which resolves file names case insensitively
+pub struct CaseInsensitiveHostDevice {
+    resolver: CaseInsensitivePathResolver,
+}
+
+impl CaseInsensitiveHostDevice {
+    pub fn new(root_path: PathBuf) -> Self {
+        Self {
+            resolver: CaseInsensitivePathResolver::new(root_path),
+        }
+    }
+
+    pub fn root_path(&self) -> &Path {
+        self.resolver.root_path()
+    }
+}
+
+impl VirtualFilesystemDevice for CaseInsensitiveHostDevice {
+    fn open_file(&self, vfs_path: &VfsPath) -> Result<VfsFile, anyhow::Error> {
+        let path = vfs_path.path().to_string_lossy();
+        let resolved_path = self.resolver.resolve(&path).ok_or_else(|| {
+            anyhow::anyhow!(
+                "File {} not found in {}",
+                path,
+                self.resolver.root_path().to_string_lossy()
+            )
+        })?;
+
+        Ok(VfsFile::Buffer(std::fs::read(resolved_path)?))
+    }
+
+    fn exists(&self, vfs_path: &VfsPath) -> bool {
+        self.resolver
+            .resolve(&vfs_path.path().to_string_lossy())
+            .is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_vfs_path() {
+        assert_eq!(
+            normalize_vfs_path("3DDATA\\NPC\\LIST_NPC.CHR"),
+            "3DDATA/NPC/LIST_NPC.CHR"
+        );
+        assert_eq!(
+            normalize_vfs_path("./3DDATA//STB\\\\LIST_ZONE.STB"),
+            "3DDATA/STB/LIST_ZONE.STB"
+        );
+        assert_eq!(normalize_vfs_path("/3DDATA/"), "3DDATA");
+        assert_eq!(normalize_vfs_path(""), "");
+    }
+
+    #[test]
+    fn test_resolve_case_insensitive() {
+        let root_path = std::env::temp_dir().join(format!(
+            "rose_vfs_path_resolver_test_{}",
+            std::process::id()
+        ));
+        let directory = root_path.join("3ddata").join("Stb");
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(directory.join("List_Zone.stb"), b"test").unwrap();
+
+        let resolver = CaseInsensitivePathResolver::new(root_path.clone());
+        assert_eq!(
+            resolver.resolve("3DDATA\\STB\\LIST_ZONE.STB"),
+            Some(directory.join("List_Zone.stb"))
+        );
+        assert_eq!(resolver.resolve("3DDATA/STB/MISSING.STB"), None);
+
+        // Files added after the directory was listed are found once the caches are cleared
+        std::fs::write(directory.join("Missing.stb"), b"test").unwrap();
+        clear_path_resolver_caches();
+        assert_eq!(
+            resolver.resolve("3DDATA/STB/MISSING.STB"),
+            Some(directory.join("Missing.stb"))
+        );
+
+        std::fs::remove_dir_all(&root_path).ok();
+    }
+}
@@ -0,0 +1,113 @@
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{BufReader, Read},
+    path::Path,
+    sync::Mutex,
+};
+
+use anyhow::Context;
+use rose_file_readers::{VfsFile, VfsPath, VirtualFilesystemDevice};
+
+use crate::vfs_path_resolver::normalize_vfs_path;
+
+/// Serves game data out of a .zip archive.
+///
+/// Entry names are matched case insensitively and with either path separator,
+/// the same as the host directory device.
+pub struct ZipFilesystemDevice {
+    archive: Mutex<zip::ZipArchive<BufReader<File>>>,
+    /// Uppercase normalized entry name to index in the archive
+    entries: HashMap<String, usize>,
+}
+
+impl ZipFilesystemDevice {
+    pub fn load(path: &Path) -> Result<Self, anyhow::Error> {
+        let file = File::open(path)
+            .with_context(|| format!("Failed to open zip archive {}", path.to_string_lossy()))?;
+        let mut archive = zip::ZipArchive::new(BufReader::new(file))
+            .with_context(|| format!("Failed to read zip archive {}", path.to_string_lossy()))?;
+
+        let mut entries = HashMap::with_capacity(archive.len());
+        for index in 0..archive.len() {
+            let entry = archive.by_index_raw(index)?;
+            if entry.is_dir() {
+                continue;
+            }
+
+            entries.insert(normalize_vfs_path(entry.name()).to_uppercase(), index);
+        }
+
+        Ok(Self {
+            archive: Mutex::new(archive),
+            entries,
+        })
+    }
+
+    fn find_entry(&self, vfs_path: &VfsPath) -> Option<usize> {
+        self.entries
+            .get(&normalize_vfs_path(&vfs_path.path().to_string_lossy()).to_uppercase())
+            .copied()
+    }
+}
+
+impl VirtualFilesystemDevice for ZipFilesystemDevice {
+    fn open_file(&self, vfs_path: &VfsPath) -> Result<VfsFile, anyhow::Error> {
+        let index = self.find_entry(vfs_path).ok_or_else(|| {
+            anyhow::anyhow!(
+                "File {} not found in zip archive",
+                vfs_path.path().to_string_lossy()
+            )
+        })?;
+
+        let mut archive = self.archive.lock().unwrap();
+        let mut file = archive.by_index(index)?;
+        let mut buffer = Vec::with_capacity(file.size() as usize);
+        file.read_to_end(&mut buffer)?;
+        Ok(VfsFile::Buffer(buffer))
+    }
+
+    fn exists(&self, vfs_path: &VfsPath) -> bool {
+        self.find_entry(vfs_path).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    #[test]
+    fn test_zip_device_case_insensitive_lookup() {
+        let zip_path = std::env::temp_dir().join(format!(
+            "rose_vfs_zip_device_test_{}.zip",
+            std::process::id()
+        ));
+
+        {
+            let mut writer = zip::ZipWriter::new(File::create(&zip_path).unwrap());
+            writer
+                .start_file(
+                    "3ddata/Stb/List_Zone.stb",
+                    zip::write::SimpleFileOptions::default(),
+                )
+                .unwrap();
+            writer.write_all(b"test").unwrap();
+            writer.finish().unwrap();
+        }
+
+        let device = ZipFilesystemDevice::load(&zip_path).unwrap();
+        let vfs_path = VfsPath::from(Path::new("3DDATA\\STB\\LIST_ZONE.STB").to_path_buf());
+        assert!(device.exists(&vfs_path));
+        match device.open_file(&vfs_path).unwrap() {
+            VfsFile::Buffer(buffer) => assert_eq!(buffer, b"test"),
+            VfsFile::View(_) => panic!("Expected buffer"),
+        }
+        assert!(!device.exists(&VfsPath::from(
+            Path::new("3DDATA/STB/MISSING.STB").to_path_buf()
+        )));
+
+        std::fs::remove_file(&zip_path).ok();
+    }
+}
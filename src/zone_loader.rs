@@ -1,5 +1,5 @@
 use std::{
-    collections::HashSet,
+    collections::{HashMap, HashSet},
     future::Future,
     num::NonZeroUsize,
     path::{Path, PathBuf},
@@ -278,7 +278,7 @@ use uuid::Uuid;
 use anyhow::Result;
 use arrayvec::ArrayVec;
 use    bevy::{
-        asset::{Asset, AssetLoader, Assets, io::Reader, LoadContext, LoadState},
+        asset::{Asset, AssetId, AssetLoader, Assets, io::Reader, LoadContext, LoadState},
         ecs::system::SystemParam,
         math::{Quat, Vec2, Vec3},
         pbr::{ExtendedMaterial, StandardMaterial},
@@ -317,7 +317,7 @@ use crate::{
     animation::{MeshAnimation, TransformAnimation, ZmoTextureAssetLoader},
     audio::{SoundRadius, SpatialSound},
     components::{
-        ColliderParent, EventObject, NightTimeEffect, TerrainMeshForGrass, WarpObject, WindSway, Zone, ZoneObject,
+        ColliderParent, EventObject, NightTimeEffect, TerrainMeshForGrass, WarpDestination, WarpObject, WindSway, Zone, ZoneInstancingStats, ZoneObject,
         ZoneObjectAnimatedObject, ZoneObjectId, ZoneObjectPart, ZoneObjectTerrain,
         COLLISION_FILTER_CLICKABLE, COLLISION_FILTER_COLLIDABLE, COLLISION_FILTER_INSPECTABLE,
         COLLISION_FILTER_MOVEABLE, COLLISION_GROUP_PHYSICS_TOY, COLLISION_GROUP_ZONE_EVENT_OBJECT,
@@ -329,11 +329,12 @@ use crate::{
     events::{LoadZoneEvent, ZoneEvent, ZoneLoadedFromVfsEvent},
     map_editor::components::EditorSelectable,
     vfs_asset_io::clear_vfs_file_cache,
+    vfs_path_resolver::normalize_vfs_path,
     render::{
         MESH_ATTRIBUTE_UV_1, ParticleMaterial, RoseEffectExtension, RoseObjectExtension, TerrainMaterial,
         WaterMaterial,
     },
-    resources::{CurrentZone, DebugInspector, GameData, SpecularTexture},
+    resources::{current_anisotropy, CurrentZone, DebugInspector, GameData, SpecularTexture},
     VfsResource,
 };
 
@@ -744,14 +745,14 @@ async fn load_zone<'a, 'b>(
 /// Files are checked at base_path first, then VFS is used as fallback.
 /// Helper function to read raw bytes with real filesystem priority for use in load_zone_direct
 /// Returns the raw file data either from real filesystem or VFS
-fn read_bytes_with_priority_sync(
+pub(crate) fn read_bytes_with_priority_sync(
     vfs: &VirtualFilesystem,
     base_path: &Path,
     vfs_path: &VfsPath,
 ) -> Result<Vec<u8>, anyhow::Error> {
     use rose_file_readers::VfsFile;
     
-    let path_str = vfs_path.path().to_string_lossy().replace('\\', "/");
+    let path_str = normalize_vfs_path(&vfs_path.path().to_string_lossy());
     
     // PRIORITY: Check real filesystem first
     let real_filesystem_path = base_path.join(&path_str);
@@ -1034,7 +1035,7 @@ async fn load_block_files_direct(
     ) -> Result<Vec<u8>, anyhow::Error> {
         use rose_file_readers::VfsFile;
         
-        let path_str = vfs_path.path().to_string_lossy().replace('\\', "/");
+        let path_str = normalize_vfs_path(&vfs_path.path().to_string_lossy());
         
         // PRIORITY: Check real filesystem first
         let real_filesystem_path = base_path.join(&path_str);
@@ -1066,7 +1067,7 @@ async fn load_block_files_direct(
     }
     
     let him_path_buf = zone_path.join(format!("{}_{}.HIM", block_x, block_y));
-    let him_path_str = him_path_buf.to_string_lossy().replace('\\', "/");
+    let him_path_str = normalize_vfs_path(&him_path_buf.to_string_lossy());
     let him_path = VfsPath::from(PathBuf::from(&him_path_str));
 
     // Check if HIM file exists before attempting to load it
@@ -1090,7 +1091,7 @@ async fn load_block_files_direct(
     };
 
     // Load and parse TIL file (optional)
-    let til_path_str = zone_path.join(format!("{}_{}.TIL", block_x, block_y)).to_string_lossy().replace('\\', "/");
+    let til_path_str = normalize_vfs_path(&zone_path.join(format!("{}_{}.TIL", block_x, block_y)).to_string_lossy());
     let til_path = VfsPath::from(PathBuf::from(&til_path_str));
     let til: Option<TilFile> = match read_bytes_with_priority(vfs, base_path, &til_path) {
         Ok(data) => {
@@ -1140,10 +1141,10 @@ async fn load_block_files_direct(
     };
 
     // Load and parse LIT constant file (optional)
-    let lit_cnst_path_str = zone_path.join(format!(
+    let lit_cnst_path_str = normalize_vfs_path(&zone_path.join(format!(
         "{}_{}/LIGHTMAP/BUILDINGLIGHTMAPDATA.LIT",
         block_x, block_y
-    )).to_string_lossy().replace('\\', "/");
+    )).to_string_lossy());
     let lit_cnst_path = VfsPath::from(PathBuf::from(&lit_cnst_path_str));
     let lit_cnst: Option<LitFile> = match read_bytes_with_priority(vfs, base_path, &lit_cnst_path) {
         Ok(data) => {
@@ -1153,10 +1154,10 @@ async fn load_block_files_direct(
     };
 
     // Load and parse LIT deco file (optional)
-    let lit_deco_path_str = zone_path.join(format!(
+    let lit_deco_path_str = normalize_vfs_path(&zone_path.join(format!(
         "{}_{}/LIGHTMAP/OBJECTLIGHTMAPDATA.LIT",
         block_x, block_y
-    )).to_string_lossy().replace('\\', "/");
+    )).to_string_lossy());
     let lit_deco_path = VfsPath::from(PathBuf::from(&lit_deco_path_str));
     let lit_deco: Option<LitFile> = match read_bytes_with_priority(vfs, base_path, &lit_deco_path) {
         Ok(data) => {
@@ -2108,6 +2109,15 @@ pub fn spawn_zone(
     let mut effect_object_count = 0;
     let mut sound_object_count = 0;
 
+    // Parts of cnst and deco objects share materials when instancing is enabled,
+    // their ZSC files are separate so each needs its own cache
+    let mut cnst_material_cache = render_config
+        .instanced_zone_objects
+        .then(ObjectMaterialCache::default);
+    let mut deco_material_cache = render_config
+        .instanced_zone_objects
+        .then(ObjectMaterialCache::default);
+
     for block_y in 0..64 {
         for block_x in 0..64 {
             if let Some(block_data) = zone_data.blocks[block_x + block_y * 64].as_ref() {
@@ -2181,6 +2191,7 @@ pub fn spawn_zone(
                             ZoneObject::EventObject,
                             ZoneObject::EventObjectPart,
                             COLLISION_GROUP_ZONE_EVENT_OBJECT,
+                            None,
                         );
 
                         commands.entity(event_entity).insert(EventObject::new(
@@ -2208,11 +2219,15 @@ pub fn spawn_zone(
                             ZoneObject::WarpObject,
                             ZoneObject::WarpObjectPart,
                             COLLISION_GROUP_ZONE_WARP_OBJECT,
+                            None,
                         );
 
                         commands
                             .entity(warp_entity)
                             .insert(WarpObject::new(WarpGateId::new(warp_object.warp_id)));
+                        if let Some(destination) = WarpDestination::from_ifo_name(&warp_object.object_name) {
+                            commands.entity(warp_entity).insert(destination);
+                        }
                         commands.entity(zone_entity).add_child(warp_entity);
                         warp_object_count += 1;
                     }
@@ -2240,6 +2255,7 @@ pub fn spawn_zone(
                             ZoneObject::CnstObject,
                             ZoneObject::CnstObjectPart,
                             COLLISION_GROUP_ZONE_OBJECT,
+                            cnst_material_cache.as_mut(),
                         );
                         commands.entity(zone_entity).add_child(object_entity);
                         cnst_object_count += 1;
@@ -2268,6 +2284,7 @@ pub fn spawn_zone(
                             ZoneObject::DecoObject,
                             ZoneObject::DecoObjectPart,
                             COLLISION_GROUP_ZONE_OBJECT,
+                            deco_material_cache.as_mut(),
                         );
                         commands.entity(zone_entity).add_child(object_entity);
                         deco_object_count += 1;
@@ -2314,6 +2331,21 @@ pub fn spawn_zone(
         }
     }
 
+    if let (Some(cnst_material_cache), Some(deco_material_cache)) =
+        (cnst_material_cache, deco_material_cache)
+    {
+        let instancing_stats = ZoneInstancingStats {
+            parts: cnst_material_cache.parts + deco_material_cache.parts,
+            draw_batches: cnst_material_cache.batches.len() + deco_material_cache.batches.len(),
+        };
+        log::info!(
+            "[SPAWN ZONE] Instanced {} object parts into {} draw batches",
+            instancing_stats.parts,
+            instancing_stats.draw_batches
+        );
+        commands.entity(zone_entity).insert(instancing_stats);
+    }
+
     log::info!("[SPAWN ZONE] ===========================================");
     log::info!("[SPAWN ZONE] Zone spawning complete");
     log::info!("[SPAWN ZONE] Terrain entities: {}", terrain_count);
@@ -2399,6 +2431,55 @@ pub fn spawn_zone(
 //     (entity, Vec::new())
 // }
 
+/// Collider for a terrain block's heightmap, with the same noise as its mesh
+/// so physics matches what is rendered. `origin` is the block's translation.
+pub fn terrain_collider(
+    heightmap: &HimFile,
+    origin: Vec3,
+    terrain_noise: &crate::terrain::GlobalTerrainNoise,
+) -> Collider {
+    let mut collider_verts = Vec::new();
+    let mut collider_indices = Vec::new();
+
+    for y in 0..heightmap.height as i32 {
+        for x in 0..heightmap.width as i32 {
+            // Calculate world coordinates for noise sampling (same as mesh vertices)
+            let local_x = x as f32 * 2.5;
+            let local_z = y as f32 * 2.5;
+            let world_x = origin.x + local_x;
+            let world_z = origin.z + local_z;
+            
+            // Apply same noise to collider for physics consistency
+            let base_height = heightmap.get_clamped(x, y) / 100.0;
+            let noise_offset = terrain_noise.get_noise(world_x, world_z);
+            let height = base_height + noise_offset;
+            
+            collider_verts.push(
+                [
+                    local_x,
+                    height,
+                    local_z,
+                ]
+                .into(),
+            );
+        }
+    }
+
+    for y in 0..(heightmap.height - 1) {
+        for x in 0..(heightmap.width - 1) {
+            let start = y * heightmap.width + x;
+            collider_indices.push([start, start + heightmap.width, start + 1]);
+            collider_indices.push([
+                start + 1,
+                start + heightmap.width,
+                start + 1 + heightmap.width,
+            ]);
+        }
+    }
+
+    Collider::trimesh(collider_verts, collider_indices).expect("Failed to create terrain collider")
+}
+
 #[allow(clippy::too_many_arguments)]
 fn spawn_terrain(
     commands: &mut Commands,
@@ -2597,45 +2678,6 @@ fn spawn_terrain(
         block_data.block_x, block_data.block_y, vertex_count, triangle_count);
     log::info!("[MEMORY] Terrain mesh created for block {}_{}", block_data.block_x, block_data.block_y);
 
-    let mut collider_verts = Vec::new();
-    let mut collider_indices = Vec::new();
-
-    for y in 0..heightmap.height as i32 {
-        for x in 0..heightmap.width as i32 {
-            // Calculate world coordinates for noise sampling (same as mesh vertices)
-            let local_x = x as f32 * 2.5;
-            let local_z = y as f32 * 2.5;
-            let world_x = offset_x - 5200.0 + local_x;
-            let world_z = -offset_y + 5200.0 + local_z;
-            
-            // Apply same noise to collider for physics consistency
-            let base_height = heightmap.get_clamped(x, y) / 100.0;
-            let noise_offset = terrain_noise.get_noise(world_x, world_z);
-            let height = base_height + noise_offset;
-            
-            collider_verts.push(
-                [
-                    local_x,
-                    height,
-                    local_z,
-                ]
-                .into(),
-            );
-        }
-    }
-
-    for y in 0..(heightmap.height - 1) {
-        for x in 0..(heightmap.width - 1) {
-            let start = y * heightmap.width + x;
-            collider_indices.push([start, start + heightmap.width, start + 1]);
-            collider_indices.push([
-                start + 1,
-                start + heightmap.width,
-                start + 1 + heightmap.width,
-            ]);
-        }
-    }
-
     // Create TerrainMaterial with all tile textures for proper multi-texture terrain rendering
     // The shader uses binding_array to sample from up to 100 textures based on per-vertex tile_info
     let material_handle = terrain_materials.add(TerrainMaterial {
@@ -2643,6 +2685,7 @@ fn spawn_terrain(
         light_direction: Vec3::new(0.5, 1.0, 0.3).normalize(),
         light_color: Color::WHITE,
         ambient_color: Color::srgb(0.9, 0.9, 1.0),
+        anisotropy: current_anisotropy(),
     });
 
       // Split spawn to avoid Bundle tuple limit (15+ components not supported)
@@ -2667,7 +2710,11 @@ fn spawn_terrain(
         ))
         .insert((
             RigidBody::Fixed,
-            Collider::trimesh(collider_verts, collider_indices).expect("Failed to create terrain collider"),
+            terrain_collider(
+                heightmap,
+                Vec3::new(offset_x - 5200.0, 0.0, -offset_y + 5200.0),
+                terrain_noise,
+            ),
             CollisionGroups::new(
                 COLLISION_GROUP_ZONE_TERRAIN,
                 COLLISION_FILTER_INSPECTABLE
@@ -2772,6 +2819,24 @@ fn spawn_water(
     (water_entity, water_center, water_half_extents)
 }
 
+#[derive(Hash, PartialEq, Eq)]
+struct ObjectMaterialKey {
+    material_id: usize,
+    /// Lightmap path and uv offset / scale bits, parts in the same lightmap
+    /// atlas only share a material when they use the same cell
+    lightmap: Option<(String, [u32; 3])>,
+}
+
+/// Object materials shared between parts using the same ZSC material and
+/// lightmap, so Bevy can batch parts with the same mesh into one instanced
+/// draw while each part keeps its own entity, collider and selection.
+#[derive(Default)]
+struct ObjectMaterialCache {
+    materials: HashMap<ObjectMaterialKey, Handle<ExtendedMaterial<StandardMaterial, RoseObjectExtension>>>,
+    batches: HashSet<(AssetId<Mesh>, AssetId<ExtendedMaterial<StandardMaterial, RoseObjectExtension>>)>,
+    parts: usize,
+}
+
 fn spawn_object(
     commands: &mut Commands,
     asset_server: &AssetServer,
@@ -2788,6 +2853,7 @@ fn spawn_object(
     object_type: fn(ZoneObjectId) -> ZoneObject,
     part_object_type: fn(ZoneObjectPart) -> ZoneObject,
     collision_group: bevy_rapier3d::prelude::Group,
+    mut material_cache: Option<&mut ObjectMaterialCache>,
 ) -> Entity {
     // log::info!("[SPAWN OBJECT] Spawning object: IFO id={}, ZSC id={}, parts={}",
     //     ifo_object_id, zsc_object_id, zsc.objects[zsc_object_id].parts.len());
@@ -2925,39 +2991,69 @@ fn spawn_object(
 
             let lightmap_count = lightmap_texture.as_ref().is_some() as usize;
 
+            let material_key = ObjectMaterialKey {
+                material_id,
+                lightmap: lit_part.map(|lit_part| {
+                    (
+                        lightmap_path.join(&lit_part.filename).to_string_lossy().into_owned(),
+                        [
+                            lightmap_uv_offset.x.to_bits(),
+                            lightmap_uv_offset.y.to_bits(),
+                            lightmap_uv_scale.to_bits(),
+                        ],
+                    )
+                }),
+            };
+            let cached_material = material_cache
+                .as_ref()
+                .and_then(|material_cache| material_cache.materials.get(&material_key).cloned());
+
             // Create ExtendedMaterial with RoseObjectExtension for zone lighting support
             // This applies zone lighting ambient color to darken objects to match the original game
-            let material = object_materials.add(ExtendedMaterial {
-                base: StandardMaterial {
-                    base_color_texture: if material_path.is_empty() || material_path == "" || material_path == "NULL" {
-                        log::warn!("[SPAWN OBJECT DEBUG] Empty or NULL texture path for mesh_id {}, using fallback", mesh_id);
-                        Some(asset_server.load("ETC/SPECULAR_SPHEREMAP.DDS"))
-                    } else {
-                        Some(base_texture_handle.clone())
-                    },
-                    unlit: false,  // Enable PBR lighting for objects/decorations
-                    double_sided: zsc_material.two_sided,
-                    // PBR properties for realistic lighting on vegetation and outdoor objects
-                    perceptual_roughness: 0.8,  // Higher roughness for matte vegetation/buildings
-                    metallic: 0.0,              // Non-metallic for organic/building materials
-                    alpha_mode: if zsc_material.alpha_enabled {
-                        if let Some(threshold) = zsc_material.alpha_test {
-                            AlphaMode::Mask(threshold)
+            let material = if let Some(cached_material) = cached_material {
+                cached_material
+            } else {
+                let material = object_materials.add(ExtendedMaterial {
+                    base: StandardMaterial {
+                        base_color_texture: if material_path.is_empty() || material_path == "" || material_path == "NULL" {
+                            log::warn!("[SPAWN OBJECT DEBUG] Empty or NULL texture path for mesh_id {}, using fallback", mesh_id);
+                            Some(asset_server.load("ETC/SPECULAR_SPHEREMAP.DDS"))
                         } else {
-                            AlphaMode::Blend
-                        }
-                    } else {
-                        AlphaMode::Opaque
+                            Some(base_texture_handle.clone())
+                        },
+                        unlit: false,  // Enable PBR lighting for objects/decorations
+                        double_sided: zsc_material.two_sided,
+                        // PBR properties for realistic lighting on vegetation and outdoor objects
+                        perceptual_roughness: 0.8,  // Higher roughness for matte vegetation/buildings
+                        metallic: 0.0,              // Non-metallic for organic/building materials
+                        alpha_mode: if zsc_material.alpha_enabled {
+                            if let Some(threshold) = zsc_material.alpha_test {
+                                AlphaMode::Mask(threshold)
+                            } else {
+                                AlphaMode::Blend
+                            }
+                        } else {
+                            AlphaMode::Opaque
+                        },
+                        ..Default::default()
                     },
-                    ..Default::default()
-                },
-                extension: RoseObjectExtension {
-                    lightmap_params: Vec3::new(lightmap_uv_offset.x, lightmap_uv_offset.y, lightmap_uv_scale).extend(0.0),
-                    lightmap_texture: lightmap_texture.clone(),
-                    specular_texture: Some(specular_texture.image.clone()),
-                    blink_state: 0, // Default to eyes open
-                },
-            });
+                    extension: RoseObjectExtension {
+                        lightmap_params: Vec3::new(lightmap_uv_offset.x, lightmap_uv_offset.y, lightmap_uv_scale).extend(0.0),
+                        lightmap_texture: lightmap_texture.clone(),
+                        specular_texture: Some(specular_texture.image.clone()),
+                        blink_state: 0, // Default to eyes open
+                    },
+                });
+                if let Some(material_cache) = material_cache.as_mut() {
+                    material_cache.materials.insert(material_key, material.clone());
+                }
+                material
+            };
+
+            if let Some(material_cache) = material_cache.as_mut() {
+                material_cache.parts += 1;
+                material_cache.batches.insert((mesh.id(), material.id()));
+            }
 
             let mut collision_filter = COLLISION_FILTER_INSPECTABLE;
 